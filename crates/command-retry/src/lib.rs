@@ -0,0 +1,219 @@
+use std::future::Future;
+use std::num::NonZeroU32;
+use std::str::FromStr;
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use opentelemetry::KeyValue;
+use opentelemetry::metrics::Counter;
+
+pub(crate) type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// 楽観的排他制御で集約の更新が競合した回数を記録するカウンター
+static CONFLICTS: LazyLock<Counter<u64>> = LazyLock::new(|| {
+    opentelemetry::global::meter(env!("CARGO_PKG_NAME"))
+        .u64_counter("command.conflicts")
+        .with_description("Number of optimistic concurrency conflicts while updating aggregates")
+        .build()
+});
+
+/// 楽観的排他制御で集約の更新が競合した場合にコマンドを再実行する方針
+///
+/// 再実行するまでの待機時間は再実行するたびに2倍になり、`max_backoff` を超えない
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RetryPolicy {
+    max_attempts: NonZeroU32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Creates a new [`RetryPolicy`].
+    ///
+    /// `max_attempts` は最初の実行を含めた最大の試行回数
+    #[must_use]
+    pub fn new(max_attempts: NonZeroU32, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        Self {
+            max_attempts,
+            initial_backoff,
+            max_backoff,
+        }
+    }
+
+    /// 環境変数から再実行する方針を読み込む。設定していない値はデフォルトの方針の値にする
+    ///
+    /// - `COMMAND_MAX_ATTEMPTS`: 最初の実行を含めた最大の試行回数。デフォルトは 3 回
+    /// - `COMMAND_INITIAL_BACKOFF_MS`: 最初に再実行するまでの待機時間。デフォルトは 10 ミリ秒
+    /// - `COMMAND_MAX_BACKOFF_MS`: 再実行するまでの待機時間の上限。デフォルトは 100 ミリ秒
+    ///
+    /// # Errors
+    pub fn from_env() -> Result<Self, Error> {
+        let default = Self::default();
+        Ok(Self {
+            max_attempts: env("COMMAND_MAX_ATTEMPTS")?.unwrap_or(default.max_attempts),
+            initial_backoff: env("COMMAND_INITIAL_BACKOFF_MS")?
+                .map_or(default.initial_backoff, Duration::from_millis),
+            max_backoff: env("COMMAND_MAX_BACKOFF_MS")?
+                .map_or(default.max_backoff, Duration::from_millis),
+        })
+    }
+
+    /// 最初の実行を含めた最大の試行回数を設定する
+    #[must_use]
+    pub fn with_max_attempts(self, max_attempts: NonZeroU32) -> Self {
+        Self {
+            max_attempts,
+            ..self
+        }
+    }
+
+    /// `attempt` 回目の実行が競合した後に待機する時間
+    fn backoff(self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2_u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_backoff)
+    }
+
+    /// コマンドを実行し、`is_conflict` が集約の更新の競合とみなした場合は最大の試行回数まで再実行する
+    ///
+    /// `command` はメトリクスとログでコマンドを識別するための名前。
+    /// 最大の試行回数まで競合した場合は最後に実行した結果を返す
+    pub async fn run<R, F, Fut>(
+        self,
+        command: &'static str,
+        is_conflict: impl Fn(&R) -> bool,
+        mut f: F,
+    ) -> R
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = R>,
+    {
+        let mut attempt = 1;
+        loop {
+            let result = f().await;
+            if !is_conflict(&result) {
+                return result;
+            }
+            let retry = attempt < self.max_attempts.get();
+            CONFLICTS.add(
+                1,
+                &[
+                    KeyValue::new("command", command),
+                    KeyValue::new("retry", retry),
+                ],
+            );
+            if !retry {
+                return result;
+            }
+            tracing::warn!(command, attempt, "aggregate was updated concurrently");
+            tokio::time::sleep(self.backoff(attempt)).await;
+            attempt += 1;
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: const { NonZeroU32::new(3).unwrap() },
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_millis(100),
+        }
+    }
+}
+
+/// 環境変数を読み込む。設定していない場合は `None` を返す
+fn env<T: FromStr>(name: &str) -> Result<Option<T>, Error> {
+    match std::env::var(name) {
+        Ok(value) => value
+            .parse()
+            .map(Some)
+            .map_err(|_| format!("{name} must be a positive number").into()),
+        Err(std::env::VarError::NotPresent) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU32;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Duration;
+
+    use super::RetryPolicy;
+
+    fn policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy::new(
+            NonZeroU32::new(max_attempts).unwrap(),
+            Duration::from_millis(10),
+            Duration::from_millis(25),
+        )
+    }
+
+    #[test]
+    fn test_backoff() {
+        let policy = policy(3);
+        assert_eq!(policy.backoff(1), Duration::from_millis(10));
+        assert_eq!(policy.backoff(2), Duration::from_millis(20));
+        assert_eq!(
+            policy.backoff(3),
+            Duration::from_millis(25),
+            "上限を超えない"
+        );
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_millis(25));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_run() {
+        let attempts = AtomicU32::new(0);
+        let result: Result<u32, &str> = policy(3)
+            .run(
+                "test",
+                |result| *result == Err("conflict"),
+                || async {
+                    match attempts.fetch_add(1, Ordering::SeqCst) {
+                        0 => Err("conflict"),
+                        attempt => Ok(attempt),
+                    }
+                },
+            )
+            .await;
+        assert_eq!(result, Ok(1), "競合した場合は再実行する");
+
+        attempts.store(0, Ordering::SeqCst);
+        let result: Result<u32, &str> = policy(3)
+            .run(
+                "test",
+                |result| *result == Err("conflict"),
+                || async {
+                    attempts.fetch_add(1, Ordering::SeqCst);
+                    Err("conflict")
+                },
+            )
+            .await;
+        assert_eq!(result, Err("conflict"));
+        assert_eq!(
+            attempts.load(Ordering::SeqCst),
+            3,
+            "最大の試行回数まで競合した場合は最後の結果を返す"
+        );
+
+        attempts.store(0, Ordering::SeqCst);
+        let result: Result<u32, &str> = policy(3)
+            .run(
+                "test",
+                |result| *result == Err("conflict"),
+                || async {
+                    attempts.fetch_add(1, Ordering::SeqCst);
+                    Err("other")
+                },
+            )
+            .await;
+        assert_eq!(result, Err("other"));
+        assert_eq!(
+            attempts.load(Ordering::SeqCst),
+            1,
+            "競合以外のエラーは再実行しない"
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use std::path::PathBuf;
+
+use event_store::EventStoreModel;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::RouterError;
+
+/// 最大試行回数までハンドラーが失敗したイベント
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct DeadLetter<P> {
+    /// デッドレターを識別する ID (`<aggregate_id>:<event_id>:<handler>`)
+    id: String,
+    /// 失敗したハンドラーの名前
+    handler: String,
+    event: EventStoreModel<P>,
+    /// 最後に失敗した時のエラー
+    error: String,
+    /// ハンドラーを実行した回数
+    attempts: u32,
+    /// 最後に失敗した日時 (UNIX 時間のミリ秒)
+    failed_at: u64,
+}
+
+impl<P> DeadLetter<P> {
+    pub(crate) fn new(
+        handler: &str,
+        event: EventStoreModel<P>,
+        error: &(dyn std::error::Error + Send + Sync),
+        attempts: u32,
+    ) -> Self {
+        Self {
+            id: format!("{}:{}:{handler}", event.aggregate_id(), event.id()),
+            handler: handler.to_string(),
+            event,
+            error: format!("{error:?}"),
+            attempts,
+            failed_at: now(),
+        }
+    }
+
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    #[must_use]
+    pub fn handler(&self) -> &str {
+        &self.handler
+    }
+
+    #[must_use]
+    pub fn event(&self) -> &EventStoreModel<P> {
+        &self.event
+    }
+
+    #[must_use]
+    pub fn error(&self) -> &str {
+        &self.error
+    }
+
+    #[must_use]
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    #[must_use]
+    pub fn failed_at(&self) -> u64 {
+        self.failed_at
+    }
+
+    /// もう一度ハンドラーが失敗した時のエラーを記録する
+    pub(crate) fn failed_again(self, error: &(dyn std::error::Error + Send + Sync)) -> Self {
+        Self {
+            error: format!("{error:?}"),
+            attempts: self.attempts + 1,
+            failed_at: now(),
+            ..self
+        }
+    }
+}
+
+fn now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| u64::try_from(duration.as_millis()).unwrap_or(u64::MAX))
+        .unwrap_or_default()
+}
+
+/// デッドレターを保存するストア
+#[derive(Debug, Clone)]
+pub enum DeadLetterStore {
+    /// ファイルに JSON Lines 形式で保存する
+    File(PathBuf),
+    /// `DynamoDB` のテーブルに保存する
+    #[cfg(feature = "aws")]
+    DynamoDb {
+        client: aws_sdk_dynamodb::Client,
+        table_name: String,
+    },
+}
+
+impl DeadLetterStore {
+    /// ファイルに保存するストアを作成する
+    #[must_use]
+    pub fn file(path: impl Into<PathBuf>) -> Self {
+        Self::File(path.into())
+    }
+
+    /// 環境変数からデッドレターのストアを作成する
+    ///
+    /// - `DEAD_LETTER_STORE`: デッドレターの保存先 (`dynamodb`・`file` のいずれか)。デフォルトは `dynamodb`
+    /// - `DEAD_LETTER_FILE`: `DEAD_LETTER_STORE` が `file` の場合に保存するファイル。デフォルトは `dead-letter.jsonl`
+    ///
+    /// `dynamodb` の場合は `table_name` のテーブルに保存する
+    ///
+    /// # Errors
+    ///
+    /// 保存先が不明な場合
+    #[cfg(feature = "aws")]
+    pub async fn from_env(table_name: impl Into<String>) -> Result<Self, crate::Error> {
+        match std::env::var("DEAD_LETTER_STORE") {
+            Ok(store) if store == "file" => Ok(Self::file(
+                std::env::var("DEAD_LETTER_FILE").unwrap_or("dead-letter.jsonl".to_string()),
+            )),
+            Ok(store) if store != "dynamodb" => {
+                Err(format!("unsupported dead letter store: {store}").into())
+            }
+            _ => Ok(Self::DynamoDb {
+                client: aws_sdk_dynamodb::Client::new(&crate::runtime::aws_config().await),
+                table_name: table_name.into(),
+            }),
+        }
+    }
+
+    /// デッドレターを保存する。同じ ID のデッドレターがある場合は置き換える
+    ///
+    /// # Errors
+    pub async fn put<P>(&self, dead_letter: &DeadLetter<P>) -> Result<(), RouterError>
+    where
+        P: Clone + Serialize + DeserializeOwned,
+    {
+        match self {
+            Self::File(path) => {
+                let mut dead_letters = self.list::<P>().await?;
+                dead_letters.retain(|d| d.id() != dead_letter.id());
+                dead_letters.push(dead_letter.clone());
+                write_file(path, &dead_letters).await
+            }
+            #[cfg(feature = "aws")]
+            Self::DynamoDb { client, table_name } => {
+                let item = serde_dynamo::to_item(dead_letter)
+                    .map_err(|e| RouterError::DeadLetterStore(e.into()))?;
+                client
+                    .put_item()
+                    .table_name(table_name)
+                    .set_item(Some(item))
+                    .send()
+                    .await
+                    .map_err(|e| RouterError::DeadLetterStore(e.into()))?;
+                Ok(())
+            }
+        }
+    }
+
+    /// 保存されている全てのデッドレターを返す
+    ///
+    /// # Errors
+    pub async fn list<P>(&self) -> Result<Vec<DeadLetter<P>>, RouterError>
+    where
+        P: DeserializeOwned,
+    {
+        match self {
+            Self::File(path) => {
+                let lines = match tokio::fs::read_to_string(path).await {
+                    Ok(lines) => lines,
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+                    Err(e) => return Err(RouterError::DeadLetterStore(e.into())),
+                };
+                lines
+                    .lines()
+                    .filter(|line| !line.is_empty())
+                    .map(|line| {
+                        serde_json::from_str(line)
+                            .map_err(|e| RouterError::DeadLetterStore(e.into()))
+                    })
+                    .collect()
+            }
+            #[cfg(feature = "aws")]
+            Self::DynamoDb { client, table_name } => {
+                let mut dead_letters = Vec::new();
+                let mut exclusive_start_key = None;
+                loop {
+                    let output = client
+                        .scan()
+                        .table_name(table_name)
+                        .set_exclusive_start_key(exclusive_start_key)
+                        .send()
+                        .await
+                        .map_err(|e| RouterError::DeadLetterStore(e.into()))?;
+                    let items: Vec<DeadLetter<P>> =
+                        serde_dynamo::from_items(output.items().to_vec())
+                            .map_err(|e| RouterError::DeadLetterStore(e.into()))?;
+                    dead_letters.extend(items);
+                    exclusive_start_key = output.last_evaluated_key;
+                    if exclusive_start_key.is_none() {
+                        break;
+                    }
+                }
+                Ok(dead_letters)
+            }
+        }
+    }
+
+    /// デッドレターを削除する
+    ///
+    /// # Errors
+    pub async fn delete(&self, id: &str) -> Result<(), RouterError> {
+        match self {
+            Self::File(path) => {
+                // NOTE: ペイロードを解釈せずに削除するので、JSON の値として読み込む
+                let mut dead_letters = self.list::<serde_json::Value>().await?;
+                dead_letters.retain(|d| d.id() != id);
+                write_file(path, &dead_letters).await
+            }
+            #[cfg(feature = "aws")]
+            Self::DynamoDb { client, table_name } => {
+                client
+                    .delete_item()
+                    .table_name(table_name)
+                    .key(
+                        "id",
+                        aws_sdk_dynamodb::types::AttributeValue::S(id.to_string()),
+                    )
+                    .send()
+                    .await
+                    .map_err(|e| RouterError::DeadLetterStore(e.into()))?;
+                Ok(())
+            }
+        }
+    }
+}
+
+/// 書き込み途中でプロセスが終了してもファイルが壊れないように、一時ファイルに書き込んでから置き換える
+async fn write_file<P>(path: &PathBuf, dead_letters: &[DeadLetter<P>]) -> Result<(), RouterError>
+where
+    P: Serialize,
+{
+    let mut bytes = Vec::new();
+    for dead_letter in dead_letters {
+        serde_json::to_writer(&mut bytes, dead_letter)
+            .map_err(|e| RouterError::DeadLetterStore(e.into()))?;
+        bytes.push(b'\n');
+    }
+    let mut tmp = path.clone().into_os_string();
+    tmp.push(".tmp");
+    tokio::fs::write(&tmp, bytes)
+        .await
+        .map_err(|e| RouterError::DeadLetterStore(e.into()))?;
+    tokio::fs::rename(&tmp, path)
+        .await
+        .map_err(|e| RouterError::DeadLetterStore(e.into()))
+}
@@ -0,0 +1,265 @@
+use std::future::Future;
+use std::time::Duration;
+
+use thiserror::Error;
+use tonic::transport::{Certificate, Channel, ClientTlsConfig, Endpoint};
+use tonic::{Code, Status};
+
+#[derive(Debug, Error)]
+pub enum ClientConfigError {
+    #[error("{0} must be set")]
+    NotSet(String),
+    #[error("{0} is invalid: {1}")]
+    Invalid(String, String),
+    #[error("Failed to read CA certificate: {0}")]
+    Certificate(String, #[source] std::io::Error),
+    #[error("Invalid endpoint: {0}")]
+    Endpoint(String, #[source] tonic::transport::Error),
+}
+
+/// `Unavailable` で失敗した RPC を再試行する方針
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// 最初の呼び出しを除いた最大再試行回数
+    max_retries: u32,
+    /// 最初に再試行するまで待つ間隔。再試行するたびに2倍にする
+    backoff: Duration,
+    /// 再試行するまで待つ間隔の上限
+    max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 2,
+            backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(1),
+        }
+    }
+}
+
+impl RetryPolicy {
+    #[must_use]
+    pub fn new(max_retries: u32, backoff: Duration, max_backoff: Duration) -> Self {
+        Self {
+            max_retries,
+            backoff,
+            max_backoff,
+        }
+    }
+
+    /// `retries` 回目の再試行までに待つ間隔
+    fn backoff(&self, retries: u32) -> Duration {
+        self.backoff
+            .saturating_mul(2u32.saturating_pow(retries.saturating_sub(1)))
+            .min(self.max_backoff)
+    }
+
+    /// RPC を呼び出して、`Unavailable` で失敗した場合は間隔を空けて再試行する
+    ///
+    /// `call` は呼び出すたびに新しいリクエストを作成する
+    ///
+    /// # Errors
+    ///
+    /// 再試行できないエラーか、最大再試行回数まで `Unavailable` で失敗した場合
+    pub async fn call<T, F, Fut>(&self, mut call: F) -> Result<T, Status>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, Status>>,
+    {
+        let mut retries = 0;
+        loop {
+            match call().await {
+                Err(status) if status.code() == Code::Unavailable && retries < self.max_retries => {
+                    retries += 1;
+                    tracing::warn!(
+                        "retry unavailable rpc ({retries}/{}): {status:?}",
+                        self.max_retries
+                    );
+                    tokio::time::sleep(self.backoff(retries)).await;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// gRPC クライアントの接続先と接続方法
+#[derive(Debug, Clone)]
+pub struct ClientConfig {
+    endpoint: String,
+    /// リクエストごとのタイムアウト
+    timeout: Duration,
+    connect_timeout: Duration,
+    /// TLS で接続する場合の設定
+    tls: Option<ClientTlsConfig>,
+    retry_policy: RetryPolicy,
+}
+
+impl ClientConfig {
+    /// 環境変数から `prefix` を接頭辞とする設定を読み込む
+    ///
+    /// - `<prefix>_ENDPOINT`: 接続先 (`host:port`)。スキームを含む場合はそのまま利用する
+    /// - `<prefix>_TIMEOUT_MS`: リクエストごとのタイムアウト。デフォルトは 500 ミリ秒
+    /// - `<prefix>_CONNECT_TIMEOUT_MS`: 接続のタイムアウト。デフォルトは 1000 ミリ秒
+    /// - `<prefix>_MAX_RETRIES`: `Unavailable` で失敗した RPC の最大再試行回数。デフォルトは 2
+    /// - `<prefix>_RETRY_BACKOFF_MS`: 最初に再試行するまで待つ間隔。デフォルトは 100 ミリ秒
+    /// - `<prefix>_MAX_RETRY_BACKOFF_MS`: 再試行するまで待つ間隔の上限。デフォルトは 1000 ミリ秒
+    /// - `<prefix>_TLS`: `true` の場合は TLS で接続する。デフォルトは `false`
+    /// - `<prefix>_TLS_CA_FILE`: サーバー証明書を検証する CA 証明書の PEM ファイル。設定しない場合は Mozilla のルート証明書で検証する
+    /// - `<prefix>_TLS_DOMAIN`: サーバー証明書を検証するドメイン名。デフォルトは接続先のホスト名
+    ///
+    /// # Errors
+    ///
+    /// 接続先が設定されていないか、設定が不正な場合
+    pub fn from_env(prefix: &str) -> Result<Self, ClientConfigError> {
+        let var = |name: &str| std::env::var(format!("{prefix}_{name}")).ok();
+        let millis = |name: &str, default: u64| match var(name) {
+            Some(value) => value
+                .parse()
+                .map(Duration::from_millis)
+                .map_err(|_| ClientConfigError::Invalid(format!("{prefix}_{name}"), value.clone())),
+            None => Ok(Duration::from_millis(default)),
+        };
+
+        let endpoint = var("ENDPOINT")
+            .ok_or_else(|| ClientConfigError::NotSet(format!("{prefix}_ENDPOINT")))?;
+        let tls = match var("TLS").as_deref() {
+            Some("true") => {
+                let mut tls = ClientTlsConfig::new();
+                tls = match var("TLS_CA_FILE") {
+                    Some(path) => tls.ca_certificate(Certificate::from_pem(
+                        std::fs::read(&path)
+                            .map_err(|e| ClientConfigError::Certificate(path.clone(), e))?,
+                    )),
+                    None => tls.with_webpki_roots(),
+                };
+                if let Some(domain) = var("TLS_DOMAIN") {
+                    tls = tls.domain_name(domain);
+                }
+                Some(tls)
+            }
+            Some("false") | None => None,
+            Some(value) => {
+                return Err(ClientConfigError::Invalid(
+                    format!("{prefix}_TLS"),
+                    value.to_string(),
+                ));
+            }
+        };
+        let max_retries = match var("MAX_RETRIES") {
+            Some(value) => value
+                .parse()
+                .map_err(|_| ClientConfigError::Invalid(format!("{prefix}_MAX_RETRIES"), value))?,
+            None => RetryPolicy::default().max_retries,
+        };
+        Ok(Self {
+            endpoint: if endpoint.contains("://") {
+                endpoint
+            } else if tls.is_some() {
+                format!("https://{endpoint}")
+            } else {
+                format!("http://{endpoint}")
+            },
+            timeout: millis("TIMEOUT_MS", 500)?,
+            connect_timeout: millis("CONNECT_TIMEOUT_MS", 1000)?,
+            tls,
+            retry_policy: RetryPolicy::new(
+                max_retries,
+                millis("RETRY_BACKOFF_MS", 100)?,
+                millis("MAX_RETRY_BACKOFF_MS", 1000)?,
+            ),
+        })
+    }
+
+    #[must_use]
+    pub fn retry_policy(&self) -> RetryPolicy {
+        self.retry_policy
+    }
+
+    /// 接続先へのチャネルを作成する
+    ///
+    /// 最初のリクエストで接続し、切断した場合は再接続する。
+    /// チャネルを複製したクライアントは同じ接続を共有するので、プロセスごとに1回だけ作成する
+    ///
+    /// # Errors
+    ///
+    /// 接続先か TLS の設定が不正な場合
+    pub fn channel(&self) -> Result<Channel, ClientConfigError> {
+        let error = |e| ClientConfigError::Endpoint(self.endpoint.clone(), e);
+        let mut endpoint = Endpoint::from_shared(self.endpoint.clone())
+            .map_err(error)?
+            .timeout(self.timeout)
+            .connect_timeout(self.connect_timeout);
+        if let Some(tls) = &self.tls {
+            endpoint = endpoint.tls_config(tls.clone()).map_err(error)?;
+        }
+        Ok(endpoint.connect_lazy())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Duration;
+
+    use tonic::{Code, Status};
+
+    use super::RetryPolicy;
+
+    #[tokio::test]
+    async fn test_retry_policy_call() {
+        struct TestCase {
+            name: &'static str,
+            code: Code,
+            expected_calls: u32,
+        }
+
+        let tests = [
+            TestCase {
+                name: "Unavailableの場合は最大再試行回数まで再試行する",
+                code: Code::Unavailable,
+                expected_calls: 3,
+            },
+            TestCase {
+                name: "Unavailable以外の場合は再試行しない",
+                code: Code::InvalidArgument,
+                expected_calls: 1,
+            },
+        ];
+
+        for TestCase {
+            name,
+            code,
+            expected_calls,
+        } in tests
+        {
+            let calls = AtomicU32::new(0);
+            let result: Result<(), Status> =
+                RetryPolicy::new(2, Duration::from_millis(1), Duration::from_millis(1))
+                    .call(|| {
+                        calls.fetch_add(1, Ordering::SeqCst);
+                        async move { Err(Status::new(code, "error")) }
+                    })
+                    .await;
+            assert_eq!(result.map_err(|s| s.code()), Err(code), "{name}");
+            assert_eq!(calls.load(Ordering::SeqCst), expected_calls, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_retry_policy_backoff() {
+        let policy = RetryPolicy::new(10, Duration::from_millis(100), Duration::from_secs(1));
+        let backoffs = (1..=6).map(|retries| policy.backoff(retries).as_millis());
+        assert_eq!(
+            backoffs.collect::<Vec<_>>(),
+            [100, 200, 400, 800, 1000, 1000],
+            "再試行するたびに2倍にして上限で止める"
+        );
+        assert_eq!(
+            policy.backoff(u32::MAX),
+            Duration::from_secs(1),
+            "再試行回数が大きくても上限を超えない"
+        );
+    }
+}
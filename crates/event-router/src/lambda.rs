@@ -0,0 +1,140 @@
+use std::fmt::Debug;
+
+use aws_lambda_events::event::dynamodb::Event;
+use aws_lambda_events::event::streams::{DynamoDbBatchItemFailure, DynamoDbEventResponse};
+use event_store::EventStoreModel;
+use lambda_runtime::LambdaEvent;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::{Router, RouterError};
+
+/// `DynamoDB Streams` のレコードをルーティングして、失敗したレコードのシーケンス番号だけを返す
+///
+/// 1つのイベントの失敗でシャード全体が止まらないように、失敗したレコードがあっても後続のレコードをルーティングする。
+/// Lambda は返したシーケンス番号のうち最も小さいレコードから再試行する
+///
+/// # Errors
+#[tracing::instrument(skip_all, fields(records = event.payload.records.len()), err, ret)]
+pub async fn handle<P>(
+    router: &Router<P>,
+    event: LambdaEvent<Event>,
+) -> Result<DynamoDbEventResponse, lambda_runtime::Error>
+where
+    P: Debug + Clone + Serialize + DeserializeOwned + Send + Sync,
+{
+    let mut batch_item_failures = Vec::new();
+    for record in event.payload.records {
+        let sequence_number = record.change.sequence_number;
+        let result = match serde_dynamo::from_item::<_, EventStoreModel<P>>(record.change.new_image)
+        {
+            Ok(model) => router.dispatch(&model).await,
+            Err(e) => Err(RouterError::Deserialize(e.into())),
+        };
+        if let Err(e) = result {
+            tracing::error!("failed to route record {sequence_number:?}: {e:?}");
+            batch_item_failures.push(DynamoDbBatchItemFailure {
+                item_identifier: sequence_number,
+            });
+        }
+    }
+    Ok(DynamoDbEventResponse {
+        batch_item_failures,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use aws_lambda_events::event::dynamodb::Event;
+    use event_store::EventStoreModel;
+    use lambda_runtime::{Context, LambdaEvent};
+    use serde::{Deserialize, Serialize};
+
+    use crate::{Handler, HandlerError, Router};
+
+    type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+    enum EventPayload {
+        CreatedV1,
+        PlacedV1,
+    }
+
+    /// イベントID が2のイベントだけ失敗するハンドラー
+    struct FailingHandler;
+
+    impl Handler<EventPayload> for FailingHandler {
+        async fn handle(&self, model: &EventStoreModel<EventPayload>) -> Result<(), HandlerError> {
+            if model.id() == 2 {
+                return Err("failed".into());
+            }
+            Ok(())
+        }
+    }
+
+    fn record(sequence_number: &str, new_image: &serde_json::Value) -> serde_json::Value {
+        serde_json::json!({
+            "eventID": sequence_number,
+            "eventName": "INSERT",
+            "eventVersion": "1.1",
+            "eventSource": "aws:dynamodb",
+            "awsRegion": "ap-northeast-1",
+            "dynamodb": {
+                "Keys": {},
+                "NewImage": new_image,
+                "SequenceNumber": sequence_number,
+                "SizeBytes": 1,
+                "StreamViewType": "NEW_IMAGE"
+            },
+            "eventSourceARN": "arn:aws:dynamodb:ap-northeast-1:000000000000:table/event-store/stream/0"
+        })
+    }
+
+    fn image(id: u64, payload: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": { "N": id.to_string() },
+            "aggregate_id": { "S": "aggregate" },
+            "payload": { "S": payload }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_handle_partial_batch_failure() -> Result<(), Error> {
+        let router = Router::new().route(
+            "placed",
+            |payload| matches!(payload, EventPayload::PlacedV1),
+            FailingHandler,
+        );
+        let event: Event = serde_json::from_value(serde_json::json!({
+            "Records": [
+                record("100", &image(1, "PlacedV1")),
+                record("200", &image(2, "PlacedV1")),
+                record("300", &serde_json::json!({ "id": { "S": "invalid" } })),
+                record("400", &image(4, "CreatedV1")),
+                record("500", &image(2, "CreatedV1")),
+            ]
+        }))?;
+
+        let response = super::handle(&router, LambdaEvent::new(event, Context::default())).await?;
+        assert_eq!(
+            response
+                .batch_item_failures
+                .iter()
+                .map(|failure| failure.item_identifier.as_deref())
+                .collect::<Vec<_>>(),
+            vec![Some("200"), Some("300")],
+            "失敗したレコードと読み取れないレコードのシーケンス番号だけを返す"
+        );
+        assert_eq!(
+            serde_json::to_value(&response)?,
+            serde_json::json!({
+                "batchItemFailures": [
+                    { "itemIdentifier": "200" },
+                    { "itemIdentifier": "300" },
+                ]
+            }),
+            "Lambda が読み取る形式でシリアライズする"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,479 @@
+mod dead_letter;
+#[cfg(feature = "grpc")]
+pub mod grpc;
+#[cfg(feature = "aws")]
+pub mod lambda;
+#[cfg(feature = "aws")]
+pub mod poller;
+#[cfg(feature = "aws")]
+mod runtime;
+
+use std::fmt::Debug;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use event_store::EventStoreModel;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+use tracing::Instrument as _;
+
+pub use dead_letter::{DeadLetter, DeadLetterStore};
+#[cfg(feature = "aws")]
+pub use runtime::{Config, run};
+
+pub(crate) type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// ハンドラーがイベントの処理に失敗した時のエラー
+pub type HandlerError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// イベントストアに保存されたイベントを処理するハンドラー
+///
+/// 同じイベントを再送する場合があるので、ハンドラーは冪等に実装する
+pub trait Handler<P>: Send + Sync {
+    /// イベントを処理する
+    fn handle(
+        &self,
+        model: &EventStoreModel<P>,
+    ) -> impl Future<Output = Result<(), HandlerError>> + Send;
+}
+
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// 異なる型のハンドラーを同じルーターに登録するためのトレイト
+trait DynHandler<P>: Send + Sync {
+    fn handle<'a>(
+        &'a self,
+        model: &'a EventStoreModel<P>,
+    ) -> BoxFuture<'a, Result<(), HandlerError>>;
+}
+
+impl<P, H> DynHandler<P> for H
+where
+    P: Sync,
+    H: Handler<P>,
+{
+    fn handle<'a>(
+        &'a self,
+        model: &'a EventStoreModel<P>,
+    ) -> BoxFuture<'a, Result<(), HandlerError>> {
+        Box::pin(Handler::handle(self, model))
+    }
+}
+
+struct Route<P> {
+    name: &'static str,
+    matches: fn(&P) -> bool,
+    handler: Arc<dyn DynHandler<P>>,
+}
+
+impl<P> Clone for Route<P> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name,
+            matches: self.matches,
+            handler: Arc::clone(&self.handler),
+        }
+    }
+}
+
+/// 最大試行回数までハンドラーが失敗した時の方針
+#[derive(Debug, Clone, Default)]
+pub enum ErrorPolicy {
+    /// エラーを返して、呼び出し元にイベントを再送させる
+    #[default]
+    Fail,
+    /// エラーを記録してイベントを読み飛ばす
+    Skip,
+    /// デッドレターとして保存して、後続のイベントのルーティングを止めない
+    DeadLetter(DeadLetterStore),
+}
+
+#[derive(Debug, Error)]
+pub enum RouterError {
+    #[error("Handler {handler} failed to handle event")]
+    Handler {
+        handler: &'static str,
+        #[source]
+        source: HandlerError,
+    },
+    #[error("Handler is not found: {0}")]
+    HandlerNotFound(String),
+    #[error("Dead letter store is not configured")]
+    DeadLetterStoreNotConfigured,
+    #[error("Failed to access dead letter store")]
+    DeadLetterStore(#[source] Error),
+    #[error("Failed to deserialize event")]
+    Deserialize(#[source] Error),
+}
+
+/// ハンドラーを再試行するまでの間隔の上限
+pub const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// イベントのペイロードごとに登録したハンドラーにイベントをルーティングするルーター
+///
+/// `P` はイベントストアのペイロード
+pub struct Router<P> {
+    routes: Vec<Route<P>>,
+    error_policy: ErrorPolicy,
+    max_attempts: u32,
+    backoff: Duration,
+}
+
+impl<P> Debug for Router<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Router")
+            .field(
+                "routes",
+                &self
+                    .routes
+                    .iter()
+                    .map(|route| route.name)
+                    .collect::<Vec<_>>(),
+            )
+            .field("error_policy", &self.error_policy)
+            .field("max_attempts", &self.max_attempts)
+            .field("backoff", &self.backoff)
+            .finish()
+    }
+}
+
+impl<P> Clone for Router<P> {
+    fn clone(&self) -> Self {
+        Self {
+            routes: self.routes.clone(),
+            error_policy: self.error_policy.clone(),
+            max_attempts: self.max_attempts,
+            backoff: self.backoff,
+        }
+    }
+}
+
+impl<P> Default for Router<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P> Router<P> {
+    /// Creates a new [`Router`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            routes: Vec::new(),
+            error_policy: ErrorPolicy::default(),
+            max_attempts: 1,
+            backoff: Duration::from_millis(100),
+        }
+    }
+
+    /// `matches` が `true` を返すペイロードのイベントを処理するハンドラーを登録する
+    ///
+    /// `name` はトレースとデッドレターでハンドラーを識別するので、ルーターの中で一意にする
+    ///
+    /// ```
+    /// # use event_router::{Handler, HandlerError, Router};
+    /// # use event_store::EventStoreModel;
+    /// enum EventPayload {
+    ///     OrderPlacedV1,
+    /// }
+    ///
+    /// struct CreateOrder;
+    ///
+    /// impl Handler<EventPayload> for CreateOrder {
+    ///     async fn handle(&self, _: &EventStoreModel<EventPayload>) -> Result<(), HandlerError> {
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let router = Router::new().route(
+    ///     "create_order",
+    ///     |payload| matches!(payload, EventPayload::OrderPlacedV1),
+    ///     CreateOrder,
+    /// );
+    /// ```
+    #[must_use]
+    pub fn route<H>(mut self, name: &'static str, matches: fn(&P) -> bool, handler: H) -> Self
+    where
+        P: Sync,
+        H: Handler<P> + 'static,
+    {
+        self.routes.push(Route {
+            name,
+            matches,
+            handler: Arc::new(handler),
+        });
+        self
+    }
+
+    /// ハンドラーが失敗した時の方針を設定する
+    #[must_use]
+    pub fn with_error_policy(self, error_policy: ErrorPolicy) -> Self {
+        Self {
+            error_policy,
+            ..self
+        }
+    }
+
+    /// ハンドラーが失敗した時に再試行する最大試行回数と、最初に再試行するまでの間隔を設定する
+    ///
+    /// 再試行するまでの間隔は再試行するたびに2倍にして、最大で [`MAX_BACKOFF`] まで空ける
+    #[must_use]
+    pub fn with_retry(self, max_attempts: u32, backoff: Duration) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            backoff,
+            ..self
+        }
+    }
+
+    /// デッドレターを保存するストアを返す
+    #[must_use]
+    pub fn dead_letter_store(&self) -> Option<&DeadLetterStore> {
+        match &self.error_policy {
+            ErrorPolicy::DeadLetter(store) => Some(store),
+            ErrorPolicy::Fail | ErrorPolicy::Skip => None,
+        }
+    }
+}
+
+impl<P> Router<P>
+where
+    P: Debug + Clone + Serialize + DeserializeOwned + Send + Sync,
+{
+    /// ペイロードが一致する全てのハンドラーにイベントをルーティングする
+    ///
+    /// イベントに保存されたトレースコンテキストをリンクしたスパンでハンドラーを実行する
+    ///
+    /// # Errors
+    ///
+    /// ハンドラーが失敗して、方針がエラーを返す場合
+    pub async fn dispatch(&self, model: &EventStoreModel<P>) -> Result<(), RouterError> {
+        use tracing_opentelemetry::OpenTelemetrySpanExt as _;
+
+        let routes: Vec<_> = self
+            .routes
+            .iter()
+            .filter(|route| (route.matches)(model.payload()))
+            .collect();
+        if routes.is_empty() {
+            return Ok(());
+        }
+        let span = tracing::info_span!(
+            "dispatch",
+            aggregate_id = model.aggregate_id(),
+            event_id = model.id()
+        );
+        span.add_link(observability::aws_dynamodb::extract(model.metadata()));
+        async {
+            for route in routes {
+                self.handle(route, model).await?;
+            }
+            Ok(())
+        }
+        .instrument(span)
+        .await
+    }
+
+    #[tracing::instrument(skip_all, fields(handler = route.name), err)]
+    async fn handle(
+        &self,
+        route: &Route<P>,
+        model: &EventStoreModel<P>,
+    ) -> Result<(), RouterError> {
+        let mut attempts = 0;
+        let mut backoff = self.backoff;
+        let error = loop {
+            attempts += 1;
+            match route.handler.handle(model).await {
+                Ok(()) => return Ok(()),
+                Err(e) if attempts >= self.max_attempts => break e,
+                Err(e) => {
+                    tracing::warn!("retry handler {} after {backoff:?}: {e:?}", route.name);
+                    tokio::time::sleep(backoff).await;
+                    backoff = backoff.saturating_mul(2).min(MAX_BACKOFF);
+                }
+            }
+        };
+        match &self.error_policy {
+            ErrorPolicy::Fail => Err(RouterError::Handler {
+                handler: route.name,
+                source: error,
+            }),
+            ErrorPolicy::Skip => {
+                tracing::error!("skip event after {attempts} attempts: {error:?}");
+                Ok(())
+            }
+            ErrorPolicy::DeadLetter(store) => {
+                tracing::error!("park event as dead letter after {attempts} attempts: {error:?}");
+                store
+                    .put(&DeadLetter::new(
+                        route.name,
+                        model.clone(),
+                        error.as_ref(),
+                        attempts,
+                    ))
+                    .await
+            }
+        }
+    }
+
+    /// デッドレターのイベントを失敗したハンドラーでもう一度処理する
+    ///
+    /// 成功した場合はデッドレターを削除し、失敗した場合はエラーを記録してデッドレターを残す
+    ///
+    /// # Errors
+    ///
+    /// ハンドラーが失敗した場合や、デッドレターのストアにアクセスできない場合
+    #[tracing::instrument(skip_all, fields(id = dead_letter.id()), err)]
+    pub async fn redrive(&self, dead_letter: DeadLetter<P>) -> Result<(), RouterError> {
+        let store = self
+            .dead_letter_store()
+            .ok_or(RouterError::DeadLetterStoreNotConfigured)?;
+        let route = self
+            .routes
+            .iter()
+            .find(|route| route.name == dead_letter.handler())
+            .ok_or_else(|| RouterError::HandlerNotFound(dead_letter.handler().to_string()))?;
+        match route.handler.handle(dead_letter.event()).await {
+            Ok(()) => store.delete(dead_letter.id()).await,
+            Err(e) => {
+                store.put(&dead_letter.failed_again(e.as_ref())).await?;
+                Err(RouterError::Handler {
+                    handler: route.name,
+                    source: e,
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Duration;
+
+    use event_store::EventStoreModel;
+    use serde::{Deserialize, Serialize};
+
+    use super::{DeadLetterStore, ErrorPolicy, Handler, HandlerError, Router, RouterError};
+
+    type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+    enum EventPayload {
+        CreatedV1,
+        PlacedV1,
+    }
+
+    /// 指定した回数だけ失敗してから成功するハンドラー
+    struct FlakyHandler {
+        failures: AtomicU32,
+        handled: Mutex<Vec<u64>>,
+    }
+
+    impl FlakyHandler {
+        fn new(failures: u32) -> Self {
+            Self {
+                failures: AtomicU32::new(failures),
+                handled: Mutex::default(),
+            }
+        }
+    }
+
+    impl Handler<EventPayload> for std::sync::Arc<FlakyHandler> {
+        async fn handle(&self, model: &EventStoreModel<EventPayload>) -> Result<(), HandlerError> {
+            if self
+                .failures
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .is_ok()
+            {
+                return Err("failed".into());
+            }
+            self.handled.lock().unwrap().push(model.id());
+            Ok(())
+        }
+    }
+
+    fn model(id: u64, payload: EventPayload) -> EventStoreModel<EventPayload> {
+        EventStoreModel::new(id, "aggregate".to_string(), payload)
+    }
+
+    fn router(handler: &std::sync::Arc<FlakyHandler>) -> Router<EventPayload> {
+        Router::new().route(
+            "placed",
+            |payload| matches!(payload, EventPayload::PlacedV1),
+            std::sync::Arc::clone(handler),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_matched_route() -> Result<(), Error> {
+        let handler = std::sync::Arc::new(FlakyHandler::new(0));
+        let router = router(&handler);
+
+        router.dispatch(&model(1, EventPayload::CreatedV1)).await?;
+        router.dispatch(&model(2, EventPayload::PlacedV1)).await?;
+        assert_eq!(*handler.handled.lock().unwrap(), vec![2]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_retry() -> Result<(), Error> {
+        let handler = std::sync::Arc::new(FlakyHandler::new(2));
+        let router = router(&handler);
+
+        assert!(matches!(
+            router.dispatch(&model(1, EventPayload::PlacedV1)).await,
+            Err(RouterError::Handler {
+                handler: "placed",
+                ..
+            })
+        ));
+        let router = router.with_retry(2, Duration::ZERO);
+        router.dispatch(&model(1, EventPayload::PlacedV1)).await?;
+        assert_eq!(*handler.handled.lock().unwrap(), vec![1]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_skip() -> Result<(), Error> {
+        let handler = std::sync::Arc::new(FlakyHandler::new(1));
+        let router = router(&handler).with_error_policy(ErrorPolicy::Skip);
+
+        router.dispatch(&model(1, EventPayload::PlacedV1)).await?;
+        router.dispatch(&model(2, EventPayload::PlacedV1)).await?;
+        assert_eq!(*handler.handled.lock().unwrap(), vec![2]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_dead_letter_and_redrive() -> Result<(), Error> {
+        let path = std::env::temp_dir().join(format!("dead-letter-{}.jsonl", std::process::id()));
+        let store = DeadLetterStore::file(&path);
+        let handler = std::sync::Arc::new(FlakyHandler::new(2));
+        let router = router(&handler).with_error_policy(ErrorPolicy::DeadLetter(store.clone()));
+
+        router.dispatch(&model(1, EventPayload::PlacedV1)).await?;
+        let dead_letters = store.list::<EventPayload>().await?;
+        assert_eq!(dead_letters.len(), 1);
+        assert_eq!(dead_letters[0].id(), "aggregate:1:placed");
+        assert_eq!(dead_letters[0].attempts(), 1);
+
+        assert!(
+            router.redrive(dead_letters[0].clone()).await.is_err(),
+            "再送に失敗した場合はエラーを返す"
+        );
+        let dead_letters = store.list::<EventPayload>().await?;
+        assert_eq!(dead_letters[0].attempts(), 2, "失敗した回数を記録する");
+
+        router.redrive(dead_letters[0].clone()).await?;
+        assert!(store.list::<EventPayload>().await?.is_empty());
+        assert_eq!(*handler.handled.lock().unwrap(), vec![1]);
+        tokio::fs::remove_file(&path).await?;
+        Ok(())
+    }
+}
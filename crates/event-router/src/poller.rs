@@ -0,0 +1,667 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use aws_sdk_dynamodbstreams::operation::get_records::{GetRecordsError, GetRecordsOutput};
+use aws_sdk_dynamodbstreams::operation::get_shard_iterator::GetShardIteratorError;
+use aws_sdk_dynamodbstreams::types::{AttributeValue, Shard, ShardIteratorType};
+use event_store::EventStoreModel;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{Router, RouterError};
+
+#[derive(Debug, Error)]
+pub enum PollerError {
+    #[error("Failed to call DynamoDB Streams: {0}")]
+    Stream(&'static str, #[source] crate::Error),
+    #[error("Stream of {0} is not enabled")]
+    StreamNotFound(String),
+    #[error("Failed to access checkpoint: {0}")]
+    Checkpoint(String, #[source] crate::Error),
+    #[error("Invalid configuration: {0}")]
+    Config(String),
+    #[error("Failed to route record of {0}")]
+    Router(String, #[source] RouterError),
+}
+
+/// シャードごとに処理したレコードの位置
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+struct ShardCheckpoint {
+    /// 最後に処理したレコードのシーケンス番号
+    sequence_number: Option<String>,
+    /// 閉じたシャードのレコードを全て処理したか
+    finished: bool,
+}
+
+/// ストリームをどこまで処理したかを記録するチェックポイント
+///
+/// プロセスを再起動した場合は、チェックポイントに記録したシーケンス番号の次のレコードから処理する
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+struct Checkpoint {
+    stream_arn: Option<String>,
+    shards: BTreeMap<String, ShardCheckpoint>,
+}
+
+impl Checkpoint {
+    async fn load(path: &PathBuf) -> Result<Self, PollerError> {
+        let error = |e: crate::Error| PollerError::Checkpoint(path.display().to_string(), e);
+        match tokio::fs::read(path).await {
+            Ok(bytes) => serde_json::from_slice(&bytes).map_err(|e| error(e.into())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(error(e.into())),
+        }
+    }
+
+    /// 書き込み途中でプロセスが終了してもチェックポイントが壊れないように、一時ファイルに書き込んでから置き換える
+    async fn save(&self, path: &PathBuf) -> Result<(), PollerError> {
+        let error = |e: crate::Error| PollerError::Checkpoint(path.display().to_string(), e);
+        let bytes = serde_json::to_vec(self).map_err(|e| error(e.into()))?;
+        let mut tmp = path.clone().into_os_string();
+        tmp.push(".tmp");
+        tokio::fs::write(&tmp, bytes)
+            .await
+            .map_err(|e| error(e.into()))?;
+        tokio::fs::rename(&tmp, path)
+            .await
+            .map_err(|e| error(e.into()))
+    }
+}
+
+/// `DynamoDB Streams` を直接読み取ってイベントをルーティングするポーラー
+///
+/// Lambda 関数を使わずに通常のプロセスとして実行する。
+/// 親シャードのレコードを全て処理してから子シャードのレコードを処理するので、集約ごとのイベントの順序を保つ
+#[derive(Debug)]
+pub struct Poller<P> {
+    router: Router<P>,
+    client: aws_sdk_dynamodbstreams::Client,
+    table_name: String,
+    stream_arn: Option<String>,
+    checkpoint_path: PathBuf,
+    interval: Duration,
+    checkpoint: Checkpoint,
+    /// シャードごとの次に読み取るシャードイテレーター
+    shard_iterators: HashMap<String, String>,
+}
+
+impl<P> Poller<P>
+where
+    P: Debug + Clone + Serialize + DeserializeOwned + Send + Sync,
+{
+    /// 環境変数から `table_name` のストリームを読み取るポーラーを作成する
+    ///
+    /// - `EVENT_STORE_STREAM_ARN`: 読み取るストリームの ARN。設定しない場合は `table_name` のテーブルから探す
+    /// - `EVENT_ROUTER_CHECKPOINT_FILE`: チェックポイントを保存するファイル。デフォルトは `event-router-checkpoint.json`
+    /// - `EVENT_ROUTER_POLL_INTERVAL_MS`: 新しいレコードがない場合に次に読み取るまで待つ間隔。デフォルトは 1000 ミリ秒
+    ///
+    /// # Errors
+    pub async fn from_env(
+        router: Router<P>,
+        table_name: impl Into<String>,
+    ) -> Result<Self, PollerError> {
+        let interval = match std::env::var("EVENT_ROUTER_POLL_INTERVAL_MS") {
+            Ok(interval) => Duration::from_millis(interval.parse().map_err(|_| {
+                PollerError::Config("EVENT_ROUTER_POLL_INTERVAL_MS must be a number".to_string())
+            })?),
+            Err(_) => Duration::from_secs(1),
+        };
+        let checkpoint_path: PathBuf = std::env::var("EVENT_ROUTER_CHECKPOINT_FILE")
+            .unwrap_or("event-router-checkpoint.json".to_string())
+            .into();
+        let checkpoint = Checkpoint::load(&checkpoint_path).await?;
+        Ok(Self {
+            router,
+            client: aws_sdk_dynamodbstreams::Client::new(&crate::runtime::aws_config().await),
+            table_name: table_name.into(),
+            stream_arn: std::env::var("EVENT_STORE_STREAM_ARN").ok(),
+            checkpoint_path,
+            interval,
+            checkpoint,
+            shard_iterators: HashMap::new(),
+        })
+    }
+
+    /// `shutdown` が完了するまでストリームを読み取り続ける
+    ///
+    /// 読み取りに失敗した場合はエラーを記録して、間隔を空けてからチェックポイントの位置から読み取り直す
+    pub async fn run(mut self, shutdown: impl Future<Output = ()>) {
+        let mut shutdown = std::pin::pin!(shutdown);
+        loop {
+            match self.poll().await {
+                // NOTE: 読み取るレコードが残っている可能性があるので待たずに読み取る
+                Ok(count) if count > 0 => continue,
+                Ok(_) => {}
+                Err(e) => {
+                    tracing::error!("failed to poll stream: {e:?}");
+                    self.shard_iterators.clear();
+                }
+            }
+            tokio::select! {
+                () = &mut shutdown => return,
+                () = tokio::time::sleep(self.interval) => {}
+            }
+        }
+    }
+
+    /// 全てのシャードを1回ずつ読み取って、処理したレコードの数を返す
+    async fn poll(&mut self) -> Result<usize, PollerError> {
+        let stream_arn = self.stream_arn().await?;
+        if self.checkpoint.stream_arn.as_ref() != Some(&stream_arn) {
+            // NOTE: テーブルを作り直した場合はストリームも変わるので、最初から読み取る
+            self.checkpoint = Checkpoint {
+                stream_arn: Some(stream_arn.clone()),
+                shards: BTreeMap::new(),
+            };
+            self.shard_iterators.clear();
+        }
+
+        let shards = self.list_shards(&stream_arn).await?;
+        let mut count = 0;
+        for shard in &shards {
+            let Some(shard_id) = shard.shard_id() else {
+                continue;
+            };
+            let state = self
+                .checkpoint
+                .shards
+                .get(shard_id)
+                .cloned()
+                .unwrap_or_default();
+            if state.finished {
+                continue;
+            }
+            // NOTE: 親シャードを処理し終わるまで子シャードは読み取らない
+            let parent_unfinished = shard.parent_shard_id().is_some_and(|parent_id| {
+                shards.iter().any(|s| s.shard_id() == Some(parent_id))
+                    && !self
+                        .checkpoint
+                        .shards
+                        .get(parent_id)
+                        .is_some_and(|p| p.finished)
+            });
+            if parent_unfinished {
+                continue;
+            }
+
+            let iterator = match self.shard_iterators.remove(shard_id) {
+                Some(iterator) => iterator,
+                None => {
+                    self.shard_iterator(&stream_arn, shard_id, state.sequence_number.as_deref())
+                        .await?
+                }
+            };
+            let output = self
+                .get_records(
+                    &stream_arn,
+                    shard_id,
+                    iterator,
+                    state.sequence_number.as_deref(),
+                )
+                .await?;
+            let records = output.records.unwrap_or_default();
+            let mut sequence_number = state.sequence_number.clone();
+            for record in records {
+                let Some(stream_record) = record.dynamodb else {
+                    continue;
+                };
+                if let Some(new_image) = stream_record.new_image {
+                    if let Err(e) = self.route(new_image).await {
+                        // NOTE: 失敗したレコードの直前までをチェックポイントに記録して、次は失敗したレコードから読み取り直す
+                        self.checkpoint.shards.insert(
+                            shard_id.to_string(),
+                            ShardCheckpoint {
+                                sequence_number,
+                                finished: false,
+                            },
+                        );
+                        self.checkpoint.save(&self.checkpoint_path).await?;
+                        return Err(PollerError::Router(shard_id.to_string(), e));
+                    }
+                    count += 1;
+                }
+                sequence_number = stream_record.sequence_number.or(sequence_number);
+            }
+
+            let finished = match output.next_shard_iterator {
+                Some(next) => {
+                    self.shard_iterators.insert(shard_id.to_string(), next);
+                    false
+                }
+                // NOTE: 閉じたシャードのレコードを全て読み取った
+                None => true,
+            };
+            if sequence_number == state.sequence_number && !finished {
+                continue;
+            }
+            self.checkpoint.shards.insert(
+                shard_id.to_string(),
+                ShardCheckpoint {
+                    sequence_number,
+                    finished,
+                },
+            );
+            self.checkpoint.save(&self.checkpoint_path).await?;
+        }
+        Ok(count)
+    }
+
+    async fn route(&self, item: HashMap<String, AttributeValue>) -> Result<(), RouterError> {
+        let model: EventStoreModel<P> =
+            serde_dynamo::from_item(item).map_err(|e| RouterError::Deserialize(e.into()))?;
+        self.router.dispatch(&model).await
+    }
+
+    async fn stream_arn(&mut self) -> Result<String, PollerError> {
+        if let Some(stream_arn) = &self.stream_arn {
+            return Ok(stream_arn.clone());
+        }
+        let output = self
+            .client
+            .list_streams()
+            .table_name(&self.table_name)
+            .send()
+            .await
+            .map_err(|e| PollerError::Stream("list streams", e.into()))?;
+        let stream_arn = output
+            .streams
+            .unwrap_or_default()
+            .into_iter()
+            .find_map(|stream| stream.stream_arn)
+            .ok_or_else(|| PollerError::StreamNotFound(self.table_name.clone()))?;
+        tracing::info!("poll stream: {stream_arn}");
+        self.stream_arn = Some(stream_arn.clone());
+        Ok(stream_arn)
+    }
+
+    async fn list_shards(&self, stream_arn: &str) -> Result<Vec<Shard>, PollerError> {
+        let mut shards = Vec::new();
+        let mut exclusive_start_shard_id = None;
+        loop {
+            let output = self
+                .client
+                .describe_stream()
+                .stream_arn(stream_arn)
+                .set_exclusive_start_shard_id(exclusive_start_shard_id)
+                .send()
+                .await
+                .map_err(|e| PollerError::Stream("describe stream", e.into()))?;
+            let Some(description) = output.stream_description else {
+                break;
+            };
+            shards.extend(description.shards.unwrap_or_default());
+            exclusive_start_shard_id = description.last_evaluated_shard_id;
+            if exclusive_start_shard_id.is_none() {
+                break;
+            }
+        }
+        Ok(shards)
+    }
+
+    /// シャードイテレーターの有効期限が切れた場合や、読み取る位置のレコードが削除された場合は、
+    /// チェックポイントの位置からイテレーターを取得し直して1回だけ読み取り直す
+    async fn get_records(
+        &self,
+        stream_arn: &str,
+        shard_id: &str,
+        iterator: String,
+        sequence_number: Option<&str>,
+    ) -> Result<GetRecordsOutput, PollerError> {
+        let error = match self
+            .client
+            .get_records()
+            .shard_iterator(iterator)
+            .send()
+            .await
+        {
+            Ok(output) => return Ok(output),
+            Err(e) => e,
+        };
+        match error.as_service_error() {
+            Some(
+                GetRecordsError::ExpiredIteratorException(_)
+                | GetRecordsError::TrimmedDataAccessException(_),
+            ) => {
+                tracing::warn!("shard iterator of {shard_id} is no longer valid: {error:?}");
+            }
+            _ => return Err(PollerError::Stream("get records", error.into())),
+        }
+        let iterator = self
+            .shard_iterator(stream_arn, shard_id, sequence_number)
+            .await?;
+        self.client
+            .get_records()
+            .shard_iterator(iterator)
+            .send()
+            .await
+            .map_err(|e| PollerError::Stream("get records", e.into()))
+    }
+
+    /// チェックポイントのレコードが保持期間を過ぎて削除されている場合は、シャードの最も古いレコードから読み取る
+    async fn shard_iterator(
+        &self,
+        stream_arn: &str,
+        shard_id: &str,
+        sequence_number: Option<&str>,
+    ) -> Result<String, PollerError> {
+        let request = self
+            .client
+            .get_shard_iterator()
+            .stream_arn(stream_arn)
+            .shard_id(shard_id);
+        let output = match sequence_number {
+            Some(sequence_number) => match request
+                .shard_iterator_type(ShardIteratorType::AfterSequenceNumber)
+                .sequence_number(sequence_number)
+                .send()
+                .await
+            {
+                Err(e)
+                    if matches!(
+                        e.as_service_error(),
+                        Some(GetShardIteratorError::TrimmedDataAccessException(_))
+                    ) =>
+                {
+                    // NOTE: 削除されたレコードは読み取れないので、取りこぼしたことを記録して続ける
+                    tracing::error!(
+                        "records after {sequence_number} of {shard_id} have been trimmed: {e:?}"
+                    );
+                    self.client
+                        .get_shard_iterator()
+                        .stream_arn(stream_arn)
+                        .shard_id(shard_id)
+                        .shard_iterator_type(ShardIteratorType::TrimHorizon)
+                        .send()
+                        .await
+                }
+                result => result,
+            },
+            None => {
+                request
+                    .shard_iterator_type(ShardIteratorType::TrimHorizon)
+                    .send()
+                    .await
+            }
+        };
+        output
+            .map_err(|e| PollerError::Stream("get shard iterator", e.into()))?
+            .shard_iterator
+            .ok_or_else(|| PollerError::StreamNotFound(format!("shard {shard_id}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use aws_sdk_dynamodbstreams::Client;
+    use aws_sdk_dynamodbstreams::operation::describe_stream::DescribeStreamOutput;
+    use aws_sdk_dynamodbstreams::operation::get_records::{GetRecordsError, GetRecordsOutput};
+    use aws_sdk_dynamodbstreams::operation::get_shard_iterator::{
+        GetShardIteratorError, GetShardIteratorOutput,
+    };
+    use aws_sdk_dynamodbstreams::types::error::{
+        ExpiredIteratorException, TrimmedDataAccessException,
+    };
+    use aws_sdk_dynamodbstreams::types::{
+        AttributeValue, Record, Shard, ShardIteratorType, StreamDescription, StreamRecord,
+    };
+    use aws_smithy_mocks::{Rule, RuleMode, mock, mock_client};
+    use event_store::EventStoreModel;
+    use serde::{Deserialize, Serialize};
+
+    use super::{Checkpoint, Poller, ShardCheckpoint};
+    use crate::{Handler, HandlerError, Router};
+
+    type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    const STREAM_ARN: &str =
+        "arn:aws:dynamodb:ap-northeast-1:000000000000:table/event-store/stream/0";
+
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+    enum EventPayload {
+        PlacedV1,
+    }
+
+    /// 処理したイベントID を記録するハンドラー
+    #[derive(Default)]
+    struct RecordingHandler {
+        handled: Mutex<Vec<u64>>,
+    }
+
+    impl Handler<EventPayload> for Arc<RecordingHandler> {
+        async fn handle(&self, model: &EventStoreModel<EventPayload>) -> Result<(), HandlerError> {
+            self.handled.lock().unwrap().push(model.id());
+            Ok(())
+        }
+    }
+
+    fn checkpoint_path(name: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("event-router-{}-{name}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    async fn save_checkpoint(path: &PathBuf, sequence_number: &str) -> Result<(), Error> {
+        let checkpoint = Checkpoint {
+            stream_arn: Some(STREAM_ARN.to_string()),
+            shards: [(
+                "shard-1".to_string(),
+                ShardCheckpoint {
+                    sequence_number: Some(sequence_number.to_string()),
+                    finished: false,
+                },
+            )]
+            .into(),
+        };
+        checkpoint.save(path).await?;
+        Ok(())
+    }
+
+    async fn poller(
+        client: Client,
+        handler: &Arc<RecordingHandler>,
+        checkpoint_path: PathBuf,
+    ) -> Result<Poller<EventPayload>, Error> {
+        let router = Router::new().route("placed", |_| true, Arc::clone(handler));
+        Ok(Poller {
+            router,
+            client,
+            table_name: "event-store".to_string(),
+            stream_arn: Some(STREAM_ARN.to_string()),
+            checkpoint: Checkpoint::load(&checkpoint_path).await?,
+            checkpoint_path,
+            interval: Duration::from_millis(1),
+            shard_iterators: HashMap::new(),
+        })
+    }
+
+    /// `shard-1` だけのストリームを `times` 回返す
+    fn describe_stream(times: usize) -> Rule {
+        mock!(Client::describe_stream)
+            .sequence()
+            .output(|| {
+                DescribeStreamOutput::builder()
+                    .stream_description(
+                        StreamDescription::builder()
+                            .shards(Shard::builder().shard_id("shard-1").build())
+                            .build(),
+                    )
+                    .build()
+            })
+            .times(times)
+            .build()
+    }
+
+    fn shard_iterator(sequence_number: &'static str, iterator: &'static str) -> Rule {
+        mock!(Client::get_shard_iterator)
+            .match_requests(move |req| {
+                req.shard_iterator_type() == Some(&ShardIteratorType::AfterSequenceNumber)
+                    && req.sequence_number() == Some(sequence_number)
+            })
+            .then_output(move || {
+                GetShardIteratorOutput::builder()
+                    .shard_iterator(iterator)
+                    .build()
+            })
+    }
+
+    /// `iterator` で読み取ると、`id` のイベントをシーケンス番号 `sequence_number` のレコードとして返す
+    fn records(iterator: &'static str, id: u64, sequence_number: &'static str) -> Rule {
+        mock!(Client::get_records)
+            .match_requests(move |req| req.shard_iterator() == Some(iterator))
+            .then_output(move || {
+                let new_image = HashMap::from([
+                    ("id".to_string(), AttributeValue::N(id.to_string())),
+                    (
+                        "aggregate_id".to_string(),
+                        AttributeValue::S("aggregate".to_string()),
+                    ),
+                    (
+                        "payload".to_string(),
+                        AttributeValue::S("PlacedV1".to_string()),
+                    ),
+                ]);
+                GetRecordsOutput::builder()
+                    .records(
+                        Record::builder()
+                            .dynamodb(
+                                StreamRecord::builder()
+                                    .sequence_number(sequence_number)
+                                    .set_new_image(Some(new_image))
+                                    .build(),
+                            )
+                            .build(),
+                    )
+                    .next_shard_iterator(format!("{iterator}-next"))
+                    .build()
+            })
+    }
+
+    async fn sequence_number(path: &PathBuf) -> Result<Option<String>, Error> {
+        let checkpoint = Checkpoint::load(path).await?;
+        Ok(checkpoint
+            .shards
+            .get("shard-1")
+            .and_then(|shard| shard.sequence_number.clone()))
+    }
+
+    #[tokio::test]
+    async fn test_poll_resume_from_checkpoint() -> Result<(), Error> {
+        let path = checkpoint_path("resume");
+        save_checkpoint(&path, "100").await?;
+        let describe_stream = describe_stream(2);
+        let after_100 = shard_iterator("100", "iterator-100");
+        let after_200 = shard_iterator("200", "iterator-200");
+        let records_200 = records("iterator-100", 1, "200");
+        let records_300 = records("iterator-200", 2, "300");
+        let client = mock_client!(
+            aws_sdk_dynamodbstreams,
+            RuleMode::MatchAny,
+            [
+                &describe_stream,
+                &after_100,
+                &after_200,
+                &records_200,
+                &records_300
+            ]
+        );
+        let handler = Arc::new(RecordingHandler::default());
+
+        let mut first = poller(client.clone(), &handler, path.clone()).await?;
+        assert_eq!(first.poll().await?, 1);
+        assert_eq!(sequence_number(&path).await?.as_deref(), Some("200"));
+
+        // NOTE: 再起動したポーラーはチェックポイントに記録したシーケンス番号の次から読み取る
+        let mut second = poller(client, &handler, path.clone()).await?;
+        assert_eq!(second.poll().await?, 1);
+        assert_eq!(sequence_number(&path).await?.as_deref(), Some("300"));
+        assert_eq!(*handler.handled.lock().unwrap(), vec![1, 2]);
+        assert_eq!(after_100.num_calls(), 1);
+        assert_eq!(after_200.num_calls(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_poll_expired_iterator() -> Result<(), Error> {
+        let path = checkpoint_path("expired");
+        save_checkpoint(&path, "100").await?;
+        let describe_stream = describe_stream(1);
+        let expired = mock!(Client::get_records)
+            .match_requests(|req| req.shard_iterator() == Some("expired"))
+            .then_error(|| {
+                GetRecordsError::ExpiredIteratorException(
+                    ExpiredIteratorException::builder().build(),
+                )
+            });
+        let after_100 = shard_iterator("100", "iterator-100");
+        let records_200 = records("iterator-100", 1, "200");
+        let client = mock_client!(
+            aws_sdk_dynamodbstreams,
+            RuleMode::MatchAny,
+            [&describe_stream, &expired, &after_100, &records_200]
+        );
+        let handler = Arc::new(RecordingHandler::default());
+
+        let mut poller = poller(client, &handler, path.clone()).await?;
+        poller
+            .shard_iterators
+            .insert("shard-1".to_string(), "expired".to_string());
+        assert_eq!(poller.poll().await?, 1);
+        assert_eq!(expired.num_calls(), 1);
+        assert_eq!(after_100.num_calls(), 1);
+        assert_eq!(sequence_number(&path).await?.as_deref(), Some("200"));
+        assert_eq!(
+            poller.shard_iterators.get("shard-1").map(String::as_str),
+            Some("iterator-100-next")
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_poll_trimmed_checkpoint() -> Result<(), Error> {
+        let path = checkpoint_path("trimmed");
+        save_checkpoint(&path, "100").await?;
+        let describe_stream = describe_stream(1);
+        let trimmed = mock!(Client::get_shard_iterator)
+            .match_requests(|req| {
+                req.shard_iterator_type() == Some(&ShardIteratorType::AfterSequenceNumber)
+            })
+            .then_error(|| {
+                GetShardIteratorError::TrimmedDataAccessException(
+                    TrimmedDataAccessException::builder().build(),
+                )
+            });
+        let trim_horizon = mock!(Client::get_shard_iterator)
+            .match_requests(|req| {
+                req.shard_iterator_type() == Some(&ShardIteratorType::TrimHorizon)
+            })
+            .then_output(|| {
+                GetShardIteratorOutput::builder()
+                    .shard_iterator("trim-horizon")
+                    .build()
+            });
+        let records_500 = records("trim-horizon", 5, "500");
+        let client = mock_client!(
+            aws_sdk_dynamodbstreams,
+            RuleMode::MatchAny,
+            [&describe_stream, &trimmed, &trim_horizon, &records_500]
+        );
+        let handler = Arc::new(RecordingHandler::default());
+
+        // NOTE: チェックポイントのレコードが削除されている場合は、残っている最も古いレコードから読み取る
+        let mut poller = poller(client, &handler, path.clone()).await?;
+        assert_eq!(poller.poll().await?, 1);
+        assert_eq!(trimmed.num_calls(), 1);
+        assert_eq!(trim_horizon.num_calls(), 1);
+        assert_eq!(*handler.handled.lock().unwrap(), vec![5]);
+        assert_eq!(sequence_number(&path).await?.as_deref(), Some("500"));
+        Ok(())
+    }
+}
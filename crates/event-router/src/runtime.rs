@@ -0,0 +1,152 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::poller::Poller;
+use crate::{DeadLetterStore, ErrorPolicy, Router};
+
+/// イベントルーターを実行する設定
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub service_name: &'static str,
+    pub service_version: &'static str,
+    /// ストリームを読み取るイベントストアのテーブル名
+    pub event_store_table_name: &'static str,
+    /// デッドレターを保存するテーブル名
+    pub dead_letter_table_name: &'static str,
+}
+
+/// `LocalStack` に接続する AWS の設定を読み込む
+pub(crate) async fn aws_config() -> aws_config::SdkConfig {
+    aws_config::defaults(aws_config::BehaviorVersion::latest())
+        .endpoint_url(format!(
+            "http://{}:{}",
+            std::env::var("LOCALSTACK_GATEWAY_HOST").unwrap_or("localhost".to_string()),
+            std::env::var("LOCALSTACK_GATEWAY_PORT").unwrap_or("4566".to_string()),
+        ))
+        .region(
+            aws_config::meta::region::RegionProviderChain::default_provider()
+                .or_else("ap-northeast-1"),
+        )
+        .test_credentials()
+        .load()
+        .await
+}
+
+/// 環境変数からハンドラーが失敗した時の方針をルーターに設定する
+///
+/// - `EVENT_ROUTER_ERROR_POLICY`: 最大試行回数までハンドラーが失敗した時の方針 (`dead-letter`・`fail`・`skip` のいずれか)。デフォルトは `dead-letter`
+/// - `EVENT_ROUTER_MAX_ATTEMPTS`: ハンドラーを実行する最大試行回数。デフォルトは 3
+async fn configure<P>(router: Router<P>, config: &Config) -> Result<Router<P>, crate::Error> {
+    let max_attempts = match std::env::var("EVENT_ROUTER_MAX_ATTEMPTS") {
+        Ok(max_attempts) => max_attempts
+            .parse()
+            .map_err(|_| "EVENT_ROUTER_MAX_ATTEMPTS must be a number")?,
+        Err(_) => 3,
+    };
+    let error_policy = match std::env::var("EVENT_ROUTER_ERROR_POLICY") {
+        Ok(policy) if policy == "fail" => ErrorPolicy::Fail,
+        Ok(policy) if policy == "skip" => ErrorPolicy::Skip,
+        Ok(policy) if policy != "dead-letter" => {
+            return Err(format!("unsupported error policy: {policy}").into());
+        }
+        _ => {
+            ErrorPolicy::DeadLetter(DeadLetterStore::from_env(config.dead_letter_table_name).await?)
+        }
+    };
+    Ok(router
+        .with_retry(max_attempts, Duration::from_millis(100))
+        .with_error_policy(error_policy))
+}
+
+/// イベントルーターを実行する
+///
+/// 環境変数 `EVENT_ROUTER_MODE` が `poller` の場合は `DynamoDB Streams` を直接読み取る通常のプロセスとして、
+/// それ以外の場合は Lambda 関数として実行する。
+///
+/// コマンドライン引数に `dead-letter` を指定した場合はデッドレターを操作するコマンドを実行する
+///
+/// - `dead-letter list`: 保存されている全てのデッドレターを JSON Lines 形式で出力する
+/// - `dead-letter redrive [<id>...]`: 指定したデッドレターをもう一度ルーティングする。指定しない場合は全てのデッドレターをルーティングする
+///
+/// # Errors
+pub async fn run<P>(router: Router<P>, config: Config) -> Result<(), crate::Error>
+where
+    P: Debug + Clone + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    let router = configure(router, &config).await?;
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().is_some_and(|command| command == "dead-letter") {
+        return dead_letter_command(&router, &args[1..]).await;
+    }
+
+    // NOTE: ポーラーとして実行する場合は Lambda ランタイムを使わずに DynamoDB Streams を直接読み取る
+    if std::env::var("EVENT_ROUTER_MODE").is_ok_and(|mode| mode == "poller") {
+        let shutdown_providers =
+            observability::provider::init_providers(config.service_name, config.service_version)?;
+        Poller::from_env(router, config.event_store_table_name)
+            .await?
+            .run(observability::server::shutdown())
+            .await;
+        shutdown_providers()?;
+        return Ok(());
+    }
+
+    let force_flush = observability::provider::init_providers_with_flush(
+        config.service_name,
+        config.service_version,
+    )?;
+    let router = Arc::new(router);
+    observability::aws_lambda::run(
+        lambda_runtime::service_fn(move |event| {
+            let router = Arc::clone(&router);
+            async move { crate::lambda::handle(&router, event).await }
+        }),
+        force_flush,
+        config.service_version.to_string(),
+    )
+    .await
+}
+
+async fn dead_letter_command<P>(router: &Router<P>, args: &[String]) -> Result<(), crate::Error>
+where
+    P: Debug + Clone + Serialize + DeserializeOwned + Send + Sync,
+{
+    let store = router
+        .dead_letter_store()
+        .ok_or("EVENT_ROUTER_ERROR_POLICY must be dead-letter")?;
+    match args.first().map(String::as_str) {
+        Some("list") => {
+            for dead_letter in store.list::<P>().await? {
+                println!("{}", serde_json::to_string(&dead_letter)?);
+            }
+            Ok(())
+        }
+        Some("redrive") => {
+            let ids = &args[1..];
+            let mut failures = 0;
+            for dead_letter in store.list::<P>().await? {
+                if !ids.is_empty() && !ids.iter().any(|id| id == dead_letter.id()) {
+                    continue;
+                }
+                let id = dead_letter.id().to_string();
+                match router.redrive(dead_letter).await {
+                    Ok(()) => println!("redriven: {id}"),
+                    Err(e) => {
+                        eprintln!("failed to redrive {id}: {e:?}");
+                        failures += 1;
+                    }
+                }
+            }
+            if failures > 0 {
+                return Err(format!("failed to redrive {failures} dead letters").into());
+            }
+            Ok(())
+        }
+        _ => Err("usage: event-router dead-letter <list|redrive [<id>...]>".into()),
+    }
+}
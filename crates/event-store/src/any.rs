@@ -0,0 +1,341 @@
+use std::fmt::Debug;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+#[cfg(feature = "dynamodb")]
+use crate::dynamodb::DynamoDbEventStore;
+use crate::in_memory::InMemoryEventStore;
+#[cfg(feature = "postgres")]
+use crate::postgres::PostgresEventStore;
+#[cfg(feature = "sqlite")]
+use crate::sqlite::SqliteEventStore;
+use crate::{
+    AggregateModel, AggregateWithEvents, EventLog, EventSequenceModel, EventStore, EventStoreError,
+    EventStoreModel, IdempotencyKeyModel, Outbox, OutboxModel, Page, PageToken, SequencePosition,
+    SnapshotPolicy,
+};
+
+/// 起動時に選択したバックエンドに処理を委譲するイベントストア
+#[derive(Debug, Clone)]
+pub enum AnyEventStore<A, E> {
+    #[cfg(feature = "dynamodb")]
+    DynamoDb(DynamoDbEventStore<A, E>),
+    InMemory(InMemoryEventStore<A, E>),
+    #[cfg(feature = "postgres")]
+    Postgres(PostgresEventStore<A, E>),
+    #[cfg(feature = "sqlite")]
+    Sqlite(SqliteEventStore<A, E>),
+}
+
+impl<A, E> AnyEventStore<A, E> {
+    /// スナップショットを保存する方針を設定する
+    #[must_use]
+    pub fn with_snapshot_policy(self, snapshot_policy: SnapshotPolicy) -> Self {
+        match self {
+            #[cfg(feature = "dynamodb")]
+            Self::DynamoDb(event_store) => {
+                Self::DynamoDb(event_store.with_snapshot_policy(snapshot_policy))
+            }
+            Self::InMemory(event_store) => {
+                Self::InMemory(event_store.with_snapshot_policy(snapshot_policy))
+            }
+            #[cfg(feature = "postgres")]
+            Self::Postgres(event_store) => {
+                Self::Postgres(event_store.with_snapshot_policy(snapshot_policy))
+            }
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(event_store) => {
+                Self::Sqlite(event_store.with_snapshot_policy(snapshot_policy))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "dynamodb")]
+impl<A, E> From<DynamoDbEventStore<A, E>> for AnyEventStore<A, E> {
+    fn from(value: DynamoDbEventStore<A, E>) -> Self {
+        Self::DynamoDb(value)
+    }
+}
+
+impl<A, E> From<InMemoryEventStore<A, E>> for AnyEventStore<A, E> {
+    fn from(value: InMemoryEventStore<A, E>) -> Self {
+        Self::InMemory(value)
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl<A, E> From<PostgresEventStore<A, E>> for AnyEventStore<A, E> {
+    fn from(value: PostgresEventStore<A, E>) -> Self {
+        Self::Postgres(value)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<A, E> From<SqliteEventStore<A, E>> for AnyEventStore<A, E> {
+    fn from(value: SqliteEventStore<A, E>) -> Self {
+        Self::Sqlite(value)
+    }
+}
+
+impl<A, E> EventStore<A, E> for AnyEventStore<A, E>
+where
+    A: Serialize + DeserializeOwned + Clone + Debug + Send + Sync + Unpin + 'static,
+    E: Serialize + DeserializeOwned + Clone + Debug + Send + Sync + Unpin + 'static,
+{
+    async fn create(
+        &self,
+        aggregate: AggregateModel<A>,
+        event: E,
+        idempotency_keys: Vec<IdempotencyKeyModel>,
+    ) -> Result<(), EventStoreError> {
+        match self {
+            #[cfg(feature = "dynamodb")]
+            Self::DynamoDb(event_store) => {
+                event_store.create(aggregate, event, idempotency_keys).await
+            }
+            Self::InMemory(event_store) => {
+                event_store.create(aggregate, event, idempotency_keys).await
+            }
+            #[cfg(feature = "postgres")]
+            Self::Postgres(event_store) => {
+                event_store.create(aggregate, event, idempotency_keys).await
+            }
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(event_store) => {
+                event_store.create(aggregate, event, idempotency_keys).await
+            }
+        }
+    }
+
+    async fn get(
+        &self,
+        aggregate_id: &str,
+    ) -> Result<Option<AggregateWithEvents<A, E>>, EventStoreError> {
+        match self {
+            #[cfg(feature = "dynamodb")]
+            Self::DynamoDb(event_store) => event_store.get(aggregate_id).await,
+            Self::InMemory(event_store) => event_store.get(aggregate_id).await,
+            #[cfg(feature = "postgres")]
+            Self::Postgres(event_store) => event_store.get(aggregate_id).await,
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(event_store) => event_store.get(aggregate_id).await,
+        }
+    }
+
+    async fn update(
+        &self,
+        aggregate: AggregateModel<A>,
+        events: Vec<E>,
+        idempotency_key: Option<IdempotencyKeyModel>,
+        outbox: Vec<OutboxModel<E>>,
+    ) -> Result<(), EventStoreError> {
+        match self {
+            #[cfg(feature = "dynamodb")]
+            Self::DynamoDb(event_store) => {
+                event_store
+                    .update(aggregate, events, idempotency_key, outbox)
+                    .await
+            }
+            Self::InMemory(event_store) => {
+                event_store
+                    .update(aggregate, events, idempotency_key, outbox)
+                    .await
+            }
+            #[cfg(feature = "postgres")]
+            Self::Postgres(event_store) => {
+                event_store
+                    .update(aggregate, events, idempotency_key, outbox)
+                    .await
+            }
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(event_store) => {
+                event_store
+                    .update(aggregate, events, idempotency_key, outbox)
+                    .await
+            }
+        }
+    }
+
+    async fn save_snapshot(&self, aggregate: AggregateModel<A>) -> Result<(), EventStoreError> {
+        match self {
+            #[cfg(feature = "dynamodb")]
+            Self::DynamoDb(event_store) => event_store.save_snapshot(aggregate).await,
+            Self::InMemory(event_store) => event_store.save_snapshot(aggregate).await,
+            #[cfg(feature = "postgres")]
+            Self::Postgres(event_store) => event_store.save_snapshot(aggregate).await,
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(event_store) => event_store.save_snapshot(aggregate).await,
+        }
+    }
+
+    async fn get_aggregate(
+        &self,
+        aggregate_id: &str,
+    ) -> Result<Option<AggregateModel<A>>, EventStoreError> {
+        match self {
+            #[cfg(feature = "dynamodb")]
+            Self::DynamoDb(event_store) => event_store.get_aggregate(aggregate_id).await,
+            Self::InMemory(event_store) => event_store.get_aggregate(aggregate_id).await,
+            #[cfg(feature = "postgres")]
+            Self::Postgres(event_store) => event_store.get_aggregate(aggregate_id).await,
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(event_store) => event_store.get_aggregate(aggregate_id).await,
+        }
+    }
+
+    async fn get_idempotency_key(
+        &self,
+        key: &str,
+    ) -> Result<Option<IdempotencyKeyModel>, EventStoreError> {
+        match self {
+            #[cfg(feature = "dynamodb")]
+            Self::DynamoDb(event_store) => event_store.get_idempotency_key(key).await,
+            Self::InMemory(event_store) => event_store.get_idempotency_key(key).await,
+            #[cfg(feature = "postgres")]
+            Self::Postgres(event_store) => event_store.get_idempotency_key(key).await,
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(event_store) => event_store.get_idempotency_key(key).await,
+        }
+    }
+
+    async fn list_aggregates(&self) -> Result<Vec<AggregateModel<A>>, EventStoreError> {
+        match self {
+            #[cfg(feature = "dynamodb")]
+            Self::DynamoDb(event_store) => event_store.list_aggregates().await,
+            Self::InMemory(event_store) => event_store.list_aggregates().await,
+            #[cfg(feature = "postgres")]
+            Self::Postgres(event_store) => event_store.list_aggregates().await,
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(event_store) => event_store.list_aggregates().await,
+        }
+    }
+
+    async fn list_aggregates_page(
+        &self,
+        page_size: usize,
+        page_token: Option<&PageToken>,
+    ) -> Result<Page<AggregateModel<A>>, EventStoreError> {
+        match self {
+            #[cfg(feature = "dynamodb")]
+            Self::DynamoDb(event_store) => {
+                event_store
+                    .list_aggregates_page(page_size, page_token)
+                    .await
+            }
+            Self::InMemory(event_store) => {
+                event_store
+                    .list_aggregates_page(page_size, page_token)
+                    .await
+            }
+            #[cfg(feature = "postgres")]
+            Self::Postgres(event_store) => {
+                event_store
+                    .list_aggregates_page(page_size, page_token)
+                    .await
+            }
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(event_store) => {
+                event_store
+                    .list_aggregates_page(page_size, page_token)
+                    .await
+            }
+        }
+    }
+}
+
+impl<A, E> Outbox<E> for AnyEventStore<A, E>
+where
+    A: Serialize + DeserializeOwned + Clone + Debug + Send + Sync + Unpin + 'static,
+    E: Serialize + DeserializeOwned + Clone + Debug + Send + Sync + Unpin + 'static,
+{
+    async fn list_outbox(&self, limit: usize) -> Result<Vec<OutboxModel<E>>, EventStoreError> {
+        match self {
+            #[cfg(feature = "dynamodb")]
+            Self::DynamoDb(event_store) => event_store.list_outbox(limit).await,
+            Self::InMemory(event_store) => event_store.list_outbox(limit).await,
+            #[cfg(feature = "postgres")]
+            Self::Postgres(event_store) => event_store.list_outbox(limit).await,
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(event_store) => event_store.list_outbox(limit).await,
+        }
+    }
+
+    async fn delete_outbox(
+        &self,
+        aggregate_id: &str,
+        event_id: u64,
+    ) -> Result<(), EventStoreError> {
+        match self {
+            #[cfg(feature = "dynamodb")]
+            Self::DynamoDb(event_store) => event_store.delete_outbox(aggregate_id, event_id).await,
+            Self::InMemory(event_store) => event_store.delete_outbox(aggregate_id, event_id).await,
+            #[cfg(feature = "postgres")]
+            Self::Postgres(event_store) => event_store.delete_outbox(aggregate_id, event_id).await,
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(event_store) => event_store.delete_outbox(aggregate_id, event_id).await,
+        }
+    }
+}
+
+impl<A, E> EventLog<E> for AnyEventStore<A, E>
+where
+    A: Serialize + DeserializeOwned + Clone + Debug + Send + Sync + Unpin + 'static,
+    E: Serialize + DeserializeOwned + Clone + Debug + Send + Sync + Unpin + 'static,
+{
+    async fn list_sequences(
+        &self,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<EventSequenceModel>, EventStoreError> {
+        match self {
+            #[cfg(feature = "dynamodb")]
+            Self::DynamoDb(event_store) => event_store.list_sequences(after, limit).await,
+            Self::InMemory(event_store) => event_store.list_sequences(after, limit).await,
+            #[cfg(feature = "postgres")]
+            Self::Postgres(event_store) => event_store.list_sequences(after, limit).await,
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(event_store) => event_store.list_sequences(after, limit).await,
+        }
+    }
+
+    async fn list_sequences_since(
+        &self,
+        after: &SequencePosition,
+        limit: usize,
+    ) -> Result<Vec<EventSequenceModel>, EventStoreError> {
+        match self {
+            #[cfg(feature = "dynamodb")]
+            Self::DynamoDb(event_store) => event_store.list_sequences_since(after, limit).await,
+            Self::InMemory(event_store) => event_store.list_sequences_since(after, limit).await,
+            #[cfg(feature = "postgres")]
+            Self::Postgres(event_store) => event_store.list_sequences_since(after, limit).await,
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(event_store) => event_store.list_sequences_since(after, limit).await,
+        }
+    }
+
+    async fn list_events(
+        &self,
+        aggregate_id: &str,
+        after: Option<u64>,
+        limit: usize,
+    ) -> Result<Vec<EventStoreModel<E>>, EventStoreError> {
+        match self {
+            #[cfg(feature = "dynamodb")]
+            Self::DynamoDb(event_store) => {
+                event_store.list_events(aggregate_id, after, limit).await
+            }
+            Self::InMemory(event_store) => {
+                event_store.list_events(aggregate_id, after, limit).await
+            }
+            #[cfg(feature = "postgres")]
+            Self::Postgres(event_store) => {
+                event_store.list_events(aggregate_id, after, limit).await
+            }
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(event_store) => event_store.list_events(aggregate_id, after, limit).await,
+        }
+    }
+}
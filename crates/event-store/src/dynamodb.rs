@@ -0,0 +1,895 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use aws_sdk_dynamodb::error::SdkError;
+use aws_sdk_dynamodb::operation::transact_write_items::TransactWriteItemsError;
+use aws_sdk_dynamodb::types::{
+    AttributeValue, Put, ReturnValuesOnConditionCheckFailure, TransactWriteItem, Update,
+};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use tracing::instrument;
+
+use crate::model::now_millis;
+use crate::{
+    AggregateModel, AggregateWithEvents, EventLog, EventSequenceModel, EventStore, EventStoreError,
+    EventStoreModel, IdempotencyKeyModel, Outbox, OutboxModel, Page, PageToken, SequencePosition,
+    SnapshotPolicy, TableNames, VersionedPayload,
+};
+
+/// イベントシーケンスを更新した順序で読み取るための索引
+const UPDATED_AT_INDEX: &str = "updated-at-index";
+/// 索引のパーティションキーの値
+///
+/// 全ての集約を更新した順序で読み取るために、全てのイベントシーケンスを同じパーティションに入れる
+const UPDATED_AT_PARTITION: &str = "all";
+
+/// `DynamoDB` をバックエンドにしたイベントストア
+///
+/// `A` は集約テーブルのペイロード、`E` はイベントストアのペイロード
+#[derive(Debug, Clone)]
+pub struct DynamoDbEventStore<A, E> {
+    dynamodb: aws_sdk_dynamodb::Client,
+    tables: TableNames,
+    snapshot_policy: SnapshotPolicy,
+    payload: PhantomData<fn() -> (A, E)>,
+}
+
+impl<A, E> DynamoDbEventStore<A, E> {
+    /// Creates a new [`DynamoDbEventStore`].
+    #[must_use]
+    pub fn new(dynamodb: aws_sdk_dynamodb::Client, tables: TableNames) -> Self {
+        Self {
+            dynamodb,
+            tables,
+            snapshot_policy: SnapshotPolicy::default(),
+            payload: PhantomData,
+        }
+    }
+
+    /// スナップショットを保存する方針を設定する
+    #[must_use]
+    pub fn with_snapshot_policy(self, snapshot_policy: SnapshotPolicy) -> Self {
+        Self {
+            snapshot_policy,
+            ..self
+        }
+    }
+}
+
+impl<A, E> DynamoDbEventStore<A, E>
+where
+    A: Serialize + DeserializeOwned + Debug + Send + Sync,
+    E: Serialize + DeserializeOwned + Debug + Send + Sync,
+{
+    /// 集約のスナップショットを取得する
+    #[instrument(skip(self), err, ret)]
+    async fn get_aggregate_model(
+        &self,
+        aggregate_id: &str,
+    ) -> Result<Option<AggregateModel<A>>, EventStoreError> {
+        use aws_sdk_dynamodb::operation::get_item::GetItemError::ResourceNotFoundException;
+
+        let output = match self
+            .dynamodb
+            .get_item()
+            .table_name(self.tables.aggregate)
+            .key("id", AttributeValue::S(aggregate_id.to_string()))
+            .consistent_read(true)
+            .send()
+            .await
+        {
+            Ok(output) => output,
+            Err(e) => match e.into_service_error() {
+                ResourceNotFoundException(_) => return Ok(None),
+                e => return Err(EventStoreError::Unknown(e.into())),
+            },
+        };
+        let Some(item) = output.item else {
+            return Ok(None);
+        };
+        Ok(Some(
+            serde_dynamo::from_item(item).map_err(|e| EventStoreError::Unknown(e.into()))?,
+        ))
+    }
+
+    /// 集約に紐づくイベントのうち、指定したイベントID以降のイベントをイベントIDの昇順で最大 `limit` 件取得する
+    #[instrument(skip(self), err, ret)]
+    async fn list_event_store_models(
+        &self,
+        aggregate_id: &str,
+        from_event_id: u64,
+        limit: usize,
+    ) -> Result<Vec<EventStoreModel<E>>, EventStoreError> {
+        use aws_sdk_dynamodb::operation::query::QueryError::ResourceNotFoundException;
+
+        let mut models = Vec::new();
+        let mut exclusive_start_key = None;
+        while models.len() < limit {
+            let output = match self
+                .dynamodb
+                .query()
+                .table_name(self.tables.event_store)
+                .key_condition_expression("aggregate_id = :aggregate_id AND id >= :from_event_id")
+                .expression_attribute_values(
+                    ":aggregate_id",
+                    AttributeValue::S(aggregate_id.to_string()),
+                )
+                .expression_attribute_values(
+                    ":from_event_id",
+                    AttributeValue::N(from_event_id.to_string()),
+                )
+                .limit(i32::try_from(limit - models.len()).unwrap_or(i32::MAX))
+                .consistent_read(true)
+                .set_exclusive_start_key(exclusive_start_key)
+                .send()
+                .await
+            {
+                Ok(output) => output,
+                Err(e) => match e.into_service_error() {
+                    ResourceNotFoundException(_) => return Ok(Vec::new()),
+                    e => return Err(EventStoreError::Unknown(e.into())),
+                },
+            };
+            let items: Vec<EventStoreModel<E>> = serde_dynamo::from_items(output.items().to_vec())
+                .map_err(|e| EventStoreError::Unknown(e.into()))?;
+            models.extend(items);
+            exclusive_start_key = output.last_evaluated_key;
+            if exclusive_start_key.is_none() {
+                break;
+            }
+        }
+        Ok(models)
+    }
+
+    /// イベントシーケンスを `current` から `new` に更新する
+    ///
+    /// イベントシーケンスの最新イベントIDで楽観的排他制御を行う。
+    /// 条件を満たさなかった場合に既存のレコードを返して、集約が存在しない場合と更新が競合した場合を区別する。
+    /// 最新イベントIDを `version` 属性に保存していた古いレコードも、更新時に `latest_event_id` 属性に書き換える
+    fn update_sequence(
+        &self,
+        current: &EventSequenceModel,
+        new: &EventSequenceModel,
+    ) -> Result<TransactWriteItem, EventStoreError> {
+        let [(partition_name, partition), (key_name, key)] = position_attributes(&new.position());
+        Ok(TransactWriteItem::builder()
+            .update(
+                Update::builder()
+                    .table_name(self.tables.event_sequence)
+                    .key(
+                        "aggregate_id",
+                        AttributeValue::S(current.aggregate_id().to_string()),
+                    )
+                    .expression_attribute_names("#version", "version")
+                    .expression_attribute_names("#partition", partition_name)
+                    .expression_attribute_names("#key", key_name)
+                    .expression_attribute_values(
+                        ":current_latest_event_id",
+                        AttributeValue::N(current.latest_event_id().to_string()),
+                    )
+                    .expression_attribute_values(
+                        ":new_latest_event_id",
+                        AttributeValue::N(new.latest_event_id().to_string()),
+                    )
+                    .expression_attribute_values(
+                        ":updated_at",
+                        AttributeValue::N(new.updated_at().to_string()),
+                    )
+                    .expression_attribute_values(":partition", partition)
+                    .expression_attribute_values(":key", key)
+                    .update_expression(
+                        "SET latest_event_id = :new_latest_event_id, updated_at = :updated_at, #partition = :partition, #key = :key REMOVE #version",
+                    )
+                    .return_values_on_condition_check_failure(
+                        ReturnValuesOnConditionCheckFailure::AllOld,
+                    )
+                    .condition_expression(
+                        "attribute_exists(aggregate_id) AND (latest_event_id = :current_latest_event_id OR #version = :current_latest_event_id)",
+                    )
+                    .build()
+                    .map_err(|e| EventStoreError::Unknown(e.into()))?,
+            )
+            .build())
+    }
+
+    /// イベントをイベントストアに追加する
+    fn put_event(
+        &self,
+        mut event: EventStoreModel<E>,
+    ) -> Result<TransactWriteItem, EventStoreError> {
+        observability::aws_dynamodb::inject(event.metadata_mut());
+        Ok(TransactWriteItem::builder()
+            .put(
+                Put::builder()
+                    .table_name(self.tables.event_store)
+                    .set_item(Some(event.try_into()?))
+                    .condition_expression(
+                        "attribute_not_exists(id) AND attribute_not_exists(aggregate_id)",
+                    )
+                    .build()
+                    .map_err(|e| EventStoreError::Unknown(e.into()))?,
+            )
+            .build())
+    }
+
+    /// 冪等キーを保存する
+    ///
+    /// 条件を満たさなかった場合に既存のレコードを返して、同じ冪等キーのコマンドと競合したとみなす
+    fn put_idempotency_key(
+        &self,
+        idempotency_key: IdempotencyKeyModel,
+    ) -> Result<TransactWriteItem, EventStoreError> {
+        Ok(TransactWriteItem::builder()
+            .put(
+                Put::builder()
+                    .table_name(self.tables.idempotency_key)
+                    .set_item(Some(idempotency_key.try_into()?))
+                    .expression_attribute_names("#key", "key")
+                    .condition_expression("attribute_not_exists(#key)")
+                    .return_values_on_condition_check_failure(
+                        ReturnValuesOnConditionCheckFailure::AllOld,
+                    )
+                    .build()
+                    .map_err(|e| EventStoreError::Unknown(e.into()))?,
+            )
+            .build())
+    }
+
+    /// 統合イベントをアウトボックスに追加する
+    fn put_outbox(&self, mut outbox: OutboxModel<E>) -> Result<TransactWriteItem, EventStoreError> {
+        observability::aws_dynamodb::inject(outbox.metadata_mut());
+        Ok(TransactWriteItem::builder()
+            .put(
+                Put::builder()
+                    .table_name(self.tables.outbox)
+                    .set_item(Some(outbox.try_into()?))
+                    .condition_expression(
+                        "attribute_not_exists(event_id) AND attribute_not_exists(aggregate_id)",
+                    )
+                    .build()
+                    .map_err(|e| EventStoreError::Unknown(e.into()))?,
+            )
+            .build())
+    }
+
+    async fn transact_write_items(
+        &self,
+        transact_items: Vec<TransactWriteItem>,
+    ) -> Result<(), EventStoreError> {
+        self.dynamodb
+            .transact_write_items()
+            .set_transact_items(Some(transact_items))
+            .send()
+            .await
+            .map_err(transaction_error)?;
+        Ok(())
+    }
+}
+
+impl<A, E> DynamoDbEventStore<A, E>
+where
+    A: VersionedPayload + Serialize + DeserializeOwned + Debug + Send + Sync,
+    E: Send + Sync,
+{
+    /// 集約テーブルの全てのレコードのペイロードを最新のバージョンに書き換えて、書き換えたレコードの数を返す
+    ///
+    /// 書き換える前に集約が更新された場合は、更新時に最新のバージョンで書き込まれるので書き換えない
+    ///
+    /// # Errors
+    #[instrument(skip(self), err, ret)]
+    pub async fn migrate_aggregates(&self) -> Result<usize, EventStoreError> {
+        use aws_sdk_dynamodb::operation::put_item::PutItemError::ConditionalCheckFailedException;
+
+        let mut count = 0;
+        let mut exclusive_start_key = None;
+        loop {
+            let output = self
+                .dynamodb
+                .scan()
+                .table_name(self.tables.aggregate)
+                .set_exclusive_start_key(exclusive_start_key)
+                .send()
+                .await
+                .map_err(|e| EventStoreError::Unknown(e.into()))?;
+            let models: Vec<AggregateModel<A>> = serde_dynamo::from_items(output.items().to_vec())
+                .map_err(|e| EventStoreError::Unknown(e.into()))?;
+            for model in models.into_iter().filter(|model| !model.is_latest()) {
+                let model = model.into_latest();
+                let result = self
+                    .dynamodb
+                    .put_item()
+                    .table_name(self.tables.aggregate)
+                    .expression_attribute_values(":version", version_attribute_value(&model))
+                    .set_item(Some(model.try_into()?))
+                    .condition_expression("attribute_exists(id) AND version = :version")
+                    .send()
+                    .await;
+                match result {
+                    Ok(_) => count += 1,
+                    Err(e) => match e.into_service_error() {
+                        ConditionalCheckFailedException(_) => {}
+                        e => return Err(EventStoreError::Unknown(e.into())),
+                    },
+                }
+            }
+            exclusive_start_key = output.last_evaluated_key;
+            if exclusive_start_key.is_none() {
+                break;
+            }
+        }
+        Ok(count)
+    }
+}
+
+impl<A, E> EventStore<A, E> for DynamoDbEventStore<A, E>
+where
+    A: Serialize + DeserializeOwned + Debug + Send + Sync,
+    E: Serialize + DeserializeOwned + Debug + Send + Sync,
+{
+    #[instrument(skip(self), err, ret)]
+    async fn create(
+        &self,
+        aggregate: AggregateModel<A>,
+        event: E,
+        idempotency_keys: Vec<IdempotencyKeyModel>,
+    ) -> Result<(), EventStoreError> {
+        let sequence =
+            EventSequenceModel::new(aggregate.id().to_string(), 0).with_updated_at(now_millis());
+        let mut item: HashMap<String, AttributeValue> = sequence.clone().try_into()?;
+        item.extend(position_attributes(&sequence.position()));
+        let mut transact_items = vec![
+            self.put_event(EventStoreModel::new(
+                sequence.latest_event_id(),
+                aggregate.id().to_string(),
+                event,
+            ))?,
+            TransactWriteItem::builder()
+                .put(
+                    Put::builder()
+                        .table_name(self.tables.event_sequence)
+                        .set_item(Some(item))
+                        .condition_expression("attribute_not_exists(aggregate_id)")
+                        .build()
+                        .map_err(|e| EventStoreError::Unknown(e.into()))?,
+                )
+                .build(),
+            TransactWriteItem::builder()
+                .put(
+                    Put::builder()
+                        .table_name(self.tables.aggregate)
+                        .set_item(Some(aggregate.try_into()?))
+                        .condition_expression("attribute_not_exists(id)")
+                        .build()
+                        .map_err(|e| EventStoreError::Unknown(e.into()))?,
+                )
+                .build(),
+        ];
+        for idempotency_key in idempotency_keys {
+            transact_items.push(self.put_idempotency_key(idempotency_key)?);
+        }
+        self.transact_write_items(transact_items).await
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn get(
+        &self,
+        aggregate_id: &str,
+    ) -> Result<Option<AggregateWithEvents<A, E>>, EventStoreError> {
+        // NOTE: バージョンNのスナップショットはイベントIDが0からN-1までのイベントを適用した状態なので、イベントIDがN以降のイベントだけを取得する
+        let snapshot = self.get_aggregate_model(aggregate_id).await?;
+        let from_event_id = snapshot.as_ref().map_or(0, AggregateModel::version);
+        let events = self
+            .list_event_store_models(aggregate_id, from_event_id, usize::MAX)
+            .await?;
+        if snapshot.is_none() && events.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(AggregateWithEvents::new(snapshot, events)))
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn update(
+        &self,
+        aggregate: AggregateModel<A>,
+        events: Vec<E>,
+        idempotency_key: Option<IdempotencyKeyModel>,
+        outbox: Vec<OutboxModel<E>>,
+    ) -> Result<(), EventStoreError> {
+        if events.is_empty() {
+            return Err(EventStoreError::EmptyEvents);
+        }
+
+        let aggregate_id = AttributeValue::S(aggregate.id().to_string());
+        // NOTE: この時点の集約はイベントを適用済みなので、イベントを適用する前のバージョンを求める
+        let current_version = u64::try_from(events.len())
+            .ok()
+            .and_then(|len| aggregate.version().checked_sub(len))
+            .ok_or(EventStoreError::InvalidAggregateVersion)?;
+        let sequence = EventSequenceModel::new(
+            aggregate.id().to_string(),
+            current_version
+                .checked_sub(1)
+                .ok_or(EventStoreError::InvalidAggregateVersion)?,
+        );
+
+        let mut transact_items = Vec::new();
+        let mut new_event_id = sequence.latest_event_id();
+        for event in events {
+            new_event_id += 1;
+            transact_items.push(self.put_event(EventStoreModel::new(
+                new_event_id,
+                aggregate.id().to_string(),
+                event,
+            ))?);
+        }
+        transact_items.push(
+            self.update_sequence(
+                &sequence,
+                &EventSequenceModel::new(aggregate.id().to_string(), new_event_id)
+                    .with_updated_at(now_millis()),
+            )?,
+        );
+        if self
+            .snapshot_policy
+            .should_snapshot(current_version, aggregate.version())
+        {
+            transact_items.push(
+                TransactWriteItem::builder()
+                    .update(
+                        Update::builder()
+                            .table_name(self.tables.aggregate)
+                            .key("id", aggregate_id)
+                            .expression_attribute_values(
+                                ":new_version",
+                                version_attribute_value(&aggregate),
+                            )
+                            .expression_attribute_values(
+                                ":new_payload",
+                                serde_dynamo::to_attribute_value(aggregate.payload())
+                                    .map_err(|e| EventStoreError::Unknown(e.into()))?,
+                            )
+                            .update_expression("SET version = :new_version, payload = :new_payload")
+                            .condition_expression("attribute_exists(id) AND version < :new_version")
+                            .return_values_on_condition_check_failure(
+                                ReturnValuesOnConditionCheckFailure::AllOld,
+                            )
+                            .build()
+                            .map_err(|e| EventStoreError::Unknown(e.into()))?,
+                    )
+                    .build(),
+            );
+        }
+        if let Some(idempotency_key) = idempotency_key {
+            transact_items.push(self.put_idempotency_key(idempotency_key)?);
+        }
+        for model in outbox {
+            transact_items.push(self.put_outbox(model)?);
+        }
+        self.transact_write_items(transact_items).await
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn save_snapshot(&self, aggregate: AggregateModel<A>) -> Result<(), EventStoreError> {
+        self.dynamodb
+            .put_item()
+            .table_name(self.tables.aggregate)
+            .expression_attribute_values(":version", version_attribute_value(&aggregate))
+            .set_item(Some(aggregate.try_into()?))
+            .condition_expression("attribute_not_exists(id) OR version < :version")
+            .send()
+            .await
+            .map_err(|e| EventStoreError::Unknown(e.into()))?;
+        Ok(())
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn get_aggregate(
+        &self,
+        aggregate_id: &str,
+    ) -> Result<Option<AggregateModel<A>>, EventStoreError> {
+        self.get_aggregate_model(aggregate_id).await
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn get_idempotency_key(
+        &self,
+        key: &str,
+    ) -> Result<Option<IdempotencyKeyModel>, EventStoreError> {
+        use aws_sdk_dynamodb::operation::get_item::GetItemError::ResourceNotFoundException;
+
+        let output = match self
+            .dynamodb
+            .get_item()
+            .table_name(self.tables.idempotency_key)
+            .key("key", AttributeValue::S(key.to_string()))
+            .consistent_read(true)
+            .send()
+            .await
+        {
+            Ok(output) => output,
+            Err(e) => match e.into_service_error() {
+                ResourceNotFoundException(_) => return Ok(None),
+                e => return Err(EventStoreError::Unknown(e.into())),
+            },
+        };
+        let Some(item) = output.item else {
+            return Ok(None);
+        };
+        Ok(Some(
+            serde_dynamo::from_item(item).map_err(|e| EventStoreError::Unknown(e.into()))?,
+        ))
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn list_aggregates(&self) -> Result<Vec<AggregateModel<A>>, EventStoreError> {
+        use aws_sdk_dynamodb::operation::scan::ScanError::ResourceNotFoundException;
+
+        let mut models = Vec::new();
+        let mut exclusive_start_key = None;
+        loop {
+            let output = match self
+                .dynamodb
+                .scan()
+                .table_name(self.tables.aggregate)
+                .set_exclusive_start_key(exclusive_start_key)
+                .send()
+                .await
+            {
+                Ok(output) => output,
+                Err(e) => match e.into_service_error() {
+                    ResourceNotFoundException(_) => return Ok(Vec::new()),
+                    e => return Err(EventStoreError::Unknown(e.into())),
+                },
+            };
+            let items: Vec<AggregateModel<A>> = serde_dynamo::from_items(output.items().to_vec())
+                .map_err(|e| EventStoreError::Unknown(e.into()))?;
+            models.extend(items);
+            exclusive_start_key = output.last_evaluated_key;
+            if exclusive_start_key.is_none() {
+                break;
+            }
+        }
+        Ok(models)
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn list_aggregates_page(
+        &self,
+        page_size: usize,
+        page_token: Option<&PageToken>,
+    ) -> Result<Page<AggregateModel<A>>, EventStoreError> {
+        use aws_sdk_dynamodb::operation::scan::ScanError::ResourceNotFoundException;
+
+        // NOTE: スキャンは1MBごとに読み取りを止めるので、指定した件数より少なくても続きがある場合は次のトークンを返す
+        let output = match self
+            .dynamodb
+            .scan()
+            .table_name(self.tables.aggregate)
+            .limit(i32::try_from(page_size).unwrap_or(i32::MAX))
+            .set_exclusive_start_key(page_token.map(|token| {
+                HashMap::from([("id".to_string(), AttributeValue::S(token.key().to_string()))])
+            }))
+            .send()
+            .await
+        {
+            Ok(output) => output,
+            Err(e) => match e.into_service_error() {
+                ResourceNotFoundException(_) => return Ok(Page::new(Vec::new(), None)),
+                e => return Err(EventStoreError::Unknown(e.into())),
+            },
+        };
+        let models: Vec<AggregateModel<A>> = serde_dynamo::from_items(output.items().to_vec())
+            .map_err(|e| EventStoreError::Unknown(e.into()))?;
+        let next_page_token = output
+            .last_evaluated_key
+            .as_ref()
+            .and_then(|key| key.get("id"))
+            .and_then(|id| id.as_s().ok())
+            .map(PageToken::new);
+        Ok(Page::new(models, next_page_token))
+    }
+}
+
+impl<A, E> EventLog<E> for DynamoDbEventStore<A, E>
+where
+    A: Serialize + DeserializeOwned + Debug + Send + Sync,
+    E: Serialize + DeserializeOwned + Debug + Send + Sync,
+{
+    #[instrument(skip(self), err, ret)]
+    async fn list_sequences(
+        &self,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<EventSequenceModel>, EventStoreError> {
+        use aws_sdk_dynamodb::operation::scan::ScanError::ResourceNotFoundException;
+
+        // NOTE: スキャンは集約IDの順に読み取れないが、同じ集約IDを開始キーにすれば同じ続きから読み取れる
+        let mut sequences = Vec::new();
+        let mut exclusive_start_key = after.map(|aggregate_id| {
+            HashMap::from([(
+                "aggregate_id".to_string(),
+                AttributeValue::S(aggregate_id.to_string()),
+            )])
+        });
+        while sequences.len() < limit {
+            let output = match self
+                .dynamodb
+                .scan()
+                .table_name(self.tables.event_sequence)
+                .limit(i32::try_from(limit - sequences.len()).unwrap_or(i32::MAX))
+                .consistent_read(true)
+                .set_exclusive_start_key(exclusive_start_key)
+                .send()
+                .await
+            {
+                Ok(output) => output,
+                Err(e) => match e.into_service_error() {
+                    ResourceNotFoundException(_) => return Ok(Vec::new()),
+                    e => return Err(EventStoreError::Unknown(e.into())),
+                },
+            };
+            let items: Vec<EventSequenceModel> = serde_dynamo::from_items(output.items().to_vec())
+                .map_err(|e| EventStoreError::Unknown(e.into()))?;
+            sequences.extend(items);
+            exclusive_start_key = output.last_evaluated_key;
+            if exclusive_start_key.is_none() {
+                break;
+            }
+        }
+        Ok(sequences)
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn list_sequences_since(
+        &self,
+        after: &SequencePosition,
+        limit: usize,
+    ) -> Result<Vec<EventSequenceModel>, EventStoreError> {
+        use aws_sdk_dynamodb::operation::query::QueryError::ResourceNotFoundException;
+
+        let [(partition_name, partition), (key_name, key)] = position_attributes(after);
+        let mut sequences = Vec::new();
+        let mut exclusive_start_key = None;
+        while sequences.len() < limit {
+            let output = match self
+                .dynamodb
+                .query()
+                .table_name(self.tables.event_sequence)
+                .index_name(UPDATED_AT_INDEX)
+                .key_condition_expression("#partition = :partition AND #key > :key")
+                .expression_attribute_names("#partition", &partition_name)
+                .expression_attribute_names("#key", &key_name)
+                .expression_attribute_values(":partition", partition.clone())
+                .expression_attribute_values(":key", key.clone())
+                .limit(i32::try_from(limit - sequences.len()).unwrap_or(i32::MAX))
+                .set_exclusive_start_key(exclusive_start_key)
+                .send()
+                .await
+            {
+                Ok(output) => output,
+                Err(e) => match e.into_service_error() {
+                    ResourceNotFoundException(_) => return Ok(Vec::new()),
+                    e => return Err(EventStoreError::Unknown(e.into())),
+                },
+            };
+            let items: Vec<EventSequenceModel> = serde_dynamo::from_items(output.items().to_vec())
+                .map_err(|e| EventStoreError::Unknown(e.into()))?;
+            sequences.extend(items);
+            exclusive_start_key = output.last_evaluated_key;
+            if exclusive_start_key.is_none() {
+                break;
+            }
+        }
+        Ok(sequences)
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn list_events(
+        &self,
+        aggregate_id: &str,
+        after: Option<u64>,
+        limit: usize,
+    ) -> Result<Vec<EventStoreModel<E>>, EventStoreError> {
+        let from_event_id = after.map_or(0, |event_id| event_id + 1);
+        self.list_event_store_models(aggregate_id, from_event_id, limit)
+            .await
+    }
+}
+
+impl<A, E> Outbox<E> for DynamoDbEventStore<A, E>
+where
+    A: Send + Sync,
+    E: Serialize + DeserializeOwned + Debug + Send + Sync,
+{
+    #[instrument(skip(self), err, ret)]
+    async fn list_outbox(&self, limit: usize) -> Result<Vec<OutboxModel<E>>, EventStoreError> {
+        use aws_sdk_dynamodb::operation::scan::ScanError::ResourceNotFoundException;
+
+        let output = match self
+            .dynamodb
+            .scan()
+            .table_name(self.tables.outbox)
+            .limit(i32::try_from(limit).unwrap_or(i32::MAX))
+            .consistent_read(true)
+            .send()
+            .await
+        {
+            Ok(output) => output,
+            Err(e) => match e.into_service_error() {
+                ResourceNotFoundException(_) => return Ok(Vec::new()),
+                e => return Err(EventStoreError::Unknown(e.into())),
+            },
+        };
+        let mut models: Vec<OutboxModel<E>> = serde_dynamo::from_items(output.items().to_vec())
+            .map_err(|e| EventStoreError::Unknown(e.into()))?;
+        // NOTE: スキャンはパーティション内ではソートキーの昇順に読み取るので、
+        //       取得した統合イベントを並べ替えれば集約ごとにイベントIDの昇順になる
+        models.sort_by(|a, b| {
+            (a.aggregate_id(), a.event_id()).cmp(&(b.aggregate_id(), b.event_id()))
+        });
+        Ok(models)
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn delete_outbox(
+        &self,
+        aggregate_id: &str,
+        event_id: u64,
+    ) -> Result<(), EventStoreError> {
+        self.dynamodb
+            .delete_item()
+            .table_name(self.tables.outbox)
+            .key("aggregate_id", AttributeValue::S(aggregate_id.to_string()))
+            .key("event_id", AttributeValue::N(event_id.to_string()))
+            .send()
+            .await
+            .map_err(|e| EventStoreError::Unknown(e.into()))?;
+        Ok(())
+    }
+}
+
+/// イベントシーケンスを更新した順序で読み取る索引のパーティションキーとソートキーの属性
+///
+/// ソートキーは文字列の順序で比較しても位置の順序と一致するように、時刻を桁数を揃えた10進数にする
+fn position_attributes(position: &SequencePosition) -> [(String, AttributeValue); 2] {
+    [
+        (
+            "updated_at_partition".to_string(),
+            AttributeValue::S(UPDATED_AT_PARTITION.to_string()),
+        ),
+        (
+            "updated_at_key".to_string(),
+            AttributeValue::S(format!(
+                "{:020}#{}",
+                position.updated_at(),
+                position.aggregate_id()
+            )),
+        ),
+    ]
+}
+
+/// トランザクションのエラーを [`EventStoreError`] に変換する
+///
+/// 既存のレコードが条件式を満たさなかった場合や他のトランザクションと競合した場合は、
+/// 他の処理が先に集約を更新したとみなして [`EventStoreError::ConcurrencyConflict`] を返す
+fn transaction_error(e: SdkError<TransactWriteItemsError>) -> EventStoreError {
+    match e.into_service_error() {
+        TransactWriteItemsError::TransactionCanceledException(e)
+            if e.cancellation_reasons()
+                .iter()
+                .any(|reason| match reason.code() {
+                    Some("TransactionConflict") => true,
+                    Some("ConditionalCheckFailed") => reason.item().is_some(),
+                    _ => false,
+                }) =>
+        {
+            EventStoreError::ConcurrencyConflict
+        }
+        e => EventStoreError::Unknown(e.into()),
+    }
+}
+
+fn version_attribute_value<P>(aggregate: &AggregateModel<P>) -> AttributeValue {
+    AttributeValue::N(aggregate.version().to_string())
+}
+
+impl<P, S> TryFrom<AggregateModel<P>> for HashMap<String, AttributeValue, S>
+where
+    P: Serialize,
+    S: std::hash::BuildHasher,
+    HashMap<String, AttributeValue, S>: From<serde_dynamo::Item>,
+{
+    type Error = EventStoreError;
+
+    fn try_from(value: AggregateModel<P>) -> Result<Self, Self::Error> {
+        serde_dynamo::to_item(value).map_err(|e| EventStoreError::Unknown(e.into()))
+    }
+}
+
+impl<P, S> TryFrom<EventStoreModel<P>> for HashMap<String, AttributeValue, S>
+where
+    P: Serialize,
+    S: std::hash::BuildHasher,
+    HashMap<String, AttributeValue, S>: From<serde_dynamo::Item>,
+{
+    type Error = EventStoreError;
+
+    fn try_from(value: EventStoreModel<P>) -> Result<Self, Self::Error> {
+        serde_dynamo::to_item(value).map_err(|e| EventStoreError::Unknown(e.into()))
+    }
+}
+
+impl<S> TryFrom<EventSequenceModel> for HashMap<String, AttributeValue, S>
+where
+    S: std::hash::BuildHasher,
+    HashMap<String, AttributeValue, S>: From<serde_dynamo::Item>,
+{
+    type Error = EventStoreError;
+
+    fn try_from(value: EventSequenceModel) -> Result<Self, Self::Error> {
+        serde_dynamo::to_item(value).map_err(|e| EventStoreError::Unknown(e.into()))
+    }
+}
+
+impl<S> TryFrom<IdempotencyKeyModel> for HashMap<String, AttributeValue, S>
+where
+    S: std::hash::BuildHasher,
+    HashMap<String, AttributeValue, S>: From<serde_dynamo::Item>,
+{
+    type Error = EventStoreError;
+
+    fn try_from(value: IdempotencyKeyModel) -> Result<Self, Self::Error> {
+        serde_dynamo::to_item(value).map_err(|e| EventStoreError::Unknown(e.into()))
+    }
+}
+
+impl<P, S> TryFrom<OutboxModel<P>> for HashMap<String, AttributeValue, S>
+where
+    P: Serialize,
+    S: std::hash::BuildHasher,
+    HashMap<String, AttributeValue, S>: From<serde_dynamo::Item>,
+{
+    type Error = EventStoreError;
+
+    fn try_from(value: OutboxModel<P>) -> Result<Self, Self::Error> {
+        serde_dynamo::to_item(value).map_err(|e| EventStoreError::Unknown(e.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use aws_sdk_dynamodb::types::AttributeValue;
+
+    use crate::EventSequenceModel;
+
+    type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    #[test]
+    fn test_read_legacy_event_sequence() -> Result<(), Error> {
+        let legacy = HashMap::from([
+            (
+                "aggregate_id".to_string(),
+                AttributeValue::S("aggregate".to_string()),
+            ),
+            ("version".to_string(), AttributeValue::N("3".to_string())),
+        ]);
+        let sequences: Vec<EventSequenceModel> = serde_dynamo::from_items(vec![legacy])?;
+        assert_eq!(
+            sequences,
+            vec![EventSequenceModel::new("aggregate".to_string(), 3)]
+        );
+
+        let item: HashMap<String, AttributeValue> =
+            EventSequenceModel::new("aggregate".to_string(), 4).try_into()?;
+        assert_eq!(
+            item.get("latest_event_id"),
+            Some(&AttributeValue::N("4".to_string()))
+        );
+        assert!(!item.contains_key("version"));
+        Ok(())
+    }
+}
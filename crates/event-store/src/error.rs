@@ -0,0 +1,19 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum EventStoreError {
+    #[error("Events are empty")]
+    EmptyEvents,
+    #[error("Invalid aggregate version")]
+    InvalidAggregateVersion,
+    /// 他の処理が先に集約を更新したため、楽観的排他制御で更新できなかった
+    ///
+    /// 集約を取得し直してコマンドを再実行すると成功する可能性がある
+    #[error("Aggregate was updated concurrently")]
+    ConcurrencyConflict,
+    /// 一覧の続きを取得するトークンが不正
+    #[error("Invalid page token")]
+    InvalidPageToken,
+    #[error(transparent)]
+    Unknown(#[from] Box<dyn std::error::Error + Send + Sync + 'static>),
+}
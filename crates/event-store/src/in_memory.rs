@@ -0,0 +1,649 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{self, Debug};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use tracing::instrument;
+
+use crate::model::now_millis;
+use crate::{
+    AggregateModel, AggregateWithEvents, EventLog, EventSequenceModel, EventStore, EventStoreError,
+    EventStoreModel, IdempotencyKeyModel, Outbox, OutboxModel, Page, PageToken, SequencePosition,
+    SnapshotPolicy,
+};
+
+/// プロセスのメモリ上に集約とイベントを保存するイベントストア
+///
+/// `DynamoDB` をバックエンドにしたイベントストアと同じ楽観的排他制御を行う。
+/// クローンしたイベントストアは同じデータを共有し、プロセスを終了するとデータは失われる
+///
+/// `A` は集約テーブルのペイロード、`E` はイベントストアのペイロード
+pub struct InMemoryEventStore<A, E> {
+    tables: Arc<Mutex<Tables<A, E>>>,
+    snapshot_policy: SnapshotPolicy,
+}
+
+/// `DynamoDB` の各テーブルに相当するデータ
+struct Tables<A, E> {
+    /// 集約IDごとの集約のスナップショット
+    aggregates: HashMap<String, AggregateModel<A>>,
+    /// 集約IDごとのイベントIDの昇順に並んだイベント
+    events: HashMap<String, Vec<EventStoreModel<E>>>,
+    /// 集約IDごとのイベントシーケンス
+    sequences: HashMap<String, EventSequenceModel>,
+    /// 冪等キーごとのコマンドの結果
+    idempotency_keys: HashMap<String, IdempotencyKeyModel>,
+    /// 集約IDとイベントIDの昇順に並んだ送信していない統合イベント
+    outbox: BTreeMap<(String, u64), OutboxModel<E>>,
+}
+
+impl<A, E> Tables<A, E> {
+    /// 冪等キーのいずれかが保存済みの場合は、他の処理が先に同じコマンドを実行したとみなす
+    fn ensure_idempotency_keys_not_exist<'a>(
+        &self,
+        idempotency_keys: impl IntoIterator<Item = &'a IdempotencyKeyModel>,
+    ) -> Result<(), EventStoreError> {
+        if idempotency_keys
+            .into_iter()
+            .any(|model| self.idempotency_keys.contains_key(model.key()))
+        {
+            return Err(EventStoreError::ConcurrencyConflict);
+        }
+        Ok(())
+    }
+
+    fn insert_idempotency_keys(
+        &mut self,
+        idempotency_keys: impl IntoIterator<Item = IdempotencyKeyModel>,
+    ) {
+        for model in idempotency_keys {
+            self.idempotency_keys.insert(model.key().to_string(), model);
+        }
+    }
+}
+
+impl<A, E> InMemoryEventStore<A, E> {
+    /// Creates a new [`InMemoryEventStore`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            tables: Arc::new(Mutex::new(Tables {
+                aggregates: HashMap::new(),
+                events: HashMap::new(),
+                sequences: HashMap::new(),
+                idempotency_keys: HashMap::new(),
+                outbox: BTreeMap::new(),
+            })),
+            snapshot_policy: SnapshotPolicy::default(),
+        }
+    }
+
+    /// スナップショットを保存する方針を設定する
+    #[must_use]
+    pub fn with_snapshot_policy(self, snapshot_policy: SnapshotPolicy) -> Self {
+        Self {
+            snapshot_policy,
+            ..self
+        }
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, Tables<A, E>>, EventStoreError> {
+        self.tables
+            .lock()
+            .map_err(|e| EventStoreError::Unknown(e.to_string().into()))
+    }
+}
+
+impl<A, E> Default for InMemoryEventStore<A, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A, E> Clone for InMemoryEventStore<A, E> {
+    fn clone(&self) -> Self {
+        Self {
+            tables: Arc::clone(&self.tables),
+            snapshot_policy: self.snapshot_policy,
+        }
+    }
+}
+
+impl<A, E> Debug for InMemoryEventStore<A, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InMemoryEventStore")
+            .field("snapshot_policy", &self.snapshot_policy)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A, E> EventStore<A, E> for InMemoryEventStore<A, E>
+where
+    A: Clone + Debug + Send + Sync,
+    E: Clone + Debug + Send + Sync,
+{
+    #[instrument(skip(self), err, ret)]
+    async fn create(
+        &self,
+        aggregate: AggregateModel<A>,
+        event: E,
+        idempotency_keys: Vec<IdempotencyKeyModel>,
+    ) -> Result<(), EventStoreError> {
+        let mut tables = self.lock()?;
+        let aggregate_id = aggregate.id().to_string();
+        if tables.sequences.contains_key(&aggregate_id)
+            || tables.aggregates.contains_key(&aggregate_id)
+        {
+            return Err(EventStoreError::Unknown(
+                format!("aggregate already exists: {aggregate_id}").into(),
+            ));
+        }
+        tables.ensure_idempotency_keys_not_exist(&idempotency_keys)?;
+
+        tables.events.insert(
+            aggregate_id.clone(),
+            vec![EventStoreModel::new(0, aggregate_id.clone(), event)],
+        );
+        tables.sequences.insert(
+            aggregate_id.clone(),
+            EventSequenceModel::new(aggregate_id.clone(), 0).with_updated_at(now_millis()),
+        );
+        tables.aggregates.insert(aggregate_id, aggregate);
+        tables.insert_idempotency_keys(idempotency_keys);
+        Ok(())
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn get(
+        &self,
+        aggregate_id: &str,
+    ) -> Result<Option<AggregateWithEvents<A, E>>, EventStoreError> {
+        let tables = self.lock()?;
+        // NOTE: バージョンNのスナップショットはイベントIDが0からN-1までのイベントを適用した状態なので、イベントIDがN以降のイベントだけを取得する
+        let snapshot = tables.aggregates.get(aggregate_id).cloned();
+        let from_event_id = snapshot.as_ref().map_or(0, AggregateModel::version);
+        let events: Vec<_> = tables
+            .events
+            .get(aggregate_id)
+            .into_iter()
+            .flatten()
+            .filter(|event| event.id() >= from_event_id)
+            .cloned()
+            .collect();
+        if snapshot.is_none() && events.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(AggregateWithEvents::new(snapshot, events)))
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn update(
+        &self,
+        aggregate: AggregateModel<A>,
+        events: Vec<E>,
+        idempotency_key: Option<IdempotencyKeyModel>,
+        outbox: Vec<OutboxModel<E>>,
+    ) -> Result<(), EventStoreError> {
+        if events.is_empty() {
+            return Err(EventStoreError::EmptyEvents);
+        }
+
+        // NOTE: この時点の集約はイベントを適用済みなので、イベントを適用する前のバージョンを求める
+        let current_version = u64::try_from(events.len())
+            .ok()
+            .and_then(|len| aggregate.version().checked_sub(len))
+            .ok_or(EventStoreError::InvalidAggregateVersion)?;
+        let current_latest_event_id = current_version
+            .checked_sub(1)
+            .ok_or(EventStoreError::InvalidAggregateVersion)?;
+
+        let mut tables = self.lock()?;
+        let aggregate_id = aggregate.id().to_string();
+        // NOTE: イベントシーケンスの最新イベントIDで楽観的排他制御を行う
+        match tables.sequences.get(&aggregate_id) {
+            Some(sequence) if sequence.latest_event_id() == current_latest_event_id => {}
+            Some(_) => return Err(EventStoreError::ConcurrencyConflict),
+            None => {
+                return Err(EventStoreError::Unknown(
+                    format!("aggregate not found: {aggregate_id}").into(),
+                ));
+            }
+        }
+        // NOTE: `DynamoDB` のトランザクションと同じく、スナップショットを更新できない場合はイベントも追加しない
+        let should_snapshot = self
+            .snapshot_policy
+            .should_snapshot(current_version, aggregate.version());
+        if should_snapshot {
+            match tables.aggregates.get(&aggregate_id) {
+                Some(snapshot) if snapshot.version() < aggregate.version() => {}
+                Some(_) => return Err(EventStoreError::ConcurrencyConflict),
+                None => {
+                    return Err(EventStoreError::Unknown(
+                        format!("aggregate not found: {aggregate_id}").into(),
+                    ));
+                }
+            }
+        }
+        tables.ensure_idempotency_keys_not_exist(&idempotency_key)?;
+
+        let mut new_event_id = current_latest_event_id;
+        let models = tables.events.entry(aggregate_id.clone()).or_default();
+        for event in events {
+            new_event_id += 1;
+            models.push(EventStoreModel::new(
+                new_event_id,
+                aggregate_id.clone(),
+                event,
+            ));
+        }
+        tables.sequences.insert(
+            aggregate_id.clone(),
+            EventSequenceModel::new(aggregate_id.clone(), new_event_id)
+                .with_updated_at(now_millis()),
+        );
+        if should_snapshot {
+            tables.aggregates.insert(aggregate_id, aggregate);
+        }
+        tables.insert_idempotency_keys(idempotency_key);
+        for model in outbox {
+            tables
+                .outbox
+                .insert((model.aggregate_id().to_string(), model.event_id()), model);
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn save_snapshot(&self, aggregate: AggregateModel<A>) -> Result<(), EventStoreError> {
+        let mut tables = self.lock()?;
+        if let Some(version) = tables
+            .aggregates
+            .get(aggregate.id())
+            .map(AggregateModel::version)
+            .filter(|version| *version >= aggregate.version())
+        {
+            return Err(EventStoreError::Unknown(
+                format!(
+                    "snapshot of aggregate {} is already version {version}",
+                    aggregate.id()
+                )
+                .into(),
+            ));
+        }
+        tables
+            .aggregates
+            .insert(aggregate.id().to_string(), aggregate);
+        Ok(())
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn get_aggregate(
+        &self,
+        aggregate_id: &str,
+    ) -> Result<Option<AggregateModel<A>>, EventStoreError> {
+        Ok(self.lock()?.aggregates.get(aggregate_id).cloned())
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn get_idempotency_key(
+        &self,
+        key: &str,
+    ) -> Result<Option<IdempotencyKeyModel>, EventStoreError> {
+        Ok(self.lock()?.idempotency_keys.get(key).cloned())
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn list_aggregates(&self) -> Result<Vec<AggregateModel<A>>, EventStoreError> {
+        Ok(self.lock()?.aggregates.values().cloned().collect())
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn list_aggregates_page(
+        &self,
+        page_size: usize,
+        page_token: Option<&PageToken>,
+    ) -> Result<Page<AggregateModel<A>>, EventStoreError> {
+        let mut aggregates: Vec<_> = self.lock()?.aggregates.values().cloned().collect();
+        aggregates.sort_by(|a, b| a.id().cmp(b.id()));
+        Ok(Page::from_sorted(
+            aggregates,
+            page_size,
+            page_token,
+            AggregateModel::id,
+        ))
+    }
+}
+
+impl<A, E> Outbox<E> for InMemoryEventStore<A, E>
+where
+    A: Send + Sync,
+    E: Clone + Debug + Send + Sync,
+{
+    #[instrument(skip(self), err, ret)]
+    async fn list_outbox(&self, limit: usize) -> Result<Vec<OutboxModel<E>>, EventStoreError> {
+        Ok(self.lock()?.outbox.values().take(limit).cloned().collect())
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn delete_outbox(
+        &self,
+        aggregate_id: &str,
+        event_id: u64,
+    ) -> Result<(), EventStoreError> {
+        self.lock()?
+            .outbox
+            .remove(&(aggregate_id.to_string(), event_id));
+        Ok(())
+    }
+}
+
+impl<A, E> EventLog<E> for InMemoryEventStore<A, E>
+where
+    A: Send + Sync,
+    E: Clone + Debug + Send + Sync,
+{
+    #[instrument(skip(self), err, ret)]
+    async fn list_sequences(
+        &self,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<EventSequenceModel>, EventStoreError> {
+        let tables = self.lock()?;
+        let mut sequences: Vec<EventSequenceModel> = tables
+            .sequences
+            .values()
+            .filter(|sequence| after.is_none_or(|after| sequence.aggregate_id() > after))
+            .cloned()
+            .collect();
+        sequences.sort();
+        sequences.truncate(limit);
+        Ok(sequences)
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn list_sequences_since(
+        &self,
+        after: &SequencePosition,
+        limit: usize,
+    ) -> Result<Vec<EventSequenceModel>, EventStoreError> {
+        let tables = self.lock()?;
+        let mut sequences: Vec<EventSequenceModel> = tables
+            .sequences
+            .values()
+            .filter(|sequence| sequence.position() > *after)
+            .cloned()
+            .collect();
+        sequences.sort_by_key(EventSequenceModel::position);
+        sequences.truncate(limit);
+        Ok(sequences)
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn list_events(
+        &self,
+        aggregate_id: &str,
+        after: Option<u64>,
+        limit: usize,
+    ) -> Result<Vec<EventStoreModel<E>>, EventStoreError> {
+        let tables = self.lock()?;
+        Ok(tables
+            .events
+            .get(aggregate_id)
+            .into_iter()
+            .flatten()
+            .filter(|event| after.is_none_or(|after| event.id() > after))
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AggregateModel, EventStore, EventStoreError, IdempotencyKeyModel, SnapshotPolicy};
+
+    use super::InMemoryEventStore;
+
+    type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    const AGGREGATE_ID: &str = "aggregate";
+
+    fn event_ids(
+        aggregate: Option<crate::AggregateWithEvents<u64, &'static str>>,
+    ) -> Option<(Option<u64>, Vec<u64>)> {
+        aggregate.map(|aggregate| {
+            let (snapshot, events) = aggregate.into_parts();
+            (
+                snapshot.as_ref().map(AggregateModel::version),
+                events.iter().map(crate::EventStoreModel::id).collect(),
+            )
+        })
+    }
+
+    #[tokio::test]
+    async fn test_update() -> Result<(), Error> {
+        let event_store = InMemoryEventStore::new();
+        assert!(event_store.get(AGGREGATE_ID).await?.is_none());
+
+        event_store
+            .create(
+                AggregateModel::new(AGGREGATE_ID.to_string(), 1, 1),
+                "created",
+                Vec::new(),
+            )
+            .await?;
+        assert!(
+            event_store
+                .create(
+                    AggregateModel::new(AGGREGATE_ID.to_string(), 1, 1),
+                    "created",
+                    Vec::new()
+                )
+                .await
+                .is_err(),
+            "同じ集約を作成できない"
+        );
+
+        event_store
+            .update(
+                AggregateModel::new(AGGREGATE_ID.to_string(), 3, 3),
+                vec!["updated", "updated"],
+                None,
+                Vec::new(),
+            )
+            .await?;
+        assert_eq!(
+            event_ids(event_store.get(AGGREGATE_ID).await?),
+            Some((Some(3), Vec::new()))
+        );
+        assert!(
+            matches!(
+                event_store
+                    .update(
+                        AggregateModel::new(AGGREGATE_ID.to_string(), 3, 3),
+                        vec!["updated"],
+                        None,
+                        Vec::new()
+                    )
+                    .await,
+                Err(EventStoreError::ConcurrencyConflict)
+            ),
+            "古いバージョンの集約は更新できない"
+        );
+        assert!(
+            matches!(
+                event_store
+                    .update(
+                        AggregateModel::new("not-found".to_string(), 2, 2),
+                        vec!["updated"],
+                        None,
+                        Vec::new()
+                    )
+                    .await,
+                Err(EventStoreError::Unknown(_))
+            ),
+            "存在しない集約は競合とみなさない"
+        );
+        assert!(matches!(
+            event_store
+                .update(
+                    AggregateModel::new(AGGREGATE_ID.to_string(), 4, 4),
+                    Vec::new(),
+                    None,
+                    Vec::new()
+                )
+                .await,
+            Err(EventStoreError::EmptyEvents)
+        ));
+        assert!(matches!(
+            event_store
+                .update(
+                    AggregateModel::new(AGGREGATE_ID.to_string(), 1, 1),
+                    vec!["updated"],
+                    None,
+                    Vec::new()
+                )
+                .await,
+            Err(EventStoreError::InvalidAggregateVersion)
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_update_on_demand_snapshot() -> Result<(), Error> {
+        let event_store = InMemoryEventStore::new().with_snapshot_policy(SnapshotPolicy::OnDemand);
+        event_store
+            .create(
+                AggregateModel::new(AGGREGATE_ID.to_string(), 1, 1),
+                "created",
+                Vec::new(),
+            )
+            .await?;
+        event_store
+            .update(
+                AggregateModel::new(AGGREGATE_ID.to_string(), 3, 3),
+                vec!["updated", "updated"],
+                None,
+                Vec::new(),
+            )
+            .await?;
+        assert_eq!(
+            event_ids(event_store.clone().get(AGGREGATE_ID).await?),
+            Some((Some(1), vec![1, 2])),
+            "スナップショット以降のイベントを取得する"
+        );
+
+        event_store
+            .save_snapshot(AggregateModel::new(AGGREGATE_ID.to_string(), 3, 3))
+            .await?;
+        assert_eq!(
+            event_ids(event_store.get(AGGREGATE_ID).await?),
+            Some((Some(3), Vec::new()))
+        );
+        assert!(
+            event_store
+                .save_snapshot(AggregateModel::new(AGGREGATE_ID.to_string(), 2, 2))
+                .await
+                .is_err(),
+            "保存済みのスナップショットより古いバージョンは保存できない"
+        );
+        assert_eq!(event_store.list_aggregates().await?.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_idempotency_key() -> Result<(), Error> {
+        let event_store = InMemoryEventStore::new();
+        let idempotency_key = |version| {
+            IdempotencyKeyModel::new("key".to_string(), AGGREGATE_ID.to_string(), version)
+        };
+        event_store
+            .create(
+                AggregateModel::new(AGGREGATE_ID.to_string(), 1, 1),
+                "created",
+                Vec::new(),
+            )
+            .await?;
+        assert!(event_store.get_idempotency_key("key").await?.is_none());
+
+        event_store
+            .update(
+                AggregateModel::new(AGGREGATE_ID.to_string(), 2, 2),
+                vec!["updated"],
+                Some(idempotency_key(2)),
+                Vec::new(),
+            )
+            .await?;
+        assert_eq!(
+            event_store.get_idempotency_key("key").await?,
+            Some(idempotency_key(2))
+        );
+        assert!(
+            matches!(
+                event_store
+                    .update(
+                        AggregateModel::new(AGGREGATE_ID.to_string(), 3, 3),
+                        vec!["updated"],
+                        Some(idempotency_key(3)),
+                        Vec::new(),
+                    )
+                    .await,
+                Err(EventStoreError::ConcurrencyConflict)
+            ),
+            "保存済みの冪等キーでは更新できない"
+        );
+        assert_eq!(
+            event_ids(event_store.get(AGGREGATE_ID).await?),
+            Some((Some(2), Vec::new())),
+            "冪等キーが競合した場合はイベントを追加しない"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_create_with_idempotency_keys() -> Result<(), Error> {
+        let event_store = InMemoryEventStore::new();
+        let idempotency_key = |key: &str, aggregate_id: &str| {
+            IdempotencyKeyModel::new(key.to_string(), aggregate_id.to_string(), 1)
+        };
+        event_store
+            .create(
+                AggregateModel::new(AGGREGATE_ID.to_string(), 1, 1),
+                "created",
+                vec![
+                    idempotency_key("client", AGGREGATE_ID),
+                    idempotency_key("guard", AGGREGATE_ID),
+                ],
+            )
+            .await?;
+        assert_eq!(
+            event_store.get_idempotency_key("client").await?,
+            Some(idempotency_key("client", AGGREGATE_ID))
+        );
+        assert_eq!(
+            event_store.get_idempotency_key("guard").await?,
+            Some(idempotency_key("guard", AGGREGATE_ID))
+        );
+        assert!(
+            matches!(
+                event_store
+                    .create(
+                        AggregateModel::new("other".to_string(), 1, 1),
+                        "created",
+                        vec![
+                            idempotency_key("other", "other"),
+                            idempotency_key("guard", "other"),
+                        ],
+                    )
+                    .await,
+                Err(EventStoreError::ConcurrencyConflict)
+            ),
+            "いずれかの冪等キーが保存済みの場合は作成できない"
+        );
+        assert!(event_store.get("other").await?.is_none());
+        assert!(
+            event_store.get_idempotency_key("other").await?.is_none(),
+            "競合した場合は他の冪等キーも保存しない"
+        );
+        Ok(())
+    }
+}
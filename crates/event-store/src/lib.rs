@@ -0,0 +1,152 @@
+mod any;
+#[cfg(feature = "dynamodb")]
+pub mod dynamodb;
+mod error;
+pub mod in_memory;
+mod model;
+mod page;
+#[cfg(feature = "postgres")]
+pub mod postgres;
+#[cfg(feature = "relay")]
+pub mod relay;
+mod snapshot;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+mod upcaster;
+
+use std::future::Future;
+
+pub use any::AnyEventStore;
+pub use error::EventStoreError;
+pub use model::{
+    AggregateModel, AggregateWithEvents, EventSequenceModel, EventStoreModel, IdempotencyKeyModel,
+    OutboxModel, SequencePosition, TableNames, VersionedPayload,
+};
+pub use page::{Page, PageToken};
+pub use snapshot::SnapshotPolicy;
+pub use upcaster::{Upcaster, UpcasterRegistry};
+
+pub(crate) type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// 集約のスナップショットとイベントを永続化するイベントストア
+///
+/// `A` は集約テーブルのペイロード、`E` はイベントストアのペイロード
+pub trait EventStore<A, E> {
+    /// 集約を作成したイベントと集約のスナップショットを永続化する
+    ///
+    /// 冪等キーを指定した場合は全て同じトランザクションで保存し、いずれかが保存済みの場合は [`EventStoreError::ConcurrencyConflict`] を返す
+    fn create(
+        &self,
+        aggregate: AggregateModel<A>,
+        event: E,
+        idempotency_keys: Vec<IdempotencyKeyModel>,
+    ) -> impl Future<Output = Result<(), EventStoreError>> + Send;
+
+    /// 集約のスナップショットと、スナップショット以降のイベントを取得する
+    ///
+    /// スナップショットもイベントも存在しない場合は `None` を返す
+    fn get(
+        &self,
+        aggregate_id: &str,
+    ) -> impl Future<Output = Result<Option<AggregateWithEvents<A, E>>, EventStoreError>> + Send;
+
+    /// イベントを追加して、スナップショットを保存する方針に従って集約のスナップショットを更新する
+    ///
+    /// `aggregate` はイベントを適用した後の集約で、イベントを適用する前のバージョンで楽観的排他制御を行う。
+    /// 冪等キーを指定した場合は同じトランザクションで保存し、保存済みの場合は [`EventStoreError::ConcurrencyConflict`] を返す。
+    /// `outbox` の統合イベントも同じトランザクションで保存する
+    fn update(
+        &self,
+        aggregate: AggregateModel<A>,
+        events: Vec<E>,
+        idempotency_key: Option<IdempotencyKeyModel>,
+        outbox: Vec<OutboxModel<E>>,
+    ) -> impl Future<Output = Result<(), EventStoreError>> + Send;
+
+    /// 集約のスナップショットを保存する
+    ///
+    /// 保存済みのスナップショットより新しいバージョンの場合だけ保存する
+    fn save_snapshot(
+        &self,
+        aggregate: AggregateModel<A>,
+    ) -> impl Future<Output = Result<(), EventStoreError>> + Send;
+
+    /// 集約のスナップショットを取得する
+    fn get_aggregate(
+        &self,
+        aggregate_id: &str,
+    ) -> impl Future<Output = Result<Option<AggregateModel<A>>, EventStoreError>> + Send;
+
+    /// 保存済みの冪等キーを取得する
+    fn get_idempotency_key(
+        &self,
+        key: &str,
+    ) -> impl Future<Output = Result<Option<IdempotencyKeyModel>, EventStoreError>> + Send;
+
+    /// 全ての集約のスナップショットを取得する
+    fn list_aggregates(
+        &self,
+    ) -> impl Future<Output = Result<Vec<AggregateModel<A>>, EventStoreError>> + Send;
+
+    /// 集約のスナップショットを `page_token` の続きから最大 `page_size` 件取得する
+    ///
+    /// ページ内の順序はバックエンドに依存するが、同じトークンで取得し直した場合は同じ続きから取得する
+    fn list_aggregates_page(
+        &self,
+        page_size: usize,
+        page_token: Option<&PageToken>,
+    ) -> impl Future<Output = Result<Page<AggregateModel<A>>, EventStoreError>> + Send;
+}
+
+/// イベントと同じトランザクションで保存した統合イベントを取り出すトランザクショナルアウトボックス
+///
+/// `E` はイベントストアのペイロード
+pub trait Outbox<E> {
+    /// 送信していない統合イベントを集約IDとイベントIDの昇順で最大 `limit` 件取得する
+    fn list_outbox(
+        &self,
+        limit: usize,
+    ) -> impl Future<Output = Result<Vec<OutboxModel<E>>, EventStoreError>> + Send;
+
+    /// 送信した統合イベントを削除する
+    fn delete_outbox(
+        &self,
+        aggregate_id: &str,
+        event_id: u64,
+    ) -> impl Future<Output = Result<(), EventStoreError>> + Send;
+}
+
+/// イベントストアに保存したイベントを集約ごとに読み取るイベントログ
+///
+/// `E` はイベントストアのペイロード
+pub trait EventLog<E> {
+    /// `after` の集約より後の集約IDと最新のイベントIDを最大 `limit` 件取得する
+    ///
+    /// 集約の順序はバックエンドに依存するが、同じ `after` で取得し直した場合は同じ続きから取得する。
+    /// `after` が `None` の場合は先頭から取得する
+    fn list_sequences(
+        &self,
+        after: Option<&str>,
+        limit: usize,
+    ) -> impl Future<Output = Result<Vec<EventSequenceModel>, EventStoreError>> + Send;
+
+    /// `after` の位置より後に更新した集約の集約IDと最新のイベントIDを、位置の昇順で最大 `limit` 件取得する
+    ///
+    /// 集約にイベントを追加すると、より後の位置に移動する。
+    /// 位置の時刻はイベントを追加したプロセスの時計で決まり、コミットした順序とも一致しないので、読み取る側は時刻を遡って読み取り直す
+    fn list_sequences_since(
+        &self,
+        after: &SequencePosition,
+        limit: usize,
+    ) -> impl Future<Output = Result<Vec<EventSequenceModel>, EventStoreError>> + Send;
+
+    /// 集約の `after` のイベントIDより後のイベントを、イベントIDの昇順で最大 `limit` 件取得する
+    ///
+    /// `after` が `None` の場合は集約の最初のイベントから取得する
+    fn list_events(
+        &self,
+        aggregate_id: &str,
+        after: Option<u64>,
+        limit: usize,
+    ) -> impl Future<Output = Result<Vec<EventStoreModel<E>>, EventStoreError>> + Send;
+}
@@ -0,0 +1,362 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// バージョンを持つペイロード
+pub trait VersionedPayload {
+    /// 最新のバージョンのペイロードか
+    fn is_latest(&self) -> bool;
+
+    /// ペイロードを最新のバージョンに変換する
+    #[must_use]
+    fn into_latest(self) -> Self;
+}
+
+/// 集約テーブルのモデル
+///
+/// バージョンNのスナップショットはイベントIDが0からN-1までのイベントを適用した状態
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AggregateModel<P> {
+    id: String,
+    version: u64,
+    payload: P,
+}
+
+impl<P> AggregateModel<P> {
+    #[must_use]
+    pub fn new(id: String, version: u64, payload: P) -> Self {
+        Self {
+            id,
+            version,
+            payload,
+        }
+    }
+
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    #[must_use]
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    #[must_use]
+    pub fn payload(&self) -> &P {
+        &self.payload
+    }
+
+    #[must_use]
+    pub fn into_payload(self) -> P {
+        self.payload
+    }
+}
+
+impl<P: VersionedPayload> AggregateModel<P> {
+    /// 最新のバージョンのペイロードか
+    #[must_use]
+    pub fn is_latest(&self) -> bool {
+        self.payload.is_latest()
+    }
+
+    /// ペイロードを最新のバージョンに変換する
+    #[must_use]
+    pub fn into_latest(self) -> Self {
+        Self {
+            payload: self.payload.into_latest(),
+            ..self
+        }
+    }
+}
+
+/// イベントストアのテーブルモデル
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct EventStoreModel<P> {
+    id: u64,
+    aggregate_id: String,
+    payload: P,
+    /// トレースコンテキストなどのイベントに付与する情報
+    #[serde(default)]
+    metadata: HashMap<String, String>,
+}
+
+impl<P> EventStoreModel<P> {
+    #[must_use]
+    pub fn new(id: u64, aggregate_id: String, payload: P) -> Self {
+        Self {
+            id,
+            aggregate_id,
+            payload,
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    #[must_use]
+    pub fn aggregate_id(&self) -> &str {
+        &self.aggregate_id
+    }
+
+    #[must_use]
+    pub fn payload(&self) -> &P {
+        &self.payload
+    }
+
+    #[must_use]
+    pub fn into_payload(self) -> P {
+        self.payload
+    }
+
+    #[must_use]
+    pub fn metadata(&self) -> &HashMap<String, String> {
+        &self.metadata
+    }
+
+    #[must_use]
+    pub fn metadata_mut(&mut self) -> &mut HashMap<String, String> {
+        &mut self.metadata
+    }
+}
+
+/// イベントストアの最新のIDを記録するテーブルのモデル
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EventSequenceModel {
+    aggregate_id: String,
+    /// 最新イベントIDを `version` 属性に保存していた古いレコードも読み取れるようにする
+    #[serde(alias = "version")]
+    latest_event_id: u64,
+    /// 最後にイベントを追加した時刻 (UNIXエポックからのミリ秒)
+    ///
+    /// 時刻を保存する前に作成したレコードは0になる
+    #[serde(default)]
+    updated_at: u64,
+}
+
+impl EventSequenceModel {
+    #[must_use]
+    pub fn new(aggregate_id: String, latest_event_id: u64) -> Self {
+        Self {
+            aggregate_id,
+            latest_event_id,
+            updated_at: 0,
+        }
+    }
+
+    /// 最後にイベントを追加した時刻を設定する
+    #[must_use]
+    pub fn with_updated_at(self, updated_at: u64) -> Self {
+        Self { updated_at, ..self }
+    }
+
+    #[must_use]
+    pub fn aggregate_id(&self) -> &str {
+        &self.aggregate_id
+    }
+
+    #[must_use]
+    pub fn latest_event_id(&self) -> u64 {
+        self.latest_event_id
+    }
+
+    #[must_use]
+    pub fn updated_at(&self) -> u64 {
+        self.updated_at
+    }
+
+    /// 集約を更新した順序で並べた時の位置
+    #[must_use]
+    pub fn position(&self) -> SequencePosition {
+        SequencePosition::new(self.updated_at, self.aggregate_id.clone())
+    }
+}
+
+/// イベントシーケンスを最後にイベントを追加した時刻の順序で並べた時の位置
+///
+/// 時刻が同じ場合は集約IDの順序で並べる
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SequencePosition {
+    updated_at: u64,
+    aggregate_id: String,
+}
+
+impl SequencePosition {
+    #[must_use]
+    pub fn new(updated_at: u64, aggregate_id: String) -> Self {
+        Self {
+            updated_at,
+            aggregate_id,
+        }
+    }
+
+    /// `updated_at` 以降に更新した全ての集約より前になる位置
+    #[must_use]
+    pub fn since(updated_at: u64) -> Self {
+        Self::new(updated_at, String::new())
+    }
+
+    #[must_use]
+    pub fn updated_at(&self) -> u64 {
+        self.updated_at
+    }
+
+    #[must_use]
+    pub fn aggregate_id(&self) -> &str {
+        &self.aggregate_id
+    }
+}
+
+/// 現在の時刻 (UNIXエポックからのミリ秒)
+pub(crate) fn now_millis() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |duration| {
+            u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+        })
+}
+
+/// 冪等キーのテーブルモデル
+///
+/// 同じ冪等キーのコマンドを再実行した場合に、最初に実行したコマンドの結果を返すために利用する
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct IdempotencyKeyModel {
+    key: String,
+    aggregate_id: String,
+    /// コマンドを実行した後の集約のバージョン
+    version: u64,
+    /// コマンドの結果を復元するために必要な情報
+    #[serde(default)]
+    metadata: HashMap<String, String>,
+}
+
+impl IdempotencyKeyModel {
+    #[must_use]
+    pub fn new(key: String, aggregate_id: String, version: u64) -> Self {
+        Self {
+            key,
+            aggregate_id,
+            version,
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    #[must_use]
+    pub fn aggregate_id(&self) -> &str {
+        &self.aggregate_id
+    }
+
+    #[must_use]
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    #[must_use]
+    pub fn metadata(&self) -> &HashMap<String, String> {
+        &self.metadata
+    }
+
+    #[must_use]
+    pub fn metadata_mut(&mut self) -> &mut HashMap<String, String> {
+        &mut self.metadata
+    }
+}
+
+/// トランザクショナルアウトボックスのテーブルモデル
+///
+/// イベントと同じトランザクションで保存し、リレーが他のサービスに送信した後に削除する
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct OutboxModel<P> {
+    aggregate_id: String,
+    /// 統合イベントの元になったイベントのID
+    event_id: u64,
+    payload: P,
+    /// トレースコンテキストなどの統合イベントに付与する情報
+    #[serde(default)]
+    metadata: HashMap<String, String>,
+}
+
+impl<P> OutboxModel<P> {
+    #[must_use]
+    pub fn new(aggregate_id: String, event_id: u64, payload: P) -> Self {
+        Self {
+            aggregate_id,
+            event_id,
+            payload,
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn aggregate_id(&self) -> &str {
+        &self.aggregate_id
+    }
+
+    #[must_use]
+    pub fn event_id(&self) -> u64 {
+        self.event_id
+    }
+
+    #[must_use]
+    pub fn payload(&self) -> &P {
+        &self.payload
+    }
+
+    #[must_use]
+    pub fn into_payload(self) -> P {
+        self.payload
+    }
+
+    #[must_use]
+    pub fn metadata(&self) -> &HashMap<String, String> {
+        &self.metadata
+    }
+
+    #[must_use]
+    pub fn metadata_mut(&mut self) -> &mut HashMap<String, String> {
+        &mut self.metadata
+    }
+}
+
+/// 集約のスナップショットと、スナップショット以降に発生したイベント
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AggregateWithEvents<A, E> {
+    snapshot: Option<AggregateModel<A>>,
+    events: Vec<EventStoreModel<E>>,
+}
+
+impl<A, E> AggregateWithEvents<A, E> {
+    #[must_use]
+    pub fn new(snapshot: Option<AggregateModel<A>>, events: Vec<EventStoreModel<E>>) -> Self {
+        Self { snapshot, events }
+    }
+
+    /// スナップショットと、イベントIDの昇順に並んだイベントに分解する
+    #[must_use]
+    pub fn into_parts(self) -> (Option<AggregateModel<A>>, Vec<EventStoreModel<E>>) {
+        (self.snapshot, self.events)
+    }
+}
+
+/// イベントストアが利用するテーブル名
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableNames {
+    /// 集約のスナップショットを保存するテーブル
+    pub aggregate: &'static str,
+    /// イベントを保存するテーブル
+    pub event_store: &'static str,
+    /// 集約ごとに最新のイベントIDを保存するテーブル
+    pub event_sequence: &'static str,
+    /// 冪等キーを保存するテーブル
+    pub idempotency_key: &'static str,
+    /// 他のサービスに送信する統合イベントを保存するテーブル
+    pub outbox: &'static str,
+}
@@ -0,0 +1,176 @@
+use crate::EventStoreError;
+
+/// 一覧の続きを取得する位置を表すトークン
+///
+/// 最後に返した行のキーを保持し、クライアントには内容に依存しないように16進数に符号化した文字列で渡す
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PageToken(String);
+
+impl PageToken {
+    #[must_use]
+    pub fn new(key: impl Into<String>) -> Self {
+        Self(key.into())
+    }
+
+    /// 最後に返した行のキー
+    #[must_use]
+    pub fn key(&self) -> &str {
+        &self.0
+    }
+
+    /// クライアントに渡す文字列に符号化する
+    #[must_use]
+    pub fn encode(&self) -> String {
+        use std::fmt::Write as _;
+
+        self.0.bytes().fold(String::new(), |mut token, byte| {
+            let _ = write!(token, "{byte:02x}");
+            token
+        })
+    }
+
+    /// クライアントから受け取った文字列を復号する
+    ///
+    /// # Errors
+    ///
+    /// [`PageToken::encode`] で符号化した文字列ではない場合
+    pub fn decode(token: &str) -> Result<Self, EventStoreError> {
+        let bytes = token
+            .as_bytes()
+            .chunks(2)
+            .map(|hex| {
+                std::str::from_utf8(hex)
+                    .ok()
+                    .filter(|hex| hex.len() == 2)
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                    .ok_or(EventStoreError::InvalidPageToken)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        String::from_utf8(bytes)
+            .map(Self)
+            .map_err(|_| EventStoreError::InvalidPageToken)
+    }
+}
+
+/// 一覧の1ページ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page<T> {
+    items: Vec<T>,
+    /// 続きがない場合は `None`
+    next_page_token: Option<PageToken>,
+}
+
+impl<T> Page<T> {
+    #[must_use]
+    pub fn new(items: Vec<T>, next_page_token: Option<PageToken>) -> Self {
+        Self {
+            items,
+            next_page_token,
+        }
+    }
+
+    #[must_use]
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    #[must_use]
+    pub fn next_page_token(&self) -> Option<&PageToken> {
+        self.next_page_token.as_ref()
+    }
+
+    #[must_use]
+    pub fn into_parts(self) -> (Vec<T>, Option<PageToken>) {
+        (self.items, self.next_page_token)
+    }
+
+    /// キーの昇順に並んだ全ての行から、`page_token` より後の行を最大 `page_size` 件取り出す
+    ///
+    /// `page_size` 件より後に行が残っている場合は、最後に取り出した行のキーを次のトークンにする
+    pub fn from_sorted<I>(
+        rows: I,
+        page_size: usize,
+        page_token: Option<&PageToken>,
+        key: impl Fn(&T) -> &str,
+    ) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self::from_ordered(rows, page_size, page_token, key, |key, token| key <= token)
+    }
+
+    /// キーの降順に並んだ全ての行から、`page_token` より後の行を最大 `page_size` 件取り出す
+    ///
+    /// 次のトークンは [`Page::from_sorted`] と同じく最後に取り出した行のキーにする
+    pub fn from_sorted_desc<I>(
+        rows: I,
+        page_size: usize,
+        page_token: Option<&PageToken>,
+        key: impl Fn(&T) -> &str,
+    ) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self::from_ordered(rows, page_size, page_token, key, |key, token| key >= token)
+    }
+
+    /// `is_before` がトークンより前と判定した行を読み飛ばしてページを取り出す
+    fn from_ordered<I>(
+        rows: I,
+        page_size: usize,
+        page_token: Option<&PageToken>,
+        key: impl Fn(&T) -> &str,
+        is_before: impl Fn(&str, &str) -> bool,
+    ) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut rows = rows
+            .into_iter()
+            .skip_while(|row| page_token.is_some_and(|token| is_before(key(row), token.key())));
+        let items: Vec<T> = rows.by_ref().take(page_size).collect();
+        let next_page_token = match items.last() {
+            Some(last) if rows.next().is_some() => Some(PageToken::new(key(last))),
+            _ => None,
+        };
+        Self::new(items, next_page_token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Page, PageToken};
+
+    #[test]
+    fn test_page_token() {
+        let token = PageToken::new("テナント#1");
+        assert_eq!(PageToken::decode(&token.encode()).ok(), Some(token));
+        assert!(PageToken::decode("xyz").is_err());
+        assert!(PageToken::decode("zz").is_err());
+    }
+
+    #[test]
+    fn test_page_from_sorted() {
+        let page = Page::from_sorted(["a", "b", "c"], 2, None, |row| row);
+        assert_eq!(page.items(), ["a", "b"]);
+        assert_eq!(page.next_page_token(), Some(&PageToken::new("b")));
+
+        let page = Page::from_sorted(["a", "b", "c"], 2, page.next_page_token(), |row| row);
+        assert_eq!(page.items(), ["c"]);
+        assert_eq!(
+            page.next_page_token(),
+            None,
+            "続きがない場合はトークンを返さない"
+        );
+    }
+
+    #[test]
+    fn test_page_from_sorted_desc() {
+        let page = Page::from_sorted_desc(["c", "b", "a"], 2, None, |row| row);
+        assert_eq!(page.items(), ["c", "b"]);
+
+        let page = Page::from_sorted_desc(["c", "b", "a"], 2, page.next_page_token(), |row| row);
+        assert_eq!(page.items(), ["a"]);
+        assert_eq!(page.next_page_token(), None);
+    }
+}
@@ -0,0 +1,277 @@
+use std::fmt::Debug;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::Serialize;
+use thiserror::Error;
+use tokio::io::AsyncWriteExt as _;
+use tracing::instrument;
+
+use crate::{EventStoreError, Outbox, OutboxModel};
+
+/// 統合イベントの送信に失敗した時のエラー
+pub type SinkError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// リレーが統合イベントを送信する送信先
+pub trait Sink<E> {
+    /// 統合イベントを送信する
+    ///
+    /// リレーは送信した後に統合イベントを削除するので、削除する前にプロセスが終了すると同じ統合イベントを再送する
+    fn publish(
+        &self,
+        outbox: &OutboxModel<E>,
+    ) -> impl Future<Output = Result<(), SinkError>> + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum RelayError {
+    #[error(transparent)]
+    EventStore(#[from] EventStoreError),
+    #[error("Failed to publish integration event")]
+    Sink(#[source] SinkError),
+}
+
+/// アウトボックスに保存された統合イベントを送信先に中継するリレー
+///
+/// 統合イベントは集約ごとにイベントIDの昇順で送信する。
+/// 複数のリレーを同時に実行すると同じ統合イベントを重複して送信するので、リレーは1つだけ実行する
+///
+/// `O` はアウトボックス、`S` は送信先、`E` はイベントストアのペイロード
+#[derive(Debug, Clone)]
+pub struct Relay<O, S, E> {
+    outbox: O,
+    sink: S,
+    batch_size: usize,
+    interval: Duration,
+    payload: PhantomData<fn() -> E>,
+}
+
+impl<O, S, E> Relay<O, S, E> {
+    /// Creates a new [`Relay`].
+    #[must_use]
+    pub fn new(outbox: O, sink: S) -> Self {
+        Self {
+            outbox,
+            sink,
+            batch_size: 100,
+            interval: Duration::from_secs(1),
+            payload: PhantomData,
+        }
+    }
+
+    /// 1回で中継する統合イベントの最大数を設定する
+    #[must_use]
+    pub fn with_batch_size(self, batch_size: usize) -> Self {
+        Self { batch_size, ..self }
+    }
+
+    /// 中継する統合イベントがない場合に、次に中継するまで待つ間隔を設定する
+    #[must_use]
+    pub fn with_interval(self, interval: Duration) -> Self {
+        Self { interval, ..self }
+    }
+}
+
+impl<O, S, E> Relay<O, S, E>
+where
+    O: Outbox<E> + Sync,
+    S: Sink<E> + Sync,
+    E: Debug + Send + Sync,
+{
+    /// 送信していない統合イベントを中継して、中継した統合イベントの数を返す
+    ///
+    /// 送信に失敗した場合はそれ以降の統合イベントを中継せずにエラーを返し、次に中継する時に送信し直す
+    ///
+    /// # Errors
+    #[instrument(skip(self), err, ret)]
+    pub async fn relay(&self) -> Result<usize, RelayError> {
+        let models = self.outbox.list_outbox(self.batch_size).await?;
+        for model in &models {
+            self.sink.publish(model).await.map_err(RelayError::Sink)?;
+            self.outbox
+                .delete_outbox(model.aggregate_id(), model.event_id())
+                .await?;
+        }
+        Ok(models.len())
+    }
+
+    /// `shutdown` が完了するまで統合イベントを中継し続ける
+    ///
+    /// 中継に失敗した場合はエラーを記録して、間隔を空けてから中継し直す
+    pub async fn run(&self, shutdown: impl Future<Output = ()>) {
+        let mut shutdown = std::pin::pin!(shutdown);
+        loop {
+            match self.relay().await {
+                // NOTE: 中継する統合イベントが残っている可能性があるので待たずに中継する
+                Ok(count) if count >= self.batch_size => continue,
+                Ok(_) => {}
+                Err(e) => tracing::error!("failed to relay outbox: {e:?}"),
+            }
+            tokio::select! {
+                () = &mut shutdown => return,
+                () = tokio::time::sleep(self.interval) => {}
+            }
+        }
+    }
+}
+
+/// 同じプロセスのチャネルに統合イベントを送信する送信先
+#[derive(Debug, Clone)]
+pub struct ChannelSink<E> {
+    sender: tokio::sync::mpsc::Sender<OutboxModel<E>>,
+}
+
+impl<E> ChannelSink<E> {
+    /// Creates a new [`ChannelSink`].
+    #[must_use]
+    pub fn new(sender: tokio::sync::mpsc::Sender<OutboxModel<E>>) -> Self {
+        Self { sender }
+    }
+}
+
+impl<E> Sink<E> for ChannelSink<E>
+where
+    E: Clone + Send + Sync,
+{
+    async fn publish(&self, outbox: &OutboxModel<E>) -> Result<(), SinkError> {
+        self.sender
+            .send(outbox.clone())
+            .await
+            .map_err(|e| e.to_string().into())
+    }
+}
+
+/// ファイルに統合イベントを JSON Lines 形式で追記する送信先
+///
+/// 他のサービスを起動せずに統合イベントを確認する場合に利用する
+#[derive(Debug, Clone)]
+pub struct FileSink {
+    path: PathBuf,
+}
+
+impl FileSink {
+    /// Creates a new [`FileSink`].
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl<E> Sink<E> for FileSink
+where
+    E: Serialize + Sync,
+{
+    async fn publish(&self, outbox: &OutboxModel<E>) -> Result<(), SinkError> {
+        let mut line = serde_json::to_vec(outbox)?;
+        line.push(b'\n');
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await?;
+        file.write_all(&line).await?;
+        file.flush().await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::in_memory::InMemoryEventStore;
+    use crate::{AggregateModel, EventStore as _, Outbox as _, OutboxModel};
+
+    use super::{ChannelSink, FileSink, Relay};
+
+    type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    const AGGREGATE_ID: &str = "aggregate";
+
+    async fn event_store() -> Result<InMemoryEventStore<u64, String>, Error> {
+        let event_store = InMemoryEventStore::new();
+        event_store
+            .create(
+                AggregateModel::new(AGGREGATE_ID.to_string(), 1, 1),
+                "created".to_string(),
+                Vec::new(),
+            )
+            .await?;
+        event_store
+            .update(
+                AggregateModel::new(AGGREGATE_ID.to_string(), 3, 3),
+                vec!["updated".to_string(), "placed".to_string()],
+                None,
+                vec![OutboxModel::new(
+                    AGGREGATE_ID.to_string(),
+                    2,
+                    "placed".to_string(),
+                )],
+            )
+            .await?;
+        Ok(event_store)
+    }
+
+    #[tokio::test]
+    async fn test_relay_channel_sink() -> Result<(), Error> {
+        let event_store = event_store().await?;
+        let (sender, mut receiver) = tokio::sync::mpsc::channel(1);
+        let relay = Relay::new(event_store.clone(), ChannelSink::new(sender));
+
+        assert_eq!(relay.relay().await?, 1);
+        assert_eq!(
+            receiver.recv().await,
+            Some(OutboxModel::new(
+                AGGREGATE_ID.to_string(),
+                2,
+                "placed".to_string()
+            ))
+        );
+        assert!(
+            event_store.list_outbox(10).await?.is_empty(),
+            "送信した統合イベントは削除する"
+        );
+        assert_eq!(relay.relay().await?, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_relay_channel_sink_closed() -> Result<(), Error> {
+        let event_store = event_store().await?;
+        let (sender, receiver) = tokio::sync::mpsc::channel(1);
+        drop(receiver);
+        let relay = Relay::new(event_store.clone(), ChannelSink::new(sender));
+
+        assert!(relay.relay().await.is_err());
+        assert_eq!(
+            event_store.list_outbox(10).await?.len(),
+            1,
+            "送信に失敗した統合イベントは削除しない"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_relay_file_sink() -> Result<(), Error> {
+        let event_store = event_store().await?;
+        let path = std::env::temp_dir().join(format!("outbox-{}.jsonl", std::process::id()));
+        let relay = Relay::new(event_store, FileSink::new(&path));
+
+        assert_eq!(relay.relay().await?, 1);
+        let lines = tokio::fs::read_to_string(&path).await?;
+        tokio::fs::remove_file(&path).await?;
+        let models = lines
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<OutboxModel<String>>, _>>()?;
+        assert_eq!(
+            models,
+            vec![OutboxModel::new(
+                AGGREGATE_ID.to_string(),
+                2,
+                "placed".to_string()
+            )]
+        );
+        Ok(())
+    }
+}
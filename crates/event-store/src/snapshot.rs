@@ -0,0 +1,96 @@
+use std::num::NonZeroU64;
+
+/// 集約のスナップショットを保存する方針
+///
+/// 集約はスナップショットとその後のイベントから復元するので、スナップショットの頻度を下げても結果は変わらず、
+/// 復元するときに読み取るイベントの数が増える
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotPolicy {
+    /// 指定した数のイベントが発生するたびにスナップショットを保存する
+    EveryEvents(NonZeroU64),
+    /// [`EventStore::save_snapshot`] を呼び出したときだけスナップショットを保存する
+    ///
+    /// [`EventStore::save_snapshot`]: crate::EventStore::save_snapshot
+    OnDemand,
+}
+
+impl SnapshotPolicy {
+    /// 集約のバージョンが `from` から `to` に進んだときにスナップショットを保存するか
+    #[must_use]
+    pub fn should_snapshot(self, from: u64, to: u64) -> bool {
+        match self {
+            Self::EveryEvents(frequency) => from / frequency != to / frequency,
+            Self::OnDemand => false,
+        }
+    }
+}
+
+impl Default for SnapshotPolicy {
+    fn default() -> Self {
+        Self::EveryEvents(NonZeroU64::MIN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SnapshotPolicy;
+
+    type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    #[test]
+    fn test_should_snapshot() -> Result<(), Error> {
+        struct TestCase {
+            name: &'static str,
+            snapshot_policy: SnapshotPolicy,
+            from: u64,
+            to: u64,
+            expected: bool,
+        }
+
+        let tests = [
+            TestCase {
+                name: "イベントごとに保存する場合はバージョンが進むと保存する",
+                snapshot_policy: SnapshotPolicy::default(),
+                from: 1,
+                to: 2,
+                expected: true,
+            },
+            TestCase {
+                name: "バージョンが頻度の倍数を跨いだ場合は保存する",
+                snapshot_policy: SnapshotPolicy::EveryEvents(10.try_into()?),
+                from: 9,
+                to: 11,
+                expected: true,
+            },
+            TestCase {
+                name: "バージョンが頻度の倍数を跨がない場合は保存しない",
+                snapshot_policy: SnapshotPolicy::EveryEvents(10.try_into()?),
+                from: 10,
+                to: 19,
+                expected: false,
+            },
+            TestCase {
+                name: "オンデマンドの場合は保存しない",
+                snapshot_policy: SnapshotPolicy::OnDemand,
+                from: 1,
+                to: 2,
+                expected: false,
+            },
+        ];
+        for TestCase {
+            name,
+            snapshot_policy,
+            from,
+            to,
+            expected,
+        } in tests
+        {
+            assert_eq!(
+                snapshot_policy.should_snapshot(from, to),
+                expected,
+                "{name}"
+            );
+        }
+        Ok(())
+    }
+}
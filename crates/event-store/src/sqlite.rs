@@ -0,0 +1,1147 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use sqlx::sqlite::{SqlitePool, SqliteRow};
+use sqlx::types::Json;
+use sqlx::{Row as _, Sqlite, Transaction};
+use tracing::instrument;
+
+use crate::model::now_millis;
+use crate::{
+    AggregateModel, AggregateWithEvents, EventLog, EventSequenceModel, EventStore, EventStoreError,
+    EventStoreModel, IdempotencyKeyModel, Outbox, OutboxModel, Page, PageToken, SequencePosition,
+    SnapshotPolicy, TableNames,
+};
+
+/// `SQLite` をバックエンドにしたイベントストア
+///
+/// 1つのファイルにデータを保存するので、インフラを用意せずに単一ノードで動かす場合に利用する。
+/// イベントの追加・イベントシーケンスの更新・スナップショットの更新を1つのトランザクションで行い、
+/// イベントテーブルの `(aggregate_id, id)` の一意制約で楽観的排他制御を行う
+///
+/// `A` は集約テーブルのペイロード、`E` はイベントストアのペイロード
+#[derive(Debug, Clone)]
+pub struct SqliteEventStore<A, E> {
+    pool: SqlitePool,
+    tables: TableNames,
+    snapshot_policy: SnapshotPolicy,
+    payload: PhantomData<fn() -> (A, E)>,
+}
+
+impl<A, E> SqliteEventStore<A, E> {
+    /// Creates a new [`SqliteEventStore`].
+    #[must_use]
+    pub fn new(pool: SqlitePool, tables: TableNames) -> Self {
+        Self {
+            pool,
+            tables,
+            snapshot_policy: SnapshotPolicy::default(),
+            payload: PhantomData,
+        }
+    }
+
+    /// スナップショットを保存する方針を設定する
+    #[must_use]
+    pub fn with_snapshot_policy(self, snapshot_policy: SnapshotPolicy) -> Self {
+        Self {
+            snapshot_policy,
+            ..self
+        }
+    }
+
+    /// イベントストアが利用するテーブルが存在しない場合に作成する
+    ///
+    /// # Errors
+    #[instrument(skip(self), err)]
+    pub async fn create_tables(&self) -> Result<(), EventStoreError> {
+        let TableNames {
+            aggregate,
+            event_store,
+            event_sequence,
+            idempotency_key,
+            outbox,
+        } = self.tables;
+        let statements = [
+            format!(
+                r#"CREATE TABLE IF NOT EXISTS "{event_store}" (
+                    aggregate_id TEXT NOT NULL,
+                    id INTEGER NOT NULL,
+                    payload TEXT NOT NULL,
+                    metadata TEXT NOT NULL DEFAULT '{{}}',
+                    PRIMARY KEY (aggregate_id, id)
+                )"#
+            ),
+            format!(
+                r#"CREATE TABLE IF NOT EXISTS "{event_sequence}" (
+                    aggregate_id TEXT PRIMARY KEY,
+                    latest_event_id INTEGER NOT NULL,
+                    updated_at INTEGER NOT NULL DEFAULT 0
+                )"#
+            ),
+            format!(
+                r#"CREATE TABLE IF NOT EXISTS "{aggregate}" (
+                    id TEXT PRIMARY KEY,
+                    version INTEGER NOT NULL,
+                    payload TEXT NOT NULL
+                )"#
+            ),
+            format!(
+                r#"CREATE TABLE IF NOT EXISTS "{idempotency_key}" (
+                    key TEXT PRIMARY KEY,
+                    aggregate_id TEXT NOT NULL,
+                    version INTEGER NOT NULL,
+                    metadata TEXT NOT NULL DEFAULT '{{}}'
+                )"#
+            ),
+            format!(
+                r#"CREATE TABLE IF NOT EXISTS "{outbox}" (
+                    aggregate_id TEXT NOT NULL,
+                    event_id INTEGER NOT NULL,
+                    payload TEXT NOT NULL,
+                    metadata TEXT NOT NULL DEFAULT '{{}}',
+                    PRIMARY KEY (aggregate_id, event_id)
+                )"#
+            ),
+        ];
+        for statement in statements {
+            sqlx::query(&statement)
+                .execute(&self.pool)
+                .await
+                .map_err(|e| EventStoreError::Unknown(e.into()))?;
+        }
+        // NOTE: 更新した時刻の列を追加する前に作成したテーブルには列を追加する
+        //       `SQLite` は `ADD COLUMN IF NOT EXISTS` に対応していないので、列の有無を調べる
+        let has_updated_at: bool = sqlx::query_scalar(
+            "SELECT COUNT(*) > 0 FROM pragma_table_info(?1) WHERE name = 'updated_at'",
+        )
+        .bind(event_sequence)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| EventStoreError::Unknown(e.into()))?;
+        if !has_updated_at {
+            sqlx::query(&format!(
+                r#"ALTER TABLE "{event_sequence}" ADD COLUMN updated_at INTEGER NOT NULL DEFAULT 0"#
+            ))
+            .execute(&self.pool)
+            .await
+            .map_err(|e| EventStoreError::Unknown(e.into()))?;
+        }
+        sqlx::query(&format!(
+            r#"CREATE INDEX IF NOT EXISTS "{event_sequence}-updated-at" ON "{event_sequence}" (updated_at, aggregate_id)"#
+        ))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| EventStoreError::Unknown(e.into()))?;
+        Ok(())
+    }
+}
+
+impl<A, E> SqliteEventStore<A, E>
+where
+    A: Serialize + DeserializeOwned + Debug + Send + Sync + Unpin + 'static,
+    E: Serialize + DeserializeOwned + Debug + Send + Sync + Unpin + 'static,
+{
+    async fn begin(&self) -> Result<Transaction<'static, Sqlite>, EventStoreError> {
+        self.pool
+            .begin()
+            .await
+            .map_err(|e| EventStoreError::Unknown(e.into()))
+    }
+
+    /// イベントをイベントストアに追加する
+    ///
+    /// 同じイベントIDのイベントが既に存在する場合は一意制約に違反してエラーになる
+    async fn insert_event(
+        &self,
+        transaction: &mut Transaction<'static, Sqlite>,
+        event: EventStoreModel<E>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(&format!(
+            r#"INSERT INTO "{}" (aggregate_id, id, payload, metadata) VALUES (?1, ?2, ?3, ?4)"#,
+            self.tables.event_store
+        ))
+        .bind(event.aggregate_id())
+        .bind(i64::try_from(event.id()).map_err(|e| sqlx::Error::Encode(e.into()))?)
+        .bind(Json(event.payload()))
+        .bind(Json(event.metadata()))
+        .execute(&mut **transaction)
+        .await?;
+        Ok(())
+    }
+
+    /// 冪等キーを保存する
+    ///
+    /// 同じ冪等キーが既に存在する場合は一意制約に違反してエラーになる
+    async fn insert_idempotency_key(
+        &self,
+        transaction: &mut Transaction<'static, Sqlite>,
+        idempotency_key: IdempotencyKeyModel,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(&format!(
+            r#"INSERT INTO "{}" (key, aggregate_id, version, metadata) VALUES (?1, ?2, ?3, ?4)"#,
+            self.tables.idempotency_key
+        ))
+        .bind(idempotency_key.key())
+        .bind(idempotency_key.aggregate_id())
+        .bind(i64::try_from(idempotency_key.version()).map_err(|e| sqlx::Error::Encode(e.into()))?)
+        .bind(Json(idempotency_key.metadata()))
+        .execute(&mut **transaction)
+        .await?;
+        Ok(())
+    }
+
+    /// 統合イベントをアウトボックスに追加する
+    async fn insert_outbox(
+        &self,
+        transaction: &mut Transaction<'static, Sqlite>,
+        outbox: OutboxModel<E>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(&format!(
+            r#"INSERT INTO "{}" (aggregate_id, event_id, payload, metadata) VALUES (?1, ?2, ?3, ?4)"#,
+            self.tables.outbox
+        ))
+        .bind(outbox.aggregate_id())
+        .bind(i64::try_from(outbox.event_id()).map_err(|e| sqlx::Error::Encode(e.into()))?)
+        .bind(Json(outbox.payload()))
+        .bind(Json(outbox.metadata()))
+        .execute(&mut **transaction)
+        .await?;
+        Ok(())
+    }
+
+    async fn commit(transaction: Transaction<'static, Sqlite>) -> Result<(), EventStoreError> {
+        transaction
+            .commit()
+            .await
+            .map_err(|e| EventStoreError::Unknown(e.into()))
+    }
+}
+
+impl<A, E> EventStore<A, E> for SqliteEventStore<A, E>
+where
+    A: Serialize + DeserializeOwned + Debug + Send + Sync + Unpin + 'static,
+    E: Serialize + DeserializeOwned + Debug + Send + Sync + Unpin + 'static,
+{
+    #[instrument(skip(self), err, ret)]
+    async fn create(
+        &self,
+        aggregate: AggregateModel<A>,
+        event: E,
+        idempotency_keys: Vec<IdempotencyKeyModel>,
+    ) -> Result<(), EventStoreError> {
+        let mut transaction = self.begin().await?;
+        for idempotency_key in idempotency_keys {
+            self.insert_idempotency_key(&mut transaction, idempotency_key)
+                .await
+                .map_err(update_error)?;
+        }
+        self.insert_event(
+            &mut transaction,
+            EventStoreModel::new(0, aggregate.id().to_string(), event),
+        )
+        .await
+        .map_err(|e| EventStoreError::Unknown(e.into()))?;
+        sqlx::query(&format!(
+            r#"INSERT INTO "{}" (aggregate_id, latest_event_id, updated_at) VALUES (?1, 0, ?2)"#,
+            self.tables.event_sequence
+        ))
+        .bind(aggregate.id())
+        .bind(to_i64(now_millis())?)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| EventStoreError::Unknown(e.into()))?;
+        sqlx::query(&format!(
+            r#"INSERT INTO "{}" (id, version, payload) VALUES (?1, ?2, ?3)"#,
+            self.tables.aggregate
+        ))
+        .bind(aggregate.id())
+        .bind(to_i64(aggregate.version())?)
+        .bind(Json(aggregate.payload()))
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| EventStoreError::Unknown(e.into()))?;
+        Self::commit(transaction).await
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn get(
+        &self,
+        aggregate_id: &str,
+    ) -> Result<Option<AggregateWithEvents<A, E>>, EventStoreError> {
+        // NOTE: バージョンNのスナップショットはイベントIDが0からN-1までのイベントを適用した状態なので、イベントIDがN以降のイベントだけを取得する
+        let snapshot = self.get_aggregate(aggregate_id).await?;
+        let from_event_id = snapshot.as_ref().map_or(0, AggregateModel::version);
+        let events = sqlx::query(&format!(
+            r#"SELECT aggregate_id, id, payload, metadata FROM "{}" WHERE aggregate_id = ?1 AND id >= ?2 ORDER BY id"#,
+            self.tables.event_store
+        ))
+        .bind(aggregate_id)
+        .bind(to_i64(from_event_id)?)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| EventStoreError::Unknown(e.into()))?
+        .iter()
+        .map(event_store_model)
+        .collect::<Result<Vec<_>, _>>()?;
+        if snapshot.is_none() && events.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(AggregateWithEvents::new(snapshot, events)))
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn update(
+        &self,
+        aggregate: AggregateModel<A>,
+        events: Vec<E>,
+        idempotency_key: Option<IdempotencyKeyModel>,
+        outbox: Vec<OutboxModel<E>>,
+    ) -> Result<(), EventStoreError> {
+        if events.is_empty() {
+            return Err(EventStoreError::EmptyEvents);
+        }
+
+        // NOTE: この時点の集約はイベントを適用済みなので、イベントを適用する前のバージョンを求める
+        let current_version = u64::try_from(events.len())
+            .ok()
+            .and_then(|len| aggregate.version().checked_sub(len))
+            .ok_or(EventStoreError::InvalidAggregateVersion)?;
+        let current_latest_event_id = current_version
+            .checked_sub(1)
+            .ok_or(EventStoreError::InvalidAggregateVersion)?;
+
+        let mut transaction = self.begin().await?;
+        if let Some(idempotency_key) = idempotency_key {
+            self.insert_idempotency_key(&mut transaction, idempotency_key)
+                .await
+                .map_err(update_error)?;
+        }
+        let mut new_event_id = current_latest_event_id;
+        for event in events {
+            new_event_id += 1;
+            self.insert_event(
+                &mut transaction,
+                EventStoreModel::new(new_event_id, aggregate.id().to_string(), event),
+            )
+            .await
+            .map_err(update_error)?;
+        }
+        // NOTE: 一意制約で同じイベントIDの追加は防がれるが、イベントシーケンスの最新イベントIDでも楽観的排他制御を行う
+        let result = sqlx::query(&format!(
+            r#"UPDATE "{}" SET latest_event_id = ?3, updated_at = ?4 WHERE aggregate_id = ?1 AND latest_event_id = ?2"#,
+            self.tables.event_sequence
+        ))
+        .bind(aggregate.id())
+        .bind(to_i64(current_latest_event_id)?)
+        .bind(to_i64(new_event_id)?)
+        .bind(to_i64(now_millis())?)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| EventStoreError::Unknown(e.into()))?;
+        if result.rows_affected() != 1 {
+            let exists = sqlx::query(&format!(
+                r#"SELECT aggregate_id FROM "{}" WHERE aggregate_id = ?1"#,
+                self.tables.event_sequence
+            ))
+            .bind(aggregate.id())
+            .fetch_optional(&mut *transaction)
+            .await
+            .map_err(|e| EventStoreError::Unknown(e.into()))?
+            .is_some();
+            if exists {
+                return Err(EventStoreError::ConcurrencyConflict);
+            }
+            return Err(EventStoreError::Unknown(
+                format!("aggregate not found: {}", aggregate.id()).into(),
+            ));
+        }
+        if self
+            .snapshot_policy
+            .should_snapshot(current_version, aggregate.version())
+        {
+            let result = sqlx::query(&format!(
+                r#"UPDATE "{}" SET version = ?2, payload = ?3 WHERE id = ?1 AND version < ?2"#,
+                self.tables.aggregate
+            ))
+            .bind(aggregate.id())
+            .bind(to_i64(aggregate.version())?)
+            .bind(Json(aggregate.payload()))
+            .execute(&mut *transaction)
+            .await
+            .map_err(|e| EventStoreError::Unknown(e.into()))?;
+            if result.rows_affected() != 1 {
+                return Err(EventStoreError::ConcurrencyConflict);
+            }
+        }
+        for model in outbox {
+            self.insert_outbox(&mut transaction, model)
+                .await
+                .map_err(|e| EventStoreError::Unknown(e.into()))?;
+        }
+        Self::commit(transaction).await
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn save_snapshot(&self, aggregate: AggregateModel<A>) -> Result<(), EventStoreError> {
+        let table = self.tables.aggregate;
+        let result = sqlx::query(&format!(
+            r#"INSERT INTO "{table}" (id, version, payload) VALUES (?1, ?2, ?3)
+               ON CONFLICT (id) DO UPDATE SET version = EXCLUDED.version, payload = EXCLUDED.payload
+               WHERE "{table}".version < EXCLUDED.version"#
+        ))
+        .bind(aggregate.id())
+        .bind(to_i64(aggregate.version())?)
+        .bind(Json(aggregate.payload()))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| EventStoreError::Unknown(e.into()))?;
+        if result.rows_affected() != 1 {
+            return Err(EventStoreError::Unknown(
+                format!(
+                    "snapshot of aggregate {} is not older than the aggregate",
+                    aggregate.id()
+                )
+                .into(),
+            ));
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn get_aggregate(
+        &self,
+        aggregate_id: &str,
+    ) -> Result<Option<AggregateModel<A>>, EventStoreError> {
+        sqlx::query(&format!(
+            r#"SELECT id, version, payload FROM "{}" WHERE id = ?1"#,
+            self.tables.aggregate
+        ))
+        .bind(aggregate_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| EventStoreError::Unknown(e.into()))?
+        .as_ref()
+        .map(aggregate_model)
+        .transpose()
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn get_idempotency_key(
+        &self,
+        key: &str,
+    ) -> Result<Option<IdempotencyKeyModel>, EventStoreError> {
+        sqlx::query(&format!(
+            r#"SELECT key, aggregate_id, version, metadata FROM "{}" WHERE key = ?1"#,
+            self.tables.idempotency_key
+        ))
+        .bind(key)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| EventStoreError::Unknown(e.into()))?
+        .as_ref()
+        .map(idempotency_key_model)
+        .transpose()
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn list_aggregates(&self) -> Result<Vec<AggregateModel<A>>, EventStoreError> {
+        sqlx::query(&format!(
+            r#"SELECT id, version, payload FROM "{}""#,
+            self.tables.aggregate
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| EventStoreError::Unknown(e.into()))?
+        .iter()
+        .map(aggregate_model)
+        .collect()
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn list_aggregates_page(
+        &self,
+        page_size: usize,
+        page_token: Option<&PageToken>,
+    ) -> Result<Page<AggregateModel<A>>, EventStoreError> {
+        // NOTE: 続きがあるかを判定するために1件多く取得する
+        let mut aggregates = sqlx::query(&format!(
+            r#"SELECT id, version, payload FROM "{}" WHERE id > ?1 ORDER BY id LIMIT ?2"#,
+            self.tables.aggregate
+        ))
+        .bind(page_token.map_or("", PageToken::key))
+        .bind(i64::try_from(page_size + 1).map_err(|e| EventStoreError::Unknown(e.into()))?)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| EventStoreError::Unknown(e.into()))?
+        .iter()
+        .map(aggregate_model)
+        .collect::<Result<Vec<_>, _>>()?;
+        let next_page_token = if aggregates.len() > page_size {
+            aggregates.truncate(page_size);
+            aggregates.last().map(|last| PageToken::new(last.id()))
+        } else {
+            None
+        };
+        Ok(Page::new(aggregates, next_page_token))
+    }
+}
+
+impl<A, E> Outbox<E> for SqliteEventStore<A, E>
+where
+    A: Send + Sync,
+    E: Serialize + DeserializeOwned + Debug + Send + Sync + Unpin + 'static,
+{
+    #[instrument(skip(self), err, ret)]
+    async fn list_outbox(&self, limit: usize) -> Result<Vec<OutboxModel<E>>, EventStoreError> {
+        sqlx::query(&format!(
+            r#"SELECT aggregate_id, event_id, payload, metadata FROM "{}" ORDER BY aggregate_id, event_id LIMIT ?1"#,
+            self.tables.outbox
+        ))
+        .bind(i64::try_from(limit).map_err(|e| EventStoreError::Unknown(e.into()))?)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| EventStoreError::Unknown(e.into()))?
+        .iter()
+        .map(outbox_model)
+        .collect()
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn delete_outbox(
+        &self,
+        aggregate_id: &str,
+        event_id: u64,
+    ) -> Result<(), EventStoreError> {
+        sqlx::query(&format!(
+            r#"DELETE FROM "{}" WHERE aggregate_id = ?1 AND event_id = ?2"#,
+            self.tables.outbox
+        ))
+        .bind(aggregate_id)
+        .bind(to_i64(event_id)?)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| EventStoreError::Unknown(e.into()))?;
+        Ok(())
+    }
+}
+
+/// イベントを追加する時のエラーを [`EventStoreError`] に変換する
+///
+/// 一意制約に違反した場合は、他の処理が先に同じイベントIDのイベントを追加したとみなす
+fn update_error(e: sqlx::Error) -> EventStoreError {
+    if e.as_database_error()
+        .is_some_and(sqlx::error::DatabaseError::is_unique_violation)
+    {
+        return EventStoreError::ConcurrencyConflict;
+    }
+    EventStoreError::Unknown(e.into())
+}
+
+impl<A, E> EventLog<E> for SqliteEventStore<A, E>
+where
+    A: Send + Sync,
+    E: Serialize + DeserializeOwned + Debug + Send + Sync + Unpin + 'static,
+{
+    #[instrument(skip(self), err, ret)]
+    async fn list_sequences(
+        &self,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<EventSequenceModel>, EventStoreError> {
+        // NOTE: `after` を指定しない場合は全ての集約IDより前になる位置から読み取る
+        sqlx::query(&format!(
+            r#"SELECT aggregate_id, latest_event_id, updated_at FROM "{}" WHERE aggregate_id > ?1 ORDER BY aggregate_id LIMIT ?2"#,
+            self.tables.event_sequence
+        ))
+        .bind(after.unwrap_or_default())
+        .bind(i64::try_from(limit).map_err(|e| EventStoreError::Unknown(e.into()))?)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| EventStoreError::Unknown(e.into()))?
+        .iter()
+        .map(sequence_from_row)
+        .collect()
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn list_sequences_since(
+        &self,
+        after: &SequencePosition,
+        limit: usize,
+    ) -> Result<Vec<EventSequenceModel>, EventStoreError> {
+        sqlx::query(&format!(
+            r#"SELECT aggregate_id, latest_event_id, updated_at FROM "{}" WHERE (updated_at, aggregate_id) > (?1, ?2) ORDER BY updated_at, aggregate_id LIMIT ?3"#,
+            self.tables.event_sequence
+        ))
+        .bind(to_i64(after.updated_at())?)
+        .bind(after.aggregate_id())
+        .bind(i64::try_from(limit).map_err(|e| EventStoreError::Unknown(e.into()))?)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| EventStoreError::Unknown(e.into()))?
+        .iter()
+        .map(sequence_from_row)
+        .collect()
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn list_events(
+        &self,
+        aggregate_id: &str,
+        after: Option<u64>,
+        limit: usize,
+    ) -> Result<Vec<EventStoreModel<E>>, EventStoreError> {
+        // NOTE: `after` を指定しない場合は全てのイベントIDより前になる位置から読み取る
+        let after = match after {
+            Some(event_id) => to_i64(event_id)?,
+            None => -1,
+        };
+        sqlx::query(&format!(
+            r#"SELECT aggregate_id, id, payload, metadata FROM "{}" WHERE aggregate_id = ?1 AND id > ?2 ORDER BY id LIMIT ?3"#,
+            self.tables.event_store
+        ))
+        .bind(aggregate_id)
+        .bind(after)
+        .bind(i64::try_from(limit).map_err(|e| EventStoreError::Unknown(e.into()))?)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| EventStoreError::Unknown(e.into()))?
+        .iter()
+        .map(event_store_model)
+        .collect()
+    }
+}
+
+fn to_i64(value: u64) -> Result<i64, EventStoreError> {
+    i64::try_from(value).map_err(|e| EventStoreError::Unknown(e.into()))
+}
+
+fn to_u64(value: i64) -> Result<u64, EventStoreError> {
+    u64::try_from(value).map_err(|e| EventStoreError::Unknown(e.into()))
+}
+
+fn sequence_from_row(row: &SqliteRow) -> Result<EventSequenceModel, EventStoreError> {
+    let aggregate_id: String = row
+        .try_get("aggregate_id")
+        .map_err(|e| EventStoreError::Unknown(e.into()))?;
+    let latest_event_id: i64 = row
+        .try_get("latest_event_id")
+        .map_err(|e| EventStoreError::Unknown(e.into()))?;
+    let updated_at: i64 = row
+        .try_get("updated_at")
+        .map_err(|e| EventStoreError::Unknown(e.into()))?;
+    Ok(
+        EventSequenceModel::new(aggregate_id, to_u64(latest_event_id)?)
+            .with_updated_at(to_u64(updated_at)?),
+    )
+}
+
+fn aggregate_model<A>(row: &SqliteRow) -> Result<AggregateModel<A>, EventStoreError>
+where
+    A: DeserializeOwned + Send + Unpin + 'static,
+{
+    let id: String = row
+        .try_get("id")
+        .map_err(|e| EventStoreError::Unknown(e.into()))?;
+    let version: i64 = row
+        .try_get("version")
+        .map_err(|e| EventStoreError::Unknown(e.into()))?;
+    let Json(payload): Json<A> = row
+        .try_get("payload")
+        .map_err(|e| EventStoreError::Unknown(e.into()))?;
+    Ok(AggregateModel::new(id, to_u64(version)?, payload))
+}
+
+fn event_store_model<E>(row: &SqliteRow) -> Result<EventStoreModel<E>, EventStoreError>
+where
+    E: DeserializeOwned + Send + Unpin + 'static,
+{
+    let aggregate_id: String = row
+        .try_get("aggregate_id")
+        .map_err(|e| EventStoreError::Unknown(e.into()))?;
+    let id: i64 = row
+        .try_get("id")
+        .map_err(|e| EventStoreError::Unknown(e.into()))?;
+    let Json(payload): Json<E> = row
+        .try_get("payload")
+        .map_err(|e| EventStoreError::Unknown(e.into()))?;
+    let Json(metadata): Json<HashMap<String, String>> = row
+        .try_get("metadata")
+        .map_err(|e| EventStoreError::Unknown(e.into()))?;
+    let mut model = EventStoreModel::new(to_u64(id)?, aggregate_id, payload);
+    *model.metadata_mut() = metadata;
+    Ok(model)
+}
+
+fn idempotency_key_model(row: &SqliteRow) -> Result<IdempotencyKeyModel, EventStoreError> {
+    let key: String = row
+        .try_get("key")
+        .map_err(|e| EventStoreError::Unknown(e.into()))?;
+    let aggregate_id: String = row
+        .try_get("aggregate_id")
+        .map_err(|e| EventStoreError::Unknown(e.into()))?;
+    let version: i64 = row
+        .try_get("version")
+        .map_err(|e| EventStoreError::Unknown(e.into()))?;
+    let Json(metadata): Json<HashMap<String, String>> = row
+        .try_get("metadata")
+        .map_err(|e| EventStoreError::Unknown(e.into()))?;
+    let mut model = IdempotencyKeyModel::new(key, aggregate_id, to_u64(version)?);
+    *model.metadata_mut() = metadata;
+    Ok(model)
+}
+
+fn outbox_model<E>(row: &SqliteRow) -> Result<OutboxModel<E>, EventStoreError>
+where
+    E: DeserializeOwned + Send + Unpin + 'static,
+{
+    let aggregate_id: String = row
+        .try_get("aggregate_id")
+        .map_err(|e| EventStoreError::Unknown(e.into()))?;
+    let event_id: i64 = row
+        .try_get("event_id")
+        .map_err(|e| EventStoreError::Unknown(e.into()))?;
+    let Json(payload): Json<E> = row
+        .try_get("payload")
+        .map_err(|e| EventStoreError::Unknown(e.into()))?;
+    let Json(metadata): Json<HashMap<String, String>> = row
+        .try_get("metadata")
+        .map_err(|e| EventStoreError::Unknown(e.into()))?;
+    let mut model = OutboxModel::new(aggregate_id, to_u64(event_id)?, payload);
+    *model.metadata_mut() = metadata;
+    Ok(model)
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    use crate::{
+        AggregateModel, EventLog, EventSequenceModel, EventStore, EventStoreError, EventStoreModel,
+        IdempotencyKeyModel, Outbox, OutboxModel, PageToken, SequencePosition, SnapshotPolicy,
+        TableNames,
+    };
+
+    use super::SqliteEventStore;
+
+    type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    const TABLE_NAMES: TableNames = TableNames {
+        aggregate: "test-aggregate",
+        event_store: "test-event-store",
+        event_sequence: "test-event-sequence",
+        idempotency_key: "test-idempotency-key",
+        outbox: "test-outbox",
+    };
+
+    #[tokio::test]
+    async fn test_update() -> Result<(), Error> {
+        let event_store = event_store().await?;
+
+        event_store
+            .create(
+                AggregateModel::new("1".to_string(), 1, 1),
+                "created".to_string(),
+                Vec::new(),
+            )
+            .await?;
+        assert!(
+            event_store
+                .create(
+                    AggregateModel::new("1".to_string(), 1, 1),
+                    "created".to_string(),
+                    Vec::new()
+                )
+                .await
+                .is_err(),
+            "同じ集約を作成できない"
+        );
+        event_store
+            .update(
+                AggregateModel::new("1".to_string(), 3, 3),
+                vec!["updated".to_string(); 2],
+                None,
+                Vec::new(),
+            )
+            .await?;
+        assert!(
+            matches!(
+                event_store
+                    .update(
+                        AggregateModel::new("1".to_string(), 3, 3),
+                        vec!["updated".to_string()],
+                        None,
+                        Vec::new()
+                    )
+                    .await,
+                Err(EventStoreError::ConcurrencyConflict)
+            ),
+            "古いバージョンの集約は更新できない"
+        );
+        assert!(
+            matches!(
+                event_store
+                    .update(
+                        AggregateModel::new("2".to_string(), 2, 2),
+                        vec!["updated".to_string()],
+                        None,
+                        Vec::new()
+                    )
+                    .await,
+                Err(EventStoreError::Unknown(_))
+            ),
+            "存在しない集約は競合とみなさない"
+        );
+        let (snapshot, events) = event_store
+            .get("1")
+            .await?
+            .ok_or("aggregate not found")?
+            .into_parts();
+        assert_eq!(snapshot, Some(AggregateModel::new("1".to_string(), 3, 3)));
+        assert!(events.is_empty());
+        assert_eq!(event_store.list_aggregates().await?.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_update_on_demand_snapshot() -> Result<(), Error> {
+        let event_store = event_store()
+            .await?
+            .with_snapshot_policy(SnapshotPolicy::OnDemand);
+
+        event_store
+            .create(
+                AggregateModel::new("1".to_string(), 1, 1),
+                "created".to_string(),
+                Vec::new(),
+            )
+            .await?;
+        event_store
+            .update(
+                AggregateModel::new("1".to_string(), 3, 3),
+                vec!["updated".to_string(); 2],
+                None,
+                Vec::new(),
+            )
+            .await?;
+        let (snapshot, events) = event_store
+            .get("1")
+            .await?
+            .ok_or("aggregate not found")?
+            .into_parts();
+        assert_eq!(snapshot, Some(AggregateModel::new("1".to_string(), 1, 1)));
+        assert_eq!(
+            events
+                .iter()
+                .map(crate::EventStoreModel::id)
+                .collect::<Vec<_>>(),
+            vec![1, 2],
+            "スナップショット以降のイベントを取得する"
+        );
+
+        event_store
+            .save_snapshot(AggregateModel::new("1".to_string(), 3, 3))
+            .await?;
+        assert_eq!(
+            event_store.get_aggregate("1").await?,
+            Some(AggregateModel::new("1".to_string(), 3, 3))
+        );
+        assert!(
+            event_store
+                .save_snapshot(AggregateModel::new("1".to_string(), 2, 2))
+                .await
+                .is_err(),
+            "保存済みのスナップショットより古いバージョンは保存できない"
+        );
+        Ok(())
+    }
+
+    /// メモリ上の `SQLite` をバックエンドにしたイベントストアを作成する
+    ///
+    /// メモリ上のデータベースは接続ごとに作られるので、接続を1つに制限する
+    #[tokio::test]
+    async fn test_idempotency_key() -> Result<(), Error> {
+        let event_store = event_store().await?;
+        let mut idempotency_key = IdempotencyKeyModel::new("key".to_string(), "1".to_string(), 1);
+        idempotency_key
+            .metadata_mut()
+            .insert("item_ids".to_string(), "1,2".to_string());
+
+        event_store
+            .create(
+                AggregateModel::new("1".to_string(), 1, 1),
+                "created".to_string(),
+                vec![idempotency_key.clone()],
+            )
+            .await?;
+        assert_eq!(
+            event_store.get_idempotency_key("key").await?,
+            Some(idempotency_key.clone())
+        );
+        assert!(event_store.get_idempotency_key("unknown").await?.is_none());
+        assert!(
+            matches!(
+                event_store
+                    .update(
+                        AggregateModel::new("1".to_string(), 2, 2),
+                        vec!["updated".to_string()],
+                        Some(idempotency_key),
+                        Vec::new(),
+                    )
+                    .await,
+                Err(EventStoreError::ConcurrencyConflict)
+            ),
+            "保存済みの冪等キーでは更新できない"
+        );
+        let (snapshot, events) = event_store
+            .get("1")
+            .await?
+            .ok_or("aggregate not found")?
+            .into_parts();
+        assert_eq!(
+            snapshot,
+            Some(AggregateModel::new("1".to_string(), 1, 1)),
+            "冪等キーが競合した場合は集約を更新しない"
+        );
+        assert!(events.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_outbox() -> Result<(), Error> {
+        let event_store = event_store().await?;
+        event_store
+            .create(
+                AggregateModel::new("1".to_string(), 1, 1),
+                "created".to_string(),
+                Vec::new(),
+            )
+            .await?;
+        let outbox = OutboxModel::new("1".to_string(), 2, "placed".to_string());
+        event_store
+            .update(
+                AggregateModel::new("1".to_string(), 3, 3),
+                vec!["updated".to_string(), "placed".to_string()],
+                None,
+                vec![outbox.clone()],
+            )
+            .await?;
+        assert_eq!(event_store.list_outbox(10).await?, vec![outbox.clone()]);
+        assert!(
+            matches!(
+                event_store
+                    .update(
+                        AggregateModel::new("1".to_string(), 3, 3),
+                        vec!["placed".to_string()],
+                        None,
+                        vec![OutboxModel::new("1".to_string(), 2, "placed".to_string())],
+                    )
+                    .await,
+                Err(EventStoreError::ConcurrencyConflict)
+            ),
+            "更新が競合した場合は統合イベントを保存しない"
+        );
+        assert_eq!(event_store.list_outbox(10).await?, vec![outbox]);
+
+        event_store.delete_outbox("1", 2).await?;
+        assert!(event_store.list_outbox(10).await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_events() -> Result<(), Error> {
+        let event_store = event_store().await?;
+        for aggregate_id in ["2", "1"] {
+            event_store
+                .create(
+                    AggregateModel::new(aggregate_id.to_string(), 1, 1),
+                    format!("{aggregate_id}-created"),
+                    Vec::new(),
+                )
+                .await?;
+        }
+        event_store
+            .update(
+                AggregateModel::new("1".to_string(), 2, 2),
+                vec!["1-updated".to_string()],
+                None,
+                Vec::new(),
+            )
+            .await?;
+
+        let sequences = event_store.list_sequences(None, 10).await?;
+        assert_eq!(
+            sequences
+                .iter()
+                .map(|sequence| (sequence.aggregate_id(), sequence.latest_event_id()))
+                .collect::<Vec<_>>(),
+            vec![("1", 1), ("2", 0)],
+            "集約IDの昇順に取得する"
+        );
+        let sequences = event_store.list_sequences(Some("1"), 10).await?;
+        assert_eq!(
+            sequences
+                .iter()
+                .map(EventSequenceModel::aggregate_id)
+                .collect::<Vec<_>>(),
+            vec!["2"],
+            "指定した集約より後の集約を取得する"
+        );
+
+        let events = event_store.list_events("1", None, 10).await?;
+        assert_eq!(
+            events
+                .iter()
+                .map(|event| (event.id(), event.payload().as_str()))
+                .collect::<Vec<_>>(),
+            vec![(0, "1-created"), (1, "1-updated")],
+            "イベントIDの昇順に取得する"
+        );
+        let events = event_store.list_events("1", Some(0), 1).await?;
+        assert_eq!(
+            events.iter().map(EventStoreModel::id).collect::<Vec<_>>(),
+            vec![1],
+            "指定したイベントより後のイベントを最大件数まで取得する"
+        );
+        assert!(event_store.list_events("2", Some(0), 10).await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_sequences_since() -> Result<(), Error> {
+        let event_store = event_store().await?;
+        for aggregate_id in ["2", "1"] {
+            event_store
+                .create(
+                    AggregateModel::new(aggregate_id.to_string(), 1, 1),
+                    format!("{aggregate_id}-created"),
+                    Vec::new(),
+                )
+                .await?;
+        }
+        let created = event_store.list_sequences(None, 10).await?;
+        let since = created
+            .iter()
+            .map(EventSequenceModel::updated_at)
+            .max()
+            .unwrap_or_default();
+        tokio::time::sleep(std::time::Duration::from_millis(2)).await;
+        event_store
+            .update(
+                AggregateModel::new("2".to_string(), 2, 2),
+                vec!["2-updated".to_string()],
+                None,
+                Vec::new(),
+            )
+            .await?;
+
+        let sequences = event_store
+            .list_sequences_since(&SequencePosition::since(0), 10)
+            .await?;
+        assert_eq!(
+            sequences
+                .iter()
+                .map(|sequence| (sequence.aggregate_id(), sequence.latest_event_id()))
+                .collect::<Vec<_>>(),
+            vec![("1", 0), ("2", 1)],
+            "更新した順序で取得する"
+        );
+        let sequences = event_store
+            .list_sequences_since(&SequencePosition::new(since, "9".to_string()), 10)
+            .await?;
+        assert_eq!(
+            sequences
+                .iter()
+                .map(EventSequenceModel::aggregate_id)
+                .collect::<Vec<_>>(),
+            vec!["2"],
+            "指定した位置より後に更新した集約だけを取得する"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_create_tables_add_updated_at() -> Result<(), Error> {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await?;
+        sqlx::query(&format!(
+            r#"CREATE TABLE "{}" (aggregate_id TEXT PRIMARY KEY, latest_event_id INTEGER NOT NULL)"#,
+            TABLE_NAMES.event_sequence
+        ))
+        .execute(&pool)
+        .await?;
+        sqlx::query(&format!(
+            r#"INSERT INTO "{}" (aggregate_id, latest_event_id) VALUES ('1', 3)"#,
+            TABLE_NAMES.event_sequence
+        ))
+        .execute(&pool)
+        .await?;
+
+        let event_store = SqliteEventStore::<u64, String>::new(pool, TABLE_NAMES);
+        event_store.create_tables().await?;
+        event_store.create_tables().await?;
+        assert_eq!(
+            event_store.list_sequences(None, 10).await?,
+            vec![EventSequenceModel::new("1".to_string(), 3)],
+            "更新した時刻の列を追加する前のレコードは時刻が0になる"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_aggregates_page() -> Result<(), Error> {
+        let event_store = event_store().await?;
+        for aggregate_id in ["3", "1", "2"] {
+            event_store
+                .create(
+                    AggregateModel::new(aggregate_id.to_string(), 1, 1),
+                    "created".to_string(),
+                    Vec::new(),
+                )
+                .await?;
+        }
+
+        let page = event_store.list_aggregates_page(2, None).await?;
+        assert_eq!(
+            page.items()
+                .iter()
+                .map(AggregateModel::id)
+                .collect::<Vec<_>>(),
+            vec!["1", "2"]
+        );
+        assert_eq!(page.next_page_token(), Some(&PageToken::new("2")));
+        let page = event_store
+            .list_aggregates_page(2, page.next_page_token())
+            .await?;
+        assert_eq!(
+            page.items()
+                .iter()
+                .map(AggregateModel::id)
+                .collect::<Vec<_>>(),
+            vec!["3"]
+        );
+        assert_eq!(
+            page.next_page_token(),
+            None,
+            "続きがない場合はトークンを返さない"
+        );
+        Ok(())
+    }
+
+    async fn event_store() -> Result<SqliteEventStore<u64, String>, Error> {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .idle_timeout(None)
+            .max_lifetime(None)
+            .connect("sqlite::memory:")
+            .await?;
+        let event_store = SqliteEventStore::new(pool, TABLE_NAMES);
+        event_store.create_tables().await?;
+        Ok(event_store)
+    }
+}
@@ -0,0 +1,59 @@
+use std::fmt;
+
+use crate::Error;
+
+/// 古いバージョンのペイロードを新しいバージョンのペイロードに変換する
+pub trait Upcaster<P>: Send + Sync {
+    /// ペイロードを変換する
+    ///
+    /// 変換対象ではないペイロードはそのまま返す
+    ///
+    /// # Errors
+    fn upcast(&self, payload: P) -> Result<P, Error>;
+}
+
+/// 登録された順にアップキャスターを適用して、ペイロードを最新のバージョンに変換するレジストリ
+pub struct UpcasterRegistry<P> {
+    upcasters: Vec<Box<dyn Upcaster<P>>>,
+}
+
+impl<P> UpcasterRegistry<P> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            upcasters: Vec::new(),
+        }
+    }
+
+    /// アップキャスターを登録する
+    ///
+    /// 古いバージョンを変換するアップキャスターから順に登録する
+    #[must_use]
+    pub fn register(mut self, upcaster: impl Upcaster<P> + 'static) -> Self {
+        self.upcasters.push(Box::new(upcaster));
+        self
+    }
+
+    /// ペイロードを最新のバージョンに変換する
+    ///
+    /// # Errors
+    pub fn upcast(&self, payload: P) -> Result<P, Error> {
+        self.upcasters
+            .iter()
+            .try_fold(payload, |payload, upcaster| upcaster.upcast(payload))
+    }
+}
+
+impl<P> Default for UpcasterRegistry<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P> fmt::Debug for UpcasterRegistry<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UpcasterRegistry")
+            .field("upcasters", &self.upcasters.len())
+            .finish()
+    }
+}
@@ -4,7 +4,7 @@ use crate::Error;
 ///
 /// # Errors
 ///
-/// 各種プロバイダーの登録時に何らかの問題が発生したらエラーが返る。  
+/// 各種プロバイダーの登録時に何らかの問題が発生したらエラーが返る。\
 /// 返り値のクロージャー内でプロバイダーを終了できなかった場合も同様にエラーが返る。
 ///
 /// # Examples
@@ -0,0 +1,324 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWriteExt as _;
+
+use crate::ProjectionError;
+
+/// 追記した記録がこの数と記録した集約の数の2倍の和を超えたら、最新の記録だけのファイルに書き直す
+const MIN_COMPACT_ENTRIES: usize = 1000;
+
+/// チェックポイントのファイルに1行ずつ追記する記録
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Entry {
+    /// 集約に最後に適用したイベントID
+    Aggregate { aggregate_id: String, event_id: u64 },
+    /// 全ての集約を読み取り終えた時刻
+    UpdatedAt(u64),
+}
+
+/// プロジェクションにどこまでイベントを適用したかを記録するチェックポイント
+///
+/// 再開した場合は、集約ごとに記録したイベントIDより後のイベントから適用する
+#[derive(Debug, Default, Clone)]
+pub struct Checkpoint {
+    /// 集約IDごとに最後に適用したイベントID
+    aggregates: BTreeMap<String, u64>,
+    /// この時刻より前に更新した全ての集約のイベントを適用済みである時刻 (UNIXエポックからのミリ秒)
+    updated_at: Option<u64>,
+    /// 最後に保存してから追加した記録
+    changes: Vec<Entry>,
+    /// 保存したファイルの記録の数
+    entries: usize,
+}
+
+impl Checkpoint {
+    /// 集約に最後に適用したイベントIDを取得する
+    #[must_use]
+    pub fn last_event_id(&self, aggregate_id: &str) -> Option<u64> {
+        self.aggregates.get(aggregate_id).copied()
+    }
+
+    /// 全ての集約を読み取り終えた時刻を取得する。一度も読み取り終えていない場合は `None`
+    #[must_use]
+    pub fn updated_at(&self) -> Option<u64> {
+        self.updated_at
+    }
+
+    /// イベントを適用した集約の数
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.aggregates.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.aggregates.is_empty()
+    }
+
+    pub(crate) fn is_applied(&self, aggregate_id: &str, event_id: u64) -> bool {
+        self.last_event_id(aggregate_id)
+            .is_some_and(|last_event_id| event_id <= last_event_id)
+    }
+
+    /// 最後に保存してから変更したか
+    pub(crate) fn is_dirty(&self) -> bool {
+        !self.changes.is_empty()
+    }
+
+    pub(crate) fn advance(&mut self, aggregate_id: &str, event_id: u64) {
+        self.push(Entry::Aggregate {
+            aggregate_id: aggregate_id.to_string(),
+            event_id,
+        });
+    }
+
+    pub(crate) fn set_updated_at(&mut self, updated_at: u64) {
+        if self.updated_at != Some(updated_at) {
+            self.push(Entry::UpdatedAt(updated_at));
+        }
+    }
+
+    fn push(&mut self, entry: Entry) {
+        self.apply(entry.clone());
+        self.changes.push(entry);
+    }
+
+    fn apply(&mut self, entry: Entry) {
+        match entry {
+            Entry::Aggregate {
+                aggregate_id,
+                event_id,
+            } => {
+                self.aggregates.insert(aggregate_id, event_id);
+            }
+            Entry::UpdatedAt(updated_at) => self.updated_at = Some(updated_at),
+        }
+    }
+
+    /// 最新の記録だけを並べる
+    fn compacted(&self) -> Vec<Entry> {
+        self.aggregates
+            .iter()
+            .map(|(aggregate_id, event_id)| Entry::Aggregate {
+                aggregate_id: aggregate_id.clone(),
+                event_id: *event_id,
+            })
+            .chain(self.updated_at.map(Entry::UpdatedAt))
+            .collect()
+    }
+}
+
+/// プロジェクションごとのチェックポイントを `<ディレクトリ>/<プロジェクション名>.jsonl` に保存するストア
+///
+/// 保存するたびに全ての集約を書き直さないように、前回から変更した記録だけを1行ずつ追記する
+#[derive(Debug, Clone)]
+pub struct CheckpointStore {
+    dir: PathBuf,
+}
+
+impl CheckpointStore {
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// 環境変数からチェックポイントを保存するディレクトリを読み込む
+    ///
+    /// - `PROJECTION_CHECKPOINT_DIR`: チェックポイントを保存するディレクトリ。デフォルトは `projection-checkpoints`
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self::new(
+            std::env::var("PROJECTION_CHECKPOINT_DIR")
+                .unwrap_or("projection-checkpoints".to_string()),
+        )
+    }
+
+    fn path(&self, name: &str) -> PathBuf {
+        self.dir.join(format!("{name}.jsonl"))
+    }
+
+    /// チェックポイントを読み込む。保存していない場合は空のチェックポイントを返す
+    ///
+    /// 追記している途中でプロセスが終了した場合は、最後の行を読み飛ばす
+    ///
+    /// # Errors
+    pub async fn load(&self, name: &str) -> Result<Checkpoint, ProjectionError> {
+        let path = self.path(name);
+        let error = |e: crate::Error| ProjectionError::Checkpoint(path.display().to_string(), e);
+        let content = match tokio::fs::read_to_string(&path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(Checkpoint::default());
+            }
+            Err(e) => return Err(error(e.into())),
+        };
+        let mut checkpoint = Checkpoint::default();
+        let mut lines = content.lines().filter(|line| !line.is_empty()).peekable();
+        while let Some(line) = lines.next() {
+            match serde_json::from_str(line) {
+                Ok(entry) => {
+                    checkpoint.apply(entry);
+                    checkpoint.entries += 1;
+                }
+                Err(_) if lines.peek().is_none() && !content.ends_with('\n') => break,
+                Err(e) => return Err(error(e.into())),
+            }
+        }
+        Ok(checkpoint)
+    }
+
+    /// 前回から変更した記録を追記する
+    ///
+    /// 追記した記録が増えたら、最新の記録だけのファイルに書き直す
+    ///
+    /// # Errors
+    pub async fn save(
+        &self,
+        name: &str,
+        checkpoint: &mut Checkpoint,
+    ) -> Result<(), ProjectionError> {
+        if !checkpoint.is_dirty() {
+            return Ok(());
+        }
+        if checkpoint.entries + checkpoint.changes.len()
+            > MIN_COMPACT_ENTRIES + 2 * checkpoint.aggregates.len()
+        {
+            let entries = checkpoint.compacted();
+            self.write(name, &entries).await?;
+            checkpoint.entries = entries.len();
+        } else {
+            self.append(name, &checkpoint.changes).await?;
+            checkpoint.entries += checkpoint.changes.len();
+        }
+        checkpoint.changes.clear();
+        Ok(())
+    }
+
+    /// チェックポイントを削除する
+    ///
+    /// # Errors
+    pub async fn reset(&self, name: &str) -> Result<(), ProjectionError> {
+        self.write(name, &[]).await
+    }
+
+    async fn append(&self, name: &str, entries: &[Entry]) -> Result<(), ProjectionError> {
+        let path = self.path(name);
+        let error = |e: crate::Error| ProjectionError::Checkpoint(path.display().to_string(), e);
+        tokio::fs::create_dir_all(&self.dir)
+            .await
+            .map_err(|e| error(e.into()))?;
+        let bytes = encode(entries).map_err(|e| error(e.into()))?;
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .await
+            .map_err(|e| error(e.into()))?;
+        file.write_all(&bytes).await.map_err(|e| error(e.into()))?;
+        file.flush().await.map_err(|e| error(e.into()))
+    }
+
+    /// 書き込み途中でプロセスが終了してもチェックポイントが壊れないように、一時ファイルに書き込んでから置き換える
+    async fn write(&self, name: &str, entries: &[Entry]) -> Result<(), ProjectionError> {
+        let path = self.path(name);
+        let error = |e: crate::Error| ProjectionError::Checkpoint(path.display().to_string(), e);
+        tokio::fs::create_dir_all(&self.dir)
+            .await
+            .map_err(|e| error(e.into()))?;
+        let bytes = encode(entries).map_err(|e| error(e.into()))?;
+        let mut tmp = path.clone().into_os_string();
+        tmp.push(".tmp");
+        tokio::fs::write(&tmp, bytes)
+            .await
+            .map_err(|e| error(e.into()))?;
+        tokio::fs::rename(&tmp, &path)
+            .await
+            .map_err(|e| error(e.into()))
+    }
+}
+
+/// 記録を1行ずつのJSONにする
+fn encode(entries: &[Entry]) -> Result<Vec<u8>, serde_json::Error> {
+    let mut bytes = Vec::new();
+    for entry in entries {
+        serde_json::to_writer(&mut bytes, entry)?;
+        bytes.push(b'\n');
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Checkpoint, CheckpointStore, MIN_COMPACT_ENTRIES};
+
+    type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    fn store(name: &str) -> CheckpointStore {
+        let dir = std::env::temp_dir().join(format!(
+            "projection-checkpoint-{}-{name}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        CheckpointStore::new(dir)
+    }
+
+    #[tokio::test]
+    async fn test_save_appends_changes() -> Result<(), Error> {
+        let store = store("append");
+        let mut checkpoint = Checkpoint::default();
+        checkpoint.advance("1", 0);
+        checkpoint.advance("2", 0);
+        store.save("test", &mut checkpoint).await?;
+        checkpoint.advance("1", 1);
+        checkpoint.set_updated_at(100);
+        store.save("test", &mut checkpoint).await?;
+
+        let content = std::fs::read_to_string(store.path("test"))?;
+        assert_eq!(content.lines().count(), 4, "変更した記録だけを追記する");
+        let loaded = store.load("test").await?;
+        assert_eq!(loaded.last_event_id("1"), Some(1));
+        assert_eq!(loaded.last_event_id("2"), Some(0));
+        assert_eq!(loaded.updated_at(), Some(100));
+
+        std::fs::write(
+            store.path("test"),
+            format!("{content}{{\"aggregate\":{{\"aggr"),
+        )?;
+        assert_eq!(
+            store.load("test").await?.last_event_id("1"),
+            Some(1),
+            "追記している途中の最後の行は読み飛ばす"
+        );
+
+        store.reset("test").await?;
+        assert!(store.load("test").await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_save_compacts_entries() -> Result<(), Error> {
+        let store = store("compact");
+        let mut checkpoint = Checkpoint::default();
+        // NOTE: 記録した集約が1つなので、記録が最小の数と2の和を超えた時に書き直す
+        let last_event_id = u64::try_from(MIN_COMPACT_ENTRIES + 2)?;
+        for event_id in 0..=last_event_id {
+            checkpoint.advance("1", event_id);
+            store.save("test", &mut checkpoint).await?;
+        }
+
+        let content = std::fs::read_to_string(store.path("test"))?;
+        assert_eq!(
+            content.lines().count(),
+            1,
+            "記録が増えたら最新の記録だけに書き直す"
+        );
+        assert_eq!(
+            store.load("test").await?.last_event_id("1"),
+            Some(last_event_id)
+        );
+        Ok(())
+    }
+}
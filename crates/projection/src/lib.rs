@@ -0,0 +1,804 @@
+mod checkpoint;
+mod runtime;
+
+use std::fmt::Debug;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use event_store::{
+    EventLog, EventSequenceModel, EventStoreError, EventStoreModel, SequencePosition,
+};
+use thiserror::Error;
+
+pub use checkpoint::{Checkpoint, CheckpointStore};
+pub use runtime::{Config, run};
+
+pub(crate) type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// 現在の時刻 (UNIXエポックからのミリ秒)
+fn now_millis() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |duration| {
+            u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+        })
+}
+
+/// プロジェクターがイベントの適用に失敗した時のエラー
+pub type ProjectorError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// イベントを適用して読み取りモデルを更新するプロジェクター
+///
+/// 集約ごとにイベントIDの昇順でイベントを適用する。
+/// 適用した後にチェックポイントを保存する前にプロセスが終了すると同じイベントをもう一度適用するので、プロジェクターは冪等に実装する
+pub trait Projector<P>: Send + Sync {
+    /// イベントを読み取りモデルに適用する
+    fn apply(
+        &self,
+        model: &EventStoreModel<P>,
+    ) -> impl Future<Output = Result<(), ProjectorError>> + Send;
+
+    /// 同じ集約のイベントをイベントIDの昇順でまとめて読み取りモデルに適用する
+    ///
+    /// 既定では1件ずつ [`Projector::apply`] で適用する。
+    /// 集約の現在の状態から読み取りモデルを作り直すプロジェクターは、まとめて1回だけ作り直すように上書きする
+    fn apply_batch(
+        &self,
+        models: &[EventStoreModel<P>],
+    ) -> impl Future<Output = Result<(), ProjectorError>> + Send
+    where
+        P: Sync,
+    {
+        async move {
+            for model in models {
+                self.apply(model).await?;
+            }
+            Ok(())
+        }
+    }
+
+    /// 読み取りモデルを全て削除する
+    fn reset(&self) -> impl Future<Output = Result<(), ProjectorError>> + Send;
+}
+
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// 異なる型のプロジェクターを同じエンジンに登録するためのトレイト
+trait DynProjector<P>: Send + Sync {
+    fn apply_batch<'a>(
+        &'a self,
+        models: &'a [EventStoreModel<P>],
+    ) -> BoxFuture<'a, Result<(), ProjectorError>>;
+
+    fn reset<'a>(&'a self) -> BoxFuture<'a, Result<(), ProjectorError>>
+    where
+        P: 'a;
+}
+
+impl<P, T> DynProjector<P> for T
+where
+    P: Sync,
+    T: Projector<P>,
+{
+    fn apply_batch<'a>(
+        &'a self,
+        models: &'a [EventStoreModel<P>],
+    ) -> BoxFuture<'a, Result<(), ProjectorError>> {
+        Box::pin(Projector::apply_batch(self, models))
+    }
+
+    fn reset<'a>(&'a self) -> BoxFuture<'a, Result<(), ProjectorError>>
+    where
+        P: 'a,
+    {
+        Box::pin(Projector::reset(self))
+    }
+}
+
+struct Projection<P> {
+    name: &'static str,
+    projector: Arc<dyn DynProjector<P>>,
+}
+
+#[derive(Debug, Error)]
+pub enum ProjectionError {
+    #[error("Projection is not found: {0}")]
+    NotFound(String),
+    #[error("Failed to read event log")]
+    EventLog(#[source] EventStoreError),
+    #[error("Failed to access checkpoint: {0}")]
+    Checkpoint(String, #[source] Error),
+    #[error("Projection {projection} failed to apply event {aggregate_id}:{event_id}")]
+    Apply {
+        projection: &'static str,
+        aggregate_id: String,
+        event_id: u64,
+        #[source]
+        source: ProjectorError,
+    },
+    #[error("Projection {projection} failed to reset")]
+    Reset {
+        projection: &'static str,
+        #[source]
+        source: ProjectorError,
+    },
+}
+
+/// 1回の読み取りでプロジェクションごとに管理する状態
+struct ProjectionState<'a, P> {
+    projection: &'a Projection<P>,
+    checkpoint: Checkpoint,
+    /// イベントの適用に失敗した場合は、この読み取りでは以降のイベントを適用しない
+    error: Option<ProjectionError>,
+}
+
+/// イベントストアのイベントを集約ごとにチェックポイントの位置から読み取って、登録したプロジェクターに適用するエンジン
+///
+/// プロジェクションごとに集約単位のチェックポイントを保存するので、プロジェクションを追加したり作り直したりしても他のプロジェクションに影響しない。
+/// 全ての集約を読み取り終えた後は、前回読み取り始めた時刻から遡る時間より後に更新した集約だけを読み取る
+///
+/// `L` はイベントログ、`P` はイベントストアのペイロード
+pub struct Engine<L, P> {
+    event_log: L,
+    checkpoints: CheckpointStore,
+    projections: Vec<Projection<P>>,
+    batch_size: usize,
+    lookback: Duration,
+}
+
+impl<L, P> Debug for Engine<L, P>
+where
+    L: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Engine")
+            .field("event_log", &self.event_log)
+            .field("checkpoints", &self.checkpoints)
+            .field(
+                "projections",
+                &self
+                    .projections
+                    .iter()
+                    .map(|projection| projection.name)
+                    .collect::<Vec<_>>(),
+            )
+            .field("batch_size", &self.batch_size)
+            .field("lookback", &self.lookback)
+            .finish()
+    }
+}
+
+impl<L, P> Engine<L, P>
+where
+    L: EventLog<P> + Send + Sync,
+    P: Send + Sync,
+{
+    #[must_use]
+    pub fn new(event_log: L, checkpoints: CheckpointStore) -> Self {
+        Self {
+            event_log,
+            checkpoints,
+            projections: Vec::new(),
+            batch_size: 100,
+            lookback: Duration::from_secs(10),
+        }
+    }
+
+    /// `name` をチェックポイントの名前にしてプロジェクターを登録する
+    #[must_use]
+    pub fn register<T>(mut self, name: &'static str, projector: T) -> Self
+    where
+        T: Projector<P> + 'static,
+    {
+        self.projections.push(Projection {
+            name,
+            projector: Arc::new(projector),
+        });
+        self
+    }
+
+    /// 1回に読み取るイベントの最大件数を設定する
+    #[must_use]
+    pub fn with_batch_size(self, batch_size: usize) -> Self {
+        Self {
+            batch_size: batch_size.max(1),
+            ..self
+        }
+    }
+
+    /// 前回から更新した集約を読み取る時に、前回読み取り始めた時刻から遡る時間を設定する
+    ///
+    /// 集約を更新した時刻はイベントを追加したプロセスの時計で決まり、コミットした順序とも一致しないので、
+    /// プロセス間の時計のずれとトランザクションの時間より長くする
+    #[must_use]
+    pub fn with_lookback(self, lookback: Duration) -> Self {
+        Self { lookback, ..self }
+    }
+
+    /// 登録したプロジェクションの名前
+    #[must_use]
+    pub fn names(&self) -> Vec<&'static str> {
+        self.projections
+            .iter()
+            .map(|projection| projection.name)
+            .collect()
+    }
+
+    /// プロジェクションのチェックポイントを取得する
+    ///
+    /// # Errors
+    pub async fn checkpoint(&self, name: &str) -> Result<Checkpoint, ProjectionError> {
+        let projection = self.projection(name)?;
+        self.checkpoints.load(projection.name).await
+    }
+
+    /// 全てのプロジェクションにチェックポイントより後のイベントを適用して、適用したイベントの数を返す
+    ///
+    /// # Errors
+    ///
+    /// イベントの適用に失敗したプロジェクションがある場合は、他のプロジェクションにイベントを適用し終えてから最初のエラーを返す
+    pub async fn catch_up(&self) -> Result<usize, ProjectionError> {
+        self.project(self.projections.iter().collect()).await
+    }
+
+    /// プロジェクションの読み取りモデルとチェックポイントを削除して、全てのイベントを適用し直す
+    ///
+    /// # Errors
+    pub async fn rebuild(&self, name: &str) -> Result<usize, ProjectionError> {
+        let projection = self.projection(name)?;
+        // NOTE: 読み取りモデルを削除した後にチェックポイントを削除する前にプロセスが終了すると、
+        //       イベントを適用し直せなくなるので先にチェックポイントを削除する
+        self.checkpoints.reset(projection.name).await?;
+        projection
+            .projector
+            .reset()
+            .await
+            .map_err(|source| ProjectionError::Reset {
+                projection: projection.name,
+                source,
+            })?;
+        tracing::info!("reset projection: {}", projection.name);
+        self.project(vec![projection]).await
+    }
+
+    /// `shutdown` が完了するまで、間隔を空けて全てのプロジェクションにイベントを適用し続ける
+    ///
+    /// 適用に失敗した場合はエラーを記録して、次の読み取りでチェックポイントの位置から適用し直す
+    pub async fn run(&self, interval: Duration, shutdown: impl Future<Output = ()>) {
+        let mut shutdown = std::pin::pin!(shutdown);
+        loop {
+            match self.catch_up().await {
+                Ok(count) if count > 0 => tracing::info!("applied {count} events"),
+                Ok(_) => {}
+                Err(e) => tracing::error!("failed to catch up projections: {e:?}"),
+            }
+            tokio::select! {
+                () = &mut shutdown => return,
+                () = tokio::time::sleep(interval) => {}
+            }
+        }
+    }
+
+    fn projection(&self, name: &str) -> Result<&Projection<P>, ProjectionError> {
+        self.projections
+            .iter()
+            .find(|projection| projection.name == name)
+            .ok_or_else(|| ProjectionError::NotFound(name.to_string()))
+    }
+
+    async fn project(&self, projections: Vec<&Projection<P>>) -> Result<usize, ProjectionError> {
+        let mut states = Vec::with_capacity(projections.len());
+        for projection in projections {
+            states.push(ProjectionState {
+                projection,
+                checkpoint: self.checkpoints.load(projection.name).await?,
+                error: None,
+            });
+        }
+
+        // NOTE: 読み取り始める前の時刻を記録して、次は読み取っている間に更新した集約も読み取る
+        let started_at = now_millis();
+        // NOTE: 全ての集約を読み取り終えていないプロジェクションがある場合は、全ての集約を読み取る
+        let mut after =
+            states
+                .iter()
+                .map(|state| state.checkpoint.updated_at())
+                .min()
+                .flatten()
+                .map(|updated_at| {
+                    SequencePosition::since(updated_at.saturating_sub(
+                        u64::try_from(self.lookback.as_millis()).unwrap_or(u64::MAX),
+                    ))
+                });
+        let mut count = 0;
+        let mut after_aggregate_id: Option<String> = None;
+        let completed = loop {
+            let sequences = match &after {
+                Some(position) => {
+                    self.event_log
+                        .list_sequences_since(position, self.batch_size)
+                        .await
+                }
+                None => {
+                    self.event_log
+                        .list_sequences(after_aggregate_id.as_deref(), self.batch_size)
+                        .await
+                }
+            }
+            .map_err(ProjectionError::EventLog)?;
+            for sequence in &sequences {
+                count += self.project_aggregate(&mut states, sequence).await?;
+            }
+            self.save_checkpoints(&mut states).await?;
+
+            match sequences.last() {
+                Some(last) if sequences.len() == self.batch_size => {
+                    if after.is_some() {
+                        after = Some(last.position());
+                    } else {
+                        after_aggregate_id = Some(last.aggregate_id().to_string());
+                    }
+                }
+                _ => break true,
+            }
+            if states.iter().all(|state| state.error.is_some()) {
+                break false;
+            }
+        };
+        if completed {
+            for state in states.iter_mut().filter(|state| state.error.is_none()) {
+                state.checkpoint.set_updated_at(started_at);
+            }
+            self.save_checkpoints(&mut states).await?;
+        }
+
+        match states.into_iter().find_map(|state| state.error) {
+            Some(e) => Err(e),
+            None => Ok(count),
+        }
+    }
+
+    /// 集約のチェックポイントより後のイベントを適用して、適用したイベントの数を返す
+    ///
+    /// 全てのプロジェクションが最新のイベントまで適用した集約はイベントを読み取らない
+    async fn project_aggregate(
+        &self,
+        states: &mut [ProjectionState<'_, P>],
+        sequence: &EventSequenceModel,
+    ) -> Result<usize, ProjectionError> {
+        let aggregate_id = sequence.aggregate_id();
+        let Some(mut after) = states
+            .iter()
+            .filter(|state| {
+                state.error.is_none()
+                    && !state
+                        .checkpoint
+                        .is_applied(aggregate_id, sequence.latest_event_id())
+            })
+            .map(|state| state.checkpoint.last_event_id(aggregate_id))
+            .min()
+        else {
+            return Ok(0);
+        };
+
+        let mut count = 0;
+        loop {
+            let events = self
+                .event_log
+                .list_events(aggregate_id, after, self.batch_size)
+                .await
+                .map_err(ProjectionError::EventLog)?;
+            count += Self::apply(states, &events).await;
+            match events.last() {
+                Some(last) if events.len() == self.batch_size => after = Some(last.id()),
+                _ => return Ok(count),
+            }
+        }
+    }
+
+    /// 同じ集約のイベントのうちチェックポイントより後のイベントを各プロジェクションにまとめて適用して、適用したイベントの数を返す
+    async fn apply(states: &mut [ProjectionState<'_, P>], events: &[EventStoreModel<P>]) -> usize {
+        let mut count = 0;
+        for state in states {
+            if state.error.is_some() {
+                continue;
+            }
+            let start = events
+                .iter()
+                .position(|event| {
+                    !state
+                        .checkpoint
+                        .is_applied(event.aggregate_id(), event.id())
+                })
+                .unwrap_or(events.len());
+            let (Some(first), Some(last)) = (events.get(start), events.last()) else {
+                continue;
+            };
+            match state
+                .projection
+                .projector
+                .apply_batch(&events[start..])
+                .await
+            {
+                Ok(()) => {
+                    state.checkpoint.advance(last.aggregate_id(), last.id());
+                    count += events.len() - start;
+                }
+                Err(source) => {
+                    state.error = Some(ProjectionError::Apply {
+                        projection: state.projection.name,
+                        aggregate_id: first.aggregate_id().to_string(),
+                        event_id: first.id(),
+                        source,
+                    });
+                }
+            }
+        }
+        count
+    }
+
+    async fn save_checkpoints(
+        &self,
+        states: &mut [ProjectionState<'_, P>],
+    ) -> Result<(), ProjectionError> {
+        for state in states.iter_mut() {
+            self.checkpoints
+                .save(state.projection.name, &mut state.checkpoint)
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use event_store::in_memory::InMemoryEventStore;
+    use event_store::{
+        AggregateModel, EventLog, EventSequenceModel, EventStore, EventStoreError, EventStoreModel,
+        SequencePosition,
+    };
+
+    use super::{CheckpointStore, Engine, ProjectionError, Projector, ProjectorError};
+
+    type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    /// 適用したイベントを記録するプロジェクター
+    #[derive(Default)]
+    struct Recorder {
+        events: Mutex<Vec<String>>,
+        /// このペイロードのイベントの適用に失敗する
+        fail_on: Option<&'static str>,
+    }
+
+    impl Recorder {
+        fn events(&self) -> Vec<String> {
+            self.events.lock().unwrap().clone()
+        }
+    }
+
+    impl Projector<String> for Arc<Recorder> {
+        async fn apply(&self, model: &EventStoreModel<String>) -> Result<(), ProjectorError> {
+            if self.fail_on == Some(model.payload().as_str()) {
+                return Err("failed".into());
+            }
+            self.events.lock().unwrap().push(model.payload().clone());
+            Ok(())
+        }
+
+        async fn reset(&self) -> Result<(), ProjectorError> {
+            self.events.lock().unwrap().clear();
+            Ok(())
+        }
+    }
+
+    /// まとめて適用したイベントの数を記録するプロジェクター
+    #[derive(Default)]
+    struct BatchRecorder {
+        batches: Mutex<Vec<usize>>,
+    }
+
+    impl Projector<String> for Arc<BatchRecorder> {
+        async fn apply(&self, model: &EventStoreModel<String>) -> Result<(), ProjectorError> {
+            self.apply_batch(std::slice::from_ref(model)).await
+        }
+
+        async fn apply_batch(
+            &self,
+            models: &[EventStoreModel<String>],
+        ) -> Result<(), ProjectorError> {
+            self.batches.lock().unwrap().push(models.len());
+            Ok(())
+        }
+
+        async fn reset(&self) -> Result<(), ProjectorError> {
+            self.batches.lock().unwrap().clear();
+            Ok(())
+        }
+    }
+
+    /// イベントを読み取った集約IDと読み取りを始めた位置
+    type Read = (String, Option<u64>);
+
+    /// イベントを読み取った集約を記録するイベントログ
+    #[derive(Default, Clone)]
+    struct RecordingLog {
+        event_store: InMemoryEventStore<u64, String>,
+        reads: Arc<Mutex<Vec<Read>>>,
+        /// 全ての集約を読み取った回数
+        full_scans: Arc<Mutex<usize>>,
+        /// 前回から更新した集約として読み取った集約ID
+        updated: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl EventLog<String> for RecordingLog {
+        async fn list_sequences(
+            &self,
+            after: Option<&str>,
+            limit: usize,
+        ) -> Result<Vec<EventSequenceModel>, EventStoreError> {
+            if after.is_none() {
+                *self.full_scans.lock().unwrap() += 1;
+            }
+            self.event_store.list_sequences(after, limit).await
+        }
+
+        async fn list_sequences_since(
+            &self,
+            after: &SequencePosition,
+            limit: usize,
+        ) -> Result<Vec<EventSequenceModel>, EventStoreError> {
+            let sequences = self.event_store.list_sequences_since(after, limit).await?;
+            self.updated.lock().unwrap().extend(
+                sequences
+                    .iter()
+                    .map(|sequence| sequence.aggregate_id().to_string()),
+            );
+            Ok(sequences)
+        }
+
+        async fn list_events(
+            &self,
+            aggregate_id: &str,
+            after: Option<u64>,
+            limit: usize,
+        ) -> Result<Vec<EventStoreModel<String>>, EventStoreError> {
+            self.reads
+                .lock()
+                .unwrap()
+                .push((aggregate_id.to_string(), after));
+            self.event_store
+                .list_events(aggregate_id, after, limit)
+                .await
+        }
+    }
+
+    fn checkpoints(name: &str) -> CheckpointStore {
+        let dir = std::env::temp_dir().join(format!("projection-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        CheckpointStore::new(dir)
+    }
+
+    async fn create(
+        event_store: &InMemoryEventStore<u64, String>,
+        aggregate_id: &str,
+    ) -> Result<(), Error> {
+        event_store
+            .create(
+                AggregateModel::new(aggregate_id.to_string(), 1, 1),
+                format!("{aggregate_id}-created"),
+                Vec::new(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_catch_up() -> Result<(), Error> {
+        let event_store = InMemoryEventStore::<u64, String>::new();
+        create(&event_store, "2").await?;
+        create(&event_store, "1").await?;
+        let recorder = Arc::new(Recorder::default());
+        let engine = Engine::new(event_store.clone(), checkpoints("catch-up"))
+            .register("recorder", Arc::clone(&recorder))
+            .with_batch_size(1);
+
+        assert_eq!(engine.catch_up().await?, 2);
+        assert_eq!(recorder.events(), vec!["1-created", "2-created"]);
+        assert_eq!(
+            engine.catch_up().await?,
+            0,
+            "チェックポイントまでのイベントは適用しない"
+        );
+
+        event_store
+            .update(
+                AggregateModel::new("1".to_string(), 2, 2),
+                vec!["1-updated".to_string()],
+                None,
+                Vec::new(),
+            )
+            .await?;
+        assert_eq!(engine.catch_up().await?, 1);
+        assert_eq!(
+            recorder.events(),
+            vec!["1-created", "2-created", "1-updated"]
+        );
+        assert_eq!(
+            engine.checkpoint("recorder").await?.last_event_id("1"),
+            Some(1)
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_catch_up_from_checkpoint() -> Result<(), Error> {
+        let log = RecordingLog::default();
+        create(&log.event_store, "1").await?;
+        create(&log.event_store, "2").await?;
+        let recorder = Arc::new(Recorder::default());
+        let engine = Engine::new(log.clone(), checkpoints("from-checkpoint"))
+            .register("recorder", Arc::clone(&recorder));
+        engine.catch_up().await?;
+        log.reads.lock().unwrap().clear();
+
+        log.event_store
+            .update(
+                AggregateModel::new("2".to_string(), 2, 2),
+                vec!["2-updated".to_string()],
+                None,
+                Vec::new(),
+            )
+            .await?;
+        assert_eq!(engine.catch_up().await?, 1);
+        assert_eq!(
+            *log.reads.lock().unwrap(),
+            vec![("2".to_string(), Some(0))],
+            "新しいイベントがある集約だけチェックポイントの位置から読み取る"
+        );
+        assert_eq!(
+            recorder.events(),
+            vec!["1-created", "2-created", "2-updated"]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_catch_up_since_updated() -> Result<(), Error> {
+        let log = RecordingLog::default();
+        create(&log.event_store, "1").await?;
+        create(&log.event_store, "2").await?;
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        let recorder = Arc::new(Recorder::default());
+        let engine = Engine::new(log.clone(), checkpoints("since-updated"))
+            .register("recorder", Arc::clone(&recorder))
+            .with_lookback(Duration::ZERO);
+        assert_eq!(engine.catch_up().await?, 2);
+        assert!(engine.checkpoint("recorder").await?.updated_at().is_some());
+
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        log.event_store
+            .update(
+                AggregateModel::new("2".to_string(), 2, 2),
+                vec!["2-updated".to_string()],
+                None,
+                Vec::new(),
+            )
+            .await?;
+        assert_eq!(engine.catch_up().await?, 1);
+        assert_eq!(
+            *log.full_scans.lock().unwrap(),
+            1,
+            "全ての集約を読み取るのは最初だけ"
+        );
+        assert_eq!(
+            *log.updated.lock().unwrap(),
+            vec!["2".to_string()],
+            "前回から更新した集約だけを読み取る"
+        );
+        assert_eq!(
+            recorder.events(),
+            vec!["1-created", "2-created", "2-updated"]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_catch_up_batch() -> Result<(), Error> {
+        let event_store = InMemoryEventStore::<u64, String>::new();
+        create(&event_store, "1").await?;
+        for version in 2..=3 {
+            event_store
+                .update(
+                    AggregateModel::new("1".to_string(), version, version),
+                    vec![format!("1-updated-{version}")],
+                    None,
+                    Vec::new(),
+                )
+                .await?;
+        }
+        let recorder = Arc::new(BatchRecorder::default());
+        let engine = Engine::new(event_store.clone(), checkpoints("batch"))
+            .register("recorder", Arc::clone(&recorder))
+            .with_batch_size(2);
+
+        assert_eq!(engine.catch_up().await?, 3);
+        assert_eq!(
+            *recorder.batches.lock().unwrap(),
+            vec![2, 1],
+            "読み取ったイベントをまとめて適用する"
+        );
+        assert_eq!(
+            engine.checkpoint("recorder").await?.last_event_id("1"),
+            Some(2)
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_rebuild() -> Result<(), Error> {
+        let event_store = InMemoryEventStore::<u64, String>::new();
+        create(&event_store, "1").await?;
+        let recorder = Arc::new(Recorder::default());
+        let other = Arc::new(Recorder::default());
+        let engine = Engine::new(event_store.clone(), checkpoints("rebuild"))
+            .register("recorder", Arc::clone(&recorder))
+            .register("other", Arc::clone(&other));
+        engine.catch_up().await?;
+
+        assert_eq!(engine.rebuild("recorder").await?, 1);
+        assert_eq!(
+            recorder.events(),
+            vec!["1-created"],
+            "削除してから適用し直す"
+        );
+        assert_eq!(
+            other.events(),
+            vec!["1-created"],
+            "他のプロジェクションは変わらない"
+        );
+        assert!(matches!(
+            engine.rebuild("unknown").await,
+            Err(ProjectionError::NotFound(_))
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_catch_up_failure() -> Result<(), Error> {
+        let event_store = InMemoryEventStore::<u64, String>::new();
+        create(&event_store, "1").await?;
+        create(&event_store, "2").await?;
+        let failing = Arc::new(Recorder {
+            fail_on: Some("1-created"),
+            ..Recorder::default()
+        });
+        let recorder = Arc::new(Recorder::default());
+        let engine = Engine::new(event_store.clone(), checkpoints("failure"))
+            .register("failing", Arc::clone(&failing))
+            .register("recorder", Arc::clone(&recorder));
+
+        assert!(matches!(
+            engine.catch_up().await,
+            Err(ProjectionError::Apply {
+                projection: "failing",
+                ..
+            })
+        ));
+        assert!(
+            failing.events().is_empty(),
+            "失敗したプロジェクションは以降のイベントを適用しない"
+        );
+        assert_eq!(
+            recorder.events(),
+            vec!["1-created", "2-created"],
+            "他のプロジェクションには適用する"
+        );
+        assert!(engine.checkpoint("failing").await?.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+use std::time::Duration;
+
+use event_store::EventLog;
+
+use crate::Engine;
+
+/// プロジェクションエンジンを実行する設定
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub service_name: &'static str,
+    pub service_version: &'static str,
+}
+
+/// コマンドライン引数で指定したコマンドを実行する
+///
+/// - `run`: 間隔を空けて全てのプロジェクションにイベントを適用し続ける。コマンドを指定しない場合はこのコマンドを実行する
+/// - `catch-up`: 全てのプロジェクションにチェックポイントより後のイベントを1回だけ適用する
+/// - `rebuild [<name>...]`: 指定したプロジェクションを削除して全てのイベントを適用し直す。指定しない場合は全てのプロジェクションを作り直す
+/// - `status`: プロジェクションごとにイベントを適用した集約の数を出力する
+///
+/// - `PROJECTION_POLL_INTERVAL_MS`: `run` で次に読み取るまで待つ間隔。デフォルトは 1000 ミリ秒
+/// - `PROJECTION_LOOKBACK_MS`: 前回から更新した集約を読み取る時に遡る時間。デフォルトは 10000 ミリ秒
+///
+/// # Errors
+pub async fn run<L, P>(engine: Engine<L, P>, config: Config) -> Result<(), crate::Error>
+where
+    L: EventLog<P> + Send + Sync,
+    P: Send + Sync,
+{
+    let engine = match std::env::var("PROJECTION_LOOKBACK_MS") {
+        Ok(lookback) => engine.with_lookback(Duration::from_millis(
+            lookback
+                .parse()
+                .map_err(|_| "PROJECTION_LOOKBACK_MS must be a number")?,
+        )),
+        Err(_) => engine,
+    };
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        None | Some("run") => {
+            let interval = match std::env::var("PROJECTION_POLL_INTERVAL_MS") {
+                Ok(interval) => Duration::from_millis(
+                    interval
+                        .parse()
+                        .map_err(|_| "PROJECTION_POLL_INTERVAL_MS must be a number")?,
+                ),
+                Err(_) => Duration::from_secs(1),
+            };
+            let shutdown_providers = observability::provider::init_providers(
+                config.service_name,
+                config.service_version,
+            )?;
+            engine
+                .run(interval, observability::server::shutdown())
+                .await;
+            shutdown_providers()?;
+            Ok(())
+        }
+        Some("catch-up") => {
+            println!("applied: {}", engine.catch_up().await?);
+            Ok(())
+        }
+        Some("rebuild") => {
+            let names: Vec<&str> = if args.len() > 1 {
+                args[1..].iter().map(String::as_str).collect()
+            } else {
+                engine.names()
+            };
+            for name in names {
+                println!("rebuilt {name}: {}", engine.rebuild(name).await?);
+            }
+            Ok(())
+        }
+        Some("status") => {
+            for name in engine.names() {
+                println!("{name}: {}", engine.checkpoint(name).await?.len());
+            }
+            Ok(())
+        }
+        _ => Err("usage: projection [run|catch-up|rebuild [<name>...]|status]".into()),
+    }
+}
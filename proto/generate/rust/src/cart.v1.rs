@@ -2,12 +2,18 @@
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateRequest {
+    /// 同じ冪等キーのリクエストを再実行した場合は最初のリクエストの結果を返す
+    #[prost(string, optional, tag="1")]
+    pub idempotency_key: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateResponse {
     #[prost(string, tag="1")]
     pub id: ::prost::alloc::string::String,
+    /// コマンド実行後の集約のバージョン
+    #[prost(uint64, tag="2")]
+    pub version: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -22,6 +28,9 @@ pub struct GetResponse {
     pub id: ::prost::alloc::string::String,
     #[prost(message, repeated, tag="2")]
     pub items: ::prost::alloc::vec::Vec<get_response::Item>,
+    /// 集約のバージョン
+    #[prost(uint64, tag="3")]
+    pub version: u64,
 }
 /// Nested message and enum types in `GetResponse`.
 pub mod get_response {
@@ -45,10 +54,19 @@ pub struct AddItemRequest {
     pub tenant_id: ::prost::alloc::string::String,
     #[prost(string, tag="3")]
     pub item_id: ::prost::alloc::string::String,
+    /// 集約が指定したバージョンの場合のみコマンドを実行する
+    #[prost(uint64, optional, tag="4")]
+    pub expected_version: ::core::option::Option<u64>,
+    /// 同じ冪等キーのリクエストを再実行した場合は最初のリクエストの結果を返す
+    #[prost(string, optional, tag="5")]
+    pub idempotency_key: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AddItemResponse {
+    /// コマンド実行後の集約のバージョン
+    #[prost(uint64, tag="1")]
+    pub version: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -59,176 +77,337 @@ pub struct RemoveItemRequest {
     pub tenant_id: ::prost::alloc::string::String,
     #[prost(string, tag="3")]
     pub item_id: ::prost::alloc::string::String,
+    /// 集約が指定したバージョンの場合のみコマンドを実行する
+    #[prost(uint64, optional, tag="4")]
+    pub expected_version: ::core::option::Option<u64>,
+    /// 同じ冪等キーのリクエストを再実行した場合は最初のリクエストの結果を返す
+    #[prost(string, optional, tag="5")]
+    pub idempotency_key: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RemoveItemResponse {
+    /// コマンド実行後の集約のバージョン
+    #[prost(uint64, tag="1")]
+    pub version: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PlaceOrderRequest {
     #[prost(string, tag="1")]
     pub id: ::prost::alloc::string::String,
+    /// 集約が指定したバージョンの場合のみコマンドを実行する
+    #[prost(uint64, optional, tag="2")]
+    pub expected_version: ::core::option::Option<u64>,
+    /// 同じ冪等キーのリクエストを再実行した場合は最初のリクエストの結果を返す
+    #[prost(string, optional, tag="3")]
+    pub idempotency_key: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PlaceOrderResponse {
+    /// コマンド実行後の集約のバージョン
+    #[prost(uint64, tag="1")]
+    pub version: u64,
 }
 /// Encoded file descriptor set for the `cart.v1` package
 pub const FILE_DESCRIPTOR_SET: &[u8] = &[
-    0x0a, 0x83, 0x13, 0x0a, 0x1a, 0x63, 0x61, 0x72, 0x74, 0x2f, 0x76, 0x31, 0x2f, 0x63, 0x61, 0x72,
+    0x0a, 0xef, 0x24, 0x0a, 0x1a, 0x63, 0x61, 0x72, 0x74, 0x2f, 0x76, 0x31, 0x2f, 0x63, 0x61, 0x72,
     0x74, 0x5f, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12,
-    0x07, 0x63, 0x61, 0x72, 0x74, 0x2e, 0x76, 0x31, 0x22, 0x0f, 0x0a, 0x0d, 0x43, 0x72, 0x65, 0x61,
-    0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0x20, 0x0a, 0x0e, 0x43, 0x72, 0x65,
-    0x61, 0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x0e, 0x0a, 0x02, 0x69,
-    0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x1c, 0x0a, 0x0a, 0x47,
-    0x65, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18,
-    0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0xa8, 0x01, 0x0a, 0x0b, 0x47, 0x65,
-    0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18,
-    0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x12, 0x2f, 0x0a, 0x05, 0x69, 0x74, 0x65,
-    0x6d, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x63, 0x61, 0x72, 0x74, 0x2e,
-    0x76, 0x31, 0x2e, 0x47, 0x65, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x2e, 0x49,
-    0x74, 0x65, 0x6d, 0x52, 0x05, 0x69, 0x74, 0x65, 0x6d, 0x73, 0x1a, 0x58, 0x0a, 0x04, 0x49, 0x74,
-    0x65, 0x6d, 0x12, 0x1b, 0x0a, 0x09, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18,
-    0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x49, 0x64, 0x12,
-    0x17, 0x0a, 0x07, 0x69, 0x74, 0x65, 0x6d, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09,
-    0x52, 0x06, 0x69, 0x74, 0x65, 0x6d, 0x49, 0x64, 0x12, 0x1a, 0x0a, 0x08, 0x71, 0x75, 0x61, 0x6e,
-    0x74, 0x69, 0x74, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x08, 0x71, 0x75, 0x61, 0x6e,
-    0x74, 0x69, 0x74, 0x79, 0x22, 0x56, 0x0a, 0x0e, 0x41, 0x64, 0x64, 0x49, 0x74, 0x65, 0x6d, 0x52,
-    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01,
-    0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x12, 0x1b, 0x0a, 0x09, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74,
-    0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x74, 0x65, 0x6e, 0x61, 0x6e,
-    0x74, 0x49, 0x64, 0x12, 0x17, 0x0a, 0x07, 0x69, 0x74, 0x65, 0x6d, 0x5f, 0x69, 0x64, 0x18, 0x03,
-    0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x69, 0x74, 0x65, 0x6d, 0x49, 0x64, 0x22, 0x11, 0x0a, 0x0f,
-    0x41, 0x64, 0x64, 0x49, 0x74, 0x65, 0x6d, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22,
-    0x59, 0x0a, 0x11, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x49, 0x74, 0x65, 0x6d, 0x52, 0x65, 0x71,
-    0x75, 0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09,
-    0x52, 0x02, 0x69, 0x64, 0x12, 0x1b, 0x0a, 0x09, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x5f, 0x69,
-    0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x49,
-    0x64, 0x12, 0x17, 0x0a, 0x07, 0x69, 0x74, 0x65, 0x6d, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01,
-    0x28, 0x09, 0x52, 0x06, 0x69, 0x74, 0x65, 0x6d, 0x49, 0x64, 0x22, 0x14, 0x0a, 0x12, 0x52, 0x65,
-    0x6d, 0x6f, 0x76, 0x65, 0x49, 0x74, 0x65, 0x6d, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
-    0x22, 0x23, 0x0a, 0x11, 0x50, 0x6c, 0x61, 0x63, 0x65, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x52, 0x65,
+    0x07, 0x63, 0x61, 0x72, 0x74, 0x2e, 0x76, 0x31, 0x22, 0x51, 0x0a, 0x0d, 0x43, 0x72, 0x65, 0x61,
+    0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x2c, 0x0a, 0x0f, 0x69, 0x64, 0x65,
+    0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x5f, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x09, 0x48, 0x00, 0x52, 0x0e, 0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x63,
+    0x79, 0x4b, 0x65, 0x79, 0x88, 0x01, 0x01, 0x42, 0x12, 0x0a, 0x10, 0x5f, 0x69, 0x64, 0x65, 0x6d,
+    0x70, 0x6f, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x5f, 0x6b, 0x65, 0x79, 0x22, 0x3a, 0x0a, 0x0e, 0x43,
+    0x72, 0x65, 0x61, 0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x0e, 0x0a,
+    0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x12, 0x18, 0x0a,
+    0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07,
+    0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x22, 0x1c, 0x0a, 0x0a, 0x47, 0x65, 0x74, 0x52, 0x65,
     0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28,
-    0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x14, 0x0a, 0x12, 0x50, 0x6c, 0x61, 0x63, 0x65, 0x4f, 0x72,
-    0x64, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x32, 0xc6, 0x02, 0x0a, 0x0b,
-    0x43, 0x61, 0x72, 0x74, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x39, 0x0a, 0x06, 0x43,
-    0x72, 0x65, 0x61, 0x74, 0x65, 0x12, 0x16, 0x2e, 0x63, 0x61, 0x72, 0x74, 0x2e, 0x76, 0x31, 0x2e,
-    0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x17, 0x2e,
-    0x63, 0x61, 0x72, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52, 0x65,
-    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x30, 0x0a, 0x03, 0x47, 0x65, 0x74, 0x12, 0x13, 0x2e,
-    0x63, 0x61, 0x72, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65,
-    0x73, 0x74, 0x1a, 0x14, 0x2e, 0x63, 0x61, 0x72, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x74,
-    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3c, 0x0a, 0x07, 0x41, 0x64, 0x64, 0x49,
-    0x74, 0x65, 0x6d, 0x12, 0x17, 0x2e, 0x63, 0x61, 0x72, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x64,
-    0x64, 0x49, 0x74, 0x65, 0x6d, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x18, 0x2e, 0x63,
-    0x61, 0x72, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x64, 0x64, 0x49, 0x74, 0x65, 0x6d, 0x52, 0x65,
-    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x45, 0x0a, 0x0a, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65,
-    0x49, 0x74, 0x65, 0x6d, 0x12, 0x1a, 0x2e, 0x63, 0x61, 0x72, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x52,
-    0x65, 0x6d, 0x6f, 0x76, 0x65, 0x49, 0x74, 0x65, 0x6d, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
-    0x1a, 0x1b, 0x2e, 0x63, 0x61, 0x72, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x52, 0x65, 0x6d, 0x6f, 0x76,
-    0x65, 0x49, 0x74, 0x65, 0x6d, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x45, 0x0a,
-    0x0a, 0x50, 0x6c, 0x61, 0x63, 0x65, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x12, 0x1a, 0x2e, 0x63, 0x61,
-    0x72, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x6c, 0x61, 0x63, 0x65, 0x4f, 0x72, 0x64, 0x65, 0x72,
-    0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x63, 0x61, 0x72, 0x74, 0x2e, 0x76,
-    0x31, 0x2e, 0x50, 0x6c, 0x61, 0x63, 0x65, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70,
-    0x6f, 0x6e, 0x73, 0x65, 0x4a, 0xf7, 0x0b, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x3a, 0x01, 0x0a,
-    0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03,
-    0x02, 0x00, 0x10, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x03, 0x04, 0x00, 0x18, 0x0a, 0x0a,
-    0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x04, 0x08, 0x15, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01,
-    0x12, 0x04, 0x06, 0x00, 0x08, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x06,
-    0x08, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x07, 0x02, 0x10, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x07, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x07, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x07, 0x0e, 0x0f, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12,
-    0x04, 0x0a, 0x00, 0x0c, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x0a, 0x08,
-    0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x0b, 0x02, 0x10, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x0b, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0b, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
-    0x02, 0x00, 0x03, 0x12, 0x03, 0x0b, 0x0e, 0x0f, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04,
-    0x0e, 0x00, 0x17, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x0e, 0x08, 0x13,
-    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x03, 0x03, 0x00, 0x12, 0x04, 0x0f, 0x02, 0x13, 0x03, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x03, 0x03, 0x00, 0x01, 0x12, 0x03, 0x0f, 0x0a, 0x0e, 0x0a, 0x0d, 0x0a, 0x06,
-    0x04, 0x03, 0x03, 0x00, 0x02, 0x00, 0x12, 0x03, 0x10, 0x04, 0x19, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
-    0x03, 0x03, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x10, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
-    0x03, 0x03, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x10, 0x0b, 0x14, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
-    0x03, 0x03, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x10, 0x17, 0x18, 0x0a, 0x0d, 0x0a, 0x06, 0x04,
-    0x03, 0x03, 0x00, 0x02, 0x01, 0x12, 0x03, 0x11, 0x04, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03,
-    0x03, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x11, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03,
-    0x03, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x11, 0x0b, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03,
-    0x03, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x11, 0x15, 0x16, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x03,
-    0x03, 0x00, 0x02, 0x02, 0x12, 0x03, 0x12, 0x04, 0x18, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03, 0x03,
-    0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x12, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03, 0x03,
-    0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x12, 0x0b, 0x13, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03, 0x03,
-    0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x12, 0x16, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02,
-    0x00, 0x12, 0x03, 0x15, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12,
-    0x03, 0x15, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x15,
-    0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x15, 0x0e, 0x0f,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x16, 0x02, 0x1a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x03, 0x02, 0x01, 0x04, 0x12, 0x03, 0x16, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x03, 0x02, 0x01, 0x06, 0x12, 0x03, 0x16, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
-    0x01, 0x01, 0x12, 0x03, 0x16, 0x10, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03,
-    0x12, 0x03, 0x16, 0x18, 0x19, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x19, 0x00, 0x1d,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x19, 0x08, 0x16, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x1a, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
-    0x02, 0x00, 0x05, 0x12, 0x03, 0x1a, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x1a, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x1a, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12, 0x03, 0x1b, 0x02,
-    0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x05, 0x12, 0x03, 0x1b, 0x02, 0x08, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1b, 0x09, 0x12, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x1b, 0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x04, 0x02, 0x02, 0x12, 0x03, 0x1c, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02,
-    0x05, 0x12, 0x03, 0x1c, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12,
-    0x03, 0x1c, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x03, 0x12, 0x03, 0x1c,
-    0x13, 0x14, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x03, 0x1f, 0x00, 0x1a, 0x0a, 0x0a, 0x0a,
-    0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x1f, 0x08, 0x17, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x06, 0x12,
-    0x04, 0x21, 0x00, 0x25, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x21, 0x08,
-    0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x22, 0x02, 0x10, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x05, 0x12, 0x03, 0x22, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03, 0x22, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
-    0x02, 0x00, 0x03, 0x12, 0x03, 0x22, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01,
-    0x12, 0x03, 0x23, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x05, 0x12, 0x03,
-    0x23, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x23, 0x09,
-    0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x03, 0x12, 0x03, 0x23, 0x15, 0x16, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x02, 0x12, 0x03, 0x24, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x06, 0x02, 0x02, 0x05, 0x12, 0x03, 0x24, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
-    0x02, 0x02, 0x01, 0x12, 0x03, 0x24, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02,
-    0x03, 0x12, 0x03, 0x24, 0x13, 0x14, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x03, 0x27, 0x00,
-    0x1d, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x03, 0x27, 0x08, 0x1a, 0x0a, 0x0a, 0x0a,
-    0x02, 0x04, 0x08, 0x12, 0x04, 0x29, 0x00, 0x2b, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01,
-    0x12, 0x03, 0x29, 0x08, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x2a,
-    0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x05, 0x12, 0x03, 0x2a, 0x02, 0x08,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x03, 0x2a, 0x09, 0x0b, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2a, 0x0e, 0x0f, 0x0a, 0x09, 0x0a, 0x02,
-    0x04, 0x09, 0x12, 0x03, 0x2d, 0x00, 0x1d, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03,
-    0x2d, 0x08, 0x1a, 0x0a, 0x0a, 0x0a, 0x02, 0x06, 0x00, 0x12, 0x04, 0x2f, 0x00, 0x3a, 0x01, 0x0a,
-    0x0a, 0x0a, 0x03, 0x06, 0x00, 0x01, 0x12, 0x03, 0x2f, 0x08, 0x13, 0x0a, 0x2d, 0x0a, 0x04, 0x06,
-    0x00, 0x02, 0x00, 0x12, 0x03, 0x31, 0x02, 0x35, 0x1a, 0x20, 0x20, 0xe7, 0xa9, 0xba, 0xe3, 0x81,
-    0xae, 0xe3, 0x82, 0xab, 0xe3, 0x83, 0xbc, 0xe3, 0x83, 0x88, 0xe3, 0x82, 0x92, 0xe4, 0xbd, 0x9c,
-    0xe6, 0x88, 0x90, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00,
-    0x02, 0x00, 0x01, 0x12, 0x03, 0x31, 0x06, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00,
-    0x02, 0x12, 0x03, 0x31, 0x0d, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x31, 0x25, 0x33, 0x0a, 0x27, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x01, 0x12, 0x03, 0x33, 0x02,
-    0x2c, 0x1a, 0x1a, 0x20, 0xe3, 0x82, 0xab, 0xe3, 0x83, 0xbc, 0xe3, 0x83, 0x88, 0xe3, 0x82, 0x92,
-    0xe5, 0x8f, 0x96, 0xe5, 0xbe, 0x97, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x06, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x33, 0x06, 0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x06,
-    0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x33, 0x0a, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02,
-    0x01, 0x03, 0x12, 0x03, 0x33, 0x1f, 0x2a, 0x0a, 0x24, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x02, 0x12,
-    0x03, 0x35, 0x02, 0x38, 0x1a, 0x17, 0x20, 0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0xe3, 0x82, 0x92,
-    0xe8, 0xbf, 0xbd, 0xe5, 0x8a, 0xa0, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x06, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x35, 0x06, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x06,
-    0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x35, 0x0e, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02,
-    0x02, 0x03, 0x12, 0x03, 0x35, 0x27, 0x36, 0x0a, 0x24, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x03, 0x12,
-    0x03, 0x37, 0x02, 0x41, 0x1a, 0x17, 0x20, 0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0xe3, 0x82, 0x92,
-    0xe5, 0x89, 0x8a, 0xe9, 0x99, 0xa4, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x06, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x37, 0x06, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x06,
-    0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x37, 0x11, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02,
-    0x03, 0x03, 0x12, 0x03, 0x37, 0x2d, 0x3f, 0x0a, 0x1b, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x04, 0x12,
-    0x03, 0x39, 0x02, 0x41, 0x1a, 0x0e, 0x20, 0xe6, 0xb3, 0xa8, 0xe6, 0x96, 0x87, 0xe3, 0x81, 0x99,
-    0xe3, 0x82, 0x8b, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x39,
-    0x06, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x39, 0x11, 0x22,
-    0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x04, 0x03, 0x12, 0x03, 0x39, 0x2d, 0x3f, 0x62, 0x06,
-    0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+    0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0xc2, 0x01, 0x0a, 0x0b, 0x47, 0x65, 0x74, 0x52, 0x65, 0x73,
+    0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28,
+    0x09, 0x52, 0x02, 0x69, 0x64, 0x12, 0x2f, 0x0a, 0x05, 0x69, 0x74, 0x65, 0x6d, 0x73, 0x18, 0x02,
+    0x20, 0x03, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x63, 0x61, 0x72, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x47,
+    0x65, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x2e, 0x49, 0x74, 0x65, 0x6d, 0x52,
+    0x05, 0x69, 0x74, 0x65, 0x6d, 0x73, 0x12, 0x18, 0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f,
+    0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e,
+    0x1a, 0x58, 0x0a, 0x04, 0x49, 0x74, 0x65, 0x6d, 0x12, 0x1b, 0x0a, 0x09, 0x74, 0x65, 0x6e, 0x61,
+    0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x74, 0x65, 0x6e,
+    0x61, 0x6e, 0x74, 0x49, 0x64, 0x12, 0x17, 0x0a, 0x07, 0x69, 0x74, 0x65, 0x6d, 0x5f, 0x69, 0x64,
+    0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x69, 0x74, 0x65, 0x6d, 0x49, 0x64, 0x12, 0x1a,
+    0x0a, 0x08, 0x71, 0x75, 0x61, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d,
+    0x52, 0x08, 0x71, 0x75, 0x61, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x22, 0xdd, 0x01, 0x0a, 0x0e, 0x41,
+    0x64, 0x64, 0x49, 0x74, 0x65, 0x6d, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a,
+    0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x12, 0x1b, 0x0a,
+    0x09, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x08, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x49, 0x64, 0x12, 0x17, 0x0a, 0x07, 0x69, 0x74,
+    0x65, 0x6d, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x69, 0x74, 0x65,
+    0x6d, 0x49, 0x64, 0x12, 0x2e, 0x0a, 0x10, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x5f,
+    0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x48, 0x00, 0x52,
+    0x0f, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x56, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e,
+    0x88, 0x01, 0x01, 0x12, 0x2c, 0x0a, 0x0f, 0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e,
+    0x63, 0x79, 0x5f, 0x6b, 0x65, 0x79, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x48, 0x01, 0x52, 0x0e,
+    0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x4b, 0x65, 0x79, 0x88, 0x01,
+    0x01, 0x42, 0x13, 0x0a, 0x11, 0x5f, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x5f, 0x76,
+    0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x42, 0x12, 0x0a, 0x10, 0x5f, 0x69, 0x64, 0x65, 0x6d, 0x70,
+    0x6f, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x5f, 0x6b, 0x65, 0x79, 0x22, 0x2b, 0x0a, 0x0f, 0x41, 0x64,
+    0x64, 0x49, 0x74, 0x65, 0x6d, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x18, 0x0a,
+    0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07,
+    0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x22, 0xe0, 0x01, 0x0a, 0x11, 0x52, 0x65, 0x6d, 0x6f,
+    0x76, 0x65, 0x49, 0x74, 0x65, 0x6d, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a,
+    0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x12, 0x1b, 0x0a,
+    0x09, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x08, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x49, 0x64, 0x12, 0x17, 0x0a, 0x07, 0x69, 0x74,
+    0x65, 0x6d, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x69, 0x74, 0x65,
+    0x6d, 0x49, 0x64, 0x12, 0x2e, 0x0a, 0x10, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x5f,
+    0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x48, 0x00, 0x52,
+    0x0f, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x56, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e,
+    0x88, 0x01, 0x01, 0x12, 0x2c, 0x0a, 0x0f, 0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e,
+    0x63, 0x79, 0x5f, 0x6b, 0x65, 0x79, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x48, 0x01, 0x52, 0x0e,
+    0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x4b, 0x65, 0x79, 0x88, 0x01,
+    0x01, 0x42, 0x13, 0x0a, 0x11, 0x5f, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x5f, 0x76,
+    0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x42, 0x12, 0x0a, 0x10, 0x5f, 0x69, 0x64, 0x65, 0x6d, 0x70,
+    0x6f, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x5f, 0x6b, 0x65, 0x79, 0x22, 0x2e, 0x0a, 0x12, 0x52, 0x65,
+    0x6d, 0x6f, 0x76, 0x65, 0x49, 0x74, 0x65, 0x6d, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+    0x12, 0x18, 0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28,
+    0x04, 0x52, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x22, 0xaa, 0x01, 0x0a, 0x11, 0x50,
+    0x6c, 0x61, 0x63, 0x65, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64,
+    0x12, 0x2e, 0x0a, 0x10, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x5f, 0x76, 0x65, 0x72,
+    0x73, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x48, 0x00, 0x52, 0x0f, 0x65, 0x78,
+    0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x56, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x88, 0x01, 0x01,
+    0x12, 0x2c, 0x0a, 0x0f, 0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x5f,
+    0x6b, 0x65, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x48, 0x01, 0x52, 0x0e, 0x69, 0x64, 0x65,
+    0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x4b, 0x65, 0x79, 0x88, 0x01, 0x01, 0x42, 0x13,
+    0x0a, 0x11, 0x5f, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x5f, 0x76, 0x65, 0x72, 0x73,
+    0x69, 0x6f, 0x6e, 0x42, 0x12, 0x0a, 0x10, 0x5f, 0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65,
+    0x6e, 0x63, 0x79, 0x5f, 0x6b, 0x65, 0x79, 0x22, 0x2e, 0x0a, 0x12, 0x50, 0x6c, 0x61, 0x63, 0x65,
+    0x4f, 0x72, 0x64, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x18, 0x0a,
+    0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07,
+    0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x32, 0xc6, 0x02, 0x0a, 0x0b, 0x43, 0x61, 0x72, 0x74,
+    0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x39, 0x0a, 0x06, 0x43, 0x72, 0x65, 0x61, 0x74,
+    0x65, 0x12, 0x16, 0x2e, 0x63, 0x61, 0x72, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x72, 0x65, 0x61,
+    0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x17, 0x2e, 0x63, 0x61, 0x72, 0x74,
+    0x2e, 0x76, 0x31, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+    0x73, 0x65, 0x12, 0x30, 0x0a, 0x03, 0x47, 0x65, 0x74, 0x12, 0x13, 0x2e, 0x63, 0x61, 0x72, 0x74,
+    0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x14,
+    0x2e, 0x63, 0x61, 0x72, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x74, 0x52, 0x65, 0x73, 0x70,
+    0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3c, 0x0a, 0x07, 0x41, 0x64, 0x64, 0x49, 0x74, 0x65, 0x6d, 0x12,
+    0x17, 0x2e, 0x63, 0x61, 0x72, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x64, 0x64, 0x49, 0x74, 0x65,
+    0x6d, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x18, 0x2e, 0x63, 0x61, 0x72, 0x74, 0x2e,
+    0x76, 0x31, 0x2e, 0x41, 0x64, 0x64, 0x49, 0x74, 0x65, 0x6d, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+    0x73, 0x65, 0x12, 0x45, 0x0a, 0x0a, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x49, 0x74, 0x65, 0x6d,
+    0x12, 0x1a, 0x2e, 0x63, 0x61, 0x72, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x52, 0x65, 0x6d, 0x6f, 0x76,
+    0x65, 0x49, 0x74, 0x65, 0x6d, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x63,
+    0x61, 0x72, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x49, 0x74, 0x65,
+    0x6d, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x45, 0x0a, 0x0a, 0x50, 0x6c, 0x61,
+    0x63, 0x65, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x12, 0x1a, 0x2e, 0x63, 0x61, 0x72, 0x74, 0x2e, 0x76,
+    0x31, 0x2e, 0x50, 0x6c, 0x61, 0x63, 0x65, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75,
+    0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x63, 0x61, 0x72, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x6c,
+    0x61, 0x63, 0x65, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+    0x4a, 0x87, 0x19, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x56, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x02,
+    0x12, 0x03, 0x02, 0x00, 0x10, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x04, 0x00, 0x07,
+    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x04, 0x08, 0x15, 0x0a, 0x78, 0x0a,
+    0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x06, 0x02, 0x26, 0x1a, 0x6b, 0x20, 0xe5, 0x90, 0x8c,
+    0xe3, 0x81, 0x98, 0xe5, 0x86, 0xaa, 0xe7, 0xad, 0x89, 0xe3, 0x82, 0xad, 0xe3, 0x83, 0xbc, 0xe3,
+    0x81, 0xae, 0xe3, 0x83, 0xaa, 0xe3, 0x82, 0xaf, 0xe3, 0x82, 0xa8, 0xe3, 0x82, 0xb9, 0xe3, 0x83,
+    0x88, 0xe3, 0x82, 0x92, 0xe5, 0x86, 0x8d, 0xe5, 0xae, 0x9f, 0xe8, 0xa1, 0x8c, 0xe3, 0x81, 0x97,
+    0xe3, 0x81, 0x9f, 0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88, 0xe3, 0x81, 0xaf, 0xe6, 0x9c, 0x80, 0xe5,
+    0x88, 0x9d, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0xaa, 0xe3, 0x82, 0xaf, 0xe3, 0x82, 0xa8, 0xe3, 0x82,
+    0xb9, 0xe3, 0x83, 0x88, 0xe3, 0x81, 0xae, 0xe7, 0xb5, 0x90, 0xe6, 0x9e, 0x9c, 0xe3, 0x82, 0x92,
+    0xe8, 0xbf, 0x94, 0xe3, 0x81, 0x99, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x06, 0x12, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x06, 0x24, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x06, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x06, 0x0b, 0x11, 0x0a,
+    0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x09, 0x00, 0x0d, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x01, 0x01, 0x12, 0x03, 0x09, 0x08, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12,
+    0x03, 0x0a, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0a,
+    0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0a, 0x0e, 0x0f,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x0a, 0x02, 0x08, 0x0a, 0x3f,
+    0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x0c, 0x02, 0x15, 0x1a, 0x32, 0x20, 0xe3, 0x82,
+    0xb3, 0xe3, 0x83, 0x9e, 0xe3, 0x83, 0xb3, 0xe3, 0x83, 0x89, 0xe5, 0xae, 0x9f, 0xe8, 0xa1, 0x8c,
+    0xe5, 0xbe, 0x8c, 0xe3, 0x81, 0xae, 0xe9, 0x9b, 0x86, 0xe7, 0xb4, 0x84, 0xe3, 0x81, 0xae, 0xe3,
+    0x83, 0x90, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x83, 0xa7, 0xe3, 0x83, 0xb3, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0c, 0x09, 0x10, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0c, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x0c, 0x02, 0x08, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12,
+    0x04, 0x0f, 0x00, 0x11, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x0f, 0x08,
+    0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x10, 0x02, 0x10, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x10, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x10, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x00, 0x05, 0x12, 0x03, 0x10, 0x02, 0x08, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04,
+    0x13, 0x00, 0x1e, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x13, 0x08, 0x13,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x1a, 0x02, 0x10, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1a, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1a, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x00, 0x05, 0x12, 0x03, 0x1a, 0x02, 0x08, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12,
+    0x03, 0x1b, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1b,
+    0x10, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x1b, 0x18, 0x19,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x04, 0x12, 0x03, 0x1b, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x06, 0x12, 0x03, 0x1b, 0x0b, 0x0f, 0x0a, 0x27, 0x0a, 0x04,
+    0x04, 0x03, 0x02, 0x02, 0x12, 0x03, 0x1d, 0x02, 0x15, 0x1a, 0x1a, 0x20, 0xe9, 0x9b, 0x86, 0xe7,
+    0xb4, 0x84, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x90, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x83,
+    0xa7, 0xe3, 0x83, 0xb3, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x1d, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x03, 0x12, 0x03, 0x1d, 0x13,
+    0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x05, 0x12, 0x03, 0x1d, 0x02, 0x08, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x03, 0x03, 0x00, 0x12, 0x04, 0x14, 0x02, 0x18, 0x03, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x03, 0x00, 0x01, 0x12, 0x03, 0x14, 0x0a, 0x0e, 0x0a, 0x0d, 0x0a, 0x06, 0x04,
+    0x03, 0x03, 0x00, 0x02, 0x00, 0x12, 0x03, 0x15, 0x04, 0x19, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03,
+    0x03, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x15, 0x0b, 0x14, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03,
+    0x03, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x15, 0x17, 0x18, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03,
+    0x03, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x15, 0x04, 0x0a, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x03,
+    0x03, 0x00, 0x02, 0x01, 0x12, 0x03, 0x16, 0x04, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03, 0x03,
+    0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x16, 0x0b, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03, 0x03,
+    0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x16, 0x15, 0x16, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03, 0x03,
+    0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x16, 0x04, 0x0a, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x03, 0x03,
+    0x00, 0x02, 0x02, 0x12, 0x03, 0x17, 0x04, 0x18, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03, 0x03, 0x00,
+    0x02, 0x02, 0x01, 0x12, 0x03, 0x17, 0x0b, 0x13, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03, 0x03, 0x00,
+    0x02, 0x02, 0x03, 0x12, 0x03, 0x17, 0x16, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03, 0x03, 0x00,
+    0x02, 0x02, 0x05, 0x12, 0x03, 0x17, 0x04, 0x0a, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04,
+    0x20, 0x00, 0x28, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x20, 0x08, 0x16,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x21, 0x02, 0x10, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x21, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x21, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
+    0x00, 0x05, 0x12, 0x03, 0x21, 0x02, 0x08, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12,
+    0x03, 0x22, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x22,
+    0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x22, 0x15, 0x16,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x05, 0x12, 0x03, 0x22, 0x02, 0x08, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x04, 0x02, 0x02, 0x12, 0x03, 0x23, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x23, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
+    0x02, 0x03, 0x12, 0x03, 0x23, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x05,
+    0x12, 0x03, 0x23, 0x02, 0x08, 0x0a, 0x5d, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x03, 0x12, 0x03, 0x25,
+    0x02, 0x27, 0x1a, 0x50, 0x20, 0xe9, 0x9b, 0x86, 0xe7, 0xb4, 0x84, 0xe3, 0x81, 0x8c, 0xe6, 0x8c,
+    0x87, 0xe5, 0xae, 0x9a, 0xe3, 0x81, 0x97, 0xe3, 0x81, 0x9f, 0xe3, 0x83, 0x90, 0xe3, 0x83, 0xbc,
+    0xe3, 0x82, 0xb8, 0xe3, 0x83, 0xa7, 0xe3, 0x83, 0xb3, 0xe3, 0x81, 0xae, 0xe5, 0xa0, 0xb4, 0xe5,
+    0x90, 0x88, 0xe3, 0x81, 0xae, 0xe3, 0x81, 0xbf, 0xe3, 0x82, 0xb3, 0xe3, 0x83, 0x9e, 0xe3, 0x83,
+    0xb3, 0xe3, 0x83, 0x89, 0xe3, 0x82, 0x92, 0xe5, 0xae, 0x9f, 0xe8, 0xa1, 0x8c, 0xe3, 0x81, 0x99,
+    0xe3, 0x82, 0x8b, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x03, 0x01, 0x12, 0x03, 0x25,
+    0x12, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x03, 0x03, 0x12, 0x03, 0x25, 0x25, 0x26,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x03, 0x04, 0x12, 0x03, 0x25, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x03, 0x05, 0x12, 0x03, 0x25, 0x0b, 0x11, 0x0a, 0x78, 0x0a, 0x04,
+    0x04, 0x04, 0x02, 0x04, 0x12, 0x03, 0x27, 0x02, 0x26, 0x1a, 0x6b, 0x20, 0xe5, 0x90, 0x8c, 0xe3,
+    0x81, 0x98, 0xe5, 0x86, 0xaa, 0xe7, 0xad, 0x89, 0xe3, 0x82, 0xad, 0xe3, 0x83, 0xbc, 0xe3, 0x81,
+    0xae, 0xe3, 0x83, 0xaa, 0xe3, 0x82, 0xaf, 0xe3, 0x82, 0xa8, 0xe3, 0x82, 0xb9, 0xe3, 0x83, 0x88,
+    0xe3, 0x82, 0x92, 0xe5, 0x86, 0x8d, 0xe5, 0xae, 0x9f, 0xe8, 0xa1, 0x8c, 0xe3, 0x81, 0x97, 0xe3,
+    0x81, 0x9f, 0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88, 0xe3, 0x81, 0xaf, 0xe6, 0x9c, 0x80, 0xe5, 0x88,
+    0x9d, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0xaa, 0xe3, 0x82, 0xaf, 0xe3, 0x82, 0xa8, 0xe3, 0x82, 0xb9,
+    0xe3, 0x83, 0x88, 0xe3, 0x81, 0xae, 0xe7, 0xb5, 0x90, 0xe6, 0x9e, 0x9c, 0xe3, 0x82, 0x92, 0xe8,
+    0xbf, 0x94, 0xe3, 0x81, 0x99, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x04, 0x01, 0x12,
+    0x03, 0x27, 0x12, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x04, 0x03, 0x12, 0x03, 0x27,
+    0x24, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x04, 0x04, 0x12, 0x03, 0x27, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x04, 0x05, 0x12, 0x03, 0x27, 0x0b, 0x11, 0x0a, 0x0a,
+    0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x2a, 0x00, 0x2d, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05,
+    0x01, 0x12, 0x03, 0x2a, 0x08, 0x17, 0x0a, 0x3f, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03,
+    0x2c, 0x02, 0x15, 0x1a, 0x32, 0x20, 0xe3, 0x82, 0xb3, 0xe3, 0x83, 0x9e, 0xe3, 0x83, 0xb3, 0xe3,
+    0x83, 0x89, 0xe5, 0xae, 0x9f, 0xe8, 0xa1, 0x8c, 0xe5, 0xbe, 0x8c, 0xe3, 0x81, 0xae, 0xe9, 0x9b,
+    0x86, 0xe7, 0xb4, 0x84, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x90, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8,
+    0xe3, 0x83, 0xa7, 0xe3, 0x83, 0xb3, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x2c, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x2c, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x2c, 0x02,
+    0x08, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x2f, 0x00, 0x37, 0x01, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x2f, 0x08, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02,
+    0x00, 0x12, 0x03, 0x30, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x30, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x30,
+    0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x05, 0x12, 0x03, 0x30, 0x02, 0x08,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x03, 0x31, 0x02, 0x17, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x31, 0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x01, 0x03, 0x12, 0x03, 0x31, 0x15, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x01, 0x05, 0x12, 0x03, 0x31, 0x02, 0x08, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x02, 0x12,
+    0x03, 0x32, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x01, 0x12, 0x03, 0x32,
+    0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x03, 0x12, 0x03, 0x32, 0x13, 0x14,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x05, 0x12, 0x03, 0x32, 0x02, 0x08, 0x0a, 0x5d,
+    0x0a, 0x04, 0x04, 0x06, 0x02, 0x03, 0x12, 0x03, 0x34, 0x02, 0x27, 0x1a, 0x50, 0x20, 0xe9, 0x9b,
+    0x86, 0xe7, 0xb4, 0x84, 0xe3, 0x81, 0x8c, 0xe6, 0x8c, 0x87, 0xe5, 0xae, 0x9a, 0xe3, 0x81, 0x97,
+    0xe3, 0x81, 0x9f, 0xe3, 0x83, 0x90, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x83, 0xa7, 0xe3,
+    0x83, 0xb3, 0xe3, 0x81, 0xae, 0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88, 0xe3, 0x81, 0xae, 0xe3, 0x81,
+    0xbf, 0xe3, 0x82, 0xb3, 0xe3, 0x83, 0x9e, 0xe3, 0x83, 0xb3, 0xe3, 0x83, 0x89, 0xe3, 0x82, 0x92,
+    0xe5, 0xae, 0x9f, 0xe8, 0xa1, 0x8c, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x03, 0x01, 0x12, 0x03, 0x34, 0x12, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x03, 0x03, 0x12, 0x03, 0x34, 0x25, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x03, 0x04, 0x12, 0x03, 0x34, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x05,
+    0x12, 0x03, 0x34, 0x0b, 0x11, 0x0a, 0x78, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x04, 0x12, 0x03, 0x36,
+    0x02, 0x26, 0x1a, 0x6b, 0x20, 0xe5, 0x90, 0x8c, 0xe3, 0x81, 0x98, 0xe5, 0x86, 0xaa, 0xe7, 0xad,
+    0x89, 0xe3, 0x82, 0xad, 0xe3, 0x83, 0xbc, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0xaa, 0xe3, 0x82, 0xaf,
+    0xe3, 0x82, 0xa8, 0xe3, 0x82, 0xb9, 0xe3, 0x83, 0x88, 0xe3, 0x82, 0x92, 0xe5, 0x86, 0x8d, 0xe5,
+    0xae, 0x9f, 0xe8, 0xa1, 0x8c, 0xe3, 0x81, 0x97, 0xe3, 0x81, 0x9f, 0xe5, 0xa0, 0xb4, 0xe5, 0x90,
+    0x88, 0xe3, 0x81, 0xaf, 0xe6, 0x9c, 0x80, 0xe5, 0x88, 0x9d, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0xaa,
+    0xe3, 0x82, 0xaf, 0xe3, 0x82, 0xa8, 0xe3, 0x82, 0xb9, 0xe3, 0x83, 0x88, 0xe3, 0x81, 0xae, 0xe7,
+    0xb5, 0x90, 0xe6, 0x9e, 0x9c, 0xe3, 0x82, 0x92, 0xe8, 0xbf, 0x94, 0xe3, 0x81, 0x99, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x01, 0x12, 0x03, 0x36, 0x12, 0x21, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x04, 0x03, 0x12, 0x03, 0x36, 0x24, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x04, 0x04, 0x12, 0x03, 0x36, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x04, 0x05, 0x12, 0x03, 0x36, 0x0b, 0x11, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x04, 0x39,
+    0x00, 0x3c, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x03, 0x39, 0x08, 0x1a, 0x0a,
+    0x3f, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x3b, 0x02, 0x15, 0x1a, 0x32, 0x20, 0xe3,
+    0x82, 0xb3, 0xe3, 0x83, 0x9e, 0xe3, 0x83, 0xb3, 0xe3, 0x83, 0x89, 0xe5, 0xae, 0x9f, 0xe8, 0xa1,
+    0x8c, 0xe5, 0xbe, 0x8c, 0xe3, 0x81, 0xae, 0xe9, 0x9b, 0x86, 0xe7, 0xb4, 0x84, 0xe3, 0x81, 0xae,
+    0xe3, 0x83, 0x90, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x83, 0xa7, 0xe3, 0x83, 0xb3, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3b, 0x09, 0x10, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x3b, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x00, 0x05, 0x12, 0x03, 0x3b, 0x02, 0x08, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x08,
+    0x12, 0x04, 0x3e, 0x00, 0x44, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x03, 0x3e,
+    0x08, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x3f, 0x02, 0x10, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3f, 0x09, 0x0b, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x03, 0x3f, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x00, 0x05, 0x12, 0x03, 0x3f, 0x02, 0x08, 0x0a, 0x5d, 0x0a, 0x04, 0x04, 0x08, 0x02,
+    0x01, 0x12, 0x03, 0x41, 0x02, 0x27, 0x1a, 0x50, 0x20, 0xe9, 0x9b, 0x86, 0xe7, 0xb4, 0x84, 0xe3,
+    0x81, 0x8c, 0xe6, 0x8c, 0x87, 0xe5, 0xae, 0x9a, 0xe3, 0x81, 0x97, 0xe3, 0x81, 0x9f, 0xe3, 0x83,
+    0x90, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x83, 0xa7, 0xe3, 0x83, 0xb3, 0xe3, 0x81, 0xae,
+    0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88, 0xe3, 0x81, 0xae, 0xe3, 0x81, 0xbf, 0xe3, 0x82, 0xb3, 0xe3,
+    0x83, 0x9e, 0xe3, 0x83, 0xb3, 0xe3, 0x83, 0x89, 0xe3, 0x82, 0x92, 0xe5, 0xae, 0x9f, 0xe8, 0xa1,
+    0x8c, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01,
+    0x01, 0x12, 0x03, 0x41, 0x12, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x03, 0x12,
+    0x03, 0x41, 0x25, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x04, 0x12, 0x03, 0x41,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x05, 0x12, 0x03, 0x41, 0x0b, 0x11,
+    0x0a, 0x78, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x02, 0x12, 0x03, 0x43, 0x02, 0x26, 0x1a, 0x6b, 0x20,
+    0xe5, 0x90, 0x8c, 0xe3, 0x81, 0x98, 0xe5, 0x86, 0xaa, 0xe7, 0xad, 0x89, 0xe3, 0x82, 0xad, 0xe3,
+    0x83, 0xbc, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0xaa, 0xe3, 0x82, 0xaf, 0xe3, 0x82, 0xa8, 0xe3, 0x82,
+    0xb9, 0xe3, 0x83, 0x88, 0xe3, 0x82, 0x92, 0xe5, 0x86, 0x8d, 0xe5, 0xae, 0x9f, 0xe8, 0xa1, 0x8c,
+    0xe3, 0x81, 0x97, 0xe3, 0x81, 0x9f, 0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88, 0xe3, 0x81, 0xaf, 0xe6,
+    0x9c, 0x80, 0xe5, 0x88, 0x9d, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0xaa, 0xe3, 0x82, 0xaf, 0xe3, 0x82,
+    0xa8, 0xe3, 0x82, 0xb9, 0xe3, 0x83, 0x88, 0xe3, 0x81, 0xae, 0xe7, 0xb5, 0x90, 0xe6, 0x9e, 0x9c,
+    0xe3, 0x82, 0x92, 0xe8, 0xbf, 0x94, 0xe3, 0x81, 0x99, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x02, 0x01, 0x12, 0x03, 0x43, 0x12, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02,
+    0x03, 0x12, 0x03, 0x43, 0x24, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x04, 0x12,
+    0x03, 0x43, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x05, 0x12, 0x03, 0x43,
+    0x0b, 0x11, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x04, 0x46, 0x00, 0x49, 0x01, 0x0a, 0x0a,
+    0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x46, 0x08, 0x1a, 0x0a, 0x3f, 0x0a, 0x04, 0x04, 0x09,
+    0x02, 0x00, 0x12, 0x03, 0x48, 0x02, 0x15, 0x1a, 0x32, 0x20, 0xe3, 0x82, 0xb3, 0xe3, 0x83, 0x9e,
+    0xe3, 0x83, 0xb3, 0xe3, 0x83, 0x89, 0xe5, 0xae, 0x9f, 0xe8, 0xa1, 0x8c, 0xe5, 0xbe, 0x8c, 0xe3,
+    0x81, 0xae, 0xe9, 0x9b, 0x86, 0xe7, 0xb4, 0x84, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x90, 0xe3, 0x83,
+    0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x83, 0xa7, 0xe3, 0x83, 0xb3, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x09, 0x02, 0x00, 0x01, 0x12, 0x03, 0x48, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x48, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x05,
+    0x12, 0x03, 0x48, 0x02, 0x08, 0x0a, 0x0a, 0x0a, 0x02, 0x06, 0x00, 0x12, 0x04, 0x4b, 0x00, 0x56,
+    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x06, 0x00, 0x01, 0x12, 0x03, 0x4b, 0x08, 0x13, 0x0a, 0x2d, 0x0a,
+    0x04, 0x06, 0x00, 0x02, 0x00, 0x12, 0x03, 0x4d, 0x02, 0x35, 0x1a, 0x20, 0x20, 0xe7, 0xa9, 0xba,
+    0xe3, 0x81, 0xae, 0xe3, 0x82, 0xab, 0xe3, 0x83, 0xbc, 0xe3, 0x83, 0x88, 0xe3, 0x82, 0x92, 0xe4,
+    0xbd, 0x9c, 0xe6, 0x88, 0x90, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x06, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x4d, 0x06, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00,
+    0x02, 0x00, 0x02, 0x12, 0x03, 0x4d, 0x0d, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x4d, 0x25, 0x33, 0x0a, 0x27, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x01, 0x12, 0x03,
+    0x4f, 0x02, 0x2c, 0x1a, 0x1a, 0x20, 0xe3, 0x82, 0xab, 0xe3, 0x83, 0xbc, 0xe3, 0x83, 0x88, 0xe3,
+    0x82, 0x92, 0xe5, 0x8f, 0x96, 0xe5, 0xbe, 0x97, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x4f, 0x06, 0x09, 0x0a, 0x0c, 0x0a,
+    0x05, 0x06, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x4f, 0x0a, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x06,
+    0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x4f, 0x1f, 0x2a, 0x0a, 0x24, 0x0a, 0x04, 0x06, 0x00, 0x02,
+    0x02, 0x12, 0x03, 0x51, 0x02, 0x38, 0x1a, 0x17, 0x20, 0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0xe3,
+    0x82, 0x92, 0xe8, 0xbf, 0xbd, 0xe5, 0x8a, 0xa0, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x51, 0x06, 0x0d, 0x0a, 0x0c, 0x0a,
+    0x05, 0x06, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x51, 0x0e, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x06,
+    0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x51, 0x27, 0x36, 0x0a, 0x24, 0x0a, 0x04, 0x06, 0x00, 0x02,
+    0x03, 0x12, 0x03, 0x53, 0x02, 0x41, 0x1a, 0x17, 0x20, 0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0xe3,
+    0x82, 0x92, 0xe5, 0x89, 0x8a, 0xe9, 0x99, 0xa4, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x53, 0x06, 0x10, 0x0a, 0x0c, 0x0a,
+    0x05, 0x06, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x53, 0x11, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x06,
+    0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x53, 0x2d, 0x3f, 0x0a, 0x1b, 0x0a, 0x04, 0x06, 0x00, 0x02,
+    0x04, 0x12, 0x03, 0x55, 0x02, 0x41, 0x1a, 0x0e, 0x20, 0xe6, 0xb3, 0xa8, 0xe6, 0x96, 0x87, 0xe3,
+    0x81, 0x99, 0xe3, 0x82, 0x8b, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x04, 0x01, 0x12,
+    0x03, 0x55, 0x06, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x55,
+    0x11, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x04, 0x03, 0x12, 0x03, 0x55, 0x2d, 0x3f,
+    0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74,
+    0x6f, 0x33,
 ];
 include!("cart.v1.serde.rs");
 include!("cart.v1.tonic.rs");
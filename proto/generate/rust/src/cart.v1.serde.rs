@@ -16,6 +16,12 @@ impl serde::Serialize for AddItemRequest {
         if !self.item_id.is_empty() {
             len += 1;
         }
+        if self.expected_version.is_some() {
+            len += 1;
+        }
+        if self.idempotency_key.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("cart.v1.AddItemRequest", len)?;
         if !self.id.is_empty() {
             struct_ser.serialize_field("id", &self.id)?;
@@ -26,6 +32,13 @@ impl serde::Serialize for AddItemRequest {
         if !self.item_id.is_empty() {
             struct_ser.serialize_field("itemId", &self.item_id)?;
         }
+        if let Some(v) = self.expected_version.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("expectedVersion", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.idempotency_key.as_ref() {
+            struct_ser.serialize_field("idempotencyKey", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -41,6 +54,10 @@ impl<'de> serde::Deserialize<'de> for AddItemRequest {
             "tenantId",
             "item_id",
             "itemId",
+            "expected_version",
+            "expectedVersion",
+            "idempotency_key",
+            "idempotencyKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -48,6 +65,8 @@ impl<'de> serde::Deserialize<'de> for AddItemRequest {
             Id,
             TenantId,
             ItemId,
+            ExpectedVersion,
+            IdempotencyKey,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -72,6 +91,8 @@ impl<'de> serde::Deserialize<'de> for AddItemRequest {
                             "id" => Ok(GeneratedField::Id),
                             "tenantId" | "tenant_id" => Ok(GeneratedField::TenantId),
                             "itemId" | "item_id" => Ok(GeneratedField::ItemId),
+                            "expectedVersion" | "expected_version" => Ok(GeneratedField::ExpectedVersion),
+                            "idempotencyKey" | "idempotency_key" => Ok(GeneratedField::IdempotencyKey),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -94,6 +115,8 @@ impl<'de> serde::Deserialize<'de> for AddItemRequest {
                 let mut id__ = None;
                 let mut tenant_id__ = None;
                 let mut item_id__ = None;
+                let mut expected_version__ = None;
+                let mut idempotency_key__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -114,12 +137,28 @@ impl<'de> serde::Deserialize<'de> for AddItemRequest {
                             }
                             item_id__ = Some(map.next_value()?);
                         }
+                        GeneratedField::ExpectedVersion => {
+                            if expected_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expectedVersion"));
+                            }
+                            expected_version__ = 
+                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::IdempotencyKey => {
+                            if idempotency_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("idempotencyKey"));
+                            }
+                            idempotency_key__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(AddItemRequest {
                     id: id__.unwrap_or_default(),
                     tenant_id: tenant_id__.unwrap_or_default(),
                     item_id: item_id__.unwrap_or_default(),
+                    expected_version: expected_version__,
+                    idempotency_key: idempotency_key__,
                 })
             }
         }
@@ -133,8 +172,15 @@ impl serde::Serialize for AddItemResponse {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("cart.v1.AddItemResponse", len)?;
+        let mut len = 0;
+        if self.version != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("cart.v1.AddItemResponse", len)?;
+        if self.version != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("version", ToString::to_string(&self.version).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -145,10 +191,12 @@ impl<'de> serde::Deserialize<'de> for AddItemResponse {
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "version",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            Version,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -169,7 +217,10 @@ impl<'de> serde::Deserialize<'de> for AddItemResponse {
                     where
                         E: serde::de::Error,
                     {
-                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                        match value {
+                            "version" => Ok(GeneratedField::Version),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -187,10 +238,21 @@ impl<'de> serde::Deserialize<'de> for AddItemResponse {
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                let mut version__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
                 }
                 Ok(AddItemResponse {
+                    version: version__.unwrap_or_default(),
                 })
             }
         }
@@ -204,8 +266,14 @@ impl serde::Serialize for CreateRequest {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("cart.v1.CreateRequest", len)?;
+        let mut len = 0;
+        if self.idempotency_key.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("cart.v1.CreateRequest", len)?;
+        if let Some(v) = self.idempotency_key.as_ref() {
+            struct_ser.serialize_field("idempotencyKey", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -216,10 +284,13 @@ impl<'de> serde::Deserialize<'de> for CreateRequest {
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "idempotency_key",
+            "idempotencyKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            IdempotencyKey,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -240,7 +311,10 @@ impl<'de> serde::Deserialize<'de> for CreateRequest {
                     where
                         E: serde::de::Error,
                     {
-                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                        match value {
+                            "idempotencyKey" | "idempotency_key" => Ok(GeneratedField::IdempotencyKey),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -258,10 +332,19 @@ impl<'de> serde::Deserialize<'de> for CreateRequest {
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                let mut idempotency_key__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::IdempotencyKey => {
+                            if idempotency_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("idempotencyKey"));
+                            }
+                            idempotency_key__ = map.next_value()?;
+                        }
+                    }
                 }
                 Ok(CreateRequest {
+                    idempotency_key: idempotency_key__,
                 })
             }
         }
@@ -279,10 +362,17 @@ impl serde::Serialize for CreateResponse {
         if !self.id.is_empty() {
             len += 1;
         }
+        if self.version != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("cart.v1.CreateResponse", len)?;
         if !self.id.is_empty() {
             struct_ser.serialize_field("id", &self.id)?;
         }
+        if self.version != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("version", ToString::to_string(&self.version).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -294,11 +384,13 @@ impl<'de> serde::Deserialize<'de> for CreateResponse {
     {
         const FIELDS: &[&str] = &[
             "id",
+            "version",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Id,
+            Version,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -321,6 +413,7 @@ impl<'de> serde::Deserialize<'de> for CreateResponse {
                     {
                         match value {
                             "id" => Ok(GeneratedField::Id),
+                            "version" => Ok(GeneratedField::Version),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -341,6 +434,7 @@ impl<'de> serde::Deserialize<'de> for CreateResponse {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut id__ = None;
+                let mut version__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -349,10 +443,19 @@ impl<'de> serde::Deserialize<'de> for CreateResponse {
                             }
                             id__ = Some(map.next_value()?);
                         }
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(CreateResponse {
                     id: id__.unwrap_or_default(),
+                    version: version__.unwrap_or_default(),
                 })
             }
         }
@@ -464,6 +567,9 @@ impl serde::Serialize for GetResponse {
         if !self.items.is_empty() {
             len += 1;
         }
+        if self.version != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("cart.v1.GetResponse", len)?;
         if !self.id.is_empty() {
             struct_ser.serialize_field("id", &self.id)?;
@@ -471,6 +577,10 @@ impl serde::Serialize for GetResponse {
         if !self.items.is_empty() {
             struct_ser.serialize_field("items", &self.items)?;
         }
+        if self.version != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("version", ToString::to_string(&self.version).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -483,12 +593,14 @@ impl<'de> serde::Deserialize<'de> for GetResponse {
         const FIELDS: &[&str] = &[
             "id",
             "items",
+            "version",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Id,
             Items,
+            Version,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -512,6 +624,7 @@ impl<'de> serde::Deserialize<'de> for GetResponse {
                         match value {
                             "id" => Ok(GeneratedField::Id),
                             "items" => Ok(GeneratedField::Items),
+                            "version" => Ok(GeneratedField::Version),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -533,6 +646,7 @@ impl<'de> serde::Deserialize<'de> for GetResponse {
             {
                 let mut id__ = None;
                 let mut items__ = None;
+                let mut version__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -547,11 +661,20 @@ impl<'de> serde::Deserialize<'de> for GetResponse {
                             }
                             items__ = Some(map.next_value()?);
                         }
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(GetResponse {
                     id: id__.unwrap_or_default(),
                     items: items__.unwrap_or_default(),
+                    version: version__.unwrap_or_default(),
                 })
             }
         }
@@ -698,10 +821,23 @@ impl serde::Serialize for PlaceOrderRequest {
         if !self.id.is_empty() {
             len += 1;
         }
+        if self.expected_version.is_some() {
+            len += 1;
+        }
+        if self.idempotency_key.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("cart.v1.PlaceOrderRequest", len)?;
         if !self.id.is_empty() {
             struct_ser.serialize_field("id", &self.id)?;
         }
+        if let Some(v) = self.expected_version.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("expectedVersion", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.idempotency_key.as_ref() {
+            struct_ser.serialize_field("idempotencyKey", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -713,11 +849,17 @@ impl<'de> serde::Deserialize<'de> for PlaceOrderRequest {
     {
         const FIELDS: &[&str] = &[
             "id",
+            "expected_version",
+            "expectedVersion",
+            "idempotency_key",
+            "idempotencyKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Id,
+            ExpectedVersion,
+            IdempotencyKey,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -740,6 +882,8 @@ impl<'de> serde::Deserialize<'de> for PlaceOrderRequest {
                     {
                         match value {
                             "id" => Ok(GeneratedField::Id),
+                            "expectedVersion" | "expected_version" => Ok(GeneratedField::ExpectedVersion),
+                            "idempotencyKey" | "idempotency_key" => Ok(GeneratedField::IdempotencyKey),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -760,6 +904,8 @@ impl<'de> serde::Deserialize<'de> for PlaceOrderRequest {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut id__ = None;
+                let mut expected_version__ = None;
+                let mut idempotency_key__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -768,10 +914,26 @@ impl<'de> serde::Deserialize<'de> for PlaceOrderRequest {
                             }
                             id__ = Some(map.next_value()?);
                         }
+                        GeneratedField::ExpectedVersion => {
+                            if expected_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expectedVersion"));
+                            }
+                            expected_version__ = 
+                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::IdempotencyKey => {
+                            if idempotency_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("idempotencyKey"));
+                            }
+                            idempotency_key__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(PlaceOrderRequest {
                     id: id__.unwrap_or_default(),
+                    expected_version: expected_version__,
+                    idempotency_key: idempotency_key__,
                 })
             }
         }
@@ -785,8 +947,15 @@ impl serde::Serialize for PlaceOrderResponse {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("cart.v1.PlaceOrderResponse", len)?;
+        let mut len = 0;
+        if self.version != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("cart.v1.PlaceOrderResponse", len)?;
+        if self.version != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("version", ToString::to_string(&self.version).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -797,10 +966,12 @@ impl<'de> serde::Deserialize<'de> for PlaceOrderResponse {
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "version",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            Version,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -821,7 +992,10 @@ impl<'de> serde::Deserialize<'de> for PlaceOrderResponse {
                     where
                         E: serde::de::Error,
                     {
-                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                        match value {
+                            "version" => Ok(GeneratedField::Version),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -839,10 +1013,21 @@ impl<'de> serde::Deserialize<'de> for PlaceOrderResponse {
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                let mut version__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
                 }
                 Ok(PlaceOrderResponse {
+                    version: version__.unwrap_or_default(),
                 })
             }
         }
@@ -866,6 +1051,12 @@ impl serde::Serialize for RemoveItemRequest {
         if !self.item_id.is_empty() {
             len += 1;
         }
+        if self.expected_version.is_some() {
+            len += 1;
+        }
+        if self.idempotency_key.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("cart.v1.RemoveItemRequest", len)?;
         if !self.id.is_empty() {
             struct_ser.serialize_field("id", &self.id)?;
@@ -876,6 +1067,13 @@ impl serde::Serialize for RemoveItemRequest {
         if !self.item_id.is_empty() {
             struct_ser.serialize_field("itemId", &self.item_id)?;
         }
+        if let Some(v) = self.expected_version.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("expectedVersion", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.idempotency_key.as_ref() {
+            struct_ser.serialize_field("idempotencyKey", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -891,6 +1089,10 @@ impl<'de> serde::Deserialize<'de> for RemoveItemRequest {
             "tenantId",
             "item_id",
             "itemId",
+            "expected_version",
+            "expectedVersion",
+            "idempotency_key",
+            "idempotencyKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -898,6 +1100,8 @@ impl<'de> serde::Deserialize<'de> for RemoveItemRequest {
             Id,
             TenantId,
             ItemId,
+            ExpectedVersion,
+            IdempotencyKey,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -922,6 +1126,8 @@ impl<'de> serde::Deserialize<'de> for RemoveItemRequest {
                             "id" => Ok(GeneratedField::Id),
                             "tenantId" | "tenant_id" => Ok(GeneratedField::TenantId),
                             "itemId" | "item_id" => Ok(GeneratedField::ItemId),
+                            "expectedVersion" | "expected_version" => Ok(GeneratedField::ExpectedVersion),
+                            "idempotencyKey" | "idempotency_key" => Ok(GeneratedField::IdempotencyKey),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -944,6 +1150,8 @@ impl<'de> serde::Deserialize<'de> for RemoveItemRequest {
                 let mut id__ = None;
                 let mut tenant_id__ = None;
                 let mut item_id__ = None;
+                let mut expected_version__ = None;
+                let mut idempotency_key__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -964,12 +1172,28 @@ impl<'de> serde::Deserialize<'de> for RemoveItemRequest {
                             }
                             item_id__ = Some(map.next_value()?);
                         }
+                        GeneratedField::ExpectedVersion => {
+                            if expected_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expectedVersion"));
+                            }
+                            expected_version__ = 
+                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::IdempotencyKey => {
+                            if idempotency_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("idempotencyKey"));
+                            }
+                            idempotency_key__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(RemoveItemRequest {
                     id: id__.unwrap_or_default(),
                     tenant_id: tenant_id__.unwrap_or_default(),
                     item_id: item_id__.unwrap_or_default(),
+                    expected_version: expected_version__,
+                    idempotency_key: idempotency_key__,
                 })
             }
         }
@@ -983,8 +1207,15 @@ impl serde::Serialize for RemoveItemResponse {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("cart.v1.RemoveItemResponse", len)?;
+        let mut len = 0;
+        if self.version != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("cart.v1.RemoveItemResponse", len)?;
+        if self.version != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("version", ToString::to_string(&self.version).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -995,10 +1226,12 @@ impl<'de> serde::Deserialize<'de> for RemoveItemResponse {
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "version",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            Version,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1019,7 +1252,10 @@ impl<'de> serde::Deserialize<'de> for RemoveItemResponse {
                     where
                         E: serde::de::Error,
                     {
-                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                        match value {
+                            "version" => Ok(GeneratedField::Version),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -1037,10 +1273,21 @@ impl<'de> serde::Deserialize<'de> for RemoveItemResponse {
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                let mut version__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
                 }
                 Ok(RemoveItemResponse {
+                    version: version__.unwrap_or_default(),
                 })
             }
         }
@@ -9,6 +9,7 @@ pub struct Item {
     #[prost(uint32, tag="3")]
     pub quantity: u32,
 }
+/// 注文はカートごとに1つだけ作成され、同じカートのリクエストを再実行した場合は作成済みの注文を返す
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateRequest {
@@ -16,12 +17,18 @@ pub struct CreateRequest {
     pub cart_id: ::prost::alloc::string::String,
     #[prost(message, repeated, tag="2")]
     pub items: ::prost::alloc::vec::Vec<Item>,
+    /// 同じ冪等キーのリクエストを再実行した場合は最初のリクエストの結果を返す
+    #[prost(string, optional, tag="3")]
+    pub idempotency_key: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateResponse {
     #[prost(string, tag="1")]
     pub id: ::prost::alloc::string::String,
+    /// コマンド実行後の集約のバージョン。作成済みの注文を返す場合は、その後に注文を更新していても注文を作成した時のバージョン
+    #[prost(uint64, tag="2")]
+    pub version: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -49,6 +56,9 @@ pub struct GetResponse {
     pub items: ::prost::alloc::vec::Vec<Item>,
     #[prost(enumeration="get_response::OrderStatus", tag="3")]
     pub status: i32,
+    /// 集約のバージョン
+    #[prost(uint64, tag="4")]
+    pub version: u64,
 }
 /// Nested message and enum types in `GetResponse`.
 pub mod get_response {
@@ -101,66 +111,120 @@ pub mod get_response {
 pub struct ListTenantReceivedOrdersRequest {
     #[prost(string, tag="1")]
     pub tenant_id: ::prost::alloc::string::String,
+    /// 1ページに含める最大件数。0の場合はサーバーのデフォルト値を使う
+    #[prost(uint32, tag="2")]
+    pub page_size: u32,
+    /// 前のページのレスポンスの next_page_token。最初のページを取得する場合は指定しない
+    #[prost(string, tag="3")]
+    pub page_token: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListTenantReceivedOrdersResponse {
     #[prost(string, repeated, tag="1")]
     pub ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// 次のページを取得するトークン。次のページがない場合は空文字列
+    #[prost(string, tag="2")]
+    pub next_page_token: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListPreparedOrdersRequest {
+    /// 1ページに含める最大件数。0の場合はサーバーのデフォルト値を使う
+    #[prost(uint32, tag="1")]
+    pub page_size: u32,
+    /// 前のページのレスポンスの next_page_token。最初のページを取得する場合は指定しない
+    #[prost(string, tag="2")]
+    pub page_token: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListPreparedOrdersResponse {
     #[prost(string, repeated, tag="1")]
     pub ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// 次のページを取得するトークン。次のページがない場合は空文字列
+    #[prost(string, tag="2")]
+    pub next_page_token: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PreparedRequest {
     #[prost(string, tag="1")]
     pub id: ::prost::alloc::string::String,
+    /// 集約が指定したバージョンの場合のみコマンドを実行する
+    #[prost(uint64, optional, tag="2")]
+    pub expected_version: ::core::option::Option<u64>,
+    /// 同じ冪等キーのリクエストを再実行した場合は最初のリクエストの結果を返す
+    #[prost(string, optional, tag="3")]
+    pub idempotency_key: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PreparedResponse {
+    /// コマンド実行後の集約のバージョン
+    #[prost(uint64, tag="1")]
+    pub version: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PickedUpRequest {
     #[prost(string, tag="1")]
     pub id: ::prost::alloc::string::String,
+    /// 集約が指定したバージョンの場合のみコマンドを実行する
+    #[prost(uint64, optional, tag="2")]
+    pub expected_version: ::core::option::Option<u64>,
+    /// 同じ冪等キーのリクエストを再実行した場合は最初のリクエストの結果を返す
+    #[prost(string, optional, tag="3")]
+    pub idempotency_key: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PickedUpResponse {
+    /// コマンド実行後の集約のバージョン
+    #[prost(uint64, tag="1")]
+    pub version: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeliveredRequest {
     #[prost(string, tag="1")]
     pub id: ::prost::alloc::string::String,
+    /// 集約が指定したバージョンの場合のみコマンドを実行する
+    #[prost(uint64, optional, tag="2")]
+    pub expected_version: ::core::option::Option<u64>,
+    /// 同じ冪等キーのリクエストを再実行した場合は最初のリクエストの結果を返す
+    #[prost(string, optional, tag="3")]
+    pub idempotency_key: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeliveredResponse {
+    /// コマンド実行後の集約のバージョン
+    #[prost(uint64, tag="1")]
+    pub version: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CancelRequest {
     #[prost(string, tag="1")]
     pub id: ::prost::alloc::string::String,
+    /// 集約が指定したバージョンの場合のみコマンドを実行する
+    #[prost(uint64, optional, tag="2")]
+    pub expected_version: ::core::option::Option<u64>,
+    /// 同じ冪等キーのリクエストを再実行した場合は最初のリクエストの結果を返す
+    #[prost(string, optional, tag="3")]
+    pub idempotency_key: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CancelResponse {
+    /// コマンド実行後の集約のバージョン
+    #[prost(uint64, tag="1")]
+    pub version: u64,
 }
 /// Encoded file descriptor set for the `order.v1` package
 pub const FILE_DESCRIPTOR_SET: &[u8] = &[
-    0x0a, 0x9a, 0x21, 0x0a, 0x1c, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2f, 0x76, 0x31, 0x2f, 0x6f, 0x72,
+    0x0a, 0x8a, 0x42, 0x0a, 0x1c, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2f, 0x76, 0x31, 0x2f, 0x6f, 0x72,
     0x64, 0x65, 0x72, 0x5f, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74,
     0x6f, 0x12, 0x08, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x22, 0x58, 0x0a, 0x04, 0x49,
     0x74, 0x65, 0x6d, 0x12, 0x1b, 0x0a, 0x09, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x5f, 0x69, 0x64,
@@ -168,264 +232,527 @@ pub const FILE_DESCRIPTOR_SET: &[u8] = &[
     0x12, 0x17, 0x0a, 0x07, 0x69, 0x74, 0x65, 0x6d, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28,
     0x09, 0x52, 0x06, 0x69, 0x74, 0x65, 0x6d, 0x49, 0x64, 0x12, 0x1a, 0x0a, 0x08, 0x71, 0x75, 0x61,
     0x6e, 0x74, 0x69, 0x74, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x08, 0x71, 0x75, 0x61,
-    0x6e, 0x74, 0x69, 0x74, 0x79, 0x22, 0x4e, 0x0a, 0x0d, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52,
-    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x17, 0x0a, 0x07, 0x63, 0x61, 0x72, 0x74, 0x5f, 0x69,
-    0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x63, 0x61, 0x72, 0x74, 0x49, 0x64, 0x12,
-    0x24, 0x0a, 0x05, 0x69, 0x74, 0x65, 0x6d, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0e,
-    0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x49, 0x74, 0x65, 0x6d, 0x52, 0x05,
-    0x69, 0x74, 0x65, 0x6d, 0x73, 0x22, 0x20, 0x0a, 0x0e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52,
-    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20,
-    0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x4a, 0x0a, 0x0a, 0x47, 0x65, 0x74, 0x52, 0x65,
-    0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1b, 0x0a, 0x08, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x5f, 0x69,
-    0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x65, 0x72,
-    0x49, 0x64, 0x12, 0x19, 0x0a, 0x07, 0x63, 0x61, 0x72, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20,
-    0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x06, 0x63, 0x61, 0x72, 0x74, 0x49, 0x64, 0x42, 0x04, 0x0a,
-    0x02, 0x69, 0x64, 0x22, 0xb7, 0x02, 0x0a, 0x0b, 0x47, 0x65, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f,
-    0x6e, 0x73, 0x65, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52,
-    0x02, 0x69, 0x64, 0x12, 0x24, 0x0a, 0x05, 0x69, 0x74, 0x65, 0x6d, 0x73, 0x18, 0x02, 0x20, 0x03,
-    0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x49, 0x74,
-    0x65, 0x6d, 0x52, 0x05, 0x69, 0x74, 0x65, 0x6d, 0x73, 0x12, 0x39, 0x0a, 0x06, 0x73, 0x74, 0x61,
-    0x74, 0x75, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x21, 0x2e, 0x6f, 0x72, 0x64, 0x65,
-    0x72, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
-    0x2e, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x06, 0x73, 0x74,
-    0x61, 0x74, 0x75, 0x73, 0x22, 0xb6, 0x01, 0x0a, 0x0b, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x53, 0x74,
-    0x61, 0x74, 0x75, 0x73, 0x12, 0x1c, 0x0a, 0x18, 0x4f, 0x52, 0x44, 0x45, 0x52, 0x5f, 0x53, 0x54,
-    0x41, 0x54, 0x55, 0x53, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44,
-    0x10, 0x00, 0x12, 0x19, 0x0a, 0x15, 0x4f, 0x52, 0x44, 0x45, 0x52, 0x5f, 0x53, 0x54, 0x41, 0x54,
-    0x55, 0x53, 0x5f, 0x52, 0x45, 0x43, 0x45, 0x49, 0x56, 0x45, 0x44, 0x10, 0x01, 0x12, 0x19, 0x0a,
-    0x15, 0x4f, 0x52, 0x44, 0x45, 0x52, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x55, 0x53, 0x5f, 0x50, 0x52,
-    0x45, 0x50, 0x41, 0x52, 0x45, 0x44, 0x10, 0x02, 0x12, 0x1b, 0x0a, 0x17, 0x4f, 0x52, 0x44, 0x45,
-    0x52, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x55, 0x53, 0x5f, 0x4f, 0x4e, 0x5f, 0x54, 0x48, 0x45, 0x5f,
-    0x57, 0x41, 0x59, 0x10, 0x03, 0x12, 0x1a, 0x0a, 0x16, 0x4f, 0x52, 0x44, 0x45, 0x52, 0x5f, 0x53,
-    0x54, 0x41, 0x54, 0x55, 0x53, 0x5f, 0x44, 0x45, 0x4c, 0x49, 0x56, 0x45, 0x52, 0x45, 0x44, 0x10,
-    0x04, 0x12, 0x1a, 0x0a, 0x16, 0x4f, 0x52, 0x44, 0x45, 0x52, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x55,
-    0x53, 0x5f, 0x43, 0x41, 0x4e, 0x43, 0x45, 0x4c, 0x4c, 0x45, 0x44, 0x10, 0x05, 0x22, 0x3e, 0x0a,
-    0x1f, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x52, 0x65, 0x63, 0x65, 0x69,
-    0x76, 0x65, 0x64, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
-    0x12, 0x1b, 0x0a, 0x09, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20,
-    0x01, 0x28, 0x09, 0x52, 0x08, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x49, 0x64, 0x22, 0x34, 0x0a,
-    0x20, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x52, 0x65, 0x63, 0x65, 0x69,
-    0x76, 0x65, 0x64, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
-    0x65, 0x12, 0x10, 0x0a, 0x03, 0x69, 0x64, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x09, 0x52, 0x03,
-    0x69, 0x64, 0x73, 0x22, 0x1b, 0x0a, 0x19, 0x4c, 0x69, 0x73, 0x74, 0x50, 0x72, 0x65, 0x70, 0x61,
-    0x72, 0x65, 0x64, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
-    0x22, 0x2e, 0x0a, 0x1a, 0x4c, 0x69, 0x73, 0x74, 0x50, 0x72, 0x65, 0x70, 0x61, 0x72, 0x65, 0x64,
-    0x4f, 0x72, 0x64, 0x65, 0x72, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x10,
-    0x0a, 0x03, 0x69, 0x64, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x09, 0x52, 0x03, 0x69, 0x64, 0x73,
-    0x22, 0x21, 0x0a, 0x0f, 0x50, 0x72, 0x65, 0x70, 0x61, 0x72, 0x65, 0x64, 0x52, 0x65, 0x71, 0x75,
+    0x6e, 0x74, 0x69, 0x74, 0x79, 0x22, 0x90, 0x01, 0x0a, 0x0d, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65,
+    0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x17, 0x0a, 0x07, 0x63, 0x61, 0x72, 0x74, 0x5f,
+    0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x63, 0x61, 0x72, 0x74, 0x49, 0x64,
+    0x12, 0x24, 0x0a, 0x05, 0x69, 0x74, 0x65, 0x6d, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32,
+    0x0e, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x49, 0x74, 0x65, 0x6d, 0x52,
+    0x05, 0x69, 0x74, 0x65, 0x6d, 0x73, 0x12, 0x2c, 0x0a, 0x0f, 0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f,
+    0x74, 0x65, 0x6e, 0x63, 0x79, 0x5f, 0x6b, 0x65, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x48,
+    0x00, 0x52, 0x0e, 0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x4b, 0x65,
+    0x79, 0x88, 0x01, 0x01, 0x42, 0x12, 0x0a, 0x10, 0x5f, 0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74,
+    0x65, 0x6e, 0x63, 0x79, 0x5f, 0x6b, 0x65, 0x79, 0x22, 0x3a, 0x0a, 0x0e, 0x43, 0x72, 0x65, 0x61,
+    0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x12, 0x18, 0x0a, 0x07, 0x76, 0x65,
+    0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x76, 0x65, 0x72,
+    0x73, 0x69, 0x6f, 0x6e, 0x22, 0x4a, 0x0a, 0x0a, 0x47, 0x65, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65,
+    0x73, 0x74, 0x12, 0x1b, 0x0a, 0x08, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01,
+    0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x49, 0x64, 0x12,
+    0x19, 0x0a, 0x07, 0x63, 0x61, 0x72, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09,
+    0x48, 0x00, 0x52, 0x06, 0x63, 0x61, 0x72, 0x74, 0x49, 0x64, 0x42, 0x04, 0x0a, 0x02, 0x69, 0x64,
+    0x22, 0xd1, 0x02, 0x0a, 0x0b, 0x47, 0x65, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+    0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64,
+    0x12, 0x24, 0x0a, 0x05, 0x69, 0x74, 0x65, 0x6d, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32,
+    0x0e, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x49, 0x74, 0x65, 0x6d, 0x52,
+    0x05, 0x69, 0x74, 0x65, 0x6d, 0x73, 0x12, 0x39, 0x0a, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73,
+    0x18, 0x03, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x21, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76,
+    0x31, 0x2e, 0x47, 0x65, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x2e, 0x4f, 0x72,
+    0x64, 0x65, 0x72, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75,
+    0x73, 0x12, 0x18, 0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x04, 0x20, 0x01,
+    0x28, 0x04, 0x52, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x22, 0xb6, 0x01, 0x0a, 0x0b,
+    0x4f, 0x72, 0x64, 0x65, 0x72, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x1c, 0x0a, 0x18, 0x4f,
+    0x52, 0x44, 0x45, 0x52, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x55, 0x53, 0x5f, 0x55, 0x4e, 0x53, 0x50,
+    0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x19, 0x0a, 0x15, 0x4f, 0x52, 0x44,
+    0x45, 0x52, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x55, 0x53, 0x5f, 0x52, 0x45, 0x43, 0x45, 0x49, 0x56,
+    0x45, 0x44, 0x10, 0x01, 0x12, 0x19, 0x0a, 0x15, 0x4f, 0x52, 0x44, 0x45, 0x52, 0x5f, 0x53, 0x54,
+    0x41, 0x54, 0x55, 0x53, 0x5f, 0x50, 0x52, 0x45, 0x50, 0x41, 0x52, 0x45, 0x44, 0x10, 0x02, 0x12,
+    0x1b, 0x0a, 0x17, 0x4f, 0x52, 0x44, 0x45, 0x52, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x55, 0x53, 0x5f,
+    0x4f, 0x4e, 0x5f, 0x54, 0x48, 0x45, 0x5f, 0x57, 0x41, 0x59, 0x10, 0x03, 0x12, 0x1a, 0x0a, 0x16,
+    0x4f, 0x52, 0x44, 0x45, 0x52, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x55, 0x53, 0x5f, 0x44, 0x45, 0x4c,
+    0x49, 0x56, 0x45, 0x52, 0x45, 0x44, 0x10, 0x04, 0x12, 0x1a, 0x0a, 0x16, 0x4f, 0x52, 0x44, 0x45,
+    0x52, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x55, 0x53, 0x5f, 0x43, 0x41, 0x4e, 0x43, 0x45, 0x4c, 0x4c,
+    0x45, 0x44, 0x10, 0x05, 0x22, 0x7a, 0x0a, 0x1f, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x65, 0x6e, 0x61,
+    0x6e, 0x74, 0x52, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x73,
+    0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1b, 0x0a, 0x09, 0x74, 0x65, 0x6e, 0x61, 0x6e,
+    0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x74, 0x65, 0x6e, 0x61,
+    0x6e, 0x74, 0x49, 0x64, 0x12, 0x1b, 0x0a, 0x09, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x73, 0x69, 0x7a,
+    0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x08, 0x70, 0x61, 0x67, 0x65, 0x53, 0x69, 0x7a,
+    0x65, 0x12, 0x1d, 0x0a, 0x0a, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18,
+    0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x70, 0x61, 0x67, 0x65, 0x54, 0x6f, 0x6b, 0x65, 0x6e,
+    0x22, 0x5c, 0x0a, 0x20, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x52, 0x65,
+    0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x73, 0x52, 0x65, 0x73, 0x70,
+    0x6f, 0x6e, 0x73, 0x65, 0x12, 0x10, 0x0a, 0x03, 0x69, 0x64, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28,
+    0x09, 0x52, 0x03, 0x69, 0x64, 0x73, 0x12, 0x26, 0x0a, 0x0f, 0x6e, 0x65, 0x78, 0x74, 0x5f, 0x70,
+    0x61, 0x67, 0x65, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52,
+    0x0d, 0x6e, 0x65, 0x78, 0x74, 0x50, 0x61, 0x67, 0x65, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x22, 0x57,
+    0x0a, 0x19, 0x4c, 0x69, 0x73, 0x74, 0x50, 0x72, 0x65, 0x70, 0x61, 0x72, 0x65, 0x64, 0x4f, 0x72,
+    0x64, 0x65, 0x72, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1b, 0x0a, 0x09, 0x70,
+    0x61, 0x67, 0x65, 0x5f, 0x73, 0x69, 0x7a, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x08,
+    0x70, 0x61, 0x67, 0x65, 0x53, 0x69, 0x7a, 0x65, 0x12, 0x1d, 0x0a, 0x0a, 0x70, 0x61, 0x67, 0x65,
+    0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x70, 0x61,
+    0x67, 0x65, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x22, 0x56, 0x0a, 0x1a, 0x4c, 0x69, 0x73, 0x74, 0x50,
+    0x72, 0x65, 0x70, 0x61, 0x72, 0x65, 0x64, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x73, 0x52, 0x65, 0x73,
+    0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x10, 0x0a, 0x03, 0x69, 0x64, 0x73, 0x18, 0x01, 0x20, 0x03,
+    0x28, 0x09, 0x52, 0x03, 0x69, 0x64, 0x73, 0x12, 0x26, 0x0a, 0x0f, 0x6e, 0x65, 0x78, 0x74, 0x5f,
+    0x70, 0x61, 0x67, 0x65, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x0d, 0x6e, 0x65, 0x78, 0x74, 0x50, 0x61, 0x67, 0x65, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x22,
+    0xa8, 0x01, 0x0a, 0x0f, 0x50, 0x72, 0x65, 0x70, 0x61, 0x72, 0x65, 0x64, 0x52, 0x65, 0x71, 0x75,
     0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52,
-    0x02, 0x69, 0x64, 0x22, 0x12, 0x0a, 0x10, 0x50, 0x72, 0x65, 0x70, 0x61, 0x72, 0x65, 0x64, 0x52,
-    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x21, 0x0a, 0x0f, 0x50, 0x69, 0x63, 0x6b, 0x65,
-    0x64, 0x55, 0x70, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64,
-    0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x12, 0x0a, 0x10, 0x50, 0x69,
-    0x63, 0x6b, 0x65, 0x64, 0x55, 0x70, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x22,
-    0x0a, 0x10, 0x44, 0x65, 0x6c, 0x69, 0x76, 0x65, 0x72, 0x65, 0x64, 0x52, 0x65, 0x71, 0x75, 0x65,
-    0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02,
-    0x69, 0x64, 0x22, 0x13, 0x0a, 0x11, 0x44, 0x65, 0x6c, 0x69, 0x76, 0x65, 0x72, 0x65, 0x64, 0x52,
-    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x1f, 0x0a, 0x0d, 0x43, 0x61, 0x6e, 0x63, 0x65,
-    0x6c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01,
-    0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x10, 0x0a, 0x0e, 0x43, 0x61, 0x6e, 0x63,
-    0x65, 0x6c, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x32, 0xdc, 0x04, 0x0a, 0x0c, 0x4f,
-    0x72, 0x64, 0x65, 0x72, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x3b, 0x0a, 0x06, 0x43,
-    0x72, 0x65, 0x61, 0x74, 0x65, 0x12, 0x17, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76, 0x31,
-    0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x18,
-    0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65,
-    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x32, 0x0a, 0x03, 0x47, 0x65, 0x74, 0x12,
-    0x14, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x74, 0x52, 0x65,
-    0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x15, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76, 0x31,
-    0x2e, 0x47, 0x65, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x71, 0x0a, 0x18,
-    0x4c, 0x69, 0x73, 0x74, 0x54, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x52, 0x65, 0x63, 0x65, 0x69, 0x76,
-    0x65, 0x64, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x73, 0x12, 0x29, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72,
-    0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x52, 0x65,
-    0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x73, 0x52, 0x65, 0x71, 0x75,
-    0x65, 0x73, 0x74, 0x1a, 0x2a, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x4c,
-    0x69, 0x73, 0x74, 0x54, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x52, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65,
-    0x64, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
-    0x5f, 0x0a, 0x12, 0x4c, 0x69, 0x73, 0x74, 0x50, 0x72, 0x65, 0x70, 0x61, 0x72, 0x65, 0x64, 0x4f,
-    0x72, 0x64, 0x65, 0x72, 0x73, 0x12, 0x23, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76, 0x31,
-    0x2e, 0x4c, 0x69, 0x73, 0x74, 0x50, 0x72, 0x65, 0x70, 0x61, 0x72, 0x65, 0x64, 0x4f, 0x72, 0x64,
-    0x65, 0x72, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x24, 0x2e, 0x6f, 0x72, 0x64,
-    0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x50, 0x72, 0x65, 0x70, 0x61, 0x72,
-    0x65, 0x64, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
-    0x12, 0x41, 0x0a, 0x08, 0x50, 0x72, 0x65, 0x70, 0x61, 0x72, 0x65, 0x64, 0x12, 0x19, 0x2e, 0x6f,
-    0x72, 0x64, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x72, 0x65, 0x70, 0x61, 0x72, 0x65, 0x64,
-    0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1a, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e,
-    0x76, 0x31, 0x2e, 0x50, 0x72, 0x65, 0x70, 0x61, 0x72, 0x65, 0x64, 0x52, 0x65, 0x73, 0x70, 0x6f,
-    0x6e, 0x73, 0x65, 0x12, 0x41, 0x0a, 0x08, 0x50, 0x69, 0x63, 0x6b, 0x65, 0x64, 0x55, 0x70, 0x12,
-    0x19, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x69, 0x63, 0x6b, 0x65,
-    0x64, 0x55, 0x70, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1a, 0x2e, 0x6f, 0x72, 0x64,
-    0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x69, 0x63, 0x6b, 0x65, 0x64, 0x55, 0x70, 0x52, 0x65,
-    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x44, 0x0a, 0x09, 0x44, 0x65, 0x6c, 0x69, 0x76, 0x65,
-    0x72, 0x65, 0x64, 0x12, 0x1a, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x44,
-    0x65, 0x6c, 0x69, 0x76, 0x65, 0x72, 0x65, 0x64, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a,
-    0x1b, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x44, 0x65, 0x6c, 0x69, 0x76,
-    0x65, 0x72, 0x65, 0x64, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3b, 0x0a, 0x06,
-    0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x12, 0x17, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76,
-    0x31, 0x2e, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a,
-    0x18, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x61, 0x6e, 0x63, 0x65,
-    0x6c, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x4a, 0x99, 0x14, 0x0a, 0x06, 0x12, 0x04,
-    0x00, 0x00, 0x5c, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08,
-    0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00, 0x11, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04,
-    0x04, 0x00, 0x08, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x04, 0x08, 0x0c,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x05, 0x02, 0x17, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x05, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x69, 0x64, 0x12, 0x2e, 0x0a, 0x10, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x5f,
+    0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x48, 0x00, 0x52,
+    0x0f, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x56, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e,
+    0x88, 0x01, 0x01, 0x12, 0x2c, 0x0a, 0x0f, 0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e,
+    0x63, 0x79, 0x5f, 0x6b, 0x65, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x48, 0x01, 0x52, 0x0e,
+    0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x4b, 0x65, 0x79, 0x88, 0x01,
+    0x01, 0x42, 0x13, 0x0a, 0x11, 0x5f, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x5f, 0x76,
+    0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x42, 0x12, 0x0a, 0x10, 0x5f, 0x69, 0x64, 0x65, 0x6d, 0x70,
+    0x6f, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x5f, 0x6b, 0x65, 0x79, 0x22, 0x2c, 0x0a, 0x10, 0x50, 0x72,
+    0x65, 0x70, 0x61, 0x72, 0x65, 0x64, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x18,
+    0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52,
+    0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x22, 0xa8, 0x01, 0x0a, 0x0f, 0x50, 0x69, 0x63,
+    0x6b, 0x65, 0x64, 0x55, 0x70, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02,
+    0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x12, 0x2e, 0x0a, 0x10,
+    0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x5f, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e,
+    0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x48, 0x00, 0x52, 0x0f, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74,
+    0x65, 0x64, 0x56, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x88, 0x01, 0x01, 0x12, 0x2c, 0x0a, 0x0f,
+    0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x5f, 0x6b, 0x65, 0x79, 0x18,
+    0x03, 0x20, 0x01, 0x28, 0x09, 0x48, 0x01, 0x52, 0x0e, 0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74,
+    0x65, 0x6e, 0x63, 0x79, 0x4b, 0x65, 0x79, 0x88, 0x01, 0x01, 0x42, 0x13, 0x0a, 0x11, 0x5f, 0x65,
+    0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x5f, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x42,
+    0x12, 0x0a, 0x10, 0x5f, 0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x5f,
+    0x6b, 0x65, 0x79, 0x22, 0x2c, 0x0a, 0x10, 0x50, 0x69, 0x63, 0x6b, 0x65, 0x64, 0x55, 0x70, 0x52,
+    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69,
+    0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f,
+    0x6e, 0x22, 0xa9, 0x01, 0x0a, 0x10, 0x44, 0x65, 0x6c, 0x69, 0x76, 0x65, 0x72, 0x65, 0x64, 0x52,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x12, 0x2e, 0x0a, 0x10, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74,
+    0x65, 0x64, 0x5f, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04,
+    0x48, 0x00, 0x52, 0x0f, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x56, 0x65, 0x72, 0x73,
+    0x69, 0x6f, 0x6e, 0x88, 0x01, 0x01, 0x12, 0x2c, 0x0a, 0x0f, 0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f,
+    0x74, 0x65, 0x6e, 0x63, 0x79, 0x5f, 0x6b, 0x65, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x48,
+    0x01, 0x52, 0x0e, 0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x4b, 0x65,
+    0x79, 0x88, 0x01, 0x01, 0x42, 0x13, 0x0a, 0x11, 0x5f, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65,
+    0x64, 0x5f, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x42, 0x12, 0x0a, 0x10, 0x5f, 0x69, 0x64,
+    0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x5f, 0x6b, 0x65, 0x79, 0x22, 0x2d, 0x0a,
+    0x11, 0x44, 0x65, 0x6c, 0x69, 0x76, 0x65, 0x72, 0x65, 0x64, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+    0x73, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20,
+    0x01, 0x28, 0x04, 0x52, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x22, 0xa6, 0x01, 0x0a,
+    0x0d, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x0e,
+    0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x12, 0x2e,
+    0x0a, 0x10, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x5f, 0x76, 0x65, 0x72, 0x73, 0x69,
+    0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x48, 0x00, 0x52, 0x0f, 0x65, 0x78, 0x70, 0x65,
+    0x63, 0x74, 0x65, 0x64, 0x56, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x88, 0x01, 0x01, 0x12, 0x2c,
+    0x0a, 0x0f, 0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x5f, 0x6b, 0x65,
+    0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x48, 0x01, 0x52, 0x0e, 0x69, 0x64, 0x65, 0x6d, 0x70,
+    0x6f, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x4b, 0x65, 0x79, 0x88, 0x01, 0x01, 0x42, 0x13, 0x0a, 0x11,
+    0x5f, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x5f, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f,
+    0x6e, 0x42, 0x12, 0x0a, 0x10, 0x5f, 0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x63,
+    0x79, 0x5f, 0x6b, 0x65, 0x79, 0x22, 0x2a, 0x0a, 0x0e, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x52,
+    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69,
+    0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f,
+    0x6e, 0x32, 0xdc, 0x04, 0x0a, 0x0c, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x53, 0x65, 0x72, 0x76, 0x69,
+    0x63, 0x65, 0x12, 0x3b, 0x0a, 0x06, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x12, 0x17, 0x2e, 0x6f,
+    0x72, 0x64, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52, 0x65,
+    0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x18, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76, 0x31,
+    0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
+    0x32, 0x0a, 0x03, 0x47, 0x65, 0x74, 0x12, 0x14, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76,
+    0x31, 0x2e, 0x47, 0x65, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x15, 0x2e, 0x6f,
+    0x72, 0x64, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f,
+    0x6e, 0x73, 0x65, 0x12, 0x71, 0x0a, 0x18, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x65, 0x6e, 0x61, 0x6e,
+    0x74, 0x52, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x73, 0x12,
+    0x29, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x54,
+    0x65, 0x6e, 0x61, 0x6e, 0x74, 0x52, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x4f, 0x72, 0x64,
+    0x65, 0x72, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2a, 0x2e, 0x6f, 0x72, 0x64,
+    0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x65, 0x6e, 0x61, 0x6e, 0x74,
+    0x52, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x73, 0x52, 0x65,
+    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x5f, 0x0a, 0x12, 0x4c, 0x69, 0x73, 0x74, 0x50, 0x72,
+    0x65, 0x70, 0x61, 0x72, 0x65, 0x64, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x73, 0x12, 0x23, 0x2e, 0x6f,
+    0x72, 0x64, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x50, 0x72, 0x65, 0x70,
+    0x61, 0x72, 0x65, 0x64, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+    0x74, 0x1a, 0x24, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x69, 0x73,
+    0x74, 0x50, 0x72, 0x65, 0x70, 0x61, 0x72, 0x65, 0x64, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x73, 0x52,
+    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x41, 0x0a, 0x08, 0x50, 0x72, 0x65, 0x70, 0x61,
+    0x72, 0x65, 0x64, 0x12, 0x19, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x50,
+    0x72, 0x65, 0x70, 0x61, 0x72, 0x65, 0x64, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1a,
+    0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x72, 0x65, 0x70, 0x61, 0x72,
+    0x65, 0x64, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x41, 0x0a, 0x08, 0x50, 0x69,
+    0x63, 0x6b, 0x65, 0x64, 0x55, 0x70, 0x12, 0x19, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76,
+    0x31, 0x2e, 0x50, 0x69, 0x63, 0x6b, 0x65, 0x64, 0x55, 0x70, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+    0x74, 0x1a, 0x1a, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x69, 0x63,
+    0x6b, 0x65, 0x64, 0x55, 0x70, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x44, 0x0a,
+    0x09, 0x44, 0x65, 0x6c, 0x69, 0x76, 0x65, 0x72, 0x65, 0x64, 0x12, 0x1a, 0x2e, 0x6f, 0x72, 0x64,
+    0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x44, 0x65, 0x6c, 0x69, 0x76, 0x65, 0x72, 0x65, 0x64, 0x52,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76,
+    0x31, 0x2e, 0x44, 0x65, 0x6c, 0x69, 0x76, 0x65, 0x72, 0x65, 0x64, 0x52, 0x65, 0x73, 0x70, 0x6f,
+    0x6e, 0x73, 0x65, 0x12, 0x3b, 0x0a, 0x06, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x12, 0x17, 0x2e,
+    0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x52,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x18, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x76,
+    0x31, 0x2e, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+    0x4a, 0xc2, 0x2d, 0x0a, 0x07, 0x12, 0x05, 0x00, 0x00, 0x8c, 0x01, 0x01, 0x0a, 0x08, 0x0a, 0x01,
+    0x02, 0x12, 0x03, 0x02, 0x00, 0x11, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x04, 0x00,
+    0x08, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x04, 0x08, 0x0c, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x05, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
     0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x05, 0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x00, 0x03, 0x12, 0x03, 0x05, 0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12,
-    0x03, 0x06, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x06,
-    0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x06, 0x09, 0x10,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x06, 0x13, 0x14, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x07, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x07, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x05, 0x15, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05,
+    0x12, 0x03, 0x05, 0x02, 0x08, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x06,
+    0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x06, 0x09, 0x10,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x06, 0x13, 0x14, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x06, 0x02, 0x08, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x07, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
     0x02, 0x01, 0x12, 0x03, 0x07, 0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03,
-    0x12, 0x03, 0x07, 0x14, 0x15, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x0a, 0x00, 0x0d,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x0a, 0x08, 0x15, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x0b, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
-    0x02, 0x00, 0x05, 0x12, 0x03, 0x0b, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x0b, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x0b, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x0c, 0x02,
-    0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x04, 0x12, 0x03, 0x0c, 0x02, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x06, 0x12, 0x03, 0x0c, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0c, 0x10, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0c, 0x18, 0x19, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12,
-    0x04, 0x0f, 0x00, 0x11, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x0f, 0x08,
-    0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x10, 0x02, 0x10, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x10, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x10, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
-    0x02, 0x00, 0x03, 0x12, 0x03, 0x10, 0x0e, 0x0f, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04,
-    0x13, 0x00, 0x18, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x13, 0x08, 0x12,
-    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x03, 0x08, 0x00, 0x12, 0x04, 0x14, 0x02, 0x17, 0x03, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x03, 0x08, 0x00, 0x01, 0x12, 0x03, 0x14, 0x08, 0x0a, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x15, 0x04, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
-    0x00, 0x05, 0x12, 0x03, 0x15, 0x04, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01,
-    0x12, 0x03, 0x15, 0x0b, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03,
-    0x15, 0x16, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x16, 0x04, 0x17,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x05, 0x12, 0x03, 0x16, 0x04, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x16, 0x0b, 0x12, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x16, 0x15, 0x16, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04,
-    0x12, 0x04, 0x1a, 0x00, 0x27, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x1a,
-    0x08, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x04, 0x04, 0x00, 0x12, 0x04, 0x1b, 0x02, 0x22, 0x03,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x04, 0x00, 0x01, 0x12, 0x03, 0x1b, 0x07, 0x12, 0x0a, 0x0d,
-    0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x1c, 0x04, 0x21, 0x0a, 0x0e, 0x0a,
-    0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1c, 0x04, 0x1c, 0x0a, 0x0e, 0x0a,
-    0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x1c, 0x1f, 0x20, 0x0a, 0x1d, 0x0a,
-    0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x1d, 0x04, 0x1e, 0x22, 0x0e, 0x20, 0xe5,
-    0x8f, 0x97, 0xe4, 0xbb, 0x98, 0xe7, 0x8a, 0xb6, 0xe6, 0x85, 0x8b, 0x0a, 0x0a, 0x0e, 0x0a, 0x07,
-    0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1d, 0x04, 0x19, 0x0a, 0x0e, 0x0a, 0x07,
-    0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x1d, 0x1c, 0x1d, 0x0a, 0x35, 0x0a, 0x06,
-    0x04, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x1e, 0x04, 0x1e, 0x22, 0x26, 0x20, 0xe3, 0x83,
-    0x86, 0xe3, 0x83, 0x8a, 0xe3, 0x83, 0xb3, 0xe3, 0x83, 0x88, 0xe3, 0x81, 0xae, 0xe6, 0xba, 0x96,
-    0xe5, 0x82, 0x99, 0xe3, 0x81, 0x8c, 0xe5, 0xae, 0x8c, 0xe4, 0xba, 0x86, 0xe3, 0x81, 0x97, 0xe3,
-    0x81, 0x9f, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03,
-    0x1e, 0x04, 0x19, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03,
-    0x1e, 0x1c, 0x1d, 0x0a, 0x1a, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x1f,
-    0x04, 0x20, 0x22, 0x0b, 0x20, 0xe9, 0x85, 0x8d, 0xe9, 0x81, 0x94, 0xe4, 0xb8, 0xad, 0x0a, 0x0a,
-    0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x1f, 0x04, 0x1b, 0x0a,
-    0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x1f, 0x1e, 0x1f, 0x0a,
-    0x1d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x20, 0x04, 0x1f, 0x22, 0x0e,
-    0x20, 0xe9, 0x85, 0x8d, 0xe9, 0x81, 0x94, 0xe6, 0xb8, 0x88, 0xe3, 0x81, 0xbf, 0x0a, 0x0a, 0x0e,
-    0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x20, 0x04, 0x1a, 0x0a, 0x0e,
-    0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x20, 0x1d, 0x1e, 0x0a, 0x32,
-    0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x21, 0x04, 0x1f, 0x22, 0x23, 0x20,
-    0xe6, 0xb3, 0xa8, 0xe6, 0x96, 0x87, 0xe3, 0x81, 0x8c, 0xe3, 0x82, 0xad, 0xe3, 0x83, 0xa3, 0xe3,
-    0x83, 0xb3, 0xe3, 0x82, 0xbb, 0xe3, 0x83, 0xab, 0xe3, 0x81, 0x95, 0xe3, 0x82, 0x8c, 0xe3, 0x81,
-    0x9f, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x21,
-    0x04, 0x1a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x05, 0x02, 0x12, 0x03, 0x21,
-    0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x24, 0x02, 0x10, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x05, 0x12, 0x03, 0x24, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x24, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x24, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02,
-    0x01, 0x12, 0x03, 0x25, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x04, 0x12,
-    0x03, 0x25, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x06, 0x12, 0x03, 0x25,
-    0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x25, 0x10, 0x15,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x25, 0x18, 0x19, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x04, 0x02, 0x02, 0x12, 0x03, 0x26, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x04, 0x02, 0x02, 0x06, 0x12, 0x03, 0x26, 0x02, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
-    0x02, 0x01, 0x12, 0x03, 0x26, 0x0e, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x03,
-    0x12, 0x03, 0x26, 0x17, 0x18, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x29, 0x00, 0x2b,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x29, 0x08, 0x27, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x2a, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
-    0x02, 0x00, 0x05, 0x12, 0x03, 0x2a, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x2a, 0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x2a, 0x15, 0x16, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x2d, 0x00, 0x2f, 0x01,
-    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x2d, 0x08, 0x28, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x2e, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
-    0x00, 0x04, 0x12, 0x03, 0x2e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x05,
-    0x12, 0x03, 0x2e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x2e, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2e, 0x18,
-    0x19, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x03, 0x31, 0x00, 0x24, 0x0a, 0x0a, 0x0a, 0x03,
-    0x04, 0x07, 0x01, 0x12, 0x03, 0x31, 0x08, 0x21, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x08, 0x12, 0x04,
-    0x33, 0x00, 0x35, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x03, 0x33, 0x08, 0x22,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x34, 0x02, 0x1a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x08, 0x02, 0x00, 0x04, 0x12, 0x03, 0x34, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x08, 0x02, 0x00, 0x05, 0x12, 0x03, 0x34, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
-    0x00, 0x01, 0x12, 0x03, 0x34, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03,
-    0x12, 0x03, 0x34, 0x18, 0x19, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x04, 0x37, 0x00, 0x39,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x37, 0x08, 0x17, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x03, 0x38, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09,
-    0x02, 0x00, 0x05, 0x12, 0x03, 0x38, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x38, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x38, 0x0e, 0x0f, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x0a, 0x12, 0x03, 0x3b, 0x00, 0x1b, 0x0a,
-    0x0a, 0x0a, 0x03, 0x04, 0x0a, 0x01, 0x12, 0x03, 0x3b, 0x08, 0x18, 0x0a, 0x0a, 0x0a, 0x02, 0x04,
-    0x0b, 0x12, 0x04, 0x3d, 0x00, 0x3f, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0b, 0x01, 0x12, 0x03,
-    0x3d, 0x08, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x00, 0x12, 0x03, 0x3e, 0x02, 0x10,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x05, 0x12, 0x03, 0x3e, 0x02, 0x08, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3e, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x0b, 0x02, 0x00, 0x03, 0x12, 0x03, 0x3e, 0x0e, 0x0f, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x0c,
-    0x12, 0x03, 0x41, 0x00, 0x1b, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0c, 0x01, 0x12, 0x03, 0x41, 0x08,
-    0x18, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0d, 0x12, 0x04, 0x43, 0x00, 0x45, 0x01, 0x0a, 0x0a, 0x0a,
-    0x03, 0x04, 0x0d, 0x01, 0x12, 0x03, 0x43, 0x08, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d, 0x02,
-    0x00, 0x12, 0x03, 0x44, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x05, 0x12,
-    0x03, 0x44, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x01, 0x12, 0x03, 0x44,
-    0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x03, 0x12, 0x03, 0x44, 0x0e, 0x0f,
-    0x0a, 0x09, 0x0a, 0x02, 0x04, 0x0e, 0x12, 0x03, 0x47, 0x00, 0x1c, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
-    0x0e, 0x01, 0x12, 0x03, 0x47, 0x08, 0x19, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0f, 0x12, 0x04, 0x49,
-    0x00, 0x4b, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0f, 0x01, 0x12, 0x03, 0x49, 0x08, 0x15, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x00, 0x12, 0x03, 0x4a, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x0f, 0x02, 0x00, 0x05, 0x12, 0x03, 0x4a, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f,
-    0x02, 0x00, 0x01, 0x12, 0x03, 0x4a, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00,
-    0x03, 0x12, 0x03, 0x4a, 0x0e, 0x0f, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x10, 0x12, 0x03, 0x4d, 0x00,
-    0x19, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x10, 0x01, 0x12, 0x03, 0x4d, 0x08, 0x16, 0x0a, 0x0a, 0x0a,
-    0x02, 0x06, 0x00, 0x12, 0x04, 0x4f, 0x00, 0x5c, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x06, 0x00, 0x01,
-    0x12, 0x03, 0x4f, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x00, 0x12, 0x03, 0x50,
-    0x02, 0x35, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x50, 0x06, 0x0c,
-    0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x50, 0x0d, 0x1a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x50, 0x25, 0x33, 0x0a, 0x0b, 0x0a, 0x04,
-    0x06, 0x00, 0x02, 0x01, 0x12, 0x03, 0x51, 0x02, 0x2c, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02,
-    0x01, 0x01, 0x12, 0x03, 0x51, 0x06, 0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x02,
-    0x12, 0x03, 0x51, 0x0a, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03,
-    0x51, 0x1f, 0x2a, 0x0a, 0x0b, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x02, 0x12, 0x03, 0x52, 0x02, 0x6b,
-    0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x52, 0x06, 0x1e, 0x0a, 0x0c,
-    0x0a, 0x05, 0x06, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x52, 0x1f, 0x3e, 0x0a, 0x0c, 0x0a, 0x05,
-    0x06, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x52, 0x49, 0x69, 0x0a, 0x0b, 0x0a, 0x04, 0x06, 0x00,
-    0x02, 0x03, 0x12, 0x03, 0x53, 0x02, 0x59, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x03, 0x01,
-    0x12, 0x03, 0x53, 0x06, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03,
-    0x53, 0x19, 0x32, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x53, 0x3d,
-    0x57, 0x0a, 0x3c, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x04, 0x12, 0x03, 0x55, 0x02, 0x3b, 0x1a, 0x2f,
-    0x20, 0xe3, 0x83, 0x86, 0xe3, 0x83, 0x8a, 0xe3, 0x83, 0xb3, 0xe3, 0x83, 0x88, 0xe3, 0x81, 0x8c,
-    0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0xe3, 0x81, 0xae, 0xe6, 0xba, 0x96, 0xe5, 0x82, 0x99, 0xe3,
-    0x81, 0x8c, 0xe5, 0xae, 0x8c, 0xe4, 0xba, 0x86, 0xe3, 0x81, 0x97, 0xe3, 0x81, 0x9f, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x55, 0x06, 0x0e, 0x0a, 0x0c, 0x0a,
-    0x05, 0x06, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x55, 0x0f, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x06,
-    0x00, 0x02, 0x04, 0x03, 0x12, 0x03, 0x55, 0x29, 0x39, 0x0a, 0x33, 0x0a, 0x04, 0x06, 0x00, 0x02,
-    0x05, 0x12, 0x03, 0x57, 0x02, 0x3b, 0x1a, 0x26, 0x20, 0xe9, 0x85, 0x8d, 0xe9, 0x81, 0x94, 0xe5,
-    0x93, 0xa1, 0xe3, 0x81, 0x8c, 0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0xe3, 0x82, 0x92, 0xe5, 0x8f,
-    0x97, 0xe3, 0x81, 0x91, 0xe5, 0x8f, 0x96, 0xe3, 0x81, 0xa3, 0xe3, 0x81, 0x9f, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x06, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x57, 0x06, 0x0e, 0x0a, 0x0c, 0x0a, 0x05,
-    0x06, 0x00, 0x02, 0x05, 0x02, 0x12, 0x03, 0x57, 0x0f, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00,
-    0x02, 0x05, 0x03, 0x12, 0x03, 0x57, 0x29, 0x39, 0x0a, 0x33, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x06,
-    0x12, 0x03, 0x59, 0x02, 0x3e, 0x1a, 0x26, 0x20, 0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0xe3, 0x81,
-    0xae, 0xe5, 0x8f, 0x97, 0xe3, 0x81, 0x91, 0xe6, 0xb8, 0xa1, 0xe3, 0x81, 0x97, 0xe3, 0x81, 0x8c,
-    0xe5, 0xae, 0x8c, 0xe4, 0xba, 0x86, 0xe3, 0x81, 0x97, 0xe3, 0x81, 0x9f, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x06, 0x00, 0x02, 0x06, 0x01, 0x12, 0x03, 0x59, 0x06, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x06,
-    0x00, 0x02, 0x06, 0x02, 0x12, 0x03, 0x59, 0x10, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02,
-    0x06, 0x03, 0x12, 0x03, 0x59, 0x2b, 0x3c, 0x0a, 0x42, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x07, 0x12,
-    0x03, 0x5b, 0x02, 0x35, 0x1a, 0x35, 0x20, 0xe6, 0xb3, 0xa8, 0xe6, 0x96, 0x87, 0xe3, 0x82, 0x92,
-    0xe4, 0xbd, 0x95, 0xe3, 0x82, 0x89, 0xe3, 0x81, 0x8b, 0xe3, 0x81, 0xae, 0xe7, 0x90, 0x86, 0xe7,
-    0x94, 0xb1, 0xe3, 0x81, 0xa7, 0xe3, 0x82, 0xad, 0xe3, 0x83, 0xa3, 0xe3, 0x83, 0xb3, 0xe3, 0x82,
-    0xbb, 0xe3, 0x83, 0xab, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06,
-    0x00, 0x02, 0x07, 0x01, 0x12, 0x03, 0x5b, 0x06, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02,
-    0x07, 0x02, 0x12, 0x03, 0x5b, 0x0d, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x07, 0x03,
-    0x12, 0x03, 0x5b, 0x25, 0x33, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+    0x12, 0x03, 0x07, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03,
+    0x07, 0x02, 0x08, 0x0a, 0x9d, 0x01, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x0b, 0x00, 0x10, 0x01,
+    0x1a, 0x90, 0x01, 0x20, 0xe6, 0xb3, 0xa8, 0xe6, 0x96, 0x87, 0xe3, 0x81, 0xaf, 0xe3, 0x82, 0xab,
+    0xe3, 0x83, 0xbc, 0xe3, 0x83, 0x88, 0xe3, 0x81, 0x94, 0xe3, 0x81, 0xa8, 0xe3, 0x81, 0xab, 0x31,
+    0xe3, 0x81, 0xa4, 0xe3, 0x81, 0xa0, 0xe3, 0x81, 0x91, 0xe4, 0xbd, 0x9c, 0xe6, 0x88, 0x90, 0xe3,
+    0x81, 0x95, 0xe3, 0x82, 0x8c, 0xe3, 0x80, 0x81, 0xe5, 0x90, 0x8c, 0xe3, 0x81, 0x98, 0xe3, 0x82,
+    0xab, 0xe3, 0x83, 0xbc, 0xe3, 0x83, 0x88, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0xaa, 0xe3, 0x82, 0xaf,
+    0xe3, 0x82, 0xa8, 0xe3, 0x82, 0xb9, 0xe3, 0x83, 0x88, 0xe3, 0x82, 0x92, 0xe5, 0x86, 0x8d, 0xe5,
+    0xae, 0x9f, 0xe8, 0xa1, 0x8c, 0xe3, 0x81, 0x97, 0xe3, 0x81, 0x9f, 0xe5, 0xa0, 0xb4, 0xe5, 0x90,
+    0x88, 0xe3, 0x81, 0xaf, 0xe4, 0xbd, 0x9c, 0xe6, 0x88, 0x90, 0xe6, 0xb8, 0x88, 0xe3, 0x81, 0xbf,
+    0xe3, 0x81, 0xae, 0xe6, 0xb3, 0xa8, 0xe6, 0x96, 0x87, 0xe3, 0x82, 0x92, 0xe8, 0xbf, 0x94, 0xe3,
+    0x81, 0x99, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x0b, 0x08, 0x15, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x0c, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0c, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x00, 0x03, 0x12, 0x03, 0x0c, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00,
+    0x05, 0x12, 0x03, 0x0c, 0x02, 0x08, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03,
+    0x0d, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0d, 0x10,
+    0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0d, 0x18, 0x19, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x04, 0x12, 0x03, 0x0d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x01, 0x06, 0x12, 0x03, 0x0d, 0x0b, 0x0f, 0x0a, 0x78, 0x0a, 0x04, 0x04,
+    0x01, 0x02, 0x02, 0x12, 0x03, 0x0f, 0x02, 0x26, 0x1a, 0x6b, 0x20, 0xe5, 0x90, 0x8c, 0xe3, 0x81,
+    0x98, 0xe5, 0x86, 0xaa, 0xe7, 0xad, 0x89, 0xe3, 0x82, 0xad, 0xe3, 0x83, 0xbc, 0xe3, 0x81, 0xae,
+    0xe3, 0x83, 0xaa, 0xe3, 0x82, 0xaf, 0xe3, 0x82, 0xa8, 0xe3, 0x82, 0xb9, 0xe3, 0x83, 0x88, 0xe3,
+    0x82, 0x92, 0xe5, 0x86, 0x8d, 0xe5, 0xae, 0x9f, 0xe8, 0xa1, 0x8c, 0xe3, 0x81, 0x97, 0xe3, 0x81,
+    0x9f, 0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88, 0xe3, 0x81, 0xaf, 0xe6, 0x9c, 0x80, 0xe5, 0x88, 0x9d,
+    0xe3, 0x81, 0xae, 0xe3, 0x83, 0xaa, 0xe3, 0x82, 0xaf, 0xe3, 0x82, 0xa8, 0xe3, 0x82, 0xb9, 0xe3,
+    0x83, 0x88, 0xe3, 0x81, 0xae, 0xe7, 0xb5, 0x90, 0xe6, 0x9e, 0x9c, 0xe3, 0x82, 0x92, 0xe8, 0xbf,
+    0x94, 0xe3, 0x81, 0x99, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x0f, 0x12, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x0f, 0x24,
+    0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x04, 0x12, 0x03, 0x0f, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x05, 0x12, 0x03, 0x0f, 0x0b, 0x11, 0x0a, 0x0a, 0x0a,
+    0x02, 0x04, 0x02, 0x12, 0x04, 0x12, 0x00, 0x16, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01,
+    0x12, 0x03, 0x12, 0x08, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x13,
+    0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x13, 0x09, 0x0b,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x13, 0x0e, 0x0f, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x13, 0x02, 0x08, 0x0a, 0x3f, 0x0a, 0x04,
+    0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x15, 0x02, 0x15, 0x1a, 0x32, 0x20, 0xe3, 0x82, 0xb3, 0xe3,
+    0x83, 0x9e, 0xe3, 0x83, 0xb3, 0xe3, 0x83, 0x89, 0xe5, 0xae, 0x9f, 0xe8, 0xa1, 0x8c, 0xe5, 0xbe,
+    0x8c, 0xe3, 0x81, 0xae, 0xe9, 0x9b, 0x86, 0xe7, 0xb4, 0x84, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x90,
+    0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x83, 0xa7, 0xe3, 0x83, 0xb3, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x15, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x15, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x01, 0x05, 0x12, 0x03, 0x15, 0x02, 0x08, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x18,
+    0x00, 0x1d, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x18, 0x08, 0x12, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x1a, 0x04, 0x18, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1a, 0x0b, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x00, 0x03, 0x12, 0x03, 0x1a, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00,
+    0x05, 0x12, 0x03, 0x1a, 0x04, 0x0a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03,
+    0x1b, 0x04, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1b, 0x0b,
+    0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x1b, 0x15, 0x16, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x05, 0x12, 0x03, 0x1b, 0x04, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x03, 0x08, 0x00, 0x12, 0x04, 0x19, 0x02, 0x1c, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x08, 0x00, 0x01, 0x12, 0x03, 0x19, 0x08, 0x0a, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12,
+    0x04, 0x1f, 0x00, 0x2e, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x1f, 0x08,
+    0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x29, 0x02, 0x10, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x29, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x29, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
+    0x02, 0x00, 0x05, 0x12, 0x03, 0x29, 0x02, 0x08, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01,
+    0x12, 0x03, 0x2a, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03,
+    0x2a, 0x10, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x2a, 0x18,
+    0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x04, 0x12, 0x03, 0x2a, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x06, 0x12, 0x03, 0x2a, 0x0b, 0x0f, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x04, 0x02, 0x02, 0x12, 0x03, 0x2b, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
+    0x02, 0x02, 0x01, 0x12, 0x03, 0x2b, 0x0e, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02,
+    0x03, 0x12, 0x03, 0x2b, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x06, 0x12,
+    0x03, 0x2b, 0x02, 0x0d, 0x0a, 0x27, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x03, 0x12, 0x03, 0x2d, 0x02,
+    0x15, 0x1a, 0x1a, 0x20, 0xe9, 0x9b, 0x86, 0xe7, 0xb4, 0x84, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x90,
+    0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x83, 0xa7, 0xe3, 0x83, 0xb3, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x04, 0x02, 0x03, 0x01, 0x12, 0x03, 0x2d, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x04, 0x02, 0x03, 0x03, 0x12, 0x03, 0x2d, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
+    0x03, 0x05, 0x12, 0x03, 0x2d, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x04, 0x04, 0x00, 0x12,
+    0x04, 0x20, 0x02, 0x27, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x04, 0x00, 0x01, 0x12, 0x03,
+    0x20, 0x07, 0x12, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x21,
+    0x04, 0x21, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x21,
+    0x04, 0x1c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x21,
+    0x1f, 0x20, 0x0a, 0x1d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x22, 0x04,
+    0x1e, 0x22, 0x0e, 0x20, 0xe5, 0x8f, 0x97, 0xe4, 0xbb, 0x98, 0xe7, 0x8a, 0xb6, 0xe6, 0x85, 0x8b,
+    0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x22, 0x04,
+    0x19, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x22, 0x1c,
+    0x1d, 0x0a, 0x35, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x23, 0x04, 0x1e,
+    0x22, 0x26, 0x20, 0xe3, 0x83, 0x86, 0xe3, 0x83, 0x8a, 0xe3, 0x83, 0xb3, 0xe3, 0x83, 0x88, 0xe3,
+    0x81, 0xae, 0xe6, 0xba, 0x96, 0xe5, 0x82, 0x99, 0xe3, 0x81, 0x8c, 0xe5, 0xae, 0x8c, 0xe4, 0xba,
+    0x86, 0xe3, 0x81, 0x97, 0xe3, 0x81, 0x9f, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00,
+    0x02, 0x02, 0x01, 0x12, 0x03, 0x23, 0x04, 0x19, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00,
+    0x02, 0x02, 0x02, 0x12, 0x03, 0x23, 0x1c, 0x1d, 0x0a, 0x1a, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00,
+    0x02, 0x03, 0x12, 0x03, 0x24, 0x04, 0x20, 0x22, 0x0b, 0x20, 0xe9, 0x85, 0x8d, 0xe9, 0x81, 0x94,
+    0xe4, 0xb8, 0xad, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12,
+    0x03, 0x24, 0x04, 0x1b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12,
+    0x03, 0x24, 0x1e, 0x1f, 0x0a, 0x1d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03,
+    0x25, 0x04, 0x1f, 0x22, 0x0e, 0x20, 0xe9, 0x85, 0x8d, 0xe9, 0x81, 0x94, 0xe6, 0xb8, 0x88, 0xe3,
+    0x81, 0xbf, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03,
+    0x25, 0x04, 0x1a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03,
+    0x25, 0x1d, 0x1e, 0x0a, 0x32, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x26,
+    0x04, 0x1f, 0x22, 0x23, 0x20, 0xe6, 0xb3, 0xa8, 0xe6, 0x96, 0x87, 0xe3, 0x81, 0x8c, 0xe3, 0x82,
+    0xad, 0xe3, 0x83, 0xa3, 0xe3, 0x83, 0xb3, 0xe3, 0x82, 0xbb, 0xe3, 0x83, 0xab, 0xe3, 0x81, 0x95,
+    0xe3, 0x82, 0x8c, 0xe3, 0x81, 0x9f, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02,
+    0x05, 0x01, 0x12, 0x03, 0x26, 0x04, 0x1a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02,
+    0x05, 0x02, 0x12, 0x03, 0x26, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x30,
+    0x00, 0x36, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x30, 0x08, 0x27, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x31, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x31, 0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x00, 0x03, 0x12, 0x03, 0x31, 0x15, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00,
+    0x05, 0x12, 0x03, 0x31, 0x02, 0x08, 0x0a, 0x6b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x01, 0x12, 0x03,
+    0x33, 0x02, 0x17, 0x1a, 0x5e, 0x20, 0x31, 0xe3, 0x83, 0x9a, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8,
+    0xe3, 0x81, 0xab, 0xe5, 0x90, 0xab, 0xe3, 0x82, 0x81, 0xe3, 0x82, 0x8b, 0xe6, 0x9c, 0x80, 0xe5,
+    0xa4, 0xa7, 0xe4, 0xbb, 0xb6, 0xe6, 0x95, 0xb0, 0xe3, 0x80, 0x82, 0x30, 0xe3, 0x81, 0xae, 0xe5,
+    0xa0, 0xb4, 0xe5, 0x90, 0x88, 0xe3, 0x81, 0xaf, 0xe3, 0x82, 0xb5, 0xe3, 0x83, 0xbc, 0xe3, 0x83,
+    0x90, 0xe3, 0x83, 0xbc, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x87, 0xe3, 0x83, 0x95, 0xe3, 0x82, 0xa9,
+    0xe3, 0x83, 0xab, 0xe3, 0x83, 0x88, 0xe5, 0x80, 0xa4, 0xe3, 0x82, 0x92, 0xe4, 0xbd, 0xbf, 0xe3,
+    0x81, 0x86, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x01, 0x12, 0x03, 0x33, 0x09,
+    0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x03, 0x12, 0x03, 0x33, 0x15, 0x16, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x05, 0x12, 0x03, 0x33, 0x02, 0x08, 0x0a, 0x7f, 0x0a,
+    0x04, 0x04, 0x05, 0x02, 0x02, 0x12, 0x03, 0x35, 0x02, 0x18, 0x1a, 0x72, 0x20, 0xe5, 0x89, 0x8d,
+    0xe3, 0x81, 0xae, 0xe3, 0x83, 0x9a, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x81, 0xae, 0xe3,
+    0x83, 0xac, 0xe3, 0x82, 0xb9, 0xe3, 0x83, 0x9d, 0xe3, 0x83, 0xb3, 0xe3, 0x82, 0xb9, 0xe3, 0x81,
+    0xae, 0x20, 0x6e, 0x65, 0x78, 0x74, 0x5f, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0xe3, 0x80, 0x82, 0xe6, 0x9c, 0x80, 0xe5, 0x88, 0x9d, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x9a,
+    0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x82, 0x92, 0xe5, 0x8f, 0x96, 0xe5, 0xbe, 0x97, 0xe3,
+    0x81, 0x99, 0xe3, 0x82, 0x8b, 0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88, 0xe3, 0x81, 0xaf, 0xe6, 0x8c,
+    0x87, 0xe5, 0xae, 0x9a, 0xe3, 0x81, 0x97, 0xe3, 0x81, 0xaa, 0xe3, 0x81, 0x84, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03, 0x35, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x02, 0x03, 0x12, 0x03, 0x35, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x02, 0x05, 0x12, 0x03, 0x35, 0x02, 0x08, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04,
+    0x38, 0x00, 0x3c, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x38, 0x08, 0x28,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x39, 0x02, 0x1a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03, 0x39, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x39, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x00, 0x04, 0x12, 0x03, 0x39, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x05,
+    0x12, 0x03, 0x39, 0x0b, 0x11, 0x0a, 0x69, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x03, 0x3b,
+    0x02, 0x1d, 0x1a, 0x5c, 0x20, 0xe6, 0xac, 0xa1, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x9a, 0xe3, 0x83,
+    0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x82, 0x92, 0xe5, 0x8f, 0x96, 0xe5, 0xbe, 0x97, 0xe3, 0x81, 0x99,
+    0xe3, 0x82, 0x8b, 0xe3, 0x83, 0x88, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xaf, 0xe3, 0x83, 0xb3, 0xe3,
+    0x80, 0x82, 0xe6, 0xac, 0xa1, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x9a, 0xe3, 0x83, 0xbc, 0xe3, 0x82,
+    0xb8, 0xe3, 0x81, 0x8c, 0xe3, 0x81, 0xaa, 0xe3, 0x81, 0x84, 0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88,
+    0xe3, 0x81, 0xaf, 0xe7, 0xa9, 0xba, 0xe6, 0x96, 0x87, 0xe5, 0xad, 0x97, 0xe5, 0x88, 0x97, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x3b, 0x09, 0x18, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x03, 0x12, 0x03, 0x3b, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x01, 0x05, 0x12, 0x03, 0x3b, 0x02, 0x08, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x07,
+    0x12, 0x04, 0x3e, 0x00, 0x43, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x03, 0x3e,
+    0x08, 0x21, 0x0a, 0x6b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x40, 0x02, 0x17, 0x1a,
+    0x5e, 0x20, 0x31, 0xe3, 0x83, 0x9a, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x81, 0xab, 0xe5,
+    0x90, 0xab, 0xe3, 0x82, 0x81, 0xe3, 0x82, 0x8b, 0xe6, 0x9c, 0x80, 0xe5, 0xa4, 0xa7, 0xe4, 0xbb,
+    0xb6, 0xe6, 0x95, 0xb0, 0xe3, 0x80, 0x82, 0x30, 0xe3, 0x81, 0xae, 0xe5, 0xa0, 0xb4, 0xe5, 0x90,
+    0x88, 0xe3, 0x81, 0xaf, 0xe3, 0x82, 0xb5, 0xe3, 0x83, 0xbc, 0xe3, 0x83, 0x90, 0xe3, 0x83, 0xbc,
+    0xe3, 0x81, 0xae, 0xe3, 0x83, 0x87, 0xe3, 0x83, 0x95, 0xe3, 0x82, 0xa9, 0xe3, 0x83, 0xab, 0xe3,
+    0x83, 0x88, 0xe5, 0x80, 0xa4, 0xe3, 0x82, 0x92, 0xe4, 0xbd, 0xbf, 0xe3, 0x81, 0x86, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x40, 0x09, 0x12, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x40, 0x15, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x07, 0x02, 0x00, 0x05, 0x12, 0x03, 0x40, 0x02, 0x08, 0x0a, 0x7f, 0x0a, 0x04, 0x04, 0x07, 0x02,
+    0x01, 0x12, 0x03, 0x42, 0x02, 0x18, 0x1a, 0x72, 0x20, 0xe5, 0x89, 0x8d, 0xe3, 0x81, 0xae, 0xe3,
+    0x83, 0x9a, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0xac, 0xe3, 0x82,
+    0xb9, 0xe3, 0x83, 0x9d, 0xe3, 0x83, 0xb3, 0xe3, 0x82, 0xb9, 0xe3, 0x81, 0xae, 0x20, 0x6e, 0x65,
+    0x78, 0x74, 0x5f, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0xe3, 0x80, 0x82,
+    0xe6, 0x9c, 0x80, 0xe5, 0x88, 0x9d, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x9a, 0xe3, 0x83, 0xbc, 0xe3,
+    0x82, 0xb8, 0xe3, 0x82, 0x92, 0xe5, 0x8f, 0x96, 0xe5, 0xbe, 0x97, 0xe3, 0x81, 0x99, 0xe3, 0x82,
+    0x8b, 0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88, 0xe3, 0x81, 0xaf, 0xe6, 0x8c, 0x87, 0xe5, 0xae, 0x9a,
+    0xe3, 0x81, 0x97, 0xe3, 0x81, 0xaa, 0xe3, 0x81, 0x84, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
+    0x02, 0x01, 0x01, 0x12, 0x03, 0x42, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01,
+    0x03, 0x12, 0x03, 0x42, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x05, 0x12,
+    0x03, 0x42, 0x02, 0x08, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x08, 0x12, 0x04, 0x45, 0x00, 0x49, 0x01,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x03, 0x45, 0x08, 0x22, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x46, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x46, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03,
+    0x12, 0x03, 0x46, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x04, 0x12, 0x03,
+    0x46, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x05, 0x12, 0x03, 0x46, 0x0b,
+    0x11, 0x0a, 0x69, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x01, 0x12, 0x03, 0x48, 0x02, 0x1d, 0x1a, 0x5c,
+    0x20, 0xe6, 0xac, 0xa1, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x9a, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8,
+    0xe3, 0x82, 0x92, 0xe5, 0x8f, 0x96, 0xe5, 0xbe, 0x97, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0xe3,
+    0x83, 0x88, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xaf, 0xe3, 0x83, 0xb3, 0xe3, 0x80, 0x82, 0xe6, 0xac,
+    0xa1, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x9a, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x81, 0x8c,
+    0xe3, 0x81, 0xaa, 0xe3, 0x81, 0x84, 0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88, 0xe3, 0x81, 0xaf, 0xe7,
+    0xa9, 0xba, 0xe6, 0x96, 0x87, 0xe5, 0xad, 0x97, 0xe5, 0x88, 0x97, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x01, 0x01, 0x12, 0x03, 0x48, 0x09, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x01, 0x03, 0x12, 0x03, 0x48, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01,
+    0x05, 0x12, 0x03, 0x48, 0x02, 0x08, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x04, 0x4b, 0x00,
+    0x51, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x4b, 0x08, 0x17, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x03, 0x4c, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x09, 0x02, 0x00, 0x01, 0x12, 0x03, 0x4c, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x4c, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x05,
+    0x12, 0x03, 0x4c, 0x02, 0x08, 0x0a, 0x5d, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x01, 0x12, 0x03, 0x4e,
+    0x02, 0x27, 0x1a, 0x50, 0x20, 0xe9, 0x9b, 0x86, 0xe7, 0xb4, 0x84, 0xe3, 0x81, 0x8c, 0xe6, 0x8c,
+    0x87, 0xe5, 0xae, 0x9a, 0xe3, 0x81, 0x97, 0xe3, 0x81, 0x9f, 0xe3, 0x83, 0x90, 0xe3, 0x83, 0xbc,
+    0xe3, 0x82, 0xb8, 0xe3, 0x83, 0xa7, 0xe3, 0x83, 0xb3, 0xe3, 0x81, 0xae, 0xe5, 0xa0, 0xb4, 0xe5,
+    0x90, 0x88, 0xe3, 0x81, 0xae, 0xe3, 0x81, 0xbf, 0xe3, 0x82, 0xb3, 0xe3, 0x83, 0x9e, 0xe3, 0x83,
+    0xb3, 0xe3, 0x83, 0x89, 0xe3, 0x82, 0x92, 0xe5, 0xae, 0x9f, 0xe8, 0xa1, 0x8c, 0xe3, 0x81, 0x99,
+    0xe3, 0x82, 0x8b, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x01, 0x12, 0x03, 0x4e,
+    0x12, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x03, 0x12, 0x03, 0x4e, 0x25, 0x26,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x04, 0x12, 0x03, 0x4e, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x05, 0x12, 0x03, 0x4e, 0x0b, 0x11, 0x0a, 0x78, 0x0a, 0x04,
+    0x04, 0x09, 0x02, 0x02, 0x12, 0x03, 0x50, 0x02, 0x26, 0x1a, 0x6b, 0x20, 0xe5, 0x90, 0x8c, 0xe3,
+    0x81, 0x98, 0xe5, 0x86, 0xaa, 0xe7, 0xad, 0x89, 0xe3, 0x82, 0xad, 0xe3, 0x83, 0xbc, 0xe3, 0x81,
+    0xae, 0xe3, 0x83, 0xaa, 0xe3, 0x82, 0xaf, 0xe3, 0x82, 0xa8, 0xe3, 0x82, 0xb9, 0xe3, 0x83, 0x88,
+    0xe3, 0x82, 0x92, 0xe5, 0x86, 0x8d, 0xe5, 0xae, 0x9f, 0xe8, 0xa1, 0x8c, 0xe3, 0x81, 0x97, 0xe3,
+    0x81, 0x9f, 0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88, 0xe3, 0x81, 0xaf, 0xe6, 0x9c, 0x80, 0xe5, 0x88,
+    0x9d, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0xaa, 0xe3, 0x82, 0xaf, 0xe3, 0x82, 0xa8, 0xe3, 0x82, 0xb9,
+    0xe3, 0x83, 0x88, 0xe3, 0x81, 0xae, 0xe7, 0xb5, 0x90, 0xe6, 0x9e, 0x9c, 0xe3, 0x82, 0x92, 0xe8,
+    0xbf, 0x94, 0xe3, 0x81, 0x99, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x01, 0x12,
+    0x03, 0x50, 0x12, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x03, 0x12, 0x03, 0x50,
+    0x24, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x04, 0x12, 0x03, 0x50, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x05, 0x12, 0x03, 0x50, 0x0b, 0x11, 0x0a, 0x0a,
+    0x0a, 0x02, 0x04, 0x0a, 0x12, 0x04, 0x53, 0x00, 0x56, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0a,
+    0x01, 0x12, 0x03, 0x53, 0x08, 0x18, 0x0a, 0x3f, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x00, 0x12, 0x03,
+    0x55, 0x02, 0x15, 0x1a, 0x32, 0x20, 0xe3, 0x82, 0xb3, 0xe3, 0x83, 0x9e, 0xe3, 0x83, 0xb3, 0xe3,
+    0x83, 0x89, 0xe5, 0xae, 0x9f, 0xe8, 0xa1, 0x8c, 0xe5, 0xbe, 0x8c, 0xe3, 0x81, 0xae, 0xe9, 0x9b,
+    0x86, 0xe7, 0xb4, 0x84, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x90, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8,
+    0xe3, 0x83, 0xa7, 0xe3, 0x83, 0xb3, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x55, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x55, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x05, 0x12, 0x03, 0x55, 0x02,
+    0x08, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0b, 0x12, 0x04, 0x58, 0x00, 0x5e, 0x01, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x0b, 0x01, 0x12, 0x03, 0x58, 0x08, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02,
+    0x00, 0x12, 0x03, 0x59, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x59, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x03, 0x12, 0x03, 0x59,
+    0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x05, 0x12, 0x03, 0x59, 0x02, 0x08,
+    0x0a, 0x5d, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x01, 0x12, 0x03, 0x5b, 0x02, 0x27, 0x1a, 0x50, 0x20,
+    0xe9, 0x9b, 0x86, 0xe7, 0xb4, 0x84, 0xe3, 0x81, 0x8c, 0xe6, 0x8c, 0x87, 0xe5, 0xae, 0x9a, 0xe3,
+    0x81, 0x97, 0xe3, 0x81, 0x9f, 0xe3, 0x83, 0x90, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x83,
+    0xa7, 0xe3, 0x83, 0xb3, 0xe3, 0x81, 0xae, 0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88, 0xe3, 0x81, 0xae,
+    0xe3, 0x81, 0xbf, 0xe3, 0x82, 0xb3, 0xe3, 0x83, 0x9e, 0xe3, 0x83, 0xb3, 0xe3, 0x83, 0x89, 0xe3,
+    0x82, 0x92, 0xe5, 0xae, 0x9f, 0xe8, 0xa1, 0x8c, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x01, 0x12, 0x03, 0x5b, 0x12, 0x22, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0b, 0x02, 0x01, 0x03, 0x12, 0x03, 0x5b, 0x25, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0b, 0x02, 0x01, 0x04, 0x12, 0x03, 0x5b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02,
+    0x01, 0x05, 0x12, 0x03, 0x5b, 0x0b, 0x11, 0x0a, 0x78, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x02, 0x12,
+    0x03, 0x5d, 0x02, 0x26, 0x1a, 0x6b, 0x20, 0xe5, 0x90, 0x8c, 0xe3, 0x81, 0x98, 0xe5, 0x86, 0xaa,
+    0xe7, 0xad, 0x89, 0xe3, 0x82, 0xad, 0xe3, 0x83, 0xbc, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0xaa, 0xe3,
+    0x82, 0xaf, 0xe3, 0x82, 0xa8, 0xe3, 0x82, 0xb9, 0xe3, 0x83, 0x88, 0xe3, 0x82, 0x92, 0xe5, 0x86,
+    0x8d, 0xe5, 0xae, 0x9f, 0xe8, 0xa1, 0x8c, 0xe3, 0x81, 0x97, 0xe3, 0x81, 0x9f, 0xe5, 0xa0, 0xb4,
+    0xe5, 0x90, 0x88, 0xe3, 0x81, 0xaf, 0xe6, 0x9c, 0x80, 0xe5, 0x88, 0x9d, 0xe3, 0x81, 0xae, 0xe3,
+    0x83, 0xaa, 0xe3, 0x82, 0xaf, 0xe3, 0x82, 0xa8, 0xe3, 0x82, 0xb9, 0xe3, 0x83, 0x88, 0xe3, 0x81,
+    0xae, 0xe7, 0xb5, 0x90, 0xe6, 0x9e, 0x9c, 0xe3, 0x82, 0x92, 0xe8, 0xbf, 0x94, 0xe3, 0x81, 0x99,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x01, 0x12, 0x03, 0x5d, 0x12, 0x21, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x03, 0x12, 0x03, 0x5d, 0x24, 0x25, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0b, 0x02, 0x02, 0x04, 0x12, 0x03, 0x5d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0b, 0x02, 0x02, 0x05, 0x12, 0x03, 0x5d, 0x0b, 0x11, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0c, 0x12,
+    0x04, 0x60, 0x00, 0x63, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0c, 0x01, 0x12, 0x03, 0x60, 0x08,
+    0x18, 0x0a, 0x3f, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x00, 0x12, 0x03, 0x62, 0x02, 0x15, 0x1a, 0x32,
+    0x20, 0xe3, 0x82, 0xb3, 0xe3, 0x83, 0x9e, 0xe3, 0x83, 0xb3, 0xe3, 0x83, 0x89, 0xe5, 0xae, 0x9f,
+    0xe8, 0xa1, 0x8c, 0xe5, 0xbe, 0x8c, 0xe3, 0x81, 0xae, 0xe9, 0x9b, 0x86, 0xe7, 0xb4, 0x84, 0xe3,
+    0x81, 0xae, 0xe3, 0x83, 0x90, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x83, 0xa7, 0xe3, 0x83,
+    0xb3, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x01, 0x12, 0x03, 0x62, 0x09, 0x10,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x03, 0x12, 0x03, 0x62, 0x13, 0x14, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x05, 0x12, 0x03, 0x62, 0x02, 0x08, 0x0a, 0x0a, 0x0a, 0x02,
+    0x04, 0x0d, 0x12, 0x04, 0x65, 0x00, 0x6b, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0d, 0x01, 0x12,
+    0x03, 0x65, 0x08, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x00, 0x12, 0x03, 0x66, 0x02,
+    0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x01, 0x12, 0x03, 0x66, 0x09, 0x0b, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x03, 0x12, 0x03, 0x66, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0d, 0x02, 0x00, 0x05, 0x12, 0x03, 0x66, 0x02, 0x08, 0x0a, 0x5d, 0x0a, 0x04, 0x04,
+    0x0d, 0x02, 0x01, 0x12, 0x03, 0x68, 0x02, 0x27, 0x1a, 0x50, 0x20, 0xe9, 0x9b, 0x86, 0xe7, 0xb4,
+    0x84, 0xe3, 0x81, 0x8c, 0xe6, 0x8c, 0x87, 0xe5, 0xae, 0x9a, 0xe3, 0x81, 0x97, 0xe3, 0x81, 0x9f,
+    0xe3, 0x83, 0x90, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x83, 0xa7, 0xe3, 0x83, 0xb3, 0xe3,
+    0x81, 0xae, 0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88, 0xe3, 0x81, 0xae, 0xe3, 0x81, 0xbf, 0xe3, 0x82,
+    0xb3, 0xe3, 0x83, 0x9e, 0xe3, 0x83, 0xb3, 0xe3, 0x83, 0x89, 0xe3, 0x82, 0x92, 0xe5, 0xae, 0x9f,
+    0xe8, 0xa1, 0x8c, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d,
+    0x02, 0x01, 0x01, 0x12, 0x03, 0x68, 0x12, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01,
+    0x03, 0x12, 0x03, 0x68, 0x25, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x04, 0x12,
+    0x03, 0x68, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x05, 0x12, 0x03, 0x68,
+    0x0b, 0x11, 0x0a, 0x78, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x02, 0x12, 0x03, 0x6a, 0x02, 0x26, 0x1a,
+    0x6b, 0x20, 0xe5, 0x90, 0x8c, 0xe3, 0x81, 0x98, 0xe5, 0x86, 0xaa, 0xe7, 0xad, 0x89, 0xe3, 0x82,
+    0xad, 0xe3, 0x83, 0xbc, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0xaa, 0xe3, 0x82, 0xaf, 0xe3, 0x82, 0xa8,
+    0xe3, 0x82, 0xb9, 0xe3, 0x83, 0x88, 0xe3, 0x82, 0x92, 0xe5, 0x86, 0x8d, 0xe5, 0xae, 0x9f, 0xe8,
+    0xa1, 0x8c, 0xe3, 0x81, 0x97, 0xe3, 0x81, 0x9f, 0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88, 0xe3, 0x81,
+    0xaf, 0xe6, 0x9c, 0x80, 0xe5, 0x88, 0x9d, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0xaa, 0xe3, 0x82, 0xaf,
+    0xe3, 0x82, 0xa8, 0xe3, 0x82, 0xb9, 0xe3, 0x83, 0x88, 0xe3, 0x81, 0xae, 0xe7, 0xb5, 0x90, 0xe6,
+    0x9e, 0x9c, 0xe3, 0x82, 0x92, 0xe8, 0xbf, 0x94, 0xe3, 0x81, 0x99, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0d, 0x02, 0x02, 0x01, 0x12, 0x03, 0x6a, 0x12, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d,
+    0x02, 0x02, 0x03, 0x12, 0x03, 0x6a, 0x24, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02,
+    0x04, 0x12, 0x03, 0x6a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x05, 0x12,
+    0x03, 0x6a, 0x0b, 0x11, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0e, 0x12, 0x04, 0x6d, 0x00, 0x70, 0x01,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0e, 0x01, 0x12, 0x03, 0x6d, 0x08, 0x19, 0x0a, 0x3f, 0x0a, 0x04,
+    0x04, 0x0e, 0x02, 0x00, 0x12, 0x03, 0x6f, 0x02, 0x15, 0x1a, 0x32, 0x20, 0xe3, 0x82, 0xb3, 0xe3,
+    0x83, 0x9e, 0xe3, 0x83, 0xb3, 0xe3, 0x83, 0x89, 0xe5, 0xae, 0x9f, 0xe8, 0xa1, 0x8c, 0xe5, 0xbe,
+    0x8c, 0xe3, 0x81, 0xae, 0xe9, 0x9b, 0x86, 0xe7, 0xb4, 0x84, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x90,
+    0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x83, 0xa7, 0xe3, 0x83, 0xb3, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0e, 0x02, 0x00, 0x01, 0x12, 0x03, 0x6f, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0e, 0x02, 0x00, 0x03, 0x12, 0x03, 0x6f, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02,
+    0x00, 0x05, 0x12, 0x03, 0x6f, 0x02, 0x08, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0f, 0x12, 0x04, 0x72,
+    0x00, 0x78, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0f, 0x01, 0x12, 0x03, 0x72, 0x08, 0x15, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x00, 0x12, 0x03, 0x73, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0f, 0x02, 0x00, 0x01, 0x12, 0x03, 0x73, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f,
+    0x02, 0x00, 0x03, 0x12, 0x03, 0x73, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00,
+    0x05, 0x12, 0x03, 0x73, 0x02, 0x08, 0x0a, 0x5d, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x01, 0x12, 0x03,
+    0x75, 0x02, 0x27, 0x1a, 0x50, 0x20, 0xe9, 0x9b, 0x86, 0xe7, 0xb4, 0x84, 0xe3, 0x81, 0x8c, 0xe6,
+    0x8c, 0x87, 0xe5, 0xae, 0x9a, 0xe3, 0x81, 0x97, 0xe3, 0x81, 0x9f, 0xe3, 0x83, 0x90, 0xe3, 0x83,
+    0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x83, 0xa7, 0xe3, 0x83, 0xb3, 0xe3, 0x81, 0xae, 0xe5, 0xa0, 0xb4,
+    0xe5, 0x90, 0x88, 0xe3, 0x81, 0xae, 0xe3, 0x81, 0xbf, 0xe3, 0x82, 0xb3, 0xe3, 0x83, 0x9e, 0xe3,
+    0x83, 0xb3, 0xe3, 0x83, 0x89, 0xe3, 0x82, 0x92, 0xe5, 0xae, 0x9f, 0xe8, 0xa1, 0x8c, 0xe3, 0x81,
+    0x99, 0xe3, 0x82, 0x8b, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x01, 0x12, 0x03,
+    0x75, 0x12, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x03, 0x12, 0x03, 0x75, 0x25,
+    0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x04, 0x12, 0x03, 0x75, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x05, 0x12, 0x03, 0x75, 0x0b, 0x11, 0x0a, 0x78, 0x0a,
+    0x04, 0x04, 0x0f, 0x02, 0x02, 0x12, 0x03, 0x77, 0x02, 0x26, 0x1a, 0x6b, 0x20, 0xe5, 0x90, 0x8c,
+    0xe3, 0x81, 0x98, 0xe5, 0x86, 0xaa, 0xe7, 0xad, 0x89, 0xe3, 0x82, 0xad, 0xe3, 0x83, 0xbc, 0xe3,
+    0x81, 0xae, 0xe3, 0x83, 0xaa, 0xe3, 0x82, 0xaf, 0xe3, 0x82, 0xa8, 0xe3, 0x82, 0xb9, 0xe3, 0x83,
+    0x88, 0xe3, 0x82, 0x92, 0xe5, 0x86, 0x8d, 0xe5, 0xae, 0x9f, 0xe8, 0xa1, 0x8c, 0xe3, 0x81, 0x97,
+    0xe3, 0x81, 0x9f, 0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88, 0xe3, 0x81, 0xaf, 0xe6, 0x9c, 0x80, 0xe5,
+    0x88, 0x9d, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0xaa, 0xe3, 0x82, 0xaf, 0xe3, 0x82, 0xa8, 0xe3, 0x82,
+    0xb9, 0xe3, 0x83, 0x88, 0xe3, 0x81, 0xae, 0xe7, 0xb5, 0x90, 0xe6, 0x9e, 0x9c, 0xe3, 0x82, 0x92,
+    0xe8, 0xbf, 0x94, 0xe3, 0x81, 0x99, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x01,
+    0x12, 0x03, 0x77, 0x12, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x03, 0x12, 0x03,
+    0x77, 0x24, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x04, 0x12, 0x03, 0x77, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x05, 0x12, 0x03, 0x77, 0x0b, 0x11, 0x0a,
+    0x0a, 0x0a, 0x02, 0x04, 0x10, 0x12, 0x04, 0x7a, 0x00, 0x7d, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x10, 0x01, 0x12, 0x03, 0x7a, 0x08, 0x16, 0x0a, 0x3f, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x00, 0x12,
+    0x03, 0x7c, 0x02, 0x15, 0x1a, 0x32, 0x20, 0xe3, 0x82, 0xb3, 0xe3, 0x83, 0x9e, 0xe3, 0x83, 0xb3,
+    0xe3, 0x83, 0x89, 0xe5, 0xae, 0x9f, 0xe8, 0xa1, 0x8c, 0xe5, 0xbe, 0x8c, 0xe3, 0x81, 0xae, 0xe9,
+    0x9b, 0x86, 0xe7, 0xb4, 0x84, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x90, 0xe3, 0x83, 0xbc, 0xe3, 0x82,
+    0xb8, 0xe3, 0x83, 0xa7, 0xe3, 0x83, 0xb3, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x7c, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x03, 0x12,
+    0x03, 0x7c, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x05, 0x12, 0x03, 0x7c,
+    0x02, 0x08, 0x0a, 0x0b, 0x0a, 0x02, 0x06, 0x00, 0x12, 0x05, 0x7f, 0x00, 0x8c, 0x01, 0x01, 0x0a,
+    0x0a, 0x0a, 0x03, 0x06, 0x00, 0x01, 0x12, 0x03, 0x7f, 0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x06,
+    0x00, 0x02, 0x00, 0x12, 0x04, 0x80, 0x01, 0x02, 0x35, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0x80, 0x01, 0x06, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00,
+    0x02, 0x12, 0x04, 0x80, 0x01, 0x0d, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x03,
+    0x12, 0x04, 0x80, 0x01, 0x25, 0x33, 0x0a, 0x0c, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x01, 0x12, 0x04,
+    0x81, 0x01, 0x02, 0x2c, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0x81,
+    0x01, 0x06, 0x09, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0x81, 0x01,
+    0x0a, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x03, 0x12, 0x04, 0x81, 0x01, 0x1f,
+    0x2a, 0x0a, 0x0c, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x02, 0x12, 0x04, 0x82, 0x01, 0x02, 0x6b, 0x0a,
+    0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0x82, 0x01, 0x06, 0x1e, 0x0a, 0x0d,
+    0x0a, 0x05, 0x06, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0x82, 0x01, 0x1f, 0x3e, 0x0a, 0x0d, 0x0a,
+    0x05, 0x06, 0x00, 0x02, 0x02, 0x03, 0x12, 0x04, 0x82, 0x01, 0x49, 0x69, 0x0a, 0x0c, 0x0a, 0x04,
+    0x06, 0x00, 0x02, 0x03, 0x12, 0x04, 0x83, 0x01, 0x02, 0x59, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00,
+    0x02, 0x03, 0x01, 0x12, 0x04, 0x83, 0x01, 0x06, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02,
+    0x03, 0x02, 0x12, 0x04, 0x83, 0x01, 0x19, 0x32, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x03,
+    0x03, 0x12, 0x04, 0x83, 0x01, 0x3d, 0x57, 0x0a, 0x3d, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x04, 0x12,
+    0x04, 0x85, 0x01, 0x02, 0x3b, 0x1a, 0x2f, 0x20, 0xe3, 0x83, 0x86, 0xe3, 0x83, 0x8a, 0xe3, 0x83,
+    0xb3, 0xe3, 0x83, 0x88, 0xe3, 0x81, 0x8c, 0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0xe3, 0x81, 0xae,
+    0xe6, 0xba, 0x96, 0xe5, 0x82, 0x99, 0xe3, 0x81, 0x8c, 0xe5, 0xae, 0x8c, 0xe4, 0xba, 0x86, 0xe3,
+    0x81, 0x97, 0xe3, 0x81, 0x9f, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x04, 0x01, 0x12,
+    0x04, 0x85, 0x01, 0x06, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x04, 0x02, 0x12, 0x04,
+    0x85, 0x01, 0x0f, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x04, 0x03, 0x12, 0x04, 0x85,
+    0x01, 0x29, 0x39, 0x0a, 0x34, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x05, 0x12, 0x04, 0x87, 0x01, 0x02,
+    0x3b, 0x1a, 0x26, 0x20, 0xe9, 0x85, 0x8d, 0xe9, 0x81, 0x94, 0xe5, 0x93, 0xa1, 0xe3, 0x81, 0x8c,
+    0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0xe3, 0x82, 0x92, 0xe5, 0x8f, 0x97, 0xe3, 0x81, 0x91, 0xe5,
+    0x8f, 0x96, 0xe3, 0x81, 0xa3, 0xe3, 0x81, 0x9f, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02,
+    0x05, 0x01, 0x12, 0x04, 0x87, 0x01, 0x06, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x05,
+    0x02, 0x12, 0x04, 0x87, 0x01, 0x0f, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x05, 0x03,
+    0x12, 0x04, 0x87, 0x01, 0x29, 0x39, 0x0a, 0x34, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x06, 0x12, 0x04,
+    0x89, 0x01, 0x02, 0x3e, 0x1a, 0x26, 0x20, 0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0xe3, 0x81, 0xae,
+    0xe5, 0x8f, 0x97, 0xe3, 0x81, 0x91, 0xe6, 0xb8, 0xa1, 0xe3, 0x81, 0x97, 0xe3, 0x81, 0x8c, 0xe5,
+    0xae, 0x8c, 0xe4, 0xba, 0x86, 0xe3, 0x81, 0x97, 0xe3, 0x81, 0x9f, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x06, 0x00, 0x02, 0x06, 0x01, 0x12, 0x04, 0x89, 0x01, 0x06, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x06,
+    0x00, 0x02, 0x06, 0x02, 0x12, 0x04, 0x89, 0x01, 0x10, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00,
+    0x02, 0x06, 0x03, 0x12, 0x04, 0x89, 0x01, 0x2b, 0x3c, 0x0a, 0x43, 0x0a, 0x04, 0x06, 0x00, 0x02,
+    0x07, 0x12, 0x04, 0x8b, 0x01, 0x02, 0x35, 0x1a, 0x35, 0x20, 0xe6, 0xb3, 0xa8, 0xe6, 0x96, 0x87,
+    0xe3, 0x82, 0x92, 0xe4, 0xbd, 0x95, 0xe3, 0x82, 0x89, 0xe3, 0x81, 0x8b, 0xe3, 0x81, 0xae, 0xe7,
+    0x90, 0x86, 0xe7, 0x94, 0xb1, 0xe3, 0x81, 0xa7, 0xe3, 0x82, 0xad, 0xe3, 0x83, 0xa3, 0xe3, 0x83,
+    0xb3, 0xe3, 0x82, 0xbb, 0xe3, 0x83, 0xab, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x06, 0x00, 0x02, 0x07, 0x01, 0x12, 0x04, 0x8b, 0x01, 0x06, 0x0c, 0x0a, 0x0d, 0x0a,
+    0x05, 0x06, 0x00, 0x02, 0x07, 0x02, 0x12, 0x04, 0x8b, 0x01, 0x0d, 0x1a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x06, 0x00, 0x02, 0x07, 0x03, 0x12, 0x04, 0x8b, 0x01, 0x25, 0x33, 0x0a, 0x08, 0x0a, 0x01, 0x0c,
+    0x12, 0x03, 0x00, 0x00, 0x12, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
 ];
 include!("order.v1.serde.rs");
 include!("order.v1.tonic.rs");
@@ -10,10 +10,23 @@ impl serde::Serialize for CancelRequest {
         if !self.id.is_empty() {
             len += 1;
         }
+        if self.expected_version.is_some() {
+            len += 1;
+        }
+        if self.idempotency_key.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("order.v1.CancelRequest", len)?;
         if !self.id.is_empty() {
             struct_ser.serialize_field("id", &self.id)?;
         }
+        if let Some(v) = self.expected_version.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("expectedVersion", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.idempotency_key.as_ref() {
+            struct_ser.serialize_field("idempotencyKey", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -25,11 +38,17 @@ impl<'de> serde::Deserialize<'de> for CancelRequest {
     {
         const FIELDS: &[&str] = &[
             "id",
+            "expected_version",
+            "expectedVersion",
+            "idempotency_key",
+            "idempotencyKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Id,
+            ExpectedVersion,
+            IdempotencyKey,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -52,6 +71,8 @@ impl<'de> serde::Deserialize<'de> for CancelRequest {
                     {
                         match value {
                             "id" => Ok(GeneratedField::Id),
+                            "expectedVersion" | "expected_version" => Ok(GeneratedField::ExpectedVersion),
+                            "idempotencyKey" | "idempotency_key" => Ok(GeneratedField::IdempotencyKey),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -72,6 +93,8 @@ impl<'de> serde::Deserialize<'de> for CancelRequest {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut id__ = None;
+                let mut expected_version__ = None;
+                let mut idempotency_key__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -80,10 +103,26 @@ impl<'de> serde::Deserialize<'de> for CancelRequest {
                             }
                             id__ = Some(map.next_value()?);
                         }
+                        GeneratedField::ExpectedVersion => {
+                            if expected_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expectedVersion"));
+                            }
+                            expected_version__ = 
+                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::IdempotencyKey => {
+                            if idempotency_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("idempotencyKey"));
+                            }
+                            idempotency_key__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(CancelRequest {
                     id: id__.unwrap_or_default(),
+                    expected_version: expected_version__,
+                    idempotency_key: idempotency_key__,
                 })
             }
         }
@@ -97,8 +136,15 @@ impl serde::Serialize for CancelResponse {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("order.v1.CancelResponse", len)?;
+        let mut len = 0;
+        if self.version != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("order.v1.CancelResponse", len)?;
+        if self.version != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("version", ToString::to_string(&self.version).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -109,10 +155,12 @@ impl<'de> serde::Deserialize<'de> for CancelResponse {
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "version",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            Version,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -133,7 +181,10 @@ impl<'de> serde::Deserialize<'de> for CancelResponse {
                     where
                         E: serde::de::Error,
                     {
-                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                        match value {
+                            "version" => Ok(GeneratedField::Version),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -151,10 +202,21 @@ impl<'de> serde::Deserialize<'de> for CancelResponse {
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                let mut version__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
                 }
                 Ok(CancelResponse {
+                    version: version__.unwrap_or_default(),
                 })
             }
         }
@@ -175,6 +237,9 @@ impl serde::Serialize for CreateRequest {
         if !self.items.is_empty() {
             len += 1;
         }
+        if self.idempotency_key.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("order.v1.CreateRequest", len)?;
         if !self.cart_id.is_empty() {
             struct_ser.serialize_field("cartId", &self.cart_id)?;
@@ -182,6 +247,9 @@ impl serde::Serialize for CreateRequest {
         if !self.items.is_empty() {
             struct_ser.serialize_field("items", &self.items)?;
         }
+        if let Some(v) = self.idempotency_key.as_ref() {
+            struct_ser.serialize_field("idempotencyKey", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -195,12 +263,15 @@ impl<'de> serde::Deserialize<'de> for CreateRequest {
             "cart_id",
             "cartId",
             "items",
+            "idempotency_key",
+            "idempotencyKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             CartId,
             Items,
+            IdempotencyKey,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -224,6 +295,7 @@ impl<'de> serde::Deserialize<'de> for CreateRequest {
                         match value {
                             "cartId" | "cart_id" => Ok(GeneratedField::CartId),
                             "items" => Ok(GeneratedField::Items),
+                            "idempotencyKey" | "idempotency_key" => Ok(GeneratedField::IdempotencyKey),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -245,6 +317,7 @@ impl<'de> serde::Deserialize<'de> for CreateRequest {
             {
                 let mut cart_id__ = None;
                 let mut items__ = None;
+                let mut idempotency_key__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::CartId => {
@@ -259,11 +332,18 @@ impl<'de> serde::Deserialize<'de> for CreateRequest {
                             }
                             items__ = Some(map.next_value()?);
                         }
+                        GeneratedField::IdempotencyKey => {
+                            if idempotency_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("idempotencyKey"));
+                            }
+                            idempotency_key__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(CreateRequest {
                     cart_id: cart_id__.unwrap_or_default(),
                     items: items__.unwrap_or_default(),
+                    idempotency_key: idempotency_key__,
                 })
             }
         }
@@ -281,10 +361,17 @@ impl serde::Serialize for CreateResponse {
         if !self.id.is_empty() {
             len += 1;
         }
+        if self.version != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("order.v1.CreateResponse", len)?;
         if !self.id.is_empty() {
             struct_ser.serialize_field("id", &self.id)?;
         }
+        if self.version != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("version", ToString::to_string(&self.version).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -296,11 +383,13 @@ impl<'de> serde::Deserialize<'de> for CreateResponse {
     {
         const FIELDS: &[&str] = &[
             "id",
+            "version",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Id,
+            Version,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -323,6 +412,7 @@ impl<'de> serde::Deserialize<'de> for CreateResponse {
                     {
                         match value {
                             "id" => Ok(GeneratedField::Id),
+                            "version" => Ok(GeneratedField::Version),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -343,6 +433,7 @@ impl<'de> serde::Deserialize<'de> for CreateResponse {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut id__ = None;
+                let mut version__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -351,10 +442,19 @@ impl<'de> serde::Deserialize<'de> for CreateResponse {
                             }
                             id__ = Some(map.next_value()?);
                         }
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(CreateResponse {
                     id: id__.unwrap_or_default(),
+                    version: version__.unwrap_or_default(),
                 })
             }
         }
@@ -372,10 +472,23 @@ impl serde::Serialize for DeliveredRequest {
         if !self.id.is_empty() {
             len += 1;
         }
+        if self.expected_version.is_some() {
+            len += 1;
+        }
+        if self.idempotency_key.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("order.v1.DeliveredRequest", len)?;
         if !self.id.is_empty() {
             struct_ser.serialize_field("id", &self.id)?;
         }
+        if let Some(v) = self.expected_version.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("expectedVersion", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.idempotency_key.as_ref() {
+            struct_ser.serialize_field("idempotencyKey", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -387,11 +500,17 @@ impl<'de> serde::Deserialize<'de> for DeliveredRequest {
     {
         const FIELDS: &[&str] = &[
             "id",
+            "expected_version",
+            "expectedVersion",
+            "idempotency_key",
+            "idempotencyKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Id,
+            ExpectedVersion,
+            IdempotencyKey,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -414,6 +533,8 @@ impl<'de> serde::Deserialize<'de> for DeliveredRequest {
                     {
                         match value {
                             "id" => Ok(GeneratedField::Id),
+                            "expectedVersion" | "expected_version" => Ok(GeneratedField::ExpectedVersion),
+                            "idempotencyKey" | "idempotency_key" => Ok(GeneratedField::IdempotencyKey),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -434,6 +555,8 @@ impl<'de> serde::Deserialize<'de> for DeliveredRequest {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut id__ = None;
+                let mut expected_version__ = None;
+                let mut idempotency_key__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -442,10 +565,26 @@ impl<'de> serde::Deserialize<'de> for DeliveredRequest {
                             }
                             id__ = Some(map.next_value()?);
                         }
+                        GeneratedField::ExpectedVersion => {
+                            if expected_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expectedVersion"));
+                            }
+                            expected_version__ = 
+                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::IdempotencyKey => {
+                            if idempotency_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("idempotencyKey"));
+                            }
+                            idempotency_key__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(DeliveredRequest {
                     id: id__.unwrap_or_default(),
+                    expected_version: expected_version__,
+                    idempotency_key: idempotency_key__,
                 })
             }
         }
@@ -459,8 +598,15 @@ impl serde::Serialize for DeliveredResponse {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("order.v1.DeliveredResponse", len)?;
+        let mut len = 0;
+        if self.version != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("order.v1.DeliveredResponse", len)?;
+        if self.version != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("version", ToString::to_string(&self.version).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -471,10 +617,12 @@ impl<'de> serde::Deserialize<'de> for DeliveredResponse {
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "version",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            Version,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -495,7 +643,10 @@ impl<'de> serde::Deserialize<'de> for DeliveredResponse {
                     where
                         E: serde::de::Error,
                     {
-                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                        match value {
+                            "version" => Ok(GeneratedField::Version),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -513,10 +664,21 @@ impl<'de> serde::Deserialize<'de> for DeliveredResponse {
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                let mut version__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
                 }
                 Ok(DeliveredResponse {
+                    version: version__.unwrap_or_default(),
                 })
             }
         }
@@ -649,6 +811,9 @@ impl serde::Serialize for GetResponse {
         if self.status != 0 {
             len += 1;
         }
+        if self.version != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("order.v1.GetResponse", len)?;
         if !self.id.is_empty() {
             struct_ser.serialize_field("id", &self.id)?;
@@ -661,6 +826,10 @@ impl serde::Serialize for GetResponse {
                 .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.status)))?;
             struct_ser.serialize_field("status", &v)?;
         }
+        if self.version != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("version", ToString::to_string(&self.version).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -674,6 +843,7 @@ impl<'de> serde::Deserialize<'de> for GetResponse {
             "id",
             "items",
             "status",
+            "version",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -681,6 +851,7 @@ impl<'de> serde::Deserialize<'de> for GetResponse {
             Id,
             Items,
             Status,
+            Version,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -705,6 +876,7 @@ impl<'de> serde::Deserialize<'de> for GetResponse {
                             "id" => Ok(GeneratedField::Id),
                             "items" => Ok(GeneratedField::Items),
                             "status" => Ok(GeneratedField::Status),
+                            "version" => Ok(GeneratedField::Version),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -727,6 +899,7 @@ impl<'de> serde::Deserialize<'de> for GetResponse {
                 let mut id__ = None;
                 let mut items__ = None;
                 let mut status__ = None;
+                let mut version__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -747,12 +920,21 @@ impl<'de> serde::Deserialize<'de> for GetResponse {
                             }
                             status__ = Some(map.next_value::<get_response::OrderStatus>()? as i32);
                         }
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(GetResponse {
                     id: id__.unwrap_or_default(),
                     items: items__.unwrap_or_default(),
                     status: status__.unwrap_or_default(),
+                    version: version__.unwrap_or_default(),
                 })
             }
         }
@@ -980,8 +1162,20 @@ impl serde::Serialize for ListPreparedOrdersRequest {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("order.v1.ListPreparedOrdersRequest", len)?;
+        let mut len = 0;
+        if self.page_size != 0 {
+            len += 1;
+        }
+        if !self.page_token.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("order.v1.ListPreparedOrdersRequest", len)?;
+        if self.page_size != 0 {
+            struct_ser.serialize_field("pageSize", &self.page_size)?;
+        }
+        if !self.page_token.is_empty() {
+            struct_ser.serialize_field("pageToken", &self.page_token)?;
+        }
         struct_ser.end()
     }
 }
@@ -992,10 +1186,16 @@ impl<'de> serde::Deserialize<'de> for ListPreparedOrdersRequest {
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "page_size",
+            "pageSize",
+            "page_token",
+            "pageToken",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            PageSize,
+            PageToken,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1016,7 +1216,11 @@ impl<'de> serde::Deserialize<'de> for ListPreparedOrdersRequest {
                     where
                         E: serde::de::Error,
                     {
-                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                        match value {
+                            "pageSize" | "page_size" => Ok(GeneratedField::PageSize),
+                            "pageToken" | "page_token" => Ok(GeneratedField::PageToken),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -1034,10 +1238,29 @@ impl<'de> serde::Deserialize<'de> for ListPreparedOrdersRequest {
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                let mut page_size__ = None;
+                let mut page_token__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::PageSize => {
+                            if page_size__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pageSize"));
+                            }
+                            page_size__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PageToken => {
+                            if page_token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pageToken"));
+                            }
+                            page_token__ = Some(map.next_value()?);
+                        }
+                    }
                 }
                 Ok(ListPreparedOrdersRequest {
+                    page_size: page_size__.unwrap_or_default(),
+                    page_token: page_token__.unwrap_or_default(),
                 })
             }
         }
@@ -1055,10 +1278,16 @@ impl serde::Serialize for ListPreparedOrdersResponse {
         if !self.ids.is_empty() {
             len += 1;
         }
+        if !self.next_page_token.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("order.v1.ListPreparedOrdersResponse", len)?;
         if !self.ids.is_empty() {
             struct_ser.serialize_field("ids", &self.ids)?;
         }
+        if !self.next_page_token.is_empty() {
+            struct_ser.serialize_field("nextPageToken", &self.next_page_token)?;
+        }
         struct_ser.end()
     }
 }
@@ -1070,11 +1299,14 @@ impl<'de> serde::Deserialize<'de> for ListPreparedOrdersResponse {
     {
         const FIELDS: &[&str] = &[
             "ids",
+            "next_page_token",
+            "nextPageToken",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Ids,
+            NextPageToken,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1097,6 +1329,7 @@ impl<'de> serde::Deserialize<'de> for ListPreparedOrdersResponse {
                     {
                         match value {
                             "ids" => Ok(GeneratedField::Ids),
+                            "nextPageToken" | "next_page_token" => Ok(GeneratedField::NextPageToken),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1117,6 +1350,7 @@ impl<'de> serde::Deserialize<'de> for ListPreparedOrdersResponse {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut ids__ = None;
+                let mut next_page_token__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Ids => {
@@ -1125,10 +1359,17 @@ impl<'de> serde::Deserialize<'de> for ListPreparedOrdersResponse {
                             }
                             ids__ = Some(map.next_value()?);
                         }
+                        GeneratedField::NextPageToken => {
+                            if next_page_token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextPageToken"));
+                            }
+                            next_page_token__ = Some(map.next_value()?);
+                        }
                     }
                 }
                 Ok(ListPreparedOrdersResponse {
                     ids: ids__.unwrap_or_default(),
+                    next_page_token: next_page_token__.unwrap_or_default(),
                 })
             }
         }
@@ -1146,10 +1387,22 @@ impl serde::Serialize for ListTenantReceivedOrdersRequest {
         if !self.tenant_id.is_empty() {
             len += 1;
         }
+        if self.page_size != 0 {
+            len += 1;
+        }
+        if !self.page_token.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("order.v1.ListTenantReceivedOrdersRequest", len)?;
         if !self.tenant_id.is_empty() {
             struct_ser.serialize_field("tenantId", &self.tenant_id)?;
         }
+        if self.page_size != 0 {
+            struct_ser.serialize_field("pageSize", &self.page_size)?;
+        }
+        if !self.page_token.is_empty() {
+            struct_ser.serialize_field("pageToken", &self.page_token)?;
+        }
         struct_ser.end()
     }
 }
@@ -1162,11 +1415,17 @@ impl<'de> serde::Deserialize<'de> for ListTenantReceivedOrdersRequest {
         const FIELDS: &[&str] = &[
             "tenant_id",
             "tenantId",
+            "page_size",
+            "pageSize",
+            "page_token",
+            "pageToken",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             TenantId,
+            PageSize,
+            PageToken,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1189,6 +1448,8 @@ impl<'de> serde::Deserialize<'de> for ListTenantReceivedOrdersRequest {
                     {
                         match value {
                             "tenantId" | "tenant_id" => Ok(GeneratedField::TenantId),
+                            "pageSize" | "page_size" => Ok(GeneratedField::PageSize),
+                            "pageToken" | "page_token" => Ok(GeneratedField::PageToken),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1209,6 +1470,8 @@ impl<'de> serde::Deserialize<'de> for ListTenantReceivedOrdersRequest {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut tenant_id__ = None;
+                let mut page_size__ = None;
+                let mut page_token__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::TenantId => {
@@ -1217,10 +1480,26 @@ impl<'de> serde::Deserialize<'de> for ListTenantReceivedOrdersRequest {
                             }
                             tenant_id__ = Some(map.next_value()?);
                         }
+                        GeneratedField::PageSize => {
+                            if page_size__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pageSize"));
+                            }
+                            page_size__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PageToken => {
+                            if page_token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pageToken"));
+                            }
+                            page_token__ = Some(map.next_value()?);
+                        }
                     }
                 }
                 Ok(ListTenantReceivedOrdersRequest {
                     tenant_id: tenant_id__.unwrap_or_default(),
+                    page_size: page_size__.unwrap_or_default(),
+                    page_token: page_token__.unwrap_or_default(),
                 })
             }
         }
@@ -1238,10 +1517,16 @@ impl serde::Serialize for ListTenantReceivedOrdersResponse {
         if !self.ids.is_empty() {
             len += 1;
         }
+        if !self.next_page_token.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("order.v1.ListTenantReceivedOrdersResponse", len)?;
         if !self.ids.is_empty() {
             struct_ser.serialize_field("ids", &self.ids)?;
         }
+        if !self.next_page_token.is_empty() {
+            struct_ser.serialize_field("nextPageToken", &self.next_page_token)?;
+        }
         struct_ser.end()
     }
 }
@@ -1253,11 +1538,14 @@ impl<'de> serde::Deserialize<'de> for ListTenantReceivedOrdersResponse {
     {
         const FIELDS: &[&str] = &[
             "ids",
+            "next_page_token",
+            "nextPageToken",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Ids,
+            NextPageToken,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1280,6 +1568,7 @@ impl<'de> serde::Deserialize<'de> for ListTenantReceivedOrdersResponse {
                     {
                         match value {
                             "ids" => Ok(GeneratedField::Ids),
+                            "nextPageToken" | "next_page_token" => Ok(GeneratedField::NextPageToken),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1300,6 +1589,7 @@ impl<'de> serde::Deserialize<'de> for ListTenantReceivedOrdersResponse {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut ids__ = None;
+                let mut next_page_token__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Ids => {
@@ -1308,10 +1598,17 @@ impl<'de> serde::Deserialize<'de> for ListTenantReceivedOrdersResponse {
                             }
                             ids__ = Some(map.next_value()?);
                         }
+                        GeneratedField::NextPageToken => {
+                            if next_page_token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextPageToken"));
+                            }
+                            next_page_token__ = Some(map.next_value()?);
+                        }
                     }
                 }
                 Ok(ListTenantReceivedOrdersResponse {
                     ids: ids__.unwrap_or_default(),
+                    next_page_token: next_page_token__.unwrap_or_default(),
                 })
             }
         }
@@ -1329,10 +1626,23 @@ impl serde::Serialize for PickedUpRequest {
         if !self.id.is_empty() {
             len += 1;
         }
+        if self.expected_version.is_some() {
+            len += 1;
+        }
+        if self.idempotency_key.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("order.v1.PickedUpRequest", len)?;
         if !self.id.is_empty() {
             struct_ser.serialize_field("id", &self.id)?;
         }
+        if let Some(v) = self.expected_version.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("expectedVersion", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.idempotency_key.as_ref() {
+            struct_ser.serialize_field("idempotencyKey", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1344,11 +1654,17 @@ impl<'de> serde::Deserialize<'de> for PickedUpRequest {
     {
         const FIELDS: &[&str] = &[
             "id",
+            "expected_version",
+            "expectedVersion",
+            "idempotency_key",
+            "idempotencyKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Id,
+            ExpectedVersion,
+            IdempotencyKey,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1371,6 +1687,8 @@ impl<'de> serde::Deserialize<'de> for PickedUpRequest {
                     {
                         match value {
                             "id" => Ok(GeneratedField::Id),
+                            "expectedVersion" | "expected_version" => Ok(GeneratedField::ExpectedVersion),
+                            "idempotencyKey" | "idempotency_key" => Ok(GeneratedField::IdempotencyKey),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1391,6 +1709,8 @@ impl<'de> serde::Deserialize<'de> for PickedUpRequest {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut id__ = None;
+                let mut expected_version__ = None;
+                let mut idempotency_key__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -1399,10 +1719,26 @@ impl<'de> serde::Deserialize<'de> for PickedUpRequest {
                             }
                             id__ = Some(map.next_value()?);
                         }
+                        GeneratedField::ExpectedVersion => {
+                            if expected_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expectedVersion"));
+                            }
+                            expected_version__ = 
+                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::IdempotencyKey => {
+                            if idempotency_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("idempotencyKey"));
+                            }
+                            idempotency_key__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(PickedUpRequest {
                     id: id__.unwrap_or_default(),
+                    expected_version: expected_version__,
+                    idempotency_key: idempotency_key__,
                 })
             }
         }
@@ -1416,8 +1752,15 @@ impl serde::Serialize for PickedUpResponse {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("order.v1.PickedUpResponse", len)?;
+        let mut len = 0;
+        if self.version != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("order.v1.PickedUpResponse", len)?;
+        if self.version != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("version", ToString::to_string(&self.version).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -1428,10 +1771,12 @@ impl<'de> serde::Deserialize<'de> for PickedUpResponse {
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "version",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            Version,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1452,7 +1797,10 @@ impl<'de> serde::Deserialize<'de> for PickedUpResponse {
                     where
                         E: serde::de::Error,
                     {
-                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                        match value {
+                            "version" => Ok(GeneratedField::Version),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -1470,10 +1818,21 @@ impl<'de> serde::Deserialize<'de> for PickedUpResponse {
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                let mut version__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
                 }
                 Ok(PickedUpResponse {
+                    version: version__.unwrap_or_default(),
                 })
             }
         }
@@ -1491,10 +1850,23 @@ impl serde::Serialize for PreparedRequest {
         if !self.id.is_empty() {
             len += 1;
         }
+        if self.expected_version.is_some() {
+            len += 1;
+        }
+        if self.idempotency_key.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("order.v1.PreparedRequest", len)?;
         if !self.id.is_empty() {
             struct_ser.serialize_field("id", &self.id)?;
         }
+        if let Some(v) = self.expected_version.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("expectedVersion", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.idempotency_key.as_ref() {
+            struct_ser.serialize_field("idempotencyKey", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1506,11 +1878,17 @@ impl<'de> serde::Deserialize<'de> for PreparedRequest {
     {
         const FIELDS: &[&str] = &[
             "id",
+            "expected_version",
+            "expectedVersion",
+            "idempotency_key",
+            "idempotencyKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Id,
+            ExpectedVersion,
+            IdempotencyKey,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1533,6 +1911,8 @@ impl<'de> serde::Deserialize<'de> for PreparedRequest {
                     {
                         match value {
                             "id" => Ok(GeneratedField::Id),
+                            "expectedVersion" | "expected_version" => Ok(GeneratedField::ExpectedVersion),
+                            "idempotencyKey" | "idempotency_key" => Ok(GeneratedField::IdempotencyKey),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1553,6 +1933,8 @@ impl<'de> serde::Deserialize<'de> for PreparedRequest {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut id__ = None;
+                let mut expected_version__ = None;
+                let mut idempotency_key__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -1561,10 +1943,26 @@ impl<'de> serde::Deserialize<'de> for PreparedRequest {
                             }
                             id__ = Some(map.next_value()?);
                         }
+                        GeneratedField::ExpectedVersion => {
+                            if expected_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expectedVersion"));
+                            }
+                            expected_version__ = 
+                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::IdempotencyKey => {
+                            if idempotency_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("idempotencyKey"));
+                            }
+                            idempotency_key__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(PreparedRequest {
                     id: id__.unwrap_or_default(),
+                    expected_version: expected_version__,
+                    idempotency_key: idempotency_key__,
                 })
             }
         }
@@ -1578,8 +1976,15 @@ impl serde::Serialize for PreparedResponse {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("order.v1.PreparedResponse", len)?;
+        let mut len = 0;
+        if self.version != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("order.v1.PreparedResponse", len)?;
+        if self.version != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("version", ToString::to_string(&self.version).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -1590,10 +1995,12 @@ impl<'de> serde::Deserialize<'de> for PreparedResponse {
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "version",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            Version,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1614,7 +2021,10 @@ impl<'de> serde::Deserialize<'de> for PreparedResponse {
                     where
                         E: serde::de::Error,
                     {
-                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                        match value {
+                            "version" => Ok(GeneratedField::Version),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -1632,10 +2042,21 @@ impl<'de> serde::Deserialize<'de> for PreparedResponse {
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                let mut version__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
                 }
                 Ok(PreparedResponse {
+                    version: version__.unwrap_or_default(),
                 })
             }
         }
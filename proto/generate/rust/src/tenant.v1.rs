@@ -4,22 +4,37 @@
 pub struct CreateRequest {
     #[prost(string, tag="1")]
     pub name: ::prost::alloc::string::String,
+    /// 同じ冪等キーのリクエストを再実行した場合は最初のリクエストの結果を返す
+    #[prost(string, optional, tag="2")]
+    pub idempotency_key: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateResponse {
     #[prost(string, tag="1")]
     pub id: ::prost::alloc::string::String,
+    /// コマンド実行後の集約のバージョン
+    #[prost(uint64, tag="2")]
+    pub version: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListTenantsRequest {
+    /// 1ページに含める最大件数。0の場合はサーバーのデフォルト値を使う
+    #[prost(uint32, tag="1")]
+    pub page_size: u32,
+    /// 前のページのレスポンスの next_page_token。最初のページを取得する場合は指定しない
+    #[prost(string, tag="2")]
+    pub page_token: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListTenantsResponse {
     #[prost(message, repeated, tag="1")]
     pub tenants: ::prost::alloc::vec::Vec<list_tenants_response::Tenant>,
+    /// 次のページを取得するトークン。次のページがない場合は空文字列
+    #[prost(string, tag="2")]
+    pub next_page_token: ::prost::alloc::string::String,
 }
 /// Nested message and enum types in `ListTenantsResponse`.
 pub mod list_tenants_response {
@@ -30,6 +45,9 @@ pub mod list_tenants_response {
         pub id: ::prost::alloc::string::String,
         #[prost(string, tag="2")]
         pub name: ::prost::alloc::string::String,
+        /// 集約のバージョン
+        #[prost(uint64, tag="3")]
+        pub version: u64,
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -39,6 +57,12 @@ pub struct AddItemsRequest {
     pub tenant_id: ::prost::alloc::string::String,
     #[prost(message, repeated, tag="2")]
     pub items: ::prost::alloc::vec::Vec<add_items_request::Item>,
+    /// 集約が指定したバージョンの場合のみコマンドを実行する
+    #[prost(uint64, optional, tag="3")]
+    pub expected_version: ::core::option::Option<u64>,
+    /// 同じ冪等キーのリクエストを再実行した場合は最初のリクエストの結果を返す
+    #[prost(string, optional, tag="4")]
+    pub idempotency_key: ::core::option::Option<::prost::alloc::string::String>,
 }
 /// Nested message and enum types in `AddItemsRequest`.
 pub mod add_items_request {
@@ -59,6 +83,9 @@ pub mod add_items_request {
 pub struct AddItemsResponse {
     #[prost(string, repeated, tag="1")]
     pub ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// コマンド実行後の集約のバージョン
+    #[prost(uint64, tag="2")]
+    pub version: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -67,22 +94,91 @@ pub struct RemoveItemsRequest {
     pub tenant_id: ::prost::alloc::string::String,
     #[prost(string, repeated, tag="2")]
     pub item_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// 集約が指定したバージョンの場合のみコマンドを実行する
+    #[prost(uint64, optional, tag="3")]
+    pub expected_version: ::core::option::Option<u64>,
+    /// 同じ冪等キーのリクエストを再実行した場合は最初のリクエストの結果を返す
+    #[prost(string, optional, tag="4")]
+    pub idempotency_key: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RemoveItemsResponse {
+    /// コマンド実行後の集約のバージョン
+    #[prost(uint64, tag="1")]
+    pub version: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListItemsRequest {
     #[prost(string, tag="1")]
     pub tenant_id: ::prost::alloc::string::String,
+    /// 1ページに含める最大件数。0の場合はサーバーのデフォルト値を使う
+    #[prost(uint32, tag="2")]
+    pub page_size: u32,
+    /// 前のページのレスポンスの next_page_token。最初のページを取得する場合は指定しない
+    #[prost(string, tag="3")]
+    pub page_token: ::prost::alloc::string::String,
+    /// 値段がこの値以上の商品に絞り込む
+    #[prost(uint32, optional, tag="4")]
+    pub min_price: ::core::option::Option<u32>,
+    /// 値段がこの値以下の商品に絞り込む
+    #[prost(uint32, optional, tag="5")]
+    pub max_price: ::core::option::Option<u32>,
+    /// 商品名にこの文字列を含む商品に絞り込む。大文字と小文字は区別しない
+    #[prost(string, optional, tag="6")]
+    pub name_contains: ::core::option::Option<::prost::alloc::string::String>,
+    /// 商品を並べる順序。同じ値の商品は商品IDの順に並べる
+    #[prost(enumeration="list_items_request::SortKey", tag="7")]
+    pub sort_key: i32,
+    /// 降順に並べる
+    #[prost(bool, tag="8")]
+    pub descending: bool,
+}
+/// Nested message and enum types in `ListItemsRequest`.
+pub mod list_items_request {
+    /// 商品を並べる順序のキー
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum SortKey {
+        /// 商品ID
+        Unspecified = 0,
+        /// 値段
+        Price = 1,
+        /// 商品名
+        Name = 2,
+    }
+    impl SortKey {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                SortKey::Unspecified => "SORT_KEY_UNSPECIFIED",
+                SortKey::Price => "SORT_KEY_PRICE",
+                SortKey::Name => "SORT_KEY_NAME",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "SORT_KEY_UNSPECIFIED" => Some(Self::Unspecified),
+                "SORT_KEY_PRICE" => Some(Self::Price),
+                "SORT_KEY_NAME" => Some(Self::Name),
+                _ => None,
+            }
+        }
+    }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListItemsResponse {
     #[prost(message, repeated, tag="1")]
     pub items: ::prost::alloc::vec::Vec<list_items_response::Item>,
+    /// 次のページを取得するトークン。次のページがない場合は空文字列
+    #[prost(string, tag="2")]
+    pub next_page_token: ::prost::alloc::string::String,
 }
 /// Nested message and enum types in `ListItemsResponse`.
 pub mod list_items_response {
@@ -97,200 +193,637 @@ pub mod list_items_response {
         pub price: u32,
     }
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchRequest {
+    /// 検索する文字列。空白で区切った全ての語をテナント名か商品名に含むものを返す
+    #[prost(string, tag="1")]
+    pub query: ::prost::alloc::string::String,
+    /// 1ページに含める最大件数。0の場合はサーバーのデフォルト値を使う
+    #[prost(uint32, tag="2")]
+    pub page_size: u32,
+    /// 前のページのレスポンスの next_page_token。最初のページを取得する場合は指定しない
+    #[prost(string, tag="3")]
+    pub page_token: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchResponse {
+    /// 関連度の高い順に並べた検索結果
+    #[prost(message, repeated, tag="1")]
+    pub hits: ::prost::alloc::vec::Vec<search_response::Hit>,
+    /// 次のページを取得するトークン。次のページがない場合は空文字列
+    #[prost(string, tag="2")]
+    pub next_page_token: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `SearchResponse`.
+pub mod search_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Item {
+        #[prost(string, tag="1")]
+        pub id: ::prost::alloc::string::String,
+        #[prost(string, tag="2")]
+        pub name: ::prost::alloc::string::String,
+        #[prost(uint32, tag="3")]
+        pub price: u32,
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Hit {
+        #[prost(string, tag="1")]
+        pub tenant_id: ::prost::alloc::string::String,
+        #[prost(string, tag="2")]
+        pub tenant_name: ::prost::alloc::string::String,
+        /// 商品名に一致した場合は商品。テナント名に一致した場合は設定しない
+        #[prost(message, optional, tag="3")]
+        pub item: ::core::option::Option<Item>,
+        /// 検索語との関連度。大きいほど関連度が高い
+        #[prost(float, tag="4")]
+        pub score: f32,
+    }
+}
 /// Encoded file descriptor set for the `tenant.v1` package
 pub const FILE_DESCRIPTOR_SET: &[u8] = &[
-    0x0a, 0xf4, 0x17, 0x0a, 0x1e, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x2f, 0x76, 0x31, 0x2f, 0x74,
+    0x0a, 0xab, 0x48, 0x0a, 0x1e, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x2f, 0x76, 0x31, 0x2f, 0x74,
     0x65, 0x6e, 0x61, 0x6e, 0x74, 0x5f, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x2e, 0x70, 0x72,
-    0x6f, 0x74, 0x6f, 0x12, 0x09, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x22, 0x23,
+    0x6f, 0x74, 0x6f, 0x12, 0x09, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x22, 0x65,
     0x0a, 0x0d, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
     0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e,
-    0x61, 0x6d, 0x65, 0x22, 0x20, 0x0a, 0x0e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52, 0x65, 0x73,
-    0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28,
-    0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x14, 0x0a, 0x12, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x65, 0x6e,
-    0x61, 0x6e, 0x74, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0x84, 0x01, 0x0a, 0x13,
-    0x4c, 0x69, 0x73, 0x74, 0x54, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f,
-    0x6e, 0x73, 0x65, 0x12, 0x3f, 0x0a, 0x07, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x73, 0x18, 0x01,
-    0x20, 0x03, 0x28, 0x0b, 0x32, 0x25, 0x2e, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x2e, 0x76, 0x31,
-    0x2e, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x73, 0x52, 0x65, 0x73, 0x70,
-    0x6f, 0x6e, 0x73, 0x65, 0x2e, 0x54, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x52, 0x07, 0x74, 0x65, 0x6e,
-    0x61, 0x6e, 0x74, 0x73, 0x1a, 0x2c, 0x0a, 0x06, 0x54, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x12, 0x0e,
+    0x61, 0x6d, 0x65, 0x12, 0x2c, 0x0a, 0x0f, 0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e,
+    0x63, 0x79, 0x5f, 0x6b, 0x65, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x0e,
+    0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x4b, 0x65, 0x79, 0x88, 0x01,
+    0x01, 0x42, 0x12, 0x0a, 0x10, 0x5f, 0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x63,
+    0x79, 0x5f, 0x6b, 0x65, 0x79, 0x22, 0x3a, 0x0a, 0x0e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52,
+    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x12, 0x18, 0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69,
+    0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f,
+    0x6e, 0x22, 0x50, 0x0a, 0x12, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x73,
+    0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1b, 0x0a, 0x09, 0x70, 0x61, 0x67, 0x65, 0x5f,
+    0x73, 0x69, 0x7a, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x08, 0x70, 0x61, 0x67, 0x65,
+    0x53, 0x69, 0x7a, 0x65, 0x12, 0x1d, 0x0a, 0x0a, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x70, 0x61, 0x67, 0x65, 0x54, 0x6f,
+    0x6b, 0x65, 0x6e, 0x22, 0xc6, 0x01, 0x0a, 0x13, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x65, 0x6e, 0x61,
+    0x6e, 0x74, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3f, 0x0a, 0x07, 0x74,
+    0x65, 0x6e, 0x61, 0x6e, 0x74, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x25, 0x2e, 0x74,
+    0x65, 0x6e, 0x61, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x65, 0x6e,
+    0x61, 0x6e, 0x74, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x2e, 0x54, 0x65, 0x6e,
+    0x61, 0x6e, 0x74, 0x52, 0x07, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x73, 0x12, 0x26, 0x0a, 0x0f,
+    0x6e, 0x65, 0x78, 0x74, 0x5f, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18,
+    0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0d, 0x6e, 0x65, 0x78, 0x74, 0x50, 0x61, 0x67, 0x65, 0x54,
+    0x6f, 0x6b, 0x65, 0x6e, 0x1a, 0x46, 0x0a, 0x06, 0x54, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x12, 0x0e,
     0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x12, 0x12,
     0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61,
-    0x6d, 0x65, 0x22, 0x97, 0x01, 0x0a, 0x0f, 0x41, 0x64, 0x64, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x52,
-    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1b, 0x0a, 0x09, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74,
-    0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x74, 0x65, 0x6e, 0x61, 0x6e,
-    0x74, 0x49, 0x64, 0x12, 0x35, 0x0a, 0x05, 0x69, 0x74, 0x65, 0x6d, 0x73, 0x18, 0x02, 0x20, 0x03,
-    0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x41,
-    0x64, 0x64, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x2e, 0x49,
-    0x74, 0x65, 0x6d, 0x52, 0x05, 0x69, 0x74, 0x65, 0x6d, 0x73, 0x1a, 0x30, 0x0a, 0x04, 0x49, 0x74,
-    0x65, 0x6d, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09,
-    0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x70, 0x72, 0x69, 0x63, 0x65, 0x18,
-    0x02, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x05, 0x70, 0x72, 0x69, 0x63, 0x65, 0x22, 0x24, 0x0a, 0x10,
-    0x41, 0x64, 0x64, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
-    0x12, 0x10, 0x0a, 0x03, 0x69, 0x64, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x09, 0x52, 0x03, 0x69,
-    0x64, 0x73, 0x22, 0x4c, 0x0a, 0x12, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x49, 0x74, 0x65, 0x6d,
-    0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1b, 0x0a, 0x09, 0x74, 0x65, 0x6e, 0x61,
-    0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x74, 0x65, 0x6e,
-    0x61, 0x6e, 0x74, 0x49, 0x64, 0x12, 0x19, 0x0a, 0x08, 0x69, 0x74, 0x65, 0x6d, 0x5f, 0x69, 0x64,
-    0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x09, 0x52, 0x07, 0x69, 0x74, 0x65, 0x6d, 0x49, 0x64, 0x73,
-    0x22, 0x15, 0x0a, 0x13, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x52,
-    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x2f, 0x0a, 0x10, 0x4c, 0x69, 0x73, 0x74, 0x49,
-    0x74, 0x65, 0x6d, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1b, 0x0a, 0x09, 0x74,
-    0x65, 0x6e, 0x61, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08,
-    0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x49, 0x64, 0x22, 0x8e, 0x01, 0x0a, 0x11, 0x4c, 0x69, 0x73,
-    0x74, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x37,
-    0x0a, 0x05, 0x69, 0x74, 0x65, 0x6d, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x21, 0x2e,
-    0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x49, 0x74,
-    0x65, 0x6d, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x2e, 0x49, 0x74, 0x65, 0x6d,
-    0x52, 0x05, 0x69, 0x74, 0x65, 0x6d, 0x73, 0x1a, 0x40, 0x0a, 0x04, 0x49, 0x74, 0x65, 0x6d, 0x12,
-    0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x12,
-    0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e,
-    0x61, 0x6d, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x70, 0x72, 0x69, 0x63, 0x65, 0x18, 0x03, 0x20, 0x01,
-    0x28, 0x0d, 0x52, 0x05, 0x70, 0x72, 0x69, 0x63, 0x65, 0x32, 0xf7, 0x02, 0x0a, 0x0d, 0x54, 0x65,
-    0x6e, 0x61, 0x6e, 0x74, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x3d, 0x0a, 0x06, 0x43,
-    0x72, 0x65, 0x61, 0x74, 0x65, 0x12, 0x18, 0x2e, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x2e, 0x76,
-    0x31, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a,
-    0x19, 0x2e, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x72, 0x65, 0x61,
-    0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4c, 0x0a, 0x0b, 0x4c, 0x69,
-    0x73, 0x74, 0x54, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x73, 0x12, 0x1d, 0x2e, 0x74, 0x65, 0x6e, 0x61,
-    0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x65, 0x6e, 0x61, 0x6e, 0x74,
-    0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1e, 0x2e, 0x74, 0x65, 0x6e, 0x61, 0x6e,
-    0x74, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x73,
-    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x43, 0x0a, 0x08, 0x41, 0x64, 0x64, 0x49,
-    0x74, 0x65, 0x6d, 0x73, 0x12, 0x1a, 0x2e, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x2e, 0x76, 0x31,
-    0x2e, 0x41, 0x64, 0x64, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
-    0x1a, 0x1b, 0x2e, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x64, 0x64,
-    0x49, 0x74, 0x65, 0x6d, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4c, 0x0a,
-    0x0b, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x12, 0x1d, 0x2e, 0x74,
-    0x65, 0x6e, 0x61, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x49,
-    0x74, 0x65, 0x6d, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1e, 0x2e, 0x74, 0x65,
-    0x6e, 0x61, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x49, 0x74,
-    0x65, 0x6d, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x46, 0x0a, 0x09, 0x4c,
-    0x69, 0x73, 0x74, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x12, 0x1b, 0x2e, 0x74, 0x65, 0x6e, 0x61, 0x6e,
-    0x74, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x52, 0x65,
-    0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1c, 0x2e, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x2e, 0x76,
-    0x31, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f,
-    0x6e, 0x73, 0x65, 0x4a, 0xf9, 0x0e, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x48, 0x01, 0x0a, 0x08,
-    0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02,
-    0x00, 0x12, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x04, 0x00, 0x06, 0x01, 0x0a, 0x0a,
-    0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x04, 0x08, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00,
-    0x02, 0x00, 0x12, 0x03, 0x05, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05,
-    0x12, 0x03, 0x05, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x05, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x05, 0x10,
-    0x11, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x08, 0x00, 0x0a, 0x01, 0x0a, 0x0a, 0x0a,
-    0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x08, 0x08, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02,
-    0x00, 0x12, 0x03, 0x09, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12,
-    0x03, 0x09, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x09,
-    0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x09, 0x0e, 0x0f,
-    0x0a, 0x09, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x03, 0x0c, 0x00, 0x1d, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
-    0x02, 0x01, 0x12, 0x03, 0x0c, 0x08, 0x1a, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x0e,
-    0x00, 0x15, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x0e, 0x08, 0x1b, 0x0a,
-    0x0c, 0x0a, 0x04, 0x04, 0x03, 0x03, 0x00, 0x12, 0x04, 0x0f, 0x02, 0x12, 0x03, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x03, 0x03, 0x00, 0x01, 0x12, 0x03, 0x0f, 0x0a, 0x10, 0x0a, 0x0d, 0x0a, 0x06, 0x04,
-    0x03, 0x03, 0x00, 0x02, 0x00, 0x12, 0x03, 0x10, 0x04, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03,
-    0x03, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x10, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03,
-    0x03, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x10, 0x0b, 0x0d, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03,
-    0x03, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x10, 0x10, 0x11, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x03,
-    0x03, 0x00, 0x02, 0x01, 0x12, 0x03, 0x11, 0x04, 0x14, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03, 0x03,
-    0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x11, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03, 0x03,
-    0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x11, 0x0b, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03, 0x03,
-    0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x11, 0x12, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02,
-    0x00, 0x12, 0x03, 0x14, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12,
-    0x03, 0x14, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x06, 0x12, 0x03, 0x14,
-    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x14, 0x12, 0x19,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x14, 0x1c, 0x1d, 0x0a, 0x0a,
-    0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x17, 0x00, 0x22, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04,
-    0x01, 0x12, 0x03, 0x17, 0x08, 0x17, 0x0a, 0x16, 0x0a, 0x04, 0x04, 0x04, 0x03, 0x00, 0x12, 0x04,
-    0x19, 0x02, 0x1e, 0x03, 0x1a, 0x08, 0x20, 0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x04, 0x03, 0x00, 0x01, 0x12, 0x03, 0x19, 0x0a, 0x0e, 0x0a, 0x1a, 0x0a, 0x06,
-    0x04, 0x04, 0x03, 0x00, 0x02, 0x00, 0x12, 0x03, 0x1b, 0x04, 0x14, 0x1a, 0x0b, 0x20, 0xe5, 0x95,
-    0x86, 0xe5, 0x93, 0x81, 0xe5, 0x90, 0x8d, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x03, 0x00,
-    0x02, 0x00, 0x05, 0x12, 0x03, 0x1b, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x03, 0x00,
-    0x02, 0x00, 0x01, 0x12, 0x03, 0x1b, 0x0b, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x03, 0x00,
-    0x02, 0x00, 0x03, 0x12, 0x03, 0x1b, 0x12, 0x13, 0x0a, 0x17, 0x0a, 0x06, 0x04, 0x04, 0x03, 0x00,
-    0x02, 0x01, 0x12, 0x03, 0x1d, 0x04, 0x15, 0x1a, 0x08, 0x20, 0xe5, 0x80, 0xa4, 0xe6, 0xae, 0xb5,
-    0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x03, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x1d, 0x04,
-    0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x03, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1d, 0x0b,
-    0x10, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x03, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x1d, 0x13,
-    0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x20, 0x02, 0x17, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x05, 0x12, 0x03, 0x20, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x20, 0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
-    0x02, 0x00, 0x03, 0x12, 0x03, 0x20, 0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01,
-    0x12, 0x03, 0x21, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x04, 0x12, 0x03,
-    0x21, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x06, 0x12, 0x03, 0x21, 0x0b,
-    0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x21, 0x10, 0x15, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x21, 0x18, 0x19, 0x0a, 0x0a, 0x0a,
-    0x02, 0x04, 0x05, 0x12, 0x04, 0x24, 0x00, 0x26, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01,
-    0x12, 0x03, 0x24, 0x08, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x25,
-    0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x04, 0x12, 0x03, 0x25, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x25, 0x0b, 0x11, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x25, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x25, 0x18, 0x19, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x06,
-    0x12, 0x04, 0x28, 0x00, 0x2b, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x28,
-    0x08, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x29, 0x02, 0x17, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x05, 0x12, 0x03, 0x29, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03, 0x29, 0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x29, 0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02,
-    0x01, 0x12, 0x03, 0x2a, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x04, 0x12,
-    0x03, 0x2a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x05, 0x12, 0x03, 0x2a,
-    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x2a, 0x12, 0x1a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x03, 0x12, 0x03, 0x2a, 0x1d, 0x1e, 0x0a, 0x09,
-    0x0a, 0x02, 0x04, 0x07, 0x12, 0x03, 0x2d, 0x00, 0x1e, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01,
-    0x12, 0x03, 0x2d, 0x08, 0x1b, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x08, 0x12, 0x04, 0x2f, 0x00, 0x31,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x03, 0x2f, 0x08, 0x18, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x30, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
-    0x02, 0x00, 0x05, 0x12, 0x03, 0x30, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x30, 0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x30, 0x15, 0x16, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x04, 0x33, 0x00, 0x3b, 0x01,
-    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x33, 0x08, 0x19, 0x0a, 0x0c, 0x0a, 0x04,
-    0x04, 0x09, 0x03, 0x00, 0x12, 0x04, 0x34, 0x02, 0x38, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09,
-    0x03, 0x00, 0x01, 0x12, 0x03, 0x34, 0x0a, 0x0e, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x09, 0x03, 0x00,
-    0x02, 0x00, 0x12, 0x03, 0x35, 0x04, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x03, 0x00, 0x02,
-    0x00, 0x05, 0x12, 0x03, 0x35, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x03, 0x00, 0x02,
-    0x00, 0x01, 0x12, 0x03, 0x35, 0x0b, 0x0d, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x03, 0x00, 0x02,
-    0x00, 0x03, 0x12, 0x03, 0x35, 0x10, 0x11, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x09, 0x03, 0x00, 0x02,
-    0x01, 0x12, 0x03, 0x36, 0x04, 0x14, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x03, 0x00, 0x02, 0x01,
-    0x05, 0x12, 0x03, 0x36, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x03, 0x00, 0x02, 0x01,
-    0x01, 0x12, 0x03, 0x36, 0x0b, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x03, 0x00, 0x02, 0x01,
-    0x03, 0x12, 0x03, 0x36, 0x12, 0x13, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x09, 0x03, 0x00, 0x02, 0x02,
-    0x12, 0x03, 0x37, 0x04, 0x15, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x03, 0x00, 0x02, 0x02, 0x05,
-    0x12, 0x03, 0x37, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x03, 0x00, 0x02, 0x02, 0x01,
-    0x12, 0x03, 0x37, 0x0b, 0x10, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x03, 0x00, 0x02, 0x02, 0x03,
-    0x12, 0x03, 0x37, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x03, 0x3a,
-    0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x04, 0x12, 0x03, 0x3a, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x06, 0x12, 0x03, 0x3a, 0x0b, 0x0f, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3a, 0x10, 0x15, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x09, 0x02, 0x00, 0x03, 0x12, 0x03, 0x3a, 0x18, 0x19, 0x0a, 0x0a, 0x0a, 0x02, 0x06, 0x00,
-    0x12, 0x04, 0x3d, 0x00, 0x48, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x06, 0x00, 0x01, 0x12, 0x03, 0x3d,
-    0x08, 0x15, 0x0a, 0x2a, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x00, 0x12, 0x03, 0x3f, 0x02, 0x35, 0x1a,
-    0x1d, 0x20, 0xe3, 0x83, 0x86, 0xe3, 0x83, 0x8a, 0xe3, 0x83, 0xb3, 0xe3, 0x83, 0x88, 0xe3, 0x82,
-    0x92, 0xe4, 0xbd, 0x9c, 0xe6, 0x88, 0x90, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3f, 0x06, 0x0c, 0x0a, 0x0c, 0x0a, 0x05,
-    0x06, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x3f, 0x0d, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00,
-    0x02, 0x00, 0x03, 0x12, 0x03, 0x3f, 0x25, 0x33, 0x0a, 0x33, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x01,
-    0x12, 0x03, 0x41, 0x02, 0x44, 0x1a, 0x26, 0x20, 0xe3, 0x83, 0x86, 0xe3, 0x83, 0x8a, 0xe3, 0x83,
-    0xb3, 0xe3, 0x83, 0x88, 0xe3, 0x81, 0xae, 0xe4, 0xb8, 0x80, 0xe8, 0xa6, 0xa7, 0xe3, 0x82, 0x92,
-    0xe5, 0x8f, 0x96, 0xe5, 0xbe, 0x97, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x06, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x41, 0x06, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x06,
-    0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x41, 0x12, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02,
-    0x01, 0x03, 0x12, 0x03, 0x41, 0x2f, 0x42, 0x0a, 0x24, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x02, 0x12,
-    0x03, 0x43, 0x02, 0x3b, 0x1a, 0x17, 0x20, 0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0xe3, 0x82, 0x92,
-    0xe8, 0xbf, 0xbd, 0xe5, 0x8a, 0xa0, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x06, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x43, 0x06, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x06,
-    0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x43, 0x0f, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02,
-    0x02, 0x03, 0x12, 0x03, 0x43, 0x29, 0x39, 0x0a, 0x24, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x03, 0x12,
-    0x03, 0x45, 0x02, 0x44, 0x1a, 0x17, 0x20, 0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0xe3, 0x82, 0x92,
-    0xe5, 0x89, 0x8a, 0xe9, 0x99, 0xa4, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x06, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x45, 0x06, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x06,
-    0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x45, 0x12, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02,
-    0x03, 0x03, 0x12, 0x03, 0x45, 0x2f, 0x42, 0x0a, 0x3c, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x04, 0x12,
-    0x03, 0x47, 0x02, 0x3e, 0x1a, 0x2f, 0x20, 0xe3, 0x83, 0x86, 0xe3, 0x83, 0x8a, 0xe3, 0x83, 0xb3,
-    0xe3, 0x83, 0x88, 0xe3, 0x81, 0xae, 0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0xe3, 0x81, 0xae, 0xe4,
-    0xb8, 0x80, 0xe8, 0xa6, 0xa7, 0xe3, 0x82, 0x92, 0xe5, 0x8f, 0x96, 0xe5, 0xbe, 0x97, 0xe3, 0x81,
-    0x99, 0xe3, 0x82, 0x8b, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03,
-    0x47, 0x06, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x47, 0x10,
-    0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x04, 0x03, 0x12, 0x03, 0x47, 0x2b, 0x3c, 0x62,
-    0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+    0x6d, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20,
+    0x01, 0x28, 0x04, 0x52, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x22, 0x9e, 0x02, 0x0a,
+    0x0f, 0x41, 0x64, 0x64, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x12, 0x1b, 0x0a, 0x09, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x08, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x49, 0x64, 0x12, 0x35, 0x0a,
+    0x05, 0x69, 0x74, 0x65, 0x6d, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x74,
+    0x65, 0x6e, 0x61, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x64, 0x64, 0x49, 0x74, 0x65, 0x6d,
+    0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x2e, 0x49, 0x74, 0x65, 0x6d, 0x52, 0x05, 0x69,
+    0x74, 0x65, 0x6d, 0x73, 0x12, 0x2e, 0x0a, 0x10, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64,
+    0x5f, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x48, 0x00,
+    0x52, 0x0f, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x56, 0x65, 0x72, 0x73, 0x69, 0x6f,
+    0x6e, 0x88, 0x01, 0x01, 0x12, 0x2c, 0x0a, 0x0f, 0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65,
+    0x6e, 0x63, 0x79, 0x5f, 0x6b, 0x65, 0x79, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x48, 0x01, 0x52,
+    0x0e, 0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x4b, 0x65, 0x79, 0x88,
+    0x01, 0x01, 0x1a, 0x30, 0x0a, 0x04, 0x49, 0x74, 0x65, 0x6d, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61,
+    0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x14,
+    0x0a, 0x05, 0x70, 0x72, 0x69, 0x63, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x05, 0x70,
+    0x72, 0x69, 0x63, 0x65, 0x42, 0x13, 0x0a, 0x11, 0x5f, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65,
+    0x64, 0x5f, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x42, 0x12, 0x0a, 0x10, 0x5f, 0x69, 0x64,
+    0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x5f, 0x6b, 0x65, 0x79, 0x22, 0x3e, 0x0a,
+    0x10, 0x41, 0x64, 0x64, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
+    0x65, 0x12, 0x10, 0x0a, 0x03, 0x69, 0x64, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x09, 0x52, 0x03,
+    0x69, 0x64, 0x73, 0x12, 0x18, 0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x22, 0xd3, 0x01,
+    0x0a, 0x12, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x52, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x12, 0x1b, 0x0a, 0x09, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x5f, 0x69,
+    0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x49,
+    0x64, 0x12, 0x19, 0x0a, 0x08, 0x69, 0x74, 0x65, 0x6d, 0x5f, 0x69, 0x64, 0x73, 0x18, 0x02, 0x20,
+    0x03, 0x28, 0x09, 0x52, 0x07, 0x69, 0x74, 0x65, 0x6d, 0x49, 0x64, 0x73, 0x12, 0x2e, 0x0a, 0x10,
+    0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x5f, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e,
+    0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x48, 0x00, 0x52, 0x0f, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74,
+    0x65, 0x64, 0x56, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x88, 0x01, 0x01, 0x12, 0x2c, 0x0a, 0x0f,
+    0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x5f, 0x6b, 0x65, 0x79, 0x18,
+    0x04, 0x20, 0x01, 0x28, 0x09, 0x48, 0x01, 0x52, 0x0e, 0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74,
+    0x65, 0x6e, 0x63, 0x79, 0x4b, 0x65, 0x79, 0x88, 0x01, 0x01, 0x42, 0x13, 0x0a, 0x11, 0x5f, 0x65,
+    0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x5f, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x42,
+    0x12, 0x0a, 0x10, 0x5f, 0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x5f,
+    0x6b, 0x65, 0x79, 0x22, 0x2f, 0x0a, 0x13, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x49, 0x74, 0x65,
+    0x6d, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x76, 0x65,
+    0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x76, 0x65, 0x72,
+    0x73, 0x69, 0x6f, 0x6e, 0x22, 0xb3, 0x03, 0x0a, 0x10, 0x4c, 0x69, 0x73, 0x74, 0x49, 0x74, 0x65,
+    0x6d, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1b, 0x0a, 0x09, 0x74, 0x65, 0x6e,
+    0x61, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x74, 0x65,
+    0x6e, 0x61, 0x6e, 0x74, 0x49, 0x64, 0x12, 0x1b, 0x0a, 0x09, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x73,
+    0x69, 0x7a, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x08, 0x70, 0x61, 0x67, 0x65, 0x53,
+    0x69, 0x7a, 0x65, 0x12, 0x1d, 0x0a, 0x0a, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x70, 0x61, 0x67, 0x65, 0x54, 0x6f, 0x6b,
+    0x65, 0x6e, 0x12, 0x20, 0x0a, 0x09, 0x6d, 0x69, 0x6e, 0x5f, 0x70, 0x72, 0x69, 0x63, 0x65, 0x18,
+    0x04, 0x20, 0x01, 0x28, 0x0d, 0x48, 0x00, 0x52, 0x08, 0x6d, 0x69, 0x6e, 0x50, 0x72, 0x69, 0x63,
+    0x65, 0x88, 0x01, 0x01, 0x12, 0x20, 0x0a, 0x09, 0x6d, 0x61, 0x78, 0x5f, 0x70, 0x72, 0x69, 0x63,
+    0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0d, 0x48, 0x01, 0x52, 0x08, 0x6d, 0x61, 0x78, 0x50, 0x72,
+    0x69, 0x63, 0x65, 0x88, 0x01, 0x01, 0x12, 0x28, 0x0a, 0x0d, 0x6e, 0x61, 0x6d, 0x65, 0x5f, 0x63,
+    0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x73, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x48, 0x02, 0x52,
+    0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x43, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x73, 0x88, 0x01, 0x01,
+    0x12, 0x3e, 0x0a, 0x08, 0x73, 0x6f, 0x72, 0x74, 0x5f, 0x6b, 0x65, 0x79, 0x18, 0x07, 0x20, 0x01,
+    0x28, 0x0e, 0x32, 0x23, 0x2e, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x4c,
+    0x69, 0x73, 0x74, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x2e,
+    0x53, 0x6f, 0x72, 0x74, 0x4b, 0x65, 0x79, 0x52, 0x07, 0x73, 0x6f, 0x72, 0x74, 0x4b, 0x65, 0x79,
+    0x12, 0x1e, 0x0a, 0x0a, 0x64, 0x65, 0x73, 0x63, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x18, 0x08,
+    0x20, 0x01, 0x28, 0x08, 0x52, 0x0a, 0x64, 0x65, 0x73, 0x63, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67,
+    0x22, 0x4a, 0x0a, 0x07, 0x53, 0x6f, 0x72, 0x74, 0x4b, 0x65, 0x79, 0x12, 0x18, 0x0a, 0x14, 0x53,
+    0x4f, 0x52, 0x54, 0x5f, 0x4b, 0x45, 0x59, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46,
+    0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x12, 0x0a, 0x0e, 0x53, 0x4f, 0x52, 0x54, 0x5f, 0x4b, 0x45,
+    0x59, 0x5f, 0x50, 0x52, 0x49, 0x43, 0x45, 0x10, 0x01, 0x12, 0x11, 0x0a, 0x0d, 0x53, 0x4f, 0x52,
+    0x54, 0x5f, 0x4b, 0x45, 0x59, 0x5f, 0x4e, 0x41, 0x4d, 0x45, 0x10, 0x02, 0x42, 0x0c, 0x0a, 0x0a,
+    0x5f, 0x6d, 0x69, 0x6e, 0x5f, 0x70, 0x72, 0x69, 0x63, 0x65, 0x42, 0x0c, 0x0a, 0x0a, 0x5f, 0x6d,
+    0x61, 0x78, 0x5f, 0x70, 0x72, 0x69, 0x63, 0x65, 0x42, 0x10, 0x0a, 0x0e, 0x5f, 0x6e, 0x61, 0x6d,
+    0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x73, 0x22, 0xb6, 0x01, 0x0a, 0x11, 0x4c,
+    0x69, 0x73, 0x74, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+    0x12, 0x37, 0x0a, 0x05, 0x69, 0x74, 0x65, 0x6d, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32,
+    0x21, 0x2e, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x69, 0x73, 0x74,
+    0x49, 0x74, 0x65, 0x6d, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x2e, 0x49, 0x74,
+    0x65, 0x6d, 0x52, 0x05, 0x69, 0x74, 0x65, 0x6d, 0x73, 0x12, 0x26, 0x0a, 0x0f, 0x6e, 0x65, 0x78,
+    0x74, 0x5f, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x02, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x0d, 0x6e, 0x65, 0x78, 0x74, 0x50, 0x61, 0x67, 0x65, 0x54, 0x6f, 0x6b, 0x65,
+    0x6e, 0x1a, 0x40, 0x0a, 0x04, 0x49, 0x74, 0x65, 0x6d, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d,
+    0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x14, 0x0a,
+    0x05, 0x70, 0x72, 0x69, 0x63, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x05, 0x70, 0x72,
+    0x69, 0x63, 0x65, 0x22, 0x61, 0x0a, 0x0d, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x52, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x71, 0x75, 0x65, 0x72, 0x79, 0x18, 0x01, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x05, 0x71, 0x75, 0x65, 0x72, 0x79, 0x12, 0x1b, 0x0a, 0x09, 0x70, 0x61,
+    0x67, 0x65, 0x5f, 0x73, 0x69, 0x7a, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x08, 0x70,
+    0x61, 0x67, 0x65, 0x53, 0x69, 0x7a, 0x65, 0x12, 0x1d, 0x0a, 0x0a, 0x70, 0x61, 0x67, 0x65, 0x5f,
+    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x70, 0x61, 0x67,
+    0x65, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x22, 0xcb, 0x02, 0x0a, 0x0e, 0x53, 0x65, 0x61, 0x72, 0x63,
+    0x68, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x31, 0x0a, 0x04, 0x68, 0x69, 0x74,
+    0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1d, 0x2e, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74,
+    0x2e, 0x76, 0x31, 0x2e, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+    0x73, 0x65, 0x2e, 0x48, 0x69, 0x74, 0x52, 0x04, 0x68, 0x69, 0x74, 0x73, 0x12, 0x26, 0x0a, 0x0f,
+    0x6e, 0x65, 0x78, 0x74, 0x5f, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18,
+    0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0d, 0x6e, 0x65, 0x78, 0x74, 0x50, 0x61, 0x67, 0x65, 0x54,
+    0x6f, 0x6b, 0x65, 0x6e, 0x1a, 0x40, 0x0a, 0x04, 0x49, 0x74, 0x65, 0x6d, 0x12, 0x0e, 0x0a, 0x02,
+    0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x12, 0x12, 0x0a, 0x04,
+    0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65,
+    0x12, 0x14, 0x0a, 0x05, 0x70, 0x72, 0x69, 0x63, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x52,
+    0x05, 0x70, 0x72, 0x69, 0x63, 0x65, 0x1a, 0x9b, 0x01, 0x0a, 0x03, 0x48, 0x69, 0x74, 0x12, 0x1b,
+    0x0a, 0x09, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28,
+    0x09, 0x52, 0x08, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x74,
+    0x65, 0x6e, 0x61, 0x6e, 0x74, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x0a, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x37, 0x0a, 0x04,
+    0x69, 0x74, 0x65, 0x6d, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1e, 0x2e, 0x74, 0x65, 0x6e,
+    0x61, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x52, 0x65, 0x73,
+    0x70, 0x6f, 0x6e, 0x73, 0x65, 0x2e, 0x49, 0x74, 0x65, 0x6d, 0x48, 0x00, 0x52, 0x04, 0x69, 0x74,
+    0x65, 0x6d, 0x88, 0x01, 0x01, 0x12, 0x14, 0x0a, 0x05, 0x73, 0x63, 0x6f, 0x72, 0x65, 0x18, 0x04,
+    0x20, 0x01, 0x28, 0x02, 0x52, 0x05, 0x73, 0x63, 0x6f, 0x72, 0x65, 0x42, 0x07, 0x0a, 0x05, 0x5f,
+    0x69, 0x74, 0x65, 0x6d, 0x32, 0xb6, 0x03, 0x0a, 0x0d, 0x54, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x53,
+    0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x3d, 0x0a, 0x06, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65,
+    0x12, 0x18, 0x2e, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x72, 0x65,
+    0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x19, 0x2e, 0x74, 0x65, 0x6e,
+    0x61, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52, 0x65, 0x73,
+    0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4c, 0x0a, 0x0b, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x65, 0x6e,
+    0x61, 0x6e, 0x74, 0x73, 0x12, 0x1d, 0x2e, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x2e, 0x76, 0x31,
+    0x2e, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x73, 0x52, 0x65, 0x71, 0x75,
+    0x65, 0x73, 0x74, 0x1a, 0x1e, 0x2e, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e,
+    0x4c, 0x69, 0x73, 0x74, 0x54, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f,
+    0x6e, 0x73, 0x65, 0x12, 0x43, 0x0a, 0x08, 0x41, 0x64, 0x64, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x12,
+    0x1a, 0x2e, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x64, 0x64, 0x49,
+    0x74, 0x65, 0x6d, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x74, 0x65,
+    0x6e, 0x61, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x64, 0x64, 0x49, 0x74, 0x65, 0x6d, 0x73,
+    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4c, 0x0a, 0x0b, 0x52, 0x65, 0x6d, 0x6f,
+    0x76, 0x65, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x12, 0x1d, 0x2e, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74,
+    0x2e, 0x76, 0x31, 0x2e, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x52,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1e, 0x2e, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x2e,
+    0x76, 0x31, 0x2e, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x52, 0x65,
+    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x46, 0x0a, 0x09, 0x4c, 0x69, 0x73, 0x74, 0x49, 0x74,
+    0x65, 0x6d, 0x73, 0x12, 0x1b, 0x2e, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e,
+    0x4c, 0x69, 0x73, 0x74, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x1a, 0x1c, 0x2e, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x69, 0x73,
+    0x74, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3d,
+    0x0a, 0x06, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x12, 0x18, 0x2e, 0x74, 0x65, 0x6e, 0x61, 0x6e,
+    0x74, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x52, 0x65, 0x71, 0x75, 0x65,
+    0x73, 0x74, 0x1a, 0x19, 0x2e, 0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x53,
+    0x65, 0x61, 0x72, 0x63, 0x68, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x4a, 0xf6, 0x33,
+    0x0a, 0x07, 0x12, 0x05, 0x00, 0x00, 0x9a, 0x01, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03,
+    0x02, 0x00, 0x12, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x04, 0x00, 0x08, 0x01, 0x0a,
+    0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x04, 0x08, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x00, 0x02, 0x00, 0x12, 0x03, 0x05, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x05, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12,
+    0x03, 0x05, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x05,
+    0x02, 0x08, 0x0a, 0x78, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x07, 0x02, 0x26, 0x1a,
+    0x6b, 0x20, 0xe5, 0x90, 0x8c, 0xe3, 0x81, 0x98, 0xe5, 0x86, 0xaa, 0xe7, 0xad, 0x89, 0xe3, 0x82,
+    0xad, 0xe3, 0x83, 0xbc, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0xaa, 0xe3, 0x82, 0xaf, 0xe3, 0x82, 0xa8,
+    0xe3, 0x82, 0xb9, 0xe3, 0x83, 0x88, 0xe3, 0x82, 0x92, 0xe5, 0x86, 0x8d, 0xe5, 0xae, 0x9f, 0xe8,
+    0xa1, 0x8c, 0xe3, 0x81, 0x97, 0xe3, 0x81, 0x9f, 0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88, 0xe3, 0x81,
+    0xaf, 0xe6, 0x9c, 0x80, 0xe5, 0x88, 0x9d, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0xaa, 0xe3, 0x82, 0xaf,
+    0xe3, 0x82, 0xa8, 0xe3, 0x82, 0xb9, 0xe3, 0x83, 0x88, 0xe3, 0x81, 0xae, 0xe7, 0xb5, 0x90, 0xe6,
+    0x9e, 0x9c, 0xe3, 0x82, 0x92, 0xe8, 0xbf, 0x94, 0xe3, 0x81, 0x99, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x07, 0x12, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x01, 0x03, 0x12, 0x03, 0x07, 0x24, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01,
+    0x04, 0x12, 0x03, 0x07, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12,
+    0x03, 0x07, 0x0b, 0x11, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x0a, 0x00, 0x0e, 0x01,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x0a, 0x08, 0x16, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x0b, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x0b, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03,
+    0x12, 0x03, 0x0b, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03,
+    0x0b, 0x02, 0x08, 0x0a, 0x3f, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x0d, 0x02, 0x15,
+    0x1a, 0x32, 0x20, 0xe3, 0x82, 0xb3, 0xe3, 0x83, 0x9e, 0xe3, 0x83, 0xb3, 0xe3, 0x83, 0x89, 0xe5,
+    0xae, 0x9f, 0xe8, 0xa1, 0x8c, 0xe5, 0xbe, 0x8c, 0xe3, 0x81, 0xae, 0xe9, 0x9b, 0x86, 0xe7, 0xb4,
+    0x84, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x90, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x83, 0xa7,
+    0xe3, 0x83, 0xb3, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0d,
+    0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0d, 0x13, 0x14,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x0d, 0x02, 0x08, 0x0a, 0x0a,
+    0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x10, 0x00, 0x15, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02,
+    0x01, 0x12, 0x03, 0x10, 0x08, 0x1a, 0x0a, 0x6b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03,
+    0x12, 0x02, 0x17, 0x1a, 0x5e, 0x20, 0x31, 0xe3, 0x83, 0x9a, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8,
+    0xe3, 0x81, 0xab, 0xe5, 0x90, 0xab, 0xe3, 0x82, 0x81, 0xe3, 0x82, 0x8b, 0xe6, 0x9c, 0x80, 0xe5,
+    0xa4, 0xa7, 0xe4, 0xbb, 0xb6, 0xe6, 0x95, 0xb0, 0xe3, 0x80, 0x82, 0x30, 0xe3, 0x81, 0xae, 0xe5,
+    0xa0, 0xb4, 0xe5, 0x90, 0x88, 0xe3, 0x81, 0xaf, 0xe3, 0x82, 0xb5, 0xe3, 0x83, 0xbc, 0xe3, 0x83,
+    0x90, 0xe3, 0x83, 0xbc, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x87, 0xe3, 0x83, 0x95, 0xe3, 0x82, 0xa9,
+    0xe3, 0x83, 0xab, 0xe3, 0x83, 0x88, 0xe5, 0x80, 0xa4, 0xe3, 0x82, 0x92, 0xe4, 0xbd, 0xbf, 0xe3,
+    0x81, 0x86, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x12, 0x09,
+    0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x12, 0x15, 0x16, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x12, 0x02, 0x08, 0x0a, 0x7f, 0x0a,
+    0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x14, 0x02, 0x18, 0x1a, 0x72, 0x20, 0xe5, 0x89, 0x8d,
+    0xe3, 0x81, 0xae, 0xe3, 0x83, 0x9a, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x81, 0xae, 0xe3,
+    0x83, 0xac, 0xe3, 0x82, 0xb9, 0xe3, 0x83, 0x9d, 0xe3, 0x83, 0xb3, 0xe3, 0x82, 0xb9, 0xe3, 0x81,
+    0xae, 0x20, 0x6e, 0x65, 0x78, 0x74, 0x5f, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0xe3, 0x80, 0x82, 0xe6, 0x9c, 0x80, 0xe5, 0x88, 0x9d, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x9a,
+    0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x82, 0x92, 0xe5, 0x8f, 0x96, 0xe5, 0xbe, 0x97, 0xe3,
+    0x81, 0x99, 0xe3, 0x82, 0x8b, 0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88, 0xe3, 0x81, 0xaf, 0xe6, 0x8c,
+    0x87, 0xe5, 0xae, 0x9a, 0xe3, 0x81, 0x97, 0xe3, 0x81, 0xaa, 0xe3, 0x81, 0x84, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x14, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x14, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x01, 0x05, 0x12, 0x03, 0x14, 0x02, 0x08, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04,
+    0x17, 0x00, 0x22, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x17, 0x08, 0x1b,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x1f, 0x02, 0x1e, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1f, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1f, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x00, 0x04, 0x12, 0x03, 0x1f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x06,
+    0x12, 0x03, 0x1f, 0x0b, 0x11, 0x0a, 0x69, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x21,
+    0x02, 0x1d, 0x1a, 0x5c, 0x20, 0xe6, 0xac, 0xa1, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x9a, 0xe3, 0x83,
+    0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x82, 0x92, 0xe5, 0x8f, 0x96, 0xe5, 0xbe, 0x97, 0xe3, 0x81, 0x99,
+    0xe3, 0x82, 0x8b, 0xe3, 0x83, 0x88, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xaf, 0xe3, 0x83, 0xb3, 0xe3,
+    0x80, 0x82, 0xe6, 0xac, 0xa1, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x9a, 0xe3, 0x83, 0xbc, 0xe3, 0x82,
+    0xb8, 0xe3, 0x81, 0x8c, 0xe3, 0x81, 0xaa, 0xe3, 0x81, 0x84, 0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88,
+    0xe3, 0x81, 0xaf, 0xe7, 0xa9, 0xba, 0xe6, 0x96, 0x87, 0xe5, 0xad, 0x97, 0xe5, 0x88, 0x97, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x21, 0x09, 0x18, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x21, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x01, 0x05, 0x12, 0x03, 0x21, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x03,
+    0x03, 0x00, 0x12, 0x04, 0x18, 0x02, 0x1d, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x03, 0x00,
+    0x01, 0x12, 0x03, 0x18, 0x0a, 0x10, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x03, 0x03, 0x00, 0x02, 0x00,
+    0x12, 0x03, 0x19, 0x04, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03, 0x03, 0x00, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x19, 0x0b, 0x0d, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03, 0x03, 0x00, 0x02, 0x00, 0x03,
+    0x12, 0x03, 0x19, 0x10, 0x11, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03, 0x03, 0x00, 0x02, 0x00, 0x05,
+    0x12, 0x03, 0x19, 0x04, 0x0a, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x03, 0x03, 0x00, 0x02, 0x01, 0x12,
+    0x03, 0x1a, 0x04, 0x14, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03, 0x03, 0x00, 0x02, 0x01, 0x01, 0x12,
+    0x03, 0x1a, 0x0b, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03, 0x03, 0x00, 0x02, 0x01, 0x03, 0x12,
+    0x03, 0x1a, 0x12, 0x13, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03, 0x03, 0x00, 0x02, 0x01, 0x05, 0x12,
+    0x03, 0x1a, 0x04, 0x0a, 0x0a, 0x29, 0x0a, 0x06, 0x04, 0x03, 0x03, 0x00, 0x02, 0x02, 0x12, 0x03,
+    0x1c, 0x04, 0x17, 0x1a, 0x1a, 0x20, 0xe9, 0x9b, 0x86, 0xe7, 0xb4, 0x84, 0xe3, 0x81, 0xae, 0xe3,
+    0x83, 0x90, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x83, 0xa7, 0xe3, 0x83, 0xb3, 0x0a, 0x0a,
+    0x0e, 0x0a, 0x07, 0x04, 0x03, 0x03, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x1c, 0x0b, 0x12, 0x0a,
+    0x0e, 0x0a, 0x07, 0x04, 0x03, 0x03, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x1c, 0x15, 0x16, 0x0a,
+    0x0e, 0x0a, 0x07, 0x04, 0x03, 0x03, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x1c, 0x04, 0x0a, 0x0a,
+    0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x24, 0x00, 0x33, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x04, 0x01, 0x12, 0x03, 0x24, 0x08, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12,
+    0x03, 0x2d, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x2d,
+    0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2d, 0x15, 0x16,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x05, 0x12, 0x03, 0x2d, 0x02, 0x08, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12, 0x03, 0x2e, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x2e, 0x10, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x2e, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x04,
+    0x12, 0x03, 0x2e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x06, 0x12, 0x03,
+    0x2e, 0x0b, 0x0f, 0x0a, 0x5d, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x02, 0x12, 0x03, 0x30, 0x02, 0x27,
+    0x1a, 0x50, 0x20, 0xe9, 0x9b, 0x86, 0xe7, 0xb4, 0x84, 0xe3, 0x81, 0x8c, 0xe6, 0x8c, 0x87, 0xe5,
+    0xae, 0x9a, 0xe3, 0x81, 0x97, 0xe3, 0x81, 0x9f, 0xe3, 0x83, 0x90, 0xe3, 0x83, 0xbc, 0xe3, 0x82,
+    0xb8, 0xe3, 0x83, 0xa7, 0xe3, 0x83, 0xb3, 0xe3, 0x81, 0xae, 0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88,
+    0xe3, 0x81, 0xae, 0xe3, 0x81, 0xbf, 0xe3, 0x82, 0xb3, 0xe3, 0x83, 0x9e, 0xe3, 0x83, 0xb3, 0xe3,
+    0x83, 0x89, 0xe3, 0x82, 0x92, 0xe5, 0xae, 0x9f, 0xe8, 0xa1, 0x8c, 0xe3, 0x81, 0x99, 0xe3, 0x82,
+    0x8b, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x30, 0x12, 0x22,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x03, 0x12, 0x03, 0x30, 0x25, 0x26, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x04, 0x12, 0x03, 0x30, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x04, 0x02, 0x02, 0x05, 0x12, 0x03, 0x30, 0x0b, 0x11, 0x0a, 0x78, 0x0a, 0x04, 0x04, 0x04,
+    0x02, 0x03, 0x12, 0x03, 0x32, 0x02, 0x26, 0x1a, 0x6b, 0x20, 0xe5, 0x90, 0x8c, 0xe3, 0x81, 0x98,
+    0xe5, 0x86, 0xaa, 0xe7, 0xad, 0x89, 0xe3, 0x82, 0xad, 0xe3, 0x83, 0xbc, 0xe3, 0x81, 0xae, 0xe3,
+    0x83, 0xaa, 0xe3, 0x82, 0xaf, 0xe3, 0x82, 0xa8, 0xe3, 0x82, 0xb9, 0xe3, 0x83, 0x88, 0xe3, 0x82,
+    0x92, 0xe5, 0x86, 0x8d, 0xe5, 0xae, 0x9f, 0xe8, 0xa1, 0x8c, 0xe3, 0x81, 0x97, 0xe3, 0x81, 0x9f,
+    0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88, 0xe3, 0x81, 0xaf, 0xe6, 0x9c, 0x80, 0xe5, 0x88, 0x9d, 0xe3,
+    0x81, 0xae, 0xe3, 0x83, 0xaa, 0xe3, 0x82, 0xaf, 0xe3, 0x82, 0xa8, 0xe3, 0x82, 0xb9, 0xe3, 0x83,
+    0x88, 0xe3, 0x81, 0xae, 0xe7, 0xb5, 0x90, 0xe6, 0x9e, 0x9c, 0xe3, 0x82, 0x92, 0xe8, 0xbf, 0x94,
+    0xe3, 0x81, 0x99, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x03, 0x01, 0x12, 0x03, 0x32,
+    0x12, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x03, 0x03, 0x12, 0x03, 0x32, 0x24, 0x25,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x03, 0x04, 0x12, 0x03, 0x32, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x03, 0x05, 0x12, 0x03, 0x32, 0x0b, 0x11, 0x0a, 0x16, 0x0a, 0x04,
+    0x04, 0x04, 0x03, 0x00, 0x12, 0x04, 0x26, 0x02, 0x2b, 0x03, 0x1a, 0x08, 0x20, 0xe5, 0x95, 0x86,
+    0xe5, 0x93, 0x81, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x03, 0x00, 0x01, 0x12, 0x03, 0x26,
+    0x0a, 0x0e, 0x0a, 0x1a, 0x0a, 0x06, 0x04, 0x04, 0x03, 0x00, 0x02, 0x00, 0x12, 0x03, 0x28, 0x04,
+    0x14, 0x1a, 0x0b, 0x20, 0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0xe5, 0x90, 0x8d, 0x0a, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x04, 0x03, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x28, 0x0b, 0x0f, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x04, 0x03, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x28, 0x12, 0x13, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x04, 0x03, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x28, 0x04, 0x0a, 0x0a, 0x17,
+    0x0a, 0x06, 0x04, 0x04, 0x03, 0x00, 0x02, 0x01, 0x12, 0x03, 0x2a, 0x04, 0x15, 0x1a, 0x08, 0x20,
+    0xe5, 0x80, 0xa4, 0xe6, 0xae, 0xb5, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x03, 0x00, 0x02,
+    0x01, 0x01, 0x12, 0x03, 0x2a, 0x0b, 0x10, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x03, 0x00, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x2a, 0x13, 0x14, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x03, 0x00, 0x02,
+    0x01, 0x05, 0x12, 0x03, 0x2a, 0x04, 0x0a, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x35,
+    0x00, 0x39, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x35, 0x08, 0x18, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x36, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x36, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x00, 0x03, 0x12, 0x03, 0x36, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00,
+    0x04, 0x12, 0x03, 0x36, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x05, 0x12,
+    0x03, 0x36, 0x0b, 0x11, 0x0a, 0x3f, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x01, 0x12, 0x03, 0x38, 0x02,
+    0x15, 0x1a, 0x32, 0x20, 0xe3, 0x82, 0xb3, 0xe3, 0x83, 0x9e, 0xe3, 0x83, 0xb3, 0xe3, 0x83, 0x89,
+    0xe5, 0xae, 0x9f, 0xe8, 0xa1, 0x8c, 0xe5, 0xbe, 0x8c, 0xe3, 0x81, 0xae, 0xe9, 0x9b, 0x86, 0xe7,
+    0xb4, 0x84, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x90, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x83,
+    0xa7, 0xe3, 0x83, 0xb3, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x01, 0x12, 0x03,
+    0x38, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x03, 0x12, 0x03, 0x38, 0x13,
+    0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x05, 0x12, 0x03, 0x38, 0x02, 0x08, 0x0a,
+    0x0a, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x3b, 0x00, 0x42, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x06, 0x01, 0x12, 0x03, 0x3b, 0x08, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12,
+    0x03, 0x3c, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3c,
+    0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x3c, 0x15, 0x16,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x05, 0x12, 0x03, 0x3c, 0x02, 0x08, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x03, 0x3d, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x3d, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x3d, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x04,
+    0x12, 0x03, 0x3d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x05, 0x12, 0x03,
+    0x3d, 0x0b, 0x11, 0x0a, 0x5d, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x02, 0x12, 0x03, 0x3f, 0x02, 0x27,
+    0x1a, 0x50, 0x20, 0xe9, 0x9b, 0x86, 0xe7, 0xb4, 0x84, 0xe3, 0x81, 0x8c, 0xe6, 0x8c, 0x87, 0xe5,
+    0xae, 0x9a, 0xe3, 0x81, 0x97, 0xe3, 0x81, 0x9f, 0xe3, 0x83, 0x90, 0xe3, 0x83, 0xbc, 0xe3, 0x82,
+    0xb8, 0xe3, 0x83, 0xa7, 0xe3, 0x83, 0xb3, 0xe3, 0x81, 0xae, 0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88,
+    0xe3, 0x81, 0xae, 0xe3, 0x81, 0xbf, 0xe3, 0x82, 0xb3, 0xe3, 0x83, 0x9e, 0xe3, 0x83, 0xb3, 0xe3,
+    0x83, 0x89, 0xe3, 0x82, 0x92, 0xe5, 0xae, 0x9f, 0xe8, 0xa1, 0x8c, 0xe3, 0x81, 0x99, 0xe3, 0x82,
+    0x8b, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x01, 0x12, 0x03, 0x3f, 0x12, 0x22,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x03, 0x12, 0x03, 0x3f, 0x25, 0x26, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x04, 0x12, 0x03, 0x3f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x02, 0x05, 0x12, 0x03, 0x3f, 0x0b, 0x11, 0x0a, 0x78, 0x0a, 0x04, 0x04, 0x06,
+    0x02, 0x03, 0x12, 0x03, 0x41, 0x02, 0x26, 0x1a, 0x6b, 0x20, 0xe5, 0x90, 0x8c, 0xe3, 0x81, 0x98,
+    0xe5, 0x86, 0xaa, 0xe7, 0xad, 0x89, 0xe3, 0x82, 0xad, 0xe3, 0x83, 0xbc, 0xe3, 0x81, 0xae, 0xe3,
+    0x83, 0xaa, 0xe3, 0x82, 0xaf, 0xe3, 0x82, 0xa8, 0xe3, 0x82, 0xb9, 0xe3, 0x83, 0x88, 0xe3, 0x82,
+    0x92, 0xe5, 0x86, 0x8d, 0xe5, 0xae, 0x9f, 0xe8, 0xa1, 0x8c, 0xe3, 0x81, 0x97, 0xe3, 0x81, 0x9f,
+    0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88, 0xe3, 0x81, 0xaf, 0xe6, 0x9c, 0x80, 0xe5, 0x88, 0x9d, 0xe3,
+    0x81, 0xae, 0xe3, 0x83, 0xaa, 0xe3, 0x82, 0xaf, 0xe3, 0x82, 0xa8, 0xe3, 0x82, 0xb9, 0xe3, 0x83,
+    0x88, 0xe3, 0x81, 0xae, 0xe7, 0xb5, 0x90, 0xe6, 0x9e, 0x9c, 0xe3, 0x82, 0x92, 0xe8, 0xbf, 0x94,
+    0xe3, 0x81, 0x99, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x01, 0x12, 0x03, 0x41,
+    0x12, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x03, 0x12, 0x03, 0x41, 0x24, 0x25,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x04, 0x12, 0x03, 0x41, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x05, 0x12, 0x03, 0x41, 0x0b, 0x11, 0x0a, 0x0a, 0x0a, 0x02,
+    0x04, 0x07, 0x12, 0x04, 0x44, 0x00, 0x47, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12,
+    0x03, 0x44, 0x08, 0x1b, 0x0a, 0x3f, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x46, 0x02,
+    0x15, 0x1a, 0x32, 0x20, 0xe3, 0x82, 0xb3, 0xe3, 0x83, 0x9e, 0xe3, 0x83, 0xb3, 0xe3, 0x83, 0x89,
+    0xe5, 0xae, 0x9f, 0xe8, 0xa1, 0x8c, 0xe5, 0xbe, 0x8c, 0xe3, 0x81, 0xae, 0xe9, 0x9b, 0x86, 0xe7,
+    0xb4, 0x84, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x90, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x83,
+    0xa7, 0xe3, 0x83, 0xb3, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x46, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x46, 0x13,
+    0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x05, 0x12, 0x03, 0x46, 0x02, 0x08, 0x0a,
+    0x0a, 0x0a, 0x02, 0x04, 0x08, 0x12, 0x04, 0x49, 0x00, 0x60, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x08, 0x01, 0x12, 0x03, 0x49, 0x08, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12,
+    0x03, 0x51, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x03, 0x51,
+    0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x03, 0x51, 0x15, 0x16,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x05, 0x12, 0x03, 0x51, 0x02, 0x08, 0x0a, 0x6b,
+    0x0a, 0x04, 0x04, 0x08, 0x02, 0x01, 0x12, 0x03, 0x53, 0x02, 0x17, 0x1a, 0x5e, 0x20, 0x31, 0xe3,
+    0x83, 0x9a, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x81, 0xab, 0xe5, 0x90, 0xab, 0xe3, 0x82,
+    0x81, 0xe3, 0x82, 0x8b, 0xe6, 0x9c, 0x80, 0xe5, 0xa4, 0xa7, 0xe4, 0xbb, 0xb6, 0xe6, 0x95, 0xb0,
+    0xe3, 0x80, 0x82, 0x30, 0xe3, 0x81, 0xae, 0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88, 0xe3, 0x81, 0xaf,
+    0xe3, 0x82, 0xb5, 0xe3, 0x83, 0xbc, 0xe3, 0x83, 0x90, 0xe3, 0x83, 0xbc, 0xe3, 0x81, 0xae, 0xe3,
+    0x83, 0x87, 0xe3, 0x83, 0x95, 0xe3, 0x82, 0xa9, 0xe3, 0x83, 0xab, 0xe3, 0x83, 0x88, 0xe5, 0x80,
+    0xa4, 0xe3, 0x82, 0x92, 0xe4, 0xbd, 0xbf, 0xe3, 0x81, 0x86, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x01, 0x01, 0x12, 0x03, 0x53, 0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x53, 0x15, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x05,
+    0x12, 0x03, 0x53, 0x02, 0x08, 0x0a, 0x7f, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x02, 0x12, 0x03, 0x55,
+    0x02, 0x18, 0x1a, 0x72, 0x20, 0xe5, 0x89, 0x8d, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x9a, 0xe3, 0x83,
+    0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0xac, 0xe3, 0x82, 0xb9, 0xe3, 0x83, 0x9d,
+    0xe3, 0x83, 0xb3, 0xe3, 0x82, 0xb9, 0xe3, 0x81, 0xae, 0x20, 0x6e, 0x65, 0x78, 0x74, 0x5f, 0x70,
+    0x61, 0x67, 0x65, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0xe3, 0x80, 0x82, 0xe6, 0x9c, 0x80, 0xe5,
+    0x88, 0x9d, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x9a, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x82,
+    0x92, 0xe5, 0x8f, 0x96, 0xe5, 0xbe, 0x97, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0xe5, 0xa0, 0xb4,
+    0xe5, 0x90, 0x88, 0xe3, 0x81, 0xaf, 0xe6, 0x8c, 0x87, 0xe5, 0xae, 0x9a, 0xe3, 0x81, 0x97, 0xe3,
+    0x81, 0xaa, 0xe3, 0x81, 0x84, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x01, 0x12,
+    0x03, 0x55, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x03, 0x12, 0x03, 0x55,
+    0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x05, 0x12, 0x03, 0x55, 0x02, 0x08,
+    0x0a, 0x3f, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x03, 0x12, 0x03, 0x57, 0x02, 0x20, 0x1a, 0x32, 0x20,
+    0xe5, 0x80, 0xa4, 0xe6, 0xae, 0xb5, 0xe3, 0x81, 0x8c, 0xe3, 0x81, 0x93, 0xe3, 0x81, 0xae, 0xe5,
+    0x80, 0xa4, 0xe4, 0xbb, 0xa5, 0xe4, 0xb8, 0x8a, 0xe3, 0x81, 0xae, 0xe5, 0x95, 0x86, 0xe5, 0x93,
+    0x81, 0xe3, 0x81, 0xab, 0xe7, 0xb5, 0x9e, 0xe3, 0x82, 0x8a, 0xe8, 0xbe, 0xbc, 0xe3, 0x82, 0x80,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x01, 0x12, 0x03, 0x57, 0x12, 0x1b, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x03, 0x12, 0x03, 0x57, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x03, 0x04, 0x12, 0x03, 0x57, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x03, 0x05, 0x12, 0x03, 0x57, 0x0b, 0x11, 0x0a, 0x3f, 0x0a, 0x04, 0x04, 0x08, 0x02,
+    0x04, 0x12, 0x03, 0x59, 0x02, 0x20, 0x1a, 0x32, 0x20, 0xe5, 0x80, 0xa4, 0xe6, 0xae, 0xb5, 0xe3,
+    0x81, 0x8c, 0xe3, 0x81, 0x93, 0xe3, 0x81, 0xae, 0xe5, 0x80, 0xa4, 0xe4, 0xbb, 0xa5, 0xe4, 0xb8,
+    0x8b, 0xe3, 0x81, 0xae, 0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0xe3, 0x81, 0xab, 0xe7, 0xb5, 0x9e,
+    0xe3, 0x82, 0x8a, 0xe8, 0xbe, 0xbc, 0xe3, 0x82, 0x80, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x04, 0x01, 0x12, 0x03, 0x59, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04,
+    0x03, 0x12, 0x03, 0x59, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x04, 0x12,
+    0x03, 0x59, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x05, 0x12, 0x03, 0x59,
+    0x0b, 0x11, 0x0a, 0x72, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x05, 0x12, 0x03, 0x5b, 0x02, 0x24, 0x1a,
+    0x65, 0x20, 0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0xe5, 0x90, 0x8d, 0xe3, 0x81, 0xab, 0xe3, 0x81,
+    0x93, 0xe3, 0x81, 0xae, 0xe6, 0x96, 0x87, 0xe5, 0xad, 0x97, 0xe5, 0x88, 0x97, 0xe3, 0x82, 0x92,
+    0xe5, 0x90, 0xab, 0xe3, 0x82, 0x80, 0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0xe3, 0x81, 0xab, 0xe7,
+    0xb5, 0x9e, 0xe3, 0x82, 0x8a, 0xe8, 0xbe, 0xbc, 0xe3, 0x82, 0x80, 0xe3, 0x80, 0x82, 0xe5, 0xa4,
+    0xa7, 0xe6, 0x96, 0x87, 0xe5, 0xad, 0x97, 0xe3, 0x81, 0xa8, 0xe5, 0xb0, 0x8f, 0xe6, 0x96, 0x87,
+    0xe5, 0xad, 0x97, 0xe3, 0x81, 0xaf, 0xe5, 0x8c, 0xba, 0xe5, 0x88, 0xa5, 0xe3, 0x81, 0x97, 0xe3,
+    0x81, 0xaa, 0xe3, 0x81, 0x84, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x05, 0x01, 0x12,
+    0x03, 0x5b, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x05, 0x03, 0x12, 0x03, 0x5b,
+    0x22, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x05, 0x04, 0x12, 0x03, 0x5b, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x05, 0x05, 0x12, 0x03, 0x5b, 0x0b, 0x11, 0x0a, 0x59,
+    0x0a, 0x04, 0x04, 0x08, 0x02, 0x06, 0x12, 0x03, 0x5d, 0x02, 0x17, 0x1a, 0x4c, 0x20, 0xe5, 0x95,
+    0x86, 0xe5, 0x93, 0x81, 0xe3, 0x82, 0x92, 0xe4, 0xb8, 0xa6, 0xe3, 0x81, 0xb9, 0xe3, 0x82, 0x8b,
+    0xe9, 0xa0, 0x86, 0xe5, 0xba, 0x8f, 0xe3, 0x80, 0x82, 0xe5, 0x90, 0x8c, 0xe3, 0x81, 0x98, 0xe5,
+    0x80, 0xa4, 0xe3, 0x81, 0xae, 0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0xe3, 0x81, 0xaf, 0xe5, 0x95,
+    0x86, 0xe5, 0x93, 0x81, 0x49, 0x44, 0xe3, 0x81, 0xae, 0xe9, 0xa0, 0x86, 0xe3, 0x81, 0xab, 0xe4,
+    0xb8, 0xa6, 0xe3, 0x81, 0xb9, 0xe3, 0x82, 0x8b, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x06, 0x01, 0x12, 0x03, 0x5d, 0x0a, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x06, 0x03,
+    0x12, 0x03, 0x5d, 0x15, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x06, 0x06, 0x12, 0x03,
+    0x5d, 0x02, 0x09, 0x0a, 0x21, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x07, 0x12, 0x03, 0x5f, 0x02, 0x16,
+    0x1a, 0x14, 0x20, 0xe9, 0x99, 0x8d, 0xe9, 0xa0, 0x86, 0xe3, 0x81, 0xab, 0xe4, 0xb8, 0xa6, 0xe3,
+    0x81, 0xb9, 0xe3, 0x82, 0x8b, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x07, 0x01, 0x12,
+    0x03, 0x5f, 0x07, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x07, 0x03, 0x12, 0x03, 0x5f,
+    0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x07, 0x05, 0x12, 0x03, 0x5f, 0x02, 0x06,
+    0x0a, 0x31, 0x0a, 0x04, 0x04, 0x08, 0x04, 0x00, 0x12, 0x04, 0x4b, 0x02, 0x4f, 0x03, 0x1a, 0x23,
+    0x20, 0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0xe3, 0x82, 0x92, 0xe4, 0xb8, 0xa6, 0xe3, 0x81, 0xb9,
+    0xe3, 0x82, 0x8b, 0xe9, 0xa0, 0x86, 0xe5, 0xba, 0x8f, 0xe3, 0x81, 0xae, 0xe3, 0x82, 0xad, 0xe3,
+    0x83, 0xbc, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x04, 0x00, 0x01, 0x12, 0x03, 0x4b, 0x07,
+    0x0e, 0x0a, 0x19, 0x0a, 0x06, 0x04, 0x08, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x4c, 0x04, 0x1d,
+    0x22, 0x0a, 0x20, 0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0x49, 0x44, 0x0a, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x08, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x4c, 0x04, 0x18, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x08, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x4c, 0x1b, 0x1c, 0x0a, 0x17, 0x0a, 0x06,
+    0x04, 0x08, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x4d, 0x04, 0x17, 0x22, 0x08, 0x20, 0xe5, 0x80,
+    0xa4, 0xe6, 0xae, 0xb5, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x08, 0x04, 0x00, 0x02, 0x01, 0x01,
+    0x12, 0x03, 0x4d, 0x04, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x08, 0x04, 0x00, 0x02, 0x01, 0x02,
+    0x12, 0x03, 0x4d, 0x15, 0x16, 0x0a, 0x1a, 0x0a, 0x06, 0x04, 0x08, 0x04, 0x00, 0x02, 0x02, 0x12,
+    0x03, 0x4e, 0x04, 0x16, 0x22, 0x0b, 0x20, 0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0xe5, 0x90, 0x8d,
+    0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x08, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x4e, 0x04,
+    0x11, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x08, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x4e, 0x14,
+    0x15, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x04, 0x62, 0x00, 0x6c, 0x01, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x62, 0x08, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02,
+    0x00, 0x12, 0x03, 0x69, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x69, 0x10, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x03, 0x12, 0x03, 0x69,
+    0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x04, 0x12, 0x03, 0x69, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x06, 0x12, 0x03, 0x69, 0x0b, 0x0f, 0x0a, 0x69,
+    0x0a, 0x04, 0x04, 0x09, 0x02, 0x01, 0x12, 0x03, 0x6b, 0x02, 0x1d, 0x1a, 0x5c, 0x20, 0xe6, 0xac,
+    0xa1, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x9a, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x82, 0x92,
+    0xe5, 0x8f, 0x96, 0xe5, 0xbe, 0x97, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0xe3, 0x83, 0x88, 0xe3,
+    0x83, 0xbc, 0xe3, 0x82, 0xaf, 0xe3, 0x83, 0xb3, 0xe3, 0x80, 0x82, 0xe6, 0xac, 0xa1, 0xe3, 0x81,
+    0xae, 0xe3, 0x83, 0x9a, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x81, 0x8c, 0xe3, 0x81, 0xaa,
+    0xe3, 0x81, 0x84, 0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88, 0xe3, 0x81, 0xaf, 0xe7, 0xa9, 0xba, 0xe6,
+    0x96, 0x87, 0xe5, 0xad, 0x97, 0xe5, 0x88, 0x97, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
+    0x01, 0x01, 0x12, 0x03, 0x6b, 0x09, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x03,
+    0x12, 0x03, 0x6b, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x05, 0x12, 0x03,
+    0x6b, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x09, 0x03, 0x00, 0x12, 0x04, 0x63, 0x02, 0x67,
+    0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x03, 0x00, 0x01, 0x12, 0x03, 0x63, 0x0a, 0x0e, 0x0a,
+    0x0d, 0x0a, 0x06, 0x04, 0x09, 0x03, 0x00, 0x02, 0x00, 0x12, 0x03, 0x64, 0x04, 0x12, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x09, 0x03, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x64, 0x0b, 0x0d, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x09, 0x03, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x64, 0x10, 0x11, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x09, 0x03, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x64, 0x04, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x06, 0x04, 0x09, 0x03, 0x00, 0x02, 0x01, 0x12, 0x03, 0x65, 0x04, 0x14, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x09, 0x03, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x65, 0x0b, 0x0f, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x09, 0x03, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x65, 0x12, 0x13, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x09, 0x03, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x65, 0x04, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x06, 0x04, 0x09, 0x03, 0x00, 0x02, 0x02, 0x12, 0x03, 0x66, 0x04, 0x15, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x09, 0x03, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x66, 0x0b, 0x10, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x09, 0x03, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x66, 0x13, 0x14, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x09, 0x03, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x66, 0x04, 0x0a, 0x0a, 0x0a, 0x0a, 0x02,
+    0x04, 0x0a, 0x12, 0x04, 0x6e, 0x00, 0x75, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0a, 0x01, 0x12,
+    0x03, 0x6e, 0x08, 0x15, 0x0a, 0x7e, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x00, 0x12, 0x03, 0x70, 0x02,
+    0x13, 0x1a, 0x71, 0x20, 0xe6, 0xa4, 0x9c, 0xe7, 0xb4, 0xa2, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b,
+    0xe6, 0x96, 0x87, 0xe5, 0xad, 0x97, 0xe5, 0x88, 0x97, 0xe3, 0x80, 0x82, 0xe7, 0xa9, 0xba, 0xe7,
+    0x99, 0xbd, 0xe3, 0x81, 0xa7, 0xe5, 0x8c, 0xba, 0xe5, 0x88, 0x87, 0xe3, 0x81, 0xa3, 0xe3, 0x81,
+    0x9f, 0xe5, 0x85, 0xa8, 0xe3, 0x81, 0xa6, 0xe3, 0x81, 0xae, 0xe8, 0xaa, 0x9e, 0xe3, 0x82, 0x92,
+    0xe3, 0x83, 0x86, 0xe3, 0x83, 0x8a, 0xe3, 0x83, 0xb3, 0xe3, 0x83, 0x88, 0xe5, 0x90, 0x8d, 0xe3,
+    0x81, 0x8b, 0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0xe5, 0x90, 0x8d, 0xe3, 0x81, 0xab, 0xe5, 0x90,
+    0xab, 0xe3, 0x82, 0x80, 0xe3, 0x82, 0x82, 0xe3, 0x81, 0xae, 0xe3, 0x82, 0x92, 0xe8, 0xbf, 0x94,
+    0xe3, 0x81, 0x99, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01, 0x12, 0x03, 0x70,
+    0x09, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x03, 0x70, 0x11, 0x12,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x05, 0x12, 0x03, 0x70, 0x02, 0x08, 0x0a, 0x6b,
+    0x0a, 0x04, 0x04, 0x0a, 0x02, 0x01, 0x12, 0x03, 0x72, 0x02, 0x17, 0x1a, 0x5e, 0x20, 0x31, 0xe3,
+    0x83, 0x9a, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x81, 0xab, 0xe5, 0x90, 0xab, 0xe3, 0x82,
+    0x81, 0xe3, 0x82, 0x8b, 0xe6, 0x9c, 0x80, 0xe5, 0xa4, 0xa7, 0xe4, 0xbb, 0xb6, 0xe6, 0x95, 0xb0,
+    0xe3, 0x80, 0x82, 0x30, 0xe3, 0x81, 0xae, 0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88, 0xe3, 0x81, 0xaf,
+    0xe3, 0x82, 0xb5, 0xe3, 0x83, 0xbc, 0xe3, 0x83, 0x90, 0xe3, 0x83, 0xbc, 0xe3, 0x81, 0xae, 0xe3,
+    0x83, 0x87, 0xe3, 0x83, 0x95, 0xe3, 0x82, 0xa9, 0xe3, 0x83, 0xab, 0xe3, 0x83, 0x88, 0xe5, 0x80,
+    0xa4, 0xe3, 0x82, 0x92, 0xe4, 0xbd, 0xbf, 0xe3, 0x81, 0x86, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0a, 0x02, 0x01, 0x01, 0x12, 0x03, 0x72, 0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x72, 0x15, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x05,
+    0x12, 0x03, 0x72, 0x02, 0x08, 0x0a, 0x7f, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x02, 0x12, 0x03, 0x74,
+    0x02, 0x18, 0x1a, 0x72, 0x20, 0xe5, 0x89, 0x8d, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x9a, 0xe3, 0x83,
+    0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0xac, 0xe3, 0x82, 0xb9, 0xe3, 0x83, 0x9d,
+    0xe3, 0x83, 0xb3, 0xe3, 0x82, 0xb9, 0xe3, 0x81, 0xae, 0x20, 0x6e, 0x65, 0x78, 0x74, 0x5f, 0x70,
+    0x61, 0x67, 0x65, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0xe3, 0x80, 0x82, 0xe6, 0x9c, 0x80, 0xe5,
+    0x88, 0x9d, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x9a, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x82,
+    0x92, 0xe5, 0x8f, 0x96, 0xe5, 0xbe, 0x97, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0xe5, 0xa0, 0xb4,
+    0xe5, 0x90, 0x88, 0xe3, 0x81, 0xaf, 0xe6, 0x8c, 0x87, 0xe5, 0xae, 0x9a, 0xe3, 0x81, 0x97, 0xe3,
+    0x81, 0xaa, 0xe3, 0x81, 0x84, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x01, 0x12,
+    0x03, 0x74, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x03, 0x12, 0x03, 0x74,
+    0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x05, 0x12, 0x03, 0x74, 0x02, 0x08,
+    0x0a, 0x0b, 0x0a, 0x02, 0x04, 0x0b, 0x12, 0x05, 0x77, 0x00, 0x8b, 0x01, 0x01, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x0b, 0x01, 0x12, 0x03, 0x77, 0x08, 0x16, 0x0a, 0x3d, 0x0a, 0x04, 0x04, 0x0b, 0x02,
+    0x00, 0x12, 0x04, 0x88, 0x01, 0x02, 0x18, 0x1a, 0x2f, 0x20, 0xe9, 0x96, 0xa2, 0xe9, 0x80, 0xa3,
+    0xe5, 0xba, 0xa6, 0xe3, 0x81, 0xae, 0xe9, 0xab, 0x98, 0xe3, 0x81, 0x84, 0xe9, 0xa0, 0x86, 0xe3,
+    0x81, 0xab, 0xe4, 0xb8, 0xa6, 0xe3, 0x81, 0xb9, 0xe3, 0x81, 0x9f, 0xe6, 0xa4, 0x9c, 0xe7, 0xb4,
+    0xa2, 0xe7, 0xb5, 0x90, 0xe6, 0x9e, 0x9c, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00,
+    0x01, 0x12, 0x04, 0x88, 0x01, 0x0f, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x03,
+    0x12, 0x04, 0x88, 0x01, 0x16, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x04, 0x12,
+    0x04, 0x88, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x06, 0x12, 0x04,
+    0x88, 0x01, 0x0b, 0x0e, 0x0a, 0x6a, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x01, 0x12, 0x04, 0x8a, 0x01,
+    0x02, 0x1d, 0x1a, 0x5c, 0x20, 0xe6, 0xac, 0xa1, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x9a, 0xe3, 0x83,
+    0xbc, 0xe3, 0x82, 0xb8, 0xe3, 0x82, 0x92, 0xe5, 0x8f, 0x96, 0xe5, 0xbe, 0x97, 0xe3, 0x81, 0x99,
+    0xe3, 0x82, 0x8b, 0xe3, 0x83, 0x88, 0xe3, 0x83, 0xbc, 0xe3, 0x82, 0xaf, 0xe3, 0x83, 0xb3, 0xe3,
+    0x80, 0x82, 0xe6, 0xac, 0xa1, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x9a, 0xe3, 0x83, 0xbc, 0xe3, 0x82,
+    0xb8, 0xe3, 0x81, 0x8c, 0xe3, 0x81, 0xaa, 0xe3, 0x81, 0x84, 0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88,
+    0xe3, 0x81, 0xaf, 0xe7, 0xa9, 0xba, 0xe6, 0x96, 0x87, 0xe5, 0xad, 0x97, 0xe5, 0x88, 0x97, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x01, 0x12, 0x04, 0x8a, 0x01, 0x09, 0x18, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x03, 0x12, 0x04, 0x8a, 0x01, 0x1b, 0x1c, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x05, 0x12, 0x04, 0x8a, 0x01, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x0b, 0x03, 0x00, 0x12, 0x04, 0x78, 0x02, 0x7c, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0b, 0x03, 0x00, 0x01, 0x12, 0x03, 0x78, 0x0a, 0x0e, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0b, 0x03,
+    0x00, 0x02, 0x00, 0x12, 0x03, 0x79, 0x04, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0b, 0x03, 0x00,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x79, 0x0b, 0x0d, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0b, 0x03, 0x00,
+    0x02, 0x00, 0x03, 0x12, 0x03, 0x79, 0x10, 0x11, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0b, 0x03, 0x00,
+    0x02, 0x00, 0x05, 0x12, 0x03, 0x79, 0x04, 0x0a, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0b, 0x03, 0x00,
+    0x02, 0x01, 0x12, 0x03, 0x7a, 0x04, 0x14, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0b, 0x03, 0x00, 0x02,
+    0x01, 0x01, 0x12, 0x03, 0x7a, 0x0b, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0b, 0x03, 0x00, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x7a, 0x12, 0x13, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0b, 0x03, 0x00, 0x02,
+    0x01, 0x05, 0x12, 0x03, 0x7a, 0x04, 0x0a, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0b, 0x03, 0x00, 0x02,
+    0x02, 0x12, 0x03, 0x7b, 0x04, 0x15, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0b, 0x03, 0x00, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x7b, 0x0b, 0x10, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0b, 0x03, 0x00, 0x02, 0x02,
+    0x03, 0x12, 0x03, 0x7b, 0x13, 0x14, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0b, 0x03, 0x00, 0x02, 0x02,
+    0x05, 0x12, 0x03, 0x7b, 0x04, 0x0a, 0x0a, 0x0d, 0x0a, 0x04, 0x04, 0x0b, 0x03, 0x01, 0x12, 0x05,
+    0x7e, 0x02, 0x85, 0x01, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x03, 0x01, 0x01, 0x12, 0x03,
+    0x7e, 0x0a, 0x0d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0b, 0x03, 0x01, 0x02, 0x00, 0x12, 0x03, 0x7f,
+    0x04, 0x19, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0b, 0x03, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x7f,
+    0x0b, 0x14, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0b, 0x03, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x7f,
+    0x17, 0x18, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0b, 0x03, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x7f,
+    0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0b, 0x03, 0x01, 0x02, 0x01, 0x12, 0x04, 0x80, 0x01,
+    0x04, 0x1b, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0b, 0x03, 0x01, 0x02, 0x01, 0x01, 0x12, 0x04, 0x80,
+    0x01, 0x0b, 0x16, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0b, 0x03, 0x01, 0x02, 0x01, 0x03, 0x12, 0x04,
+    0x80, 0x01, 0x19, 0x1a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0b, 0x03, 0x01, 0x02, 0x01, 0x05, 0x12,
+    0x04, 0x80, 0x01, 0x04, 0x0a, 0x0a, 0x72, 0x0a, 0x06, 0x04, 0x0b, 0x03, 0x01, 0x02, 0x02, 0x12,
+    0x04, 0x82, 0x01, 0x04, 0x1b, 0x1a, 0x62, 0x20, 0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0xe5, 0x90,
+    0x8d, 0xe3, 0x81, 0xab, 0xe4, 0xb8, 0x80, 0xe8, 0x87, 0xb4, 0xe3, 0x81, 0x97, 0xe3, 0x81, 0x9f,
+    0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88, 0xe3, 0x81, 0xaf, 0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0xe3,
+    0x80, 0x82, 0xe3, 0x83, 0x86, 0xe3, 0x83, 0x8a, 0xe3, 0x83, 0xb3, 0xe3, 0x83, 0x88, 0xe5, 0x90,
+    0x8d, 0xe3, 0x81, 0xab, 0xe4, 0xb8, 0x80, 0xe8, 0x87, 0xb4, 0xe3, 0x81, 0x97, 0xe3, 0x81, 0x9f,
+    0xe5, 0xa0, 0xb4, 0xe5, 0x90, 0x88, 0xe3, 0x81, 0xaf, 0xe8, 0xa8, 0xad, 0xe5, 0xae, 0x9a, 0xe3,
+    0x81, 0x97, 0xe3, 0x81, 0xaa, 0xe3, 0x81, 0x84, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0b, 0x03,
+    0x01, 0x02, 0x02, 0x01, 0x12, 0x04, 0x82, 0x01, 0x12, 0x16, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0b,
+    0x03, 0x01, 0x02, 0x02, 0x03, 0x12, 0x04, 0x82, 0x01, 0x19, 0x1a, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x0b, 0x03, 0x01, 0x02, 0x02, 0x04, 0x12, 0x04, 0x82, 0x01, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x0b, 0x03, 0x01, 0x02, 0x02, 0x06, 0x12, 0x04, 0x82, 0x01, 0x0d, 0x11, 0x0a, 0x4e, 0x0a,
+    0x06, 0x04, 0x0b, 0x03, 0x01, 0x02, 0x03, 0x12, 0x04, 0x84, 0x01, 0x04, 0x14, 0x1a, 0x3e, 0x20,
+    0xe6, 0xa4, 0x9c, 0xe7, 0xb4, 0xa2, 0xe8, 0xaa, 0x9e, 0xe3, 0x81, 0xa8, 0xe3, 0x81, 0xae, 0xe9,
+    0x96, 0xa2, 0xe9, 0x80, 0xa3, 0xe5, 0xba, 0xa6, 0xe3, 0x80, 0x82, 0xe5, 0xa4, 0xa7, 0xe3, 0x81,
+    0x8d, 0xe3, 0x81, 0x84, 0xe3, 0x81, 0xbb, 0xe3, 0x81, 0xa9, 0xe9, 0x96, 0xa2, 0xe9, 0x80, 0xa3,
+    0xe5, 0xba, 0xa6, 0xe3, 0x81, 0x8c, 0xe9, 0xab, 0x98, 0xe3, 0x81, 0x84, 0x0a, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x0b, 0x03, 0x01, 0x02, 0x03, 0x01, 0x12, 0x04, 0x84, 0x01, 0x0a, 0x0f, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x0b, 0x03, 0x01, 0x02, 0x03, 0x03, 0x12, 0x04, 0x84, 0x01, 0x12, 0x13, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x0b, 0x03, 0x01, 0x02, 0x03, 0x05, 0x12, 0x04, 0x84, 0x01, 0x04, 0x09,
+    0x0a, 0x0c, 0x0a, 0x02, 0x06, 0x00, 0x12, 0x06, 0x8d, 0x01, 0x00, 0x9a, 0x01, 0x01, 0x0a, 0x0b,
+    0x0a, 0x03, 0x06, 0x00, 0x01, 0x12, 0x04, 0x8d, 0x01, 0x08, 0x15, 0x0a, 0x2b, 0x0a, 0x04, 0x06,
+    0x00, 0x02, 0x00, 0x12, 0x04, 0x8f, 0x01, 0x02, 0x35, 0x1a, 0x1d, 0x20, 0xe3, 0x83, 0x86, 0xe3,
+    0x83, 0x8a, 0xe3, 0x83, 0xb3, 0xe3, 0x83, 0x88, 0xe3, 0x82, 0x92, 0xe4, 0xbd, 0x9c, 0xe6, 0x88,
+    0x90, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00,
+    0x01, 0x12, 0x04, 0x8f, 0x01, 0x06, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x02,
+    0x12, 0x04, 0x8f, 0x01, 0x0d, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x03, 0x12,
+    0x04, 0x8f, 0x01, 0x25, 0x33, 0x0a, 0x34, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x01, 0x12, 0x04, 0x91,
+    0x01, 0x02, 0x44, 0x1a, 0x26, 0x20, 0xe3, 0x83, 0x86, 0xe3, 0x83, 0x8a, 0xe3, 0x83, 0xb3, 0xe3,
+    0x83, 0x88, 0xe3, 0x81, 0xae, 0xe4, 0xb8, 0x80, 0xe8, 0xa6, 0xa7, 0xe3, 0x82, 0x92, 0xe5, 0x8f,
+    0x96, 0xe5, 0xbe, 0x97, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x06,
+    0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0x91, 0x01, 0x06, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00,
+    0x02, 0x01, 0x02, 0x12, 0x04, 0x91, 0x01, 0x12, 0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02,
+    0x01, 0x03, 0x12, 0x04, 0x91, 0x01, 0x2f, 0x42, 0x0a, 0x25, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x02,
+    0x12, 0x04, 0x93, 0x01, 0x02, 0x3b, 0x1a, 0x17, 0x20, 0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0xe3,
+    0x82, 0x92, 0xe8, 0xbf, 0xbd, 0xe5, 0x8a, 0xa0, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0x93, 0x01, 0x06, 0x0e, 0x0a, 0x0d,
+    0x0a, 0x05, 0x06, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0x93, 0x01, 0x0f, 0x1e, 0x0a, 0x0d, 0x0a,
+    0x05, 0x06, 0x00, 0x02, 0x02, 0x03, 0x12, 0x04, 0x93, 0x01, 0x29, 0x39, 0x0a, 0x25, 0x0a, 0x04,
+    0x06, 0x00, 0x02, 0x03, 0x12, 0x04, 0x95, 0x01, 0x02, 0x44, 0x1a, 0x17, 0x20, 0xe5, 0x95, 0x86,
+    0xe5, 0x93, 0x81, 0xe3, 0x82, 0x92, 0xe5, 0x89, 0x8a, 0xe9, 0x99, 0xa4, 0xe3, 0x81, 0x99, 0xe3,
+    0x82, 0x8b, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x03, 0x01, 0x12, 0x04, 0x95, 0x01,
+    0x06, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x03, 0x02, 0x12, 0x04, 0x95, 0x01, 0x12,
+    0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x03, 0x03, 0x12, 0x04, 0x95, 0x01, 0x2f, 0x42,
+    0x0a, 0x3d, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x04, 0x12, 0x04, 0x97, 0x01, 0x02, 0x3e, 0x1a, 0x2f,
+    0x20, 0xe3, 0x83, 0x86, 0xe3, 0x83, 0x8a, 0xe3, 0x83, 0xb3, 0xe3, 0x83, 0x88, 0xe3, 0x81, 0xae,
+    0xe5, 0x95, 0x86, 0xe5, 0x93, 0x81, 0xe3, 0x81, 0xae, 0xe4, 0xb8, 0x80, 0xe8, 0xa6, 0xa7, 0xe3,
+    0x82, 0x92, 0xe5, 0x8f, 0x96, 0xe5, 0xbe, 0x97, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x04, 0x01, 0x12, 0x04, 0x97, 0x01, 0x06, 0x0f, 0x0a, 0x0d,
+    0x0a, 0x05, 0x06, 0x00, 0x02, 0x04, 0x02, 0x12, 0x04, 0x97, 0x01, 0x10, 0x20, 0x0a, 0x0d, 0x0a,
+    0x05, 0x06, 0x00, 0x02, 0x04, 0x03, 0x12, 0x04, 0x97, 0x01, 0x2b, 0x3c, 0x0a, 0x61, 0x0a, 0x04,
+    0x06, 0x00, 0x02, 0x05, 0x12, 0x04, 0x99, 0x01, 0x02, 0x35, 0x1a, 0x53, 0x20, 0xe3, 0x83, 0x86,
+    0xe3, 0x83, 0x8a, 0xe3, 0x83, 0xb3, 0xe3, 0x83, 0x88, 0xe5, 0x90, 0x8d, 0xe3, 0x81, 0xa8, 0xe5,
+    0x95, 0x86, 0xe5, 0x93, 0x81, 0xe5, 0x90, 0x8d, 0xe3, 0x81, 0x8b, 0xe3, 0x82, 0x89, 0xe5, 0x85,
+    0xa8, 0xe3, 0x81, 0xa6, 0xe3, 0x81, 0xae, 0xe3, 0x83, 0x86, 0xe3, 0x83, 0x8a, 0xe3, 0x83, 0xb3,
+    0xe3, 0x83, 0x88, 0xe3, 0x82, 0x92, 0xe6, 0xa8, 0xaa, 0xe6, 0x96, 0xad, 0xe3, 0x81, 0x97, 0xe3,
+    0x81, 0xa6, 0xe6, 0xa4, 0x9c, 0xe7, 0xb4, 0xa2, 0xe3, 0x81, 0x99, 0xe3, 0x82, 0x8b, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x05, 0x01, 0x12, 0x04, 0x99, 0x01, 0x06, 0x0c, 0x0a, 0x0d,
+    0x0a, 0x05, 0x06, 0x00, 0x02, 0x05, 0x02, 0x12, 0x04, 0x99, 0x01, 0x0d, 0x1a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x06, 0x00, 0x02, 0x05, 0x03, 0x12, 0x04, 0x99, 0x01, 0x25, 0x33, 0x0a, 0x08, 0x0a, 0x01,
+    0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
 ];
 include!("tenant.v1.serde.rs");
 include!("tenant.v1.tonic.rs");
@@ -13,6 +13,12 @@ impl serde::Serialize for AddItemsRequest {
         if !self.items.is_empty() {
             len += 1;
         }
+        if self.expected_version.is_some() {
+            len += 1;
+        }
+        if self.idempotency_key.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("tenant.v1.AddItemsRequest", len)?;
         if !self.tenant_id.is_empty() {
             struct_ser.serialize_field("tenantId", &self.tenant_id)?;
@@ -20,6 +26,13 @@ impl serde::Serialize for AddItemsRequest {
         if !self.items.is_empty() {
             struct_ser.serialize_field("items", &self.items)?;
         }
+        if let Some(v) = self.expected_version.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("expectedVersion", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.idempotency_key.as_ref() {
+            struct_ser.serialize_field("idempotencyKey", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -33,12 +46,18 @@ impl<'de> serde::Deserialize<'de> for AddItemsRequest {
             "tenant_id",
             "tenantId",
             "items",
+            "expected_version",
+            "expectedVersion",
+            "idempotency_key",
+            "idempotencyKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             TenantId,
             Items,
+            ExpectedVersion,
+            IdempotencyKey,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -62,6 +81,8 @@ impl<'de> serde::Deserialize<'de> for AddItemsRequest {
                         match value {
                             "tenantId" | "tenant_id" => Ok(GeneratedField::TenantId),
                             "items" => Ok(GeneratedField::Items),
+                            "expectedVersion" | "expected_version" => Ok(GeneratedField::ExpectedVersion),
+                            "idempotencyKey" | "idempotency_key" => Ok(GeneratedField::IdempotencyKey),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -83,6 +104,8 @@ impl<'de> serde::Deserialize<'de> for AddItemsRequest {
             {
                 let mut tenant_id__ = None;
                 let mut items__ = None;
+                let mut expected_version__ = None;
+                let mut idempotency_key__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::TenantId => {
@@ -97,11 +120,27 @@ impl<'de> serde::Deserialize<'de> for AddItemsRequest {
                             }
                             items__ = Some(map.next_value()?);
                         }
+                        GeneratedField::ExpectedVersion => {
+                            if expected_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expectedVersion"));
+                            }
+                            expected_version__ = 
+                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::IdempotencyKey => {
+                            if idempotency_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("idempotencyKey"));
+                            }
+                            idempotency_key__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(AddItemsRequest {
                     tenant_id: tenant_id__.unwrap_or_default(),
                     items: items__.unwrap_or_default(),
+                    expected_version: expected_version__,
+                    idempotency_key: idempotency_key__,
                 })
             }
         }
@@ -229,10 +268,17 @@ impl serde::Serialize for AddItemsResponse {
         if !self.ids.is_empty() {
             len += 1;
         }
+        if self.version != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("tenant.v1.AddItemsResponse", len)?;
         if !self.ids.is_empty() {
             struct_ser.serialize_field("ids", &self.ids)?;
         }
+        if self.version != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("version", ToString::to_string(&self.version).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -244,11 +290,13 @@ impl<'de> serde::Deserialize<'de> for AddItemsResponse {
     {
         const FIELDS: &[&str] = &[
             "ids",
+            "version",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Ids,
+            Version,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -271,6 +319,7 @@ impl<'de> serde::Deserialize<'de> for AddItemsResponse {
                     {
                         match value {
                             "ids" => Ok(GeneratedField::Ids),
+                            "version" => Ok(GeneratedField::Version),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -291,6 +340,7 @@ impl<'de> serde::Deserialize<'de> for AddItemsResponse {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut ids__ = None;
+                let mut version__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Ids => {
@@ -299,10 +349,19 @@ impl<'de> serde::Deserialize<'de> for AddItemsResponse {
                             }
                             ids__ = Some(map.next_value()?);
                         }
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(AddItemsResponse {
                     ids: ids__.unwrap_or_default(),
+                    version: version__.unwrap_or_default(),
                 })
             }
         }
@@ -320,10 +379,16 @@ impl serde::Serialize for CreateRequest {
         if !self.name.is_empty() {
             len += 1;
         }
+        if self.idempotency_key.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("tenant.v1.CreateRequest", len)?;
         if !self.name.is_empty() {
             struct_ser.serialize_field("name", &self.name)?;
         }
+        if let Some(v) = self.idempotency_key.as_ref() {
+            struct_ser.serialize_field("idempotencyKey", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -335,11 +400,14 @@ impl<'de> serde::Deserialize<'de> for CreateRequest {
     {
         const FIELDS: &[&str] = &[
             "name",
+            "idempotency_key",
+            "idempotencyKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Name,
+            IdempotencyKey,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -362,6 +430,7 @@ impl<'de> serde::Deserialize<'de> for CreateRequest {
                     {
                         match value {
                             "name" => Ok(GeneratedField::Name),
+                            "idempotencyKey" | "idempotency_key" => Ok(GeneratedField::IdempotencyKey),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -382,6 +451,7 @@ impl<'de> serde::Deserialize<'de> for CreateRequest {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut name__ = None;
+                let mut idempotency_key__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Name => {
@@ -390,10 +460,17 @@ impl<'de> serde::Deserialize<'de> for CreateRequest {
                             }
                             name__ = Some(map.next_value()?);
                         }
+                        GeneratedField::IdempotencyKey => {
+                            if idempotency_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("idempotencyKey"));
+                            }
+                            idempotency_key__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(CreateRequest {
                     name: name__.unwrap_or_default(),
+                    idempotency_key: idempotency_key__,
                 })
             }
         }
@@ -411,10 +488,17 @@ impl serde::Serialize for CreateResponse {
         if !self.id.is_empty() {
             len += 1;
         }
+        if self.version != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("tenant.v1.CreateResponse", len)?;
         if !self.id.is_empty() {
             struct_ser.serialize_field("id", &self.id)?;
         }
+        if self.version != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("version", ToString::to_string(&self.version).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -426,11 +510,13 @@ impl<'de> serde::Deserialize<'de> for CreateResponse {
     {
         const FIELDS: &[&str] = &[
             "id",
+            "version",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Id,
+            Version,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -453,6 +539,7 @@ impl<'de> serde::Deserialize<'de> for CreateResponse {
                     {
                         match value {
                             "id" => Ok(GeneratedField::Id),
+                            "version" => Ok(GeneratedField::Version),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -473,6 +560,7 @@ impl<'de> serde::Deserialize<'de> for CreateResponse {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut id__ = None;
+                let mut version__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -481,10 +569,19 @@ impl<'de> serde::Deserialize<'de> for CreateResponse {
                             }
                             id__ = Some(map.next_value()?);
                         }
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(CreateResponse {
                     id: id__.unwrap_or_default(),
+                    version: version__.unwrap_or_default(),
                 })
             }
         }
@@ -502,10 +599,54 @@ impl serde::Serialize for ListItemsRequest {
         if !self.tenant_id.is_empty() {
             len += 1;
         }
+        if self.page_size != 0 {
+            len += 1;
+        }
+        if !self.page_token.is_empty() {
+            len += 1;
+        }
+        if self.min_price.is_some() {
+            len += 1;
+        }
+        if self.max_price.is_some() {
+            len += 1;
+        }
+        if self.name_contains.is_some() {
+            len += 1;
+        }
+        if self.sort_key != 0 {
+            len += 1;
+        }
+        if self.descending {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("tenant.v1.ListItemsRequest", len)?;
         if !self.tenant_id.is_empty() {
             struct_ser.serialize_field("tenantId", &self.tenant_id)?;
         }
+        if self.page_size != 0 {
+            struct_ser.serialize_field("pageSize", &self.page_size)?;
+        }
+        if !self.page_token.is_empty() {
+            struct_ser.serialize_field("pageToken", &self.page_token)?;
+        }
+        if let Some(v) = self.min_price.as_ref() {
+            struct_ser.serialize_field("minPrice", v)?;
+        }
+        if let Some(v) = self.max_price.as_ref() {
+            struct_ser.serialize_field("maxPrice", v)?;
+        }
+        if let Some(v) = self.name_contains.as_ref() {
+            struct_ser.serialize_field("nameContains", v)?;
+        }
+        if self.sort_key != 0 {
+            let v = list_items_request::SortKey::from_i32(self.sort_key)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.sort_key)))?;
+            struct_ser.serialize_field("sortKey", &v)?;
+        }
+        if self.descending {
+            struct_ser.serialize_field("descending", &self.descending)?;
+        }
         struct_ser.end()
     }
 }
@@ -518,11 +659,31 @@ impl<'de> serde::Deserialize<'de> for ListItemsRequest {
         const FIELDS: &[&str] = &[
             "tenant_id",
             "tenantId",
+            "page_size",
+            "pageSize",
+            "page_token",
+            "pageToken",
+            "min_price",
+            "minPrice",
+            "max_price",
+            "maxPrice",
+            "name_contains",
+            "nameContains",
+            "sort_key",
+            "sortKey",
+            "descending",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             TenantId,
+            PageSize,
+            PageToken,
+            MinPrice,
+            MaxPrice,
+            NameContains,
+            SortKey,
+            Descending,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -545,6 +706,13 @@ impl<'de> serde::Deserialize<'de> for ListItemsRequest {
                     {
                         match value {
                             "tenantId" | "tenant_id" => Ok(GeneratedField::TenantId),
+                            "pageSize" | "page_size" => Ok(GeneratedField::PageSize),
+                            "pageToken" | "page_token" => Ok(GeneratedField::PageToken),
+                            "minPrice" | "min_price" => Ok(GeneratedField::MinPrice),
+                            "maxPrice" | "max_price" => Ok(GeneratedField::MaxPrice),
+                            "nameContains" | "name_contains" => Ok(GeneratedField::NameContains),
+                            "sortKey" | "sort_key" => Ok(GeneratedField::SortKey),
+                            "descending" => Ok(GeneratedField::Descending),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -565,6 +733,13 @@ impl<'de> serde::Deserialize<'de> for ListItemsRequest {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut tenant_id__ = None;
+                let mut page_size__ = None;
+                let mut page_token__ = None;
+                let mut min_price__ = None;
+                let mut max_price__ = None;
+                let mut name_contains__ = None;
+                let mut sort_key__ = None;
+                let mut descending__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::TenantId => {
@@ -573,16 +748,145 @@ impl<'de> serde::Deserialize<'de> for ListItemsRequest {
                             }
                             tenant_id__ = Some(map.next_value()?);
                         }
+                        GeneratedField::PageSize => {
+                            if page_size__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pageSize"));
+                            }
+                            page_size__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PageToken => {
+                            if page_token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pageToken"));
+                            }
+                            page_token__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::MinPrice => {
+                            if min_price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minPrice"));
+                            }
+                            min_price__ = 
+                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::MaxPrice => {
+                            if max_price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxPrice"));
+                            }
+                            max_price__ = 
+                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::NameContains => {
+                            if name_contains__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nameContains"));
+                            }
+                            name_contains__ = map.next_value()?;
+                        }
+                        GeneratedField::SortKey => {
+                            if sort_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sortKey"));
+                            }
+                            sort_key__ = Some(map.next_value::<list_items_request::SortKey>()? as i32);
+                        }
+                        GeneratedField::Descending => {
+                            if descending__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("descending"));
+                            }
+                            descending__ = Some(map.next_value()?);
+                        }
                     }
                 }
                 Ok(ListItemsRequest {
                     tenant_id: tenant_id__.unwrap_or_default(),
+                    page_size: page_size__.unwrap_or_default(),
+                    page_token: page_token__.unwrap_or_default(),
+                    min_price: min_price__,
+                    max_price: max_price__,
+                    name_contains: name_contains__,
+                    sort_key: sort_key__.unwrap_or_default(),
+                    descending: descending__.unwrap_or_default(),
                 })
             }
         }
         deserializer.deserialize_struct("tenant.v1.ListItemsRequest", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for list_items_request::SortKey {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "SORT_KEY_UNSPECIFIED",
+            Self::Price => "SORT_KEY_PRICE",
+            Self::Name => "SORT_KEY_NAME",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for list_items_request::SortKey {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "SORT_KEY_UNSPECIFIED",
+            "SORT_KEY_PRICE",
+            "SORT_KEY_NAME",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = list_items_request::SortKey;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "SORT_KEY_UNSPECIFIED" => Ok(list_items_request::SortKey::Unspecified),
+                    "SORT_KEY_PRICE" => Ok(list_items_request::SortKey::Price),
+                    "SORT_KEY_NAME" => Ok(list_items_request::SortKey::Name),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
 impl serde::Serialize for ListItemsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -594,10 +898,16 @@ impl serde::Serialize for ListItemsResponse {
         if !self.items.is_empty() {
             len += 1;
         }
+        if !self.next_page_token.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("tenant.v1.ListItemsResponse", len)?;
         if !self.items.is_empty() {
             struct_ser.serialize_field("items", &self.items)?;
         }
+        if !self.next_page_token.is_empty() {
+            struct_ser.serialize_field("nextPageToken", &self.next_page_token)?;
+        }
         struct_ser.end()
     }
 }
@@ -609,11 +919,14 @@ impl<'de> serde::Deserialize<'de> for ListItemsResponse {
     {
         const FIELDS: &[&str] = &[
             "items",
+            "next_page_token",
+            "nextPageToken",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Items,
+            NextPageToken,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -636,6 +949,7 @@ impl<'de> serde::Deserialize<'de> for ListItemsResponse {
                     {
                         match value {
                             "items" => Ok(GeneratedField::Items),
+                            "nextPageToken" | "next_page_token" => Ok(GeneratedField::NextPageToken),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -656,6 +970,7 @@ impl<'de> serde::Deserialize<'de> for ListItemsResponse {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut items__ = None;
+                let mut next_page_token__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Items => {
@@ -664,10 +979,17 @@ impl<'de> serde::Deserialize<'de> for ListItemsResponse {
                             }
                             items__ = Some(map.next_value()?);
                         }
+                        GeneratedField::NextPageToken => {
+                            if next_page_token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextPageToken"));
+                            }
+                            next_page_token__ = Some(map.next_value()?);
+                        }
                     }
                 }
                 Ok(ListItemsResponse {
                     items: items__.unwrap_or_default(),
+                    next_page_token: next_page_token__.unwrap_or_default(),
                 })
             }
         }
@@ -808,8 +1130,20 @@ impl serde::Serialize for ListTenantsRequest {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("tenant.v1.ListTenantsRequest", len)?;
+        let mut len = 0;
+        if self.page_size != 0 {
+            len += 1;
+        }
+        if !self.page_token.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("tenant.v1.ListTenantsRequest", len)?;
+        if self.page_size != 0 {
+            struct_ser.serialize_field("pageSize", &self.page_size)?;
+        }
+        if !self.page_token.is_empty() {
+            struct_ser.serialize_field("pageToken", &self.page_token)?;
+        }
         struct_ser.end()
     }
 }
@@ -820,10 +1154,16 @@ impl<'de> serde::Deserialize<'de> for ListTenantsRequest {
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "page_size",
+            "pageSize",
+            "page_token",
+            "pageToken",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            PageSize,
+            PageToken,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -844,7 +1184,11 @@ impl<'de> serde::Deserialize<'de> for ListTenantsRequest {
                     where
                         E: serde::de::Error,
                     {
-                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                        match value {
+                            "pageSize" | "page_size" => Ok(GeneratedField::PageSize),
+                            "pageToken" | "page_token" => Ok(GeneratedField::PageToken),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -862,10 +1206,29 @@ impl<'de> serde::Deserialize<'de> for ListTenantsRequest {
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                let mut page_size__ = None;
+                let mut page_token__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::PageSize => {
+                            if page_size__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pageSize"));
+                            }
+                            page_size__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PageToken => {
+                            if page_token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pageToken"));
+                            }
+                            page_token__ = Some(map.next_value()?);
+                        }
+                    }
                 }
                 Ok(ListTenantsRequest {
+                    page_size: page_size__.unwrap_or_default(),
+                    page_token: page_token__.unwrap_or_default(),
                 })
             }
         }
@@ -883,10 +1246,16 @@ impl serde::Serialize for ListTenantsResponse {
         if !self.tenants.is_empty() {
             len += 1;
         }
+        if !self.next_page_token.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("tenant.v1.ListTenantsResponse", len)?;
         if !self.tenants.is_empty() {
             struct_ser.serialize_field("tenants", &self.tenants)?;
         }
+        if !self.next_page_token.is_empty() {
+            struct_ser.serialize_field("nextPageToken", &self.next_page_token)?;
+        }
         struct_ser.end()
     }
 }
@@ -898,11 +1267,14 @@ impl<'de> serde::Deserialize<'de> for ListTenantsResponse {
     {
         const FIELDS: &[&str] = &[
             "tenants",
+            "next_page_token",
+            "nextPageToken",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Tenants,
+            NextPageToken,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -925,6 +1297,7 @@ impl<'de> serde::Deserialize<'de> for ListTenantsResponse {
                     {
                         match value {
                             "tenants" => Ok(GeneratedField::Tenants),
+                            "nextPageToken" | "next_page_token" => Ok(GeneratedField::NextPageToken),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -945,6 +1318,7 @@ impl<'de> serde::Deserialize<'de> for ListTenantsResponse {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut tenants__ = None;
+                let mut next_page_token__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Tenants => {
@@ -953,10 +1327,17 @@ impl<'de> serde::Deserialize<'de> for ListTenantsResponse {
                             }
                             tenants__ = Some(map.next_value()?);
                         }
+                        GeneratedField::NextPageToken => {
+                            if next_page_token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextPageToken"));
+                            }
+                            next_page_token__ = Some(map.next_value()?);
+                        }
                     }
                 }
                 Ok(ListTenantsResponse {
                     tenants: tenants__.unwrap_or_default(),
+                    next_page_token: next_page_token__.unwrap_or_default(),
                 })
             }
         }
@@ -977,6 +1358,9 @@ impl serde::Serialize for list_tenants_response::Tenant {
         if !self.name.is_empty() {
             len += 1;
         }
+        if self.version != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("tenant.v1.ListTenantsResponse.Tenant", len)?;
         if !self.id.is_empty() {
             struct_ser.serialize_field("id", &self.id)?;
@@ -984,6 +1368,10 @@ impl serde::Serialize for list_tenants_response::Tenant {
         if !self.name.is_empty() {
             struct_ser.serialize_field("name", &self.name)?;
         }
+        if self.version != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("version", ToString::to_string(&self.version).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -996,12 +1384,14 @@ impl<'de> serde::Deserialize<'de> for list_tenants_response::Tenant {
         const FIELDS: &[&str] = &[
             "id",
             "name",
+            "version",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Id,
             Name,
+            Version,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1025,6 +1415,7 @@ impl<'de> serde::Deserialize<'de> for list_tenants_response::Tenant {
                         match value {
                             "id" => Ok(GeneratedField::Id),
                             "name" => Ok(GeneratedField::Name),
+                            "version" => Ok(GeneratedField::Version),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1046,6 +1437,7 @@ impl<'de> serde::Deserialize<'de> for list_tenants_response::Tenant {
             {
                 let mut id__ = None;
                 let mut name__ = None;
+                let mut version__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -1060,11 +1452,20 @@ impl<'de> serde::Deserialize<'de> for list_tenants_response::Tenant {
                             }
                             name__ = Some(map.next_value()?);
                         }
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(list_tenants_response::Tenant {
                     id: id__.unwrap_or_default(),
                     name: name__.unwrap_or_default(),
+                    version: version__.unwrap_or_default(),
                 })
             }
         }
@@ -1085,6 +1486,12 @@ impl serde::Serialize for RemoveItemsRequest {
         if !self.item_ids.is_empty() {
             len += 1;
         }
+        if self.expected_version.is_some() {
+            len += 1;
+        }
+        if self.idempotency_key.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("tenant.v1.RemoveItemsRequest", len)?;
         if !self.tenant_id.is_empty() {
             struct_ser.serialize_field("tenantId", &self.tenant_id)?;
@@ -1092,6 +1499,13 @@ impl serde::Serialize for RemoveItemsRequest {
         if !self.item_ids.is_empty() {
             struct_ser.serialize_field("itemIds", &self.item_ids)?;
         }
+        if let Some(v) = self.expected_version.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("expectedVersion", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.idempotency_key.as_ref() {
+            struct_ser.serialize_field("idempotencyKey", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1106,12 +1520,18 @@ impl<'de> serde::Deserialize<'de> for RemoveItemsRequest {
             "tenantId",
             "item_ids",
             "itemIds",
+            "expected_version",
+            "expectedVersion",
+            "idempotency_key",
+            "idempotencyKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             TenantId,
             ItemIds,
+            ExpectedVersion,
+            IdempotencyKey,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1135,6 +1555,8 @@ impl<'de> serde::Deserialize<'de> for RemoveItemsRequest {
                         match value {
                             "tenantId" | "tenant_id" => Ok(GeneratedField::TenantId),
                             "itemIds" | "item_ids" => Ok(GeneratedField::ItemIds),
+                            "expectedVersion" | "expected_version" => Ok(GeneratedField::ExpectedVersion),
+                            "idempotencyKey" | "idempotency_key" => Ok(GeneratedField::IdempotencyKey),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1156,6 +1578,8 @@ impl<'de> serde::Deserialize<'de> for RemoveItemsRequest {
             {
                 let mut tenant_id__ = None;
                 let mut item_ids__ = None;
+                let mut expected_version__ = None;
+                let mut idempotency_key__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::TenantId => {
@@ -1170,11 +1594,27 @@ impl<'de> serde::Deserialize<'de> for RemoveItemsRequest {
                             }
                             item_ids__ = Some(map.next_value()?);
                         }
+                        GeneratedField::ExpectedVersion => {
+                            if expected_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expectedVersion"));
+                            }
+                            expected_version__ = 
+                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::IdempotencyKey => {
+                            if idempotency_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("idempotencyKey"));
+                            }
+                            idempotency_key__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(RemoveItemsRequest {
                     tenant_id: tenant_id__.unwrap_or_default(),
                     item_ids: item_ids__.unwrap_or_default(),
+                    expected_version: expected_version__,
+                    idempotency_key: idempotency_key__,
                 })
             }
         }
@@ -1188,8 +1628,15 @@ impl serde::Serialize for RemoveItemsResponse {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("tenant.v1.RemoveItemsResponse", len)?;
+        let mut len = 0;
+        if self.version != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("tenant.v1.RemoveItemsResponse", len)?;
+        if self.version != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("version", ToString::to_string(&self.version).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -1200,10 +1647,12 @@ impl<'de> serde::Deserialize<'de> for RemoveItemsResponse {
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "version",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            Version,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1224,7 +1673,10 @@ impl<'de> serde::Deserialize<'de> for RemoveItemsResponse {
                     where
                         E: serde::de::Error,
                     {
-                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                        match value {
+                            "version" => Ok(GeneratedField::Version),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -1242,13 +1694,535 @@ impl<'de> serde::Deserialize<'de> for RemoveItemsResponse {
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                let mut version__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
                 }
                 Ok(RemoveItemsResponse {
+                    version: version__.unwrap_or_default(),
                 })
             }
         }
         deserializer.deserialize_struct("tenant.v1.RemoveItemsResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for SearchRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.query.is_empty() {
+            len += 1;
+        }
+        if self.page_size != 0 {
+            len += 1;
+        }
+        if !self.page_token.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("tenant.v1.SearchRequest", len)?;
+        if !self.query.is_empty() {
+            struct_ser.serialize_field("query", &self.query)?;
+        }
+        if self.page_size != 0 {
+            struct_ser.serialize_field("pageSize", &self.page_size)?;
+        }
+        if !self.page_token.is_empty() {
+            struct_ser.serialize_field("pageToken", &self.page_token)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SearchRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "query",
+            "page_size",
+            "pageSize",
+            "page_token",
+            "pageToken",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Query,
+            PageSize,
+            PageToken,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "query" => Ok(GeneratedField::Query),
+                            "pageSize" | "page_size" => Ok(GeneratedField::PageSize),
+                            "pageToken" | "page_token" => Ok(GeneratedField::PageToken),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SearchRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct tenant.v1.SearchRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<SearchRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut query__ = None;
+                let mut page_size__ = None;
+                let mut page_token__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Query => {
+                            if query__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("query"));
+                            }
+                            query__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::PageSize => {
+                            if page_size__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pageSize"));
+                            }
+                            page_size__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PageToken => {
+                            if page_token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pageToken"));
+                            }
+                            page_token__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(SearchRequest {
+                    query: query__.unwrap_or_default(),
+                    page_size: page_size__.unwrap_or_default(),
+                    page_token: page_token__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("tenant.v1.SearchRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SearchResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.hits.is_empty() {
+            len += 1;
+        }
+        if !self.next_page_token.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("tenant.v1.SearchResponse", len)?;
+        if !self.hits.is_empty() {
+            struct_ser.serialize_field("hits", &self.hits)?;
+        }
+        if !self.next_page_token.is_empty() {
+            struct_ser.serialize_field("nextPageToken", &self.next_page_token)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SearchResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "hits",
+            "next_page_token",
+            "nextPageToken",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Hits,
+            NextPageToken,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "hits" => Ok(GeneratedField::Hits),
+                            "nextPageToken" | "next_page_token" => Ok(GeneratedField::NextPageToken),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SearchResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct tenant.v1.SearchResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<SearchResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut hits__ = None;
+                let mut next_page_token__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Hits => {
+                            if hits__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("hits"));
+                            }
+                            hits__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::NextPageToken => {
+                            if next_page_token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextPageToken"));
+                            }
+                            next_page_token__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(SearchResponse {
+                    hits: hits__.unwrap_or_default(),
+                    next_page_token: next_page_token__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("tenant.v1.SearchResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for search_response::Hit {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.tenant_id.is_empty() {
+            len += 1;
+        }
+        if !self.tenant_name.is_empty() {
+            len += 1;
+        }
+        if self.item.is_some() {
+            len += 1;
+        }
+        if self.score != 0. {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("tenant.v1.SearchResponse.Hit", len)?;
+        if !self.tenant_id.is_empty() {
+            struct_ser.serialize_field("tenantId", &self.tenant_id)?;
+        }
+        if !self.tenant_name.is_empty() {
+            struct_ser.serialize_field("tenantName", &self.tenant_name)?;
+        }
+        if let Some(v) = self.item.as_ref() {
+            struct_ser.serialize_field("item", v)?;
+        }
+        if self.score != 0. {
+            struct_ser.serialize_field("score", &self.score)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for search_response::Hit {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "tenant_id",
+            "tenantId",
+            "tenant_name",
+            "tenantName",
+            "item",
+            "score",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TenantId,
+            TenantName,
+            Item,
+            Score,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tenantId" | "tenant_id" => Ok(GeneratedField::TenantId),
+                            "tenantName" | "tenant_name" => Ok(GeneratedField::TenantName),
+                            "item" => Ok(GeneratedField::Item),
+                            "score" => Ok(GeneratedField::Score),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = search_response::Hit;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct tenant.v1.SearchResponse.Hit")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<search_response::Hit, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut tenant_id__ = None;
+                let mut tenant_name__ = None;
+                let mut item__ = None;
+                let mut score__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::TenantId => {
+                            if tenant_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tenantId"));
+                            }
+                            tenant_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::TenantName => {
+                            if tenant_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tenantName"));
+                            }
+                            tenant_name__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Item => {
+                            if item__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("item"));
+                            }
+                            item__ = map.next_value()?;
+                        }
+                        GeneratedField::Score => {
+                            if score__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("score"));
+                            }
+                            score__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(search_response::Hit {
+                    tenant_id: tenant_id__.unwrap_or_default(),
+                    tenant_name: tenant_name__.unwrap_or_default(),
+                    item: item__,
+                    score: score__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("tenant.v1.SearchResponse.Hit", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for search_response::Item {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if self.price != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("tenant.v1.SearchResponse.Item", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if self.price != 0 {
+            struct_ser.serialize_field("price", &self.price)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for search_response::Item {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "name",
+            "price",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            Name,
+            Price,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "name" => Ok(GeneratedField::Name),
+                            "price" => Ok(GeneratedField::Price),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = search_response::Item;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct tenant.v1.SearchResponse.Item")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<search_response::Item, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut name__ = None;
+                let mut price__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Price => {
+                            if price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("price"));
+                            }
+                            price__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(search_response::Item {
+                    id: id__.unwrap_or_default(),
+                    name: name__.unwrap_or_default(),
+                    price: price__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("tenant.v1.SearchResponse.Item", FIELDS, GeneratedVisitor)
+    }
+}
@@ -1,6 +1,10 @@
+/// 集約テーブルのマイグレーション関連のモジュール
+pub mod migration;
 /// テーブルモデル関連のモジュール
 pub mod model;
 /// データの永続性とデータストアとのインタラクションを管理するモジュール
 pub mod persistence;
 /// リポジトリ関連のモジュール
 pub mod repository;
+/// イベントのペイロードを最新のバージョンに変換するモジュール
+pub(crate) mod upcaster;
@@ -1,140 +1,168 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::str::FromStr;
 
 use anyhow::Context as _;
-use aws_sdk_dynamodb::types::AttributeValue;
+use event_store::VersionedPayload;
 use serde::{Deserialize, Serialize};
 
 /// 集約のテーブルモデル
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
-pub(crate) struct AggregateModel {
-    id: String,
-    version: u64,
-    payload: AggregatePayload,
-}
-
-impl AggregateModel {
-    pub(crate) fn id(&self) -> &str {
-        &self.id
-    }
-
-    pub(crate) fn version(&self) -> u64 {
-        self.version
-    }
+pub(crate) type AggregateModel = event_store::AggregateModel<AggregatePayload>;
 
-    pub(crate) fn payload(&self) -> &AggregatePayload {
-        &self.payload
-    }
+/// 集約からテーブルモデルに変換する
+pub(crate) fn from_aggregate(
+    aggregate: kernel::command::model::aggregate::Aggregate,
+) -> AggregateModel {
+    AggregateModel::new(
+        aggregate.id().to_string(),
+        aggregate.version(),
+        aggregate.into(),
+    )
+}
 
-    pub(crate) fn version_attribute_value<T: From<serde_dynamo::AttributeValue>>(
-        &self,
-    ) -> Result<T, anyhow::Error> {
-        Ok(serde_dynamo::to_attribute_value(self.version)?)
-    }
+/// テーブルモデルから集約に変換する
+pub(crate) fn into_aggregate(
+    model: AggregateModel,
+) -> Result<kernel::command::model::aggregate::Aggregate, anyhow::Error> {
+    use kernel::command::model::aggregate::Aggregate;
+    use kernel::command::model::entity::{Item, Tenant};
+    use kernel::id::Id;
 
-    pub(crate) fn payload_attribute_value<T: From<serde_dynamo::AttributeValue>>(
-        &self,
-    ) -> Result<T, anyhow::Error> {
-        Ok(serde_dynamo::to_attribute_value(&self.payload)?)
-    }
+    let id = model.id().to_string();
+    let version = model.version();
+    let (items, is_order_placed) = match model.into_payload() {
+        AggregatePayload::V1 {
+            items,
+            is_order_placed,
+        } => {
+            let items: HashMap<Id<Tenant>, HashMap<Id<Item>, u32>> = items
+                .iter()
+                .map(|(tenant_id, quantity_by_item_id)| {
+                    let tenant_id: Id<Tenant> = Id::from_str(tenant_id)
+                        .with_context(|| format!("parse tenant id: {tenant_id}"))?;
+                    let quantity_by_item_id: HashMap<Id<Item>, u32> = quantity_by_item_id
+                        .iter()
+                        .map(|(item_id, quantity)| {
+                            let item_id: Id<Item> = Id::from_str(item_id)
+                                .with_context(|| format!("parse item id: {item_id}"))?;
+                            Ok::<_, anyhow::Error>((item_id, *quantity))
+                        })
+                        .collect::<Result<_, _>>()
+                        .with_context(|| {
+                            format!("transform quantity_by_item_id: {quantity_by_item_id:?}")
+                        })?;
+                    Ok::<_, anyhow::Error>((tenant_id, quantity_by_item_id))
+                })
+                .collect::<Result<_, _>>()
+                .with_context(|| format!("transform items: {items:?}"))?;
+            (items, is_order_placed)
+        }
+        AggregatePayload::V2 {
+            items,
+            is_order_placed,
+        } => {
+            let mut quantity_by_item_id_by_tenant_id: HashMap<Id<Tenant>, HashMap<Id<Item>, u32>> =
+                HashMap::new();
+            for item in items {
+                let crate::command::model::Item::V1 {
+                    tenant_id,
+                    item_id,
+                    quantity,
+                } = item;
+                let tenant_id: Id<Tenant> = Id::from_str(&tenant_id)
+                    .with_context(|| format!("parse tenant id: {tenant_id}"))?;
+                let item_id: Id<Item> =
+                    Id::from_str(&item_id).with_context(|| format!("parse item id: {item_id}"))?;
+                quantity_by_item_id_by_tenant_id
+                    .entry(tenant_id)
+                    .or_default()
+                    .insert(item_id, quantity);
+            }
+            (quantity_by_item_id_by_tenant_id, is_order_placed)
+        }
+    };
+    let aggregate = Aggregate::new(
+        Id::from_str(&id).with_context(|| format!("parse aggregate id: {id}"))?,
+        items,
+        is_order_placed,
+        version,
+    );
+    Ok(aggregate)
 }
 
-impl From<kernel::command::model::aggregate::Aggregate> for AggregateModel {
-    fn from(value: kernel::command::model::aggregate::Aggregate) -> Self {
-        Self {
-            id: value.id().to_string(),
-            version: value.version(),
-            payload: value.into(),
-        }
-    }
+/// 集約テーブルのペイロード
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub(crate) enum AggregatePayload {
+    V1 {
+        items: HashMap<String, HashMap<String, u32>>,
+        is_order_placed: bool,
+    },
+    V2 {
+        items: Vec<Item>,
+        is_order_placed: bool,
+    },
 }
 
-impl TryFrom<AggregateModel> for kernel::command::model::aggregate::Aggregate {
-    type Error = anyhow::Error;
+impl VersionedPayload for AggregatePayload {
+    fn is_latest(&self) -> bool {
+        matches!(self, Self::V2 { .. })
+    }
 
-    fn try_from(
-        AggregateModel {
-            id,
-            version,
-            payload,
-        }: AggregateModel,
-    ) -> Result<Self, Self::Error> {
-        use kernel::command::model::aggregate::Aggregate;
-        use kernel::command::model::entity::{Item, Tenant};
-        use kernel::id::Id;
-
-        let (items, is_order_placed) = match payload {
-            AggregatePayload::V1 {
+    fn into_latest(self) -> Self {
+        match self {
+            Self::V1 {
                 items,
                 is_order_placed,
             } => {
-                let items: HashMap<Id<Tenant>, HashMap<Id<Item>, u32>> = items
-                    .iter()
-                    .map(|(tenant_id, quantity_by_item_id)| {
-                        let tenant_id: Id<Tenant> = Id::from_str(tenant_id)
-                            .with_context(|| format!("parse tenant id: {tenant_id}"))?;
-                        let quantity_by_item_id: HashMap<Id<Item>, u32> = quantity_by_item_id
-                            .iter()
-                            .map(|(item_id, quantity)| {
-                                let item_id: Id<Item> = Id::from_str(item_id)
-                                    .with_context(|| format!("parse item id: {item_id}"))?;
-                                Ok::<_, Self::Error>((item_id, *quantity))
+                let mut items: Vec<Item> = items
+                    .into_iter()
+                    .flat_map(|(tenant_id, quantity_by_item_id)| {
+                        quantity_by_item_id
+                            .into_iter()
+                            .map(move |(item_id, quantity)| Item::V1 {
+                                tenant_id: tenant_id.clone(),
+                                item_id,
+                                quantity,
                             })
-                            .collect::<Result<_, _>>()
-                            .with_context(|| {
-                                format!("transform quantity_by_item_id: {quantity_by_item_id:?}")
-                            })?;
-                        Ok::<_, Self::Error>((tenant_id, quantity_by_item_id))
                     })
-                    .collect::<Result<_, _>>()
-                    .with_context(|| format!("transform items: {items:?}"))?;
-                (items, is_order_placed)
+                    .collect();
+                items.sort();
+                Self::V2 {
+                    items,
+                    is_order_placed,
+                }
             }
-        };
-        let aggregate = Aggregate::new(
-            Id::from_str(&id).with_context(|| format!("parse aggregate id: {id}"))?,
-            items,
-            is_order_placed,
-            version,
-        );
-        Ok(aggregate)
-    }
-}
-impl<S> TryFrom<AggregateModel> for HashMap<String, AttributeValue, S>
-where
-    S: std::hash::BuildHasher,
-    HashMap<String, AttributeValue, S>: From<serde_dynamo::Item>,
-{
-    type Error = anyhow::Error;
-
-    fn try_from(value: AggregateModel) -> Result<Self, Self::Error> {
-        Ok(serde_dynamo::to_item(value)?)
+            payload @ Self::V2 { .. } => payload,
+        }
     }
 }
 
-/// 集約テーブルのペイロード
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
-pub(crate) enum AggregatePayload {
+/// カートに追加された商品
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Item {
     V1 {
-        items: HashMap<String, HashMap<String, u32>>,
-        is_order_placed: bool,
+        tenant_id: String,
+        item_id: String,
+        quantity: u32,
     },
 }
 
 impl From<kernel::command::model::aggregate::Aggregate> for AggregatePayload {
     fn from(value: kernel::command::model::aggregate::Aggregate) -> Self {
-        let items: HashMap<String, HashMap<String, u32>> = value
+        let mut items: Vec<Item> = value
             .items()
             .iter()
-            .map(|(tenant_id, quantity_by_item_id)| {
-                let quantity_by_item_id: HashMap<String, u32> = quantity_by_item_id
+            .flat_map(|(tenant_id, quantity_by_item_id)| {
+                quantity_by_item_id
                     .iter()
-                    .map(|(item_id, quantity)| (item_id.to_string(), *quantity))
-                    .collect();
-                (tenant_id.to_string(), quantity_by_item_id)
+                    .map(|(item_id, quantity)| Item::V1 {
+                        tenant_id: tenant_id.to_string(),
+                        item_id: item_id.to_string(),
+                        quantity: *quantity,
+                    })
             })
             .collect();
-        Self::V1 {
+        items.sort();
+        Self::V2 {
             items,
             is_order_placed: value.is_order_placed(),
         }
@@ -142,67 +170,62 @@ impl From<kernel::command::model::aggregate::Aggregate> for AggregatePayload {
 }
 
 /// イベントストアのテーブルモデル
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
-pub struct EventStoreModel {
-    id: u64,
-    aggregate_id: String,
-    payload: EventPayload,
-    metadata: std::collections::HashMap<String, String>,
-}
+pub type EventStoreModel = event_store::EventStoreModel<EventPayload>;
 
-impl EventStoreModel {
-    #[must_use]
-    pub(crate) fn new(id: u64, aggregate_id: String, payload: EventPayload) -> Self {
-        Self {
-            id,
-            aggregate_id,
-            payload,
-            metadata: std::collections::HashMap::new(),
-        }
-    }
-
-    #[must_use]
-    pub fn aggregate_id(&self) -> &str {
-        &self.aggregate_id
-    }
-
-    #[must_use]
-    pub fn payload(&self) -> &EventPayload {
-        &self.payload
-    }
+/// イベントストアのペイロード
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EventPayload {
+    CreatedV1,
+    ItemAddedV1 {
+        tenant_id: String,
+        item_id: String,
+    },
+    ItemRemovedV1 {
+        tenant_id: String,
+        item_id: String,
+    },
+    /// 注文を確定した時点のカートの商品を持たない古いペイロード
+    OrderPlacedV1,
+    OrderPlacedV2 {
+        items: Vec<Item>,
+    },
+}
 
+impl EventPayload {
+    /// 注文が確定したイベントであるか
     #[must_use]
-    pub fn metadata(&self) -> &std::collections::HashMap<String, String> {
-        &self.metadata
+    pub fn is_order_placed(&self) -> bool {
+        matches!(self, Self::OrderPlacedV1 | Self::OrderPlacedV2 { .. })
     }
 
+    /// 注文を確定した時点のカートの商品を注文サービスに送る商品に変換する
+    ///
+    /// 商品を持たない古いペイロードと注文が確定したイベント以外は `None` を返す
+    #[cfg(feature = "proto")]
     #[must_use]
-    pub(crate) fn metadata_mut(&mut self) -> &mut std::collections::HashMap<String, String> {
-        &mut self.metadata
-    }
-}
-
-impl<S> TryFrom<EventStoreModel> for HashMap<String, AttributeValue, S>
-where
-    S: std::hash::BuildHasher,
-    HashMap<String, AttributeValue, S>: From<serde_dynamo::Item>,
-{
-    type Error = anyhow::Error;
-
-    fn try_from(value: EventStoreModel) -> Result<Self, Self::Error> {
-        serde_dynamo::to_item(value).with_context(|| "try from EventStoreModel")
+    pub fn order_items(&self) -> Option<Vec<proto::order::v1::Item>> {
+        let Self::OrderPlacedV2 { items } = self else {
+            return None;
+        };
+        Some(
+            items
+                .iter()
+                .map(
+                    |Item::V1 {
+                         tenant_id,
+                         item_id,
+                         quantity,
+                     }| proto::order::v1::Item {
+                        tenant_id: tenant_id.clone(),
+                        item_id: item_id.clone(),
+                        quantity: *quantity,
+                    },
+                )
+                .collect(),
+        )
     }
 }
 
-/// イベントストアのペイロード
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub enum EventPayload {
-    CreatedV1,
-    ItemAddedV1 { tenant_id: String, item_id: String },
-    ItemRemovedV1 { tenant_id: String, item_id: String },
-    OrderPlacedV1,
-}
-
 impl From<kernel::command::event::Event> for EventPayload {
     fn from(value: kernel::command::event::Event) -> Self {
         match value {
@@ -219,47 +242,229 @@ impl From<kernel::command::event::Event> for EventPayload {
                     item_id: item_id.to_string(),
                 }
             }
-            kernel::command::event::Event::OrderPlaced => EventPayload::OrderPlacedV1,
+            kernel::command::event::Event::OrderPlaced { items } => EventPayload::OrderPlacedV2 {
+                items: items
+                    .into_iter()
+                    .flat_map(|(tenant_id, quantity_by_item_id)| {
+                        quantity_by_item_id
+                            .into_iter()
+                            .map(move |(item_id, quantity)| Item::V1 {
+                                tenant_id: tenant_id.to_string(),
+                                item_id: item_id.to_string(),
+                                quantity,
+                            })
+                    })
+                    .collect(),
+            },
         }
     }
 }
 
-/// イベントストアの最新のIDを記録するテーブルのモデル
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub(crate) struct EventSequenceModel {
-    aggregate_id: String,
-    latest_event_id: u64,
+impl TryFrom<EventPayload> for kernel::command::event::Event {
+    type Error = anyhow::Error;
+
+    fn try_from(value: EventPayload) -> Result<Self, Self::Error> {
+        use kernel::command::event::Event;
+
+        let event = match value {
+            EventPayload::CreatedV1 => Event::Created,
+            EventPayload::ItemAddedV1 { tenant_id, item_id } => Event::ItemAdded {
+                tenant_id: tenant_id
+                    .parse()
+                    .with_context(|| format!("parse tenant id: {tenant_id}"))?,
+                item_id: item_id
+                    .parse()
+                    .with_context(|| format!("parse item id: {item_id}"))?,
+            },
+            EventPayload::ItemRemovedV1 { tenant_id, item_id } => Event::ItemRemoved {
+                tenant_id: tenant_id
+                    .parse()
+                    .with_context(|| format!("parse tenant id: {tenant_id}"))?,
+                item_id: item_id
+                    .parse()
+                    .with_context(|| format!("parse item id: {item_id}"))?,
+            },
+            // NOTE: 古いペイロードは注文を確定した時点のカートの商品を持たないが、集約の復元には利用しない
+            EventPayload::OrderPlacedV1 => Event::OrderPlaced {
+                items: BTreeMap::new(),
+            },
+            EventPayload::OrderPlacedV2 { items } => {
+                let mut quantity_by_tenant_id: BTreeMap<_, BTreeMap<_, _>> = BTreeMap::new();
+                for Item::V1 {
+                    tenant_id,
+                    item_id,
+                    quantity,
+                } in items
+                {
+                    quantity_by_tenant_id
+                        .entry(
+                            tenant_id
+                                .parse()
+                                .with_context(|| format!("parse tenant id: {tenant_id}"))?,
+                        )
+                        .or_default()
+                        .insert(
+                            item_id
+                                .parse()
+                                .with_context(|| format!("parse item id: {item_id}"))?,
+                            quantity,
+                        );
+                }
+                Event::OrderPlaced {
+                    items: quantity_by_tenant_id,
+                }
+            }
+        };
+        Ok(event)
+    }
 }
 
-impl EventSequenceModel {
-    #[must_use]
-    pub(crate) fn new(aggregate_id: String, latest_event_id: u64) -> Self {
-        Self {
-            aggregate_id,
-            latest_event_id,
+/// アウトボックスのテーブルモデル
+pub type OutboxModel = event_store::OutboxModel<EventPayload>;
+
+/// イベントのうち他のサービスに送信する統合イベントをアウトボックスのテーブルモデルに変換する
+///
+/// `version` はイベントを適用した後の集約のバージョン
+pub(crate) fn into_outbox(
+    aggregate_id: &str,
+    version: u64,
+    events: &[EventPayload],
+) -> Result<Vec<OutboxModel>, anyhow::Error> {
+    // NOTE: イベントを適用した後の集約のバージョンから、追加するイベントのイベントIDを求める
+    let first_event_id = u64::try_from(events.len())
+        .ok()
+        .and_then(|len| version.checked_sub(len))
+        .with_context(|| format!("invalid aggregate version: {version}"))?;
+    Ok(events
+        .iter()
+        .zip(first_event_id..)
+        .filter(|(event, _)| event.is_order_placed())
+        .map(|(event, event_id)| {
+            OutboxModel::new(aggregate_id.to_string(), event_id, event.clone())
+        })
+        .collect())
+}
+
+/// 冪等キーからテーブルモデルに変換する
+pub(crate) fn from_idempotency(
+    idempotency: &kernel::command::model::idempotency::Idempotency,
+) -> event_store::IdempotencyKeyModel {
+    event_store::IdempotencyKeyModel::new(
+        idempotency.key().to_string(),
+        idempotency.aggregate_id().to_string(),
+        idempotency.version(),
+    )
+}
+
+/// テーブルモデルから冪等キーに変換する
+pub(crate) fn into_idempotency(
+    model: &event_store::IdempotencyKeyModel,
+) -> Result<kernel::command::model::idempotency::Idempotency, anyhow::Error> {
+    let aggregate_id = model.aggregate_id();
+    Ok(kernel::command::model::idempotency::Idempotency::new(
+        model.key().to_string(),
+        aggregate_id
+            .parse()
+            .with_context(|| format!("parse aggregate id: {aggregate_id}"))?,
+        model.version(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use kernel::command::event::Event;
+    use kernel::id::Id;
+
+    use super::{EventPayload, Item};
+
+    type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    fn item(tenant_id: &str, item_id: &str, quantity: u32) -> Item {
+        Item::V1 {
+            tenant_id: tenant_id.to_string(),
+            item_id: item_id.to_string(),
+            quantity,
         }
     }
 
-    #[must_use]
-    pub(crate) fn latest_event_id(&self) -> u64 {
-        self.latest_event_id
+    #[test]
+    fn test_order_placed_round_trip() -> Result<(), Error> {
+        let (tenant_1, tenant_2) = (Id::generate(), Id::generate());
+        let (item_1, item_2, item_3) = (Id::generate(), Id::generate(), Id::generate());
+        let event = Event::OrderPlaced {
+            items: BTreeMap::from([
+                (
+                    tenant_1.clone(),
+                    BTreeMap::from([(item_1.clone(), 1), (item_2.clone(), 2)]),
+                ),
+                (tenant_2.clone(), BTreeMap::from([(item_3.clone(), 3)])),
+            ]),
+        };
+
+        let payload = EventPayload::from(event.clone());
+        let EventPayload::OrderPlacedV2 { items } = &payload else {
+            panic!("expected OrderPlacedV2: {payload:?}");
+        };
+        let mut items = items.clone();
+        items.sort();
+        let mut expected = vec![
+            item(&tenant_1.to_string(), &item_1.to_string(), 1),
+            item(&tenant_1.to_string(), &item_2.to_string(), 2),
+            item(&tenant_2.to_string(), &item_3.to_string(), 3),
+        ];
+        expected.sort();
+        assert_eq!(items, expected);
+        assert_eq!(Event::try_from(payload)?, event);
+
+        let empty = Event::OrderPlaced {
+            items: BTreeMap::new(),
+        };
+        assert_eq!(Event::try_from(EventPayload::from(empty.clone()))?, empty);
+        Ok(())
     }
 
-    pub(crate) fn latest_event_id_attribute_value<T: From<serde_dynamo::AttributeValue>>(
-        &self,
-    ) -> Result<T, anyhow::Error> {
-        Ok(serde_dynamo::to_attribute_value(self.latest_event_id)?)
+    #[test]
+    fn test_order_placed_v1_decodes_to_empty_items() -> Result<(), Error> {
+        // NOTE: 商品を持たない古いペイロードは DynamoDB に文字列として保存されている
+        let payload: EventPayload = serde_dynamo::from_attribute_value(
+            aws_sdk_dynamodb::types::AttributeValue::S("OrderPlacedV1".to_string()),
+        )?;
+        assert_eq!(payload, EventPayload::OrderPlacedV1);
+        assert!(payload.is_order_placed());
+        assert_eq!(
+            Event::try_from(payload)?,
+            Event::OrderPlaced {
+                items: BTreeMap::new(),
+            }
+        );
+        Ok(())
     }
-}
 
-impl<S> TryFrom<EventSequenceModel> for HashMap<String, AttributeValue, S>
-where
-    S: std::hash::BuildHasher,
-    HashMap<String, AttributeValue, S>: From<serde_dynamo::Item>,
-{
-    type Error = anyhow::Error;
+    #[test]
+    fn test_order_placed_v2_rejects_invalid_id() {
+        let payload = EventPayload::OrderPlacedV2 {
+            items: vec![item("invalid", "invalid", 1)],
+        };
+        assert!(Event::try_from(payload).is_err());
+    }
 
-    fn try_from(value: EventSequenceModel) -> Result<Self, Self::Error> {
-        serde_dynamo::to_item(value).with_context(|| "try from EventSequenceModel")
+    #[cfg(feature = "proto")]
+    #[test]
+    fn test_order_items() {
+        let payload = EventPayload::OrderPlacedV2 {
+            items: vec![item("tenant", "item", 2)],
+        };
+        assert_eq!(
+            payload.order_items(),
+            Some(vec![proto::order::v1::Item {
+                tenant_id: "tenant".to_string(),
+                item_id: "item".to_string(),
+                quantity: 2,
+            }])
+        );
+        assert_eq!(EventPayload::OrderPlacedV1.order_items(), None);
+        assert_eq!(EventPayload::CreatedV1.order_items(), None);
     }
 }
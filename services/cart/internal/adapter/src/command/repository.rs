@@ -1,21 +1,90 @@
-use anyhow::Context as _;
-use aws_sdk_dynamodb::types::{Put, TransactWriteItem};
-
-use crate::command::model::EventSequenceModel;
-use crate::{AGGREGATE_TABLE_NAME, EVENT_SEQUENCE_TABLE_NAME, EVENT_STORE_TABLE_NAME};
+use std::sync::Arc;
 
-use super::model::{AggregateModel, EventStoreModel};
+use anyhow::Context as _;
+use event_store::{AnyEventStore, EventStore as _, TableNames, UpcasterRegistry};
+
+use crate::command::model::{AggregatePayload, EventPayload};
+use crate::command::persistence::EventStore;
+use crate::command::upcaster::upcasters;
+use crate::{
+    AGGREGATE_TABLE_NAME, EVENT_SEQUENCE_TABLE_NAME, EVENT_STORE_TABLE_NAME,
+    IDEMPOTENCY_KEY_TABLE_NAME, OUTBOX_TABLE_NAME,
+};
+
+use super::model::{
+    from_aggregate, from_idempotency, into_aggregate, into_idempotency, into_outbox,
+};
+
+pub use event_store::SnapshotPolicy;
+
+pub(crate) const TABLE_NAMES: TableNames = TableNames {
+    aggregate: AGGREGATE_TABLE_NAME,
+    event_store: EVENT_STORE_TABLE_NAME,
+    event_sequence: EVENT_SEQUENCE_TABLE_NAME,
+    idempotency_key: IDEMPOTENCY_KEY_TABLE_NAME,
+    outbox: OUTBOX_TABLE_NAME,
+};
 
 /// コマンド操作を行うリポジトリ
 #[derive(Debug, Clone)]
 pub struct CommandRepository {
-    dynamodb: aws_sdk_dynamodb::Client,
+    event_store: AnyEventStore<AggregatePayload, EventPayload>,
+    upcasters: Arc<UpcasterRegistry<EventPayload>>,
+    outbox: bool,
 }
 
 impl CommandRepository {
     #[must_use]
-    pub fn new(dynamodb: aws_sdk_dynamodb::Client) -> Self {
-        Self { dynamodb }
+    pub fn new(event_store: EventStore) -> Self {
+        Self {
+            event_store: event_store.0,
+            upcasters: Arc::new(upcasters()),
+            outbox: false,
+        }
+    }
+
+    /// スナップショットを保存する方針を設定する
+    #[must_use]
+    pub fn with_snapshot_policy(self, snapshot_policy: SnapshotPolicy) -> Self {
+        Self {
+            event_store: self.event_store.with_snapshot_policy(snapshot_policy),
+            ..self
+        }
+    }
+
+    /// 集約を更新する時に、他のサービスに送信する統合イベントをアウトボックスに保存する
+    ///
+    /// 保存した統合イベントは `outbox-relay` が他のサービスに送信する
+    #[must_use]
+    pub fn with_outbox(self) -> Self {
+        Self {
+            outbox: true,
+            ..self
+        }
+    }
+
+    /// 集約の現在の状態をスナップショットとして保存する
+    ///
+    /// 集約が存在しない場合は `false` を返す
+    ///
+    /// # Errors
+    #[tracing::instrument(skip(self), err, ret)]
+    pub async fn snapshot(
+        &self,
+        id: kernel::id::Id<kernel::command::model::aggregate::Aggregate>,
+    ) -> Result<Result<bool, kernel::command::error::CommandKernelError>, anyhow::Error> {
+        use kernel::command::processor::CommandProcessor as _;
+
+        let aggregate = match self.get(id).await? {
+            Ok(Some(aggregate)) => aggregate,
+            Ok(None) => return Ok(Ok(false)),
+            Err(e) => return Ok(Err(e)),
+        };
+        self.event_store
+            .save_snapshot(from_aggregate(aggregate))
+            .await
+            .with_context(|| "save snapshot")?;
+        Ok(Ok(true))
     }
 }
 
@@ -25,52 +94,27 @@ impl kernel::command::processor::CommandProcessor for CommandRepository {
         &self,
         aggregate: kernel::command::model::aggregate::Aggregate,
         event: kernel::command::event::Event,
+        idempotency: Option<kernel::command::model::idempotency::Idempotency>,
     ) -> Result<Result<(), kernel::command::error::CommandKernelError>, anyhow::Error> {
         if event != kernel::command::event::Event::Created {
             return Err(anyhow::anyhow!("invalid event"));
         }
 
-        let mut transact_items = Vec::new();
-        let sequence = EventSequenceModel::new(aggregate.id().to_string(), 0);
-
-        transact_items.push(put_event(EventStoreModel::new(
-            sequence.latest_event_id(),
-            aggregate.id().to_string(),
-            event.into(),
-        ))?);
-        transact_items.push(
-            TransactWriteItem::builder()
-                .put(
-                    Put::builder()
-                        .table_name(EVENT_SEQUENCE_TABLE_NAME)
-                        .set_item(Some(sequence.try_into()?))
-                        .condition_expression("attribute_not_exists(aggregate_id)")
-                        .build()
-                        .with_context(|| "put event sequence model")?,
-                )
-                .build(),
-        );
-        transact_items.push(
-            TransactWriteItem::builder()
-                .put(
-                    Put::builder()
-                        .table_name(AGGREGATE_TABLE_NAME)
-                        .set_item(Some(AggregateModel::from(aggregate).try_into()?))
-                        .condition_expression("attribute_not_exists(id)")
-                        .build()
-                        .with_context(|| "put aggregate model")?,
-                )
-                .build(),
-        );
-
-        self.dynamodb
-            .transact_write_items()
-            .set_transact_items(Some(transact_items))
-            .send()
+        match self
+            .event_store
+            .create(
+                from_aggregate(aggregate),
+                event.into(),
+                idempotency.iter().map(from_idempotency).collect(),
+            )
             .await
-            .with_context(|| "transact write items")?;
-
-        Ok(Ok(()))
+        {
+            Ok(()) => Ok(Ok(())),
+            Err(event_store::EventStoreError::ConcurrencyConflict) => Ok(Err(
+                kernel::command::error::CommandKernelError::ConcurrencyConflict,
+            )),
+            Err(e) => Err(anyhow::Error::from(e).context("create aggregate")),
+        }
     }
 
     #[tracing::instrument(skip(self), err, ret)]
@@ -84,28 +128,38 @@ impl kernel::command::processor::CommandProcessor for CommandRepository {
         >,
         anyhow::Error,
     > {
-        use aws_sdk_dynamodb::operation::get_item::GetItemError::ResourceNotFoundException;
-        use aws_sdk_dynamodb::types::AttributeValue;
-
-        let result = self
-            .dynamodb
-            .get_item()
-            .table_name(AGGREGATE_TABLE_NAME)
-            .key("id", AttributeValue::S(id.to_string()))
-            .send()
-            .await;
-        if let Err(e) = result {
-            match e.into_service_error() {
-                ResourceNotFoundException(_) => return Ok(Ok(None)),
-                e => return Err(e.into()),
-            }
-        }
-        let Some(item) = result?.item else {
+        use std::collections::HashMap;
+
+        use kernel::command::model::aggregate::Aggregate;
+
+        let Some(aggregate_with_events) = self
+            .event_store
+            .get(&id.to_string())
+            .await
+            .with_context(|| "get aggregate with events")?
+        else {
             return Ok(Ok(None));
         };
-        let aggregate: AggregateModel = serde_dynamo::from_item(item)
-            .with_context(|| "from DynamoDB item to AggregateModel")?;
-        Ok(Ok(Some(aggregate.try_into()?)))
+        let (snapshot, models) = aggregate_with_events.into_parts();
+        let mut aggregate = match snapshot {
+            Some(snapshot) => {
+                into_aggregate(snapshot).with_context(|| "from AggregateModel to Aggregate")?
+            }
+            None => Aggregate::new(id, HashMap::new(), false, 0),
+        };
+        for model in models {
+            let event = self
+                .upcasters
+                .upcast(model.into_payload())
+                .map_err(anyhow::Error::from_boxed)
+                .with_context(|| "upcast EventPayload")?
+                .try_into()
+                .with_context(|| "from EventPayload to Event")?;
+            if let Err(e) = aggregate.apply_event(event) {
+                return Ok(Err(e));
+            }
+        }
+        Ok(Ok(Some(aggregate)))
     }
 
     #[tracing::instrument(skip(self), err, ret)]
@@ -113,9 +167,8 @@ impl kernel::command::processor::CommandProcessor for CommandRepository {
         &self,
         aggregate: kernel::command::model::aggregate::Aggregate,
         events: Vec<kernel::command::event::Event>,
+        idempotency: Option<kernel::command::model::idempotency::Idempotency>,
     ) -> Result<Result<(), kernel::command::error::CommandKernelError>, anyhow::Error> {
-        use aws_sdk_dynamodb::types::{AttributeValue, Update};
-
         if events.is_empty() {
             return Err(anyhow::anyhow!("events is empty"));
         }
@@ -126,112 +179,51 @@ impl kernel::command::processor::CommandProcessor for CommandRepository {
             return Err(anyhow::anyhow!("invalid event"));
         }
 
-        let aggregate_id = AttributeValue::S(aggregate.id().to_string());
-        let sequence: EventSequenceModel = serde_dynamo::from_item(
-            self.dynamodb
-                .get_item()
-                .table_name(EVENT_SEQUENCE_TABLE_NAME)
-                .key("aggregate_id", aggregate_id.clone())
-                .send()
-                .await
-                .with_context(|| "get event sequence item")?
-                .item
-                .with_context(|| "event sequence item not present")?,
-        )
-        .with_context(|| "from DynamoDB item to EventSequenceModel")?;
-
-        let mut transact_items = Vec::new();
-        let mut new_event_id = sequence.latest_event_id();
-        for event in events {
-            new_event_id += 1;
-            transact_items.push(put_event(EventStoreModel::new(
-                new_event_id,
-                aggregate.id().to_string(),
-                event.into(),
-            ))?);
-        }
-        transact_items.push(
-            TransactWriteItem::builder()
-                .update(
-                    Update::builder()
-                        .table_name(EVENT_SEQUENCE_TABLE_NAME)
-                        .key("aggregate_id", aggregate_id.clone())
-                        .expression_attribute_values(
-                            ":current_latest_event_id",
-                            sequence.latest_event_id_attribute_value()?,
-                        )
-                        .expression_attribute_values(
-                            ":new_latest_event_id",
-                            AttributeValue::N(new_event_id.to_string()),
-                        )
-                        .update_expression("SET latest_event_id = :new_latest_event_id")
-                        .condition_expression(
-                            "attribute_exists(aggregate_id) AND latest_event_id = :current_latest_event_id",
-                        )
-                        .build()
-                        .with_context(|| "update event sequence")?,
-                )
-                .build(),
-        );
-        let aggregate: AggregateModel = aggregate.into();
-        transact_items.push(
-            TransactWriteItem::builder()
-                .update(
-                    Update::builder()
-                        .table_name(AGGREGATE_TABLE_NAME)
-                        .key("id", aggregate_id)
-                        .expression_attribute_values(
-                            ":current_version",
-                            serde_dynamo::to_attribute_value(
-                                // NOTE: この時点のAggregateはバージョンが更新されているので1つ前のバージョンを指定する
-                                aggregate
-                                    .version()
-                                    .checked_sub(1)
-                                    .with_context(|| "invalid aggregate version")?,
-                            )
-                            .with_context(|| "AggregateModel to AttributeValue")?,
-                        )
-                        .expression_attribute_values(
-                            ":new_version",
-                            aggregate.version_attribute_value()?,
-                        )
-                        .expression_attribute_values(
-                            ":new_payload",
-                            aggregate.payload_attribute_value()?,
-                        )
-                        .update_expression("SET version = :new_version, payload = :new_payload")
-                        .condition_expression("attribute_exists(id) AND version = :current_version")
-                        .build()
-                        .with_context(|| "update aggregate")?,
-                )
-                .build(),
-        );
-
-        self.dynamodb
-            .transact_write_items()
-            .set_transact_items(Some(transact_items))
-            .send()
+        let events: Vec<EventPayload> = events.into_iter().map(Into::into).collect();
+        let outbox = if self.outbox {
+            into_outbox(&aggregate.id().to_string(), aggregate.version(), &events)?
+        } else {
+            Vec::new()
+        };
+        match self
+            .event_store
+            .update(
+                from_aggregate(aggregate),
+                events,
+                idempotency.as_ref().map(from_idempotency),
+                outbox,
+            )
             .await
-            .with_context(|| "transect write items")?;
-
-        Ok(Ok(()))
+        {
+            Ok(()) => Ok(Ok(())),
+            Err(event_store::EventStoreError::ConcurrencyConflict) => Ok(Err(
+                kernel::command::error::CommandKernelError::ConcurrencyConflict,
+            )),
+            Err(e) => Err(anyhow::Error::from(e).context("update aggregate")),
+        }
     }
-}
 
-/// イベントをイベントストアに追加する
-fn put_event(mut event: EventStoreModel) -> Result<TransactWriteItem, anyhow::Error> {
-    observability::aws_dynamodb::inject(event.metadata_mut());
-    let tx = TransactWriteItem::builder()
-        .put(
-            Put::builder()
-                .table_name(EVENT_STORE_TABLE_NAME)
-                .set_item(Some(event.try_into()?))
-                .condition_expression(
-                    "attribute_not_exists(id) AND attribute_not_exists(aggregate_id)",
-                )
-                .build()
-                .with_context(|| "put event store model")?,
-        )
-        .build();
-    Ok(tx)
+    #[tracing::instrument(skip(self), err, ret)]
+    async fn get_idempotency(
+        &self,
+        key: String,
+    ) -> Result<
+        Result<
+            Option<kernel::command::model::idempotency::Idempotency>,
+            kernel::command::error::CommandKernelError,
+        >,
+        anyhow::Error,
+    > {
+        let Some(model) = self
+            .event_store
+            .get_idempotency_key(&key)
+            .await
+            .with_context(|| "get idempotency key")?
+        else {
+            return Ok(Ok(None));
+        };
+        Ok(Ok(Some(into_idempotency(&model).with_context(|| {
+            "from IdempotencyKeyModel to Idempotency"
+        })?)))
+    }
 }
@@ -0,0 +1,139 @@
+use event_store::UpcasterRegistry;
+
+use super::model::EventPayload;
+
+/// イベントのペイロードを最新のバージョンに変換するアップキャスターを登録したレジストリ
+pub(crate) fn upcasters() -> UpcasterRegistry<EventPayload> {
+    // NOTE: 現在は全てのペイロードが最新のバージョンなので、アップキャスターは登録されていない
+    UpcasterRegistry::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use event_store::{Upcaster, UpcasterRegistry};
+
+    use crate::command::model::{EventPayload, EventStoreModel};
+
+    use super::upcasters;
+
+    /// V2のスキーマを想定したイベントストアのテーブルモデル
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+    struct EventStoreModelV2 {
+        id: u64,
+        aggregate_id: String,
+        payload: EventPayloadV2,
+        metadata: std::collections::HashMap<String, String>,
+    }
+
+    /// V2のスキーマを想定したイベントのペイロード
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+    enum EventPayloadV2 {
+        CreatedV1,
+        ItemAddedV1 {
+            tenant_id: String,
+            item_id: String,
+        },
+        ItemAddedV2 {
+            tenant_id: String,
+            item_id: String,
+            quantity: u32,
+        },
+        ItemRemovedV1 {
+            tenant_id: String,
+            item_id: String,
+        },
+        OrderPlacedV1,
+    }
+
+    struct ItemAddedV2Upcaster;
+
+    impl Upcaster<EventPayloadV2> for ItemAddedV2Upcaster {
+        fn upcast(
+            &self,
+            payload: EventPayloadV2,
+        ) -> Result<EventPayloadV2, Box<dyn std::error::Error + Send + Sync + 'static>> {
+            match payload {
+                EventPayloadV2::ItemAddedV1 { tenant_id, item_id } => {
+                    Ok(EventPayloadV2::ItemAddedV2 {
+                        tenant_id,
+                        item_id,
+                        quantity: 1,
+                    })
+                }
+                payload => Ok(payload),
+            }
+        }
+    }
+
+    #[test]
+    fn test_upcast_v1_rows_into_v2_ok() -> anyhow::Result<()> {
+        struct TestCase {
+            name: &'static str,
+            model: EventStoreModel,
+            expected: EventPayloadV2,
+        }
+
+        let tests = [
+            TestCase {
+                name: "ItemAddedV1のレコードは数量1のItemAddedV2に変換される",
+                model: EventStoreModel::new(
+                    1,
+                    "aggregate".to_string(),
+                    EventPayload::ItemAddedV1 {
+                        tenant_id: "tenant".to_string(),
+                        item_id: "item".to_string(),
+                    },
+                ),
+                expected: EventPayloadV2::ItemAddedV2 {
+                    tenant_id: "tenant".to_string(),
+                    item_id: "item".to_string(),
+                    quantity: 1,
+                },
+            },
+            TestCase {
+                name: "変換対象ではないレコードはそのまま返る",
+                model: EventStoreModel::new(
+                    2,
+                    "aggregate".to_string(),
+                    EventPayload::OrderPlacedV1,
+                ),
+                expected: EventPayloadV2::OrderPlacedV1,
+            },
+        ];
+        let registry = UpcasterRegistry::new().register(ItemAddedV2Upcaster);
+        for TestCase {
+            name,
+            model,
+            expected,
+        } in tests
+        {
+            let item: serde_dynamo::Item = serde_dynamo::to_item(model)?;
+            let model: EventStoreModelV2 = serde_dynamo::from_item(item)?;
+            assert_eq!(
+                registry
+                    .upcast(model.payload)
+                    .map_err(anyhow::Error::from_boxed)?,
+                expected,
+                "{name}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_upcast_latest_payload_ok() -> anyhow::Result<()> {
+        let payload = EventPayload::ItemRemovedV1 {
+            tenant_id: "tenant".to_string(),
+            item_id: "item".to_string(),
+        };
+        assert_eq!(
+            upcasters()
+                .upcast(payload.clone())
+                .map_err(anyhow::Error::from_boxed)?,
+            payload
+        );
+        Ok(())
+    }
+}
@@ -8,3 +8,5 @@ pub mod query;
 pub(crate) const AGGREGATE_TABLE_NAME: &str = "cart-aggregate";
 pub(crate) const EVENT_SEQUENCE_TABLE_NAME: &str = "cart-event-sequence";
 pub(crate) const EVENT_STORE_TABLE_NAME: &str = "cart-event-store";
+pub(crate) const IDEMPOTENCY_KEY_TABLE_NAME: &str = "cart-idempotency-key";
+pub(crate) const OUTBOX_TABLE_NAME: &str = "cart-outbox";
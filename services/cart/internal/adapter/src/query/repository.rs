@@ -1,14 +1,18 @@
-use crate::AGGREGATE_TABLE_NAME;
+use crate::command::model::from_aggregate;
+use crate::command::persistence::EventStore;
+use crate::command::repository::CommandRepository;
 
 #[derive(Debug, Clone)]
 pub struct QueryRepository {
-    dynamodb: aws_sdk_dynamodb::Client,
+    repository: CommandRepository,
 }
 
 impl QueryRepository {
     #[must_use]
-    pub fn new(dynamodb: aws_sdk_dynamodb::Client) -> Self {
-        Self { dynamodb }
+    pub fn new(event_store: EventStore) -> Self {
+        Self {
+            repository: CommandRepository::new(event_store),
+        }
     }
 }
 
@@ -22,28 +26,73 @@ impl kernel::query::processor::QueryProcessor for QueryRepository {
         anyhow::Error,
     > {
         use anyhow::Context as _;
-        use aws_sdk_dynamodb::operation::get_item::GetItemError::ResourceNotFoundException;
-        use aws_sdk_dynamodb::types::AttributeValue;
-
-        let result = self
-            .dynamodb
-            .get_item()
-            .table_name(AGGREGATE_TABLE_NAME)
-            .key("id", AttributeValue::S(id.to_string()))
-            .send()
-            .await;
-        if let Err(e) = result {
-            match e.into_service_error() {
-                ResourceNotFoundException(_) => return Ok(Ok(None)),
-                e => return Err(e.into()),
-            }
-        }
-        let Some(item) = result?.item else {
+        use kernel::command::processor::CommandProcessor as _;
+
+        // NOTE: スナップショットの頻度を下げてもクエリの結果が遅れないように、スナップショットより後のイベントも適用する
+        let Some(aggregate) = self
+            .repository
+            .get(id.to_string().parse()?)
+            .await?
+            .with_context(|| "replay aggregate")?
+        else {
             return Ok(Ok(None));
         };
         // NOTE: Query領域だがデータベースを分けないのでCommandで定義したテーブルモデルを利用する
-        let aggregate: crate::command::model::AggregateModel = serde_dynamo::from_item(item)
-            .with_context(|| "from DynamoDB item to AggregateModel")?;
-        Ok(Ok(Some(aggregate.try_into()?)))
+        Ok(Ok(Some(super::model::into_cart(&from_aggregate(
+            aggregate,
+        ))?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use kernel::command::command::Command;
+    use kernel::command::model::aggregate::Aggregate;
+    use kernel::command::processor::CommandProcessor as _;
+    use kernel::id::Id;
+    use kernel::query::processor::QueryProcessor as _;
+
+    use super::QueryRepository;
+    use crate::command::persistence::EventStore;
+    use crate::command::repository::{CommandRepository, SnapshotPolicy};
+
+    type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    #[tokio::test]
+    async fn test_get_replay_events_after_snapshot() -> Result<(), Error> {
+        let event_store = EventStore::in_memory();
+        let repository = CommandRepository::new(event_store.clone())
+            .with_snapshot_policy(SnapshotPolicy::OnDemand);
+        let query = QueryRepository::new(event_store);
+
+        let mut aggregate = Aggregate::new(Id::generate(), HashMap::new(), false, 0);
+        let events = aggregate.apply_command(Command::Create, None)?;
+        repository
+            .create(aggregate.clone(), events[0].clone(), None)
+            .await??;
+        for _ in 0..2 {
+            let events = aggregate.apply_command(
+                Command::AddItem {
+                    tenant_id: Id::generate(),
+                    item_id: Id::generate(),
+                },
+                None,
+            )?;
+            repository.update(aggregate.clone(), events, None).await??;
+        }
+
+        let cart = query
+            .get(aggregate.id().to_string().parse()?)
+            .await??
+            .ok_or("cart not found")?;
+        assert_eq!(cart.items().len(), 2);
+        assert_eq!(
+            cart.version(),
+            aggregate.version(),
+            "スナップショットより後のイベントも適用したバージョンを返すので、次のコマンドの期待するバージョンに使える"
+        );
+        Ok(())
     }
 }
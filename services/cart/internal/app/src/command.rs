@@ -4,3 +4,5 @@ pub mod error;
 pub mod model;
 /// ユースケースを定義したモジュール
 pub mod usecase;
+
+pub use command_retry::RetryPolicy;
@@ -4,4 +4,8 @@ use thiserror::Error;
 pub enum CommandUseCaseError {
     #[error("Aggregate not found")]
     AggregateNotFound,
+    #[error("Aggregate was updated concurrently")]
+    ConcurrencyConflict,
+    #[error("Aggregate version mismatch: expected {expected}, actual {actual}")]
+    VersionMismatch { expected: u64, actual: u64 },
 }
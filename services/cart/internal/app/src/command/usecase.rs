@@ -3,50 +3,125 @@ use std::future::Future;
 use kernel::command::command::Command;
 use kernel::command::model::aggregate::Aggregate;
 use kernel::command::model::entity::{Item, Tenant};
+use kernel::command::model::idempotency::Idempotency;
 use kernel::command::processor::CommandProcessor;
 use kernel::id::Id;
 
 use super::error::CommandUseCaseError;
+use super::RetryPolicy;
+
+/// 集約の更新が競合した結果であるか
+fn is_conflict<T>(result: &Result<Result<T, CommandUseCaseError>, anyhow::Error>) -> bool {
+    matches!(result, Ok(Err(CommandUseCaseError::ConcurrencyConflict)))
+}
 
 /// ユースケースのインターフェイス
 pub trait CommandUseCaseExt {
-    /// カートを作成する
+    /// カートを作成し、カートIDと集約のバージョンを返す
+    ///
+    /// `idempotency_key` を指定した場合は同じ冪等キーで作成済みのカートIDと集約のバージョンを返す
     fn create(
         &self,
-    ) -> impl Future<Output = Result<Result<Id<Aggregate>, CommandUseCaseError>, anyhow::Error>> + Send;
+        idempotency_key: Option<String>,
+    ) -> impl Future<Output = Result<Result<(Id<Aggregate>, u64), CommandUseCaseError>, anyhow::Error>>
+           + Send;
 
-    /// カートに商品を追加する
+    /// カートに商品を追加し、集約のバージョンを返す
+    ///
+    /// `expected_version` を指定した場合は集約がそのバージョンのときだけ商品を追加する。
+    /// `idempotency_key` を指定した場合は同じ冪等キーで実行済みのコマンドの集約のバージョンを返す
     fn add_item(
         &self,
         id: Id<Aggregate>,
         tenant_id: Id<Tenant>,
         item_id: Id<Item>,
-    ) -> impl Future<Output = Result<Result<(), CommandUseCaseError>, anyhow::Error>> + Send;
+        expected_version: Option<u64>,
+        idempotency_key: Option<String>,
+    ) -> impl Future<Output = Result<Result<u64, CommandUseCaseError>, anyhow::Error>> + Send;
 
-    /// カートの商品を削除する
+    /// カートの商品を削除し、集約のバージョンを返す
+    ///
+    /// `expected_version` を指定した場合は集約がそのバージョンのときだけ商品を削除する。
+    /// `idempotency_key` を指定した場合は同じ冪等キーで実行済みのコマンドの集約のバージョンを返す
     fn remove_item(
         &self,
         id: Id<Aggregate>,
         tenant_id: Id<Tenant>,
         item_id: Id<Item>,
-    ) -> impl Future<Output = Result<Result<(), CommandUseCaseError>, anyhow::Error>> + Send;
+        expected_version: Option<u64>,
+        idempotency_key: Option<String>,
+    ) -> impl Future<Output = Result<Result<u64, CommandUseCaseError>, anyhow::Error>> + Send;
 
-    /// カートの商品を注文する
+    /// カートの商品を注文し、集約のバージョンを返す
+    ///
+    /// `expected_version` を指定した場合は集約がそのバージョンのときだけ注文する。
+    /// `idempotency_key` を指定した場合は同じ冪等キーで実行済みのコマンドの集約のバージョンを返す
     fn place_order(
         &self,
         id: Id<Aggregate>,
-    ) -> impl Future<Output = Result<Result<(), CommandUseCaseError>, anyhow::Error>> + Send;
+        expected_version: Option<u64>,
+        idempotency_key: Option<String>,
+    ) -> impl Future<Output = Result<Result<u64, CommandUseCaseError>, anyhow::Error>> + Send;
 }
 
 /// ユースケースの実態
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct CommandUseCase<P: CommandProcessor> {
     processor: P,
+    retry_policy: RetryPolicy,
 }
 
 impl<P: CommandProcessor> CommandUseCase<P> {
     pub fn new(processor: P) -> Self {
-        Self { processor }
+        Self {
+            processor,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// 集約の更新が競合した場合にコマンドを再実行する方針を設定する
+    #[must_use]
+    pub fn with_retry_policy(self, retry_policy: RetryPolicy) -> Self {
+        Self {
+            retry_policy,
+            ..self
+        }
+    }
+
+    /// 集約を更新して集約のバージョンを返し、更新が競合した場合は [`CommandUseCaseError::ConcurrencyConflict`] を返す
+    ///
+    /// 冪等キーを指定した場合は、集約のバージョンと一緒にイベントと同じトランザクションで保存する
+    async fn update(
+        &self,
+        aggregate: Aggregate,
+        events: Vec<kernel::command::event::Event>,
+        idempotency_key: Option<String>,
+    ) -> Result<Result<u64, CommandUseCaseError>, anyhow::Error> {
+        let version = aggregate.version();
+        let idempotency =
+            idempotency_key.map(|key| Idempotency::new(key, aggregate.id().clone(), version));
+        match self
+            .processor
+            .update(aggregate, events, idempotency)
+            .await?
+        {
+            Ok(()) => Ok(Ok(version)),
+            Err(kernel::command::error::CommandKernelError::ConcurrencyConflict) => {
+                Ok(Err(CommandUseCaseError::ConcurrencyConflict))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// 冪等キーを指定して実行したコマンドの結果を取得する
+    async fn get_idempotency(
+        &self,
+        key: Option<&String>,
+    ) -> Result<Option<Idempotency>, anyhow::Error> {
+        let Some(key) = key else {
+            return Ok(None);
+        };
+        Ok(self.processor.get_idempotency(key.clone()).await??)
     }
 }
 
@@ -55,17 +130,45 @@ where
     P: CommandProcessor + Send + Sync + 'static,
 {
     #[tracing::instrument(skip(self), err, ret)]
-    async fn create(&self) -> Result<Result<Id<Aggregate>, CommandUseCaseError>, anyhow::Error> {
+    async fn create(
+        &self,
+        idempotency_key: Option<String>,
+    ) -> Result<Result<(Id<Aggregate>, u64), CommandUseCaseError>, anyhow::Error> {
         use anyhow::Context as _;
 
-        let mut aggregate = Aggregate::default();
-        let id = aggregate.id().clone();
-        let event = aggregate
-            .apply_command(Command::Create)?
-            .pop()
-            .with_context(|| "event not present")?;
-        self.processor.create(aggregate, event).await??;
-        Ok(Ok(id))
+        let idempotency_key = idempotency_key.map(|key| format!("create:{key}"));
+        // NOTE: 同じ冪等キーのコマンドと競合した場合は、再実行して先に作成されたカートを返す
+        self.retry_policy
+            .run("create", is_conflict, || {
+                let idempotency_key = idempotency_key.clone();
+                async move {
+                    if let Some(idempotency) =
+                        self.get_idempotency(idempotency_key.as_ref()).await?
+                    {
+                        return Ok(Ok((
+                            idempotency.aggregate_id().clone(),
+                            idempotency.version(),
+                        )));
+                    }
+                    let mut aggregate = Aggregate::default();
+                    let id = aggregate.id().clone();
+                    let event = aggregate
+                        .apply_command(Command::Create, None)?
+                        .pop()
+                        .with_context(|| "event not present")?;
+                    let version = aggregate.version();
+                    let idempotency =
+                        idempotency_key.map(|key| Idempotency::new(key, id.clone(), version));
+                    match self.processor.create(aggregate, event, idempotency).await? {
+                        Ok(()) => Ok(Ok((id, version))),
+                        Err(kernel::command::error::CommandKernelError::ConcurrencyConflict) => {
+                            Ok(Err(CommandUseCaseError::ConcurrencyConflict))
+                        }
+                        Err(e) => Err(e.into()),
+                    }
+                }
+            })
+            .await
     }
 
     #[tracing::instrument(skip(self), err, ret)]
@@ -74,13 +177,47 @@ where
         id: Id<Aggregate>,
         tenant_id: Id<Tenant>,
         item_id: Id<Item>,
-    ) -> Result<Result<(), CommandUseCaseError>, anyhow::Error> {
-        let Some(mut aggregate): Option<Aggregate> = self.processor.get(id).await?? else {
-            return Ok(Err(CommandUseCaseError::AggregateNotFound));
-        };
-        let events = aggregate.apply_command(Command::AddItem { tenant_id, item_id })?;
-        self.processor.update(aggregate, events).await??;
-        Ok(Ok(()))
+        expected_version: Option<u64>,
+        idempotency_key: Option<String>,
+    ) -> Result<Result<u64, CommandUseCaseError>, anyhow::Error> {
+        let idempotency_key = idempotency_key.map(|key| format!("add_item:{id}:{key}"));
+        self.retry_policy
+            .run("add_item", is_conflict, || {
+                let id = id.clone();
+                let idempotency_key = idempotency_key.clone();
+                let tenant_id = tenant_id.clone();
+                let item_id = item_id.clone();
+                async move {
+                    if let Some(idempotency) =
+                        self.get_idempotency(idempotency_key.as_ref()).await?
+                    {
+                        return Ok(Ok(idempotency.version()));
+                    }
+                    let Some(mut aggregate): Option<Aggregate> = self.processor.get(id).await??
+                    else {
+                        return Ok(Err(CommandUseCaseError::AggregateNotFound));
+                    };
+                    let events = match aggregate
+                        .apply_command(Command::AddItem { tenant_id, item_id }, expected_version)
+                    {
+                        Ok(events) => events,
+                        Err(e) => match e {
+                            kernel::command::error::CommandKernelError::VersionMismatch {
+                                expected,
+                                actual,
+                            } => {
+                                return Ok(Err(CommandUseCaseError::VersionMismatch {
+                                    expected,
+                                    actual,
+                                }))
+                            }
+                            _ => return Err(e.into()),
+                        },
+                    };
+                    self.update(aggregate, events, idempotency_key).await
+                }
+            })
+            .await
     }
 
     #[tracing::instrument(skip(self), err, ret)]
@@ -89,32 +226,94 @@ where
         id: Id<Aggregate>,
         tenant_id: Id<Tenant>,
         item_id: Id<Item>,
-    ) -> Result<Result<(), CommandUseCaseError>, anyhow::Error> {
-        let Some(mut aggregate): Option<Aggregate> = self.processor.get(id).await?? else {
-            return Ok(Err(CommandUseCaseError::AggregateNotFound));
-        };
-        let events = match aggregate.apply_command(Command::RemoveItem { tenant_id, item_id }) {
-            Ok(events) => events,
-            Err(e) => match e {
-                kernel::command::error::CommandKernelError::TenantNotFound
-                | kernel::command::error::CommandKernelError::ItemNotFound => return Ok(Ok(())),
-                _ => return Err(e.into()),
-            },
-        };
-        self.processor.update(aggregate, events).await??;
-        Ok(Ok(()))
+        expected_version: Option<u64>,
+        idempotency_key: Option<String>,
+    ) -> Result<Result<u64, CommandUseCaseError>, anyhow::Error> {
+        let idempotency_key = idempotency_key.map(|key| format!("remove_item:{id}:{key}"));
+        self.retry_policy
+            .run("remove_item", is_conflict, || {
+                let id = id.clone();
+                let idempotency_key = idempotency_key.clone();
+                let tenant_id = tenant_id.clone();
+                let item_id = item_id.clone();
+                async move {
+                    if let Some(idempotency) =
+                        self.get_idempotency(idempotency_key.as_ref()).await?
+                    {
+                        return Ok(Ok(idempotency.version()));
+                    }
+                    let Some(mut aggregate): Option<Aggregate> = self.processor.get(id).await??
+                    else {
+                        return Ok(Err(CommandUseCaseError::AggregateNotFound));
+                    };
+                    let events = match aggregate
+                        .apply_command(Command::RemoveItem { tenant_id, item_id }, expected_version)
+                    {
+                        Ok(events) => events,
+                        Err(e) => match e {
+                            kernel::command::error::CommandKernelError::TenantNotFound
+                            | kernel::command::error::CommandKernelError::ItemNotFound => {
+                                return Ok(Ok(aggregate.version()))
+                            }
+                            kernel::command::error::CommandKernelError::VersionMismatch {
+                                expected,
+                                actual,
+                            } => {
+                                return Ok(Err(CommandUseCaseError::VersionMismatch {
+                                    expected,
+                                    actual,
+                                }))
+                            }
+                            _ => return Err(e.into()),
+                        },
+                    };
+                    self.update(aggregate, events, idempotency_key).await
+                }
+            })
+            .await
     }
 
     #[tracing::instrument(skip(self), err, ret)]
     async fn place_order(
         &self,
         id: Id<Aggregate>,
-    ) -> Result<Result<(), CommandUseCaseError>, anyhow::Error> {
-        let Some(mut aggregate): Option<Aggregate> = self.processor.get(id).await?? else {
-            return Ok(Err(CommandUseCaseError::AggregateNotFound));
-        };
-        let events = aggregate.apply_command(Command::PlaceOrder)?;
-        self.processor.update(aggregate, events).await??;
-        Ok(Ok(()))
+        expected_version: Option<u64>,
+        idempotency_key: Option<String>,
+    ) -> Result<Result<u64, CommandUseCaseError>, anyhow::Error> {
+        let idempotency_key = idempotency_key.map(|key| format!("place_order:{id}:{key}"));
+        self.retry_policy
+            .run("place_order", is_conflict, || {
+                let id = id.clone();
+                let idempotency_key = idempotency_key.clone();
+                async move {
+                    if let Some(idempotency) =
+                        self.get_idempotency(idempotency_key.as_ref()).await?
+                    {
+                        return Ok(Ok(idempotency.version()));
+                    }
+                    let Some(mut aggregate): Option<Aggregate> = self.processor.get(id).await??
+                    else {
+                        return Ok(Err(CommandUseCaseError::AggregateNotFound));
+                    };
+                    let events =
+                        match aggregate.apply_command(Command::PlaceOrder, expected_version) {
+                            Ok(events) => events,
+                            Err(e) => match e {
+                                kernel::command::error::CommandKernelError::VersionMismatch {
+                                    expected,
+                                    actual,
+                                } => {
+                                    return Ok(Err(CommandUseCaseError::VersionMismatch {
+                                        expected,
+                                        actual,
+                                    }))
+                                }
+                                _ => return Err(e.into()),
+                            },
+                        };
+                    self.update(aggregate, events, idempotency_key).await
+                }
+            })
+            .await
     }
 }
@@ -9,6 +9,19 @@ use proto::cart::v1::{
 use tonic::{Code, Request, Response, Status};
 use tonic_types::{ErrorDetails, StatusExt as _};
 
+/// 集約のバージョンが期待するバージョンと異なる場合のステータス
+///
+/// クライアントが集約を取得し直してから再実行できるように `ABORTED` を返す
+fn version_mismatch(e: &CommandUseCaseError) -> Status {
+    Status::with_error_details(
+        Code::Aborted,
+        e.to_string(),
+        ErrorDetails::new()
+            .add_precondition_failure_violation("VERSION", "expected_version", e.to_string())
+            .to_owned(),
+    )
+}
+
 pub struct Service<C: CommandUseCaseExt, Q: QueryUseCaseExt> {
     command: C,
     query: Q,
@@ -31,10 +44,22 @@ where
     Q: QueryUseCaseExt + Send + Sync + 'static,
 {
     #[tracing::instrument(skip(self), err, ret)]
-    async fn create(&self, _: Request<CreateRequest>) -> Result<Response<CreateResponse>, Status> {
-        match self.command.create().await {
+    async fn create(
+        &self,
+        req: Request<CreateRequest>,
+    ) -> Result<Response<CreateResponse>, Status> {
+        let CreateRequest { idempotency_key } = req.into_inner();
+        match self.command.create(idempotency_key).await {
             Ok(result) => match result {
-                Ok(id) => return Ok(Response::new(CreateResponse { id: id.to_string() })),
+                Ok((id, version)) => {
+                    return Ok(Response::new(CreateResponse {
+                        id: id.to_string(),
+                        version,
+                    }))
+                }
+                Err(e @ CommandUseCaseError::ConcurrencyConflict) => {
+                    return Err(Status::aborted(e.to_string()))
+                }
                 Err(e) => return Err(Status::unknown(e.to_string())),
             },
             Err(e) => return Err(Status::unknown(e.to_string())),
@@ -50,6 +75,8 @@ where
             id,
             tenant_id,
             item_id,
+            expected_version,
+            idempotency_key,
         } = req.into_inner();
         let cart_id = id.parse().map_err(|e: anyhow::Error| {
             Status::with_error_details(
@@ -78,9 +105,19 @@ where
                     .to_owned(),
             )
         })?;
-        match self.command.add_item(cart_id, tenant_id, item_id).await {
+        match self
+            .command
+            .add_item(
+                cart_id,
+                tenant_id,
+                item_id,
+                expected_version,
+                idempotency_key,
+            )
+            .await
+        {
             Ok(result) => match result {
-                Ok(()) => return Ok(Response::new(AddItemResponse {})),
+                Ok(version) => return Ok(Response::new(AddItemResponse { version })),
                 Err(e) => match e {
                     CommandUseCaseError::AggregateNotFound => {
                         return Err(Status::with_error_details(
@@ -91,6 +128,12 @@ where
                                 .to_owned(),
                         ))
                     }
+                    CommandUseCaseError::ConcurrencyConflict => {
+                        return Err(Status::aborted(e.to_string()))
+                    }
+                    CommandUseCaseError::VersionMismatch { .. } => {
+                        return Err(version_mismatch(&e))
+                    }
                 },
             },
             Err(e) => return Err(Status::unknown(e.to_string())),
@@ -106,6 +149,8 @@ where
             id,
             tenant_id,
             item_id,
+            expected_version,
+            idempotency_key,
         } = req.into_inner();
         let cart_id = id.parse().map_err(|e: anyhow::Error| {
             Status::with_error_details(
@@ -134,9 +179,19 @@ where
                     .to_owned(),
             )
         })?;
-        match self.command.remove_item(cart_id, tenant_id, item_id).await {
+        match self
+            .command
+            .remove_item(
+                cart_id,
+                tenant_id,
+                item_id,
+                expected_version,
+                idempotency_key,
+            )
+            .await
+        {
             Ok(result) => match result {
-                Ok(()) => return Ok(Response::new(RemoveItemResponse {})),
+                Ok(version) => return Ok(Response::new(RemoveItemResponse { version })),
                 Err(e) => match e {
                     CommandUseCaseError::AggregateNotFound => {
                         return Err(Status::with_error_details(
@@ -147,6 +202,12 @@ where
                                 .to_owned(),
                         ))
                     }
+                    CommandUseCaseError::ConcurrencyConflict => {
+                        return Err(Status::aborted(e.to_string()))
+                    }
+                    CommandUseCaseError::VersionMismatch { .. } => {
+                        return Err(version_mismatch(&e))
+                    }
                 },
             },
             Err(e) => return Err(Status::unknown(e.to_string())),
@@ -158,7 +219,11 @@ where
         &self,
         req: Request<PlaceOrderRequest>,
     ) -> Result<Response<PlaceOrderResponse>, Status> {
-        let PlaceOrderRequest { id } = req.into_inner();
+        let PlaceOrderRequest {
+            id,
+            expected_version,
+            idempotency_key,
+        } = req.into_inner();
         let cart_id = id.parse().map_err(|e: anyhow::Error| {
             Status::with_error_details(
                 Code::InvalidArgument,
@@ -168,9 +233,13 @@ where
                     .to_owned(),
             )
         })?;
-        match self.command.place_order(cart_id).await {
+        match self
+            .command
+            .place_order(cart_id, expected_version, idempotency_key)
+            .await
+        {
             Ok(result) => match result {
-                Ok(()) => return Ok(Response::new(PlaceOrderResponse {})),
+                Ok(version) => return Ok(Response::new(PlaceOrderResponse { version })),
                 Err(e) => match e {
                     CommandUseCaseError::AggregateNotFound => {
                         return Err(Status::with_error_details(
@@ -181,6 +250,12 @@ where
                                 .to_owned(),
                         ))
                     }
+                    CommandUseCaseError::ConcurrencyConflict => {
+                        return Err(Status::aborted(e.to_string()))
+                    }
+                    CommandUseCaseError::VersionMismatch { .. } => {
+                        return Err(version_mismatch(&e))
+                    }
                 },
             },
             Err(e) => return Err(Status::unknown(e.to_string())),
@@ -217,6 +292,7 @@ where
                         return Ok(Response::new(GetResponse {
                             id: cart.id().to_string(),
                             items,
+                            version: cart.version(),
                         }));
                     }
                     None => return Err(Status::not_found(format!("cart not found: {id}"))),
@@ -8,6 +8,10 @@ pub enum CommandKernelError {
     AggregateAlreadyCreated,
     #[error("Cannot update Aggregate version")]
     AggregateVersionOverflowed,
+    #[error("Aggregate was updated concurrently")]
+    ConcurrencyConflict,
+    #[error("Aggregate version mismatch: expected {expected}, actual {actual}")]
+    VersionMismatch { expected: u64, actual: u64 },
     #[error("Order already placed")]
     OrderAlreadyPlaced,
     #[error("Tenant not found")]
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use crate::id::Id;
 
 use super::model::entity::{Item, Tenant};
@@ -13,5 +15,8 @@ pub enum Event {
         tenant_id: Id<Tenant>,
         item_id: Id<Item>,
     },
-    OrderPlaced,
+    /// 注文を確定した時点のカートの商品の数量をテナントごとに持つ
+    OrderPlaced {
+        items: BTreeMap<Id<Tenant>, BTreeMap<Id<Item>, u32>>,
+    },
 }
@@ -2,3 +2,5 @@
 pub mod aggregate;
 /// エンティティを定義するモジュール
 pub mod entity;
+/// 冪等キーを定義するモジュール
+pub mod idempotency;
@@ -56,13 +56,36 @@ impl Aggregate {
         self.version
     }
 
+    /// 集約のバージョンが期待するバージョンと一致するか検証する
+    ///
+    /// `expected_version` が `None` の場合は検証しない
+    ///
+    /// # Errors
+    pub fn ensure_version(&self, expected_version: Option<u64>) -> Result<(), CommandKernelError> {
+        match expected_version {
+            Some(expected) if expected != self.version => {
+                Err(CommandKernelError::VersionMismatch {
+                    expected,
+                    actual: self.version,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// 集約にコマンドを実行する
     ///
     /// 集約にコマンドを実行すると、コマンドに応じて集約の状態を変更し、集約の状態を変更したイベントを返す
+    /// `expected_version` を指定した場合は集約がそのバージョンのときだけコマンドを実行する
     ///
     /// # Errors
     #[tracing::instrument(skip(self), err, ret)]
-    pub fn apply_command(&mut self, command: Command) -> Result<Vec<Event>, CommandKernelError> {
+    pub fn apply_command(
+        &mut self,
+        command: Command,
+        expected_version: Option<u64>,
+    ) -> Result<Vec<Event>, CommandKernelError> {
+        self.ensure_version(expected_version)?;
         if command != Command::Create && self.version == 0 {
             return Err(CommandKernelError::AggregateNotCreated);
         }
@@ -78,31 +101,14 @@ impl Aggregate {
                 vec![Event::Created]
             }
             Command::AddItem { tenant_id, item_id } => {
-                let quantity_by_item_id = self
-                    .items
-                    .entry(tenant_id.clone())
-                    .or_insert(HashMap::from([(item_id.clone(), 0)]));
-                quantity_by_item_id
-                    .entry(item_id.clone())
-                    .and_modify(|quantity| *quantity += 1)
-                    .or_insert(1);
                 vec![Event::ItemAdded { tenant_id, item_id }]
             }
             Command::RemoveItem { tenant_id, item_id } => {
-                if let Some(quantity_by_item_id) = self.items.get_mut(&tenant_id) {
-                    if let Some(quantity) = quantity_by_item_id.get_mut(&item_id) {
-                        *quantity -= 1;
-                        if *quantity == 0 {
-                            quantity_by_item_id.remove(&item_id);
-                            if quantity_by_item_id.is_empty() {
-                                self.items.remove(&tenant_id);
-                            }
-                        }
-                    } else {
-                        return Err(CommandKernelError::ItemNotFound);
-                    }
-                } else {
+                let Some(quantity_by_item_id) = self.items.get(&tenant_id) else {
                     return Err(CommandKernelError::TenantNotFound);
+                };
+                if !quantity_by_item_id.contains_key(&item_id) {
+                    return Err(CommandKernelError::ItemNotFound);
                 }
                 vec![Event::ItemRemoved { tenant_id, item_id }]
             }
@@ -112,22 +118,72 @@ impl Aggregate {
                         message: "item is empty".to_string(),
                     });
                 }
-                self.is_order_placed = true;
-                vec![Event::OrderPlaced]
+                let items = self
+                    .items
+                    .iter()
+                    .map(|(tenant_id, quantity_by_item_id)| {
+                        (
+                            tenant_id.clone(),
+                            quantity_by_item_id
+                                .iter()
+                                .map(|(item_id, quantity)| (item_id.clone(), *quantity))
+                                .collect(),
+                        )
+                    })
+                    .collect();
+                vec![Event::OrderPlaced { items }]
             }
         };
+        for event in events.clone() {
+            self.apply_event(event)?;
+        }
+        Ok(events)
+    }
 
-        self.version = self
+    /// 集約にイベントを適用する
+    ///
+    /// イベントストアから集約を復元するときに利用するため、イベントの検証はせずに集約の状態だけを変更する
+    ///
+    /// # Errors
+    #[tracing::instrument(skip(self), err, ret)]
+    pub fn apply_event(&mut self, event: Event) -> Result<(), CommandKernelError> {
+        let version = self
             .version
             .checked_add(1)
             .ok_or(CommandKernelError::AggregateVersionOverflowed)?;
-        Ok(events)
+        match event {
+            Event::Created => {}
+            Event::ItemAdded { tenant_id, item_id } => {
+                *self
+                    .items
+                    .entry(tenant_id)
+                    .or_default()
+                    .entry(item_id)
+                    .or_insert(0) += 1;
+            }
+            Event::ItemRemoved { tenant_id, item_id } => {
+                if let Some(quantity_by_item_id) = self.items.get_mut(&tenant_id) {
+                    if let Some(quantity) = quantity_by_item_id.get_mut(&item_id) {
+                        *quantity = quantity.saturating_sub(1);
+                        if *quantity == 0 {
+                            quantity_by_item_id.remove(&item_id);
+                        }
+                    }
+                    if quantity_by_item_id.is_empty() {
+                        self.items.remove(&tenant_id);
+                    }
+                }
+            }
+            Event::OrderPlaced { .. } => self.is_order_placed = true,
+        }
+        self.version = version;
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, HashMap};
 
     use anyhow::Result;
 
@@ -171,7 +227,7 @@ mod tests {
             expected_events,
         } in tests
         {
-            let actual_events = aggregate.apply_command(Command::Create)?;
+            let actual_events = aggregate.apply_command(Command::Create, None)?;
             pretty_assertions::assert_eq!(
                 actual_events,
                 expected_events,
@@ -210,7 +266,7 @@ mod tests {
             expected,
         } in tests
         {
-            let actual = aggregate.apply_command(Command::Create);
+            let actual = aggregate.apply_command(Command::Create, None);
             assert_eq!(actual, Err(expected), "{name}");
         }
     }
@@ -361,7 +417,7 @@ mod tests {
             expected_events,
         } in tests
         {
-            let actual_events = aggregate.apply_command(command)?;
+            let actual_events = aggregate.apply_command(command, None)?;
             pretty_assertions::assert_eq!(
                 actual_events,
                 expected_events,
@@ -418,7 +474,7 @@ mod tests {
             expected,
         } in tests
         {
-            let actual = aggregate.apply_command(command);
+            let actual = aggregate.apply_command(command, None);
             assert_eq!(actual, Err(expected), "{name}");
         }
     }
@@ -570,7 +626,7 @@ mod tests {
             expected_events,
         } in tests
         {
-            let actual_events = aggregate.apply_command(command)?;
+            let actual_events = aggregate.apply_command(command, None)?;
             pretty_assertions::assert_eq!(
                 actual_events,
                 expected_events,
@@ -659,7 +715,7 @@ mod tests {
             expected,
         } in tests
         {
-            let actual = aggregate.apply_command(command);
+            let actual = aggregate.apply_command(command, None);
             assert_eq!(actual, Err(expected), "{name}");
         }
     }
@@ -697,7 +753,12 @@ mod tests {
                 is_order_placed: true,
                 version: 3,
             },
-            expected_events: vec![Event::OrderPlaced],
+            expected_events: vec![Event::OrderPlaced {
+                items: BTreeMap::from_iter([(
+                    tenant_id.clone(),
+                    BTreeMap::from_iter([(item_id.clone(), 1)]),
+                )]),
+            }],
         }];
 
         for TestCase {
@@ -707,7 +768,7 @@ mod tests {
             expected_events,
         } in tests
         {
-            let actual_events = aggregate.apply_command(Command::PlaceOrder)?;
+            let actual_events = aggregate.apply_command(Command::PlaceOrder, None)?;
             pretty_assertions::assert_eq!(
                 actual_events,
                 expected_events,
@@ -764,7 +825,7 @@ mod tests {
             expected,
         } in tests
         {
-            let actual = aggregate.apply_command(Command::PlaceOrder);
+            let actual = aggregate.apply_command(Command::PlaceOrder, None);
             assert_eq!(actual, Err(expected), "{name}");
         }
     }
@@ -775,31 +836,132 @@ mod tests {
             name: &'static str,
             aggregate: Aggregate,
             command: Command,
+            expected_version: Option<u64>,
             expected: CommandKernelError,
         }
 
-        let tests = [TestCase {
-            name: "バージョンが最大値の集約にコマンド実行時はエラーが返る",
-            aggregate: Aggregate {
-                version: u64::MAX,
-                ..Default::default()
+        let tests = [
+            TestCase {
+                name: "バージョンが最大値の集約にコマンド実行時はエラーが返る",
+                aggregate: Aggregate {
+                    version: u64::MAX,
+                    ..Default::default()
+                },
+                command: Command::AddItem {
+                    tenant_id: Id::generate(),
+                    item_id: Id::generate(),
+                },
+                expected_version: None,
+                expected: CommandKernelError::AggregateVersionOverflowed,
             },
-            command: Command::AddItem {
-                tenant_id: Id::generate(),
-                item_id: Id::generate(),
+            TestCase {
+                name: "期待するバージョンと集約のバージョンが異なる場合はエラーが返る",
+                aggregate: Aggregate {
+                    version: 2,
+                    ..Default::default()
+                },
+                command: Command::AddItem {
+                    tenant_id: Id::generate(),
+                    item_id: Id::generate(),
+                },
+                expected_version: Some(1),
+                expected: CommandKernelError::VersionMismatch {
+                    expected: 1,
+                    actual: 2,
+                },
             },
-            expected: CommandKernelError::AggregateVersionOverflowed,
-        }];
+        ];
 
         for TestCase {
             name,
             mut aggregate,
             command,
+            expected_version,
             expected,
         } in tests
         {
-            let actual = aggregate.apply_command(command);
+            let actual = aggregate.apply_command(command, expected_version);
             assert_eq!(actual, Err(expected), "{name}");
         }
     }
+
+    #[test]
+    fn test_apply_event_ok() -> Result<()> {
+        struct TestCase {
+            name: &'static str,
+            events: Vec<Event>,
+            expected: Aggregate,
+        }
+
+        let aggregate_id: Id<Aggregate> = Id::generate();
+        let tenant_id: Id<Tenant> = Id::generate();
+        let item_id_1: Id<Item> = Id::generate();
+        let item_id_2: Id<Item> = Id::generate();
+        let tests = [
+            TestCase {
+                name: "Createdを適用した場合はバージョン1の集約が復元される",
+                events: vec![Event::Created],
+                expected: Aggregate {
+                    id: aggregate_id.clone(),
+                    items: HashMap::new(),
+                    is_order_placed: false,
+                    version: 1,
+                },
+            },
+            TestCase {
+                name: "複数のイベントを順番に適用した場合はイベントの数をバージョンとする集約が復元される",
+                events: vec![
+                    Event::Created,
+                    Event::ItemAdded {
+                        tenant_id: tenant_id.clone(),
+                        item_id: item_id_1.clone(),
+                    },
+                    Event::ItemAdded {
+                        tenant_id: tenant_id.clone(),
+                        item_id: item_id_1.clone(),
+                    },
+                    Event::ItemAdded {
+                        tenant_id: tenant_id.clone(),
+                        item_id: item_id_2.clone(),
+                    },
+                    Event::ItemRemoved {
+                        tenant_id: tenant_id.clone(),
+                        item_id: item_id_2.clone(),
+                    },
+                    Event::OrderPlaced {
+                        items: BTreeMap::from_iter([(
+                            tenant_id.clone(),
+                            BTreeMap::from_iter([(item_id_1.clone(), 2)]),
+                        )]),
+                    },
+                ],
+                expected: Aggregate {
+                    id: aggregate_id.clone(),
+                    items: HashMap::from_iter([(
+                        tenant_id.clone(),
+                        HashMap::from_iter([(item_id_1.clone(), 2)]),
+                    )]),
+                    is_order_placed: true,
+                    version: 6,
+                },
+            },
+        ];
+
+        for TestCase {
+            name,
+            events,
+            expected,
+        } in tests
+        {
+            let mut aggregate = Aggregate {
+                id: aggregate_id.clone(),
+                ..Default::default()
+            };
+            for event in events {
+                aggregate.apply_event(event)?;
+            }
+            pretty_assertions::assert_eq!(aggregate, expected, "{name}");
+        }
+        Ok(())
+    }
 }
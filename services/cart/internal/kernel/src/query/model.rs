@@ -7,12 +7,14 @@ pub struct Tenant;
 pub struct Cart {
     id: Id<Cart>,
     items: Vec<Item>,
+    /// 集約のバージョン
+    version: u64,
 }
 
 impl Cart {
     #[must_use]
-    pub fn new(id: Id<Cart>, items: Vec<Item>) -> Self {
-        Self { id, items }
+    pub fn new(id: Id<Cart>, items: Vec<Item>, version: u64) -> Self {
+        Self { id, items, version }
     }
 
     #[must_use]
@@ -24,12 +26,18 @@ impl Cart {
     pub fn items(&self) -> &[Item] {
         &self.items
     }
+
+    /// 集約のバージョン
+    #[must_use]
+    pub fn version(&self) -> u64 {
+        self.version
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
 pub struct Item {
     tenant_id: Id<Tenant>,
-    item_id: Id<Item>,
+    id: Id<Item>,
     quantity: u32,
 }
 
@@ -38,7 +46,7 @@ impl Item {
     pub fn new(tenant_id: Id<Tenant>, item_id: Id<Item>, quantity: u32) -> Self {
         Self {
             tenant_id,
-            item_id,
+            id: item_id,
             quantity,
         }
     }
@@ -50,7 +58,7 @@ impl Item {
 
     #[must_use]
     pub fn item_id(&self) -> &Id<Item> {
-        &self.item_id
+        &self.id
     }
 
     #[must_use]
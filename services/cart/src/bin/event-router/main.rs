@@ -1,56 +1,58 @@
-use lambda_runtime::tracing;
+use adapter::command::model::{EventPayload, EventStoreModel};
+use anyhow::Context as _;
+use event_router::grpc::{ClientConfig, RetryPolicy};
+use event_router::{Config, Handler, HandlerError, Router};
+use proto::cart::v1::cart_service_client::CartServiceClient;
+use proto::order::v1::order_service_client::OrderServiceClient;
+use tonic::transport::Channel;
 
-#[tracing::instrument(err, ret)]
-async fn handler(
-    event: lambda_runtime::LambdaEvent<aws_lambda_events::event::dynamodb::Event>,
-) -> Result<(), lambda_runtime::Error> {
-    use adapter::command::model::{EventPayload, EventStoreModel};
-    use anyhow::Context as _;
-    use proto::cart::v1::cart_service_client::CartServiceClient;
-    use proto::order::v1::order_service_client::OrderServiceClient;
+/// 注文が確定したカートから注文サービスに注文を作成するハンドラー
+///
+/// クライアントは Lambda 関数のコンテナかポーラーのプロセスごとに1回だけ作成して、接続を使い回す
+#[derive(Debug, Clone)]
+struct CreateOrder {
+    cart_service: CartServiceClient<Channel>,
+    cart_service_retry_policy: RetryPolicy,
+    order_service: OrderServiceClient<Channel>,
+    order_service_retry_policy: RetryPolicy,
+}
 
-    let models: Vec<EventStoreModel> = event
-        .payload
-        .records
-        .into_iter()
-        .map(|record| serde_dynamo::from_item(record.change.new_image))
-        .collect::<Result<_, _>>()?;
-    for model in &models {
-        let metadata = model.metadata();
-        let cx = observability::aws_dynamodb::extract(metadata);
-        let span = tracing::Span::current();
-        observability::aws_lambda::add_link(cx, &span);
+impl CreateOrder {
+    fn from_env() -> Result<Self, anyhow::Error> {
+        let cart_service =
+            ClientConfig::from_env("CART_SERVICE").context("load cart service config")?;
+        let order_service =
+            ClientConfig::from_env("ORDER_SERVICE").context("load order service config")?;
+        Ok(Self {
+            cart_service: CartServiceClient::new(cart_service.channel()?),
+            cart_service_retry_policy: cart_service.retry_policy(),
+            order_service: OrderServiceClient::new(order_service.channel()?),
+            order_service_retry_policy: order_service.retry_policy(),
+        })
     }
-    let order_placed_models: Vec<_> = models
-        .into_iter()
-        .filter(|model| model.payload() == &EventPayload::OrderPlacedV1)
-        .collect();
-    let cart_service_endpoint =
-        std::env::var("CART_SERVICE_ENDPOINT").context("CART_SERVICE_ENDPOINT must be set")?;
-    let mut cart_service = CartServiceClient::connect(format!("http://{cart_service_endpoint}"))
-        .await
-        .context("connect cart service")?;
-    let order_service_endpoint =
-        std::env::var("ORDER_SERVICE_ENDPOINT").context("ORDER_SERVICE_ENDPOINT must be set")?;
-    let mut order_service = OrderServiceClient::connect(format!("http://{order_service_endpoint}"))
-        .await
-        .context("connect order service")?;
-    for model in order_placed_models {
-        let cart_id = model.aggregate_id().to_string();
+
+    /// カートサービスからカートの商品を取得する
+    async fn cart_items(
+        &self,
+        cart_id: &str,
+    ) -> Result<Vec<proto::order::v1::Item>, anyhow::Error> {
         let message = proto::cart::v1::GetRequest {
-            id: cart_id.clone(),
+            id: cart_id.to_string(),
         };
-        let mut request = tonic::Request::new(message.clone());
-        request.set_timeout(std::time::Duration::from_millis(500));
-        observability::grpc_client::inject(
-            &observability::grpc_client::context(),
-            request.metadata_mut(),
-        );
-        let response = cart_service
-            .get(request)
+        let response = self
+            .cart_service_retry_policy
+            .call(|| {
+                let mut client = self.cart_service.clone();
+                let mut request = tonic::Request::new(message.clone());
+                observability::grpc_client::inject(
+                    &observability::grpc_client::context(),
+                    request.metadata_mut(),
+                );
+                async move { client.get(request).await }
+            })
             .await
             .with_context(|| format!("Call cart.v1.CartService/Get: {message:?}"))?;
-        let items: Vec<_> = response
+        Ok(response
             .into_inner()
             .items
             .into_iter()
@@ -66,32 +68,61 @@ async fn handler(
                     quantity,
                 }
             })
-            .collect();
-        let message = proto::order::v1::CreateRequest { cart_id, items };
-        let mut request = tonic::Request::new(message.clone());
-        request.set_timeout(std::time::Duration::from_millis(500));
-        observability::grpc_client::inject(
-            &observability::grpc_client::context(),
-            request.metadata_mut(),
-        );
-        order_service
-            .create(request)
+            .collect())
+    }
+
+    async fn create_order(&self, model: &EventStoreModel) -> Result<(), anyhow::Error> {
+        let cart_id = model.aggregate_id().to_string();
+        let items = match model.payload().order_items() {
+            Some(items) => items,
+            // NOTE: 古いペイロードは注文を確定した時点のカートの商品を持たないので、カートサービスから取得する
+            None => self.cart_items(&cart_id).await?,
+        };
+        // NOTE: 同じイベントが再送された場合に注文を重複して作成しないように、イベントから冪等キーを決める。
+        //       注文サービスはカートごとに1つだけ注文を作成するので、冪等キーが異なる場合も重複しない
+        let idempotency_key = Some(format!("{cart_id}:{}", model.id()));
+        let message = proto::order::v1::CreateRequest {
+            cart_id,
+            items,
+            idempotency_key,
+        };
+        self.order_service_retry_policy
+            .call(|| {
+                let mut client = self.order_service.clone();
+                let mut request = tonic::Request::new(message.clone());
+                observability::grpc_client::inject(
+                    &observability::grpc_client::context(),
+                    request.metadata_mut(),
+                );
+                async move { client.create(request).await }
+            })
             .await
             .with_context(|| format!("Call order.v1.OrderService/Create: {message:?}"))?;
+        Ok(())
+    }
+}
+
+impl Handler<EventPayload> for CreateOrder {
+    async fn handle(&self, model: &EventStoreModel) -> Result<(), HandlerError> {
+        Ok(self.create_order(model).await?)
     }
-    Ok(())
 }
 
 #[tokio::main]
-async fn main() -> Result<(), lambda_runtime::Error> {
-    let force_flush = observability::provider::init_providers_with_flush(
-        env!("CARGO_PKG_NAME"),
-        env!("CARGO_PKG_VERSION"),
-    )?;
-    observability::aws_lambda::run(
-        lambda_runtime::service_fn(handler),
-        force_flush,
-        env!("CARGO_PKG_VERSION").to_string(),
+async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let router = Router::new().route(
+        "create_order",
+        EventPayload::is_order_placed,
+        CreateOrder::from_env()?,
+    );
+    event_router::run(
+        router,
+        Config {
+            service_name: env!("CARGO_PKG_NAME"),
+            service_version: env!("CARGO_PKG_VERSION"),
+            event_store_table_name: "cart-event-store",
+            dead_letter_table_name: "cart-event-router-dead-letter",
+        },
     )
     .await
 }
@@ -0,0 +1,172 @@
+use adapter::command::model::{EventPayload, OutboxModel};
+use anyhow::Context as _;
+use event_store::relay::{FileSink, Relay, Sink, SinkError};
+use proto::cart::v1::cart_service_client::CartServiceClient;
+use proto::order::v1::order_service_client::OrderServiceClient;
+use tonic::transport::Channel;
+
+/// 注文が確定したカートから注文サービスに注文を作成する送信先
+#[derive(Debug, Clone)]
+struct OrderServiceSink {
+    cart_service: CartServiceClient<Channel>,
+    order_service: OrderServiceClient<Channel>,
+}
+
+impl OrderServiceSink {
+    async fn connect() -> Result<Self, anyhow::Error> {
+        let cart_service_endpoint =
+            std::env::var("CART_SERVICE_ENDPOINT").context("CART_SERVICE_ENDPOINT must be set")?;
+        let cart_service = CartServiceClient::connect(format!("http://{cart_service_endpoint}"))
+            .await
+            .context("connect cart service")?;
+        let order_service_endpoint = std::env::var("ORDER_SERVICE_ENDPOINT")
+            .context("ORDER_SERVICE_ENDPOINT must be set")?;
+        let order_service = OrderServiceClient::connect(format!("http://{order_service_endpoint}"))
+            .await
+            .context("connect order service")?;
+        Ok(Self {
+            cart_service,
+            order_service,
+        })
+    }
+
+    /// カートサービスからカートの商品を取得する
+    async fn cart_items(
+        &self,
+        cart_id: &str,
+    ) -> Result<Vec<proto::order::v1::Item>, anyhow::Error> {
+        let message = proto::cart::v1::GetRequest {
+            id: cart_id.to_string(),
+        };
+        let mut request = tonic::Request::new(message.clone());
+        request.set_timeout(std::time::Duration::from_millis(500));
+        observability::grpc_client::inject(
+            &observability::grpc_client::context(),
+            request.metadata_mut(),
+        );
+        let response = self
+            .cart_service
+            .clone()
+            .get(request)
+            .await
+            .with_context(|| format!("Call cart.v1.CartService/Get: {message:?}"))?;
+        Ok(response
+            .into_inner()
+            .items
+            .into_iter()
+            .map(|item| {
+                let proto::cart::v1::get_response::Item {
+                    tenant_id,
+                    item_id,
+                    quantity,
+                } = item;
+                proto::order::v1::Item {
+                    tenant_id,
+                    item_id,
+                    quantity,
+                }
+            })
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self), err, ret)]
+    async fn create_order(&self, outbox: &OutboxModel) -> Result<(), anyhow::Error> {
+        if !outbox.payload().is_order_placed() {
+            return Ok(());
+        }
+
+        let cart_id = outbox.aggregate_id().to_string();
+        let items = match outbox.payload().order_items() {
+            Some(items) => items,
+            // NOTE: 古いペイロードは注文を確定した時点のカートの商品を持たないので、カートサービスから取得する
+            None => self.cart_items(&cart_id).await?,
+        };
+        // NOTE: イベントルーターと同じ冪等キーにして、同じイベントから注文を重複して作成しないようにする。
+        //       注文サービスはカートごとに1つだけ注文を作成するので、冪等キーが異なる場合も重複しない
+        let idempotency_key = Some(format!("{cart_id}:{}", outbox.event_id()));
+        let message = proto::order::v1::CreateRequest {
+            cart_id,
+            items,
+            idempotency_key,
+        };
+        let mut request = tonic::Request::new(message.clone());
+        request.set_timeout(std::time::Duration::from_millis(500));
+        observability::grpc_client::inject(
+            &observability::grpc_client::context(),
+            request.metadata_mut(),
+        );
+        self.order_service
+            .clone()
+            .create(request)
+            .await
+            .with_context(|| format!("Call order.v1.OrderService/Create: {message:?}"))?;
+        Ok(())
+    }
+}
+
+impl Sink<EventPayload> for OrderServiceSink {
+    async fn publish(&self, outbox: &OutboxModel) -> Result<(), SinkError> {
+        Ok(self.create_order(outbox).await?)
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let shutdown_providers =
+        observability::provider::init_providers(env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))?;
+
+    // NOTE: 他のプロセスからアウトボックスを参照できないので、プロセスのメモリ上に保存するイベントストアは利用できない
+    let event_store = match std::env::var("EVENT_STORE_BACKEND") {
+        Ok(backend) if backend == "postgres" => {
+            let pool =
+                adapter::command::persistence::postgres(&std::env::var("DATABASE_URL")?).await?;
+            adapter::command::persistence::EventStore::postgres(pool).await?
+        }
+        Ok(backend) if backend == "sqlite" => {
+            let pool =
+                adapter::command::persistence::sqlite(&std::env::var("DATABASE_URL")?).await?;
+            adapter::command::persistence::EventStore::sqlite(pool).await?
+        }
+        Ok(backend) if backend != "dynamodb" => {
+            return Err(format!("unsupported event store backend: {backend}").into());
+        }
+        _ => {
+            let config = aws_config::defaults(aws_config::BehaviorVersion::latest())
+                .endpoint_url(format!(
+                    "http://{}:{}",
+                    std::env::var("LOCALSTACK_GATEWAY_HOST").unwrap_or("localhost".to_string()),
+                    std::env::var("LOCALSTACK_GATEWAY_PORT").unwrap_or("4566".to_string()),
+                ))
+                .region(
+                    aws_config::meta::region::RegionProviderChain::default_provider()
+                        .or_else("ap-northeast-1"),
+                )
+                .test_credentials()
+                .load()
+                .await;
+            adapter::command::persistence::EventStore::dynamodb(
+                adapter::command::persistence::dynamodb(&config),
+            )
+        }
+    };
+    match std::env::var("OUTBOX_SINK") {
+        Ok(sink) if sink == "file" => {
+            let path = std::env::var("OUTBOX_FILE").unwrap_or("outbox.jsonl".to_string());
+            tracing::info!("relay outbox to file: {path}");
+            Relay::new(event_store, FileSink::new(path))
+                .run(observability::server::shutdown())
+                .await;
+        }
+        Ok(sink) if sink != "grpc" => {
+            return Err(format!("unknown outbox sink: {sink}").into());
+        }
+        _ => {
+            tracing::info!("relay outbox to order service");
+            Relay::new(event_store, OrderServiceSink::connect().await?)
+                .run(observability::server::shutdown())
+                .await;
+        }
+    }
+    shutdown_providers()?;
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anyhow::Context as _;
+use event_store::dynamodb::DynamoDbEventStore;
+
+use super::model::{AggregatePayload, EventStorePayload};
+use super::repository::TABLE_NAMES;
+
+/// 集約テーブルの全てのレコードのペイロードを最新のバージョンに書き換えるマイグレーション
+#[derive(Debug, Clone)]
+pub struct AggregateMigration {
+    event_store: DynamoDbEventStore<AggregatePayload, EventStorePayload>,
+}
+
+impl AggregateMigration {
+    #[must_use]
+    pub fn new(dynamodb: aws_sdk_dynamodb::Client) -> Self {
+        Self {
+            event_store: DynamoDbEventStore::new(dynamodb, TABLE_NAMES),
+        }
+    }
+
+    /// マイグレーションを実行して、書き換えたレコードの数を返す
+    ///
+    /// 書き換える前に集約が更新された場合は、更新時に最新のバージョンで書き込まれるので書き換えない
+    ///
+    /// # Errors
+    #[tracing::instrument(skip(self), err(Debug), ret)]
+    pub async fn run(&self) -> Result<usize, anyhow::Error> {
+        self.event_store
+            .migrate_aggregates()
+            .await
+            .with_context(|| "migrate aggregates")
+    }
+}
@@ -1,101 +1,60 @@
-use std::collections::HashMap;
-
 use anyhow::Context;
-use aws_sdk_dynamodb::types::AttributeValue;
+use event_store::VersionedPayload;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
-pub(crate) struct AggregateModel {
-    id: String,
-    version: u64,
-    payload: AggregatePayload,
+/// 集約のテーブルモデル
+pub(crate) type AggregateModel = event_store::AggregateModel<AggregatePayload>;
+
+/// 集約からテーブルモデルに変換する
+pub(crate) fn from_aggregate(
+    aggregate: kernel::command::model::aggregate::Aggregate,
+) -> AggregateModel {
+    AggregateModel::new(
+        aggregate.id().to_string(),
+        aggregate.version(),
+        aggregate.into(),
+    )
 }
 
-impl AggregateModel {
-    pub(crate) fn id(&self) -> &str {
-        &self.id
-    }
-
-    pub(crate) fn version(&self) -> u64 {
-        self.version
-    }
-
-    pub(crate) fn payload(&self) -> &AggregatePayload {
-        &self.payload
-    }
-
-    pub(crate) fn version_attribute_value<T: From<serde_dynamo::AttributeValue>>(
-        &self,
-    ) -> Result<T, anyhow::Error> {
-        Ok(serde_dynamo::to_attribute_value(self.version)?)
-    }
-
-    pub(crate) fn payload_attribute_value<T: From<serde_dynamo::AttributeValue>>(
-        &self,
-    ) -> Result<T, anyhow::Error> {
-        Ok(serde_dynamo::to_attribute_value(&self.payload)?)
-    }
-}
-
-impl From<kernel::command::model::aggregate::Aggregate> for AggregateModel {
-    fn from(value: kernel::command::model::aggregate::Aggregate) -> Self {
-        Self {
-            id: value.id().to_string(),
-            version: value.version(),
-            payload: value.into(),
+/// テーブルモデルから集約に変換する
+pub(crate) fn into_aggregate(
+    model: AggregateModel,
+) -> Result<kernel::command::model::aggregate::Aggregate, anyhow::Error> {
+    use kernel::command::model::aggregate::Aggregate;
+    use kernel::command::model::entity::{Cart, Item, OrderStatus};
+    use kernel::id::Id;
+
+    let id = model.id().to_string();
+    let version = model.version();
+    let (cart_id, items, status): (Id<Cart>, Vec<Item>, OrderStatus) = match model.into_payload() {
+        AggregatePayload::V1 {
+            cart_id,
+            items,
+            order_status,
         }
-    }
-}
-
-impl TryFrom<AggregateModel> for kernel::command::model::aggregate::Aggregate {
-    type Error = anyhow::Error;
-
-    fn try_from(value: AggregateModel) -> Result<Self, Self::Error> {
-        use kernel::command::model::entity::{Cart, Item, OrderStatus};
-        use kernel::id::Id;
-
-        let AggregateModel {
-            id,
-            version,
-            payload,
-        } = value;
-
-        let (cart_id, items, status): (Id<Cart>, Vec<Item>, OrderStatus) = match payload {
-            AggregatePayload::V1 {
-                cart_id,
-                items,
-                order_status,
-            } => (
-                cart_id
-                    .parse()
-                    .with_context(|| format!("parse cart id: {cart_id}"))?,
-                items
-                    .into_iter()
-                    .map(TryInto::try_into)
-                    .collect::<Result<_, _>>()?,
-                order_status.into(),
-            ),
-        };
-        Ok(Self::new(
-            id.parse().with_context(|| "parse aggregate id: {id}")?,
+        | AggregatePayload::V2 {
             cart_id,
             items,
-            status,
-            version,
-        ))
-    }
-}
-
-impl<S> TryFrom<AggregateModel> for HashMap<String, AttributeValue, S>
-where
-    S: std::hash::BuildHasher,
-    HashMap<String, AttributeValue, S>: From<serde_dynamo::Item>,
-{
-    type Error = anyhow::Error;
-
-    fn try_from(value: AggregateModel) -> Result<Self, Self::Error> {
-        Ok(serde_dynamo::to_item(value)?)
-    }
+            order_status,
+            ..
+        } => (
+            cart_id
+                .parse()
+                .with_context(|| format!("parse cart id: {cart_id}"))?,
+            items
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            order_status.into(),
+        ),
+    };
+    Ok(Aggregate::new(
+        id.parse().with_context(|| "parse aggregate id: {id}")?,
+        cart_id,
+        items,
+        status,
+        version,
+    ))
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
@@ -105,20 +64,64 @@ pub(crate) enum AggregatePayload {
         items: Vec<Item>,
         order_status: OrderStatus,
     },
+    V2 {
+        cart_id: String,
+        items: Vec<Item>,
+        order_status: OrderStatus,
+        /// 注文に含まれる商品のテナントID
+        tenant_ids: Vec<String>,
+    },
+}
+
+impl VersionedPayload for AggregatePayload {
+    fn is_latest(&self) -> bool {
+        matches!(self, Self::V2 { .. })
+    }
+
+    fn into_latest(self) -> Self {
+        match self {
+            Self::V1 {
+                cart_id,
+                items,
+                order_status,
+            } => Self::V2 {
+                tenant_ids: tenant_ids(&items),
+                cart_id,
+                items,
+                order_status,
+            },
+            payload @ Self::V2 { .. } => payload,
+        }
+    }
+}
+
+/// 商品のテナントIDを重複なく昇順で返す
+fn tenant_ids(items: &[Item]) -> Vec<String> {
+    let mut tenant_ids: Vec<String> = items
+        .iter()
+        .map(|item| match item {
+            Item::V1 { tenant_id, .. } => tenant_id.clone(),
+        })
+        .collect();
+    tenant_ids.sort();
+    tenant_ids.dedup();
+    tenant_ids
 }
 
 impl From<kernel::command::model::aggregate::Aggregate> for AggregatePayload {
     fn from(value: kernel::command::model::aggregate::Aggregate) -> Self {
-        Self::V1 {
+        let items: Vec<Item> = value.items().iter().copied().map(Into::into).collect();
+        Self::V2 {
             cart_id: value.cart_id().to_string(),
-            items: value.items().iter().copied().map(Into::into).collect(),
+            tenant_ids: tenant_ids(&items),
+            items,
             order_status: value.status().into(),
         }
     }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
-pub(crate) enum Item {
+pub enum Item {
     V1 {
         id: String,
         tenant_id: String,
@@ -193,38 +196,11 @@ impl From<OrderStatus> for kernel::command::model::entity::OrderStatus {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
-pub(crate) struct EventStoreModel {
-    id: u64,
-    aggregate_id: String,
-    payload: EventStorePayload,
-}
-
-impl EventStoreModel {
-    #[must_use]
-    pub(crate) fn new(id: u64, aggregate_id: String, payload: EventStorePayload) -> Self {
-        Self {
-            id,
-            aggregate_id,
-            payload,
-        }
-    }
-}
-
-impl<S> TryFrom<EventStoreModel> for HashMap<String, AttributeValue, S>
-where
-    S: std::hash::BuildHasher,
-    HashMap<String, AttributeValue, S>: From<serde_dynamo::Item>,
-{
-    type Error = anyhow::Error;
-
-    fn try_from(value: EventStoreModel) -> Result<Self, Self::Error> {
-        serde_dynamo::to_item(value).with_context(|| "try from EventStoreModel")
-    }
-}
+/// イベントストアのテーブルモデル
+pub type EventStoreModel = event_store::EventStoreModel<EventStorePayload>;
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
-pub(crate) enum EventStorePayload {
+pub enum EventStorePayload {
     CreatedV1 { cart_id: String, items: Vec<Item> },
     PreparedV1,
     PickedUpV1,
@@ -247,41 +223,50 @@ impl From<kernel::command::event::Event> for EventStorePayload {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
-pub(crate) struct EventSequenceModel {
-    aggregate_id: String,
-    latest_event_id: u64,
-}
-
-impl EventSequenceModel {
-    #[must_use]
-    pub(crate) fn new(aggregate_id: String, latest_event_id: u64) -> Self {
-        Self {
-            aggregate_id,
-            latest_event_id,
-        }
-    }
-
-    #[must_use]
-    pub(crate) fn latest_event_id(&self) -> u64 {
-        self.latest_event_id
-    }
+impl TryFrom<EventStorePayload> for kernel::command::event::Event {
+    type Error = anyhow::Error;
 
-    pub(crate) fn latest_event_id_attribute_value<T: From<serde_dynamo::AttributeValue>>(
-        &self,
-    ) -> Result<T, anyhow::Error> {
-        Ok(serde_dynamo::to_attribute_value(self.latest_event_id)?)
+    fn try_from(value: EventStorePayload) -> Result<Self, Self::Error> {
+        let event = match value {
+            EventStorePayload::CreatedV1 { cart_id, items } => Self::Created {
+                cart_id: cart_id
+                    .parse()
+                    .with_context(|| format!("parse cart id: {cart_id}"))?,
+                items: items
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<_, _>>()?,
+            },
+            EventStorePayload::PreparedV1 => Self::Prepared,
+            EventStorePayload::PickedUpV1 => Self::PickedUp,
+            EventStorePayload::DeliveredV1 => Self::Delivered,
+            EventStorePayload::CanceledV1 => Self::Canceled,
+        };
+        Ok(event)
     }
 }
 
-impl<S> TryFrom<EventSequenceModel> for HashMap<String, AttributeValue, S>
-where
-    S: std::hash::BuildHasher,
-    HashMap<String, AttributeValue, S>: From<serde_dynamo::Item>,
-{
-    type Error = anyhow::Error;
+/// 冪等キーからテーブルモデルに変換する
+pub(crate) fn from_idempotency(
+    idempotency: &kernel::command::model::idempotency::Idempotency,
+) -> event_store::IdempotencyKeyModel {
+    event_store::IdempotencyKeyModel::new(
+        idempotency.key().to_string(),
+        idempotency.aggregate_id().to_string(),
+        idempotency.version(),
+    )
+}
 
-    fn try_from(value: EventSequenceModel) -> Result<Self, Self::Error> {
-        serde_dynamo::to_item(value).with_context(|| "try from EventSequenceModel")
-    }
+/// テーブルモデルから冪等キーに変換する
+pub(crate) fn into_idempotency(
+    model: &event_store::IdempotencyKeyModel,
+) -> Result<kernel::command::model::idempotency::Idempotency, anyhow::Error> {
+    let aggregate_id = model.aggregate_id();
+    Ok(kernel::command::model::idempotency::Idempotency::new(
+        model.key().to_string(),
+        aggregate_id
+            .parse()
+            .with_context(|| format!("parse aggregate id: {aggregate_id}"))?,
+        model.version(),
+    ))
 }
@@ -0,0 +1,119 @@
+use event_store::UpcasterRegistry;
+
+use super::model::EventStorePayload;
+
+/// イベントのペイロードを最新のバージョンに変換するアップキャスターを登録したレジストリ
+pub(crate) fn upcasters() -> UpcasterRegistry<EventStorePayload> {
+    // NOTE: 現在は全てのペイロードが最新のバージョンなので、アップキャスターは登録されていない
+    UpcasterRegistry::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use event_store::{Upcaster, UpcasterRegistry};
+
+    use crate::command::model::{EventStoreModel, EventStorePayload, Item};
+
+    use super::upcasters;
+
+    /// V2のスキーマを想定したイベントストアのテーブルモデル
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+    struct EventStoreModelV2 {
+        id: u64,
+        aggregate_id: String,
+        payload: EventStorePayloadV2,
+    }
+
+    /// V2のスキーマを想定したイベントのペイロード
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+    enum EventStorePayloadV2 {
+        CreatedV1 { cart_id: String, items: Vec<Item> },
+        PreparedV1,
+        PickedUpV1,
+        DeliveredV1,
+        CanceledV1,
+        CanceledV2 { reason: Option<String> },
+    }
+
+    struct CanceledV2Upcaster;
+
+    impl Upcaster<EventStorePayloadV2> for CanceledV2Upcaster {
+        fn upcast(
+            &self,
+            payload: EventStorePayloadV2,
+        ) -> Result<EventStorePayloadV2, Box<dyn std::error::Error + Send + Sync + 'static>>
+        {
+            match payload {
+                EventStorePayloadV2::CanceledV1 => {
+                    Ok(EventStorePayloadV2::CanceledV2 { reason: None })
+                }
+                payload => Ok(payload),
+            }
+        }
+    }
+
+    #[test]
+    fn test_upcast_v1_rows_into_v2_ok() -> anyhow::Result<()> {
+        struct TestCase {
+            name: &'static str,
+            model: EventStoreModel,
+            expected: EventStorePayloadV2,
+        }
+
+        let tests = [
+            TestCase {
+                name: "CanceledV1のレコードはCanceledV2に変換される",
+                model: EventStoreModel::new(
+                    4,
+                    "aggregate".to_string(),
+                    EventStorePayload::CanceledV1,
+                ),
+                expected: EventStorePayloadV2::CanceledV2 { reason: None },
+            },
+            TestCase {
+                name: "変換対象ではないレコードはそのまま返る",
+                model: EventStoreModel::new(
+                    1,
+                    "aggregate".to_string(),
+                    EventStorePayload::PreparedV1,
+                ),
+                expected: EventStorePayloadV2::PreparedV1,
+            },
+        ];
+        let registry = UpcasterRegistry::new().register(CanceledV2Upcaster);
+        for TestCase {
+            name,
+            model,
+            expected,
+        } in tests
+        {
+            let item: serde_dynamo::Item = serde_dynamo::to_item(model)?;
+            let model: EventStoreModelV2 = serde_dynamo::from_item(item)?;
+            assert_eq!(
+                registry
+                    .upcast(model.payload)
+                    .map_err(anyhow::Error::from_boxed)?,
+                expected,
+                "{name}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_upcast_latest_payload_ok() -> anyhow::Result<()> {
+        let payload = EventStorePayload::CreatedV1 {
+            cart_id: "cart".to_string(),
+            items: Vec::new(),
+        };
+        assert_eq!(
+            upcasters()
+                .upcast(payload.clone())
+                .map_err(anyhow::Error::from_boxed)?,
+            payload
+        );
+        Ok(())
+    }
+}
@@ -8,3 +8,9 @@ pub mod query;
 pub(crate) const AGGREGATE_TABLE_NAME: &str = "order-aggregate";
 pub(crate) const EVENT_SEQUENCE_TABLE_NAME: &str = "order-event-sequence";
 pub(crate) const EVENT_STORE_TABLE_NAME: &str = "order-event-store";
+pub(crate) const IDEMPOTENCY_KEY_TABLE_NAME: &str = "order-idempotency-key";
+pub(crate) const OUTBOX_TABLE_NAME: &str = "order-outbox";
+pub(crate) const ORDER_BY_CART_TABLE_NAME: &str = "order-projection-order-by-cart";
+pub(crate) const ORDERS_BY_TENANT_STATUS_TABLE_NAME: &str =
+    "order-projection-orders-by-tenant-status";
+pub(crate) const ORDERS_BY_STATUS_TABLE_NAME: &str = "order-projection-orders-by-status";
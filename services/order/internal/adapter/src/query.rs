@@ -1,4 +1,6 @@
 /// クエリモデルを定義するモジュール
 pub mod model;
+/// クエリ操作で注文をキーで検索するためのプロジェクション関連のモジュール
+pub mod projection;
 /// リポジトリ関連のモジュール
 pub mod repository;
@@ -0,0 +1,792 @@
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Arc, RwLock};
+
+use anyhow::Context as _;
+use aws_sdk_dynamodb::error::SdkError;
+use aws_sdk_dynamodb::operation::put_item::PutItemError;
+use aws_sdk_dynamodb::operation::transact_write_items::TransactWriteItemsError;
+use aws_sdk_dynamodb::types::{
+    AttributeValue, ConditionCheck, Delete, DeleteRequest, Put, TransactWriteItem, WriteRequest,
+};
+use event_store::{Page, PageToken};
+use projection::{Projector, ProjectorError};
+
+use crate::command::model::{AggregatePayload, EventStoreModel, EventStorePayload};
+use crate::command::persistence::EventStore;
+use crate::command::repository::CommandRepository;
+use crate::query::model::OrderStatus;
+use crate::{
+    ORDERS_BY_STATUS_TABLE_NAME, ORDERS_BY_TENANT_STATUS_TABLE_NAME, ORDER_BY_CART_TABLE_NAME,
+};
+
+/// 1つのトランザクションで書き込める項目の最大数
+const MAX_TRANSACT_ITEMS: usize = 100;
+/// プロジェクションの書き込みが他のトランザクションと競合した場合に書き込む最大試行回数
+const MAX_PUT_ATTEMPTS: usize = 3;
+/// プロジェクションのキーに利用する全ての注文の状態
+const STATUSES: [OrderStatus; 5] = [
+    OrderStatus::Created,
+    OrderStatus::Prepared,
+    OrderStatus::PickedUp,
+    OrderStatus::Delivered,
+    OrderStatus::Canceled,
+];
+
+/// プロジェクションのキーに利用する注文の状態
+pub(crate) fn status_key(status: &OrderStatus) -> &'static str {
+    match status {
+        OrderStatus::Created => "created",
+        OrderStatus::Prepared => "prepared",
+        OrderStatus::PickedUp => "picked_up",
+        OrderStatus::Delivered => "delivered",
+        OrderStatus::Canceled => "canceled",
+    }
+}
+
+/// テナントと注文の状態の組み合わせを1つのキーにする
+fn tenant_status_key(tenant_id: &str, status: &OrderStatus) -> String {
+    format!("{tenant_id}#{}", status_key(status))
+}
+
+/// カートIDの行にプロジェクションの状態とバージョンを書き込む
+///
+/// より新しいバージョンの注文を反映済みの場合は書き込まずに `false` を返す。
+/// 同じバージョンは書き込むので、途中で失敗したプロジェクションを再実行できる
+async fn claim(
+    client: &aws_sdk_dynamodb::Client,
+    projection: &OrderProjection,
+) -> Result<bool, anyhow::Error> {
+    // NOTE: 属性名が予約語と衝突しないように、式の属性名で置き換える
+    let result = client
+        .put_item()
+        .table_name(ORDER_BY_CART_TABLE_NAME)
+        .item("cart_id", AttributeValue::S(projection.cart_id.clone()))
+        .item("order_id", AttributeValue::S(projection.order_id.clone()))
+        .item(
+            "status",
+            AttributeValue::S(status_key(&projection.status).to_string()),
+        )
+        .item("version", AttributeValue::N(projection.version.to_string()))
+        .condition_expression("attribute_not_exists(#version) OR #version <= :version")
+        .expression_attribute_names("#version", "version")
+        .expression_attribute_values(
+            ":version",
+            AttributeValue::N(projection.version.to_string()),
+        )
+        .send()
+        .await;
+    match result {
+        Ok(_) => Ok(true),
+        Err(e)
+            if matches!(
+                e.as_service_error(),
+                Some(PutItemError::ConditionalCheckFailedException(_))
+            ) =>
+        {
+            Ok(false)
+        }
+        Err(e) => Err(anyhow::Error::from(e).context("put order by cart")),
+    }
+}
+
+/// 注文のプロジェクションを現在の状態に置き換える書き込みを作成する
+///
+/// 以前の状態を読み取らずに置き換えられるように、現在の状態の行を書き込んで他の全ての状態の行を削除する
+fn write_items(projection: &OrderProjection) -> Result<Vec<TransactWriteItem>, anyhow::Error> {
+    let mut puts = Vec::new();
+    let mut deletes = Vec::new();
+    for status in &STATUSES {
+        let (items, write) = if *status == projection.status {
+            (&mut puts, put_item as fn(_, _) -> _)
+        } else {
+            (&mut deletes, delete_item as fn(_, _) -> _)
+        };
+        for tenant_id in &projection.tenant_ids {
+            items.push(write(
+                ORDERS_BY_TENANT_STATUS_TABLE_NAME,
+                [
+                    ("tenant_status", tenant_status_key(tenant_id, status)),
+                    ("order_id", projection.order_id.clone()),
+                ],
+            )?);
+        }
+        items.push(write(
+            ORDERS_BY_STATUS_TABLE_NAME,
+            [
+                ("status", status_key(status).to_string()),
+                ("order_id", projection.order_id.clone()),
+            ],
+        )?);
+    }
+    // NOTE: 書き込みの途中で読み取っても注文が見つからなくならないように、現在の状態の行を先に書き込む
+    puts.append(&mut deletes);
+    Ok(puts)
+}
+
+/// 書き込みを1つのトランザクションに収まる数に分割する
+///
+/// テナントの数が多くても書き込めるように、トランザクションごとにカートIDの行のバージョンが変わっていないことを条件にする。
+/// 全ての書き込みは何度実行しても結果が変わらないので、途中で失敗しても最初から再実行できる
+fn transactions(
+    projection: &OrderProjection,
+) -> Result<Vec<Vec<TransactWriteItem>>, anyhow::Error> {
+    let condition = ConditionCheck::builder()
+        .table_name(ORDER_BY_CART_TABLE_NAME)
+        .key("cart_id", AttributeValue::S(projection.cart_id.clone()))
+        .condition_expression("#version = :version")
+        .expression_attribute_names("#version", "version")
+        .expression_attribute_values(
+            ":version",
+            AttributeValue::N(projection.version.to_string()),
+        )
+        .build()
+        .with_context(|| format!("build condition check: {ORDER_BY_CART_TABLE_NAME}"))?;
+    Ok(write_items(projection)?
+        .chunks(MAX_TRANSACT_ITEMS - 1)
+        .map(|items| {
+            let mut transaction = Vec::with_capacity(items.len() + 1);
+            transaction.push(
+                TransactWriteItem::builder()
+                    .condition_check(condition.clone())
+                    .build(),
+            );
+            transaction.extend_from_slice(items);
+            transaction
+        })
+        .collect())
+}
+
+/// トランザクションが取り消された理由に `code` が含まれる場合は `true` を返す
+fn is_canceled_by(e: &SdkError<TransactWriteItemsError>, code: &str) -> bool {
+    matches!(
+        e.as_service_error(),
+        Some(TransactWriteItemsError::TransactionCanceledException(e))
+            if e.cancellation_reasons().iter().any(|reason| reason.code() == Some(code))
+    )
+}
+
+/// 注文の現在の状態から作成するプロジェクション
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct OrderProjection {
+    order_id: String,
+    cart_id: String,
+    tenant_ids: Vec<String>,
+    status: OrderStatus,
+    /// プロジェクションを作成した集約のバージョン
+    version: u64,
+}
+
+/// プロセスのメモリ上に保存するプロジェクション
+#[derive(Debug, Default)]
+struct InMemoryProjections {
+    /// カートID → (注文ID, 集約のバージョン)
+    order_by_cart: HashMap<String, (String, u64)>,
+    /// (テナントID, 注文の状態, 注文ID)
+    orders_by_tenant_status: BTreeSet<(String, &'static str, String)>,
+    /// (注文の状態, 注文ID)
+    orders_by_status: BTreeSet<(&'static str, String)>,
+}
+
+#[derive(Debug, Clone)]
+enum Backend {
+    DynamoDb(aws_sdk_dynamodb::Client),
+    InMemory(Arc<RwLock<InMemoryProjections>>),
+}
+
+/// クエリ操作で注文をキーで検索するためのプロジェクションを保存するストア
+///
+/// - カートIDから注文IDを引くプロジェクション
+/// - テナントと注文の状態から注文IDの一覧を引くプロジェクション
+/// - 注文の状態から注文IDの一覧を引くプロジェクション
+#[derive(Debug, Clone)]
+pub struct ProjectionStore(Backend);
+
+impl ProjectionStore {
+    /// `DynamoDB` のテーブルに保存するストアを作成する
+    #[must_use]
+    pub fn dynamodb(dynamodb: aws_sdk_dynamodb::Client) -> Self {
+        Self(Backend::DynamoDb(dynamodb))
+    }
+
+    /// プロセスのメモリ上に保存するストアを作成する
+    ///
+    /// `DynamoDB Streams` を使えないイベントストアのバックエンドで、コマンド操作と同じプロセスでプロジェクションを更新する場合に利用する
+    #[must_use]
+    pub fn in_memory() -> Self {
+        Self(Backend::InMemory(Arc::default()))
+    }
+
+    /// 注文のプロジェクションを現在の状態に置き換える
+    #[tracing::instrument(skip(self), err(Debug))]
+    async fn put(&self, projection: &OrderProjection) -> Result<(), anyhow::Error> {
+        let status = status_key(&projection.status);
+        match &self.0 {
+            Backend::DynamoDb(client) => {
+                if !claim(client, projection).await? {
+                    return Ok(());
+                }
+                for items in transactions(projection)? {
+                    let mut attempts = 0;
+                    loop {
+                        attempts += 1;
+                        match client
+                            .transact_write_items()
+                            .set_transact_items(Some(items.clone()))
+                            .send()
+                            .await
+                        {
+                            Ok(_) => break,
+                            // NOTE: より新しいバージョンのプロジェクションが書き込みを始めた場合は、そちらに任せて中断する
+                            Err(e) if is_canceled_by(&e, "ConditionalCheckFailed") => return Ok(()),
+                            Err(e)
+                                if attempts < MAX_PUT_ATTEMPTS
+                                    && is_canceled_by(&e, "TransactionConflict") => {}
+                            Err(e) => {
+                                return Err(
+                                    anyhow::Error::from(e).context("transact write projections")
+                                )
+                            }
+                        }
+                    }
+                }
+                Ok(())
+            }
+            Backend::InMemory(projections) => {
+                let mut projections = projections
+                    .write()
+                    .map_err(|e| anyhow::anyhow!("{e}"))
+                    .with_context(|| "lock projections")?;
+                if projections
+                    .order_by_cart
+                    .get(&projection.cart_id)
+                    .is_some_and(|(_, version)| *version > projection.version)
+                {
+                    return Ok(());
+                }
+                projections.order_by_cart.insert(
+                    projection.cart_id.clone(),
+                    (projection.order_id.clone(), projection.version),
+                );
+                projections
+                    .orders_by_tenant_status
+                    .retain(|(_, _, order_id)| order_id != &projection.order_id);
+                for tenant_id in &projection.tenant_ids {
+                    projections.orders_by_tenant_status.insert((
+                        tenant_id.clone(),
+                        status,
+                        projection.order_id.clone(),
+                    ));
+                }
+                projections
+                    .orders_by_status
+                    .retain(|(_, order_id)| order_id != &projection.order_id);
+                projections
+                    .orders_by_status
+                    .insert((status, projection.order_id.clone()));
+                Ok(())
+            }
+        }
+    }
+
+    /// 全てのプロジェクションを削除する
+    ///
+    /// # Errors
+    #[tracing::instrument(skip(self), err(Debug))]
+    pub async fn reset(&self) -> Result<(), anyhow::Error> {
+        match &self.0 {
+            Backend::DynamoDb(client) => {
+                delete_all(client, ORDER_BY_CART_TABLE_NAME, &["cart_id"]).await?;
+                delete_all(
+                    client,
+                    ORDERS_BY_TENANT_STATUS_TABLE_NAME,
+                    &["tenant_status", "order_id"],
+                )
+                .await?;
+                delete_all(client, ORDERS_BY_STATUS_TABLE_NAME, &["status", "order_id"]).await
+            }
+            Backend::InMemory(projections) => {
+                *projections
+                    .write()
+                    .map_err(|e| anyhow::anyhow!("{e}"))
+                    .with_context(|| "lock projections")? = InMemoryProjections::default();
+                Ok(())
+            }
+        }
+    }
+
+    /// カートから作成した注文のIDを取得する
+    #[tracing::instrument(skip(self), err(Debug), ret)]
+    pub(crate) async fn get_order_id_by_cart_id(
+        &self,
+        cart_id: &str,
+    ) -> Result<Option<String>, anyhow::Error> {
+        match &self.0 {
+            Backend::DynamoDb(client) => {
+                let output = client
+                    .get_item()
+                    .table_name(ORDER_BY_CART_TABLE_NAME)
+                    .key("cart_id", AttributeValue::S(cart_id.to_string()))
+                    .send()
+                    .await
+                    .with_context(|| "get order by cart")?;
+                Ok(output
+                    .item
+                    .and_then(|item| item.get("order_id").cloned())
+                    .and_then(|order_id| order_id.as_s().ok().cloned()))
+            }
+            Backend::InMemory(projections) => Ok(projections
+                .read()
+                .map_err(|e| anyhow::anyhow!("{e}"))
+                .with_context(|| "lock projections")?
+                .order_by_cart
+                .get(cart_id)
+                .map(|(order_id, _)| order_id.clone())),
+        }
+    }
+
+    /// テナントの商品を含む指定した状態の注文のIDを昇順で1ページ分取得する
+    #[tracing::instrument(skip(self), err(Debug), ret)]
+    pub(crate) async fn list_order_ids_by_tenant_status(
+        &self,
+        tenant_id: &str,
+        status: &OrderStatus,
+        page_size: usize,
+        page_token: Option<&PageToken>,
+    ) -> Result<Page<String>, anyhow::Error> {
+        match &self.0 {
+            Backend::DynamoDb(client) => {
+                query_order_ids(
+                    client,
+                    ORDERS_BY_TENANT_STATUS_TABLE_NAME,
+                    "tenant_status",
+                    tenant_status_key(tenant_id, status),
+                    page_size,
+                    page_token,
+                )
+                .await
+            }
+            Backend::InMemory(projections) => {
+                let status = status_key(status);
+                let order_ids: Vec<_> = projections
+                    .read()
+                    .map_err(|e| anyhow::anyhow!("{e}"))
+                    .with_context(|| "lock projections")?
+                    .orders_by_tenant_status
+                    .iter()
+                    .filter(|(id, s, _)| id == tenant_id && *s == status)
+                    .map(|(_, _, order_id)| order_id.clone())
+                    .collect();
+                Ok(Page::from_sorted(
+                    order_ids,
+                    page_size,
+                    page_token,
+                    String::as_str,
+                ))
+            }
+        }
+    }
+
+    /// 指定した状態の注文のIDを昇順で1ページ分取得する
+    #[tracing::instrument(skip(self), err(Debug), ret)]
+    pub(crate) async fn list_order_ids_by_status(
+        &self,
+        status: &OrderStatus,
+        page_size: usize,
+        page_token: Option<&PageToken>,
+    ) -> Result<Page<String>, anyhow::Error> {
+        match &self.0 {
+            Backend::DynamoDb(client) => {
+                query_order_ids(
+                    client,
+                    ORDERS_BY_STATUS_TABLE_NAME,
+                    "status",
+                    status_key(status).to_string(),
+                    page_size,
+                    page_token,
+                )
+                .await
+            }
+            Backend::InMemory(projections) => {
+                let status = status_key(status);
+                let order_ids: Vec<_> = projections
+                    .read()
+                    .map_err(|e| anyhow::anyhow!("{e}"))
+                    .with_context(|| "lock projections")?
+                    .orders_by_status
+                    .iter()
+                    .filter(|(s, _)| *s == status)
+                    .map(|(_, order_id)| order_id.clone())
+                    .collect();
+                Ok(Page::from_sorted(
+                    order_ids,
+                    page_size,
+                    page_token,
+                    String::as_str,
+                ))
+            }
+        }
+    }
+}
+
+fn put_item<const N: usize>(
+    table_name: &str,
+    attributes: [(&str, String); N],
+) -> Result<TransactWriteItem, anyhow::Error> {
+    let put = Put::builder()
+        .table_name(table_name)
+        .set_item(Some(
+            attributes
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), AttributeValue::S(value)))
+                .collect(),
+        ))
+        .build()
+        .with_context(|| format!("build put item: {table_name}"))?;
+    Ok(TransactWriteItem::builder().put(put).build())
+}
+
+fn delete_item<const N: usize>(
+    table_name: &str,
+    key: [(&str, String); N],
+) -> Result<TransactWriteItem, anyhow::Error> {
+    let delete = Delete::builder()
+        .table_name(table_name)
+        .set_key(Some(
+            key.into_iter()
+                .map(|(name, value)| (name.to_string(), AttributeValue::S(value)))
+                .collect(),
+        ))
+        .build()
+        .with_context(|| format!("build delete item: {table_name}"))?;
+    Ok(TransactWriteItem::builder().delete(delete).build())
+}
+
+/// テーブルの全ての行を削除する
+async fn delete_all(
+    client: &aws_sdk_dynamodb::Client,
+    table_name: &str,
+    key_names: &[&str],
+) -> Result<(), anyhow::Error> {
+    let mut exclusive_start_key = None;
+    loop {
+        // NOTE: `status` は予約語なので、キーの属性名を式の属性名で置き換える
+        let mut request = client.scan().table_name(table_name).projection_expression(
+            (0..key_names.len())
+                .map(|i| format!("#key{i}"))
+                .collect::<Vec<_>>()
+                .join(", "),
+        );
+        for (i, key_name) in key_names.iter().enumerate() {
+            request = request.expression_attribute_names(format!("#key{i}"), *key_name);
+        }
+        let output = request
+            .set_exclusive_start_key(exclusive_start_key)
+            .send()
+            .await
+            .with_context(|| format!("scan {table_name}"))?;
+        let requests = output
+            .items()
+            .iter()
+            .map(|key| {
+                let delete = DeleteRequest::builder()
+                    .set_key(Some(key.clone()))
+                    .build()
+                    .with_context(|| format!("build delete request: {table_name}"))?;
+                Ok(WriteRequest::builder().delete_request(delete).build())
+            })
+            .collect::<Result<Vec<_>, anyhow::Error>>()?;
+        // NOTE: 1回のバッチ書き込みで削除できる行の数に上限があるので分割する
+        for requests in requests.chunks(25) {
+            let mut requests = requests.to_vec();
+            while !requests.is_empty() {
+                let output = client
+                    .batch_write_item()
+                    .request_items(table_name, requests)
+                    .send()
+                    .await
+                    .with_context(|| format!("batch delete {table_name}"))?;
+                requests = output
+                    .unprocessed_items
+                    .and_then(|mut items| items.remove(table_name))
+                    .unwrap_or_default();
+            }
+        }
+        exclusive_start_key = output.last_evaluated_key;
+        if exclusive_start_key.is_none() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// パーティションキーが一致する行の注文IDを、ソートキーの昇順でトークンの注文IDより後から1ページ分取得する
+///
+/// 続きの有無を判定するために1件多く読み取り、次のトークンは最後に返した注文IDから作る
+async fn query_order_ids(
+    client: &aws_sdk_dynamodb::Client,
+    table_name: &str,
+    partition_key: &str,
+    value: String,
+    page_size: usize,
+    page_token: Option<&PageToken>,
+) -> Result<Page<String>, anyhow::Error> {
+    let mut order_ids = Vec::new();
+    let mut exclusive_start_key = page_token.map(|token| {
+        HashMap::from([
+            (partition_key.to_string(), AttributeValue::S(value.clone())),
+            (
+                "order_id".to_string(),
+                AttributeValue::S(token.key().to_string()),
+            ),
+        ])
+    });
+    // NOTE: 1回のクエリで読み取れるサイズに上限があるので、件数が足りない場合は続きから読み取る
+    while order_ids.len() <= page_size {
+        let limit = i32::try_from(page_size + 1 - order_ids.len()).unwrap_or(i32::MAX);
+        let output = client
+            .query()
+            .table_name(table_name)
+            .key_condition_expression("#key = :value")
+            .expression_attribute_names("#key", partition_key)
+            .expression_attribute_values(":value", AttributeValue::S(value.clone()))
+            .limit(limit)
+            .set_exclusive_start_key(exclusive_start_key)
+            .send()
+            .await
+            .with_context(|| format!("query {table_name}"))?;
+        order_ids.extend(output.items().iter().filter_map(|item| {
+            item.get("order_id")
+                .and_then(|order_id| order_id.as_s().ok())
+                .cloned()
+        }));
+        exclusive_start_key = output.last_evaluated_key;
+        if exclusive_start_key.is_none() {
+            break;
+        }
+    }
+    Ok(Page::from_sorted(
+        order_ids,
+        page_size,
+        None,
+        String::as_str,
+    ))
+}
+
+/// 注文のイベントからクエリ操作のプロジェクションを更新するプロジェクター
+#[derive(Debug, Clone)]
+pub struct OrderProjector {
+    repository: CommandRepository,
+    store: ProjectionStore,
+}
+
+impl OrderProjector {
+    #[must_use]
+    pub fn new(event_store: EventStore, store: ProjectionStore) -> Self {
+        Self {
+            repository: CommandRepository::new(event_store),
+            store,
+        }
+    }
+
+    /// 注文の現在の状態をプロジェクションに反映する
+    ///
+    /// イベントの内容ではなくイベントストアから復元した現在の状態を反映するので、
+    /// 同じイベントを何度反映しても、イベントの順序が入れ替わっても結果は変わらない
+    ///
+    /// # Errors
+    #[tracing::instrument(skip(self), err(Debug))]
+    pub async fn project(&self, order_id: &str) -> Result<(), anyhow::Error> {
+        use kernel::command::processor::CommandProcessor as _;
+
+        let id = order_id
+            .parse()
+            .with_context(|| format!("parse order id: {order_id}"))?;
+        let aggregate = match self.repository.get(id).await? {
+            Ok(Some(aggregate)) => aggregate,
+            Ok(None) => return Ok(()),
+            Err(e) => return Err(anyhow::Error::from(e).context("restore aggregate")),
+        };
+        let version = aggregate.version();
+        let AggregatePayload::V2 {
+            cart_id,
+            order_status,
+            tenant_ids,
+            ..
+        } = AggregatePayload::from(aggregate)
+        else {
+            anyhow::bail!("aggregate payload is not latest");
+        };
+        self.store
+            .put(&OrderProjection {
+                order_id: order_id.to_string(),
+                cart_id,
+                tenant_ids,
+                status: order_status,
+                version,
+            })
+            .await
+    }
+}
+
+impl Projector<EventStorePayload> for OrderProjector {
+    async fn apply(&self, model: &EventStoreModel) -> Result<(), ProjectorError> {
+        Ok(self.project(model.aggregate_id()).await?)
+    }
+
+    /// 集約の現在の状態を反映するので、同じ集約のイベントをまとめて適用する場合は1回だけ反映する
+    async fn apply_batch(&self, models: &[EventStoreModel]) -> Result<(), ProjectorError> {
+        match models.last() {
+            Some(model) => self.apply(model).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn reset(&self) -> Result<(), ProjectorError> {
+        Ok(self.store.reset().await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::model::OrderStatus;
+
+    use super::{transactions, OrderProjection, ProjectionStore};
+
+    #[tokio::test]
+    async fn test_in_memory_projection_store_put() -> anyhow::Result<()> {
+        let store = ProjectionStore::in_memory();
+        let mut projection = OrderProjection {
+            order_id: "order".to_string(),
+            cart_id: "cart".to_string(),
+            tenant_ids: vec!["tenant-1".to_string(), "tenant-2".to_string()],
+            status: OrderStatus::Created,
+            version: 1,
+        };
+        store.put(&projection).await?;
+        projection.status = OrderStatus::Prepared;
+        projection.version = 2;
+        store.put(&projection).await?;
+        store
+            .put(&OrderProjection {
+                status: OrderStatus::Created,
+                version: 1,
+                ..projection.clone()
+            })
+            .await?;
+
+        assert_eq!(
+            store.get_order_id_by_cart_id("cart").await?,
+            Some("order".to_string())
+        );
+        assert!(
+            store
+                .list_order_ids_by_tenant_status("tenant-1", &OrderStatus::Created, 10, None)
+                .await?
+                .items()
+                .is_empty(),
+            "古いバージョンのプロジェクションは反映しない"
+        );
+        assert_eq!(
+            store
+                .list_order_ids_by_tenant_status("tenant-2", &OrderStatus::Prepared, 10, None)
+                .await?
+                .items(),
+            ["order".to_string()]
+        );
+        assert!(store
+            .list_order_ids_by_status(&OrderStatus::Created, 10, None)
+            .await?
+            .items()
+            .is_empty());
+        assert_eq!(
+            store
+                .list_order_ids_by_status(&OrderStatus::Prepared, 10, None)
+                .await?
+                .items(),
+            ["order".to_string()]
+        );
+
+        store.reset().await?;
+        assert_eq!(store.get_order_id_by_cart_id("cart").await?, None);
+        assert!(store
+            .list_order_ids_by_status(&OrderStatus::Prepared, 10, None)
+            .await?
+            .items()
+            .is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_transactions() -> anyhow::Result<()> {
+        let projection = OrderProjection {
+            order_id: "order".to_string(),
+            cart_id: "cart".to_string(),
+            tenant_ids: vec!["tenant-1".to_string(), "tenant-2".to_string()],
+            status: OrderStatus::Prepared,
+            version: 2,
+        };
+        let chunks = transactions(&projection)?;
+        assert_eq!(chunks.len(), 1);
+        let items = &chunks[0];
+        assert!(items[0].condition_check().is_some());
+        assert_eq!(
+            items.iter().filter(|item| item.put().is_some()).count(),
+            3,
+            "現在の状態の行を書き込む"
+        );
+        assert_eq!(
+            items.iter().filter(|item| item.delete().is_some()).count(),
+            12,
+            "他の全ての状態の行を削除する"
+        );
+
+        let projection = OrderProjection {
+            tenant_ids: (0..50).map(|i| format!("tenant-{i}")).collect(),
+            ..projection
+        };
+        let chunks = transactions(&projection)?;
+        assert_eq!(
+            chunks.len(),
+            3,
+            "1つのトランザクションに収まらない場合は分割する"
+        );
+        for items in &chunks {
+            assert!(items.len() <= 100);
+            assert!(
+                items[0].condition_check().is_some(),
+                "全てのトランザクションでバージョンを検証する"
+            );
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_projection_store_list_page() -> anyhow::Result<()> {
+        let store = ProjectionStore::in_memory();
+        for order_id in ["order-3", "order-1", "order-2"] {
+            store
+                .put(&OrderProjection {
+                    order_id: order_id.to_string(),
+                    cart_id: format!("cart-{order_id}"),
+                    tenant_ids: vec!["tenant".to_string()],
+                    status: OrderStatus::Prepared,
+                    version: 1,
+                })
+                .await?;
+        }
+
+        let first = store
+            .list_order_ids_by_status(&OrderStatus::Prepared, 2, None)
+            .await?;
+        assert_eq!(
+            first.items(),
+            ["order-1".to_string(), "order-2".to_string()]
+        );
+        let second = store
+            .list_order_ids_by_status(&OrderStatus::Prepared, 2, first.next_page_token())
+            .await?;
+        assert_eq!(second.items(), ["order-3".to_string()]);
+        assert_eq!(second.next_page_token(), None);
+        Ok(())
+    }
+}
@@ -1,34 +1,69 @@
-use crate::query::model::AggregateModel;
-use crate::AGGREGATE_TABLE_NAME;
+use crate::command::model::from_aggregate;
+use crate::command::persistence::EventStore;
+use crate::command::repository::CommandRepository;
+use crate::query::model::{decode_page_token, into_order, OrderStatus};
+use crate::query::projection::ProjectionStore;
 
 #[derive(Debug, Clone)]
 pub struct QueryRepository {
-    dynamodb: aws_sdk_dynamodb::Client,
+    repository: CommandRepository,
+    projections: ProjectionStore,
 }
 
 impl QueryRepository {
     #[must_use]
-    pub fn new(dynamodb: aws_sdk_dynamodb::Client) -> Self {
-        Self { dynamodb }
+    pub fn new(event_store: EventStore, projections: ProjectionStore) -> Self {
+        Self {
+            repository: CommandRepository::new(event_store),
+            projections,
+        }
     }
 
-    /// 集約テーブルから全てのレコードを取得する
-    #[tracing::instrument(skip(self), err(Debug), ret)]
-    async fn list_aggregate_models(&self) -> Result<Vec<AggregateModel>, anyhow::Error> {
+    /// スナップショットとその後のイベントから注文の現在の状態を取得する
+    ///
+    /// スナップショットの頻度を下げてもクエリの結果が遅れないように、スナップショットより後のイベントも適用する
+    async fn get_order(
+        &self,
+        id: &str,
+    ) -> Result<Option<kernel::query::model::Order>, anyhow::Error> {
         use anyhow::Context as _;
+        use kernel::command::processor::CommandProcessor as _;
 
-        let result = self
-            .dynamodb
-            .scan()
-            .table_name(AGGREGATE_TABLE_NAME)
-            .send()
-            .await
-            .with_context(|| "scan Aggregate table")?;
-        serde_dynamo::from_items(result.items().to_vec())
-            .with_context(|| "from DynamoDB items to aggregate models")
+        let Some(aggregate) = self
+            .repository
+            .get(id.parse()?)
+            .await?
+            .with_context(|| "replay aggregate")?
+        else {
+            return Ok(None);
+        };
+        Ok(Some(
+            into_order(&from_aggregate(aggregate))
+                .with_context(|| "try from aggregate model to order")?,
+        ))
     }
 }
 
+/// プロジェクションから取得した注文IDのページを、次のページのトークンを符号化したクエリモデルのページに変換する
+fn parse_order_ids(
+    page: event_store::Page<String>,
+) -> Result<kernel::query::model::Page<kernel::id::Id<kernel::query::model::Order>>, anyhow::Error>
+{
+    use anyhow::Context as _;
+    use std::str::FromStr as _;
+
+    let (order_ids, next_page_token) = page.into_parts();
+    let ids = order_ids
+        .iter()
+        .map(|id| kernel::id::Id::from_str(id))
+        .collect::<Result<_, _>>()
+        .with_context(|| format!("parse order ids: {order_ids:?}"))?;
+    Ok(kernel::query::model::Page::new(
+        ids,
+        next_page_token.as_ref().map(event_store::PageToken::encode),
+    ))
+}
+
 impl kernel::query::processor::QueryProcessor for QueryRepository {
     #[tracing::instrument(skip(self), err(Debug), ret)]
     async fn get_by_order_id(
@@ -38,33 +73,7 @@ impl kernel::query::processor::QueryProcessor for QueryRepository {
         Result<Option<kernel::query::model::Order>, kernel::query::error::QueryKernelError>,
         anyhow::Error,
     > {
-        use anyhow::Context as _;
-        use aws_sdk_dynamodb::operation::get_item::GetItemError::ResourceNotFoundException;
-        use aws_sdk_dynamodb::types::AttributeValue;
-
-        let result = self
-            .dynamodb
-            .get_item()
-            .table_name(AGGREGATE_TABLE_NAME)
-            .key("id", AttributeValue::S(id.to_string()))
-            .send()
-            .await;
-        if let Err(e) = result {
-            match e.into_service_error() {
-                ResourceNotFoundException(_) => return Ok(Ok(None)),
-                e => return Err(e.into()),
-            }
-        }
-        let Some(item) = result?.item else {
-            return Ok(Ok(None));
-        };
-        let model: AggregateModel = serde_dynamo::from_item(item)
-            .with_context(|| "from DynamoDB item to AggregateModel")?;
-        Ok(Ok(Some(
-            model
-                .try_into()
-                .with_context(|| "try from aggregate model to cart")?,
-        )))
+        Ok(Ok(self.get_order(&id.to_string()).await?))
     }
 
     #[tracing::instrument(skip(self), err(Debug), ret)]
@@ -77,105 +86,74 @@ impl kernel::query::processor::QueryProcessor for QueryRepository {
     > {
         use anyhow::Context as _;
 
-        let models = self
-            .list_aggregate_models()
+        let Some(order_id) = self
+            .projections
+            .get_order_id_by_cart_id(&id.to_string())
             .await
-            .with_context(|| "list aggregate model")?;
-        let Some(model) = models.into_iter().find(|model| match model.payload() {
-            crate::command::model::AggregatePayload::V1 { cart_id, .. } => {
-                cart_id == &id.to_string()
-            }
-        }) else {
+            .with_context(|| "get order id by cart id")?
+        else {
             return Ok(Ok(None));
         };
-        Ok(Ok(Some(
-            model
-                .try_into()
-                .with_context(|| "try from aggregate model to cart")?,
-        )))
+        Ok(Ok(self.get_order(&order_id).await?))
     }
 
     #[tracing::instrument(skip(self), err(Debug), ret)]
     async fn list_tenant_received_order_ids(
         &self,
         tenant_id: kernel::id::Id<kernel::query::model::Tenant>,
+        page: kernel::query::model::PageRequest,
     ) -> Result<
         Result<
-            Vec<kernel::id::Id<kernel::query::model::Order>>,
+            kernel::query::model::Page<kernel::id::Id<kernel::query::model::Order>>,
             kernel::query::error::QueryKernelError,
         >,
         anyhow::Error,
     > {
-        use crate::query::model::OrderStatus;
         use anyhow::Context as _;
-        use kernel::id::Id;
-        use std::str::FromStr as _;
 
-        let models = self
-            .list_aggregate_models()
+        let page_token = match decode_page_token(&page) {
+            Ok(page_token) => page_token,
+            Err(e) => return Ok(Err(e)),
+        };
+        let order_ids = self
+            .projections
+            .list_order_ids_by_tenant_status(
+                &tenant_id.to_string(),
+                &OrderStatus::Created,
+                page.page_size(),
+                page_token.as_ref(),
+            )
             .await
-            .with_context(|| "list aggregate model")?;
-        let order_ids: Vec<_> = models
-            .into_iter()
-            .filter(|model| match model.payload() {
-                crate::command::model::AggregatePayload::V1 {
-                    items,
-                    order_status,
-                    ..
-                } => {
-                    if *order_status != OrderStatus::Created {
-                        return false;
-                    }
-                    items.iter().any(|item| match item {
-                        crate::command::model::Item::V1 { tenant_id: id, .. } => {
-                            id == &tenant_id.to_string()
-                        }
-                    })
-                }
-            })
-            .map(|model| model.id().to_string())
-            .collect();
-        let order_ids: Vec<_> = order_ids
-            .iter()
-            .map(|id| Id::from_str(id))
-            .collect::<Result<_, _>>()
-            .with_context(|| format!("parse order ids: {order_ids:?}"))?;
-        Ok(Ok(order_ids))
+            .with_context(|| "list order ids by tenant and status")?;
+        Ok(Ok(parse_order_ids(order_ids)?))
     }
 
     #[tracing::instrument(skip(self), err(Debug), ret)]
     async fn list_prepared_order_ids(
         &self,
+        page: kernel::query::model::PageRequest,
     ) -> Result<
         Result<
-            Vec<kernel::id::Id<kernel::query::model::Order>>,
+            kernel::query::model::Page<kernel::id::Id<kernel::query::model::Order>>,
             kernel::query::error::QueryKernelError,
         >,
         anyhow::Error,
     > {
-        use crate::query::model::OrderStatus;
         use anyhow::Context as _;
-        use kernel::id::Id;
-        use std::str::FromStr as _;
 
-        let models = self
-            .list_aggregate_models()
+        let page_token = match decode_page_token(&page) {
+            Ok(page_token) => page_token,
+            Err(e) => return Ok(Err(e)),
+        };
+        let order_ids = self
+            .projections
+            .list_order_ids_by_status(
+                &OrderStatus::Prepared,
+                page.page_size(),
+                page_token.as_ref(),
+            )
             .await
-            .with_context(|| "list aggregate model")?;
-        let order_ids: Vec<_> = models
-            .into_iter()
-            .filter(|model| match model.payload() {
-                crate::command::model::AggregatePayload::V1 { order_status, .. } => {
-                    *order_status == OrderStatus::Prepared
-                }
-            })
-            .map(|model| model.id().to_string())
-            .collect();
-        let order_ids: Vec<_> = order_ids
-            .iter()
-            .map(|id| Id::from_str(id))
-            .collect::<Result<_, _>>()
-            .with_context(|| format!("parse order ids: {order_ids:?}"))?;
-        Ok(Ok(order_ids))
+            .with_context(|| "list order ids by status")?;
+        Ok(Ok(parse_order_ids(order_ids)?))
     }
 }
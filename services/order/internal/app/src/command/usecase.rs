@@ -3,54 +3,136 @@ use std::future::Future;
 use kernel::command::command::Command;
 use kernel::command::model::aggregate::Aggregate;
 use kernel::command::model::entity::Cart;
+use kernel::command::model::idempotency::Idempotency;
 use kernel::id::Id;
 
 use super::error::CommandUseCaseError;
 use super::model::Item;
+use super::RetryPolicy;
+
+/// 集約の更新が競合した結果であるか
+fn is_conflict<T>(result: &Result<Result<T, CommandUseCaseError>, anyhow::Error>) -> bool {
+    matches!(result, Ok(Err(CommandUseCaseError::ConcurrencyConflict)))
+}
 
 /// ユースケースのインターフェイス
 pub trait CommandUseCaseExt {
-    /// 注文を作成する
+    /// 注文を作成し、注文IDと集約のバージョンを返す
+    ///
+    /// 注文はカートごとに1つだけ作成し、同じカートの注文が作成済みの場合はその注文IDと集約のバージョンを返す。
+    /// `idempotency_key` を指定した場合は同じ冪等キーで作成済みの注文IDと集約のバージョンを返す。
+    /// 作成済みの注文を返す場合のバージョンは、その後に注文を更新していても注文を作成した時のバージョンになる
     fn create(
         &self,
         cart_id: Id<Cart>,
         items: Vec<Item>,
-    ) -> impl Future<Output = Result<Result<Id<Aggregate>, CommandUseCaseError>, anyhow::Error>> + Send;
+        idempotency_key: Option<String>,
+    ) -> impl Future<Output = Result<Result<(Id<Aggregate>, u64), CommandUseCaseError>, anyhow::Error>>
+           + Send;
 
     /// テナントが商品の準備が完了した
+    ///
+    /// `expected_version` を指定した場合は集約がそのバージョンのときだけ商品の準備を完了する。
+    /// `idempotency_key` を指定した場合は同じ冪等キーで実行済みのコマンドの集約のバージョンを返す
     fn prepared(
         &self,
         id: Id<Aggregate>,
-    ) -> impl Future<Output = Result<Result<(), CommandUseCaseError>, anyhow::Error>> + Send;
+        expected_version: Option<u64>,
+        idempotency_key: Option<String>,
+    ) -> impl Future<Output = Result<Result<u64, CommandUseCaseError>, anyhow::Error>> + Send;
 
     /// 配達員が商品を受け取った
+    ///
+    /// `expected_version` を指定した場合は集約がそのバージョンのときだけ商品を受け取る。
+    /// `idempotency_key` を指定した場合は同じ冪等キーで実行済みのコマンドの集約のバージョンを返す
     fn picked_up(
         &self,
         id: Id<Aggregate>,
-    ) -> impl Future<Output = Result<Result<(), CommandUseCaseError>, anyhow::Error>> + Send;
+        expected_version: Option<u64>,
+        idempotency_key: Option<String>,
+    ) -> impl Future<Output = Result<Result<u64, CommandUseCaseError>, anyhow::Error>> + Send;
 
     /// 商品の受け渡しが完了した
+    ///
+    /// `expected_version` を指定した場合は集約がそのバージョンのときだけ商品の受け渡しを完了する。
+    /// `idempotency_key` を指定した場合は同じ冪等キーで実行済みのコマンドの集約のバージョンを返す
     fn delivered(
         &self,
         id: Id<Aggregate>,
-    ) -> impl Future<Output = Result<Result<(), CommandUseCaseError>, anyhow::Error>> + Send;
+        expected_version: Option<u64>,
+        idempotency_key: Option<String>,
+    ) -> impl Future<Output = Result<Result<u64, CommandUseCaseError>, anyhow::Error>> + Send;
 
     /// 注文を何らかの理由でキャンセルする
+    ///
+    /// `expected_version` を指定した場合は集約がそのバージョンのときだけ注文をキャンセルする。
+    /// `idempotency_key` を指定した場合は同じ冪等キーで実行済みのコマンドの集約のバージョンを返す
     fn cancel(
         &self,
         id: Id<Aggregate>,
-    ) -> impl Future<Output = Result<Result<(), CommandUseCaseError>, anyhow::Error>> + Send;
+        expected_version: Option<u64>,
+        idempotency_key: Option<String>,
+    ) -> impl Future<Output = Result<Result<u64, CommandUseCaseError>, anyhow::Error>> + Send;
 }
 
 /// ユースケースの実態
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct CommandUseCase<P: kernel::command::processor::CommandProcessor> {
     processor: P,
+    retry_policy: RetryPolicy,
 }
 
 impl<P: kernel::command::processor::CommandProcessor> CommandUseCase<P> {
     pub fn new(processor: P) -> Self {
-        Self { processor }
+        Self {
+            processor,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// 集約の更新が競合した場合にコマンドを再実行する方針を設定する
+    #[must_use]
+    pub fn with_retry_policy(self, retry_policy: RetryPolicy) -> Self {
+        Self {
+            retry_policy,
+            ..self
+        }
+    }
+
+    /// 集約を更新して集約のバージョンを返し、更新が競合した場合は [`CommandUseCaseError::ConcurrencyConflict`] を返す
+    ///
+    /// 冪等キーを指定した場合は、集約のバージョンと一緒にイベントと同じトランザクションで保存する
+    async fn update(
+        &self,
+        aggregate: Aggregate,
+        events: Vec<kernel::command::event::Event>,
+        idempotency_key: Option<String>,
+    ) -> Result<Result<u64, CommandUseCaseError>, anyhow::Error> {
+        let version = aggregate.version();
+        let idempotency =
+            idempotency_key.map(|key| Idempotency::new(key, aggregate.id().clone(), version));
+        match self
+            .processor
+            .update(aggregate, events, idempotency)
+            .await?
+        {
+            Ok(()) => Ok(Ok(version)),
+            Err(kernel::command::error::CommandKernelError::ConcurrencyConflict) => {
+                Ok(Err(CommandUseCaseError::ConcurrencyConflict))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// 冪等キーを指定して実行したコマンドの結果を取得する
+    async fn get_idempotency(
+        &self,
+        key: Option<&String>,
+    ) -> Result<Option<Idempotency>, anyhow::Error> {
+        let Some(key) = key else {
+            return Ok(None);
+        };
+        Ok(self.processor.get_idempotency(key.clone()).await??)
     }
 }
 
@@ -63,71 +145,374 @@ where
         &self,
         cart_id: Id<Cart>,
         items: Vec<Item>,
-    ) -> Result<Result<Id<Aggregate>, CommandUseCaseError>, anyhow::Error> {
+        idempotency_key: Option<String>,
+    ) -> Result<Result<(Id<Aggregate>, u64), CommandUseCaseError>, anyhow::Error> {
         use anyhow::Context as _;
 
-        let mut aggregate = Aggregate::default();
-        let id = aggregate.id().clone();
-        let event = aggregate
-            .apply_command(Command::Create {
-                cart_id,
-                items: items.into_iter().map(Into::into).collect(),
-            })?
-            .pop()
-            .with_context(|| "event not present")?;
-        self.processor.create(aggregate, event).await??;
-        Ok(Ok(id))
+        // NOTE: クライアントの冪等キーとカートIDの冪等キーが衝突しないように、異なる接頭辞を付ける
+        let idempotency_key = idempotency_key.map(|key| format!("create:client:{key}"));
+        // NOTE: カートIDの冪等キーを注文の作成イベントと同じトランザクションで保存し、
+        //       クライアントの冪等キーが異なる場合も同じカートの注文を重複して作成しないようにする。
+        //       同じカートの注文の作成と競合した場合は、再実行して先に作成された注文を返す
+        let cart_key = format!("create:cart:{cart_id}");
+        self.retry_policy
+            .run("create", is_conflict, || {
+                let items = items.clone();
+                let idempotency_key = idempotency_key.clone();
+                let cart_key = cart_key.clone();
+                async move {
+                    for key in [idempotency_key.as_ref(), Some(&cart_key)] {
+                        if let Some(idempotency) = self.get_idempotency(key).await? {
+                            return Ok(Ok((
+                                idempotency.aggregate_id().clone(),
+                                idempotency.version(),
+                            )));
+                        }
+                    }
+                    let mut aggregate = Aggregate::default();
+                    let id = aggregate.id().clone();
+                    let event = aggregate
+                        .apply_command(
+                            Command::Create {
+                                cart_id,
+                                items: items.into_iter().map(Into::into).collect(),
+                            },
+                            None,
+                        )?
+                        .pop()
+                        .with_context(|| "event not present")?;
+                    let version = aggregate.version();
+                    let idempotencies = idempotency_key
+                        .into_iter()
+                        .chain([cart_key])
+                        .map(|key| Idempotency::new(key, id.clone(), version))
+                        .collect();
+                    match self
+                        .processor
+                        .create(aggregate, event, idempotencies)
+                        .await?
+                    {
+                        Ok(()) => Ok(Ok((id, version))),
+                        Err(kernel::command::error::CommandKernelError::ConcurrencyConflict) => {
+                            Ok(Err(CommandUseCaseError::ConcurrencyConflict))
+                        }
+                        Err(e) => Err(e.into()),
+                    }
+                }
+            })
+            .await
     }
 
     #[tracing::instrument(skip(self), err(Debug), ret)]
     async fn prepared(
         &self,
         id: Id<Aggregate>,
-    ) -> Result<Result<(), CommandUseCaseError>, anyhow::Error> {
-        let Some(mut aggregate) = self.processor.get(id).await?? else {
-            return Ok(Err(CommandUseCaseError::AggregateNotFound));
-        };
-        let events = aggregate.apply_command(Command::Prepared)?;
-        self.processor.update(aggregate, events).await??;
-        Ok(Ok(()))
+        expected_version: Option<u64>,
+        idempotency_key: Option<String>,
+    ) -> Result<Result<u64, CommandUseCaseError>, anyhow::Error> {
+        let idempotency_key = idempotency_key.map(|key| format!("prepared:{id}:{key}"));
+        self.retry_policy
+            .run("prepared", is_conflict, || {
+                let id = id.clone();
+                let idempotency_key = idempotency_key.clone();
+                async move {
+                    if let Some(idempotency) =
+                        self.get_idempotency(idempotency_key.as_ref()).await?
+                    {
+                        return Ok(Ok(idempotency.version()));
+                    }
+                    let Some(mut aggregate) = self.processor.get(id).await?? else {
+                        return Ok(Err(CommandUseCaseError::AggregateNotFound));
+                    };
+                    let events = match aggregate.apply_command(Command::Prepared, expected_version)
+                    {
+                        Ok(events) => events,
+                        Err(kernel::command::error::CommandKernelError::VersionMismatch {
+                            expected,
+                            actual,
+                        }) => {
+                            return Ok(Err(CommandUseCaseError::VersionMismatch {
+                                expected,
+                                actual,
+                            }))
+                        }
+                        Err(e) => return Err(e.into()),
+                    };
+                    self.update(aggregate, events, idempotency_key).await
+                }
+            })
+            .await
     }
 
     #[tracing::instrument(skip(self), err(Debug), ret)]
     async fn picked_up(
         &self,
         id: Id<Aggregate>,
-    ) -> Result<Result<(), CommandUseCaseError>, anyhow::Error> {
-        let Some(mut aggregate) = self.processor.get(id).await?? else {
-            return Ok(Err(CommandUseCaseError::AggregateNotFound));
-        };
-        let events = aggregate.apply_command(Command::PickedUp)?;
-        self.processor.update(aggregate, events).await??;
-        Ok(Ok(()))
+        expected_version: Option<u64>,
+        idempotency_key: Option<String>,
+    ) -> Result<Result<u64, CommandUseCaseError>, anyhow::Error> {
+        let idempotency_key = idempotency_key.map(|key| format!("picked_up:{id}:{key}"));
+        self.retry_policy
+            .run("picked_up", is_conflict, || {
+                let id = id.clone();
+                let idempotency_key = idempotency_key.clone();
+                async move {
+                    if let Some(idempotency) =
+                        self.get_idempotency(idempotency_key.as_ref()).await?
+                    {
+                        return Ok(Ok(idempotency.version()));
+                    }
+                    let Some(mut aggregate) = self.processor.get(id).await?? else {
+                        return Ok(Err(CommandUseCaseError::AggregateNotFound));
+                    };
+                    let events = match aggregate.apply_command(Command::PickedUp, expected_version)
+                    {
+                        Ok(events) => events,
+                        Err(kernel::command::error::CommandKernelError::VersionMismatch {
+                            expected,
+                            actual,
+                        }) => {
+                            return Ok(Err(CommandUseCaseError::VersionMismatch {
+                                expected,
+                                actual,
+                            }))
+                        }
+                        Err(e) => return Err(e.into()),
+                    };
+                    self.update(aggregate, events, idempotency_key).await
+                }
+            })
+            .await
     }
 
     #[tracing::instrument(skip(self), err(Debug), ret)]
     async fn delivered(
         &self,
         id: Id<Aggregate>,
-    ) -> Result<Result<(), CommandUseCaseError>, anyhow::Error> {
-        let Some(mut aggregate) = self.processor.get(id).await?? else {
-            return Ok(Err(CommandUseCaseError::AggregateNotFound));
-        };
-        let events = aggregate.apply_command(Command::Delivered)?;
-        self.processor.update(aggregate, events).await??;
-        Ok(Ok(()))
+        expected_version: Option<u64>,
+        idempotency_key: Option<String>,
+    ) -> Result<Result<u64, CommandUseCaseError>, anyhow::Error> {
+        let idempotency_key = idempotency_key.map(|key| format!("delivered:{id}:{key}"));
+        self.retry_policy
+            .run("delivered", is_conflict, || {
+                let id = id.clone();
+                let idempotency_key = idempotency_key.clone();
+                async move {
+                    if let Some(idempotency) =
+                        self.get_idempotency(idempotency_key.as_ref()).await?
+                    {
+                        return Ok(Ok(idempotency.version()));
+                    }
+                    let Some(mut aggregate) = self.processor.get(id).await?? else {
+                        return Ok(Err(CommandUseCaseError::AggregateNotFound));
+                    };
+                    let events = match aggregate.apply_command(Command::Delivered, expected_version)
+                    {
+                        Ok(events) => events,
+                        Err(kernel::command::error::CommandKernelError::VersionMismatch {
+                            expected,
+                            actual,
+                        }) => {
+                            return Ok(Err(CommandUseCaseError::VersionMismatch {
+                                expected,
+                                actual,
+                            }))
+                        }
+                        Err(e) => return Err(e.into()),
+                    };
+                    self.update(aggregate, events, idempotency_key).await
+                }
+            })
+            .await
     }
 
     #[tracing::instrument(skip(self), err(Debug), ret)]
     async fn cancel(
         &self,
         id: Id<Aggregate>,
-    ) -> Result<Result<(), CommandUseCaseError>, anyhow::Error> {
-        let Some(mut aggregate) = self.processor.get(id).await?? else {
-            return Ok(Err(CommandUseCaseError::AggregateNotFound));
-        };
-        let events = aggregate.apply_command(Command::Cancel)?;
-        self.processor.update(aggregate, events).await??;
-        Ok(Ok(()))
+        expected_version: Option<u64>,
+        idempotency_key: Option<String>,
+    ) -> Result<Result<u64, CommandUseCaseError>, anyhow::Error> {
+        let idempotency_key = idempotency_key.map(|key| format!("cancel:{id}:{key}"));
+        self.retry_policy
+            .run("cancel", is_conflict, || {
+                let id = id.clone();
+                let idempotency_key = idempotency_key.clone();
+                async move {
+                    if let Some(idempotency) =
+                        self.get_idempotency(idempotency_key.as_ref()).await?
+                    {
+                        return Ok(Ok(idempotency.version()));
+                    }
+                    let Some(mut aggregate) = self.processor.get(id).await?? else {
+                        return Ok(Err(CommandUseCaseError::AggregateNotFound));
+                    };
+                    let events = match aggregate.apply_command(Command::Cancel, expected_version) {
+                        Ok(events) => events,
+                        Err(kernel::command::error::CommandKernelError::VersionMismatch {
+                            expected,
+                            actual,
+                        }) => {
+                            return Ok(Err(CommandUseCaseError::VersionMismatch {
+                                expected,
+                                actual,
+                            }))
+                        }
+                        Err(e) => return Err(e.into()),
+                    };
+                    self.update(aggregate, events, idempotency_key).await
+                }
+            })
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    use kernel::command::error::CommandKernelError;
+    use kernel::command::event::Event;
+    use kernel::command::model::aggregate::Aggregate;
+    use kernel::command::model::entity::Cart;
+    use kernel::command::model::idempotency::Idempotency;
+    use kernel::command::processor::MockCommandProcessor;
+    use kernel::id::Id;
+
+    use super::{CommandUseCase, CommandUseCaseExt};
+    use crate::command::model::Item;
+
+    type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    /// 保存した冪等キーを返すプロセッサーを作成する
+    fn processor(idempotencies: &Arc<Mutex<HashMap<String, Idempotency>>>) -> MockCommandProcessor {
+        let mut processor = MockCommandProcessor::new();
+        let idempotencies = Arc::clone(idempotencies);
+        processor.expect_get_idempotency().returning(move |key| {
+            let idempotency = idempotencies.lock().unwrap().get(&key).cloned();
+            Box::pin(async move { Ok(Ok(idempotency)) })
+        });
+        processor
+    }
+
+    #[tokio::test]
+    async fn test_create_same_cart_ok() -> Result<(), Error> {
+        let idempotencies = Arc::new(Mutex::new(HashMap::new()));
+        let mut processor = processor(&idempotencies);
+        {
+            let idempotencies = Arc::clone(&idempotencies);
+            processor
+                .expect_create()
+                .times(1)
+                .returning(move |_, event, keys| {
+                    assert!(matches!(event, Event::Created { .. }));
+                    let mut idempotencies = idempotencies.lock().unwrap();
+                    for idempotency in keys {
+                        idempotencies.insert(idempotency.key().to_string(), idempotency);
+                    }
+                    Box::pin(async { Ok(Ok(())) })
+                });
+        }
+        let usecase = CommandUseCase::new(processor);
+        let cart_id = Id::generate();
+        let items = vec![Item::new(Id::generate(), Id::generate(), 1)];
+
+        let (id, version) = usecase
+            .create(cart_id, items.clone(), Some("first".to_string()))
+            .await??;
+        assert_eq!(version, 1);
+        assert_eq!(
+            usecase
+                .create(cart_id, items.clone(), Some("second".to_string()))
+                .await??,
+            (id.clone(), version),
+            "冪等キーが異なっても同じカートの場合は作成済みの注文を返す"
+        );
+        assert_eq!(
+            usecase
+                .create(Id::generate(), items, Some("first".to_string()))
+                .await??,
+            (id, version),
+            "同じ冪等キーの場合は作成済みの注文を返す"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_create_client_key_not_collide_with_cart_key() -> Result<(), Error> {
+        let idempotencies = Arc::new(Mutex::new(HashMap::new()));
+        let mut processor = processor(&idempotencies);
+        {
+            let idempotencies = Arc::clone(&idempotencies);
+            processor
+                .expect_create()
+                .times(2)
+                .returning(move |_, _, keys| {
+                    let mut idempotencies = idempotencies.lock().unwrap();
+                    for idempotency in keys {
+                        idempotencies.insert(idempotency.key().to_string(), idempotency);
+                    }
+                    Box::pin(async { Ok(Ok(())) })
+                });
+        }
+        let usecase = CommandUseCase::new(processor);
+        let items = vec![Item::new(Id::generate(), Id::generate(), 1)];
+        let other_cart_id: Id<Cart> = Id::generate();
+
+        let (other_id, _) = usecase
+            .create(other_cart_id, items.clone(), None)
+            .await??;
+        let (id, _) = usecase
+            .create(Id::generate(), items, Some(format!("cart:{other_cart_id}")))
+            .await??;
+        assert_ne!(
+            id, other_id,
+            "カートIDの冪等キーと同じ形式の冪等キーでも別のカートの注文を返さない"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_create_cart_conflict_ok() -> Result<(), Error> {
+        let idempotencies = Arc::new(Mutex::new(HashMap::new()));
+        let mut processor = processor(&idempotencies);
+        let cart_id = Id::generate();
+        let existing_id: Id<Aggregate> = Id::generate();
+        {
+            let idempotencies = Arc::clone(&idempotencies);
+            let existing_id = existing_id.clone();
+            processor
+                .expect_create()
+                .times(1)
+                .returning(move |_, _, keys| {
+                    // NOTE: 別のリクエストが先に同じカートの注文を作成したので、カートIDの冪等キーが競合する
+                    let cart_key = keys
+                        .iter()
+                        .find(|idempotency| idempotency.key().starts_with("create:cart:"))
+                        .map(|idempotency| idempotency.key().to_string())
+                        .unwrap();
+                    idempotencies.lock().unwrap().insert(
+                        cart_key.clone(),
+                        Idempotency::new(cart_key, existing_id.clone(), 1),
+                    );
+                    Box::pin(async { Ok(Err(CommandKernelError::ConcurrencyConflict)) })
+                });
+        }
+        let usecase = CommandUseCase::new(processor);
+
+        let result = usecase
+            .create(
+                cart_id,
+                vec![Item::new(Id::generate(), Id::generate(), 1)],
+                Some("key".to_string()),
+            )
+            .await??;
+        assert_eq!(
+            result,
+            (existing_id, 1),
+            "同じカートの注文の作成と競合した場合は先に作成された注文を返す"
+        );
+        Ok(())
     }
 }
@@ -1,4 +1,7 @@
 use thiserror::Error;
 
 #[derive(Debug, Error)]
-pub enum QueryUseCaseError {}
+pub enum QueryUseCaseError {
+    #[error("Invalid page token")]
+    InvalidPageToken,
+}
@@ -1,2 +1,3 @@
 // NOTE: DTOを定義せずにkernelのOrderStatusをre-exportする
 pub use kernel::query::model::OrderStatus;
+pub use kernel::query::model::{Page, PageRequest};
@@ -1,7 +1,8 @@
 use std::future::Future;
 
 use kernel::id::Id;
-use kernel::query::model::{Cart, Order, Tenant};
+use kernel::query::error::QueryKernelError;
+use kernel::query::model::{Cart, Order, Page, PageRequest, Tenant};
 use kernel::query::processor::QueryProcessor;
 
 use super::error::QueryUseCaseError;
@@ -20,16 +21,18 @@ pub trait QueryUseCaseExt {
         id: Id<Cart>,
     ) -> impl Future<Output = Result<Result<Option<Order>, QueryUseCaseError>, anyhow::Error>> + Send;
 
-    /// テナントに入った注文のID一覧を取得する
+    /// テナントに入った注文のID一覧を注文IDの昇順で1ページ分取得する
     fn list_tenant_received_order_ids(
         &self,
         tenant_id: Id<Tenant>,
-    ) -> impl Future<Output = Result<Result<Vec<Id<Order>>, QueryUseCaseError>, anyhow::Error>> + Send;
+        page: PageRequest,
+    ) -> impl Future<Output = Result<Result<Page<Id<Order>>, QueryUseCaseError>, anyhow::Error>> + Send;
 
-    /// 準備完了状態になった注文のID一覧を取得する
+    /// 準備完了状態になった注文のID一覧を注文IDの昇順で1ページ分取得する
     fn list_prepared_order_ids(
         &self,
-    ) -> impl Future<Output = Result<Result<Vec<Id<Order>>, QueryUseCaseError>, anyhow::Error>> + Send;
+        page: PageRequest,
+    ) -> impl Future<Output = Result<Result<Page<Id<Order>>, QueryUseCaseError>, anyhow::Error>> + Send;
 }
 
 /// ユースケースの実態
@@ -68,17 +71,26 @@ where
     async fn list_tenant_received_order_ids(
         &self,
         tenant_id: Id<Tenant>,
-    ) -> Result<Result<Vec<Id<Order>>, QueryUseCaseError>, anyhow::Error> {
-        Ok(Ok(self
+        page: PageRequest,
+    ) -> Result<Result<Page<Id<Order>>, QueryUseCaseError>, anyhow::Error> {
+        match self
             .processor
-            .list_tenant_received_order_ids(tenant_id)
-            .await??))
+            .list_tenant_received_order_ids(tenant_id, page)
+            .await?
+        {
+            Ok(page) => Ok(Ok(page)),
+            Err(QueryKernelError::InvalidPageToken) => Ok(Err(QueryUseCaseError::InvalidPageToken)),
+        }
     }
 
     #[tracing::instrument(skip(self), err(Debug), ret)]
     async fn list_prepared_order_ids(
         &self,
-    ) -> Result<Result<Vec<Id<Order>>, QueryUseCaseError>, anyhow::Error> {
-        Ok(Ok(self.processor.list_prepared_order_ids().await??))
+        page: PageRequest,
+    ) -> Result<Result<Page<Id<Order>>, QueryUseCaseError>, anyhow::Error> {
+        match self.processor.list_prepared_order_ids(page).await? {
+            Ok(page) => Ok(Ok(page)),
+            Err(QueryKernelError::InvalidPageToken) => Ok(Err(QueryUseCaseError::InvalidPageToken)),
+        }
     }
 }
@@ -10,6 +10,47 @@ use proto::order::v1::{
 use tonic::{Code, Request, Response, Status};
 use tonic_types::{ErrorDetails, StatusExt as _};
 
+/// 1ページに含める件数を指定しなかった場合の件数
+const DEFAULT_PAGE_SIZE: usize = 100;
+/// 1ページに含める最大件数
+const MAX_PAGE_SIZE: usize = 1000;
+
+/// リクエストのページの指定を変換する
+///
+/// 件数を指定しなかった場合はデフォルトの件数、最大件数より多い場合は最大件数にする
+fn page_request(page_size: u32, page_token: String) -> app::query::model::PageRequest {
+    let page_size = match usize::try_from(page_size) {
+        Ok(0) => DEFAULT_PAGE_SIZE,
+        Ok(page_size) => page_size.min(MAX_PAGE_SIZE),
+        Err(_) => MAX_PAGE_SIZE,
+    };
+    app::query::model::PageRequest::new(page_size, Some(page_token).filter(|t| !t.is_empty()))
+}
+
+/// ページのトークンが不正な場合のステータス
+fn invalid_page_token(e: &app::query::error::QueryUseCaseError) -> Status {
+    Status::with_error_details(
+        Code::InvalidArgument,
+        format!("{e:#}"),
+        ErrorDetails::new()
+            .add_bad_request_violation("page_token", format!("{e:#}"))
+            .to_owned(),
+    )
+}
+
+/// 集約のバージョンが期待するバージョンと異なる場合のステータス
+///
+/// クライアントが集約を取得し直してから再実行できるように `ABORTED` を返す
+fn version_mismatch(e: &app::command::error::CommandUseCaseError) -> Status {
+    Status::with_error_details(
+        Code::Aborted,
+        format!("{e:#}"),
+        ErrorDetails::new()
+            .add_precondition_failure_violation("VERSION", "expected_version", format!("{e:#}"))
+            .to_owned(),
+    )
+}
+
 pub struct Service<C: CommandUseCaseExt, Q: QueryUseCaseExt> {
     command: C,
     query: Q,
@@ -32,7 +73,11 @@ where
         &self,
         req: Request<CreateRequest>,
     ) -> Result<Response<CreateResponse>, Status> {
-        let CreateRequest { cart_id, items } = req.into_inner();
+        let CreateRequest {
+            cart_id,
+            items,
+            idempotency_key,
+        } = req.into_inner();
         let cart_id = cart_id.parse().map_err(|e: anyhow::Error| {
             Status::with_error_details(
                 Code::InvalidArgument,
@@ -42,40 +87,48 @@ where
                     .to_owned(),
             )
         })?;
-        let items: Vec<_> = items
-            .into_iter()
-            .map(|item| {
-                let Item {
-                    tenant_id,
-                    item_id,
-                    quantity,
-                } = item;
-                let tenant_id = tenant_id.parse().map_err(|e: anyhow::Error| {
-                    Status::with_error_details(
-                        Code::InvalidArgument,
-                        format!("invalid tenant id: {tenant_id}"),
-                        ErrorDetails::new()
-                            .add_bad_request_violation("tenant_id", format!("{e:#}"))
-                            .to_owned(),
-                    )
-                })?;
-                let item_id = item_id.parse().map_err(|e: anyhow::Error| {
-                    Status::with_error_details(
-                        Code::InvalidArgument,
-                        format!("invalid item id: {item_id}"),
-                        ErrorDetails::new()
-                            .add_bad_request_violation("item_id", format!("{e:#}"))
-                            .to_owned(),
-                    )
-                })?;
-                Ok::<app::command::model::Item, Status>(app::command::model::Item::new(
-                    item_id, tenant_id, quantity,
-                ))
-            })
-            .collect::<Result<_, _>>()?;
-        match self.command.create(cart_id, items).await {
+        let mut command_items = Vec::with_capacity(items.len());
+        for Item {
+            tenant_id,
+            item_id,
+            quantity,
+        } in items
+        {
+            let tenant_id = tenant_id.parse().map_err(|e: anyhow::Error| {
+                Status::with_error_details(
+                    Code::InvalidArgument,
+                    format!("invalid tenant id: {tenant_id}"),
+                    ErrorDetails::new()
+                        .add_bad_request_violation("tenant_id", format!("{e:#}"))
+                        .to_owned(),
+                )
+            })?;
+            let item_id = item_id.parse().map_err(|e: anyhow::Error| {
+                Status::with_error_details(
+                    Code::InvalidArgument,
+                    format!("invalid item id: {item_id}"),
+                    ErrorDetails::new()
+                        .add_bad_request_violation("item_id", format!("{e:#}"))
+                        .to_owned(),
+                )
+            })?;
+            command_items.push(app::command::model::Item::new(item_id, tenant_id, quantity));
+        }
+        match self
+            .command
+            .create(cart_id, command_items, idempotency_key)
+            .await
+        {
             Ok(result) => match result {
-                Ok(id) => return Ok(Response::new(CreateResponse { id: id.to_string() })),
+                Ok((id, version)) => {
+                    return Ok(Response::new(CreateResponse {
+                        id: id.to_string(),
+                        version,
+                    }))
+                }
+                Err(e @ app::command::error::CommandUseCaseError::ConcurrencyConflict) => {
+                    return Err(Status::aborted(format!("{e:#}")))
+                }
                 Err(e) => return Err(Status::unknown(format!("{e:#}"))),
             },
             Err(e) => return Err(Status::unknown(format!("{e:#}"))),
@@ -87,7 +140,11 @@ where
         &self,
         req: Request<PreparedRequest>,
     ) -> Result<Response<PreparedResponse>, Status> {
-        let PreparedRequest { id } = req.into_inner();
+        let PreparedRequest {
+            id,
+            expected_version,
+            idempotency_key,
+        } = req.into_inner();
         let id = id.parse().map_err(|e: anyhow::Error| {
             Status::with_error_details(
                 Code::InvalidArgument,
@@ -97,9 +154,19 @@ where
                     .to_owned(),
             )
         })?;
-        match self.command.prepared(id).await {
+        match self
+            .command
+            .prepared(id, expected_version, idempotency_key)
+            .await
+        {
             Ok(result) => match result {
-                Ok(()) => return Ok(Response::new(PreparedResponse {})),
+                Ok(version) => return Ok(Response::new(PreparedResponse { version })),
+                Err(e @ app::command::error::CommandUseCaseError::ConcurrencyConflict) => {
+                    return Err(Status::aborted(format!("{e:#}")))
+                }
+                Err(e @ app::command::error::CommandUseCaseError::VersionMismatch { .. }) => {
+                    return Err(version_mismatch(&e))
+                }
                 Err(e) => return Err(Status::unknown(format!("{e:#}"))),
             },
             Err(e) => return Err(Status::unknown(format!("{e:#}"))),
@@ -111,7 +178,11 @@ where
         &self,
         req: Request<PickedUpRequest>,
     ) -> Result<Response<PickedUpResponse>, Status> {
-        let PickedUpRequest { id } = req.into_inner();
+        let PickedUpRequest {
+            id,
+            expected_version,
+            idempotency_key,
+        } = req.into_inner();
         let id = id.parse().map_err(|e: anyhow::Error| {
             Status::with_error_details(
                 Code::InvalidArgument,
@@ -121,9 +192,19 @@ where
                     .to_owned(),
             )
         })?;
-        match self.command.picked_up(id).await {
+        match self
+            .command
+            .picked_up(id, expected_version, idempotency_key)
+            .await
+        {
             Ok(result) => match result {
-                Ok(()) => return Ok(Response::new(PickedUpResponse {})),
+                Ok(version) => return Ok(Response::new(PickedUpResponse { version })),
+                Err(e @ app::command::error::CommandUseCaseError::ConcurrencyConflict) => {
+                    return Err(Status::aborted(format!("{e:#}")))
+                }
+                Err(e @ app::command::error::CommandUseCaseError::VersionMismatch { .. }) => {
+                    return Err(version_mismatch(&e))
+                }
                 Err(e) => return Err(Status::unknown(format!("{e:#}"))),
             },
             Err(e) => return Err(Status::unknown(format!("{e:#}"))),
@@ -135,7 +216,11 @@ where
         &self,
         req: Request<DeliveredRequest>,
     ) -> Result<Response<DeliveredResponse>, Status> {
-        let DeliveredRequest { id } = req.into_inner();
+        let DeliveredRequest {
+            id,
+            expected_version,
+            idempotency_key,
+        } = req.into_inner();
         let id = id.parse().map_err(|e: anyhow::Error| {
             Status::with_error_details(
                 Code::InvalidArgument,
@@ -145,9 +230,19 @@ where
                     .to_owned(),
             )
         })?;
-        match self.command.delivered(id).await {
+        match self
+            .command
+            .delivered(id, expected_version, idempotency_key)
+            .await
+        {
             Ok(result) => match result {
-                Ok(()) => return Ok(Response::new(DeliveredResponse {})),
+                Ok(version) => return Ok(Response::new(DeliveredResponse { version })),
+                Err(e @ app::command::error::CommandUseCaseError::ConcurrencyConflict) => {
+                    return Err(Status::aborted(format!("{e:#}")))
+                }
+                Err(e @ app::command::error::CommandUseCaseError::VersionMismatch { .. }) => {
+                    return Err(version_mismatch(&e))
+                }
                 Err(e) => return Err(Status::unknown(format!("{e:#}"))),
             },
             Err(e) => return Err(Status::unknown(format!("{e:#}"))),
@@ -159,7 +254,11 @@ where
         &self,
         req: Request<CancelRequest>,
     ) -> Result<Response<CancelResponse>, Status> {
-        let CancelRequest { id } = req.into_inner();
+        let CancelRequest {
+            id,
+            expected_version,
+            idempotency_key,
+        } = req.into_inner();
         let id = id.parse().map_err(|e: anyhow::Error| {
             Status::with_error_details(
                 Code::InvalidArgument,
@@ -169,9 +268,19 @@ where
                     .to_owned(),
             )
         })?;
-        match self.command.cancel(id).await {
+        match self
+            .command
+            .cancel(id, expected_version, idempotency_key)
+            .await
+        {
             Ok(result) => match result {
-                Ok(()) => return Ok(Response::new(CancelResponse {})),
+                Ok(version) => return Ok(Response::new(CancelResponse { version })),
+                Err(e @ app::command::error::CommandUseCaseError::ConcurrencyConflict) => {
+                    return Err(Status::aborted(format!("{e:#}")))
+                }
+                Err(e @ app::command::error::CommandUseCaseError::VersionMismatch { .. }) => {
+                    return Err(version_mismatch(&e))
+                }
                 Err(e) => return Err(Status::unknown(format!("{e:#}"))),
             },
             Err(e) => return Err(Status::unknown(format!("{e:#}"))),
@@ -252,6 +361,7 @@ where
             id: order.id().to_string(),
             items,
             status,
+            version: order.version(),
         }));
     }
 
@@ -260,7 +370,11 @@ where
         &self,
         req: Request<ListTenantReceivedOrdersRequest>,
     ) -> Result<Response<ListTenantReceivedOrdersResponse>, Status> {
-        let ListTenantReceivedOrdersRequest { tenant_id } = req.into_inner();
+        let ListTenantReceivedOrdersRequest {
+            tenant_id,
+            page_size,
+            page_token,
+        } = req.into_inner();
         let tenant_id = tenant_id.parse().map_err(|e: anyhow::Error| {
             Status::with_error_details(
                 Code::InvalidArgument,
@@ -270,14 +384,23 @@ where
                     .to_owned(),
             )
         })?;
-        match self.query.list_tenant_received_order_ids(tenant_id).await {
+        match self
+            .query
+            .list_tenant_received_order_ids(tenant_id, page_request(page_size, page_token))
+            .await
+        {
             Ok(result) => match result {
-                Ok(ids) => {
+                Ok(page) => {
                     return Ok(Response::new(ListTenantReceivedOrdersResponse {
-                        ids: ids.into_iter().map(|id| id.to_string()).collect(),
+                        next_page_token: page.next_page_token().unwrap_or_default().to_string(),
+                        ids: page
+                            .into_items()
+                            .into_iter()
+                            .map(|id| id.to_string())
+                            .collect(),
                     }))
                 }
-                Err(e) => return Err(Status::unknown(format!("{e:#}"))),
+                Err(e) => return Err(invalid_page_token(&e)),
             },
             Err(e) => return Err(Status::unknown(format!("{e:#}"))),
         }
@@ -286,16 +409,29 @@ where
     #[tracing::instrument(skip(self), err(Debug), ret)]
     async fn list_prepared_orders(
         &self,
-        _: Request<ListPreparedOrdersRequest>,
+        req: Request<ListPreparedOrdersRequest>,
     ) -> Result<Response<ListPreparedOrdersResponse>, Status> {
-        match self.query.list_prepared_order_ids().await {
+        let ListPreparedOrdersRequest {
+            page_size,
+            page_token,
+        } = req.into_inner();
+        match self
+            .query
+            .list_prepared_order_ids(page_request(page_size, page_token))
+            .await
+        {
             Ok(result) => match result {
-                Ok(ids) => {
+                Ok(page) => {
                     return Ok(Response::new(ListPreparedOrdersResponse {
-                        ids: ids.into_iter().map(|id| id.to_string()).collect(),
+                        next_page_token: page.next_page_token().unwrap_or_default().to_string(),
+                        ids: page
+                            .into_items()
+                            .into_iter()
+                            .map(|id| id.to_string())
+                            .collect(),
                     }))
                 }
-                Err(e) => return Err(Status::unknown(format!("{e:#}"))),
+                Err(e) => return Err(invalid_page_token(&e)),
             },
             Err(e) => return Err(Status::unknown(format!("{e:#}"))),
         }
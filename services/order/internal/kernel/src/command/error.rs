@@ -11,6 +11,10 @@ pub enum CommandKernelError {
     AggregateAlreadyCreated,
     #[error("Cannot update Aggregate version")]
     AggregateVersionOverflowed,
+    #[error("Aggregate was updated concurrently")]
+    ConcurrencyConflict,
+    #[error("Aggregate version mismatch: expected {expected}, actual {actual}")]
+    VersionMismatch { expected: u64, actual: u64 },
     #[error("Items is empty")]
     ItemsIsEmpty,
     #[error("Invalid operation: current_status =  {current_status:?}")]
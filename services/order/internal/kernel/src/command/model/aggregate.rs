@@ -60,9 +60,27 @@ impl Aggregate {
         self.version
     }
 
+    /// 集約のバージョンが期待するバージョンと一致するか検証する
+    ///
+    /// `expected_version` が `None` の場合は検証しない
+    ///
+    /// # Errors
+    pub fn ensure_version(&self, expected_version: Option<u64>) -> Result<(), CommandKernelError> {
+        match expected_version {
+            Some(expected) if expected != self.version => {
+                Err(CommandKernelError::VersionMismatch {
+                    expected,
+                    actual: self.version,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// 集約にコマンドを実行する
     ///
     /// 集約にコマンドを実行すると、コマンドに応じて集約の状態を変更し、集約の状態を変更したイベントを返す
+    /// `expected_version` を指定した場合は集約がそのバージョンのときだけコマンドを実行する
     ///
     /// # Errors
     ///
@@ -70,7 +88,12 @@ impl Aggregate {
     ///
     /// [`anyhow::Error`]: https://docs.rs/anyhow/latest/anyhow/struct.Error.html
     #[tracing::instrument(skip(self), err(Debug), ret)]
-    pub fn apply_command(&mut self, command: Command) -> Result<Vec<Event>, CommandKernelError> {
+    pub fn apply_command(
+        &mut self,
+        command: Command,
+        expected_version: Option<u64>,
+    ) -> Result<Vec<Event>, CommandKernelError> {
+        self.ensure_version(expected_version)?;
         if !matches!(command, Command::Create { .. }) && self.version == 0 {
             return Err(CommandKernelError::AggregateNotCreated);
         }
@@ -86,8 +109,6 @@ impl Aggregate {
                 if items.is_empty() {
                     return Err(CommandKernelError::ItemsIsEmpty);
                 }
-                self.cart_id = cart_id;
-                self.items.clone_from(&items);
                 vec![Event::Created { cart_id, items }]
             }
             Command::Prepared => {
@@ -96,7 +117,6 @@ impl Aggregate {
                         current_status: self.status,
                     });
                 }
-                self.status = OrderStatus::Prepared;
                 vec![Event::Prepared]
             }
             Command::PickedUp => {
@@ -105,7 +125,6 @@ impl Aggregate {
                         current_status: self.status,
                     });
                 }
-                self.status = OrderStatus::PickedUp;
                 vec![Event::PickedUp]
             }
             Command::Delivered => {
@@ -114,7 +133,6 @@ impl Aggregate {
                         current_status: self.status,
                     });
                 }
-                self.status = OrderStatus::Delivered;
                 vec![Event::Delivered]
             }
             Command::Cancel => {
@@ -123,15 +141,41 @@ impl Aggregate {
                         current_status: self.status,
                     });
                 }
-                self.status = OrderStatus::Canceled;
                 vec![Event::Canceled]
             }
         };
-        self.version = self
+        for event in events.clone() {
+            self.apply_event(event)?;
+        }
+        Ok(events)
+    }
+
+    /// 集約にイベントを適用する
+    ///
+    /// イベントストアから集約を復元するときに利用するため、イベントの検証はせずに集約の状態だけを変更する
+    ///
+    /// # Errors
+    ///
+    /// 集約のバージョンが更新できない場合は [`CommandKernelError::AggregateVersionOverflowed`] を返す
+    #[tracing::instrument(skip(self), err(Debug), ret)]
+    pub fn apply_event(&mut self, event: Event) -> Result<(), CommandKernelError> {
+        let version = self
             .version
             .checked_add(1)
             .ok_or(CommandKernelError::AggregateVersionOverflowed)?;
-        Ok(events)
+        match event {
+            Event::Created { cart_id, items } => {
+                self.cart_id = cart_id;
+                self.items = items;
+                self.status = OrderStatus::Created;
+            }
+            Event::Prepared => self.status = OrderStatus::Prepared,
+            Event::PickedUp => self.status = OrderStatus::PickedUp,
+            Event::Delivered => self.status = OrderStatus::Delivered,
+            Event::Canceled => self.status = OrderStatus::Canceled,
+        }
+        self.version = version;
+        Ok(())
     }
 }
 
@@ -245,7 +289,7 @@ mod tests {
             expected_events,
         } in tests
         {
-            let actual_events = aggregate.apply_command(command)?;
+            let actual_events = aggregate.apply_command(command, None)?;
             pretty_assertions::assert_eq!(
                 actual_events,
                 expected_events,
@@ -313,7 +357,7 @@ mod tests {
             expected,
         } in tests
         {
-            let actual = aggregate.apply_command(command);
+            let actual = aggregate.apply_command(command, None);
             assert_eq!(actual, Err(expected), "{name}");
         }
     }
@@ -354,7 +398,7 @@ mod tests {
             expected_events,
         } in tests
         {
-            let actual_events = aggregate.apply_command(Command::Prepared)?;
+            let actual_events = aggregate.apply_command(Command::Prepared, None)?;
             pretty_assertions::assert_eq!(
                 actual_events,
                 expected_events,
@@ -443,7 +487,7 @@ mod tests {
             expected,
         } in tests
         {
-            let actual = aggregate.apply_command(Command::Prepared);
+            let actual = aggregate.apply_command(Command::Prepared, None);
             assert_eq!(actual, Err(expected), "{name}");
         }
     }
@@ -484,7 +528,7 @@ mod tests {
             expected_events,
         } in tests
         {
-            let actual_events = aggregate.apply_command(Command::PickedUp)?;
+            let actual_events = aggregate.apply_command(Command::PickedUp, None)?;
             pretty_assertions::assert_eq!(
                 actual_events,
                 expected_events,
@@ -574,7 +618,7 @@ mod tests {
             expected,
         } in tests
         {
-            let actual = aggregate.apply_command(Command::PickedUp);
+            let actual = aggregate.apply_command(Command::PickedUp, None);
             assert_eq!(actual, Err(expected), "{name}");
         }
     }
@@ -615,7 +659,7 @@ mod tests {
             expected_events,
         } in tests
         {
-            let actual_events = aggregate.apply_command(Command::Delivered)?;
+            let actual_events = aggregate.apply_command(Command::Delivered, None)?;
             pretty_assertions::assert_eq!(
                 actual_events,
                 expected_events,
@@ -705,7 +749,7 @@ mod tests {
             expected,
         } in tests
         {
-            let actual = aggregate.apply_command(Command::Delivered);
+            let actual = aggregate.apply_command(Command::Delivered, None);
             assert_eq!(actual, Err(expected), "{name}");
         }
     }
@@ -786,7 +830,7 @@ mod tests {
             expected_events,
         } in tests
         {
-            let actual_events = aggregate.apply_command(Command::Cancel)?;
+            let actual_events = aggregate.apply_command(Command::Cancel, None)?;
             pretty_assertions::assert_eq!(
                 actual_events,
                 expected_events,
@@ -854,8 +898,92 @@ mod tests {
             expected,
         } in tests
         {
-            let actual = aggregate.apply_command(Command::Cancel);
+            let actual = aggregate.apply_command(Command::Cancel, None);
             assert_eq!(actual, Err(expected), "{name}");
         }
     }
+
+    #[test]
+    fn test_apply_command_version_mismatch_err() {
+        let mut aggregate = Aggregate {
+            status: OrderStatus::Created,
+            version: 2,
+            ..Default::default()
+        };
+        let actual = aggregate.apply_command(Command::Cancel, Some(1));
+        assert_eq!(
+            actual,
+            Err(CommandKernelError::VersionMismatch {
+                expected: 1,
+                actual: 2
+            }),
+            "期待するバージョンと集約のバージョンが異なる場合はVersionMismatchが返る"
+        );
+        assert_eq!(aggregate.version(), 2, "集約の状態は変更されない");
+    }
+
+    #[test]
+    fn test_apply_event_ok() -> Result<()> {
+        struct TestCase {
+            name: &'static str,
+            events: Vec<Event>,
+            expected: Aggregate,
+        }
+
+        let aggregate_id: Id<Aggregate> = Id::generate();
+        let cart_id: Id<Cart> = Id::generate();
+        let items = vec![Item::new(Id::generate(), Id::generate(), 1)];
+        let tests = [
+            TestCase {
+                name: "Createdを適用した場合はバージョン1の集約が復元される",
+                events: vec![Event::Created {
+                    cart_id,
+                    items: items.clone(),
+                }],
+                expected: Aggregate::new(
+                    aggregate_id.clone(),
+                    cart_id,
+                    items.clone(),
+                    OrderStatus::Created,
+                    1,
+                ),
+            },
+            TestCase {
+                name: "複数のイベントを順番に適用した場合はイベントの数をバージョンとする集約が復元される",
+                events: vec![
+                    Event::Created {
+                        cart_id,
+                        items: items.clone(),
+                    },
+                    Event::Prepared,
+                    Event::PickedUp,
+                    Event::Delivered,
+                ],
+                expected: Aggregate::new(
+                    aggregate_id.clone(),
+                    cart_id,
+                    items.clone(),
+                    OrderStatus::Delivered,
+                    4,
+                ),
+            },
+        ];
+
+        for TestCase {
+            name,
+            events,
+            expected,
+        } in tests
+        {
+            let mut aggregate = Aggregate {
+                id: aggregate_id.clone(),
+                ..Default::default()
+            };
+            for event in events {
+                aggregate.apply_event(event)?;
+            }
+            pretty_assertions::assert_eq!(aggregate, expected, "{name}");
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,42 @@
+use crate::id::Id;
+
+use super::aggregate::Aggregate;
+
+/// 冪等キーを指定して実行したコマンドの結果
+///
+/// 同じ冪等キーでコマンドを再実行した場合は、イベントを発行せずに保存済みの結果を返す
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Idempotency {
+    key: String,
+    aggregate_id: Id<Aggregate>,
+    version: u64,
+}
+
+impl Idempotency {
+    #[must_use]
+    pub fn new(key: String, aggregate_id: Id<Aggregate>, version: u64) -> Self {
+        Self {
+            key,
+            aggregate_id,
+            version,
+        }
+    }
+
+    /// 冪等キー
+    #[must_use]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// コマンドを実行した集約のID
+    #[must_use]
+    pub fn aggregate_id(&self) -> &Id<Aggregate> {
+        &self.aggregate_id
+    }
+
+    /// コマンドを実行した後の集約のバージョン
+    #[must_use]
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+}
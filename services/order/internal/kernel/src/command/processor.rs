@@ -5,13 +5,18 @@ use crate::id::Id;
 use super::error::CommandKernelError;
 use super::event::Event;
 use super::model::aggregate::Aggregate;
+use super::model::idempotency::Idempotency;
 
+#[cfg_attr(feature = "test", mockall::automock)]
 pub trait CommandProcessor {
     /// 集約とイベントを作成する
+    ///
+    /// 冪等キーを指定した場合は全てイベントと一緒に保存する
     fn create(
         &self,
         aggregate: Aggregate,
         event: Event,
+        idempotencies: Vec<Idempotency>,
     ) -> impl Future<Output = Result<Result<(), CommandKernelError>, anyhow::Error>> + Send;
 
     /// 集約を取得する
@@ -21,9 +26,19 @@ pub trait CommandProcessor {
     ) -> impl Future<Output = Result<Result<Option<Aggregate>, CommandKernelError>, anyhow::Error>> + Send;
 
     /// 集約を更新しイベントを追加する
+    ///
+    /// 冪等キーを指定した場合はイベントと一緒に保存する
     fn update(
         &self,
         aggregate: Aggregate,
         events: Vec<Event>,
+        idempotency: Option<Idempotency>,
     ) -> impl Future<Output = Result<Result<(), CommandKernelError>, anyhow::Error>> + Send;
+
+    /// 冪等キーを指定して実行したコマンドの結果を取得する
+    fn get_idempotency(
+        &self,
+        key: String,
+    ) -> impl Future<Output = Result<Result<Option<Idempotency>, CommandKernelError>, anyhow::Error>>
+           + Send;
 }
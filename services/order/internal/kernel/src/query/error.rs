@@ -1,4 +1,7 @@
 use thiserror::Error;
 
 #[derive(Debug, Error, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub enum QueryKernelError {}
+pub enum QueryKernelError {
+    #[error("Invalid page token")]
+    InvalidPageToken,
+}
@@ -11,12 +11,19 @@ pub struct Order {
     id: Id<Order>,
     items: Vec<Item>,
     status: OrderStatus,
+    /// 集約のバージョン
+    version: u64,
 }
 
 impl Order {
     #[must_use]
-    pub fn new(id: Id<Order>, items: Vec<Item>, status: OrderStatus) -> Self {
-        Self { id, items, status }
+    pub fn new(id: Id<Order>, items: Vec<Item>, status: OrderStatus, version: u64) -> Self {
+        Self {
+            id,
+            items,
+            status,
+            version,
+        }
     }
 
     #[must_use]
@@ -33,6 +40,12 @@ impl Order {
     pub fn status(&self) -> OrderStatus {
         self.status
     }
+
+    /// 集約のバージョン
+    #[must_use]
+    pub fn version(&self) -> u64 {
+        self.version
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -76,3 +89,75 @@ pub enum OrderStatus {
     Delivered,
     Canceled,
 }
+
+/// 一覧を取得するページの指定
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct PageRequest {
+    page_size: usize,
+    /// 前のページの次のページを取得するトークン。最初のページを取得する場合は `None`
+    page_token: Option<String>,
+}
+
+impl PageRequest {
+    /// Creates a new [`PageRequest`].
+    #[must_use]
+    pub fn new(page_size: usize, page_token: Option<String>) -> Self {
+        Self {
+            page_size,
+            page_token,
+        }
+    }
+
+    #[must_use]
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    #[must_use]
+    pub fn page_token(&self) -> Option<&str> {
+        self.page_token.as_deref()
+    }
+}
+
+/// 一覧の1ページ
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Page<T> {
+    items: Vec<T>,
+    /// 次のページを取得するトークン。次のページがない場合は `None`
+    next_page_token: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// Creates a new [`Page`].
+    #[must_use]
+    pub fn new(items: Vec<T>, next_page_token: Option<String>) -> Self {
+        Self {
+            items,
+            next_page_token,
+        }
+    }
+
+    #[must_use]
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    #[must_use]
+    pub fn next_page_token(&self) -> Option<&str> {
+        self.next_page_token.as_deref()
+    }
+
+    #[must_use]
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+
+    /// ページの要素を変換する
+    #[must_use]
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            next_page_token: self.next_page_token,
+        }
+    }
+}
@@ -3,7 +3,7 @@ use std::future::Future;
 use crate::id::Id;
 
 use super::error::QueryKernelError;
-use super::model::{Cart, Order, Tenant};
+use super::model::{Cart, Order, Page, PageRequest, Tenant};
 
 pub trait QueryProcessor {
     /// 注文を取得する
@@ -18,14 +18,16 @@ pub trait QueryProcessor {
         id: Id<Cart>,
     ) -> impl Future<Output = Result<Result<Option<Order>, QueryKernelError>, anyhow::Error>> + Send;
 
-    /// テナントに入った注文のID一覧を取得する
+    /// テナントに入った注文のID一覧を注文IDの昇順で1ページ分取得する
     fn list_tenant_received_order_ids(
         &self,
         tenant_id: Id<Tenant>,
-    ) -> impl Future<Output = Result<Result<Vec<Id<Order>>, QueryKernelError>, anyhow::Error>> + Send;
+        page: PageRequest,
+    ) -> impl Future<Output = Result<Result<Page<Id<Order>>, QueryKernelError>, anyhow::Error>> + Send;
 
-    /// 準備完了状態になった注文のID一覧を取得する
+    /// 準備完了状態になった注文のID一覧を注文IDの昇順で1ページ分取得する
     fn list_prepared_order_ids(
         &self,
-    ) -> impl Future<Output = Result<Result<Vec<Id<Order>>, QueryKernelError>, anyhow::Error>> + Send;
+        page: PageRequest,
+    ) -> impl Future<Output = Result<Result<Page<Id<Order>>, QueryKernelError>, anyhow::Error>> + Send;
 }
@@ -0,0 +1,83 @@
+#![recursion_limit = "256"]
+
+use adapter::command::{EventStoreModel, EventStorePayload, Item};
+use adapter::query::projection::{OrderProjector, ProjectionStore};
+use event_router::{Config, Handler, HandlerError, Router};
+
+/// 注文のイベントを構造化ログとして記録するハンドラー
+#[derive(Debug, Clone, Copy)]
+struct AuditLog;
+
+impl Handler<EventStorePayload> for AuditLog {
+    async fn handle(&self, model: &EventStoreModel) -> Result<(), HandlerError> {
+        let order_id = model.aggregate_id();
+        match model.payload() {
+            EventStorePayload::CreatedV1 { cart_id, items } => {
+                let item_ids: Vec<&str> = items
+                    .iter()
+                    .map(|item| match item {
+                        Item::V1 { id, .. } => id.as_str(),
+                    })
+                    .collect();
+                tracing::info!(order_id, cart_id, ?item_ids, "order created");
+            }
+            EventStorePayload::PreparedV1 => tracing::info!(order_id, "order prepared"),
+            EventStorePayload::PickedUpV1 => tracing::info!(order_id, "order picked up"),
+            EventStorePayload::DeliveredV1 => tracing::info!(order_id, "order delivered"),
+            EventStorePayload::CanceledV1 => tracing::info!(order_id, "order canceled"),
+        }
+        Ok(())
+    }
+}
+
+/// 注文のイベントからクエリ操作のプロジェクションを更新するハンドラー
+#[derive(Debug, Clone)]
+struct ProjectOrder(OrderProjector);
+
+impl ProjectOrder {
+    async fn from_env() -> Self {
+        let config = aws_config::defaults(aws_config::BehaviorVersion::latest())
+            .endpoint_url(format!(
+                "http://{}:{}",
+                std::env::var("LOCALSTACK_GATEWAY_HOST").unwrap_or("localhost".to_string()),
+                std::env::var("LOCALSTACK_GATEWAY_PORT").unwrap_or("4566".to_string()),
+            ))
+            .region(
+                aws_config::meta::region::RegionProviderChain::default_provider()
+                    .or_else("ap-northeast-1"),
+            )
+            .test_credentials()
+            .load()
+            .await;
+        let dynamodb = adapter::command::persistence::dynamodb(&config);
+        Self(OrderProjector::new(
+            adapter::command::persistence::EventStore::dynamodb(dynamodb.clone()),
+            ProjectionStore::dynamodb(dynamodb),
+        ))
+    }
+}
+
+impl Handler<EventStorePayload> for ProjectOrder {
+    async fn handle(&self, model: &EventStoreModel) -> Result<(), HandlerError> {
+        Ok(self.0.project(model.aggregate_id()).await?)
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let router = Router::new().route("audit_log", |_| true, AuditLog).route(
+        "project_order",
+        |_| true,
+        ProjectOrder::from_env().await,
+    );
+    event_router::run(
+        router,
+        Config {
+            service_name: env!("CARGO_PKG_NAME"),
+            service_version: env!("CARGO_PKG_VERSION"),
+            event_store_table_name: "order-event-store",
+            dead_letter_table_name: "order-event-router-dead-letter",
+        },
+    )
+    .await
+}
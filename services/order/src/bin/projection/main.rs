@@ -0,0 +1,36 @@
+#![recursion_limit = "256"]
+
+use adapter::command::persistence::EventStore;
+use adapter::query::projection::{OrderProjector, ProjectionStore};
+use projection::{CheckpointStore, Config, Engine};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let config = aws_config::defaults(aws_config::BehaviorVersion::latest())
+        .endpoint_url(format!(
+            "http://{}:{}",
+            std::env::var("LOCALSTACK_GATEWAY_HOST").unwrap_or("localhost".to_string()),
+            std::env::var("LOCALSTACK_GATEWAY_PORT").unwrap_or("4566".to_string()),
+        ))
+        .region(
+            aws_config::meta::region::RegionProviderChain::default_provider()
+                .or_else("ap-northeast-1"),
+        )
+        .test_credentials()
+        .load()
+        .await;
+    let dynamodb = adapter::command::persistence::dynamodb(&config);
+    let event_store = EventStore::dynamodb(dynamodb.clone());
+    let engine = Engine::new(event_store.clone(), CheckpointStore::from_env()).register(
+        "orders",
+        OrderProjector::new(event_store, ProjectionStore::dynamodb(dynamodb)),
+    );
+    projection::run(
+        engine,
+        Config {
+            service_name: env!("CARGO_PKG_NAME"),
+            service_version: env!("CARGO_PKG_VERSION"),
+        },
+    )
+    .await
+}
@@ -1,3 +1,8 @@
+#![recursion_limit = "256"]
+
+use adapter::query::projection::{OrderProjector, ProjectionStore};
+use projection::{CheckpointStore, Engine};
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
     let shutdown_providers =
@@ -7,26 +12,93 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>
         std::env::var("HOST").unwrap_or("0.0.0.0".to_string()),
         std::env::var("PORT").unwrap_or(50051.to_string()),
     );
-    let config = aws_config::defaults(aws_config::BehaviorVersion::v2024_03_28())
-        .endpoint_url(format!(
-            "http://{}:{}",
-            std::env::var("LOCALSTACK_GATEWAY_HOST").unwrap_or("localhost".to_string()),
-            std::env::var("LOCALSTACK_GATEWAY_PORT").unwrap_or("4566".to_string()),
-        ))
-        .region(
-            aws_config::meta::region::RegionProviderChain::default_provider()
-                .or_else("ap-northeast-1"),
-        )
-        .test_credentials()
-        .load()
-        .await;
-    let dynamodb = adapter::command::persistence::dynamodb(&config);
-    let server = driver::server::Server::new(driver::server::Service::new(
-        app::command::usecase::CommandUseCase::new(
-            adapter::command::repository::CommandRepository::new(dynamodb.clone()),
+    let snapshot_policy = match std::env::var("SNAPSHOT_FREQUENCY") {
+        Ok(frequency) if frequency == "on-demand" => {
+            adapter::command::repository::SnapshotPolicy::OnDemand
+        }
+        Ok(frequency) => {
+            adapter::command::repository::SnapshotPolicy::EveryEvents(frequency.parse()?)
+        }
+        Err(_) => adapter::command::repository::SnapshotPolicy::default(),
+    };
+    let retry_policy = app::command::RetryPolicy::from_env()?;
+    let (event_store, projections) = match std::env::var("EVENT_STORE_BACKEND") {
+        Ok(backend) if backend == "in-memory" => (
+            adapter::command::persistence::EventStore::in_memory(),
+            ProjectionStore::in_memory(),
         ),
+        Ok(backend) if backend == "postgres" => {
+            let pool =
+                adapter::command::persistence::postgres(&std::env::var("DATABASE_URL")?).await?;
+            (
+                adapter::command::persistence::EventStore::postgres(pool).await?,
+                ProjectionStore::in_memory(),
+            )
+        }
+        Ok(backend) if backend == "sqlite" => {
+            let pool =
+                adapter::command::persistence::sqlite(&std::env::var("DATABASE_URL")?).await?;
+            (
+                adapter::command::persistence::EventStore::sqlite(pool).await?,
+                ProjectionStore::in_memory(),
+            )
+        }
+        Ok(backend) if backend != "dynamodb" => {
+            return Err(format!("unknown event store backend: {backend}").into());
+        }
+        _ => {
+            let config = aws_config::defaults(aws_config::BehaviorVersion::latest())
+                .endpoint_url(format!(
+                    "http://{}:{}",
+                    std::env::var("LOCALSTACK_GATEWAY_HOST").unwrap_or("localhost".to_string()),
+                    std::env::var("LOCALSTACK_GATEWAY_PORT").unwrap_or("4566".to_string()),
+                ))
+                .region(
+                    aws_config::meta::region::RegionProviderChain::default_provider()
+                        .or_else("ap-northeast-1"),
+                )
+                .test_credentials()
+                .load()
+                .await;
+            let dynamodb = adapter::command::persistence::dynamodb(&config);
+            if std::env::var("MIGRATE_AGGREGATES").is_ok_and(|x| x == "true") {
+                let migration =
+                    adapter::command::migration::AggregateMigration::new(dynamodb.clone());
+                tokio::spawn(async move {
+                    match migration.run().await {
+                        Ok(count) => tracing::info!("migrated {count} aggregates"),
+                        Err(e) => tracing::error!("failed to migrate aggregates: {e:?}"),
+                    }
+                });
+            }
+            (
+                adapter::command::persistence::EventStore::dynamodb(dynamodb.clone()),
+                ProjectionStore::dynamodb(dynamodb),
+            )
+        }
+    };
+    let mut command_repository =
+        adapter::command::repository::CommandRepository::new(event_store.clone())
+            .with_snapshot_policy(snapshot_policy);
+    if snapshot(&command_repository).await? {
+        shutdown_providers()?;
+        return Ok(());
+    }
+    // NOTE: DynamoDB 以外のバックエンドはイベントルーターでプロジェクションを更新できないので、同じプロセスで更新する
+    if std::env::var("EVENT_STORE_BACKEND").is_ok_and(|backend| backend != "dynamodb") {
+        let projector = OrderProjector::new(event_store.clone(), projections.clone());
+        let engine = Engine::new(event_store.clone(), CheckpointStore::from_env())
+            .register("orders", projector.clone());
+        let count = engine.rebuild("orders").await?;
+        tracing::info!("projected {count} events");
+        command_repository = command_repository.with_projector(projector);
+    }
+    let server = driver::server::Server::new(driver::server::Service::new(
+        app::command::usecase::CommandUseCase::new(command_repository)
+            .with_retry_policy(retry_policy),
         app::query::usecase::QueryUseCase::new(adapter::query::repository::QueryRepository::new(
-            dynamodb,
+            event_store,
+            projections,
         )),
     ));
     tracing::info!("listing on: {addr}");
@@ -34,3 +106,26 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>
     shutdown_providers()?;
     Ok(())
 }
+
+/// コマンドライン引数に `snapshot` を指定した場合は、指定した集約の現在の状態をスナップショットとして保存して `true` を返す
+///
+/// `SNAPSHOT_FREQUENCY` が `on-demand` の場合にスナップショットを保存するために、サーバーを起動せずに実行する
+async fn snapshot(
+    repository: &adapter::command::repository::CommandRepository,
+) -> Result<bool, Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) != Some("snapshot") {
+        return Ok(false);
+    }
+    if args.len() < 2 {
+        return Err("usage: snapshot <aggregate_id>...".into());
+    }
+    for id in &args[1..] {
+        if repository.snapshot(id.parse()?).await?? {
+            println!("saved snapshot: {id}");
+        } else {
+            eprintln!("aggregate not found: {id}");
+        }
+    }
+    Ok(true)
+}
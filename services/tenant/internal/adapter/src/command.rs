@@ -1,12 +1,21 @@
+/// 集約テーブルのマイグレーション関連のモジュール
+pub mod migration;
 /// テーブルモデル関連のモジュール
 pub(crate) mod model;
 /// データの永続性とデータストアとのインタラクションを管理するモジュール
 pub mod persistence;
 /// リポジトリ関連のモジュール
 pub mod repository;
+/// イベントのペイロードを最新のバージョンに変換するモジュール
+pub(crate) mod upcaster;
 
-pub(crate) use model::{AggregateModel, EventSequenceModel, EventStoreModel};
 #[cfg(test)]
-pub(crate) use model::{AggregatePayload, EventPayload, Item};
-pub use persistence::dynamodb;
-pub use repository::CommandRepository;
+pub(crate) use event_store::EventSequenceModel;
+pub use migration::AggregateMigration;
+pub(crate) use model::{
+    from_aggregate, from_idempotency, into_aggregate, into_idempotency, AggregateModel,
+    AggregatePayload,
+};
+pub use model::{EventPayload, EventStoreModel, Item};
+pub use persistence::{dynamodb, postgres, sqlite, EventStore};
+pub use repository::{CommandRepository, SnapshotPolicy};
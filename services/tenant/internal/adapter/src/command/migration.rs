@@ -0,0 +1,189 @@
+use event_store::dynamodb::DynamoDbEventStore;
+use tracing::instrument;
+
+use super::repository::TABLE_NAMES;
+use super::{AggregatePayload, EventPayload};
+
+type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// 集約テーブルの全てのレコードのペイロードを最新のバージョンに書き換えるマイグレーション
+#[derive(Debug, Clone)]
+pub struct AggregateMigration {
+    event_store: DynamoDbEventStore<AggregatePayload, EventPayload>,
+}
+
+impl AggregateMigration {
+    /// Creates a new [`AggregateMigration`].
+    #[must_use]
+    pub fn new(dynamodb: aws_sdk_dynamodb::Client) -> Self {
+        Self {
+            event_store: DynamoDbEventStore::new(dynamodb, TABLE_NAMES),
+        }
+    }
+
+    /// マイグレーションを実行して、書き換えたレコードの数を返す
+    ///
+    /// 書き換える前に集約が更新された場合は、更新時に最新のバージョンで書き込まれるので書き換えない
+    ///
+    /// # Errors
+    #[instrument(skip(self), err, ret)]
+    pub async fn run(&self) -> Result<usize, Error> {
+        Ok(self.event_store.migrate_aggregates().await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testcontainers::ContainerAsync;
+    use testcontainers_modules::dynamodb_local::DynamoDb;
+
+    use crate::command::{AggregateModel, AggregatePayload, Item};
+    use crate::AGGREGATE_TABLE_NAME;
+
+    use super::AggregateMigration;
+
+    type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    const TENANT_NAME: &str = "テストテナント";
+    const ITEM_NAME: &str = "テスト商品";
+
+    #[tokio::test]
+    async fn test_with_container_run_ok() -> Result<(), Error> {
+        let Context {
+            container: _container,
+            migration,
+            dynamodb,
+        } = Context::with_container().await?;
+
+        let item = Item::V1 {
+            id: "item".to_string(),
+            name: ITEM_NAME.to_string(),
+            price: 1000,
+        };
+        let fixtures = [
+            AggregateModel::new(
+                "v1".to_string(),
+                2,
+                AggregatePayload::V1 {
+                    name: TENANT_NAME.to_string(),
+                    items: vec![item.clone()],
+                },
+            ),
+            AggregateModel::new(
+                "v2".to_string(),
+                1,
+                AggregatePayload::V2 {
+                    name: TENANT_NAME.to_string(),
+                    items: Vec::new(),
+                    item_count: 0,
+                },
+            ),
+        ];
+        for fixture in fixtures {
+            dynamodb
+                .put_item()
+                .table_name(AGGREGATE_TABLE_NAME)
+                .set_item(Some(fixture.try_into()?))
+                .send()
+                .await?;
+        }
+
+        let result = migration.run().await;
+        assert!(result.is_ok(), "result must be ok: {result:?}");
+        assert_eq!(result.unwrap(), 1);
+
+        let mut actual: Vec<AggregateModel> = serde_dynamo::from_items(
+            dynamodb
+                .scan()
+                .table_name(AGGREGATE_TABLE_NAME)
+                .send()
+                .await?
+                .items()
+                .to_vec(),
+        )?;
+        actual.sort();
+        assert_eq!(
+            actual,
+            vec![
+                AggregateModel::new(
+                    "v1".to_string(),
+                    2,
+                    AggregatePayload::V2 {
+                        name: TENANT_NAME.to_string(),
+                        items: vec![item],
+                        item_count: 1,
+                    },
+                ),
+                AggregateModel::new(
+                    "v2".to_string(),
+                    1,
+                    AggregatePayload::V2 {
+                        name: TENANT_NAME.to_string(),
+                        items: Vec::new(),
+                        item_count: 0,
+                    },
+                ),
+            ]
+        );
+        Ok(())
+    }
+
+    struct Context {
+        container: ContainerAsync<DynamoDb>,
+        migration: AggregateMigration,
+        dynamodb: aws_sdk_dynamodb::Client,
+    }
+
+    impl Context {
+        async fn with_container() -> Result<Self, Error> {
+            use aws_config::BehaviorVersion;
+            use aws_sdk_dynamodb::types::{
+                AttributeDefinition, BillingMode, KeySchemaElement, KeyType, ScalarAttributeType,
+            };
+            use testcontainers::runners::AsyncRunner;
+
+            // NOTE: デフォルトのDockerコンテキスト以外を使っている場合にtestcontainersが正しく動作しないため、
+            // 環境変数の `DOCKER_HOST` にホストを設定する必要がある
+            // read mores: https://github.com/testcontainers/testcontainers-rs/issues/627
+            option_env!("DOCKER_HOST")
+                .unwrap_or_else(|| panic!("DOCKER_HOST must be set (e.g. DOCKER_HOST=(docker context inspect | jq -r '.[0].Endpoints.docker.Host'))"));
+
+            let container = DynamoDb::default().start().await?;
+            let endpoint = format!(
+                "http://{}:{}",
+                container.get_host().await?,
+                container.get_host_port_ipv4(8000).await?,
+            );
+            let config = aws_config::defaults(BehaviorVersion::latest())
+                .endpoint_url(endpoint)
+                .test_credentials()
+                .load()
+                .await;
+            let dynamodb = aws_sdk_dynamodb::Client::new(&config);
+            dynamodb
+                .create_table()
+                .table_name(AGGREGATE_TABLE_NAME)
+                .attribute_definitions(
+                    AttributeDefinition::builder()
+                        .attribute_name("id")
+                        .attribute_type(ScalarAttributeType::S)
+                        .build()?,
+                )
+                .key_schema(
+                    KeySchemaElement::builder()
+                        .attribute_name("id")
+                        .key_type(KeyType::Hash)
+                        .build()?,
+                )
+                .billing_mode(BillingMode::PayPerRequest)
+                .send()
+                .await?;
+
+            Ok(Self {
+                container,
+                migration: AggregateMigration::new(dynamodb.clone()),
+                dynamodb,
+            })
+        }
+    }
+}
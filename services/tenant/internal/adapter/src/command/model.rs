@@ -1,11 +1,10 @@
 pub(crate) mod aggregate;
 pub(crate) mod entity;
 pub(crate) mod event;
+pub(crate) mod idempotency;
 
 pub(crate) use aggregate::AggregateModel;
-#[cfg(test)]
-pub(crate) use aggregate::AggregatePayload;
-pub(crate) use entity::Item;
-#[cfg(test)]
-pub(crate) use event::EventPayload;
-pub(crate) use event::{EventSequenceModel, EventStoreModel};
+pub(crate) use aggregate::{from_aggregate, into_aggregate, AggregatePayload};
+pub use entity::Item;
+pub use event::{EventPayload, EventStoreModel};
+pub(crate) use idempotency::{from_idempotency, into_idempotency};
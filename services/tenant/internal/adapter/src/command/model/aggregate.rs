@@ -1,108 +1,75 @@
-use std::collections::HashMap;
-
-use aws_sdk_dynamodb::types::AttributeValue;
+use event_store::VersionedPayload;
 use serde::{Deserialize, Serialize};
 
 use super::Item;
 
 type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub(crate) struct AggregateModel {
-    id: String,
-    version: u64,
-    payload: AggregatePayload,
-}
-
-impl AggregateModel {
-    pub(crate) fn version(&self) -> u64 {
-        self.version
-    }
-
-    pub(crate) fn version_attribute_value<T: From<serde_dynamo::AttributeValue>>(
-        &self,
-    ) -> Result<T, Error> {
-        Ok(serde_dynamo::to_attribute_value(self.version)?)
-    }
+/// 集約のテーブルモデル
+pub(crate) type AggregateModel = event_store::AggregateModel<AggregatePayload>;
 
-    pub(crate) fn payload_attribute_value<T: From<serde_dynamo::AttributeValue>>(
-        &self,
-    ) -> Result<T, Error> {
-        Ok(serde_dynamo::to_attribute_value(&self.payload)?)
-    }
-
-    #[cfg(test)]
-    pub(crate) fn new(id: String, version: u64, payload: AggregatePayload) -> Self {
-        Self {
-            id,
-            version,
-            payload,
-        }
-    }
-
-    #[cfg(test)]
-    #[allow(dead_code)]
-    pub(crate) fn id(&self) -> &str {
-        &self.id
-    }
+/// 集約からテーブルモデルに変換する
+pub(crate) fn from_aggregate(aggregate: kernel::Aggregate) -> AggregateModel {
+    AggregateModel::new(
+        aggregate.id().to_string(),
+        aggregate.version(),
+        aggregate.into(),
+    )
 }
 
-impl From<kernel::Aggregate> for AggregateModel {
-    fn from(value: kernel::Aggregate) -> Self {
-        Self {
-            id: value.id().to_string(),
-            version: value.version(),
-            payload: value.into(),
+/// テーブルモデルから集約に変換する
+pub(crate) fn into_aggregate(model: AggregateModel) -> Result<kernel::Aggregate, Error> {
+    let id = model.id().parse()?;
+    let version = model.version();
+    let (name, items) = match model.into_payload() {
+        AggregatePayload::V1 { name, items } | AggregatePayload::V2 { name, items, .. } => {
+            (name, items)
         }
-    }
+    };
+    let items: Vec<kernel::Item> = items
+        .into_iter()
+        .map(TryInto::try_into)
+        .collect::<Result<_, _>>()?;
+    Ok(kernel::Aggregate::new(id, name, items, version))
 }
 
-impl TryInto<kernel::Aggregate> for AggregateModel {
-    type Error = Error;
-
-    fn try_into(self) -> Result<kernel::Aggregate, Self::Error> {
-        let (name, items) = match self.payload {
-            AggregatePayload::V1 { name, items } => (name, items),
-        };
-        let items: Vec<kernel::Item> = items
-            .into_iter()
-            .map(|item| match item {
-                Item::V1 { id, name, price } => {
-                    Ok::<_, Error>(kernel::Item::new(id.parse()?, name, price))
-                }
-            })
-            .collect::<Result<_, _>>()?;
-        Ok(kernel::Aggregate::new(
-            self.id.parse()?,
-            name,
-            items,
-            self.version,
-        ))
-    }
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum AggregatePayload {
+    V1 {
+        name: String,
+        items: Vec<Item>,
+    },
+    V2 {
+        name: String,
+        items: Vec<Item>,
+        /// 商品数
+        item_count: u64,
+    },
 }
 
-impl<S> TryFrom<AggregateModel> for HashMap<String, AttributeValue, S>
-where
-    S: std::hash::BuildHasher,
-    HashMap<String, AttributeValue, S>: From<serde_dynamo::Item>,
-{
-    type Error = Error;
-
-    fn try_from(value: AggregateModel) -> Result<Self, Self::Error> {
-        Ok(serde_dynamo::to_item(value)?)
+impl VersionedPayload for AggregatePayload {
+    fn is_latest(&self) -> bool {
+        matches!(self, Self::V2 { .. })
     }
-}
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub(crate) enum AggregatePayload {
-    V1 { name: String, items: Vec<Item> },
+    fn into_latest(self) -> Self {
+        match self {
+            Self::V1 { name, items } => Self::V2 {
+                item_count: items.len() as u64,
+                name,
+                items,
+            },
+            payload @ Self::V2 { .. } => payload,
+        }
+    }
 }
 
 impl From<kernel::Aggregate> for AggregatePayload {
     fn from(value: kernel::Aggregate) -> Self {
         let items: Vec<Item> = value.items().iter().cloned().map(Into::into).collect();
-        Self::V1 {
+        Self::V2 {
             name: value.name().to_string(),
+            item_count: items.len() as u64,
             items,
         }
     }
@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
 
+type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub(crate) enum Item {
+pub enum Item {
     V1 {
         id: String,
         name: String,
@@ -18,3 +20,13 @@ impl From<kernel::Item> for Item {
         }
     }
 }
+
+impl TryFrom<Item> for kernel::Item {
+    type Error = Error;
+
+    fn try_from(value: Item) -> Result<Self, Self::Error> {
+        match value {
+            Item::V1 { id, name, price } => Ok(Self::new(id.parse()?, name, price)),
+        }
+    }
+}
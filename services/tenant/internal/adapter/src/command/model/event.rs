@@ -1,56 +1,27 @@
-use std::collections::HashMap;
-
-use aws_sdk_dynamodb::types::AttributeValue;
 use serde::{Deserialize, Serialize};
 
 use super::Item;
 
 type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub(crate) struct EventStoreModel {
-    id: u64,
-    aggregate_id: String,
-    payload: EventPayload,
-}
-
-impl EventStoreModel {
-    pub(crate) fn new(id: u64, aggregate_id: String, payload: EventPayload) -> Self {
-        Self {
-            id,
-            aggregate_id,
-            payload,
-        }
-    }
-
-    #[cfg(test)]
-    pub(crate) fn id(&self) -> u64 {
-        self.id
-    }
-
-    #[cfg(test)]
-    pub(crate) fn aggregate_id(&self) -> &str {
-        &self.aggregate_id
-    }
-}
-
-impl<S> TryFrom<EventStoreModel> for HashMap<String, AttributeValue, S>
-where
-    S: std::hash::BuildHasher,
-    HashMap<String, AttributeValue, S>: From<serde_dynamo::Item>,
-{
-    type Error = Error;
-
-    fn try_from(value: EventStoreModel) -> Result<Self, Self::Error> {
-        Ok(serde_dynamo::to_item(value)?)
-    }
-}
+/// イベントストアのテーブルモデル
+pub type EventStoreModel = event_store::EventStoreModel<EventPayload>;
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub(crate) enum EventPayload {
-    TenantCreatedV1 { name: String },
-    ItemsAddedV1 { items: Vec<Item> },
-    ItemsRemoved { item_ids: Vec<String> },
+pub enum EventPayload {
+    TenantCreatedV1 {
+        name: String,
+    },
+    ItemsAddedV1 {
+        items: Vec<Item>,
+    },
+    /// NOTE: バージョンのない古いペイロードなので、読み込み時に `ItemsRemovedV1` にアップキャストする
+    ItemsRemoved {
+        item_ids: Vec<String>,
+    },
+    ItemsRemovedV1 {
+        item_ids: Vec<String>,
+    },
 }
 
 impl From<kernel::Event> for EventPayload {
@@ -60,59 +31,35 @@ impl From<kernel::Event> for EventPayload {
             kernel::Event::ItemsAdded { items } => Self::ItemsAddedV1 {
                 items: items.into_iter().map(Into::into).collect(),
             },
-            kernel::Event::ItemsRemoved { item_ids } => Self::ItemsRemoved {
+            kernel::Event::ItemsRemoved { item_ids } => Self::ItemsRemovedV1 {
                 item_ids: item_ids.into_iter().map(|x| x.to_string()).collect(),
             },
         }
     }
 }
 
-impl TryFrom<EventPayload> for AttributeValue {
+impl TryFrom<EventPayload> for kernel::Event {
     type Error = Error;
 
     fn try_from(value: EventPayload) -> Result<Self, Self::Error> {
-        Ok(serde_dynamo::to_attribute_value(value)?)
-    }
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub(crate) struct EventSequenceModel {
-    aggregate_id: String,
-    version: u64,
-}
-
-impl EventSequenceModel {
-    pub(crate) fn new(aggregate_id: String, version: u64) -> Self {
-        Self {
-            aggregate_id,
-            version,
-        }
-    }
-
-    pub(crate) fn version(&self) -> u64 {
-        self.version
-    }
-
-    pub(crate) fn version_attribute_value<T: From<serde_dynamo::AttributeValue>>(
-        &self,
-    ) -> Result<T, Error> {
-        Ok(serde_dynamo::to_attribute_value(self.version)?)
-    }
-
-    #[cfg(test)]
-    pub(crate) fn aggregate_id(&self) -> &str {
-        &self.aggregate_id
-    }
-}
-
-impl<S> TryFrom<EventSequenceModel> for HashMap<String, AttributeValue, S>
-where
-    S: std::hash::BuildHasher,
-    HashMap<String, AttributeValue, S>: From<serde_dynamo::Item>,
-{
-    type Error = Error;
-
-    fn try_from(value: EventSequenceModel) -> Result<Self, Self::Error> {
-        Ok(serde_dynamo::to_item(value)?)
+        let event = match value {
+            EventPayload::TenantCreatedV1 { name } => Self::Created { name },
+            EventPayload::ItemsAddedV1 { items } => Self::ItemsAdded {
+                items: items
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<_, _>>()?,
+            },
+            EventPayload::ItemsRemovedV1 { item_ids } => Self::ItemsRemoved {
+                item_ids: item_ids
+                    .iter()
+                    .map(|id| id.parse())
+                    .collect::<Result<_, _>>()?,
+            },
+            EventPayload::ItemsRemoved { .. } => {
+                return Err("ItemsRemoved must be upcasted to ItemsRemovedV1".into())
+            }
+        };
+        Ok(event)
     }
 }
@@ -0,0 +1,47 @@
+use event_store::IdempotencyKeyModel;
+
+type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// 追加した商品のIDを保存するメタデータのキー
+const ITEM_IDS_KEY: &str = "item_ids";
+
+/// 冪等キーからテーブルモデルに変換する
+pub(crate) fn from_idempotency(idempotency: &kernel::Idempotency) -> IdempotencyKeyModel {
+    let mut model = IdempotencyKeyModel::new(
+        idempotency.key().to_string(),
+        idempotency.aggregate_id().to_string(),
+        idempotency.version(),
+    );
+    if !idempotency.item_ids().is_empty() {
+        let item_ids: Vec<_> = idempotency
+            .item_ids()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        model
+            .metadata_mut()
+            .insert(ITEM_IDS_KEY.to_string(), item_ids.join(","));
+    }
+    model
+}
+
+/// テーブルモデルから冪等キーに変換する
+pub(crate) fn into_idempotency(model: &IdempotencyKeyModel) -> Result<kernel::Idempotency, Error> {
+    let item_ids = model
+        .metadata()
+        .get(ITEM_IDS_KEY)
+        .map(|item_ids| {
+            item_ids
+                .split(',')
+                .map(str::parse)
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()?
+        .unwrap_or_default();
+    Ok(kernel::Idempotency::new(
+        model.key().to_string(),
+        model.aggregate_id().parse()?,
+        model.version(),
+        item_ids,
+    ))
+}
@@ -1,4 +1,106 @@
+use event_store::dynamodb::DynamoDbEventStore;
+use event_store::in_memory::InMemoryEventStore;
+use event_store::postgres::PostgresEventStore;
+use event_store::sqlite::SqliteEventStore;
+use event_store::{
+    AnyEventStore, EventLog, EventSequenceModel, EventStoreError, EventStoreModel, SequencePosition,
+};
+
+use super::repository::TABLE_NAMES;
+use super::{AggregatePayload, EventPayload};
+
+type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
 #[must_use]
 pub fn dynamodb(config: &aws_config::SdkConfig) -> aws_sdk_dynamodb::Client {
     aws_sdk_dynamodb::Client::new(config)
 }
+
+/// `PostgreSQL` のコネクションプールを作成する
+///
+/// # Errors
+pub async fn postgres(database_url: &str) -> Result<sqlx::PgPool, sqlx::Error> {
+    sqlx::PgPool::connect(database_url).await
+}
+
+/// `SQLite` のコネクションプールを作成する
+///
+/// データベースのファイルが存在しない場合は作成する
+///
+/// # Errors
+pub async fn sqlite(database_url: &str) -> Result<sqlx::SqlitePool, sqlx::Error> {
+    use std::str::FromStr as _;
+
+    let options =
+        sqlx::sqlite::SqliteConnectOptions::from_str(database_url)?.create_if_missing(true);
+    sqlx::SqlitePool::connect_with(options).await
+}
+
+/// コマンド操作とクエリ操作のリポジトリで共有するイベントストア
+#[derive(Debug, Clone)]
+pub struct EventStore(pub(crate) AnyEventStore<AggregatePayload, EventPayload>);
+
+impl EventStore {
+    /// `DynamoDB` をバックエンドにしたイベントストアを作成する
+    #[must_use]
+    pub fn dynamodb(dynamodb: aws_sdk_dynamodb::Client) -> Self {
+        Self(DynamoDbEventStore::new(dynamodb, TABLE_NAMES).into())
+    }
+
+    /// `PostgreSQL` をバックエンドにしたイベントストアを作成する
+    ///
+    /// テーブルが存在しない場合は作成する
+    ///
+    /// # Errors
+    pub async fn postgres(pool: sqlx::PgPool) -> Result<Self, Error> {
+        let event_store = PostgresEventStore::new(pool, TABLE_NAMES);
+        event_store.create_tables().await?;
+        Ok(Self(event_store.into()))
+    }
+
+    /// `SQLite` をバックエンドにしたイベントストアを作成する
+    ///
+    /// テーブルが存在しない場合は作成する
+    ///
+    /// # Errors
+    pub async fn sqlite(pool: sqlx::SqlitePool) -> Result<Self, Error> {
+        let event_store = SqliteEventStore::new(pool, TABLE_NAMES);
+        event_store.create_tables().await?;
+        Ok(Self(event_store.into()))
+    }
+
+    /// プロセスのメモリ上にデータを保存するイベントストアを作成する
+    ///
+    /// `DynamoDB` を用意せずにサーバーを起動する場合に利用する
+    #[must_use]
+    pub fn in_memory() -> Self {
+        Self(InMemoryEventStore::new().into())
+    }
+}
+
+impl EventLog<EventPayload> for EventStore {
+    async fn list_sequences(
+        &self,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<EventSequenceModel>, EventStoreError> {
+        self.0.list_sequences(after, limit).await
+    }
+
+    async fn list_sequences_since(
+        &self,
+        after: &SequencePosition,
+        limit: usize,
+    ) -> Result<Vec<EventSequenceModel>, EventStoreError> {
+        self.0.list_sequences_since(after, limit).await
+    }
+
+    async fn list_events(
+        &self,
+        aggregate_id: &str,
+        after: Option<u64>,
+        limit: usize,
+    ) -> Result<Vec<EventStoreModel<EventPayload>>, EventStoreError> {
+        self.0.list_events(aggregate_id, after, limit).await
+    }
+}
@@ -1,23 +1,101 @@
-use aws_sdk_dynamodb::types::{AttributeValue, Put, TransactWriteItem, Update};
+use std::sync::Arc;
+
+use event_store::{AnyEventStore, EventStore as _, EventStoreError, TableNames, UpcasterRegistry};
 use kernel::command::error::CommandProcessorError;
 use kernel::{CommandKernelError, CommandProcessor, Event, Id};
 use tracing::instrument;
 
-use crate::{AGGREGATE_TABLE_NAME, EVENT_SEQUENCE_TABLE_NAME, EVENT_STORE_TABLE_NAME};
+use crate::query::TenantProjector;
+use crate::{
+    AGGREGATE_TABLE_NAME, EVENT_SEQUENCE_TABLE_NAME, EVENT_STORE_TABLE_NAME,
+    IDEMPOTENCY_KEY_TABLE_NAME, OUTBOX_TABLE_NAME,
+};
+
+use super::persistence::EventStore;
+use super::upcaster::upcasters;
+use super::{
+    from_aggregate, from_idempotency, into_aggregate, into_idempotency, AggregatePayload,
+    EventPayload,
+};
+
+pub use event_store::SnapshotPolicy;
 
-use super::{AggregateModel, EventSequenceModel, EventStoreModel};
+pub(crate) const TABLE_NAMES: TableNames = TableNames {
+    aggregate: AGGREGATE_TABLE_NAME,
+    event_store: EVENT_STORE_TABLE_NAME,
+    event_sequence: EVENT_SEQUENCE_TABLE_NAME,
+    idempotency_key: IDEMPOTENCY_KEY_TABLE_NAME,
+    outbox: OUTBOX_TABLE_NAME,
+};
 
 /// コマンド操作を行うリポジトリ
 #[derive(Debug, Clone)]
 pub struct CommandRepository {
-    dynamodb: aws_sdk_dynamodb::Client,
+    event_store: AnyEventStore<AggregatePayload, EventPayload>,
+    upcasters: Arc<UpcasterRegistry<EventPayload>>,
+    /// イベントを保存した後に同じプロセスでプロジェクションを更新するプロジェクター
+    projectors: Arc<Vec<TenantProjector>>,
 }
 
 impl CommandRepository {
     /// Creates a new [`CommandRepository`].
     #[must_use]
-    pub fn new(dynamodb: aws_sdk_dynamodb::Client) -> Self {
-        Self { dynamodb }
+    pub fn new(event_store: EventStore) -> Self {
+        Self {
+            event_store: event_store.0,
+            upcasters: Arc::new(upcasters()),
+            projectors: Arc::default(),
+        }
+    }
+
+    /// イベントを保存した後に同じプロセスでプロジェクションを更新する。複数回呼び出すと全てのプロジェクターで更新する
+    ///
+    /// `DynamoDB Streams` を使えないイベントストアのバックエンドで、イベントルーターの代わりにプロジェクションを更新する場合に利用する
+    #[must_use]
+    pub fn with_projector(self, projector: impl Into<TenantProjector>) -> Self {
+        let mut projectors = self.projectors.to_vec();
+        projectors.push(projector.into());
+        Self {
+            projectors: Arc::new(projectors),
+            ..self
+        }
+    }
+
+    /// プロジェクションを更新する
+    ///
+    /// イベントは保存済みなので、失敗した場合はエラーを記録するだけにしてコマンド操作を失敗させない
+    async fn project(&self, id: &str) {
+        for projector in self.projectors.iter() {
+            if let Err(e) = projector.project(id).await {
+                tracing::error!("failed to project tenant {id}: {e:?}");
+            }
+        }
+    }
+
+    /// スナップショットを保存する方針を設定する
+    #[must_use]
+    pub fn with_snapshot_policy(self, snapshot_policy: SnapshotPolicy) -> Self {
+        Self {
+            event_store: self.event_store.with_snapshot_policy(snapshot_policy),
+            ..self
+        }
+    }
+
+    /// 集約の現在の状態をスナップショットとして保存する
+    ///
+    /// 集約が存在しない場合は `false` を返す
+    ///
+    /// # Errors
+    #[instrument(skip(self), err, ret)]
+    pub async fn snapshot(&self, id: Id<kernel::Aggregate>) -> Result<bool, CommandKernelError> {
+        let Some(aggregate) = self.get(id).await? else {
+            return Ok(false);
+        };
+        self.event_store
+            .save_snapshot(from_aggregate(aggregate))
+            .await
+            .map_err(|e| CommandKernelError::Unknown(e.into()))?;
+        Ok(true)
     }
 }
 
@@ -27,64 +105,24 @@ impl CommandProcessor for CommandRepository {
         &self,
         aggregate: kernel::Aggregate,
         event: kernel::Event,
+        idempotency: Option<kernel::Idempotency>,
     ) -> Result<(), kernel::CommandKernelError> {
         if !matches!(event, kernel::Event::Created { .. }) {
             return Err(CommandProcessorError::InvalidEvent.into());
         }
-        let mut transact_items = Vec::new();
-        let sequence = EventSequenceModel::new(aggregate.id().to_string(), 0);
-        let event_id = sequence.version();
-        transact_items.push(
-            TransactWriteItem::builder()
-                .put(
-                    Put::builder()
-                        .table_name(EVENT_STORE_TABLE_NAME)
-                        .set_item(Some(
-                            EventStoreModel::new(
-                                event_id,
-                                aggregate.id().to_string(),
-                                event.into(),
-                            )
-                            .try_into()?,
-                        ))
-                        .condition_expression(
-                            "attribute_not_exists(id) AND attribute_not_exists(aggregate_id)",
-                        )
-                        .build()
-                        .map_err(|e| CommandKernelError::Unknown(e.into()))?,
-                )
-                .build(),
-        );
-        transact_items.push(
-            TransactWriteItem::builder()
-                .put(
-                    Put::builder()
-                        .table_name(EVENT_SEQUENCE_TABLE_NAME)
-                        .set_item(Some(sequence.try_into()?))
-                        .condition_expression("attribute_not_exists(aggregate_id)")
-                        .build()
-                        .map_err(|e| CommandKernelError::Unknown(e.into()))?,
-                )
-                .build(),
-        );
-        transact_items.push(
-            TransactWriteItem::builder()
-                .put(
-                    Put::builder()
-                        .table_name(AGGREGATE_TABLE_NAME)
-                        .set_item(Some(AggregateModel::from(aggregate).try_into()?))
-                        .condition_expression("attribute_not_exists(id)")
-                        .build()
-                        .map_err(|e| CommandKernelError::Unknown(e.into()))?,
-                )
-                .build(),
-        );
-        self.dynamodb
-            .transact_write_items()
-            .set_transact_items(Some(transact_items))
-            .send()
+        let id = aggregate.id().to_string();
+        self.event_store
+            .create(
+                from_aggregate(aggregate),
+                event.into(),
+                idempotency.iter().map(from_idempotency).collect(),
+            )
             .await
-            .map_err(|e| CommandKernelError::Unknown(e.into()))?;
+            .map_err(|e| match e {
+                EventStoreError::ConcurrencyConflict => CommandKernelError::ConcurrencyConflict,
+                e => CommandKernelError::Unknown(e.into()),
+            })?;
+        self.project(&id).await;
         Ok(())
     }
 
@@ -93,36 +131,32 @@ impl CommandProcessor for CommandRepository {
         &self,
         id: Id<kernel::Aggregate>,
     ) -> Result<Option<kernel::Aggregate>, kernel::CommandKernelError> {
-        use aws_sdk_dynamodb::operation::get_item::GetItemError::ResourceNotFoundException;
-
-        let output = match self
-            .dynamodb
-            .get_item()
-            .table_name(AGGREGATE_TABLE_NAME)
-            .key("id", AttributeValue::S(id.to_string()))
-            .send()
+        let Some(aggregate_with_events) = self
+            .event_store
+            .get(&id.to_string())
             .await
-        {
-            Ok(output) => output,
-            Err(e) => match e.into_service_error() {
-                ResourceNotFoundException(_) => return Ok(None),
-                e => return Err(CommandKernelError::Unknown(e.into())),
-            },
-        };
-        let Some(item) = output.item() else {
+            .map_err(|e| CommandKernelError::Unknown(e.into()))?
+        else {
             return Ok(None);
         };
-        let model: AggregateModel = serde_dynamo::from_item(item.clone())
-            .map_err(|e| CommandKernelError::Unknown(e.into()))?;
-        Ok(Some(model.try_into()?))
+        let (snapshot, models) = aggregate_with_events.into_parts();
+        let mut aggregate = match snapshot {
+            Some(snapshot) => into_aggregate(snapshot)?,
+            None => kernel::Aggregate::new(id, String::new(), Vec::new(), 0),
+        };
+        for model in models {
+            let payload = self.upcasters.upcast(model.into_payload())?;
+            aggregate.apply_event(payload.try_into()?)?;
+        }
+        Ok(Some(aggregate))
     }
 
-    #[allow(clippy::too_many_lines)]
     #[instrument(skip(self), err, ret)]
     async fn update(
         &self,
         aggregate: kernel::Aggregate,
         events: Vec<kernel::Event>,
+        idempotency: Option<kernel::Idempotency>,
     ) -> Result<(), kernel::CommandKernelError> {
         if events.is_empty() {
             return Err(CommandProcessorError::EmptyEvent.into());
@@ -133,128 +167,56 @@ impl CommandProcessor for CommandRepository {
         {
             return Err(CommandProcessorError::InvalidEvent.into());
         }
-
-        let aggregate_id = AttributeValue::S(aggregate.id().to_string());
-        let sequence: EventSequenceModel = serde_dynamo::from_item(
-            self.dynamodb
-                .get_item()
-                .table_name(EVENT_SEQUENCE_TABLE_NAME)
-                .key("aggregate_id", aggregate_id.clone())
-                .send()
-                .await
-                .map_err(|e| CommandKernelError::Unknown(e.into()))?
-                .item()
-                .ok_or_else(|| CommandKernelError::Unknown("event sequence not found".into()))?
-                .clone(),
-        )
-        .map_err(|e| CommandKernelError::Unknown(e.into()))?;
-        let current_event_id = sequence.version();
-        let mut new_event_id = current_event_id;
-        let mut transact_items = Vec::new();
-        for event in events {
-            new_event_id += 1;
-            transact_items.push(
-                TransactWriteItem::builder()
-                    .put(
-                        Put::builder()
-                            .table_name(EVENT_STORE_TABLE_NAME)
-                            .set_item(Some(
-                                EventStoreModel::new(
-                                    new_event_id,
-                                    aggregate.id().to_string(),
-                                    event.into(),
-                                )
-                                .try_into()?,
-                            ))
-                            .condition_expression(
-                                "attribute_not_exists(id) AND attribute_not_exists(aggregate_id)",
-                            )
-                            .build()
-                            .map_err(|e| CommandKernelError::Unknown(e.into()))?,
-                    )
-                    .build(),
-            );
-        }
-        transact_items.push(
-            TransactWriteItem::builder()
-                .update(
-                    Update::builder()
-                        .table_name(EVENT_SEQUENCE_TABLE_NAME)
-                        .key("aggregate_id", aggregate_id.clone())
-                        .expression_attribute_values(
-                            ":current_version",
-                            sequence.version_attribute_value()?,
-                        )
-                        .expression_attribute_values(
-                            ":new_version",
-                            AttributeValue::N(new_event_id.to_string()),
-                        )
-                        .update_expression("SET version = :new_version")
-                        .condition_expression(
-                            "attribute_exists(aggregate_id) AND version = :current_version",
-                        )
-                        .build()
-                        .map_err(|e| CommandKernelError::Unknown(e.into()))?,
-                )
-                .build(),
-        );
-        let aggregate_model: AggregateModel = aggregate.into();
-        transact_items.push(
-            TransactWriteItem::builder()
-                .update(
-                    Update::builder()
-                        .table_name(AGGREGATE_TABLE_NAME)
-                        .key("id", aggregate_id)
-                        .expression_attribute_values(
-                            ":current_version",
-                            serde_dynamo::to_attribute_value(
-                                // NOTE: この時点のAggregateはバージョンが更新されているので1つ前のバージョンを指定する
-                                aggregate_model.version().checked_sub(1).ok_or_else(|| {
-                                    CommandKernelError::Unknown("invalid aggregate version".into())
-                                })?,
-                            )
-                            .map_err(|e| CommandKernelError::Unknown(e.into()))?,
-                        )
-                        .expression_attribute_values(
-                            ":new_version",
-                            aggregate_model.version_attribute_value()?,
-                        )
-                        .expression_attribute_values(
-                            ":new_payload",
-                            aggregate_model.payload_attribute_value()?,
-                        )
-                        .update_expression("SET version = :new_version, payload = :new_payload")
-                        .condition_expression("attribute_exists(id) AND version = :current_version")
-                        .build()
-                        .map_err(|e| CommandKernelError::Unknown(e.into()))?,
-                )
-                .build(),
-        );
-        self.dynamodb
-            .transact_write_items()
-            .set_transact_items(Some(transact_items))
-            .send()
+        let id = aggregate.id().to_string();
+        self.event_store
+            .update(
+                from_aggregate(aggregate),
+                events.into_iter().map(Into::into).collect(),
+                idempotency.as_ref().map(from_idempotency),
+                Vec::new(),
+            )
             .await
-            .map_err(|e| CommandKernelError::Unknown(e.into()))?;
+            .map_err(|e| match e {
+                EventStoreError::ConcurrencyConflict => CommandKernelError::ConcurrencyConflict,
+                e => CommandKernelError::Unknown(e.into()),
+            })?;
+        self.project(&id).await;
         Ok(())
     }
+
+    #[instrument(skip(self), err, ret)]
+    async fn get_idempotency(
+        &self,
+        key: String,
+    ) -> Result<Option<kernel::Idempotency>, kernel::CommandKernelError> {
+        let Some(model) = self
+            .event_store
+            .get_idempotency_key(&key)
+            .await
+            .map_err(|e| CommandKernelError::Unknown(e.into()))?
+        else {
+            return Ok(None);
+        };
+        Ok(Some(into_idempotency(&model)?))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use kernel::command::error::CommandProcessorError;
-    use kernel::{Aggregate, CommandKernelError, CommandProcessor, Event, Id};
+    use kernel::{Aggregate, CommandKernelError, CommandProcessor, Event, Id, Idempotency};
     use testcontainers::ContainerAsync;
     use testcontainers_modules::dynamodb_local::DynamoDb;
 
     use crate::command::repository::{
         AGGREGATE_TABLE_NAME, EVENT_SEQUENCE_TABLE_NAME, EVENT_STORE_TABLE_NAME,
+        IDEMPOTENCY_KEY_TABLE_NAME,
     };
     use crate::command::{
         AggregateModel, AggregatePayload, EventPayload, EventSequenceModel, EventStoreModel, Item,
     };
 
-    use super::CommandRepository;
+    use super::{CommandRepository, EventStore, SnapshotPolicy};
 
     type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
 
@@ -289,9 +251,10 @@ mod tests {
             expected_aggregate_models: vec![AggregateModel::new(
                 id.to_string(),
                 1,
-                AggregatePayload::V1 {
+                AggregatePayload::V2 {
                     name: TENANT_NAME.to_string(),
                     items: Vec::new(),
+                    item_count: 0,
                 },
             )],
             expected_event_store_models: vec![EventStoreModel::new(
@@ -312,7 +275,7 @@ mod tests {
             expected_event_sequence_models,
         } in tests
         {
-            let result = repository.create(aggregate, event).await;
+            let result = repository.create(aggregate, event, None).await;
             assert!(result.is_ok(), "{name}: result should be ok");
             let actual_aggregate_models: Vec<AggregateModel> = serde_dynamo::from_items(
                 dynamodb
@@ -346,6 +309,17 @@ mod tests {
                     .items()
                     .to_vec(),
             )?;
+            // NOTE: 更新した時刻は実行するたびに変わるので比較しない
+            let actual_event_sequence_models: Vec<EventSequenceModel> =
+                actual_event_sequence_models
+                    .iter()
+                    .map(|model| {
+                        EventSequenceModel::new(
+                            model.aggregate_id().to_string(),
+                            model.latest_event_id(),
+                        )
+                    })
+                    .collect();
             assert_eq!(
                 actual_event_sequence_models, expected_event_sequence_models,
                 "{name}"
@@ -386,7 +360,7 @@ mod tests {
             assert,
         } in tests
         {
-            let result = repository.create(aggregate, event).await;
+            let result = repository.create(aggregate, event, None).await;
             assert!(result.is_err(), "{name}: result mut be error");
             assert(name, result.err().unwrap());
         }
@@ -493,7 +467,7 @@ mod tests {
         {
             fixture.run(&dynamodb).await?;
 
-            let result = repository.create(aggregate, event).await;
+            let result = repository.create(aggregate, event, None).await;
             assert!(result.is_err(), "{name}: result must be error");
             assert(name, result.err().unwrap());
 
@@ -503,6 +477,7 @@ mod tests {
     }
 
     #[tokio::test]
+    #[allow(clippy::too_many_lines)]
     async fn test_with_container_get_ok() -> Result<(), Error> {
         struct TestCase {
             name: &'static str,
@@ -522,20 +497,73 @@ mod tests {
 
         let tests = [
             TestCase {
-                name: "AggregateTableに指定したIDを持つ集約がある場合はその集約を返す",
+                name: "EventStoreTableに指定したIDを持つイベントがある場合はイベントから復元した集約を返す",
+                fixture: Fixture {
+                    event_stores: vec![
+                        EventStoreModel::new(
+                            0,
+                            aggregate_id.to_string(),
+                            EventPayload::TenantCreatedV1 {
+                                name: TENANT_NAME.to_string(),
+                            },
+                        ),
+                        EventStoreModel::new(
+                            1,
+                            aggregate_id.to_string(),
+                            EventPayload::ItemsAddedV1 {
+                                items: vec![Item::V1 {
+                                    id: item_id.to_string(),
+                                    name: ITEM_NAME.to_string(),
+                                    price: 1000,
+                                }],
+                            },
+                        ),
+                    ],
+                    ..Default::default()
+                },
+                id: aggregate_id.clone(),
+                expected: Some(Aggregate::new(
+                    aggregate_id.clone(),
+                    TENANT_NAME.to_string(),
+                    vec![kernel::Item::new(
+                        item_id.clone(),
+                        ITEM_NAME.to_string(),
+                        1000,
+                    )],
+                    2,
+                )),
+            },
+            TestCase {
+                name: "スナップショットがある場合はスナップショット以降のイベントだけを適用した集約を返す",
                 fixture: Fixture {
                     aggregates: vec![AggregateModel::new(
                         aggregate_id.to_string(),
-                        2,
+                        1,
                         AggregatePayload::V1 {
                             name: TENANT_NAME.to_string(),
-                            items: vec![Item::V1 {
-                                id: item_id.to_string(),
-                                name: ITEM_NAME.to_string(),
-                                price: 1000,
-                            }],
+                            items: Vec::new(),
                         },
                     )],
+                    event_stores: vec![
+                        EventStoreModel::new(
+                            0,
+                            aggregate_id.to_string(),
+                            EventPayload::TenantCreatedV1 {
+                                name: TENANT_NAME.to_string(),
+                            },
+                        ),
+                        EventStoreModel::new(
+                            1,
+                            aggregate_id.to_string(),
+                            EventPayload::ItemsAddedV1 {
+                                items: vec![Item::V1 {
+                                    id: item_id.to_string(),
+                                    name: ITEM_NAME.to_string(),
+                                    price: 1000,
+                                }],
+                            },
+                        ),
+                    ],
                     ..Default::default()
                 },
                 id: aggregate_id.clone(),
@@ -551,7 +579,28 @@ mod tests {
                 )),
             },
             TestCase {
-                name: "AggregateTableに指定したIDを持つ集約がない場合はNoneを返す",
+                name: "スナップショット以降のイベントがない場合はスナップショットの集約を返す",
+                fixture: Fixture {
+                    aggregates: vec![AggregateModel::new(
+                        aggregate_id.to_string(),
+                        2,
+                        AggregatePayload::V1 {
+                            name: TENANT_NAME.to_string(),
+                            items: Vec::new(),
+                        },
+                    )],
+                    ..Default::default()
+                },
+                id: aggregate_id.clone(),
+                expected: Some(Aggregate::new(
+                    aggregate_id.clone(),
+                    TENANT_NAME.to_string(),
+                    Vec::new(),
+                    2,
+                )),
+            },
+            TestCase {
+                name: "EventStoreTableに指定したIDを持つイベントがない場合はNoneを返す",
                 fixture: Fixture {
                     ..Default::default()
                 },
@@ -640,13 +689,14 @@ mod tests {
                 expected_aggregate_models: vec![AggregateModel::new(
                     aggregate_id.to_string(),
                     2,
-                    AggregatePayload::V1 {
+                    AggregatePayload::V2 {
                         name: TENANT_NAME.to_string(),
                         items: vec![Item::V1 {
                             id: item_id.to_string(),
                             name: ITEM_NAME.to_string(),
                             price: 1000,
                         }],
+                        item_count: 1,
                     },
                 )],
                 expected_event_store_models: vec![
@@ -741,13 +791,14 @@ mod tests {
                 expected_aggregate_models: vec![AggregateModel::new(
                     aggregate_id.to_string(),
                     3,
-                    AggregatePayload::V1 {
+                    AggregatePayload::V2 {
                         name: TENANT_NAME.to_string(),
                         items: vec![Item::V1 {
                             id: item_id_2.to_string(),
                             name: ITEM_NAME.to_string(),
                             price: 2000,
                         }],
+                        item_count: 1,
                     },
                 )],
                 expected_event_store_models: vec![
@@ -779,7 +830,7 @@ mod tests {
                     EventStoreModel::new(
                         2,
                         aggregate_id.to_string(),
-                        EventPayload::ItemsRemoved {
+                        EventPayload::ItemsRemovedV1 {
                             item_ids: vec![item_id.to_string()],
                         },
                     ),
@@ -802,7 +853,7 @@ mod tests {
         {
             fixture.run(&dynamodb).await?;
 
-            let result = repository.update(aggregate, events).await;
+            let result = repository.update(aggregate, events, None).await;
             assert!(result.is_ok(), "{name}: result must be ok: {result:?}");
             let actual_aggregate_models: Vec<AggregateModel> = serde_dynamo::from_items(
                 dynamodb
@@ -836,6 +887,17 @@ mod tests {
                     .items()
                     .to_vec(),
             )?;
+            // NOTE: 更新した時刻は実行するたびに変わるので比較しない
+            let actual_event_sequence_models: Vec<EventSequenceModel> =
+                actual_event_sequence_models
+                    .iter()
+                    .map(|model| {
+                        EventSequenceModel::new(
+                            model.aggregate_id().to_string(),
+                            model.latest_event_id(),
+                        )
+                    })
+                    .collect();
             assert_eq!(
                 actual_event_sequence_models, expected_event_sequence_models,
                 "{name}"
@@ -847,6 +909,100 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_with_container_update_snapshot_ok() -> Result<(), Error> {
+        struct TestCase {
+            name: &'static str,
+            snapshot_policy: SnapshotPolicy,
+            expected_aggregate_models: Vec<AggregateModel>,
+        }
+
+        let Context {
+            container: _container,
+            repository,
+            dynamodb,
+        } = Context::with_container().await?;
+
+        let aggregate_id: Id<Aggregate> = Id::generate();
+        let fixture = Fixture {
+            aggregates: vec![AggregateModel::new(
+                aggregate_id.to_string(),
+                1,
+                AggregatePayload::V1 {
+                    name: TENANT_NAME.to_string(),
+                    items: Vec::new(),
+                },
+            )],
+            event_stores: vec![EventStoreModel::new(
+                0,
+                aggregate_id.to_string(),
+                EventPayload::TenantCreatedV1 {
+                    name: TENANT_NAME.to_string(),
+                },
+            )],
+            event_sequences: vec![EventSequenceModel::new(aggregate_id.to_string(), 0)],
+        };
+        let tests = [
+            TestCase {
+                name: "スナップショットの頻度に達した場合はスナップショットが更新される",
+                snapshot_policy: SnapshotPolicy::EveryEvents(2.try_into()?),
+                expected_aggregate_models: vec![AggregateModel::new(
+                    aggregate_id.to_string(),
+                    2,
+                    AggregatePayload::V2 {
+                        name: TENANT_NAME.to_string(),
+                        items: Vec::new(),
+                        item_count: 0,
+                    },
+                )],
+            },
+            TestCase {
+                name:
+                    "スナップショットをオンデマンドで保存する場合はスナップショットが更新されない",
+                snapshot_policy: SnapshotPolicy::OnDemand,
+                expected_aggregate_models: vec![AggregateModel::new(
+                    aggregate_id.to_string(),
+                    1,
+                    AggregatePayload::V1 {
+                        name: TENANT_NAME.to_string(),
+                        items: Vec::new(),
+                    },
+                )],
+            },
+        ];
+        for TestCase {
+            name,
+            snapshot_policy,
+            expected_aggregate_models,
+        } in tests
+        {
+            fixture.run(&dynamodb).await?;
+
+            let repository = repository.clone().with_snapshot_policy(snapshot_policy);
+            let result = repository
+                .update(
+                    Aggregate::new(aggregate_id.clone(), TENANT_NAME.to_string(), Vec::new(), 2),
+                    vec![Event::ItemsAdded { items: Vec::new() }],
+                    None,
+                )
+                .await;
+            assert!(result.is_ok(), "{name}: result must be ok: {result:?}");
+            let actual_aggregate_models: Vec<AggregateModel> = serde_dynamo::from_items(
+                dynamodb
+                    .scan()
+                    .table_name(AGGREGATE_TABLE_NAME)
+                    .send()
+                    .await?
+                    .items()
+                    .to_vec(),
+            )?;
+            assert_eq!(actual_aggregate_models, expected_aggregate_models, "{name}");
+
+            fixture.rollback(&dynamodb).await?;
+        }
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_with_container_update_err() -> Result<(), Error> {
         struct TestCase {
@@ -866,7 +1022,7 @@ mod tests {
         let aggregate_id: Id<Aggregate> = Id::generate();
         let tests = [
             TestCase {
-                name: "集約バージョンがコンフリクトした場合はUnknownが返る",
+                name: "集約バージョンがコンフリクトした場合はConcurrencyConflictが返る",
                 fixture: Fixture {
                     aggregates: vec![AggregateModel::new(
                         aggregate_id.to_string(),
@@ -887,7 +1043,10 @@ mod tests {
                 ),
                 events: vec![Event::ItemsAdded { items: Vec::new() }],
                 assert: |name, actual| {
-                    assert!(matches!(actual, CommandKernelError::Unknown(_)), "{name}");
+                    assert!(
+                        matches!(actual, CommandKernelError::ConcurrencyConflict),
+                        "{name}"
+                    );
                 },
             },
             TestCase {
@@ -917,7 +1076,7 @@ mod tests {
         {
             fixture.run(&dynamodb).await?;
 
-            let result = repository.update(aggregate, events).await;
+            let result = repository.update(aggregate, events, None).await;
             assert!(result.is_err(), "{name}: result must be err");
             assert(name, result.err().unwrap());
 
@@ -926,6 +1085,73 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_with_container_idempotency_ok() -> Result<(), Error> {
+        let Context {
+            container: _container,
+            repository,
+            ..
+        } = Context::with_container().await?;
+
+        let aggregate_id: Id<Aggregate> = Id::generate();
+        let item_ids = vec![Id::generate(), Id::generate()];
+        let created = Idempotency::new(
+            "create:key".to_string(),
+            aggregate_id.clone(),
+            1,
+            Vec::new(),
+        );
+        let items_added = Idempotency::new(
+            format!("add_items:{aggregate_id}:key"),
+            aggregate_id.clone(),
+            2,
+            item_ids.clone(),
+        );
+        repository
+            .create(
+                Aggregate::new(aggregate_id.clone(), TENANT_NAME.to_string(), Vec::new(), 1),
+                Event::Created {
+                    name: TENANT_NAME.to_string(),
+                },
+                Some(created.clone()),
+            )
+            .await?;
+        repository
+            .update(
+                Aggregate::new(aggregate_id.clone(), TENANT_NAME.to_string(), Vec::new(), 2),
+                vec![Event::ItemsAdded { items: Vec::new() }],
+                Some(items_added.clone()),
+            )
+            .await?;
+        assert_eq!(
+            repository
+                .get_idempotency(created.key().to_string())
+                .await?,
+            Some(created)
+        );
+        assert_eq!(
+            repository
+                .get_idempotency(items_added.key().to_string())
+                .await?,
+            Some(items_added.clone()),
+            "追加した商品IDを復元できる"
+        );
+        assert!(
+            matches!(
+                repository
+                    .update(
+                        Aggregate::new(aggregate_id, TENANT_NAME.to_string(), Vec::new(), 3),
+                        vec![Event::ItemsAdded { items: Vec::new() }],
+                        Some(items_added),
+                    )
+                    .await,
+                Err(CommandKernelError::ConcurrencyConflict)
+            ),
+            "保存済みの冪等キーでは更新できない"
+        );
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_update_err() -> Result<(), Error> {
         struct TestCase {
@@ -993,7 +1219,7 @@ mod tests {
             assert,
         } in tests
         {
-            let result = repository.update(aggregate, events).await;
+            let result = repository.update(aggregate, events, None).await;
             assert!(result.is_err(), "{name}: result must be err");
             assert(name, result.err().unwrap());
         }
@@ -1007,6 +1233,7 @@ mod tests {
     }
 
     impl Context {
+        #[allow(clippy::too_many_lines)]
         async fn with_container() -> Result<Self, Error> {
             use aws_config::BehaviorVersion;
             use aws_sdk_dynamodb::types::{
@@ -1026,13 +1253,13 @@ mod tests {
                 container.get_host().await?,
                 container.get_host_port_ipv4(8000).await?,
             );
-            let config = aws_config::defaults(BehaviorVersion::v2024_03_28())
+            let config = aws_config::defaults(BehaviorVersion::latest())
                 .endpoint_url(endpoint)
                 .test_credentials()
                 .load()
                 .await;
             let dynamodb = aws_sdk_dynamodb::Client::new(&config);
-            let repository = CommandRepository::new(dynamodb.clone());
+            let repository = CommandRepository::new(EventStore::dynamodb(dynamodb.clone()));
 
             dynamodb
                 .create_table()
@@ -1101,6 +1328,25 @@ mod tests {
                 .send()
                 .await?;
 
+            dynamodb
+                .create_table()
+                .table_name(IDEMPOTENCY_KEY_TABLE_NAME)
+                .attribute_definitions(
+                    AttributeDefinition::builder()
+                        .attribute_name("key")
+                        .attribute_type(ScalarAttributeType::S)
+                        .build()?,
+                )
+                .key_schema(
+                    KeySchemaElement::builder()
+                        .attribute_name("key")
+                        .key_type(KeyType::Hash)
+                        .build()?,
+                )
+                .billing_mode(BillingMode::PayPerRequest)
+                .send()
+                .await?;
+
             Ok(Self {
                 container: Some(container),
                 repository,
@@ -1111,12 +1357,12 @@ mod tests {
         async fn without_container() -> Result<Self, Error> {
             use aws_config::BehaviorVersion;
 
-            let config = aws_config::defaults(BehaviorVersion::v2024_03_28())
+            let config = aws_config::defaults(BehaviorVersion::latest())
                 .test_credentials()
                 .load()
                 .await;
             let dynamodb = aws_sdk_dynamodb::Client::new(&config);
-            let repository = CommandRepository::new(dynamodb.clone());
+            let repository = CommandRepository::new(EventStore::dynamodb(dynamodb.clone()));
             Ok(Self {
                 container: None,
                 repository,
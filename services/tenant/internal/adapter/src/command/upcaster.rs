@@ -0,0 +1,184 @@
+use event_store::{Upcaster, UpcasterRegistry};
+
+use super::EventPayload;
+
+type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// イベントのペイロードを最新のバージョンに変換するアップキャスターを登録したレジストリ
+pub(crate) fn upcasters() -> UpcasterRegistry<EventPayload> {
+    UpcasterRegistry::new().register(ItemsRemovedUpcaster)
+}
+
+/// バージョンのない `ItemsRemoved` を `ItemsRemovedV1` に変換する
+struct ItemsRemovedUpcaster;
+
+impl Upcaster<EventPayload> for ItemsRemovedUpcaster {
+    fn upcast(&self, payload: EventPayload) -> Result<EventPayload, Error> {
+        match payload {
+            EventPayload::ItemsRemoved { item_ids } => {
+                Ok(EventPayload::ItemsRemovedV1 { item_ids })
+            }
+            payload => Ok(payload),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use event_store::{Upcaster, UpcasterRegistry};
+
+    use crate::command::{EventPayload, EventStoreModel, Item};
+
+    use super::upcasters;
+
+    type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    #[test]
+    fn test_upcast_ok() -> Result<(), Error> {
+        struct TestCase {
+            name: &'static str,
+            payload: EventPayload,
+            expected: EventPayload,
+        }
+
+        let tests = [
+            TestCase {
+                name: "ItemsRemovedはItemsRemovedV1に変換される",
+                payload: EventPayload::ItemsRemoved {
+                    item_ids: vec!["item".to_string()],
+                },
+                expected: EventPayload::ItemsRemovedV1 {
+                    item_ids: vec!["item".to_string()],
+                },
+            },
+            TestCase {
+                name: "最新のバージョンのペイロードはそのまま返る",
+                payload: EventPayload::TenantCreatedV1 {
+                    name: "テストテナント".to_string(),
+                },
+                expected: EventPayload::TenantCreatedV1 {
+                    name: "テストテナント".to_string(),
+                },
+            },
+        ];
+        let registry = upcasters();
+        for TestCase {
+            name,
+            payload,
+            expected,
+        } in tests
+        {
+            assert_eq!(registry.upcast(payload)?, expected, "{name}");
+        }
+        Ok(())
+    }
+
+    /// V2のスキーマを想定したイベントストアのテーブルモデル
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+    struct EventStoreModelV2 {
+        id: u64,
+        aggregate_id: String,
+        payload: EventPayloadV2,
+    }
+
+    /// V2のスキーマを想定したイベントのペイロード
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+    enum EventPayloadV2 {
+        TenantCreatedV1 { name: String },
+        TenantCreatedV2 { name: String, display_name: String },
+        ItemsAddedV1 { items: Vec<Item> },
+        ItemsRemoved { item_ids: Vec<String> },
+        ItemsRemovedV1 { item_ids: Vec<String> },
+    }
+
+    struct TenantCreatedV2Upcaster;
+
+    impl Upcaster<EventPayloadV2> for TenantCreatedV2Upcaster {
+        fn upcast(&self, payload: EventPayloadV2) -> Result<EventPayloadV2, Error> {
+            match payload {
+                EventPayloadV2::TenantCreatedV1 { name } => Ok(EventPayloadV2::TenantCreatedV2 {
+                    display_name: name.clone(),
+                    name,
+                }),
+                payload => Ok(payload),
+            }
+        }
+    }
+
+    struct ItemsRemovedUpcaster;
+
+    impl Upcaster<EventPayloadV2> for ItemsRemovedUpcaster {
+        fn upcast(&self, payload: EventPayloadV2) -> Result<EventPayloadV2, Error> {
+            match payload {
+                EventPayloadV2::ItemsRemoved { item_ids } => {
+                    Ok(EventPayloadV2::ItemsRemovedV1 { item_ids })
+                }
+                payload => Ok(payload),
+            }
+        }
+    }
+
+    #[test]
+    fn test_upcast_v1_rows_into_v2_ok() -> Result<(), Error> {
+        struct TestCase {
+            name: &'static str,
+            model: EventStoreModel,
+            expected: EventPayloadV2,
+        }
+
+        let tests = [
+            TestCase {
+                name: "TenantCreatedV1のレコードはTenantCreatedV2に変換される",
+                model: EventStoreModel::new(
+                    0,
+                    "aggregate".to_string(),
+                    EventPayload::TenantCreatedV1 {
+                        name: "テストテナント".to_string(),
+                    },
+                ),
+                expected: EventPayloadV2::TenantCreatedV2 {
+                    name: "テストテナント".to_string(),
+                    display_name: "テストテナント".to_string(),
+                },
+            },
+            TestCase {
+                name: "ItemsRemovedのレコードはItemsRemovedV1に変換される",
+                model: EventStoreModel::new(
+                    1,
+                    "aggregate".to_string(),
+                    EventPayload::ItemsRemoved {
+                        item_ids: vec!["item".to_string()],
+                    },
+                ),
+                expected: EventPayloadV2::ItemsRemovedV1 {
+                    item_ids: vec!["item".to_string()],
+                },
+            },
+            TestCase {
+                name: "変換対象ではないレコードはそのまま返る",
+                model: EventStoreModel::new(
+                    2,
+                    "aggregate".to_string(),
+                    EventPayload::ItemsAddedV1 { items: Vec::new() },
+                ),
+                expected: EventPayloadV2::ItemsAddedV1 { items: Vec::new() },
+            },
+        ];
+        let registry = UpcasterRegistry::new()
+            .register(TenantCreatedV2Upcaster)
+            .register(ItemsRemovedUpcaster);
+        for TestCase {
+            name,
+            model,
+            expected,
+        } in tests
+        {
+            let item: serde_dynamo::Item = serde_dynamo::to_item(model)?;
+            let model: EventStoreModelV2 = serde_dynamo::from_item(item)?;
+            assert_eq!(registry.upcast(model.payload)?, expected, "{name}");
+        }
+        Ok(())
+    }
+}
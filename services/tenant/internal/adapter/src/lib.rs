@@ -8,6 +8,14 @@ pub mod query;
 pub const EVENT_SEQUENCE_TABLE_NAME: &str = "tenant-event-sequence";
 pub const EVENT_STORE_TABLE_NAME: &str = "tenant-event-store";
 pub const AGGREGATE_TABLE_NAME: &str = "tenant-aggregate";
+pub const IDEMPOTENCY_KEY_TABLE_NAME: &str = "tenant-idempotency-key";
+pub const OUTBOX_TABLE_NAME: &str = "tenant-outbox";
+pub const ITEM_CATALOG_TABLE_NAME: &str = "tenant-projection-item-catalog";
 
-pub use command::{dynamodb, CommandRepository};
-pub use query::QueryRepository;
+pub use command::{
+    dynamodb, postgres, sqlite, AggregateMigration, CommandRepository, EventStore, SnapshotPolicy,
+};
+pub use query::{
+    ItemCatalogProjector, ItemCatalogStore, QueryRepository, SearchIndex, SearchProjector,
+    TenantProjector,
+};
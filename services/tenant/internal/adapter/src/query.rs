@@ -1,7 +1,13 @@
 /// クエリモデルを定義するモジュール
 pub mod model;
+/// クエリ操作でテナントの商品を検索するためのプロジェクション関連のモジュール
+pub mod projection;
 /// リポジトリ関連のモジュール
 pub mod repository;
+/// テナント名と商品名から全てのテナントを横断して検索するためのインデックス関連のモジュール
+pub mod search;
 
-pub(crate) use model::{AggregateModel, AggregatePayload};
+pub(crate) use model::{decode_page_token, into_page, into_tenant};
+pub use projection::{ItemCatalogProjector, ItemCatalogStore, TenantProjector};
 pub use repository::QueryRepository;
+pub use search::{SearchIndex, SearchProjector};
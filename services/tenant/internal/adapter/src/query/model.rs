@@ -1,45 +1,35 @@
-use serde::Deserialize;
+use event_store::PageToken;
+use kernel::query::{PageRequest, QueryKernelError};
 
-#[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub(crate) struct AggregateModel {
-    id: String,
-    version: u64,
-    payload: AggregatePayload,
-}
-
-impl AggregateModel {
-    pub(crate) fn payload(&self) -> &AggregatePayload {
-        &self.payload
-    }
-}
+use crate::command::{AggregateModel, AggregatePayload};
 
-impl From<AggregateModel> for kernel::query::Tenant {
-    fn from(value: AggregateModel) -> Self {
-        let name = match value.payload {
-            AggregatePayload::V1 { name, .. } => name,
-        };
-        Self::new(value.id, name)
-    }
-}
+// NOTE: Query領域だがデータベースを分けないのでCommandで定義したテーブルモデルを利用する
 
-#[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub(crate) enum AggregatePayload {
-    V1 { name: String, items: Vec<Item> },
+pub(crate) fn into_tenant(model: AggregateModel) -> kernel::query::Tenant {
+    let id = model.id().to_string();
+    let version = model.version();
+    let name = match model.into_payload() {
+        AggregatePayload::V1 { name, .. } | AggregatePayload::V2 { name, .. } => name,
+    };
+    kernel::query::Tenant::new(id, name, version)
 }
 
-#[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub(crate) enum Item {
-    V1 {
-        id: String,
-        name: String,
-        price: u32,
-    },
+/// クライアントから受け取ったページのトークンを復号する
+pub(crate) fn decode_page_token(page: &PageRequest) -> Result<Option<PageToken>, QueryKernelError> {
+    page.page_token()
+        .map(PageToken::decode)
+        .transpose()
+        .map_err(|_| QueryKernelError::InvalidPageToken)
 }
 
-impl From<Item> for kernel::query::Item {
-    fn from(value: Item) -> Self {
-        match value {
-            Item::V1 { id, name, price } => Self::new(id, name, price),
-        }
-    }
+/// イベントストアのページを、次のページのトークンを符号化したクエリモデルのページに変換する
+pub(crate) fn into_page<T, U>(
+    page: event_store::Page<T>,
+    f: impl FnMut(T) -> U,
+) -> kernel::query::Page<U> {
+    let (items, next_page_token) = page.into_parts();
+    kernel::query::Page::new(
+        items.into_iter().map(f).collect(),
+        next_page_token.as_ref().map(PageToken::encode),
+    )
 }
@@ -0,0 +1,366 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+
+use aws_sdk_dynamodb::types::{AttributeValue, DeleteRequest, PutRequest, WriteRequest};
+use kernel::CommandProcessor as _;
+use projection::{Projector, ProjectorError};
+use tracing::instrument;
+
+use crate::command::{CommandRepository, EventPayload, EventStore, EventStoreModel};
+use crate::ITEM_CATALOG_TABLE_NAME;
+
+use super::SearchProjector;
+
+type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+#[derive(Debug, Clone)]
+enum Backend {
+    DynamoDb(aws_sdk_dynamodb::Client),
+    /// テナントIDごとの商品ID順の商品
+    InMemory(Arc<RwLock<HashMap<String, BTreeMap<String, kernel::query::Item>>>>),
+}
+
+/// クエリ操作でテナントの商品を検索するための商品カタログのプロジェクションを保存するストア
+#[derive(Debug, Clone)]
+pub struct ItemCatalogStore(Backend);
+
+impl ItemCatalogStore {
+    /// `DynamoDB` のテーブルに保存するストアを作成する
+    #[must_use]
+    pub fn dynamodb(dynamodb: aws_sdk_dynamodb::Client) -> Self {
+        Self(Backend::DynamoDb(dynamodb))
+    }
+
+    /// プロセスのメモリ上に保存するストアを作成する
+    ///
+    /// `DynamoDB Streams` を使えないイベントストアのバックエンドで、コマンド操作と同じプロセスでプロジェクションを更新する場合に利用する
+    #[must_use]
+    pub fn in_memory() -> Self {
+        Self(Backend::InMemory(Arc::default()))
+    }
+
+    /// テナントの商品を現在の商品に置き換える
+    #[instrument(skip(self), err)]
+    async fn put(&self, tenant_id: &str, items: Vec<kernel::query::Item>) -> Result<(), Error> {
+        match &self.0 {
+            Backend::DynamoDb(client) => {
+                let current: HashSet<&str> = items.iter().map(kernel::query::Item::id).collect();
+                let mut requests = Vec::new();
+                // NOTE: 以前の商品のうち現在の商品に含まれない商品を削除する
+                for item_id in query_item_ids(client, tenant_id).await? {
+                    if current.contains(item_id.as_str()) {
+                        continue;
+                    }
+                    let delete = DeleteRequest::builder()
+                        .key("tenant_id", AttributeValue::S(tenant_id.to_string()))
+                        .key("item_id", AttributeValue::S(item_id))
+                        .build()?;
+                    requests.push(WriteRequest::builder().delete_request(delete).build());
+                }
+                for item in &items {
+                    let put = PutRequest::builder()
+                        .item("tenant_id", AttributeValue::S(tenant_id.to_string()))
+                        .item("item_id", AttributeValue::S(item.id().to_string()))
+                        .item("name", AttributeValue::S(item.name().to_string()))
+                        .item("price", AttributeValue::N(item.price().to_string()))
+                        .build()?;
+                    requests.push(WriteRequest::builder().put_request(put).build());
+                }
+                batch_write(client, requests).await
+            }
+            Backend::InMemory(catalog) => {
+                let mut catalog = catalog.write().map_err(|e| e.to_string())?;
+                catalog.insert(
+                    tenant_id.to_string(),
+                    items
+                        .into_iter()
+                        .map(|item| (item.id().to_string(), item))
+                        .collect(),
+                );
+                Ok(())
+            }
+        }
+    }
+
+    /// 全てのテナントの商品を削除する
+    ///
+    /// # Errors
+    #[instrument(skip(self), err)]
+    pub async fn reset(&self) -> Result<(), Error> {
+        match &self.0 {
+            Backend::DynamoDb(client) => {
+                let mut exclusive_start_key = None;
+                loop {
+                    let output = client
+                        .scan()
+                        .table_name(ITEM_CATALOG_TABLE_NAME)
+                        .projection_expression("tenant_id, item_id")
+                        .set_exclusive_start_key(exclusive_start_key)
+                        .send()
+                        .await?;
+                    let requests = output
+                        .items()
+                        .iter()
+                        .map(|key| {
+                            let delete = DeleteRequest::builder()
+                                .set_key(Some(key.clone()))
+                                .build()?;
+                            Ok(WriteRequest::builder().delete_request(delete).build())
+                        })
+                        .collect::<Result<Vec<_>, Error>>()?;
+                    batch_write(client, requests).await?;
+                    exclusive_start_key = output.last_evaluated_key;
+                    if exclusive_start_key.is_none() {
+                        break;
+                    }
+                }
+                Ok(())
+            }
+            Backend::InMemory(catalog) => {
+                catalog.write().map_err(|e| e.to_string())?.clear();
+                Ok(())
+            }
+        }
+    }
+
+    /// テナントの全ての商品を取得する
+    #[instrument(skip(self), err, ret)]
+    pub(crate) async fn list_items(
+        &self,
+        tenant_id: &str,
+    ) -> Result<Vec<kernel::query::Item>, Error> {
+        match &self.0 {
+            Backend::DynamoDb(client) => {
+                let mut items = Vec::new();
+                let mut exclusive_start_key = None;
+                loop {
+                    let output = client
+                        .query()
+                        .table_name(ITEM_CATALOG_TABLE_NAME)
+                        .key_condition_expression("tenant_id = :tenant_id")
+                        .expression_attribute_values(
+                            ":tenant_id",
+                            AttributeValue::S(tenant_id.to_string()),
+                        )
+                        .set_exclusive_start_key(exclusive_start_key)
+                        .send()
+                        .await?;
+                    for item in output.items() {
+                        items.push(into_item(item)?);
+                    }
+                    exclusive_start_key = output.last_evaluated_key;
+                    if exclusive_start_key.is_none() {
+                        break;
+                    }
+                }
+                Ok(items)
+            }
+            Backend::InMemory(catalog) => Ok(catalog
+                .read()
+                .map_err(|e| e.to_string())?
+                .get(tenant_id)
+                .map(|items| items.values().cloned().collect())
+                .unwrap_or_default()),
+        }
+    }
+}
+
+/// テーブルの行から商品に変換する
+fn into_item(item: &HashMap<String, AttributeValue>) -> Result<kernel::query::Item, Error> {
+    let attribute = |name: &str| {
+        item.get(name)
+            .ok_or_else(|| format!("attribute not found: {name}"))
+    };
+    let string = |name: &str| -> Result<String, Error> {
+        attribute(name)?
+            .as_s()
+            .cloned()
+            .map_err(|_| format!("attribute is not string: {name}").into())
+    };
+    let price = attribute("price")?
+        .as_n()
+        .map_err(|_| "attribute is not number: price")?
+        .parse()?;
+    Ok(kernel::query::Item::new(
+        string("item_id")?,
+        string("name")?,
+        price,
+    ))
+}
+
+/// テナントの全ての商品IDを取得する
+async fn query_item_ids(
+    client: &aws_sdk_dynamodb::Client,
+    tenant_id: &str,
+) -> Result<Vec<String>, Error> {
+    let mut item_ids = Vec::new();
+    let mut exclusive_start_key = None;
+    loop {
+        let output = client
+            .query()
+            .table_name(ITEM_CATALOG_TABLE_NAME)
+            .key_condition_expression("tenant_id = :tenant_id")
+            .expression_attribute_values(":tenant_id", AttributeValue::S(tenant_id.to_string()))
+            .projection_expression("item_id")
+            .set_exclusive_start_key(exclusive_start_key)
+            .send()
+            .await?;
+        item_ids.extend(output.items().iter().filter_map(|item| {
+            item.get("item_id")
+                .and_then(|item_id| item_id.as_s().ok())
+                .cloned()
+        }));
+        exclusive_start_key = output.last_evaluated_key;
+        if exclusive_start_key.is_none() {
+            break;
+        }
+    }
+    Ok(item_ids)
+}
+
+/// 書き込みをバッチで実行し、処理されなかった書き込みは再実行する
+async fn batch_write(
+    client: &aws_sdk_dynamodb::Client,
+    requests: Vec<WriteRequest>,
+) -> Result<(), Error> {
+    // NOTE: 1回のバッチ書き込みで書き込める行の数に上限があるので分割する
+    for requests in requests.chunks(25) {
+        let mut requests = requests.to_vec();
+        while !requests.is_empty() {
+            let output = client
+                .batch_write_item()
+                .request_items(ITEM_CATALOG_TABLE_NAME, requests)
+                .send()
+                .await?;
+            requests = output
+                .unprocessed_items
+                .and_then(|mut items| items.remove(ITEM_CATALOG_TABLE_NAME))
+                .unwrap_or_default();
+        }
+    }
+    Ok(())
+}
+
+/// テナントのイベントから商品カタログのプロジェクションを更新するプロジェクター
+#[derive(Debug, Clone)]
+pub struct ItemCatalogProjector {
+    repository: CommandRepository,
+    store: ItemCatalogStore,
+}
+
+impl ItemCatalogProjector {
+    /// Creates a new [`ItemCatalogProjector`].
+    #[must_use]
+    pub fn new(event_store: EventStore, store: ItemCatalogStore) -> Self {
+        Self {
+            repository: CommandRepository::new(event_store),
+            store,
+        }
+    }
+
+    /// テナントの現在の商品をプロジェクションに反映する
+    ///
+    /// イベントの内容ではなくイベントストアから復元した現在の状態を反映するので、
+    /// 同じイベントを何度反映しても、イベントの順序が入れ替わっても結果は変わらない
+    ///
+    /// # Errors
+    #[instrument(skip(self), err)]
+    pub async fn project(&self, tenant_id: &str) -> Result<(), Error> {
+        let Some(aggregate) = self.repository.get(tenant_id.parse()?).await? else {
+            return Ok(());
+        };
+        let items = aggregate
+            .items()
+            .iter()
+            .map(|item| {
+                kernel::query::Item::new(
+                    item.id().to_string(),
+                    item.name().to_string(),
+                    item.price(),
+                )
+            })
+            .collect();
+        self.store.put(tenant_id, items).await
+    }
+
+    /// 全てのテナントの商品をプロジェクションから削除する
+    ///
+    /// # Errors
+    pub async fn reset(&self) -> Result<(), Error> {
+        self.store.reset().await
+    }
+}
+
+impl Projector<EventPayload> for ItemCatalogProjector {
+    async fn apply(&self, model: &EventStoreModel) -> Result<(), ProjectorError> {
+        self.project(model.aggregate_id()).await
+    }
+
+    /// 集約の現在の状態を反映するので、同じ集約のイベントをまとめて適用する場合は1回だけ反映する
+    async fn apply_batch(&self, models: &[EventStoreModel]) -> Result<(), ProjectorError> {
+        match models.last() {
+            Some(model) => self.apply(model).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn reset(&self) -> Result<(), ProjectorError> {
+        self.reset().await
+    }
+}
+
+/// コマンド操作と同じプロセスでテナントのプロジェクションを更新するプロジェクター
+#[derive(Debug, Clone)]
+pub enum TenantProjector {
+    ItemCatalog(ItemCatalogProjector),
+    Search(SearchProjector),
+}
+
+impl TenantProjector {
+    /// テナントの現在の状態をプロジェクションに反映する
+    ///
+    /// # Errors
+    pub async fn project(&self, tenant_id: &str) -> Result<(), Error> {
+        match self {
+            Self::ItemCatalog(projector) => projector.project(tenant_id).await,
+            Self::Search(projector) => projector.project(tenant_id).await,
+        }
+    }
+}
+
+impl From<ItemCatalogProjector> for TenantProjector {
+    fn from(value: ItemCatalogProjector) -> Self {
+        Self::ItemCatalog(value)
+    }
+}
+
+impl From<SearchProjector> for TenantProjector {
+    fn from(value: SearchProjector) -> Self {
+        Self::Search(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ItemCatalogStore;
+
+    type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    #[tokio::test]
+    async fn test_in_memory_item_catalog_store_put() -> Result<(), Error> {
+        let store = ItemCatalogStore::in_memory();
+        let item =
+            |id: &str, price| kernel::query::Item::new(id.to_string(), id.to_string(), price);
+        store
+            .put("tenant", vec![item("item-2", 200), item("item-1", 100)])
+            .await?;
+        store.put("tenant", vec![item("item-3", 300)]).await?;
+
+        assert_eq!(store.list_items("tenant").await?, vec![item("item-3", 300)]);
+        assert!(store.list_items("other").await?.is_empty());
+
+        store.reset().await?;
+        assert!(store.list_items("tenant").await?.is_empty());
+        Ok(())
+    }
+}
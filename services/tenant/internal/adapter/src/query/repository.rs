@@ -1,70 +1,188 @@
-use kernel::QueryProcessor;
+use event_store::{AnyEventStore, EventStore as _};
+use kernel::query::{ItemFilter, ItemSort, ItemSortKey, Page, PageRequest, QueryKernelError};
+use kernel::{CommandProcessor as _, QueryProcessor};
 use tracing::instrument;
 
-use crate::AGGREGATE_TABLE_NAME;
+use crate::command::{
+    from_aggregate, AggregatePayload, CommandRepository, EventPayload, EventStore,
+};
 
-use super::{AggregateModel, AggregatePayload};
+use super::search::MAX_SEARCH_OFFSET;
+use super::{decode_page_token, into_page, into_tenant, ItemCatalogStore, SearchIndex};
 
 type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
 
 #[derive(Debug, Clone)]
 pub struct QueryRepository {
-    dynamodb: aws_sdk_dynamodb::Client,
+    event_store: AnyEventStore<AggregatePayload, EventPayload>,
+    repository: CommandRepository,
+    catalog: ItemCatalogStore,
+    search_index: SearchIndex,
 }
 
 impl QueryRepository {
     /// Creates a new [`QueryRepository`].
     #[must_use]
-    pub fn new(dynamodb: aws_sdk_dynamodb::Client) -> Self {
-        Self { dynamodb }
+    pub fn new(
+        event_store: EventStore,
+        catalog: ItemCatalogStore,
+        search_index: SearchIndex,
+    ) -> Self {
+        Self {
+            event_store: event_store.0.clone(),
+            repository: CommandRepository::new(event_store),
+            catalog,
+            search_index,
+        }
+    }
+}
+
+/// 並び順の値と商品IDを連結して、文字列の順序が商品の並び順と一致するページのキーにする
+fn sort_key(item: &kernel::query::Item, key: ItemSortKey) -> String {
+    match key {
+        ItemSortKey::Id => item.id().to_string(),
+        // NOTE: 桁数を揃えて文字列の順序を数値の順序と一致させる
+        ItemSortKey::Price => format!("{:010}\0{}", item.price(), item.id()),
+        ItemSortKey::Name => format!("{}\0{}", item.name(), item.id()),
     }
 }
 
+/// ページのキーと商品の組からページのキーを取り出す
+fn page_key((key, _): &(String, kernel::query::Item)) -> &str {
+    key
+}
+
 impl QueryProcessor for QueryRepository {
     #[instrument(skip(self), err, ret)]
-    async fn list_tenants(&self) -> Result<Vec<kernel::query::Tenant>, Error> {
-        let models: Vec<AggregateModel> = serde_dynamo::from_items(
-            self.dynamodb
-                .scan()
-                .table_name(AGGREGATE_TABLE_NAME)
-                .send()
-                .await?
-                .items()
-                .to_vec(),
-        )?;
-        Ok(models.into_iter().map(Into::into).collect())
+    async fn list_tenants(&self, page: PageRequest) -> Result<Page<kernel::query::Tenant>, Error> {
+        let page_token = decode_page_token(&page)?;
+        let (models, next_page_token) = self
+            .event_store
+            .list_aggregates_page(page.page_size(), page_token.as_ref())
+            .await?
+            .into_parts();
+        // NOTE: スナップショットの頻度を下げてもテナント名が遅れないように、スナップショットより後のイベントも適用する
+        let mut tenants = Vec::with_capacity(models.len());
+        for model in models {
+            let aggregate = self.repository.get(model.id().parse()?).await?;
+            tenants.push(into_tenant(aggregate.map_or(model, from_aggregate)));
+        }
+        Ok(into_page(
+            event_store::Page::new(tenants, next_page_token),
+            std::convert::identity,
+        ))
     }
 
     #[instrument(skip(self), err, ret)]
     async fn list_items(
         &self,
         tenant_id: kernel::Id<kernel::Aggregate>,
-    ) -> Result<Option<Vec<kernel::query::Item>>, Error> {
-        use aws_sdk_dynamodb::operation::get_item::GetItemError::ResourceNotFoundException;
-        use aws_sdk_dynamodb::types::AttributeValue;
-
-        let output = match self
-            .dynamodb
-            .get_item()
-            .table_name(AGGREGATE_TABLE_NAME)
-            .key("id", AttributeValue::S(tenant_id.to_string()))
-            .send()
-            .await
+        filter: ItemFilter,
+        sort: ItemSort,
+        page: PageRequest,
+    ) -> Result<Option<Page<kernel::query::Item>>, Error> {
+        let page_token = decode_page_token(&page)?;
+        let items = self.catalog.list_items(&tenant_id.to_string()).await?;
+        // NOTE: 商品カタログには商品のないテナントが存在しないので、集約からテナントの存在を確認する
+        if items.is_empty()
+            && self
+                .event_store
+                .get_aggregate(&tenant_id.to_string())
+                .await?
+                .is_none()
         {
-            Ok(o) => o,
-            Err(e) => match e.into_service_error() {
-                ResourceNotFoundException(_) => return Ok(None),
-                e => return Err(e.into()),
-            },
-        };
-        let Some(item) = output.item else {
             return Ok(None);
+        }
+        let mut rows: Vec<_> = items
+            .into_iter()
+            .filter(|item| filter.matches(item))
+            .map(|item| (sort_key(&item, sort.key()), item))
+            .collect();
+        rows.sort_by(|(a, _), (b, _)| a.cmp(b));
+        let rows = if sort.descending() {
+            rows.reverse();
+            event_store::Page::from_sorted_desc(
+                rows,
+                page.page_size(),
+                page_token.as_ref(),
+                page_key,
+            )
+        } else {
+            event_store::Page::from_sorted(rows, page.page_size(), page_token.as_ref(), page_key)
         };
-        let model: AggregateModel = serde_dynamo::from_item(item)?;
-        match model.payload() {
-            AggregatePayload::V1 { items, .. } => {
-                Ok(Some(items.iter().cloned().map(Into::into).collect()))
-            }
+        Ok(Some(into_page(rows, |(_, item)| item)))
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn search(
+        &self,
+        query: String,
+        page: PageRequest,
+    ) -> Result<Page<kernel::query::SearchHit>, Error> {
+        // NOTE: 検索結果のページのトークンには読み飛ばす検索結果の数が入っている
+        let offset = decode_page_token(&page)?
+            .map(|token| token.key().parse())
+            .transpose()
+            .map_err(|_| QueryKernelError::InvalidPageToken)?
+            .unwrap_or_default();
+        if offset > MAX_SEARCH_OFFSET {
+            return Err(QueryKernelError::InvalidPageToken.into());
         }
+        let hits = self.search_index.search(&query, page.page_size(), offset)?;
+        Ok(into_page(hits, std::convert::identity))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use kernel::query::{PageRequest, Tenant};
+    use kernel::{Aggregate, Command, CommandProcessor as _, Id, Item, QueryProcessor as _};
+
+    use super::QueryRepository;
+    use crate::command::{CommandRepository, EventStore, SnapshotPolicy};
+    use crate::query::{ItemCatalogStore, SearchIndex};
+
+    type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    #[tokio::test]
+    async fn test_list_tenants_replay_events_after_snapshot() -> Result<(), Error> {
+        let event_store = EventStore::in_memory();
+        let repository = CommandRepository::new(event_store.clone())
+            .with_snapshot_policy(SnapshotPolicy::OnDemand);
+        let query = QueryRepository::new(
+            event_store,
+            ItemCatalogStore::in_memory(),
+            SearchIndex::in_memory()?,
+        );
+
+        let mut aggregate = Aggregate::new(Id::generate(), String::new(), Vec::new(), 0);
+        let events = aggregate.apply_command(
+            Command::Create {
+                name: "山田商店".to_string(),
+            },
+            None,
+        )?;
+        repository
+            .create(aggregate.clone(), events[0].clone(), None)
+            .await?;
+        let events = aggregate.apply_command(
+            Command::AddItems {
+                items: vec![Item::new(Id::generate(), "じゃがいも".to_string(), 100)],
+            },
+            None,
+        )?;
+        repository.update(aggregate.clone(), events, None).await?;
+
+        let page = query.list_tenants(PageRequest::new(10, None)).await?;
+        assert_eq!(
+            page.items(),
+            [Tenant::new(
+                aggregate.id().to_string(),
+                "山田商店".to_string(),
+                aggregate.version(),
+            )],
+            "スナップショットより後のイベントも適用したバージョンを返す"
+        );
+        Ok(())
     }
 }
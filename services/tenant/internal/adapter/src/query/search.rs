@@ -0,0 +1,661 @@
+use std::path::Path;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+
+use event_store::PageToken;
+use kernel::CommandProcessor as _;
+use projection::{Projector, ProjectorError};
+use tantivy::collector::TopDocs;
+use tantivy::directory::MmapDirectory;
+use tantivy::query::{BooleanQuery, Occur, Query, TermQuery};
+use tantivy::schema::{
+    Field, IndexRecordOption, Schema, TextFieldIndexing, TextOptions, Value as _, STORED, STRING,
+};
+use tantivy::tokenizer::{LowerCaser, NgramTokenizer, TextAnalyzer};
+use tantivy::{
+    doc, Index, IndexReader, IndexWriter, ReloadPolicy, TantivyDocument, TantivyError, Term,
+};
+use tokio::sync::oneshot;
+use tracing::instrument;
+
+use crate::command::{CommandRepository, EventPayload, EventStore, EventStoreModel};
+
+type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// 名前を分かち書きせずに検索するための N-gram のトークナイザーの名前
+const NGRAM_TOKENIZER: &str = "ngram";
+/// インデックスに書き込むときに使うメモリの上限
+const WRITER_MEMORY_BUDGET: usize = 15_000_000;
+/// 1回のコミットにまとめる変更の数の上限
+const MAX_BATCH_CHANGES: usize = 1_000;
+/// 読み飛ばせる検索結果の数の上限
+///
+/// 読み飛ばす検索結果も全て集計するので、深いページほど検索が重くなる
+pub(crate) const MAX_SEARCH_OFFSET: usize = 1_000;
+
+/// インデックスのフィールド
+#[derive(Debug, Clone, Copy)]
+struct Fields {
+    tenant_id: Field,
+    tenant_name: Field,
+    item_id: Field,
+    /// テナントのドキュメントではテナント名、商品のドキュメントでは商品名
+    name: Field,
+    price: Field,
+}
+
+impl Fields {
+    fn schema() -> (Schema, Self) {
+        let mut builder = Schema::builder();
+        // NOTE: 日本語は単語の区切りがないので、形態素解析の辞書を持たずに検索できる N-gram で索引を作る
+        let name = TextOptions::default().set_stored().set_indexing_options(
+            TextFieldIndexing::default()
+                .set_tokenizer(NGRAM_TOKENIZER)
+                .set_index_option(IndexRecordOption::WithFreqs),
+        );
+        let fields = Self {
+            tenant_id: builder.add_text_field("tenant_id", STRING | STORED),
+            tenant_name: builder.add_text_field("tenant_name", STORED),
+            item_id: builder.add_text_field("item_id", STRING | STORED),
+            name: builder.add_text_field("name", name),
+            price: builder.add_u64_field("price", STORED),
+        };
+        (builder.build(), fields)
+    }
+}
+
+/// インデックスに適用する変更
+#[derive(Debug)]
+enum Change {
+    /// テナントのドキュメントを現在のテナント名と商品に置き換える
+    Put {
+        tenant_id: String,
+        tenant_name: String,
+        items: Vec<kernel::query::Item>,
+    },
+    /// 全てのドキュメントを削除する
+    Reset,
+}
+
+impl Change {
+    fn apply(&self, writer: &IndexWriter, fields: Fields) -> Result<(), TantivyError> {
+        match self {
+            Self::Put {
+                tenant_id,
+                tenant_name,
+                items,
+            } => {
+                writer.delete_term(Term::from_field_text(fields.tenant_id, tenant_id));
+                writer.add_document(doc!(
+                    fields.tenant_id => tenant_id.as_str(),
+                    fields.tenant_name => tenant_name.as_str(),
+                    fields.name => tenant_name.as_str(),
+                ))?;
+                for item in items {
+                    writer.add_document(doc!(
+                        fields.tenant_id => tenant_id.as_str(),
+                        fields.tenant_name => tenant_name.as_str(),
+                        fields.item_id => item.id(),
+                        fields.name => item.name(),
+                        fields.price => u64::from(item.price()),
+                    ))?;
+                }
+            }
+            Self::Reset => {
+                writer.delete_all_documents()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// インデックスを更新するスレッドに送る変更と、コミットした結果を返すチャネル
+struct WriteRequest {
+    change: Change,
+    done: oneshot::Sender<Result<(), TantivyError>>,
+}
+
+/// 届いた変更をまとめて適用して1回だけコミットすることを、送信側が全て破棄されるまで繰り返す
+///
+/// まとめた変更のどれかが失敗した場合は全ての変更を取り消して、まとめた全ての送信元に失敗を返す
+fn write_batches(
+    mut writer: IndexWriter,
+    reader: &IndexReader,
+    fields: Fields,
+    requests: &Receiver<WriteRequest>,
+) {
+    while let Ok(request) = requests.recv() {
+        let mut batch = vec![request];
+        batch.extend(requests.try_iter().take(MAX_BATCH_CHANGES - 1));
+        let result = batch
+            .iter()
+            .try_for_each(|request| request.change.apply(&writer, fields))
+            .and_then(|()| writer.commit())
+            // NOTE: 更新したプロセスではすぐに検索結果に反映する
+            .and_then(|_| reader.reload());
+        if let Err(e) = &result {
+            tracing::error!("failed to commit search index: {e}");
+            if let Err(e) = writer.rollback() {
+                tracing::error!("failed to rollback search index: {e}");
+            }
+        }
+        for request in batch {
+            let _ = request.done.send(result.clone());
+        }
+    }
+}
+
+/// テナント名と商品名から全てのテナントを横断して検索するための転置インデックス
+///
+/// テナントごとにテナント名のドキュメントと商品ごとのドキュメントを保存する
+#[derive(Clone)]
+pub struct SearchIndex {
+    index: Index,
+    reader: IndexReader,
+    fields: Fields,
+    /// インデックスを更新するスレッドへの送信側
+    ///
+    /// インデックスを更新するときに作成する。読み込むだけのプロセスではインデックスのロックを取らない
+    writer: Arc<Mutex<Option<Sender<WriteRequest>>>>,
+}
+
+impl std::fmt::Debug for SearchIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SearchIndex")
+            .field("index", &self.index)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SearchIndex {
+    /// プロセスのメモリ上にインデックスを作成する
+    ///
+    /// # Errors
+    pub fn in_memory() -> Result<Self, Error> {
+        let (schema, fields) = Fields::schema();
+        Self::new(Index::create_in_ram(schema), fields)
+    }
+
+    /// ディレクトリのインデックスを開く。インデックスがない場合は作成する
+    ///
+    /// 複数のプロセスで同じディレクトリを開く場合、インデックスを更新できるのは1つのプロセスだけ。
+    /// 他のプロセスがコミットした変更はインデックスのメタデータの変更を監視して読み込み直すので、
+    /// 更新するプロセスと検索するプロセスは同じファイルシステムのディレクトリを開く必要がある
+    ///
+    /// # Errors
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, Error> {
+        std::fs::create_dir_all(path.as_ref())?;
+        let (schema, fields) = Fields::schema();
+        Self::new(
+            Index::open_or_create(MmapDirectory::open(path)?, schema)?,
+            fields,
+        )
+    }
+
+    fn new(index: Index, fields: Fields) -> Result<Self, Error> {
+        index.tokenizers().register(
+            NGRAM_TOKENIZER,
+            TextAnalyzer::builder(NgramTokenizer::all_ngrams(1, 2)?)
+                .filter(LowerCaser)
+                .build(),
+        );
+        let reader = index
+            .reader_builder()
+            .reload_policy(ReloadPolicy::OnCommitWithDelay)
+            .try_into()?;
+        Ok(Self {
+            index,
+            reader,
+            fields,
+            writer: Arc::default(),
+        })
+    }
+
+    /// インデックスを更新するスレッドに変更を送り、変更をコミットするまで待つ
+    ///
+    /// コミットはファイルへの書き込みを待つので、非同期のタスクを止めないように専用のスレッドで実行する。
+    /// 同時に届いた変更はまとめてコミットする
+    async fn write(&self, change: Change) -> Result<(), Error> {
+        let (done, result) = oneshot::channel();
+        self.sender()?
+            .send(WriteRequest { change, done })
+            .map_err(|_| "search index writer is stopped")?;
+        Ok(result
+            .await
+            .map_err(|_| "search index writer is stopped")??)
+    }
+
+    /// インデックスを更新するスレッドへの送信側を取得する。スレッドがない場合は作成する
+    fn sender(&self) -> Result<Sender<WriteRequest>, Error> {
+        let mut sender = self.writer.lock().map_err(|e| e.to_string())?;
+        if let Some(sender) = sender.as_ref() {
+            return Ok(sender.clone());
+        }
+        let writer = self
+            .index
+            .writer_with_num_threads(1, WRITER_MEMORY_BUDGET)?;
+        let (tx, rx) = mpsc::channel();
+        let reader = self.reader.clone();
+        let fields = self.fields;
+        std::thread::Builder::new()
+            .name("search-index-writer".to_string())
+            .spawn(move || write_batches(writer, &reader, fields, &rx))?;
+        Ok(sender.insert(tx).clone())
+    }
+
+    /// テナントのドキュメントを現在のテナント名と商品に置き換える
+    #[instrument(skip(self), err)]
+    async fn put(
+        &self,
+        tenant_id: &str,
+        tenant_name: &str,
+        items: Vec<kernel::query::Item>,
+    ) -> Result<(), Error> {
+        self.write(Change::Put {
+            tenant_id: tenant_id.to_string(),
+            tenant_name: tenant_name.to_string(),
+            items,
+        })
+        .await
+    }
+
+    /// 全てのドキュメントを削除する
+    ///
+    /// # Errors
+    #[instrument(skip(self), err)]
+    pub async fn reset(&self) -> Result<(), Error> {
+        self.write(Change::Reset).await
+    }
+
+    /// 検索語の全ての N-gram を含むドキュメントを関連度の高い順に1ページ取得する
+    ///
+    /// 次のページのトークンには読み飛ばすドキュメントの数を入れる。
+    /// 読み飛ばす数が [`MAX_SEARCH_OFFSET`] を超える場合は次のページのトークンを返さない
+    #[instrument(skip(self), err)]
+    pub(crate) fn search(
+        &self,
+        query: &str,
+        page_size: usize,
+        offset: usize,
+    ) -> Result<event_store::Page<kernel::query::SearchHit>, Error> {
+        let Some(query) = self.query(query)? else {
+            return Ok(event_store::Page::new(Vec::new(), None));
+        };
+        let searcher = self.reader.searcher();
+        let docs = searcher.search(
+            &query,
+            &TopDocs::with_limit(page_size + 1).and_offset(offset),
+        )?;
+        let next_offset = offset + page_size;
+        let next_page_token = (docs.len() > page_size && next_offset <= MAX_SEARCH_OFFSET)
+            .then(|| PageToken::new(next_offset.to_string()));
+        let hits = docs
+            .into_iter()
+            .take(page_size)
+            .map(|(score, address)| Ok(self.to_hit(&searcher.doc(address)?, score)))
+            .collect::<Result<_, Error>>()?;
+        Ok(event_store::Page::new(hits, next_page_token))
+    }
+
+    /// 検索語を空白で区切り、全ての語の N-gram を含むドキュメントに一致するクエリを作成する
+    ///
+    /// 利用者の入力をクエリの構文として解釈しないように、クエリパーサーは使わない
+    fn query(&self, query: &str) -> Result<Option<BooleanQuery>, Error> {
+        let mut analyzer = self.index.tokenizer_for_field(self.fields.name)?;
+        let mut terms = Vec::new();
+        for word in query.split_whitespace() {
+            let mut stream = analyzer.token_stream(word);
+            while let Some(token) = stream.next() {
+                terms.push(Term::from_field_text(self.fields.name, &token.text));
+            }
+        }
+        if terms.is_empty() {
+            return Ok(None);
+        }
+        terms.sort();
+        terms.dedup();
+        Ok(Some(BooleanQuery::new(
+            terms
+                .into_iter()
+                .map(|term| {
+                    let query: Box<dyn Query> =
+                        Box::new(TermQuery::new(term, IndexRecordOption::WithFreqs));
+                    (Occur::Must, query)
+                })
+                .collect(),
+        )))
+    }
+
+    /// ドキュメントから検索結果に変換する
+    fn to_hit(&self, doc: &TantivyDocument, score: f32) -> kernel::query::SearchHit {
+        let text = |field| {
+            doc.get_first(field)
+                .and_then(|value| value.as_str())
+                .map(ToString::to_string)
+        };
+        let item = text(self.fields.item_id).map(|item_id| {
+            let price = doc
+                .get_first(self.fields.price)
+                .and_then(|value| value.as_u64())
+                .and_then(|price| price.try_into().ok())
+                .unwrap_or_default();
+            kernel::query::Item::new(item_id, text(self.fields.name).unwrap_or_default(), price)
+        });
+        kernel::query::SearchHit::new(
+            text(self.fields.tenant_id).unwrap_or_default(),
+            text(self.fields.tenant_name).unwrap_or_default(),
+            item,
+            score,
+        )
+    }
+}
+
+/// テナントのイベントから検索用のインデックスを更新するプロジェクター
+#[derive(Debug, Clone)]
+pub struct SearchProjector {
+    repository: CommandRepository,
+    index: SearchIndex,
+}
+
+impl SearchProjector {
+    /// Creates a new [`SearchProjector`].
+    #[must_use]
+    pub fn new(event_store: EventStore, index: SearchIndex) -> Self {
+        Self {
+            repository: CommandRepository::new(event_store),
+            index,
+        }
+    }
+
+    /// テナントの現在のテナント名と商品をインデックスに反映する
+    ///
+    /// イベントストアから復元した現在の状態を反映するので、同じイベントを何度反映しても結果は変わらない
+    ///
+    /// # Errors
+    #[instrument(skip(self), err)]
+    pub async fn project(&self, tenant_id: &str) -> Result<(), Error> {
+        let Some(aggregate) = self.repository.get(tenant_id.parse()?).await? else {
+            return Ok(());
+        };
+        let items = aggregate
+            .items()
+            .iter()
+            .map(|item| {
+                kernel::query::Item::new(
+                    item.id().to_string(),
+                    item.name().to_string(),
+                    item.price(),
+                )
+            })
+            .collect();
+        self.index.put(tenant_id, aggregate.name(), items).await
+    }
+
+    /// 全てのドキュメントをインデックスから削除する
+    ///
+    /// # Errors
+    pub async fn reset(&self) -> Result<(), Error> {
+        self.index.reset().await
+    }
+}
+
+impl Projector<EventPayload> for SearchProjector {
+    async fn apply(&self, model: &EventStoreModel) -> Result<(), ProjectorError> {
+        self.project(model.aggregate_id()).await
+    }
+
+    /// 集約の現在の状態を反映するので、同じ集約のイベントをまとめて適用する場合は1回だけ反映する
+    async fn apply_batch(&self, models: &[EventStoreModel]) -> Result<(), ProjectorError> {
+        match models.last() {
+            Some(model) => self.apply(model).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn reset(&self) -> Result<(), ProjectorError> {
+        self.reset().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use event_store::PageToken;
+    use kernel::{Aggregate, Command, CommandProcessor as _, Id, Item};
+
+    use super::{SearchIndex, SearchProjector};
+    use crate::command::{CommandRepository, EventStore};
+
+    type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    fn item(id: &str, name: &str) -> kernel::query::Item {
+        kernel::query::Item::new(id.to_string(), name.to_string(), 100)
+    }
+
+    /// 検索結果の商品ID、商品でない場合はテナントIDを昇順に並べる
+    fn ids(index: &SearchIndex, query: &str) -> Result<Vec<String>, Error> {
+        let mut ids: Vec<_> = index
+            .search(query, 10, 0)?
+            .items()
+            .iter()
+            .map(|hit| {
+                hit.item()
+                    .map_or(hit.tenant_id(), kernel::query::Item::id)
+                    .to_string()
+            })
+            .collect();
+        ids.sort();
+        Ok(ids)
+    }
+
+    #[tokio::test]
+    async fn test_search_index_search() -> Result<(), Error> {
+        let index = SearchIndex::in_memory()?;
+        index
+            .put(
+                "tenant-1",
+                "山田商店",
+                vec![
+                    item("item-1", "北海道産じゃがいも"),
+                    item("item-2", "Green Tea"),
+                ],
+            )
+            .await?;
+        index
+            .put(
+                "tenant-2",
+                "田中書店",
+                vec![item("item-3", "じゃがいも料理の本")],
+            )
+            .await?;
+
+        let ids = |query| ids(&index, query);
+        assert_eq!(ids("じゃがいも")?, vec!["item-1", "item-3"]);
+        assert_eq!(ids("green")?, vec!["item-2"]);
+        assert_eq!(ids("田 店")?, vec!["tenant-1", "tenant-2"]);
+        assert_eq!(ids("書店 本")?, Vec::<String>::new());
+        assert!(ids(" ")?.is_empty());
+
+        let first = index.search("じゃがいも", 1, 0)?;
+        assert_eq!(first.items().len(), 1);
+        assert_eq!(first.next_page_token(), Some(&PageToken::new("1")));
+        let second = index.search("じゃがいも", 1, 1)?;
+        assert_eq!(second.items().len(), 1);
+        assert_eq!(second.next_page_token(), None);
+        assert_ne!(first.items(), second.items());
+
+        // NOTE: 商品を置き換えると以前の商品は検索されない
+        index.put("tenant-1", "山田商店", Vec::new()).await?;
+        assert_eq!(ids("じゃがいも")?, vec!["item-3"]);
+
+        index.reset().await?;
+        assert!(ids("店")?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_search_index_search_partial_match() -> Result<(), Error> {
+        let index = SearchIndex::in_memory()?;
+        index
+            .put(
+                "tenant-1",
+                "山田商店",
+                vec![
+                    item("item-1", "北海道産じゃがいも"),
+                    item("item-2", "鹿児島県産さつまいも"),
+                ],
+            )
+            .await?;
+
+        assert_eq!(ids(&index, "じゃが")?, vec!["item-1"]);
+        assert_eq!(ids(&index, "海道")?, vec!["item-1"]);
+        assert_eq!(ids(&index, "いも")?, vec!["item-1", "item-2"]);
+        assert_eq!(ids(&index, "産 いも")?, vec!["item-1", "item-2"]);
+        assert_eq!(ids(&index, "山田")?, vec!["tenant-1"]);
+        assert!(ids(&index, "じゃがバター")?.is_empty());
+
+        // NOTE: 商品名に一致した場合は商品とテナントを返す
+        let page = index.search("じゃがいも", 10, 0)?;
+        let [hit] = page.items() else {
+            panic!("expected a hit: {:?}", page.items());
+        };
+        assert_eq!(hit.tenant_id(), "tenant-1");
+        assert_eq!(hit.tenant_name(), "山田商店");
+        assert_eq!(hit.item(), Some(&item("item-1", "北海道産じゃがいも")));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_search_index_search_pages() -> Result<(), Error> {
+        let index = SearchIndex::in_memory()?;
+        let items: Vec<_> = (1..=5)
+            .map(|i| item(&format!("item-{i}"), &format!("りんご{i}")))
+            .collect();
+        index.put("tenant-1", "山田商店", items).await?;
+
+        let mut ids = Vec::new();
+        let mut tokens = Vec::new();
+        let mut offset = 0;
+        loop {
+            let page = index.search("りんご", 2, offset)?;
+            ids.extend(
+                page.items()
+                    .iter()
+                    .filter_map(|hit| hit.item().map(|item| item.id().to_string())),
+            );
+            let Some(token) = page.next_page_token() else {
+                break;
+            };
+            tokens.push(token.clone());
+            offset = token.key().parse()?;
+        }
+        assert_eq!(tokens, vec![PageToken::new("2"), PageToken::new("4")]);
+        ids.sort();
+        assert_eq!(
+            ids,
+            vec!["item-1", "item-2", "item-3", "item-4", "item-5"],
+            "ページの境界で検索結果が重複したり抜けたりしない"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_search_index_put_concurrently() -> Result<(), Error> {
+        let index = SearchIndex::in_memory()?;
+        let tasks: Vec<_> = (1..=20)
+            .map(|i| {
+                let index = index.clone();
+                tokio::spawn(async move {
+                    index
+                        .put(
+                            &format!("tenant-{i}"),
+                            "山田商店",
+                            vec![item(&format!("item-{i}"), "じゃがいも")],
+                        )
+                        .await
+                })
+            })
+            .collect();
+        for task in tasks {
+            task.await??;
+        }
+        assert_eq!(
+            index.search("じゃがいも", 100, 0)?.items().len(),
+            20,
+            "同時に届いた変更をまとめてコミットしても全て反映する"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_search_projector_remove_items() -> Result<(), Error> {
+        let index = SearchIndex::in_memory()?;
+        let event_store = EventStore::in_memory();
+        let repository = CommandRepository::new(event_store.clone())
+            .with_projector(SearchProjector::new(event_store, index.clone()));
+
+        let mut aggregate = Aggregate::new(Id::generate(), String::new(), Vec::new(), 0);
+        let events = aggregate.apply_command(
+            Command::Create {
+                name: "山田商店".to_string(),
+            },
+            None,
+        )?;
+        repository
+            .create(aggregate.clone(), events[0].clone(), None)
+            .await?;
+        let potato = Item::new(Id::generate(), "じゃがいも".to_string(), 100);
+        let onion = Item::new(Id::generate(), "たまねぎ".to_string(), 100);
+        let events = aggregate.apply_command(
+            Command::AddItems {
+                items: vec![potato.clone(), onion.clone()],
+            },
+            None,
+        )?;
+        repository.update(aggregate.clone(), events, None).await?;
+        assert_eq!(ids(&index, "じゃがいも")?, vec![potato.id().to_string()]);
+
+        let events = aggregate.apply_command(
+            Command::RemoveItems {
+                item_ids: vec![potato.id().clone()],
+            },
+            None,
+        )?;
+        repository.update(aggregate, events, None).await?;
+        assert!(
+            ids(&index, "じゃがいも")?.is_empty(),
+            "削除した商品は検索されない"
+        );
+        assert_eq!(ids(&index, "たまねぎ")?, vec![onion.id().to_string()]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_search_index_reload_on_commit() -> Result<(), Error> {
+        let dir = std::env::temp_dir().join(format!("search-index-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        // NOTE: プロジェクションエンジンとサーバーのように、同じディレクトリを更新するインデックスと検索するインデックスを別々に開く
+        let writer = SearchIndex::open(&dir)?;
+        let reader = SearchIndex::open(&dir)?;
+
+        writer
+            .put("tenant-1", "山田商店", vec![item("item-1", "じゃがいも")])
+            .await?;
+        let mut found = Vec::new();
+        for _ in 0..50 {
+            found = ids(&reader, "じゃがいも")?;
+            if !found.is_empty() {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(100));
+        }
+        assert_eq!(
+            found,
+            vec!["item-1"],
+            "他のインデックスのコミットを読み込み直す"
+        );
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
@@ -5,6 +5,7 @@ pub mod model;
 /// ユースケースを定義したモジュール
 pub mod usecase;
 
+pub use command_retry::RetryPolicy;
 pub use error::CommandUseCaseError;
 pub use model::{Item, Tenant};
 pub use usecase::{CommandUseCase, CommandUseCaseExt};
@@ -9,6 +9,10 @@ pub enum CommandUseCaseError {
     NotFound,
     #[error("Cannot update aggregate")]
     Overflowed,
+    #[error("Aggregate was updated concurrently")]
+    ConcurrencyConflict,
+    #[error("Aggregate version mismatch: expected {expected}, actual {actual}")]
+    VersionMismatch { expected: u64, actual: u64 },
     #[error(transparent)]
     KernelError(#[from] CommandKernelError),
     #[error(transparent)]
@@ -1,41 +1,119 @@
 use std::future::Future;
 
-use kernel::{Aggregate, Command, CommandProcessor, Id};
+use kernel::{Aggregate, Command, CommandProcessor, Id, Idempotency};
 use tracing::instrument;
 
-use super::{CommandUseCaseError, Item, Tenant};
+use super::{CommandUseCaseError, Item, RetryPolicy, Tenant};
 
 type Result<T> = core::result::Result<T, CommandUseCaseError>;
 
+/// 集約の更新が競合した結果であるか
+fn is_conflict<T>(result: &Result<T>) -> bool {
+    matches!(result, Err(CommandUseCaseError::ConcurrencyConflict))
+}
+
 /// ユースケースのインターフェイス
 pub trait CommandUseCaseExt {
-    /// テナントを作成する
-    fn create(&self, tenant: Tenant) -> impl Future<Output = Result<Id<Aggregate>>> + Send;
+    /// テナントを作成し、テナントIDと集約のバージョンを返す
+    ///
+    /// `idempotency_key` を指定した場合は同じ冪等キーで作成済みのテナントIDと集約のバージョンを返す
+    fn create(
+        &self,
+        tenant: Tenant,
+        idempotency_key: Option<String>,
+    ) -> impl Future<Output = Result<(Id<Aggregate>, u64)>> + Send;
 
-    /// テナントに商品を追加する
+    /// テナントに商品を追加し、商品IDと集約のバージョンを返す
+    ///
+    /// `expected_version` を指定した場合は集約がそのバージョンのときだけ商品を追加する。
+    /// `idempotency_key` を指定した場合は同じ冪等キーで追加済みの商品IDと集約のバージョンを返す
     fn add_items(
         &self,
         tenant_id: Id<Aggregate>,
         items: Vec<Item>,
-    ) -> impl Future<Output = Result<Vec<Id<kernel::Item>>>> + Send;
+        expected_version: Option<u64>,
+        idempotency_key: Option<String>,
+    ) -> impl Future<Output = Result<(Vec<Id<kernel::Item>>, u64)>> + Send;
 
-    /// テナントから商品を削除する
+    /// テナントから商品を削除し、集約のバージョンを返す
+    ///
+    /// `expected_version` を指定した場合は集約がそのバージョンのときだけ商品を削除する。
+    /// `idempotency_key` を指定した場合は同じ冪等キーで削除した後の集約のバージョンを返す
     fn remove_items(
         &self,
         tenant_id: Id<Aggregate>,
         item_ids: Vec<Id<kernel::Item>>,
-    ) -> impl Future<Output = Result<()>> + Send;
+        expected_version: Option<u64>,
+        idempotency_key: Option<String>,
+    ) -> impl Future<Output = Result<u64>> + Send;
 }
 
 /// ユースケースの実態
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct CommandUseCase<P: CommandProcessor> {
     processor: P,
+    retry_policy: RetryPolicy,
 }
 
 impl<P: CommandProcessor> CommandUseCase<P> {
     pub fn new(processor: P) -> Self {
-        Self { processor }
+        Self {
+            processor,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// 集約の更新が競合した場合にコマンドを再実行する方針を設定する
+    #[must_use]
+    pub fn with_retry_policy(self, retry_policy: RetryPolicy) -> Self {
+        Self {
+            retry_policy,
+            ..self
+        }
+    }
+
+    /// 集約を作成し、冪等キーが競合した場合は [`CommandUseCaseError::ConcurrencyConflict`] を返す
+    async fn create_aggregate(
+        &self,
+        aggregate: Aggregate,
+        event: kernel::Event,
+        idempotency: Option<Idempotency>,
+    ) -> Result<()> {
+        self.processor
+            .create(aggregate, event, idempotency)
+            .await
+            .map_err(|e| match e {
+                kernel::CommandKernelError::ConcurrencyConflict => {
+                    CommandUseCaseError::ConcurrencyConflict
+                }
+                e => CommandUseCaseError::KernelError(e),
+            })
+    }
+
+    /// 集約を更新し、更新が競合した場合は [`CommandUseCaseError::ConcurrencyConflict`] を返す
+    async fn update(
+        &self,
+        aggregate: Aggregate,
+        events: Vec<kernel::Event>,
+        idempotency: Option<Idempotency>,
+    ) -> Result<()> {
+        self.processor
+            .update(aggregate, events, idempotency)
+            .await
+            .map_err(|e| match e {
+                kernel::CommandKernelError::ConcurrencyConflict => {
+                    CommandUseCaseError::ConcurrencyConflict
+                }
+                e => CommandUseCaseError::KernelError(e),
+            })
+    }
+
+    /// 冪等キーを指定して実行したコマンドの結果を取得する
+    async fn get_idempotency(&self, key: Option<&String>) -> Result<Option<Idempotency>> {
+        let Some(key) = key else {
+            return Ok(None);
+        };
+        Ok(self.processor.get_idempotency(key.clone()).await?)
     }
 }
 
@@ -44,27 +122,52 @@ where
     P: CommandProcessor + Send + Sync + 'static,
 {
     #[instrument(skip(self), err, ret)]
-    async fn create(&self, tenant: Tenant) -> Result<Id<Aggregate>> {
-        let mut aggregate = Aggregate::default();
-        let aggregate_id = aggregate.id().clone();
-        let events = aggregate
-            .apply_command(Command::Create { name: tenant.name })
-            .map_err(|e| match e {
-                kernel::CommandKernelError::InvalidTenantName => {
-                    CommandUseCaseError::InvalidArgument
+    async fn create(
+        &self,
+        tenant: Tenant,
+        idempotency_key: Option<String>,
+    ) -> Result<(Id<Aggregate>, u64)> {
+        let idempotency_key = idempotency_key.map(|key| format!("create:{key}"));
+        // NOTE: 同じ冪等キーのコマンドと競合した場合は、再実行して先に作成されたテナントを返す
+        self.retry_policy
+            .run("create", is_conflict, || {
+                let tenant = tenant.clone();
+                let idempotency_key = idempotency_key.clone();
+                async move {
+                    if let Some(idempotency) =
+                        self.get_idempotency(idempotency_key.as_ref()).await?
+                    {
+                        return Ok((idempotency.aggregate_id().clone(), idempotency.version()));
+                    }
+                    let mut aggregate = Aggregate::default();
+                    let aggregate_id = aggregate.id().clone();
+                    let events = aggregate
+                        .apply_command(Command::Create { name: tenant.name }, None)
+                        .map_err(|e| match e {
+                            kernel::CommandKernelError::InvalidTenantName => {
+                                CommandUseCaseError::InvalidArgument
+                            }
+                            e => CommandUseCaseError::KernelError(e),
+                        })?;
+                    let version = aggregate.version();
+                    let idempotency = idempotency_key.map(|key| {
+                        Idempotency::new(key, aggregate_id.clone(), version, Vec::new())
+                    });
+                    self.create_aggregate(
+                        aggregate,
+                        events
+                            .first()
+                            .ok_or_else(|| {
+                                CommandUseCaseError::Unknown("event not returned".into())
+                            })?
+                            .clone(),
+                        idempotency,
+                    )
+                    .await?;
+                    Ok((aggregate_id, version))
                 }
-                e => CommandUseCaseError::KernelError(e),
-            })?;
-        self.processor
-            .create(
-                aggregate,
-                events
-                    .first()
-                    .ok_or_else(|| CommandUseCaseError::Unknown("event not returned".into()))?
-                    .clone(),
-            )
-            .await?;
-        Ok(aggregate_id)
+            })
+            .await
     }
 
     #[instrument(skip(self), err, ret)]
@@ -72,57 +175,128 @@ where
         &self,
         id: Id<Aggregate>,
         items: Vec<Item>,
-    ) -> Result<Vec<Id<kernel::Item>>> {
+        expected_version: Option<u64>,
+        idempotency_key: Option<String>,
+    ) -> Result<(Vec<Id<kernel::Item>>, u64)> {
         let items: Vec<kernel::Item> = items.into_iter().map(Into::into).collect();
         let item_ids: Vec<_> = items.iter().map(|x| x.id().clone()).collect();
-        let mut aggregate = self
-            .processor
-            .get(id)
-            .await?
-            .ok_or_else(|| CommandUseCaseError::NotFound)?;
-        let events = aggregate
-            .apply_command(Command::AddItems { items })
-            .map_err(|e| match e {
-                kernel::CommandKernelError::AggregateVersionOverflowed => {
-                    CommandUseCaseError::Overflowed
+        let idempotency_key = idempotency_key.map(|key| format!("add_items:{id}:{key}"));
+        // NOTE: 再実行しても同じ商品IDになるように、商品を作成してから集約を取得し直す
+        self.retry_policy
+            .run("add_items", is_conflict, || {
+                let id = id.clone();
+                let items = items.clone();
+                let item_ids = item_ids.clone();
+                let idempotency_key = idempotency_key.clone();
+                async move {
+                    if let Some(idempotency) =
+                        self.get_idempotency(idempotency_key.as_ref()).await?
+                    {
+                        return Ok((idempotency.item_ids().to_vec(), idempotency.version()));
+                    }
+                    let mut aggregate = self
+                        .processor
+                        .get(id)
+                        .await?
+                        .ok_or_else(|| CommandUseCaseError::NotFound)?;
+                    let events = aggregate
+                        .apply_command(Command::AddItems { items }, expected_version)
+                        .map_err(|e| match e {
+                            kernel::CommandKernelError::AggregateVersionOverflowed => {
+                                CommandUseCaseError::Overflowed
+                            }
+                            kernel::CommandKernelError::InvalidItemName
+                            | kernel::CommandKernelError::EmptyItems => {
+                                CommandUseCaseError::InvalidArgument
+                            }
+                            kernel::CommandKernelError::VersionMismatch { expected, actual } => {
+                                CommandUseCaseError::VersionMismatch { expected, actual }
+                            }
+                            kernel::CommandKernelError::Unknown(e) => {
+                                CommandUseCaseError::Unknown(e)
+                            }
+                            _ => CommandUseCaseError::KernelError(e),
+                        })?;
+                    let version = aggregate.version();
+                    let idempotency = idempotency_key.map(|key| {
+                        Idempotency::new(key, aggregate.id().clone(), version, item_ids.clone())
+                    });
+                    self.update(aggregate, events, idempotency).await?;
+                    Ok((item_ids, version))
                 }
-                kernel::CommandKernelError::InvalidItemName
-                | kernel::CommandKernelError::EmptyItems => CommandUseCaseError::InvalidArgument,
-                kernel::CommandKernelError::Unknown(e) => CommandUseCaseError::Unknown(e),
-                _ => CommandUseCaseError::KernelError(e),
-            })?;
-        self.processor.update(aggregate, events).await?;
-        Ok(item_ids)
+            })
+            .await
     }
 
     #[instrument(skip(self), err, ret)]
-    async fn remove_items(&self, id: Id<Aggregate>, item_ids: Vec<Id<kernel::Item>>) -> Result<()> {
-        let mut aggregate = self
-            .processor
-            .get(id)
-            .await?
-            .ok_or_else(|| CommandUseCaseError::NotFound)?;
-        // テナントの商品にないIDを削除する
-        let item_ids: Vec<_> = item_ids
-            .into_iter()
-            .filter(|id| aggregate.items().iter().any(|item| item.id() == id))
-            .collect();
-        if item_ids.is_empty() {
-            // NOTE: 引数に指定された全ての商品IDがテナントに存在しない場合は集約の更新・イベントの作成をせずに処理を終了する
-            return Ok(());
-        }
-        let events = aggregate
-            .apply_command(Command::RemoveItems { item_ids })
-            .map_err(|e| match e {
-                kernel::CommandKernelError::AggregateVersionOverflowed => {
-                    CommandUseCaseError::Overflowed
+    async fn remove_items(
+        &self,
+        id: Id<Aggregate>,
+        item_ids: Vec<Id<kernel::Item>>,
+        expected_version: Option<u64>,
+        idempotency_key: Option<String>,
+    ) -> Result<u64> {
+        let idempotency_key = idempotency_key.map(|key| format!("remove_items:{id}:{key}"));
+        self.retry_policy
+            .run("remove_items", is_conflict, || {
+                let id = id.clone();
+                let item_ids = item_ids.clone();
+                let idempotency_key = idempotency_key.clone();
+                async move {
+                    if let Some(idempotency) =
+                        self.get_idempotency(idempotency_key.as_ref()).await?
+                    {
+                        return Ok(idempotency.version());
+                    }
+                    let mut aggregate = self
+                        .processor
+                        .get(id)
+                        .await?
+                        .ok_or_else(|| CommandUseCaseError::NotFound)?;
+                    // テナントの商品にないIDを削除する
+                    let item_ids: Vec<_> = item_ids
+                        .into_iter()
+                        .filter(|id| aggregate.items().iter().any(|item| item.id() == id))
+                        .collect();
+                    if item_ids.is_empty() {
+                        // NOTE: 引数に指定された全ての商品IDがテナントに存在しない場合は集約の更新・イベントの作成をせずに処理を終了する
+                        aggregate
+                            .ensure_version(expected_version)
+                            .map_err(|e| match e {
+                                kernel::CommandKernelError::VersionMismatch {
+                                    expected,
+                                    actual,
+                                } => CommandUseCaseError::VersionMismatch { expected, actual },
+                                e => CommandUseCaseError::KernelError(e),
+                            })?;
+                        return Ok(aggregate.version());
+                    }
+                    let events = aggregate
+                        .apply_command(Command::RemoveItems { item_ids }, expected_version)
+                        .map_err(|e| match e {
+                            kernel::CommandKernelError::AggregateVersionOverflowed => {
+                                CommandUseCaseError::Overflowed
+                            }
+                            kernel::CommandKernelError::EmptyItemIds => {
+                                CommandUseCaseError::InvalidArgument
+                            }
+                            kernel::CommandKernelError::VersionMismatch { expected, actual } => {
+                                CommandUseCaseError::VersionMismatch { expected, actual }
+                            }
+                            kernel::CommandKernelError::Unknown(e) => {
+                                CommandUseCaseError::Unknown(e)
+                            }
+                            _ => CommandUseCaseError::KernelError(e),
+                        })?;
+                    let version = aggregate.version();
+                    let idempotency = idempotency_key.map(|key| {
+                        Idempotency::new(key, aggregate.id().clone(), version, Vec::new())
+                    });
+                    self.update(aggregate, events, idempotency).await?;
+                    Ok(version)
                 }
-                kernel::CommandKernelError::EmptyItemIds => CommandUseCaseError::InvalidArgument,
-                kernel::CommandKernelError::Unknown(e) => CommandUseCaseError::Unknown(e),
-                _ => CommandUseCaseError::KernelError(e),
-            })?;
-        self.processor.update(aggregate, events).await?;
-        Ok(())
+            })
+            .await
     }
 }
 
@@ -174,7 +348,7 @@ mod tests {
                             let item_ids = item_ids.clone();
                             processor
                                 .expect_update()
-                                .returning(move |aggregate, events| {
+                                .returning(move |aggregate, events, _| {
                                     let items =
                                         vec![items[0].clone(), items[3].clone(), items[4].clone()];
                                     let item_ids = item_ids.clone();
@@ -228,7 +402,7 @@ mod tests {
                             let item_ids = item_ids.clone();
                             processor
                                 .expect_update()
-                                .returning(move |aggregate, events| {
+                                .returning(move |aggregate, events, _| {
                                     let items =
                                         vec![items[0].clone(), items[3].clone(), items[4].clone()];
                                     let item_ids = item_ids.clone();
@@ -286,47 +460,231 @@ mod tests {
         } in tests
         {
             let usecase: CommandUseCase<MockCommandProcessor> = CommandUseCase::new(processor);
-            let result = usecase.remove_items(tenant_id, item_ids).await;
+            let result = usecase.remove_items(tenant_id, item_ids, None, None).await;
             assert!(result.is_ok(), "{name}: result must be ok: {result:#?}");
         }
         Ok(())
     }
 
     #[tokio::test]
+    async fn test_remove_items_retry_ok() -> Result<(), Error> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let item = Item::new(Id::generate(), String::new(), 1000);
+        let aggregate_id: Id<Aggregate> = Id::generate();
+        let aggregate = Aggregate::new(aggregate_id.clone(), String::new(), vec![item.clone()], 1);
+        let mut processor = MockCommandProcessor::new();
+        processor.expect_get().times(2).returning(move |_| {
+            let aggregate = aggregate.clone();
+            Box::pin(async move { Ok(Some(aggregate)) })
+        });
+        let attempts = Arc::new(AtomicUsize::new(0));
+        {
+            let attempts = Arc::clone(&attempts);
+            processor
+                .expect_update()
+                .times(2)
+                .returning(move |_, _, _| {
+                    // NOTE: 1回目の更新だけ競合させる
+                    if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                        return Box::pin(async {
+                            Err(kernel::CommandKernelError::ConcurrencyConflict)
+                        });
+                    }
+                    Box::pin(async { Ok(()) })
+                });
+        }
+
+        let usecase = CommandUseCase::new(processor);
+        let result = usecase
+            .remove_items(aggregate_id, vec![item.id().clone()], None, None)
+            .await;
+        assert!(
+            result.is_ok(),
+            "集約の更新が競合した場合は再実行する: {result:#?}"
+        );
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_remove_items_idempotency_ok() -> Result<(), Error> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        use kernel::Idempotency;
+
+        let item = Item::new(Id::generate(), String::new(), 1000);
+        let aggregate_id: Id<Aggregate> = Id::generate();
+        let aggregate = Aggregate::new(aggregate_id.clone(), String::new(), vec![item.clone()], 1);
+        let key = format!("remove_items:{aggregate_id}:key");
+        let mut processor = MockCommandProcessor::new();
+        let attempts = Arc::new(AtomicUsize::new(0));
+        {
+            let attempts = Arc::clone(&attempts);
+            let aggregate_id = aggregate_id.clone();
+            processor
+                .expect_get_idempotency()
+                .times(2)
+                .returning(move |actual| {
+                    assert_eq!(actual, key);
+                    // NOTE: 2回目の取得では、先に実行された同じ冪等キーのコマンドの結果が保存されている
+                    if attempts.load(Ordering::SeqCst) == 0 {
+                        return Box::pin(async { Ok(None) });
+                    }
+                    let idempotency = Idempotency::new(actual, aggregate_id.clone(), 2, Vec::new());
+                    Box::pin(async move { Ok(Some(idempotency)) })
+                });
+        }
+        processor.expect_get().times(1).returning(move |_| {
+            let aggregate = aggregate.clone();
+            Box::pin(async move { Ok(Some(aggregate)) })
+        });
+        processor
+            .expect_update()
+            .times(1)
+            .returning(move |_, _, idempotency| {
+                assert!(idempotency.is_some(), "冪等キーをイベントと一緒に保存する");
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Box::pin(async { Err(kernel::CommandKernelError::ConcurrencyConflict) })
+            });
+
+        let usecase = CommandUseCase::new(processor);
+        let result = usecase
+            .remove_items(
+                aggregate_id,
+                vec![item.id().clone()],
+                None,
+                Some("key".to_string()),
+            )
+            .await;
+        assert!(
+            matches!(result, Ok(2)),
+            "同じ冪等キーのコマンドと競合した場合は保存済みの結果を返す: {result:#?}"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[allow(clippy::too_many_lines)]
     async fn test_remove_items_err() -> Result<(), Error> {
         struct TestCase<P: CommandProcessor> {
             name: &'static str,
             tenant_id: Id<Aggregate>,
             item_ids: Vec<Id<Item>>,
+            expected_version: Option<u64>,
             processor: P,
             assert: fn(name: &str, actual: CommandUseCaseError),
         }
 
-        let tests = [TestCase {
-            name: "集約が存在しない場合はNotFoundが返る",
-            tenant_id: Id::generate(),
-            item_ids: Vec::new(),
-            processor: {
-                let mut processor = MockCommandProcessor::new();
-                processor
-                    .expect_get()
-                    .returning(|_| Box::pin(async { Ok(None) }));
-                processor
+        let item = Item::new(Id::generate(), String::new(), 1000);
+        let aggregate_id: Id<Aggregate> = Id::generate();
+        let aggregate = Aggregate::new(aggregate_id.clone(), String::new(), vec![item.clone()], 1);
+        let tests = [
+            TestCase {
+                name: "集約が存在しない場合はNotFoundが返る",
+                tenant_id: Id::generate(),
+                item_ids: Vec::new(),
+                expected_version: None,
+                processor: {
+                    let mut processor = MockCommandProcessor::new();
+                    processor
+                        .expect_get()
+                        .returning(|_| Box::pin(async { Ok(None) }));
+                    processor
+                },
+                assert: |name, actual| {
+                    assert!(matches!(actual, CommandUseCaseError::NotFound), "{name}");
+                },
             },
-            assert: |name, actual| {
-                assert!(matches!(actual, CommandUseCaseError::NotFound), "{name}");
+            TestCase {
+                name: "再実行しても集約の更新が競合する場合はConcurrencyConflictが返る",
+                tenant_id: aggregate_id.clone(),
+                item_ids: vec![item.id().clone()],
+                expected_version: None,
+                processor: {
+                    let mut processor = MockCommandProcessor::new();
+                    let aggregate = aggregate.clone();
+                    processor.expect_get().returning(move |_| {
+                        let aggregate = aggregate.clone();
+                        Box::pin(async move { Ok(Some(aggregate)) })
+                    });
+                    processor.expect_update().returning(|_, _, _| {
+                        Box::pin(async { Err(kernel::CommandKernelError::ConcurrencyConflict) })
+                    });
+                    processor
+                },
+                assert: |name, actual| {
+                    assert!(
+                        matches!(actual, CommandUseCaseError::ConcurrencyConflict),
+                        "{name}"
+                    );
+                },
             },
-        }];
+            TestCase {
+                name: "期待するバージョンと集約のバージョンが異なる場合はVersionMismatchが返る",
+                tenant_id: aggregate_id.clone(),
+                item_ids: vec![item.id().clone()],
+                expected_version: Some(2),
+                processor: {
+                    let mut processor = MockCommandProcessor::new();
+                    let aggregate = aggregate.clone();
+                    processor.expect_get().returning(move |_| {
+                        let aggregate = aggregate.clone();
+                        Box::pin(async move { Ok(Some(aggregate)) })
+                    });
+                    processor.expect_update().never();
+                    processor
+                },
+                assert: |name, actual| {
+                    assert!(
+                        matches!(
+                            actual,
+                            CommandUseCaseError::VersionMismatch {
+                                expected: 2,
+                                actual: 1
+                            }
+                        ),
+                        "{name}"
+                    );
+                },
+            },
+            TestCase {
+                name: "削除する商品IDを保持していなくても期待するバージョンと集約のバージョンが異なる場合はVersionMismatchが返る",
+                tenant_id: aggregate_id,
+                item_ids: vec![Id::generate()],
+                expected_version: Some(2),
+                processor: {
+                    let mut processor = MockCommandProcessor::new();
+                    processor.expect_get().returning(move |_| {
+                        let aggregate = aggregate.clone();
+                        Box::pin(async move { Ok(Some(aggregate)) })
+                    });
+                    processor.expect_update().never();
+                    processor
+                },
+                assert: |name, actual| {
+                    assert!(
+                        matches!(actual, CommandUseCaseError::VersionMismatch { .. }),
+                        "{name}"
+                    );
+                },
+            },
+        ];
         for TestCase {
             name,
             tenant_id,
             item_ids,
+            expected_version,
             processor,
             assert,
         } in tests
         {
             let usecase: CommandUseCase<MockCommandProcessor> = CommandUseCase::new(processor);
-            let result = usecase.remove_items(tenant_id, item_ids).await;
+            let result = usecase
+                .remove_items(tenant_id, item_ids, expected_version, None)
+                .await;
             assert!(result.is_err(), "{name}: result must be error: {result:#?}");
             assert(name, result.err().unwrap());
         }
@@ -5,5 +5,7 @@ pub mod command;
 /// クエリ操作関連のモジュール
 pub mod query;
 
-pub use command::{CommandUseCase, CommandUseCaseError, CommandUseCaseExt, Item, Tenant};
+pub use command::{
+    CommandUseCase, CommandUseCaseError, CommandUseCaseExt, Item, RetryPolicy, Tenant,
+};
 pub use query::{QueryUseCase, QueryUseCaseExt};
@@ -3,5 +3,6 @@ pub mod model;
 /// ユースケースを定義したモジュール
 pub mod usecase;
 
-pub use model::{Item, Tenant};
+pub use kernel::query::{ItemFilter, ItemSort, ItemSortKey, Page, PageRequest, QueryKernelError};
+pub use model::{Item, SearchHit, Tenant};
 pub use usecase::{QueryUseCase, QueryUseCaseExt};
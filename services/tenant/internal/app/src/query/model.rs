@@ -2,6 +2,7 @@
 pub struct Tenant {
     id: String,
     name: String,
+    version: u64,
 }
 
 impl Tenant {
@@ -14,6 +15,12 @@ impl Tenant {
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    /// 集約のバージョン
+    #[must_use]
+    pub fn version(&self) -> u64 {
+        self.version
+    }
 }
 
 impl From<kernel::query::Tenant> for Tenant {
@@ -21,6 +28,7 @@ impl From<kernel::query::Tenant> for Tenant {
         Self {
             id: value.id().to_string(),
             name: value.name().to_string(),
+            version: value.version(),
         }
     }
 }
@@ -58,3 +66,46 @@ impl From<kernel::query::Item> for Item {
         }
     }
 }
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchHit {
+    tenant_id: String,
+    tenant_name: String,
+    item: Option<Item>,
+    score: f32,
+}
+
+impl SearchHit {
+    #[must_use]
+    pub fn tenant_id(&self) -> &str {
+        &self.tenant_id
+    }
+
+    #[must_use]
+    pub fn tenant_name(&self) -> &str {
+        &self.tenant_name
+    }
+
+    /// 商品名に一致した場合は商品
+    #[must_use]
+    pub fn item(&self) -> Option<&Item> {
+        self.item.as_ref()
+    }
+
+    /// 検索語との関連度
+    #[must_use]
+    pub fn score(&self) -> f32 {
+        self.score
+    }
+}
+
+impl From<kernel::query::SearchHit> for SearchHit {
+    fn from(value: kernel::query::SearchHit) -> Self {
+        Self {
+            tenant_id: value.tenant_id().to_string(),
+            tenant_name: value.tenant_name().to_string(),
+            item: value.item().cloned().map(Into::into),
+            score: value.score(),
+        }
+    }
+}
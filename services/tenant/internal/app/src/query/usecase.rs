@@ -1,22 +1,33 @@
 use std::future::Future;
 
+use kernel::query::{ItemFilter, ItemSort, Page, PageRequest};
 use kernel::{Aggregate, Id, QueryProcessor};
 use tracing::instrument;
 
-use super::{Item, Tenant};
+use super::{Item, SearchHit, Tenant};
 
 type Result<T> = core::result::Result<T, Box<dyn std::error::Error + Send + Sync + 'static>>;
 
 /// ユースケースのインターフェイス
 pub trait QueryUseCaseExt {
-    /// テナントの一覧を取得する
-    fn list_tenants(&self) -> impl Future<Output = Result<Vec<Tenant>>> + Send;
+    /// テナントの一覧を1ページ取得する
+    fn list_tenants(&self, page: PageRequest) -> impl Future<Output = Result<Page<Tenant>>> + Send;
 
-    /// テナント商品の一覧を取得する
+    /// テナント商品の一覧を条件で絞り込み、指定した順序で1ページ取得する
     fn list_items(
         &self,
         tenant_id: Id<Aggregate>,
-    ) -> impl Future<Output = Result<Option<Vec<Item>>>> + Send;
+        filter: ItemFilter,
+        sort: ItemSort,
+        page: PageRequest,
+    ) -> impl Future<Output = Result<Option<Page<Item>>>> + Send;
+
+    /// テナント名と商品名から全てのテナントを横断して検索し、関連度の高い順に1ページ取得する
+    fn search(
+        &self,
+        query: String,
+        page: PageRequest,
+    ) -> impl Future<Output = Result<Page<SearchHit>>> + Send;
 }
 
 /// ユースケースの実態
@@ -37,22 +48,27 @@ where
     P: QueryProcessor + Send + Sync + 'static,
 {
     #[instrument(skip(self), err, ret)]
-    async fn list_tenants(&self) -> Result<Vec<Tenant>> {
-        Ok(self
-            .processor
-            .list_tenants()
-            .await?
-            .into_iter()
-            .map(Into::into)
-            .collect())
+    async fn list_tenants(&self, page: PageRequest) -> Result<Page<Tenant>> {
+        Ok(self.processor.list_tenants(page).await?.map(Into::into))
     }
 
     #[instrument(skip(self), err, ret)]
-    async fn list_items(&self, tenant_id: Id<Aggregate>) -> Result<Option<Vec<Item>>> {
+    async fn list_items(
+        &self,
+        tenant_id: Id<Aggregate>,
+        filter: ItemFilter,
+        sort: ItemSort,
+        page: PageRequest,
+    ) -> Result<Option<Page<Item>>> {
         Ok(self
             .processor
-            .list_items(tenant_id)
+            .list_items(tenant_id, filter, sort, page)
             .await?
-            .map(|items| items.into_iter().map(Item::from).collect()))
+            .map(|items| items.map(Item::from)))
+    }
+
+    #[instrument(skip(self), err, ret)]
+    async fn search(&self, query: String, page: PageRequest) -> Result<Page<SearchHit>> {
+        Ok(self.processor.search(query, page).await?.map(Into::into))
     }
 }
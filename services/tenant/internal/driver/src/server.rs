@@ -6,7 +6,7 @@ use proto::tenant::v1::tenant_service_server::{TenantService, TenantServiceServe
 use proto::tenant::v1::{
     AddItemsRequest, AddItemsResponse, CreateRequest, CreateResponse, ListItemsRequest,
     ListItemsResponse, ListTenantsRequest, ListTenantsResponse, RemoveItemsRequest,
-    RemoveItemsResponse, FILE_DESCRIPTOR_SET,
+    RemoveItemsResponse, SearchRequest, SearchResponse, FILE_DESCRIPTOR_SET,
 };
 use tonic::{Code, Request, Response, Status};
 use tonic_types::{ErrorDetails, StatusExt};
@@ -14,6 +14,61 @@ use tracing::instrument;
 
 type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
 
+/// 1ページに含める件数を指定しなかった場合の件数
+const DEFAULT_PAGE_SIZE: usize = 100;
+/// 1ページに含める最大件数
+const MAX_PAGE_SIZE: usize = 1000;
+
+/// リクエストのページの指定を変換する
+///
+/// 件数を指定しなかった場合はデフォルトの件数、最大件数より多い場合は最大件数にする
+fn page_request(page_size: u32, page_token: String) -> app::query::PageRequest {
+    let page_size = match usize::try_from(page_size) {
+        Ok(0) => DEFAULT_PAGE_SIZE,
+        Ok(page_size) => page_size.min(MAX_PAGE_SIZE),
+        Err(_) => MAX_PAGE_SIZE,
+    };
+    app::query::PageRequest::new(page_size, Some(page_token).filter(|t| !t.is_empty()))
+}
+
+/// リクエストの商品の並び順のキーを変換する
+fn item_sort_key(sort_key: i32) -> Result<app::query::ItemSortKey, Error> {
+    use proto::tenant::v1::list_items_request::SortKey;
+
+    match SortKey::try_from(sort_key)? {
+        SortKey::Unspecified => Ok(app::query::ItemSortKey::Id),
+        SortKey::Price => Ok(app::query::ItemSortKey::Price),
+        SortKey::Name => Ok(app::query::ItemSortKey::Name),
+    }
+}
+
+/// クエリ操作のエラーのステータス
+fn query_error(e: &Error) -> Status {
+    match e.downcast_ref::<app::query::QueryKernelError>() {
+        Some(e @ app::query::QueryKernelError::InvalidPageToken) => Status::with_error_details(
+            Code::InvalidArgument,
+            e.to_string(),
+            ErrorDetails::new()
+                .add_bad_request_violation("page_token", e.to_string())
+                .to_owned(),
+        ),
+        None => Status::unknown(e.to_string()),
+    }
+}
+
+/// 集約のバージョンが期待するバージョンと異なる場合のステータス
+///
+/// クライアントが集約を取得し直してから再実行できるように `ABORTED` を返す
+fn version_mismatch(e: &app::CommandUseCaseError) -> Status {
+    Status::with_error_details(
+        Code::Aborted,
+        e.to_string(),
+        ErrorDetails::new()
+            .add_precondition_failure_violation("VERSION", "expected_version", e.to_string())
+            .to_owned(),
+    )
+}
+
 #[derive(Debug)]
 pub struct Service<C: CommandUseCaseExt, Q: QueryUseCaseExt> {
     command: C,
@@ -43,12 +98,19 @@ where
     ) -> Result<Response<CreateResponse>, Status> {
         use app::Tenant;
 
-        let req = req.into_inner();
-        let name = req.name;
+        let CreateRequest {
+            name,
+            idempotency_key,
+        } = req.into_inner();
         self.command
-            .create(Tenant::new(name.clone()))
+            .create(Tenant::new(name.clone()), idempotency_key)
             .await
-            .map(|id| Response::new(CreateResponse { id: id.to_string() }))
+            .map(|(id, version)| {
+                Response::new(CreateResponse {
+                    id: id.to_string(),
+                    version,
+                })
+            })
             .map_err(|e| match e {
                 app::CommandUseCaseError::InvalidArgument => Status::with_error_details(
                     Code::InvalidArgument,
@@ -57,6 +119,7 @@ where
                         .add_bad_request_violation("name", format!("invalid tenant name: {name}"))
                         .to_owned(),
                 ),
+                app::CommandUseCaseError::ConcurrencyConflict => Status::aborted(e.to_string()),
                 e => Status::unknown(e.to_string()),
             })
     }
@@ -64,24 +127,31 @@ where
     #[instrument(skip(self), err, ret)]
     async fn list_tenants(
         &self,
-        _: Request<ListTenantsRequest>,
+        req: Request<ListTenantsRequest>,
     ) -> Result<Response<ListTenantsResponse>, Status> {
         use proto::tenant::v1::list_tenants_response::Tenant;
 
-        self.query
-            .list_tenants()
+        let ListTenantsRequest {
+            page_size,
+            page_token,
+        } = req.into_inner();
+        let page = self
+            .query
+            .list_tenants(page_request(page_size, page_token))
             .await
-            .map(|tenants| {
-                let tenants: Vec<_> = tenants
-                    .into_iter()
-                    .map(|t| Tenant {
-                        id: t.id().to_string(),
-                        name: t.name().to_string(),
-                    })
-                    .collect();
-                Response::new(ListTenantsResponse { tenants })
-            })
-            .map_err(|e| Status::unknown(e.to_string()))
+            .map_err(|e| query_error(&e))?;
+        Ok(Response::new(ListTenantsResponse {
+            next_page_token: page.next_page_token().unwrap_or_default().to_string(),
+            tenants: page
+                .into_items()
+                .into_iter()
+                .map(|t| Tenant {
+                    id: t.id().to_string(),
+                    name: t.name().to_string(),
+                    version: t.version(),
+                })
+                .collect(),
+        }))
     }
 
     #[instrument(skip(self), err, ret)]
@@ -91,7 +161,12 @@ where
     ) -> Result<Response<AddItemsResponse>, Status> {
         use app::Item;
 
-        let AddItemsRequest { tenant_id, items } = req.into_inner();
+        let AddItemsRequest {
+            tenant_id,
+            items,
+            expected_version,
+            idempotency_key,
+        } = req.into_inner();
         let tenant_id = tenant_id.parse().map_err(|e: Error| {
             Status::with_error_details(
                 Code::InvalidArgument,
@@ -106,14 +181,19 @@ where
             .map(|x| Item::new(x.name, x.price))
             .collect();
         self.command
-            .add_items(tenant_id, items)
+            .add_items(tenant_id, items, expected_version, idempotency_key)
             .await
-            .map(|ids| {
+            .map(|(ids, version)| {
                 Response::new(AddItemsResponse {
                     ids: ids.into_iter().map(|x| x.to_string()).collect(),
+                    version,
                 })
             })
-            .map_err(|e| Status::internal(e.to_string()))
+            .map_err(|e| match e {
+                app::CommandUseCaseError::ConcurrencyConflict => Status::aborted(e.to_string()),
+                app::CommandUseCaseError::VersionMismatch { .. } => version_mismatch(&e),
+                e => Status::internal(e.to_string()),
+            })
     }
 
     #[instrument(skip(self), err, ret)]
@@ -124,6 +204,8 @@ where
         let RemoveItemsRequest {
             tenant_id,
             item_ids,
+            expected_version,
+            idempotency_key,
         } = req.into_inner();
         let tenant_id = tenant_id.parse().map_err(|e: Error| {
             Status::with_error_details(
@@ -148,10 +230,14 @@ where
                 )
             })?;
         self.command
-            .remove_items(tenant_id, item_ids)
+            .remove_items(tenant_id, item_ids, expected_version, idempotency_key)
             .await
-            .map(|()| Response::new(RemoveItemsResponse {}))
-            .map_err(|e| Status::internal(e.to_string()))
+            .map(|version| Response::new(RemoveItemsResponse { version }))
+            .map_err(|e| match e {
+                app::CommandUseCaseError::ConcurrencyConflict => Status::aborted(e.to_string()),
+                app::CommandUseCaseError::VersionMismatch { .. } => version_mismatch(&e),
+                e => Status::internal(e.to_string()),
+            })
     }
 
     #[instrument(skip(self), err, ret)]
@@ -161,7 +247,46 @@ where
     ) -> Result<Response<ListItemsResponse>, Status> {
         use proto::tenant::v1::list_items_response::Item;
 
-        let ListItemsRequest { tenant_id } = req.into_inner();
+        let ListItemsRequest {
+            tenant_id,
+            page_size,
+            page_token,
+            min_price,
+            max_price,
+            name_contains,
+            sort_key,
+            descending,
+        } = req.into_inner();
+        if let (Some(min_price), Some(max_price)) = (min_price, max_price) {
+            if min_price > max_price {
+                return Err(Status::with_error_details(
+                    Code::InvalidArgument,
+                    format!("invalid price range: {min_price} > {max_price}"),
+                    ErrorDetails::new()
+                        .add_bad_request_violation(
+                            "max_price",
+                            "must be greater than or equal to min_price",
+                        )
+                        .to_owned(),
+                ));
+            }
+        }
+        let sort_key = item_sort_key(sort_key).map_err(|e| {
+            Status::with_error_details(
+                Code::InvalidArgument,
+                format!("invalid sort key: {sort_key}"),
+                ErrorDetails::new()
+                    .add_bad_request_violation("sort_key", e.to_string())
+                    .to_owned(),
+            )
+        })?;
+        // NOTE: 商品名の文字列が空の場合は商品名で絞り込まない
+        let filter = app::query::ItemFilter::new(
+            min_price,
+            max_price,
+            name_contains.filter(|name| !name.is_empty()),
+        );
+        let sort = app::query::ItemSort::new(sort_key, descending);
         let tenant_id = tenant_id.parse().map_err(|e: Error| {
             Status::with_error_details(
                 Code::InvalidArgument,
@@ -171,25 +296,73 @@ where
                     .to_owned(),
             )
         })?;
-        if let Some(items) = self
+        if let Some(page) = self
             .query
-            .list_items(tenant_id)
+            .list_items(tenant_id, filter, sort, page_request(page_size, page_token))
             .await
-            .map_err(|e| Status::unknown(e.to_string()))?
+            .map_err(|e| query_error(&e))?
         {
-            let items: Vec<_> = items
-                .into_iter()
-                .map(|i| Item {
-                    id: i.id().to_string(),
-                    name: i.name().to_string(),
-                    price: i.price(),
-                })
-                .collect();
-            Ok(Response::new(ListItemsResponse { items }))
+            Ok(Response::new(ListItemsResponse {
+                next_page_token: page.next_page_token().unwrap_or_default().to_string(),
+                items: page
+                    .into_items()
+                    .into_iter()
+                    .map(|i| Item {
+                        id: i.id().to_string(),
+                        name: i.name().to_string(),
+                        price: i.price(),
+                    })
+                    .collect(),
+            }))
         } else {
             Err(Status::not_found("tenant not found"))
         }
     }
+
+    #[instrument(skip(self), err, ret)]
+    async fn search(
+        &self,
+        req: Request<SearchRequest>,
+    ) -> Result<Response<SearchResponse>, Status> {
+        use proto::tenant::v1::search_response::{Hit, Item};
+
+        let SearchRequest {
+            query,
+            page_size,
+            page_token,
+        } = req.into_inner();
+        if query.trim().is_empty() {
+            return Err(Status::with_error_details(
+                Code::InvalidArgument,
+                "query is empty",
+                ErrorDetails::new()
+                    .add_bad_request_violation("query", "must not be empty")
+                    .to_owned(),
+            ));
+        }
+        let page = self
+            .query
+            .search(query, page_request(page_size, page_token))
+            .await
+            .map_err(|e| query_error(&e))?;
+        Ok(Response::new(SearchResponse {
+            next_page_token: page.next_page_token().unwrap_or_default().to_string(),
+            hits: page
+                .into_items()
+                .into_iter()
+                .map(|hit| Hit {
+                    tenant_id: hit.tenant_id().to_string(),
+                    tenant_name: hit.tenant_name().to_string(),
+                    item: hit.item().map(|i| Item {
+                        id: i.id().to_string(),
+                        name: i.name().to_string(),
+                        price: i.price(),
+                    }),
+                    score: hit.score(),
+                })
+                .collect(),
+        }))
+    }
 }
 
 pub struct Server<C: CommandUseCaseExt, Q: QueryUseCaseExt> {
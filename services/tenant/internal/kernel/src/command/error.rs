@@ -16,6 +16,10 @@ pub enum CommandKernelError {
     EmptyItems,
     #[error("Item ids are empty")]
     EmptyItemIds,
+    #[error("Aggregate was updated concurrently")]
+    ConcurrencyConflict,
+    #[error("Aggregate version mismatch: expected {expected}, actual {actual}")]
+    VersionMismatch { expected: u64, actual: u64 },
     #[error(transparent)]
     ProcessorError(#[from] CommandProcessorError),
     #[error(transparent)]
@@ -2,6 +2,9 @@
 pub mod aggregate;
 /// エンティティを定義するモジュール
 pub mod entity;
+/// 冪等キーを定義するモジュール
+pub mod idempotency;
 
 pub use aggregate::Aggregate;
 pub use entity::Item;
+pub use idempotency::Idempotency;
@@ -48,11 +48,35 @@ impl Aggregate {
         self.version
     }
 
+    /// 集約のバージョンが期待するバージョンと一致するか検証する
+    ///
+    /// `expected_version` が `None` の場合は検証しない
+    ///
+    /// # Errors
+    pub fn ensure_version(&self, expected_version: Option<u64>) -> Result<(), CommandKernelError> {
+        match expected_version {
+            Some(expected) if expected != self.version => {
+                Err(CommandKernelError::VersionMismatch {
+                    expected,
+                    actual: self.version,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// 集約にコマンドを実行する
     ///
+    /// `expected_version` を指定した場合は集約がそのバージョンのときだけコマンドを実行する
+    ///
     /// # Errors
     #[instrument(skip(self), err, ret)]
-    pub fn apply_command(&mut self, command: Command) -> Result<Vec<Event>, CommandKernelError> {
+    pub fn apply_command(
+        &mut self,
+        command: Command,
+        expected_version: Option<u64>,
+    ) -> Result<Vec<Event>, CommandKernelError> {
+        self.ensure_version(expected_version)?;
         if !matches!(&command, Command::Create { .. }) && self.version == 0 {
             return Err(CommandKernelError::AggregateNotCreated);
         }
@@ -74,25 +98,34 @@ impl Aggregate {
         }
 
         let events: Vec<Event> = match command {
-            Command::Create { name } => {
-                self.name.clone_from(&name);
-                vec![Event::Created { name }]
-            }
-            Command::AddItems { items } => {
-                self.items.extend_from_slice(&items);
-                vec![Event::ItemsAdded { items }]
-            }
-            Command::RemoveItems { item_ids } => {
-                self.items.retain(|x| !item_ids.contains(x.id()));
-                vec![Event::ItemsRemoved { item_ids }]
-            }
+            Command::Create { name } => vec![Event::Created { name }],
+            Command::AddItems { items } => vec![Event::ItemsAdded { items }],
+            Command::RemoveItems { item_ids } => vec![Event::ItemsRemoved { item_ids }],
         };
+        for event in events.clone() {
+            self.apply_event(event)?;
+        }
+        Ok(events)
+    }
 
-        self.version = self
+    /// 集約にイベントを適用する
+    ///
+    /// イベントストアから集約を復元するときに利用するため、イベントの検証はせずに集約の状態だけを変更する
+    ///
+    /// # Errors
+    #[instrument(skip(self), err, ret)]
+    pub fn apply_event(&mut self, event: Event) -> Result<(), CommandKernelError> {
+        let version = self
             .version
             .checked_add(1)
             .ok_or_else(|| CommandKernelError::AggregateVersionOverflowed)?;
-        Ok(events)
+        match event {
+            Event::Created { name } => self.name = name,
+            Event::ItemsAdded { items } => self.items.extend(items),
+            Event::ItemsRemoved { item_ids } => self.items.retain(|x| !item_ids.contains(x.id())),
+        }
+        self.version = version;
+        Ok(())
     }
 }
 
@@ -109,6 +142,7 @@ mod tests {
             name: &'static str,
             aggregate: Aggregate,
             command: Command,
+            expected_version: Option<u64>,
             expected_aggregate: Aggregate,
             expected_events: Vec<Event>,
         }
@@ -127,6 +161,7 @@ mod tests {
                 command: Command::Create {
                     name: tenant_name.clone(),
                 },
+                expected_version: None,
                 expected_aggregate: Aggregate {
                     id: aggregate_id.clone(),
                     name: tenant_name.clone(),
@@ -148,6 +183,7 @@ mod tests {
                 command: Command::AddItems {
                     items: vec![Item::new(item_id.clone(), item_name.clone(), 1000)],
                 },
+                expected_version: None,
                 expected_aggregate: Aggregate {
                     id: aggregate_id.clone(),
                     name: tenant_name.clone(),
@@ -169,6 +205,7 @@ mod tests {
                 command: Command::AddItems {
                     items: vec![Item::new(item_id.clone(), item_name.clone(), 2000)],
                 },
+                expected_version: None,
                 expected_aggregate: Aggregate {
                     id: aggregate_id.clone(),
                     name: tenant_name.clone(),
@@ -196,6 +233,7 @@ mod tests {
                 command: Command::RemoveItems {
                     item_ids: vec![item_id.clone()],
                 },
+                expected_version: None,
                 expected_aggregate: Aggregate {
                     id: aggregate_id.clone(),
                     name: tenant_name.clone(),
@@ -220,6 +258,7 @@ mod tests {
                 command: Command::RemoveItems {
                     item_ids: vec![item_id.clone(), item_id_2.clone()],
                 },
+                expected_version: None,
                 expected_aggregate: Aggregate {
                     id: aggregate_id.clone(),
                     name: tenant_name.clone(),
@@ -230,16 +269,39 @@ mod tests {
                     item_ids: vec![item_id.clone(), item_id_2.clone()],
                 }],
             },
+            TestCase {
+                name: "期待するバージョンと集約のバージョンが一致する場合、コマンドが実行されイベントが返る",
+                aggregate: Aggregate {
+                    id: aggregate_id.clone(),
+                    name: tenant_name.clone(),
+                    items: vec![Item::new(item_id.clone(), item_name.clone(), 1000)],
+                    version: 2,
+                },
+                command: Command::RemoveItems {
+                    item_ids: vec![item_id.clone()],
+                },
+                expected_version: Some(2),
+                expected_aggregate: Aggregate {
+                    id: aggregate_id.clone(),
+                    name: tenant_name.clone(),
+                    items: Vec::new(),
+                    version: 3,
+                },
+                expected_events: vec![Event::ItemsRemoved {
+                    item_ids: vec![item_id.clone()],
+                }],
+            },
         ];
         for TestCase {
             name,
             mut aggregate,
             command,
+            expected_version,
             expected_aggregate,
             expected_events,
         } in tests
         {
-            let actual = aggregate.apply_command(command)?;
+            let actual = aggregate.apply_command(command, expected_version)?;
             assert_eq!(
                 aggregate, expected_aggregate,
                 "{name}: aggregate not equaled"
@@ -256,6 +318,7 @@ mod tests {
             name: &'static str,
             aggregate: Aggregate,
             command: Command,
+            expected_version: Option<u64>,
             assert: fn(name: &str, actual: CommandKernelError),
         }
         let tests = [
@@ -265,6 +328,7 @@ mod tests {
                 command: Command::RemoveItems {
                     item_ids: vec![Id::generate()],
                 },
+                expected_version: None,
                 assert: |name, actual| {
                     assert!(
                         matches!(actual, CommandKernelError::AggregateNotCreated),
@@ -281,6 +345,7 @@ mod tests {
                 command: Command::Create {
                     name: String::new(),
                 },
+                expected_version: None,
                 assert: |name, actual| {
                     assert!(
                         matches!(actual, CommandKernelError::AggregateAlreadyCreated),
@@ -294,6 +359,7 @@ mod tests {
                 command: Command::Create {
                     name: String::new(),
                 },
+                expected_version: None,
                 assert: |name, actual| {
                     assert!(
                         matches!(actual, CommandKernelError::InvalidTenantName),
@@ -308,6 +374,7 @@ mod tests {
                     ..Default::default()
                 },
                 command: Command::AddItems { items: Vec::new() },
+                expected_version: None,
                 assert: |name, actual| {
                     assert!(matches!(actual, CommandKernelError::EmptyItems), "{name}");
                 },
@@ -321,6 +388,7 @@ mod tests {
                 command: Command::AddItems {
                     items: vec![Item::new(Id::generate(), String::new(), 1000)],
                 },
+                expected_version: None,
                 assert: |name, actual| {
                     assert!(
                         matches!(actual, CommandKernelError::InvalidItemName),
@@ -337,6 +405,7 @@ mod tests {
                 command: Command::RemoveItems {
                     item_ids: Vec::new(),
                 },
+                expected_version: None,
                 assert: |name, actual| {
                     assert!(matches!(actual, CommandKernelError::EmptyItemIds), "{name}");
                 },
@@ -350,6 +419,7 @@ mod tests {
                 command: Command::RemoveItems {
                     item_ids: vec![Id::generate()],
                 },
+                expected_version: None,
                 assert: |name, actual| {
                     assert!(
                         matches!(actual, CommandKernelError::AggregateVersionOverflowed),
@@ -357,15 +427,39 @@ mod tests {
                     );
                 },
             },
+            TestCase {
+                name: "期待するバージョンと集約のバージョンが異なる場合はVersionMismatchが返る",
+                aggregate: Aggregate {
+                    version: 2,
+                    ..Default::default()
+                },
+                command: Command::RemoveItems {
+                    item_ids: vec![Id::generate()],
+                },
+                expected_version: Some(1),
+                assert: |name, actual| {
+                    assert!(
+                        matches!(
+                            actual,
+                            CommandKernelError::VersionMismatch {
+                                expected: 1,
+                                actual: 2
+                            }
+                        ),
+                        "{name}"
+                    );
+                },
+            },
         ];
         for TestCase {
             name,
             mut aggregate,
             command,
+            expected_version,
             assert,
         } in tests
         {
-            let result = aggregate.apply_command(command);
+            let result = aggregate.apply_command(command, expected_version);
             assert(
                 name,
                 result
@@ -375,4 +469,71 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_apply_event_ok() -> Result<(), Error> {
+        struct TestCase {
+            name: &'static str,
+            events: Vec<Event>,
+            expected: Aggregate,
+        }
+        let aggregate_id: Id<Aggregate> = Id::generate();
+        let item_id: Id<Item> = Id::generate();
+        let item_id_2: Id<Item> = Id::generate();
+        let tenant_name = "テストテナント".to_string();
+        let item_name = "テスト商品".to_string();
+        let tests = [
+            TestCase {
+                name: "集約作成イベントを適用した場合はバージョン1の集約が復元される",
+                events: vec![Event::Created {
+                    name: tenant_name.clone(),
+                }],
+                expected: Aggregate {
+                    id: aggregate_id.clone(),
+                    name: tenant_name.clone(),
+                    items: Vec::new(),
+                    version: 1,
+                },
+            },
+            TestCase {
+                name: "複数のイベントを順番に適用した場合はイベントの数をバージョンとする集約が復元される",
+                events: vec![
+                    Event::Created {
+                        name: tenant_name.clone(),
+                    },
+                    Event::ItemsAdded {
+                        items: vec![
+                            Item::new(item_id.clone(), item_name.clone(), 1000),
+                            Item::new(item_id_2.clone(), item_name.clone(), 2000),
+                        ],
+                    },
+                    Event::ItemsRemoved {
+                        item_ids: vec![item_id.clone()],
+                    },
+                ],
+                expected: Aggregate {
+                    id: aggregate_id.clone(),
+                    name: tenant_name.clone(),
+                    items: vec![Item::new(item_id_2.clone(), item_name.clone(), 2000)],
+                    version: 3,
+                },
+            },
+        ];
+        for TestCase {
+            name,
+            events,
+            expected,
+        } in tests
+        {
+            let mut aggregate = Aggregate {
+                id: aggregate_id.clone(),
+                ..Default::default()
+            };
+            for event in events {
+                aggregate.apply_event(event)?;
+            }
+            assert_eq!(aggregate, expected, "{name}");
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,56 @@
+use crate::Id;
+
+use super::{Aggregate, Item};
+
+/// 冪等キーを指定して実行したコマンドの結果
+///
+/// 同じ冪等キーでコマンドを再実行した場合は、イベントを発行せずに保存済みの結果を返す
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Idempotency {
+    key: String,
+    aggregate_id: Id<Aggregate>,
+    version: u64,
+    item_ids: Vec<Id<Item>>,
+}
+
+impl Idempotency {
+    /// Creates a new [`Idempotency`].
+    #[must_use]
+    pub fn new(
+        key: String,
+        aggregate_id: Id<Aggregate>,
+        version: u64,
+        item_ids: Vec<Id<Item>>,
+    ) -> Self {
+        Self {
+            key,
+            aggregate_id,
+            version,
+            item_ids,
+        }
+    }
+
+    /// 冪等キー
+    #[must_use]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// コマンドを実行した集約のID
+    #[must_use]
+    pub fn aggregate_id(&self) -> &Id<Aggregate> {
+        &self.aggregate_id
+    }
+
+    /// コマンドを実行した後の集約のバージョン
+    #[must_use]
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// コマンドで追加した商品のID
+    #[must_use]
+    pub fn item_ids(&self) -> &[Id<Item>] {
+        &self.item_ids
+    }
+}
@@ -2,15 +2,18 @@ use std::future::Future;
 
 use crate::Id;
 
-use super::{Aggregate, CommandKernelError, Event};
+use super::{Aggregate, CommandKernelError, Event, Idempotency};
 
 #[cfg_attr(feature = "test", mockall::automock)]
 pub trait CommandProcessor {
     /// 集約とイベントを作成する
+    ///
+    /// 冪等キーを指定した場合はイベントと一緒に保存する
     fn create(
         &self,
         aggregate: Aggregate,
         event: Event,
+        idempotency: Option<Idempotency>,
     ) -> impl Future<Output = Result<(), CommandKernelError>> + Send;
 
     /// 集約を取得する
@@ -20,9 +23,18 @@ pub trait CommandProcessor {
     ) -> impl Future<Output = Result<Option<Aggregate>, CommandKernelError>> + Send;
 
     /// 集約を更新しイベントを追加する
+    ///
+    /// 冪等キーを指定した場合はイベントと一緒に保存する
     fn update(
         &self,
         aggregate: Aggregate,
         events: Vec<Event>,
+        idempotency: Option<Idempotency>,
     ) -> impl Future<Output = Result<(), CommandKernelError>> + Send;
+
+    /// 冪等キーを指定して実行したコマンドの結果を取得する
+    fn get_idempotency(
+        &self,
+        key: String,
+    ) -> impl Future<Output = Result<Option<Idempotency>, CommandKernelError>> + Send;
 }
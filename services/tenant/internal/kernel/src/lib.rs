@@ -7,6 +7,8 @@ pub mod id;
 /// クエリ操作関連のモジュール
 pub mod query;
 
-pub use command::{Aggregate, Command, CommandKernelError, CommandProcessor, Event, Item};
+pub use command::{
+    Aggregate, Command, CommandKernelError, CommandProcessor, Event, Idempotency, Item,
+};
 pub use id::Id;
-pub use query::QueryProcessor;
+pub use query::{QueryKernelError, QueryProcessor};
@@ -1,7 +1,10 @@
+/// エラーを定義したモジュール
+pub mod error;
 /// クエリモデルを定義するモジュール
 pub mod model;
 /// リポジトリ操作のインターフェイスを定義するモジュール
 pub mod processor;
 
-pub use model::{Item, Tenant};
+pub use error::QueryKernelError;
+pub use model::{Item, ItemFilter, ItemSort, ItemSortKey, Page, PageRequest, SearchHit, Tenant};
 pub use processor::QueryProcessor;
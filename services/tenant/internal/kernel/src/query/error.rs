@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum QueryKernelError {
+    #[error("Invalid page token")]
+    InvalidPageToken,
+}
@@ -2,13 +2,15 @@
 pub struct Tenant {
     id: String,
     name: String,
+    /// 集約のバージョン
+    version: u64,
 }
 
 impl Tenant {
     /// Creates a new [`Tenant`].
     #[must_use]
-    pub fn new(id: String, name: String) -> Self {
-        Self { id, name }
+    pub fn new(id: String, name: String, version: u64) -> Self {
+        Self { id, name, version }
     }
 
     #[must_use]
@@ -20,6 +22,12 @@ impl Tenant {
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    /// 集約のバージョン
+    #[must_use]
+    pub fn version(&self) -> u64 {
+        self.version
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Default)]
@@ -51,3 +59,214 @@ impl Item {
         self.price
     }
 }
+
+/// テナント名か商品名で検索した結果
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SearchHit {
+    tenant_id: String,
+    tenant_name: String,
+    /// 商品名に一致した場合は商品。テナント名に一致した場合は `None`
+    item: Option<Item>,
+    /// 検索語との関連度
+    score: f32,
+}
+
+impl SearchHit {
+    /// Creates a new [`SearchHit`].
+    #[must_use]
+    pub fn new(tenant_id: String, tenant_name: String, item: Option<Item>, score: f32) -> Self {
+        Self {
+            tenant_id,
+            tenant_name,
+            item,
+            score,
+        }
+    }
+
+    #[must_use]
+    pub fn tenant_id(&self) -> &str {
+        &self.tenant_id
+    }
+
+    #[must_use]
+    pub fn tenant_name(&self) -> &str {
+        &self.tenant_name
+    }
+
+    #[must_use]
+    pub fn item(&self) -> Option<&Item> {
+        self.item.as_ref()
+    }
+
+    /// 検索語との関連度
+    #[must_use]
+    pub fn score(&self) -> f32 {
+        self.score
+    }
+}
+
+/// 商品一覧の絞り込み条件
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct ItemFilter {
+    /// 値段の下限 (指定した値段を含む)
+    min_price: Option<u32>,
+    /// 値段の上限 (指定した値段を含む)
+    max_price: Option<u32>,
+    /// 商品名に含む文字列
+    name_contains: Option<String>,
+}
+
+impl ItemFilter {
+    /// Creates a new [`ItemFilter`].
+    #[must_use]
+    pub fn new(
+        min_price: Option<u32>,
+        max_price: Option<u32>,
+        name_contains: Option<String>,
+    ) -> Self {
+        Self {
+            min_price,
+            max_price,
+            name_contains,
+        }
+    }
+
+    #[must_use]
+    pub fn min_price(&self) -> Option<u32> {
+        self.min_price
+    }
+
+    #[must_use]
+    pub fn max_price(&self) -> Option<u32> {
+        self.max_price
+    }
+
+    #[must_use]
+    pub fn name_contains(&self) -> Option<&str> {
+        self.name_contains.as_deref()
+    }
+
+    /// 商品が全ての条件を満たすか判定する
+    ///
+    /// 商品名は大文字と小文字を区別せずに比較する
+    #[must_use]
+    pub fn matches(&self, item: &Item) -> bool {
+        self.min_price.is_none_or(|min| min <= item.price())
+            && self.max_price.is_none_or(|max| item.price() <= max)
+            && self
+                .name_contains
+                .as_ref()
+                .is_none_or(|name| item.name().to_lowercase().contains(&name.to_lowercase()))
+    }
+}
+
+/// 商品一覧を並べる順序のキー
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
+pub enum ItemSortKey {
+    /// 商品ID
+    #[default]
+    Id,
+    /// 値段
+    Price,
+    /// 商品名
+    Name,
+}
+
+/// 商品一覧を並べる順序
+///
+/// 同じ値の商品は商品IDの順に並べる
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
+pub struct ItemSort {
+    key: ItemSortKey,
+    descending: bool,
+}
+
+impl ItemSort {
+    /// Creates a new [`ItemSort`].
+    #[must_use]
+    pub fn new(key: ItemSortKey, descending: bool) -> Self {
+        Self { key, descending }
+    }
+
+    #[must_use]
+    pub fn key(&self) -> ItemSortKey {
+        self.key
+    }
+
+    #[must_use]
+    pub fn descending(&self) -> bool {
+        self.descending
+    }
+}
+
+/// 一覧を取得するページの指定
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct PageRequest {
+    page_size: usize,
+    /// 前のページの次のページを取得するトークン。最初のページを取得する場合は `None`
+    page_token: Option<String>,
+}
+
+impl PageRequest {
+    /// Creates a new [`PageRequest`].
+    #[must_use]
+    pub fn new(page_size: usize, page_token: Option<String>) -> Self {
+        Self {
+            page_size,
+            page_token,
+        }
+    }
+
+    #[must_use]
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    #[must_use]
+    pub fn page_token(&self) -> Option<&str> {
+        self.page_token.as_deref()
+    }
+}
+
+/// 一覧の1ページ
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Page<T> {
+    items: Vec<T>,
+    /// 次のページを取得するトークン。次のページがない場合は `None`
+    next_page_token: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// Creates a new [`Page`].
+    #[must_use]
+    pub fn new(items: Vec<T>, next_page_token: Option<String>) -> Self {
+        Self {
+            items,
+            next_page_token,
+        }
+    }
+
+    #[must_use]
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    #[must_use]
+    pub fn next_page_token(&self) -> Option<&str> {
+        self.next_page_token.as_deref()
+    }
+
+    #[must_use]
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+
+    /// ページの要素を変換する
+    #[must_use]
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            next_page_token: self.next_page_token,
+        }
+    }
+}
@@ -2,17 +2,36 @@ use std::future::Future;
 
 use crate::{Aggregate, Id};
 
-use super::{Item, Tenant};
+use super::{Item, ItemFilter, ItemSort, Page, PageRequest, SearchHit, Tenant};
 
 type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
 
 pub trait QueryProcessor {
-    /// テナントの一覧を取得する
-    fn list_tenants(&self) -> impl Future<Output = Result<Vec<Tenant>, Error>> + Send;
+    /// テナントの一覧を1ページ取得する
+    ///
+    /// ページのトークンが不正な場合は [`QueryKernelError::InvalidPageToken`](super::QueryKernelError::InvalidPageToken) を返す
+    fn list_tenants(
+        &self,
+        page: PageRequest,
+    ) -> impl Future<Output = Result<Page<Tenant>, Error>> + Send;
 
-    /// テナント商品の一覧を取得する
+    /// テナント商品の一覧を条件で絞り込み、指定した順序で1ページ取得する
+    ///
+    /// ページのトークンが不正な場合は [`QueryKernelError::InvalidPageToken`](super::QueryKernelError::InvalidPageToken) を返す
     fn list_items(
         &self,
         tenant_id: Id<Aggregate>,
-    ) -> impl Future<Output = Result<Option<Vec<Item>>, Error>> + Send;
+        filter: ItemFilter,
+        sort: ItemSort,
+        page: PageRequest,
+    ) -> impl Future<Output = Result<Option<Page<Item>>, Error>> + Send;
+
+    /// テナント名と商品名から全てのテナントを横断して検索し、関連度の高い順に1ページ取得する
+    ///
+    /// ページのトークンが不正な場合は [`QueryKernelError::InvalidPageToken`](super::QueryKernelError::InvalidPageToken) を返す
+    fn search(
+        &self,
+        query: String,
+        page: PageRequest,
+    ) -> impl Future<Output = Result<Page<SearchHit>, Error>> + Send;
 }
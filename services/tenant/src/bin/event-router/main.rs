@@ -0,0 +1,86 @@
+#![recursion_limit = "256"]
+
+use adapter::command::{EventPayload, EventStoreModel, Item};
+use adapter::{ItemCatalogProjector, ItemCatalogStore};
+use event_router::{Config, Handler, HandlerError, Router};
+
+/// テナントのイベントを構造化ログとして記録するハンドラー
+#[derive(Debug, Clone, Copy)]
+struct AuditLog;
+
+impl Handler<EventPayload> for AuditLog {
+    async fn handle(&self, model: &EventStoreModel) -> Result<(), HandlerError> {
+        let tenant_id = model.aggregate_id();
+        match model.payload() {
+            EventPayload::TenantCreatedV1 { name } => {
+                tracing::info!(tenant_id, name, "tenant created");
+            }
+            EventPayload::ItemsAddedV1 { items } => {
+                let item_ids: Vec<&str> = items
+                    .iter()
+                    .map(|item| match item {
+                        Item::V1 { id, .. } => id.as_str(),
+                    })
+                    .collect();
+                tracing::info!(tenant_id, ?item_ids, "items added");
+            }
+            EventPayload::ItemsRemoved { item_ids } | EventPayload::ItemsRemovedV1 { item_ids } => {
+                tracing::info!(tenant_id, ?item_ids, "items removed");
+            }
+        }
+        Ok(())
+    }
+}
+
+/// テナントのイベントから商品カタログのプロジェクションを更新するハンドラー
+#[derive(Debug, Clone)]
+struct ProjectItemCatalog(ItemCatalogProjector);
+
+impl ProjectItemCatalog {
+    async fn from_env() -> Self {
+        let config = aws_config::defaults(aws_config::BehaviorVersion::latest())
+            .endpoint_url(format!(
+                "http://{}:{}",
+                std::env::var("LOCALSTACK_GATEWAY_HOST").unwrap_or("localhost".to_string()),
+                std::env::var("LOCALSTACK_GATEWAY_PORT").unwrap_or("4566".to_string()),
+            ))
+            .region(
+                aws_config::meta::region::RegionProviderChain::default_provider()
+                    .or_else("ap-northeast-1"),
+            )
+            .test_credentials()
+            .load()
+            .await;
+        let dynamodb = adapter::dynamodb(&config);
+        Self(ItemCatalogProjector::new(
+            adapter::EventStore::dynamodb(dynamodb.clone()),
+            ItemCatalogStore::dynamodb(dynamodb),
+        ))
+    }
+}
+
+impl Handler<EventPayload> for ProjectItemCatalog {
+    async fn handle(&self, model: &EventStoreModel) -> Result<(), HandlerError> {
+        self.0.project(model.aggregate_id()).await
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let router = Router::new().route("audit_log", |_| true, AuditLog).route(
+        "project_item_catalog",
+        // NOTE: 商品を追加・削除したイベントだけが商品カタログを変更する
+        |payload| !matches!(payload, EventPayload::TenantCreatedV1 { .. }),
+        ProjectItemCatalog::from_env().await,
+    );
+    event_router::run(
+        router,
+        Config {
+            service_name: env!("CARGO_PKG_NAME"),
+            service_version: env!("CARGO_PKG_VERSION"),
+            event_store_table_name: adapter::EVENT_STORE_TABLE_NAME,
+            dead_letter_table_name: "tenant-event-router-dead-letter",
+        },
+    )
+    .await
+}
@@ -0,0 +1,39 @@
+#![recursion_limit = "256"]
+
+use adapter::{ItemCatalogProjector, ItemCatalogStore, SearchIndex, SearchProjector};
+use projection::{CheckpointStore, Config, Engine};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let config = aws_config::defaults(aws_config::BehaviorVersion::latest())
+        .endpoint_url(format!(
+            "http://{}:{}",
+            std::env::var("LOCALSTACK_GATEWAY_HOST").unwrap_or("localhost".to_string()),
+            std::env::var("LOCALSTACK_GATEWAY_PORT").unwrap_or("4566".to_string()),
+        ))
+        .region(
+            aws_config::meta::region::RegionProviderChain::default_provider()
+                .or_else("ap-northeast-1"),
+        )
+        .test_credentials()
+        .load()
+        .await;
+    let dynamodb = adapter::dynamodb(&config);
+    let event_store = adapter::EventStore::dynamodb(dynamodb.clone());
+    let search_index =
+        SearchIndex::open(std::env::var("SEARCH_INDEX_DIR").unwrap_or("search-index".to_string()))?;
+    let engine = Engine::new(event_store.clone(), CheckpointStore::from_env())
+        .register(
+            "item_catalog",
+            ItemCatalogProjector::new(event_store.clone(), ItemCatalogStore::dynamodb(dynamodb)),
+        )
+        .register("search", SearchProjector::new(event_store, search_index));
+    projection::run(
+        engine,
+        Config {
+            service_name: env!("CARGO_PKG_NAME"),
+            service_version: env!("CARGO_PKG_VERSION"),
+        },
+    )
+    .await
+}
@@ -1,7 +1,14 @@
-use adapter::{dynamodb, CommandRepository, QueryRepository};
-use app::{CommandUseCase, QueryUseCase};
+#![recursion_limit = "256"]
+
+use adapter::{
+    dynamodb, postgres, sqlite, AggregateMigration, CommandRepository, EventStore,
+    ItemCatalogProjector, ItemCatalogStore, QueryRepository, SearchIndex, SearchProjector,
+    SnapshotPolicy,
+};
+use app::{CommandUseCase, QueryUseCase, RetryPolicy};
 use aws_config::BehaviorVersion;
 use driver::server::{Server, Service};
+use projection::{CheckpointStore, Engine};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
@@ -12,26 +19,116 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>
         std::env::var("HOST").unwrap_or("0.0.0.0".to_string()),
         std::env::var("PORT").unwrap_or(50051.to_string()),
     );
-    let config = aws_config::defaults(BehaviorVersion::v2024_03_28())
-        .endpoint_url(format!(
-            "http://{}:{}",
-            std::env::var("LOCALSTACK_GATEWAY_HOST").unwrap_or("localhost".to_string()),
-            std::env::var("LOCALSTACK_GATEWAY_PORT").unwrap_or("4566".to_string()),
-        ))
-        .region(
-            aws_config::meta::region::RegionProviderChain::default_provider()
-                .or_else("ap-northeast-1"),
-        )
-        .test_credentials()
-        .load()
-        .await;
-    let dynamodb = dynamodb(&config);
+    let snapshot_policy = match std::env::var("SNAPSHOT_FREQUENCY") {
+        Ok(frequency) if frequency == "on-demand" => SnapshotPolicy::OnDemand,
+        Ok(frequency) => SnapshotPolicy::EveryEvents(frequency.parse()?),
+        Err(_) => SnapshotPolicy::default(),
+    };
+    let retry_policy = RetryPolicy::from_env()?;
+    let (event_store, catalog, search_index) = match std::env::var("EVENT_STORE_BACKEND") {
+        Ok(backend) if backend == "in-memory" => (
+            EventStore::in_memory(),
+            ItemCatalogStore::in_memory(),
+            SearchIndex::in_memory()?,
+        ),
+        Ok(backend) if backend == "postgres" => (
+            EventStore::postgres(postgres(&std::env::var("DATABASE_URL")?).await?).await?,
+            ItemCatalogStore::in_memory(),
+            SearchIndex::in_memory()?,
+        ),
+        Ok(backend) if backend == "sqlite" => (
+            EventStore::sqlite(sqlite(&std::env::var("DATABASE_URL")?).await?).await?,
+            ItemCatalogStore::in_memory(),
+            SearchIndex::in_memory()?,
+        ),
+        Ok(backend) if backend != "dynamodb" => {
+            return Err(format!("unknown event store backend: {backend}").into());
+        }
+        _ => {
+            let config = aws_config::defaults(BehaviorVersion::latest())
+                .endpoint_url(format!(
+                    "http://{}:{}",
+                    std::env::var("LOCALSTACK_GATEWAY_HOST").unwrap_or("localhost".to_string()),
+                    std::env::var("LOCALSTACK_GATEWAY_PORT").unwrap_or("4566".to_string()),
+                ))
+                .region(
+                    aws_config::meta::region::RegionProviderChain::default_provider()
+                        .or_else("ap-northeast-1"),
+                )
+                .test_credentials()
+                .load()
+                .await;
+            let dynamodb = dynamodb(&config);
+            if std::env::var("MIGRATE_AGGREGATES").is_ok_and(|x| x == "true") {
+                let migration = AggregateMigration::new(dynamodb.clone());
+                tokio::spawn(async move {
+                    match migration.run().await {
+                        Ok(count) => tracing::info!("migrated {count} aggregates"),
+                        Err(e) => tracing::error!("failed to migrate aggregates: {e}"),
+                    }
+                });
+            }
+            // NOTE: 検索用のインデックスはプロジェクションエンジンが更新するので、同じファイルシステムの同じディレクトリを読み込む。
+            //       プロジェクションエンジンがコミットした変更はインデックスのメタデータの変更を監視して読み込み直す
+            (
+                EventStore::dynamodb(dynamodb.clone()),
+                ItemCatalogStore::dynamodb(dynamodb),
+                SearchIndex::open(
+                    std::env::var("SEARCH_INDEX_DIR").unwrap_or("search-index".to_string()),
+                )?,
+            )
+        }
+    };
+    let mut command_repository =
+        CommandRepository::new(event_store.clone()).with_snapshot_policy(snapshot_policy);
+    if snapshot(&command_repository).await? {
+        shutdown_providers()?;
+        return Ok(());
+    }
+    // NOTE: DynamoDB 以外のバックエンドはイベントルーターでプロジェクションを更新できないので、同じプロセスで更新する
+    if std::env::var("EVENT_STORE_BACKEND").is_ok_and(|backend| backend != "dynamodb") {
+        let item_catalog = ItemCatalogProjector::new(event_store.clone(), catalog.clone());
+        let search = SearchProjector::new(event_store.clone(), search_index.clone());
+        let engine = Engine::new(event_store.clone(), CheckpointStore::from_env())
+            .register("item_catalog", item_catalog.clone())
+            .register("search", search.clone());
+        for name in engine.names() {
+            let count = engine.rebuild(name).await?;
+            tracing::info!("projected {count} events to {name}");
+        }
+        command_repository = command_repository
+            .with_projector(item_catalog)
+            .with_projector(search);
+    }
     let server = Server::new(Service::new(
-        CommandUseCase::new(CommandRepository::new(dynamodb.clone())),
-        QueryUseCase::new(QueryRepository::new(dynamodb)),
+        CommandUseCase::new(command_repository).with_retry_policy(retry_policy),
+        QueryUseCase::new(QueryRepository::new(event_store, catalog, search_index)),
     ));
     tracing::info!("listing on: {addr}");
     server.run(addr.parse()?).await?;
     shutdown_providers()?;
     Ok(())
 }
+
+/// コマンドライン引数に `snapshot` を指定した場合は、指定した集約の現在の状態をスナップショットとして保存して `true` を返す
+///
+/// `SNAPSHOT_FREQUENCY` が `on-demand` の場合にスナップショットを保存するために、サーバーを起動せずに実行する
+async fn snapshot(
+    repository: &CommandRepository,
+) -> Result<bool, Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) != Some("snapshot") {
+        return Ok(false);
+    }
+    if args.len() < 2 {
+        return Err("usage: snapshot <aggregate_id>...".into());
+    }
+    for id in &args[1..] {
+        if repository.snapshot(id.parse()?).await? {
+            println!("saved snapshot: {id}");
+        } else {
+            eprintln!("aggregate not found: {id}");
+        }
+    }
+    Ok(true)
+}
@@ -1,5 +1,9 @@
-use adapter::QueryRepository;
-use adapter::{dynamodb, CommandRepository};
+#![recursion_limit = "256"]
+
+use adapter::{dynamodb, postgres, sqlite, CommandRepository, EventStore};
+use adapter::{
+    ItemCatalogProjector, ItemCatalogStore, QueryRepository, SearchIndex, SearchProjector,
+};
 use app::CommandUseCase;
 use app::QueryUseCase;
 use aws_config::BehaviorVersion;
@@ -9,11 +13,10 @@ use driver::server::Service;
 use proto::tenant::v1::add_items_request::Item;
 use proto::tenant::v1::tenant_service_client::TenantServiceClient;
 use proto::tenant::v1::{
-    AddItemsRequest, CreateRequest, ListItemsRequest, ListTenantsRequest, RemoveItemsRequest,
+    AddItemsRequest, AddItemsResponse, CreateRequest, CreateResponse, ListItemsRequest,
+    ListTenantsRequest, RemoveItemsRequest, SearchRequest,
 };
 use rand::Rng;
-use testcontainers::ContainerAsync;
-use testcontainers_modules::dynamodb_local::DynamoDb;
 use tonic::transport::Channel;
 use tonic::Code;
 use tonic_health::pb::{health_client::HealthClient, HealthCheckRequest};
@@ -25,55 +28,282 @@ type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
 async fn test_with_container_command_ok() -> Result<(), Error> {
     let Context {
         container: _container,
-        mut client,
+        client,
     } = Context::with_container().await?;
+    assert_command_ok(client).await
+}
+
+#[tokio::test]
+async fn test_with_container_command_ok_postgres() -> Result<(), Error> {
+    let Context {
+        container: _container,
+        client,
+    } = Context::with_postgres_container().await?;
+    assert_command_ok(client).await
+}
+
+#[tokio::test]
+async fn test_command_ok() -> Result<(), Error> {
+    let Context { client, .. } = Context::without_container().await?;
+    assert_command_ok(client).await
+}
+
+#[tokio::test]
+async fn test_command_ok_sqlite() -> Result<(), Error> {
+    let Context { client, .. } = Context::with_sqlite().await?;
+    assert_command_ok(client).await
+}
+
+async fn assert_command_ok(mut client: TenantServiceClient<Channel>) -> Result<(), Error> {
     let res = client
         .create(CreateRequest {
             name: "テストテナント".to_string(),
+            idempotency_key: None,
         })
         .await?;
-    let tenant_id = res.into_inner().id;
-    let res = client.list_tenants(ListTenantsRequest {}).await?;
-    assert_eq!(res.into_inner().tenants.len(), 1);
-    let res = client
-        .add_items(AddItemsRequest {
-            tenant_id: tenant_id.clone(),
-            items: vec![
-                Item {
-                    name: "テスト商品1".to_string(),
-                    price: 1000,
-                },
-                Item {
-                    name: "テスト商品2".to_string(),
-                    price: 2000,
-                },
-                Item {
-                    name: "テスト商品3".to_string(),
-                    price: 3000,
-                },
-            ],
-        })
-        .await?;
-    let item_ids = res.into_inner().ids;
+    let CreateResponse {
+        id: tenant_id,
+        version,
+    } = res.into_inner();
+    let res = client.list_tenants(ListTenantsRequest::default()).await?;
+    let tenants = res.into_inner().tenants;
+    assert_eq!(tenants.len(), 1);
+    assert_eq!(tenants[0].version, version);
+    let add_items_request = AddItemsRequest {
+        tenant_id: tenant_id.clone(),
+        items: vec![
+            Item {
+                name: "テスト商品1".to_string(),
+                price: 1000,
+            },
+            Item {
+                name: "テスト商品2".to_string(),
+                price: 2000,
+            },
+            Item {
+                name: "テスト商品3".to_string(),
+                price: 3000,
+            },
+        ],
+        expected_version: Some(version),
+        idempotency_key: Some("add-items".to_string()),
+    };
+    let res = client.add_items(add_items_request.clone()).await?;
+    let AddItemsResponse {
+        ids: item_ids,
+        version,
+    } = res.into_inner();
+    let res = client.add_items(add_items_request).await?;
+    assert_eq!(
+        res.into_inner(),
+        AddItemsResponse {
+            ids: item_ids.clone(),
+            version,
+        },
+        "同じ冪等キーのリクエストは最初の結果を返す"
+    );
     let res = client
         .list_items(ListItemsRequest {
             tenant_id: tenant_id.clone(),
+            ..Default::default()
         })
         .await?;
+    let mut sorted_item_ids = item_ids.clone();
+    sorted_item_ids.sort();
     assert_eq!(
-        item_ids,
+        sorted_item_ids,
         res.into_inner()
             .items
             .into_iter()
             .map(|x| x.id)
-            .collect::<Vec<_>>()
+            .collect::<Vec<_>>(),
+        "商品IDの昇順で取得する"
+    );
+    assert_list_items_paged(&mut client, &tenant_id, &sorted_item_ids).await?;
+    assert_list_items_filtered(&mut client, &tenant_id).await?;
+    assert_search(&mut client, &tenant_id).await?;
+    let item_id = item_ids.get(1).ok_or("item id not found")?.clone();
+    let result = client
+        .remove_items(RemoveItemsRequest {
+            tenant_id: tenant_id.clone(),
+            item_ids: vec![item_id.clone()],
+            expected_version: Some(version - 1),
+            idempotency_key: None,
+        })
+        .await;
+    assert_eq!(
+        result.err().ok_or("stale version must be error")?.code(),
+        Code::Aborted
     );
-    client
+    let res = client
         .remove_items(RemoveItemsRequest {
             tenant_id,
-            item_ids: vec![item_ids.get(1).ok_or("item id not found")?.to_string()],
+            item_ids: vec![item_id],
+            expected_version: Some(version),
+            idempotency_key: None,
         })
         .await?;
+    assert_eq!(res.into_inner().version, version + 1);
+    Ok(())
+}
+
+/// ページサイズを指定して商品一覧を2ページに分けて取得できることを確認する
+async fn assert_list_items_paged(
+    client: &mut TenantServiceClient<Channel>,
+    tenant_id: &str,
+    sorted_item_ids: &[String],
+) -> Result<(), Error> {
+    let first_page = client
+        .list_items(ListItemsRequest {
+            tenant_id: tenant_id.to_string(),
+            page_size: 2,
+            ..Default::default()
+        })
+        .await?
+        .into_inner();
+    let second_page = client
+        .list_items(ListItemsRequest {
+            tenant_id: tenant_id.to_string(),
+            page_size: 2,
+            page_token: first_page.next_page_token.clone(),
+            ..Default::default()
+        })
+        .await?
+        .into_inner();
+    assert_eq!(
+        sorted_item_ids,
+        first_page
+            .items
+            .into_iter()
+            .chain(second_page.items)
+            .map(|x| x.id)
+            .collect::<Vec<_>>(),
+        "次のページのトークンで続きを取得する"
+    );
+    assert!(second_page.next_page_token.is_empty());
+    Ok(())
+}
+
+/// 値段と商品名で絞り込み、値段の降順に並べた商品一覧を取得できることを確認する
+async fn assert_list_items_filtered(
+    client: &mut TenantServiceClient<Channel>,
+    tenant_id: &str,
+) -> Result<(), Error> {
+    use proto::tenant::v1::list_items_request::SortKey;
+
+    let names = |res: proto::tenant::v1::ListItemsResponse| -> Vec<String> {
+        res.items.into_iter().map(|x| x.name).collect()
+    };
+    let res = client
+        .list_items(ListItemsRequest {
+            tenant_id: tenant_id.to_string(),
+            min_price: Some(1500),
+            sort_key: SortKey::Price.into(),
+            descending: true,
+            ..Default::default()
+        })
+        .await?;
+    assert_eq!(
+        names(res.into_inner()),
+        ["テスト商品3", "テスト商品2"],
+        "値段の下限で絞り込み、値段の降順に並べる"
+    );
+    let res = client
+        .list_items(ListItemsRequest {
+            tenant_id: tenant_id.to_string(),
+            max_price: Some(2000),
+            name_contains: Some("商品1".to_string()),
+            sort_key: SortKey::Name.into(),
+            ..Default::default()
+        })
+        .await?;
+    assert_eq!(
+        names(res.into_inner()),
+        ["テスト商品1"],
+        "値段の上限と商品名で絞り込む"
+    );
+    let result = client
+        .list_items(ListItemsRequest {
+            tenant_id: tenant_id.to_string(),
+            min_price: Some(2000),
+            max_price: Some(1000),
+            ..Default::default()
+        })
+        .await;
+    assert_eq!(
+        result
+            .err()
+            .ok_or("invalid price range must be error")?
+            .code(),
+        Code::InvalidArgument
+    );
+    Ok(())
+}
+
+/// テナント名と商品名を日本語で検索できることを確認する
+async fn assert_search(
+    client: &mut TenantServiceClient<Channel>,
+    tenant_id: &str,
+) -> Result<(), Error> {
+    let res = client
+        .search(SearchRequest {
+            query: "商品2".to_string(),
+            ..Default::default()
+        })
+        .await?;
+    let hits = res.into_inner().hits;
+    assert_eq!(
+        hits.iter()
+            .map(|hit| hit.item.as_ref().map(|item| item.name.as_str()))
+            .collect::<Vec<_>>(),
+        [Some("テスト商品2")],
+        "商品名に検索語を含む商品を返す"
+    );
+    assert_eq!(hits[0].tenant_id, tenant_id);
+    assert_eq!(hits[0].tenant_name, "テストテナント");
+    let res = client
+        .search(SearchRequest {
+            query: "テナント".to_string(),
+            ..Default::default()
+        })
+        .await?;
+    assert_eq!(
+        res.into_inner()
+            .hits
+            .into_iter()
+            .map(|hit| (hit.tenant_id, hit.item))
+            .collect::<Vec<_>>(),
+        [(tenant_id.to_string(), None)],
+        "テナント名に検索語を含むテナントを返す"
+    );
+    let first_page = client
+        .search(SearchRequest {
+            query: "テスト 商品".to_string(),
+            page_size: 2,
+            ..Default::default()
+        })
+        .await?
+        .into_inner();
+    let second_page = client
+        .search(SearchRequest {
+            query: "テスト 商品".to_string(),
+            page_size: 2,
+            page_token: first_page.next_page_token.clone(),
+        })
+        .await?
+        .into_inner();
+    assert_eq!(first_page.hits.len() + second_page.hits.len(), 3);
+    assert!(second_page.next_page_token.is_empty());
+    let result = client
+        .search(SearchRequest {
+            query: " ".to_string(),
+            ..Default::default()
+        })
+        .await;
+    assert_eq!(
+        result.err().ok_or("empty query must be error")?.code(),
+        Code::InvalidArgument
+    );
     Ok(())
 }
 
@@ -91,6 +321,7 @@ async fn test_command_create_err() -> Result<(), Error> {
         name: "テナント名が空文字の場合はInvalidArgumentが返る",
         request: CreateRequest {
             name: String::new(),
+            idempotency_key: None,
         },
         expected: Code::InvalidArgument,
     }];
@@ -109,18 +340,23 @@ async fn test_command_create_err() -> Result<(), Error> {
 }
 
 struct Context {
-    container: Option<ContainerAsync<DynamoDb>>,
+    /// テストが終わるまでコンテナを停止させないために保持する
+    container: Option<Box<dyn std::any::Any>>,
     client: TenantServiceClient<Channel>,
 }
 
 impl Context {
     #[allow(clippy::too_many_lines)]
     async fn with_container() -> Result<Self, Error> {
-        use adapter::{AGGREGATE_TABLE_NAME, EVENT_SEQUENCE_TABLE_NAME, EVENT_STORE_TABLE_NAME};
+        use adapter::{
+            AGGREGATE_TABLE_NAME, EVENT_SEQUENCE_TABLE_NAME, EVENT_STORE_TABLE_NAME,
+            IDEMPOTENCY_KEY_TABLE_NAME,
+        };
         use aws_sdk_dynamodb::types::{
             AttributeDefinition, BillingMode, KeySchemaElement, KeyType, ScalarAttributeType,
         };
         use testcontainers::runners::AsyncRunner;
+        use testcontainers_modules::dynamodb_local::DynamoDb;
 
         // NOTE: デフォルトのDockerコンテキスト以外を使っている場合にtestcontainersが正しく動作しないため、
         // 環境変数の `DOCKER_HOST` にホストを設定する必要がある
@@ -134,7 +370,7 @@ impl Context {
             container.get_host().await?,
             container.get_host_port_ipv4(8000).await?
         );
-        let config = aws_config::defaults(BehaviorVersion::v2024_03_28())
+        let config = aws_config::defaults(BehaviorVersion::latest())
             .endpoint_url(endpoint)
             .test_credentials()
             .load()
@@ -190,6 +426,24 @@ impl Context {
             .billing_mode(BillingMode::PayPerRequest)
             .send()
             .await?;
+        dynamodb
+            .create_table()
+            .table_name(IDEMPOTENCY_KEY_TABLE_NAME)
+            .attribute_definitions(
+                AttributeDefinition::builder()
+                    .attribute_name("key")
+                    .attribute_type(ScalarAttributeType::S)
+                    .build()?,
+            )
+            .key_schema(
+                KeySchemaElement::builder()
+                    .attribute_name("key")
+                    .key_type(KeyType::Hash)
+                    .build()?,
+            )
+            .billing_mode(BillingMode::PayPerRequest)
+            .send()
+            .await?;
         dynamodb
             .create_table()
             .table_name(AGGREGATE_TABLE_NAME)
@@ -209,79 +463,98 @@ impl Context {
             .send()
             .await?;
 
-        let server = Server::new(Service::new(
-            CommandUseCase::new(CommandRepository::new(dynamodb.clone())),
-            QueryUseCase::new(QueryRepository::new(dynamodb)),
+        Ok(Self {
+            container: Some(Box::new(container)),
+            client: serve(EventStore::dynamodb(dynamodb)).await?,
+        })
+    }
+
+    async fn with_postgres_container() -> Result<Self, Error> {
+        use testcontainers::runners::AsyncRunner;
+        use testcontainers_modules::postgres::Postgres;
+
+        // NOTE: デフォルトのDockerコンテキスト以外を使っている場合にtestcontainersが正しく動作しないため、
+        // 環境変数の `DOCKER_HOST` にホストを設定する必要がある
+        // read mores: https://github.com/testcontainers/testcontainers-rs/issues/627
+        option_env!("DOCKER_HOST").unwrap_or_else(|| panic!("DOCKER_HOST must be set (e.g. DOCKER_HOST=(docker context inspect | jq -r '.[0].Endpoints.docker.Host'))"));
+
+        // PostgreSQLのコンテナを起動する
+        let container = Postgres::default().start().await?;
+        let pool = postgres(&format!(
+            "postgres://postgres:postgres@{}:{}/postgres",
+            container.get_host().await?,
+            container.get_host_port_ipv4(5432).await?
+        ))
+        .await?;
+        Ok(Self {
+            container: Some(Box::new(container)),
+            client: serve(EventStore::postgres(pool).await?).await?,
+        })
+    }
+
+    /// 一時ディレクトリに作成した `SQLite` のデータベースを使ってサーバーを起動する
+    async fn with_sqlite() -> Result<Self, Error> {
+        let path = std::env::temp_dir().join(format!(
+            "tenant-service-test-{}.db",
+            rand::thread_rng().gen::<u64>()
         ));
-        let port = {
-            let mut rng = rand::thread_rng();
-            rng.gen_range(50000..60000)
-        };
-        let addr = format!("[::1]:{port}").parse()?;
-        // gRPCサーバーを起動する
-        tokio::spawn(async move {
-            server.run(addr).await?;
-            Ok::<_, Error>(())
-        });
-        // gRPCサーバーが起動している状態か確認する
-        let health_check = || async {
-            let conn = tonic::transport::Endpoint::new(format!("http://{addr}"))?.connect_lazy();
-            let mut client = HealthClient::new(conn);
-            let res = client.check(HealthCheckRequest::default()).await?;
-            if res.into_inner().status() != ServingStatus::Serving.into() {
-                return Err("not served".into());
-            }
-            Ok::<_, Error>(())
-        };
-        health_check
-            .retry(ExponentialBuilder::default())
-            .sleep(tokio::time::sleep)
-            .await?;
-        let client = TenantServiceClient::connect(format!("http://{addr}")).await?;
+        let pool = sqlite(&format!("sqlite://{}", path.display())).await?;
         Ok(Self {
-            container: Some(container),
-            client,
+            container: None,
+            client: serve(EventStore::sqlite(pool).await?).await?,
         })
     }
 
+    /// インメモリのイベントストアを使ってサーバーを起動する
     async fn without_container() -> Result<Self, Error> {
-        let config = aws_config::defaults(BehaviorVersion::v2024_03_28())
-            .test_credentials()
-            .load()
-            .await;
-        let dynamodb = dynamodb(&config);
-        let server = Server::new(Service::new(
-            CommandUseCase::new(CommandRepository::new(dynamodb.clone())),
-            QueryUseCase::new(QueryRepository::new(dynamodb)),
-        ));
-        let port = {
-            let mut rng = rand::thread_rng();
-            rng.gen_range(50000..60000)
-        };
-        let addr = format!("[::1]:{port}").parse()?;
-        // gRPCサーバーを起動する
-        tokio::spawn(async move {
-            server.run(addr).await?;
-            Ok::<_, Error>(())
-        });
-        // gRPCサーバーが起動している状態か確認する
-        let health_check = || async {
-            let conn = tonic::transport::Endpoint::new(format!("http://{addr}"))?.connect_lazy();
-            let mut client = HealthClient::new(conn);
-            let res = client.check(HealthCheckRequest::default()).await?;
-            if res.into_inner().status() != ServingStatus::Serving.into() {
-                return Err("not served".into());
-            }
-            Ok::<_, Error>(())
-        };
-        health_check
-            .retry(ExponentialBuilder::default())
-            .sleep(tokio::time::sleep)
-            .await?;
-        let client = TenantServiceClient::connect(format!("http://{addr}")).await?;
         Ok(Self {
             container: None,
-            client,
+            client: serve(EventStore::in_memory()).await?,
         })
     }
 }
+
+/// イベントストアを使ってサーバーを起動し、クライアントを返す
+async fn serve(event_store: EventStore) -> Result<TenantServiceClient<Channel>, Error> {
+    // NOTE: イベントルーターとプロジェクションエンジンを起動しないので、同じプロセスで商品カタログと検索用のインデックスを更新する
+    let catalog = ItemCatalogStore::in_memory();
+    let search_index = SearchIndex::in_memory()?;
+    let command_repository = CommandRepository::new(event_store.clone())
+        .with_projector(ItemCatalogProjector::new(
+            event_store.clone(),
+            catalog.clone(),
+        ))
+        .with_projector(SearchProjector::new(
+            event_store.clone(),
+            search_index.clone(),
+        ));
+    let server = Server::new(Service::new(
+        CommandUseCase::new(command_repository),
+        QueryUseCase::new(QueryRepository::new(event_store, catalog, search_index)),
+    ));
+    let port = {
+        let mut rng = rand::thread_rng();
+        rng.gen_range(50000..60000)
+    };
+    let addr = format!("[::1]:{port}").parse()?;
+    // gRPCサーバーを起動する
+    tokio::spawn(async move {
+        server.run(addr).await?;
+        Ok::<_, Error>(())
+    });
+    // gRPCサーバーが起動している状態か確認する
+    let health_check = || async {
+        let conn = tonic::transport::Endpoint::new(format!("http://{addr}"))?.connect_lazy();
+        let mut client = HealthClient::new(conn);
+        let res = client.check(HealthCheckRequest::default()).await?;
+        if res.into_inner().status() != ServingStatus::Serving.into() {
+            return Err("not served".into());
+        }
+        Ok::<_, Error>(())
+    };
+    health_check
+        .retry(ExponentialBuilder::default())
+        .sleep(tokio::time::sleep)
+        .await?;
+    Ok(TenantServiceClient::connect(format!("http://{addr}")).await?)
+}
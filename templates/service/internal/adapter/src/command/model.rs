@@ -1 +1,98 @@
+use anyhow::Context as _;
+use event_store::VersionedPayload;
+use serde::{Deserialize, Serialize};
 
+/// 集約のテーブルモデル
+pub(crate) type AggregateModel = event_store::AggregateModel<AggregatePayload>;
+
+/// 集約からテーブルモデルに変換する
+pub(crate) fn from_aggregate(
+    aggregate: kernel::command::model::aggregate::Aggregate,
+) -> AggregateModel {
+    AggregateModel::new(
+        aggregate.id().to_string(),
+        aggregate.version(),
+        aggregate.into(),
+    )
+}
+
+/// テーブルモデルから集約に変換する
+pub(crate) fn into_aggregate(
+    model: AggregateModel,
+) -> Result<kernel::command::model::aggregate::Aggregate, anyhow::Error> {
+    use kernel::command::model::aggregate::Aggregate;
+
+    let id = model
+        .id()
+        .parse()
+        .with_context(|| format!("parse aggregate id: {}", model.id()))?;
+    let version = model.version();
+    match model.into_payload() {
+        AggregatePayload::V1 {} => Ok(Aggregate::new(id, version)),
+    }
+}
+
+/// 集約テーブルのペイロード
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub(crate) enum AggregatePayload {
+    V1 {},
+}
+
+impl VersionedPayload for AggregatePayload {
+    fn is_latest(&self) -> bool {
+        matches!(self, Self::V1 { .. })
+    }
+
+    fn into_latest(self) -> Self {
+        self
+    }
+}
+
+impl From<kernel::command::model::aggregate::Aggregate> for AggregatePayload {
+    fn from(_value: kernel::command::model::aggregate::Aggregate) -> Self {
+        Self::V1 {}
+    }
+}
+
+/// イベントストアのペイロード
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub(crate) enum EventPayload {}
+
+impl From<kernel::command::event::Event> for EventPayload {
+    fn from(value: kernel::command::event::Event) -> Self {
+        match value {}
+    }
+}
+
+impl TryFrom<EventPayload> for kernel::command::event::Event {
+    type Error = anyhow::Error;
+
+    fn try_from(value: EventPayload) -> Result<Self, Self::Error> {
+        match value {}
+    }
+}
+
+/// 冪等キーからテーブルモデルに変換する
+pub(crate) fn from_idempotency(
+    idempotency: &kernel::command::model::idempotency::Idempotency,
+) -> event_store::IdempotencyKeyModel {
+    event_store::IdempotencyKeyModel::new(
+        idempotency.key().to_string(),
+        idempotency.aggregate_id().to_string(),
+        idempotency.version(),
+    )
+}
+
+/// テーブルモデルから冪等キーに変換する
+pub(crate) fn into_idempotency(
+    model: &event_store::IdempotencyKeyModel,
+) -> Result<kernel::command::model::idempotency::Idempotency, anyhow::Error> {
+    let aggregate_id = model.aggregate_id();
+    Ok(kernel::command::model::idempotency::Idempotency::new(
+        model.key().to_string(),
+        aggregate_id
+            .parse()
+            .with_context(|| format!("parse aggregate id: {aggregate_id}"))?,
+        model.version(),
+    ))
+}
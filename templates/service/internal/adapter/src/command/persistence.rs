@@ -1 +1,84 @@
+use event_store::dynamodb::DynamoDbEventStore;
+use event_store::in_memory::InMemoryEventStore;
+use event_store::postgres::PostgresEventStore;
+use event_store::sqlite::SqliteEventStore;
+use event_store::AnyEventStore;
 
+use super::model::{AggregatePayload, EventPayload};
+use super::repository::TABLE_NAMES;
+
+#[must_use]
+pub fn dynamodb(config: &aws_config::SdkConfig) -> aws_sdk_dynamodb::Client {
+    aws_sdk_dynamodb::Client::new(config)
+}
+
+/// `PostgreSQL` のコネクションプールを作成する
+///
+/// # Errors
+pub async fn postgres(database_url: &str) -> Result<sqlx::PgPool, sqlx::Error> {
+    sqlx::PgPool::connect(database_url).await
+}
+
+/// `SQLite` のコネクションプールを作成する
+///
+/// データベースのファイルが存在しない場合は作成する
+///
+/// # Errors
+pub async fn sqlite(database_url: &str) -> Result<sqlx::SqlitePool, sqlx::Error> {
+    use std::str::FromStr as _;
+
+    let options = sqlx::sqlite::SqliteConnectOptions::from_str(database_url)?.create_if_missing(true);
+    sqlx::SqlitePool::connect_with(options).await
+}
+
+/// コマンド操作とクエリ操作のリポジトリで共有するイベントストア
+#[derive(Debug, Clone)]
+pub struct EventStore(pub(crate) AnyEventStore<AggregatePayload, EventPayload>);
+
+impl EventStore {
+    /// `DynamoDB` をバックエンドにしたイベントストアを作成する
+    #[must_use]
+    pub fn dynamodb(dynamodb: aws_sdk_dynamodb::Client) -> Self {
+        Self(DynamoDbEventStore::new(dynamodb, TABLE_NAMES).into())
+    }
+
+    /// `PostgreSQL` をバックエンドにしたイベントストアを作成する
+    ///
+    /// テーブルが存在しない場合は作成する
+    ///
+    /// # Errors
+    pub async fn postgres(pool: sqlx::PgPool) -> Result<Self, anyhow::Error> {
+        use anyhow::Context as _;
+
+        let event_store = PostgresEventStore::new(pool, TABLE_NAMES);
+        event_store
+            .create_tables()
+            .await
+            .with_context(|| "create tables")?;
+        Ok(Self(event_store.into()))
+    }
+
+    /// `SQLite` をバックエンドにしたイベントストアを作成する
+    ///
+    /// テーブルが存在しない場合は作成する
+    ///
+    /// # Errors
+    pub async fn sqlite(pool: sqlx::SqlitePool) -> Result<Self, anyhow::Error> {
+        use anyhow::Context as _;
+
+        let event_store = SqliteEventStore::new(pool, TABLE_NAMES);
+        event_store
+            .create_tables()
+            .await
+            .with_context(|| "create tables")?;
+        Ok(Self(event_store.into()))
+    }
+
+    /// プロセスのメモリ上にデータを保存するイベントストアを作成する
+    ///
+    /// `DynamoDB` を用意せずにサーバーを起動する場合に利用する
+    #[must_use]
+    pub fn in_memory() -> Self {
+        Self(InMemoryEventStore::new().into())
+    }
+}
@@ -1,19 +1,75 @@
+use anyhow::Context as _;
+use event_store::{AnyEventStore, EventStore as _, TableNames};
+
+use crate::command::model::{AggregatePayload, EventPayload};
+use crate::command::persistence::EventStore;
+use crate::{
+    AGGREGATE_TABLE_NAME, EVENT_SEQUENCE_TABLE_NAME, EVENT_STORE_TABLE_NAME,
+    IDEMPOTENCY_KEY_TABLE_NAME, OUTBOX_TABLE_NAME,
+};
+
+use super::model::{from_aggregate, from_idempotency, into_aggregate, into_idempotency};
+
+pub use event_store::SnapshotPolicy;
+
+pub(crate) const TABLE_NAMES: TableNames = TableNames {
+    aggregate: AGGREGATE_TABLE_NAME,
+    event_store: EVENT_STORE_TABLE_NAME,
+    event_sequence: EVENT_SEQUENCE_TABLE_NAME,
+    idempotency_key: IDEMPOTENCY_KEY_TABLE_NAME,
+    outbox: OUTBOX_TABLE_NAME,
+};
+
 /// コマンド操作を行うリポジトリ
 #[derive(Debug, Clone)]
-pub struct CommandRepository;
+pub struct CommandRepository {
+    event_store: AnyEventStore<AggregatePayload, EventPayload>,
+}
+
+impl CommandRepository {
+    #[must_use]
+    pub fn new(event_store: EventStore) -> Self {
+        Self {
+            event_store: event_store.0,
+        }
+    }
+
+    /// スナップショットを保存する方針を設定する
+    #[must_use]
+    pub fn with_snapshot_policy(self, snapshot_policy: SnapshotPolicy) -> Self {
+        Self {
+            event_store: self.event_store.with_snapshot_policy(snapshot_policy),
+        }
+    }
+}
 
 impl kernel::command::processor::CommandProcessor for CommandRepository {
     async fn create(
         &self,
-        _aggregate: kernel::command::model::aggregate::Aggregate,
-        _event: kernel::command::event::Event,
+        aggregate: kernel::command::model::aggregate::Aggregate,
+        event: kernel::command::event::Event,
+        idempotency: Option<kernel::command::model::idempotency::Idempotency>,
     ) -> Result<Result<(), kernel::command::error::CommandKernelError>, anyhow::Error> {
-        todo!()
+        match self
+            .event_store
+            .create(
+                from_aggregate(aggregate),
+                event.into(),
+                idempotency.as_ref().map(from_idempotency),
+            )
+            .await
+        {
+            Ok(()) => Ok(Ok(())),
+            Err(event_store::EventStoreError::ConcurrencyConflict) => Ok(Err(
+                kernel::command::error::CommandKernelError::ConcurrencyConflict,
+            )),
+            Err(e) => Err(anyhow::Error::from(e).context("create aggregate")),
+        }
     }
 
     async fn get(
         &self,
-        _id: kernel::id::Id<kernel::command::model::aggregate::Aggregate>,
+        id: kernel::id::Id<kernel::command::model::aggregate::Aggregate>,
     ) -> Result<
         Result<
             Option<kernel::command::model::aggregate::Aggregate>,
@@ -21,14 +77,79 @@ impl kernel::command::processor::CommandProcessor for CommandRepository {
         >,
         anyhow::Error,
     > {
-        todo!()
+        use kernel::command::model::aggregate::Aggregate;
+
+        let Some(aggregate_with_events) = self
+            .event_store
+            .get(&id.to_string())
+            .await
+            .with_context(|| "get aggregate with events")?
+        else {
+            return Ok(Ok(None));
+        };
+        let (snapshot, models) = aggregate_with_events.into_parts();
+        let mut aggregate = match snapshot {
+            Some(snapshot) => {
+                into_aggregate(snapshot).with_context(|| "from AggregateModel to Aggregate")?
+            }
+            None => Aggregate::new(id, 0),
+        };
+        for model in models {
+            let event = model
+                .into_payload()
+                .try_into()
+                .with_context(|| "from EventPayload to Event")?;
+            if let Err(e) = aggregate.apply_event(event) {
+                return Ok(Err(e));
+            }
+        }
+        Ok(Ok(Some(aggregate)))
     }
 
     async fn update(
         &self,
-        _aggregate: kernel::command::model::aggregate::Aggregate,
-        _events: Vec<kernel::command::event::Event>,
+        aggregate: kernel::command::model::aggregate::Aggregate,
+        events: Vec<kernel::command::event::Event>,
+        idempotency: Option<kernel::command::model::idempotency::Idempotency>,
     ) -> Result<Result<(), kernel::command::error::CommandKernelError>, anyhow::Error> {
-        todo!()
+        match self
+            .event_store
+            .update(
+                from_aggregate(aggregate),
+                events.into_iter().map(Into::into).collect(),
+                idempotency.as_ref().map(from_idempotency),
+                Vec::new(),
+            )
+            .await
+        {
+            Ok(()) => Ok(Ok(())),
+            Err(event_store::EventStoreError::ConcurrencyConflict) => Ok(Err(
+                kernel::command::error::CommandKernelError::ConcurrencyConflict,
+            )),
+            Err(e) => Err(anyhow::Error::from(e).context("update aggregate")),
+        }
+    }
+
+    async fn get_idempotency(
+        &self,
+        key: String,
+    ) -> Result<
+        Result<
+            Option<kernel::command::model::idempotency::Idempotency>,
+            kernel::command::error::CommandKernelError,
+        >,
+        anyhow::Error,
+    > {
+        let Some(model) = self
+            .event_store
+            .get_idempotency_key(&key)
+            .await
+            .with_context(|| "get idempotency key")?
+        else {
+            return Ok(Ok(None));
+        };
+        Ok(Ok(Some(into_idempotency(&model).with_context(
+            || "from IdempotencyKeyModel to Idempotency",
+        )?)))
     }
 }
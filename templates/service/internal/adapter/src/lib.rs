@@ -4,3 +4,9 @@
 pub mod command;
 /// クエリ操作関連のモジュール
 pub mod query;
+
+pub(crate) const AGGREGATE_TABLE_NAME: &str = "{{ project-name }}-aggregate";
+pub(crate) const EVENT_SEQUENCE_TABLE_NAME: &str = "{{ project-name }}-event-sequence";
+pub(crate) const EVENT_STORE_TABLE_NAME: &str = "{{ project-name }}-event-store";
+pub(crate) const IDEMPOTENCY_KEY_TABLE_NAME: &str = "{{ project-name }}-idempotency-key";
+pub(crate) const OUTBOX_TABLE_NAME: &str = "{{ project-name }}-outbox";
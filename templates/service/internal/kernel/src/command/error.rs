@@ -1,4 +1,9 @@
 use thiserror::Error;
 
 #[derive(Debug, Error, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub enum CommandKernelError {}
+pub enum CommandKernelError {
+    #[error("Aggregate was updated concurrently")]
+    ConcurrencyConflict,
+    #[error("Aggregate version mismatch: expected {expected}, actual {actual}")]
+    VersionMismatch { expected: u64, actual: u64 },
+}
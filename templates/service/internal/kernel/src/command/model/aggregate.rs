@@ -28,9 +28,27 @@ impl Aggregate {
         self.version
     }
 
+    /// 集約のバージョンが期待するバージョンと一致するか検証する
+    ///
+    /// `expected_version` が `None` の場合は検証しない
+    ///
+    /// # Errors
+    pub fn ensure_version(&self, expected_version: Option<u64>) -> Result<(), CommandKernelError> {
+        match expected_version {
+            Some(expected) if expected != self.version => {
+                Err(CommandKernelError::VersionMismatch {
+                    expected,
+                    actual: self.version,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// 集約にコマンドを実行する
     ///
     /// 集約にコマンドを実行すると、コマンドに応じて集約の状態を変更し、集約の状態を変更したイベントを返す
+    /// `expected_version` を指定した場合は集約がそのバージョンのときだけコマンドを実行する
     ///
     /// # Errors
     ///
@@ -38,7 +56,22 @@ impl Aggregate {
     ///
     /// [`anyhow::Error`]: https://docs.rs/anyhow/latest/anyhow/struct.Error.html
     pub fn apply_command(
-        &mut self, _command: Command) -> Result<Vec<Event>, CommandKernelError> {
+        &mut self,
+        _command: Command,
+        expected_version: Option<u64>,
+    ) -> Result<Vec<Event>, CommandKernelError> {
+        self.ensure_version(expected_version)?;
+        todo!()
+    }
+
+    /// 集約にイベントを適用する
+    ///
+    /// イベントストアから集約を復元するときに利用するため、イベントの検証はせずに集約の状態だけを変更する
+    ///
+    /// # Errors
+    ///
+    /// 集約のバージョンが更新できない場合はエラーを返す
+    pub fn apply_event(&mut self, _event: Event) -> Result<(), CommandKernelError> {
         todo!()
     }
 }
@@ -2,9 +2,33 @@ locals {
   services = [
     {
       name = "tenant",
+      event_router = {
+        environments = [
+          {
+            name  = "OPENTELEMETRY_COLLECTOR_HOST",
+            value = "opentelemetry-collector-gateway",
+          },
+          {
+            name  = "LOCALSTACK_GATEWAY_HOST",
+            value = "localstack",
+          },
+        ],
+      },
     },
     {
       name = "order",
+      event_router = {
+        environments = [
+          {
+            name  = "OPENTELEMETRY_COLLECTOR_HOST",
+            value = "opentelemetry-collector-gateway",
+          },
+          {
+            name  = "LOCALSTACK_GATEWAY_HOST",
+            value = "localstack",
+          },
+        ],
+      },
     },
     {
       name = "cart",
@@ -22,6 +46,10 @@ locals {
             name  = "OPENTELEMETRY_COLLECTOR_HOST",
             value = "opentelemetry-collector-gateway",
           },
+          {
+            name  = "LOCALSTACK_GATEWAY_HOST",
+            value = "localstack",
+          },
         ],
       },
     },
@@ -65,6 +93,62 @@ resource "aws_dynamodb_table" "event_sequence" {
     name = "aggregate_id"
     type = "S"
   }
+
+  attribute {
+    name = "updated_at_partition"
+    type = "S"
+  }
+
+  attribute {
+    name = "updated_at_key"
+    type = "S"
+  }
+
+  # プロジェクションが前回から更新した集約だけを読み取るための索引
+  global_secondary_index {
+    name            = "updated-at-index"
+    hash_key        = "updated_at_partition"
+    range_key       = "updated_at_key"
+    projection_type = "INCLUDE"
+    non_key_attributes = [
+      "latest_event_id",
+      "updated_at",
+    ]
+  }
+}
+
+#trivy:ignore:AVD-AWS-0024 trivy:ignore:AVD-AWS-0025
+resource "aws_dynamodb_table" "idempotency_key" {
+  for_each = { for service in local.services : service.name => service }
+
+  name         = "${each.key}-idempotency-key"
+  billing_mode = "PAY_PER_REQUEST"
+  hash_key     = "key"
+
+  attribute {
+    name = "key"
+    type = "S"
+  }
+}
+
+#trivy:ignore:AVD-AWS-0024 trivy:ignore:AVD-AWS-0025
+resource "aws_dynamodb_table" "outbox" {
+  for_each = { for service in local.services : service.name => service }
+
+  name         = "${each.key}-outbox"
+  billing_mode = "PAY_PER_REQUEST"
+  hash_key     = "aggregate_id"
+  range_key    = "event_id"
+
+  attribute {
+    name = "aggregate_id"
+    type = "S"
+  }
+
+  attribute {
+    name = "event_id"
+    type = "N"
+  }
 }
 
 #trivy:ignore:AVD-AWS-0024 trivy:ignore:AVD-AWS-0025
@@ -81,6 +165,94 @@ resource "aws_dynamodb_table" "aggregate" {
   }
 }
 
+# 注文サービスのクエリ操作でカートIDから注文を検索するプロジェクション。反映した注文の状態とバージョンも保存し、古いバージョンで上書きしないようにする
+#trivy:ignore:AVD-AWS-0024 trivy:ignore:AVD-AWS-0025
+resource "aws_dynamodb_table" "order_projection_order_by_cart" {
+  name         = "order-projection-order-by-cart"
+  billing_mode = "PAY_PER_REQUEST"
+  hash_key     = "cart_id"
+
+  attribute {
+    name = "cart_id"
+    type = "S"
+  }
+}
+
+# 注文サービスのクエリ操作でテナントと注文の状態から注文を検索するプロジェクション
+#trivy:ignore:AVD-AWS-0024 trivy:ignore:AVD-AWS-0025
+resource "aws_dynamodb_table" "order_projection_orders_by_tenant_status" {
+  name         = "order-projection-orders-by-tenant-status"
+  billing_mode = "PAY_PER_REQUEST"
+  hash_key     = "tenant_status"
+  range_key    = "order_id"
+
+  attribute {
+    name = "tenant_status"
+    type = "S"
+  }
+
+  attribute {
+    name = "order_id"
+    type = "S"
+  }
+}
+
+# 注文サービスのクエリ操作で注文の状態から注文を検索するプロジェクション
+#trivy:ignore:AVD-AWS-0024 trivy:ignore:AVD-AWS-0025
+resource "aws_dynamodb_table" "order_projection_orders_by_status" {
+  name         = "order-projection-orders-by-status"
+  billing_mode = "PAY_PER_REQUEST"
+  hash_key     = "status"
+  range_key    = "order_id"
+
+  attribute {
+    name = "status"
+    type = "S"
+  }
+
+  attribute {
+    name = "order_id"
+    type = "S"
+  }
+}
+
+# テナントサービスのクエリ操作でテナントの商品を絞り込み・並べ替えるための商品カタログのプロジェクション
+#trivy:ignore:AVD-AWS-0024 trivy:ignore:AVD-AWS-0025
+resource "aws_dynamodb_table" "tenant_projection_item_catalog" {
+  name         = "tenant-projection-item-catalog"
+  billing_mode = "PAY_PER_REQUEST"
+  hash_key     = "tenant_id"
+  range_key    = "item_id"
+
+  attribute {
+    name = "tenant_id"
+    type = "S"
+  }
+
+  attribute {
+    name = "item_id"
+    type = "S"
+  }
+}
+
+# イベントルーターが何度ルーティングしても失敗したイベントを保存するテーブル
+#trivy:ignore:AVD-AWS-0024 trivy:ignore:AVD-AWS-0025
+resource "aws_dynamodb_table" "event_router_dead_letter" {
+  for_each = {
+    for service in local.services : service.name => service
+    if can(service.event_router)
+  }
+
+  name         = "${each.key}-event-router-dead-letter"
+  billing_mode = "PAY_PER_REQUEST"
+  hash_key     = "id"
+
+  attribute {
+    name = "id"
+    type = "S"
+  }
+}
+
 ##############
 # AWS Lambda #
 ##############
@@ -156,4 +328,6 @@ resource "aws_lambda_event_source_mapping" "event_router" {
   event_source_arn  = aws_dynamodb_table.event_store[each.key].stream_arn
   function_name     = aws_lambda_function.event_router[each.key].arn
   starting_position = "LATEST"
+  # NOTE: 失敗したレコードだけを再試行する
+  function_response_types = ["ReportBatchItemFailures"]
 }